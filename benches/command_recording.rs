@@ -0,0 +1,135 @@
+// Measures the per-call overhead of recording commands through the wrappers
+// against calling the same methods through the raw vtables with a checked
+// Option::unwrap on every call, which is what dx_call used to do. Runs on
+// WARP, so that only the CPU side of command recording is measured:
+//
+//     cargo bench --bench command_recording
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rusty_d3d12::*;
+
+#[no_mangle]
+pub static D3D12SDKVersion: u32 = 606;
+
+#[no_mangle]
+pub static D3D12SDKPath: &[u8; 9] = b".\\D3D12\\\0";
+
+const CALLS_PER_ITERATION: u32 = 10_000;
+const ITERATIONS: u32 = 50;
+
+struct Recorder {
+    command_allocator: CommandAllocator,
+    command_list: CommandList,
+    root_signature: RootSignature,
+}
+
+impl Recorder {
+    fn new() -> DxResult<Self> {
+        let factory = Factory::new(CreateFactoryFlags::None)?;
+        let device = Device::new(&factory.enum_warp_adapter()?)?;
+
+        let root_parameters = [RootParameter::default().new_constants(
+            &RootConstants::default().with_num_32_bit_values(4),
+        )];
+        let (serialized_signature, serialization_result) =
+            RootSignature::serialize_versioned(
+                &VersionedRootSignatureDesc::default().with_desc_1_1(
+                    &RootSignatureDesc::default()
+                        .with_parameters(&root_parameters),
+                ),
+            );
+        serialization_result?;
+        let root_signature = device.create_root_signature(
+            0,
+            &ShaderBytecode::new(serialized_signature.get_buffer()),
+        )?;
+
+        let command_allocator =
+            device.create_command_allocator(CommandListType::Direct)?;
+        let command_list = device.create_command_list(
+            CommandListType::Direct,
+            &command_allocator,
+            None,
+        )?;
+        command_list.close()?;
+
+        Ok(Self {
+            command_allocator,
+            command_list,
+            root_signature,
+        })
+    }
+
+    /// Returns the fastest of the iterations to filter out scheduling noise
+    fn measure(&self, record: impl Fn(&CommandList)) -> DxResult<Duration> {
+        let mut best = Duration::MAX;
+        for _ in 0..ITERATIONS {
+            self.command_allocator.reset()?;
+            self.command_list.reset(&self.command_allocator, None)?;
+            self.command_list
+                .set_graphics_root_signature(&self.root_signature);
+
+            let start = Instant::now();
+            record(&self.command_list);
+            best = best.min(start.elapsed());
+
+            self.command_list.close()?;
+        }
+
+        Ok(best)
+    }
+}
+
+fn record_with_wrappers(command_list: &CommandList) {
+    for index in 0..CALLS_PER_ITERATION {
+        command_list.set_graphics_root_32bit_constant(0, black_box(index), 0);
+        command_list.set_primitive_topology(PrimitiveTopology::TriangleList);
+    }
+}
+
+fn record_with_checked_vtable(command_list: &CommandList) {
+    let this = command_list.as_raw();
+    for index in 0..CALLS_PER_ITERATION {
+        unsafe {
+            let vtbl = (*this).lpVtbl;
+            ((*vtbl).SetGraphicsRoot32BitConstant.unwrap())(
+                this,
+                0,
+                black_box(index),
+                0,
+            );
+            ((*vtbl).IASetPrimitiveTopology.unwrap())(
+                this,
+                PrimitiveTopology::TriangleList as i32,
+            );
+        }
+    }
+}
+
+fn main() {
+    let recorder = Recorder::new().expect("Cannot create WARP device");
+
+    let calls = (2 * CALLS_PER_ITERATION) as f64;
+    let report = |name, duration: Duration| {
+        println!(
+            "{:<16} {:>8.2} ns/call",
+            name,
+            duration.as_nanos() as f64 / calls
+        );
+    };
+
+    report(
+        "wrappers",
+        recorder
+            .measure(record_with_wrappers)
+            .expect("Cannot record commands"),
+    );
+    report(
+        "checked vtable",
+        recorder
+            .measure(record_with_checked_vtable)
+            .expect("Cannot record commands"),
+    );
+}
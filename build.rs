@@ -2,7 +2,7 @@ extern crate bindgen;
 
 use regex::Regex;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 const D3D12_AGILITY_SDK_INCLUDE_PATH: &str = "extern\\D3D12AgilitySDK\\include";
 const D3D12_AGILITY_SDK_LIB_PATH: &str = "extern\\D3D12AgilitySDK\\bin";
@@ -96,15 +96,35 @@ fn patch_d3d12_header() -> String {
         .to_string()
 }
 
+/// x64 binaries live directly in the `bin` directories of the SDKs,
+/// the ARM64 ones are expected in their `arm64` subdirectories. Note that
+/// the build script runs on the host, so the target architecture has to be
+/// taken from Cargo's environment rather than from `cfg!`
+fn target_lib_path(workspace_dir: &Path, lib_path: &str) -> PathBuf {
+    let base_path = workspace_dir.join(lib_path);
+    match env::var("CARGO_CFG_TARGET_ARCH").as_deref() {
+        Ok("aarch64") => {
+            let arm64_path = base_path.join("arm64");
+            if !arm64_path.exists() {
+                println!(
+                    "cargo:warning=ARM64 binaries are expected in {}",
+                    arm64_path.to_str().unwrap()
+                );
+            }
+            arm64_path
+        }
+        _ => base_path,
+    }
+}
+
 fn main() {
     let workspace_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let agility_sdk_lib_path =
+        target_lib_path(&workspace_dir, D3D12_AGILITY_SDK_LIB_PATH);
 
     println!(
         "cargo:rustc-link-search={}",
-        workspace_dir
-            .join(D3D12_AGILITY_SDK_LIB_PATH)
-            .to_str()
-            .unwrap()
+        agility_sdk_lib_path.to_str().unwrap()
     );
     println!("cargo:rustc-link-lib=d3d12");
     println!("cargo:rustc-link-lib=dxgi");
@@ -129,14 +149,16 @@ fn main() {
         // the pre-built library
         println!(
             "cargo:rustc-link-search={}",
-            workspace_dir.join(PIX_LIB_PATH).to_str().unwrap()
+            target_lib_path(&workspace_dir, PIX_LIB_PATH)
+                .to_str()
+                .unwrap()
         );
         println!("cargo:rustc-link-lib=static=pix_wrapper");
         println!("cargo:rustc-link-lib=WinPixEventRuntime");
     }
 
     // Copy DX12 Agility SDK libs that are needed by examples
-    let copy_source_path = workspace_dir.join(D3D12_AGILITY_SDK_LIB_PATH);
+    let copy_source_path = agility_sdk_lib_path;
     let profile = env::var("PROFILE").unwrap();
     let examples_bin_path =
         workspace_dir.join("target").join(profile).join("examples");
@@ -165,10 +187,12 @@ fn main() {
 
     #[cfg(feature = "pix")]
     {
+        let pix_lib_path = target_lib_path(&workspace_dir, PIX_LIB_PATH);
+
         // Copy PIX runtime DLL since it's needed by examples
         let pix_dll_name = "WinPixEventRuntime.dll";
         std::fs::copy(
-            &format!("{}\\{}", PIX_LIB_PATH, pix_dll_name),
+            pix_lib_path.join(pix_dll_name),
             examples_bin_path.join(pix_dll_name),
         )
         .unwrap_or_else(|err| {
@@ -182,7 +206,7 @@ fn main() {
 
         // dll
         std::fs::copy(
-            &format!("{}\\{}", PIX_LIB_PATH, pix_dll_name),
+            pix_lib_path.join(pix_dll_name),
             out_path.join(pix_dll_name),
         )
         .unwrap_or_else(|err| {
@@ -192,7 +216,7 @@ fn main() {
 
         // static wrapper lib
         std::fs::copy(
-            &format!("{}\\{}", PIX_LIB_PATH, pix_wrapper_lib_name),
+            pix_lib_path.join(pix_wrapper_lib_name),
             out_path.join(pix_wrapper_lib_name),
         )
         .unwrap_or_else(|err| {
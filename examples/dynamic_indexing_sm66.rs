@@ -122,7 +122,7 @@ mod sample_assets {
         pub height: u32,
         pub mip_levels: u16,
         pub format: Format,
-        pub data: [DataProperties; D3D12_REQ_MIP_LEVELS as usize],
+        pub data: [DataProperties; REQ_MIP_LEVELS as usize],
     }
 
     pub const TEXTURES: [TextureResource; 1] = {
@@ -600,8 +600,7 @@ impl DynamicIndexingSample {
                 &vertex_buffer,
                 &vertex_staging_buffer,
                 ByteCount(0),
-                0,
-                1,
+                &SubresourceRange::mip(0),
                 &[vertex_subresource_data],
             )
             .expect("Cannot update vertex buffer");
@@ -687,8 +686,7 @@ impl DynamicIndexingSample {
                 &index_buffer,
                 &index_staging_buffer,
                 ByteCount(0),
-                0,
-                1,
+                &SubresourceRange::mip(0),
                 &[index_subresource_data],
             )
             .expect("Cannot update index buffer");
@@ -812,12 +810,10 @@ impl DynamicIndexingSample {
             .set_name("CityDiffuseTexture")
             .expect("Cannot set texture name");
 
-        let subresource_count = u32::from(
-            texture_desc.depth_or_array_size() * texture_desc.mip_levels(),
-        );
+        let subresources = SubresourceRange::all(&texture_desc);
 
         let upload_buffer_size = city_diffuse_texture
-            .get_required_intermediate_size(0, subresource_count)
+            .get_required_intermediate_size(&subresources)
             .expect("Cannot request upload buffer size");
 
         let texture_staging_buffer = self
@@ -852,8 +848,7 @@ impl DynamicIndexingSample {
                 &city_diffuse_texture,
                 &texture_staging_buffer,
                 ByteCount(0),
-                0,
-                subresource_count,
+                &subresources,
                 slice::from_ref(&texture_subresource_data),
             )
             .expect("Cannot upload diffuse texture");
@@ -877,11 +872,10 @@ impl DynamicIndexingSample {
     ) {
         let _debug_printer = make_debug_printer!(&self.info_queue);
 
-        let subresource_count = (texture_desc.depth_or_array_size()
-            * texture_desc.mip_levels()) as u32;
+        let subresources = SubresourceRange::all(texture_desc);
 
         let upload_buffer_step = self.city_material_textures[0]
-            .get_required_intermediate_size(0, subresource_count)
+            .get_required_intermediate_size(&subresources)
             .expect("Cannot get upload buffer step");
 
         let upload_buffer_size =
@@ -922,8 +916,7 @@ impl DynamicIndexingSample {
                     &self.city_material_textures[mat_idx],
                     &materials_staging_buffer,
                     ByteCount(mat_idx as u64 * upload_buffer_step.0),
-                    0,
-                    subresource_count,
+                    &subresources,
                     slice::from_ref(&texture_subresource_data),
                 )
                 .expect("Cannot update material staging buffer");
@@ -1067,7 +1060,7 @@ impl DynamicIndexingSample {
         self.command_list.clear_render_target_view(
             rtv_handle,
             clear_color,
-            &[],
+            None,
         );
 
         self.command_list.clear_depth_stencil_view(
@@ -1075,7 +1068,7 @@ impl DynamicIndexingSample {
             ClearFlags::Depth,
             1.,
             0,
-            &[],
+            None,
         );
 
         if USE_BUNDLES {
@@ -1488,11 +1481,7 @@ fn create_swapchain(
         .with_buffer_count(u32::from(FRAMES_IN_FLIGHT));
     let swapchain = unsafe {
         factory
-            .create_swapchain(
-                &command_queue,
-                hwnd as *mut HWND__,
-                &swapchain_desc,
-            )
+            .create_swapchain(&command_queue, hwnd as HWND, &swapchain_desc)
             .expect("Cannot create swapchain")
     };
     factory
@@ -1534,7 +1523,7 @@ struct SceneConstantBuffer {
 struct FrameResource {
     command_allocator: CommandAllocator,
     bundle_allocator: CommandAllocator,
-    bundle: Option<CommandList>,
+    bundle: Option<Bundle>,
     cbv_staging_buffer: Resource,
     constant_buffer_ptr: *mut u8,
     constant_buffers: Vec<SceneConstantBuffer>,
@@ -1621,8 +1610,7 @@ impl FrameResource {
         cbv_srv_descriptor_handle_size: ByteCount,
     ) {
         let bundle = device
-            .create_command_list(
-                CommandListType::Bundle,
+            .create_typed_command_list::<BundleKind>(
                 &self.bundle_allocator,
                 Some(pso),
             )
@@ -1649,9 +1637,9 @@ impl FrameResource {
         self.bundle = Some(bundle);
     }
 
-    fn populate_command_list(
+    fn populate_command_list<K: GraphicsCapable>(
         &mut self,
-        command_list: &CommandList,
+        command_list: &CommandList<K>,
         frame_resource_index: u32,
         num_indices: u32,
         index_buffer_view_desc: &IndexBufferView,
@@ -0,0 +1,135 @@
+use rusty_d3d12::*;
+
+#[no_mangle]
+pub static D3D12SDKVersion: u32 = 606;
+
+#[no_mangle]
+pub static D3D12SDKPath: &[u8; 9] = b".\\D3D12\\\0";
+
+const SHADER: &str = r#"
+#define RS "RootFlags(0)"
+
+[RootSignature(RS)]
+float4 VS(uint id: SV_VertexID, out float4 color: Color): SV_Position
+{
+    float2 uv = float2(id & 1, id >> 1);
+    color = float4(uv, 1. - uv.x - uv.y, 1.);
+    return float4(uv * 2. - 0.5, 0., 1.);
+}
+
+[RootSignature(RS)]
+float4 PS(float4 pos: SV_Position, float4 color: Color): SV_Target
+{
+    return color;
+}
+"#;
+
+#[derive(Default)]
+struct Triangle {
+    command_allocators: Vec<CommandAllocator>,
+    command_list: Option<CommandList>,
+    root_signature: Option<RootSignature>,
+    pipeline_state: Option<PipelineState>,
+}
+
+impl SampleApp for Triangle {
+    fn init(&mut self, context: &mut SampleContext) -> DxResult<()> {
+        let compile = |entry_point, target| {
+            hassle_rs::utils::compile_hlsl(
+                "triangle.hlsl",
+                SHADER,
+                entry_point,
+                target,
+                &[],
+                &[],
+            )
+            .expect("Cannot compile shader")
+        };
+        let vs = compile("VS", "vs_6_0");
+        let ps = compile("PS", "ps_6_0");
+
+        let device = &context.device;
+        for _ in 0..context.config.back_buffer_count {
+            self.command_allocators.push(
+                device.create_command_allocator(CommandListType::Direct)?,
+            );
+        }
+        let command_list = device.create_command_list(
+            CommandListType::Direct,
+            &self.command_allocators[0],
+            None,
+        )?;
+        command_list.close()?;
+        self.command_list = Some(command_list);
+
+        let root_signature =
+            device.create_root_signature(0, &ShaderBytecode::new(&vs))?;
+        self.pipeline_state = Some(
+            device.create_graphics_pipeline_state(
+                &GraphicsPipelineStateDesc::default()
+                    .with_root_signature(&root_signature)
+                    .with_vs_bytecode(&ShaderBytecode::new(&vs))
+                    .with_ps_bytecode(&ShaderBytecode::new(&ps))
+                    .with_depth_stencil_state(
+                        DepthStencilDesc::default().with_depth_enable(false),
+                    )
+                    .with_primitive_topology_type(
+                        PrimitiveTopologyType::Triangle,
+                    )
+                    .with_rtv_formats(&[context.config.back_buffer_format]),
+            )?,
+        );
+        self.root_signature = Some(root_signature);
+
+        Ok(())
+    }
+
+    fn render(&mut self, context: &mut SampleContext) -> DxResult<()> {
+        let allocator =
+            &self.command_allocators[context.back_buffer_index() as usize];
+        let command_list = self.command_list.as_ref().unwrap();
+        allocator.reset()?;
+        command_list.reset(allocator, self.pipeline_state.as_ref())?;
+
+        let transition = |before, after| {
+            ResourceBarrier::new_transition(
+                &ResourceTransitionBarrier::default()
+                    .with_resource(context.back_buffer())
+                    .with_state_before(before)
+                    .with_state_after(after),
+            )
+        };
+        command_list.resource_barrier(&[transition(
+            ResourceStates::Common,
+            ResourceStates::RenderTarget,
+        )]);
+
+        let rtv = context.back_buffer_rtv();
+        command_list.clear_render_target_view(rtv, [0., 0.1, 0.2, 1.], None);
+        command_list.set_render_targets(&mut [rtv], false, None);
+        command_list
+            .set_graphics_root_signature(self.root_signature.as_ref().unwrap());
+        command_list.set_viewports(&[context.viewport()]);
+        command_list.set_scissor_rects(&[context.scissor_rect()]);
+        command_list.set_primitive_topology(PrimitiveTopology::TriangleList);
+        command_list.draw_instanced(3, 1, 0, 0);
+
+        command_list.resource_barrier(&[transition(
+            ResourceStates::RenderTarget,
+            ResourceStates::Common,
+        )]);
+        command_list.close()?;
+
+        context
+            .command_queue
+            .execute_command_lists(std::slice::from_ref(command_list));
+
+        Ok(())
+    }
+}
+
+fn main() {
+    simple_logger::init_with_level(log::Level::Info).unwrap();
+
+    run_sample(Triangle::default(), SampleConfig::default());
+}
@@ -60,7 +60,7 @@ impl Drop for ScopedDebugMessagePrinter {
 
 macro_rules! make_debug_printer {
     ($info_queue:expr) => {
-        ScopedDebugMessagePrinter::new(Rc::clone(&$info_queue));
+        ScopedDebugMessagePrinter::new(Rc::clone(&$info_queue))
     };
 }
 
@@ -500,7 +500,7 @@ impl HelloMeshShadersSample {
         self.command_list.clear_render_target_view(
             rtv_handle,
             clear_color,
-            &[],
+            None,
         );
 
         // ToDo: DSV
@@ -810,11 +810,7 @@ fn create_swapchain(
         .with_buffer_count(u32::from(FRAMES_IN_FLIGHT));
     let swapchain = unsafe {
         factory
-            .create_swapchain(
-                &command_queue,
-                hwnd as *mut HWND__,
-                &swapchain_desc,
-            )
+            .create_swapchain(&command_queue, hwnd as HWND, &swapchain_desc)
             .expect("Cannot create swapchain")
     };
     factory
@@ -359,7 +359,7 @@ impl HelloTextureSample {
             .texture
             .as_ref()
             .expect("No texture has been created")
-                .get_required_intermediate_size(0, 1)
+                .get_required_intermediate_size(&SubresourceRange::mip(0))
                 .expect("Cannot get required intermediate size for texture staging buffer");
 
         self.texture_upload_heap = Some(
@@ -395,8 +395,7 @@ impl HelloTextureSample {
                     .as_ref()
                     .expect("No texture staging buffer has been created"),
                 ByteCount(0),
-                0,
-                1,
+                &SubresourceRange::mip(0),
                 &vec![texture_subresource_data],
             )
             .expect("Cannot update texture");
@@ -456,7 +455,7 @@ impl HelloTextureSample {
         self.command_list.clear_render_target_view(
             rtv_handle,
             clear_color,
-            &[],
+            None,
         );
 
         self.command_list
@@ -727,11 +726,7 @@ fn create_swapchain(
         .with_buffer_count(FRAMES_IN_FLIGHT);
     let swapchain = unsafe {
         factory
-            .create_swapchain(
-                &command_queue,
-                hwnd as *mut HWND__,
-                &swapchain_desc,
-            )
+            .create_swapchain(&command_queue, hwnd as HWND, &swapchain_desc)
             .expect("Cannot create swapchain")
     };
     factory
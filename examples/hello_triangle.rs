@@ -147,14 +147,12 @@ struct HelloTriangleSample {
     index_buffers: Vec<IndexBuffer>,
     vertex_buffers: Vec<VertexBuffer>,
     current_frame: u64,
-    current_fence_value: u64,
     rtv_descriptor_size: ByteCount,
     rtv_heap: DescriptorHeap,
     swapchain: Swapchain,
     command_list: CommandList,
     command_allocator: CommandAllocator,
     command_queue: CommandQueue,
-    fence: Fence,
     info_queue: Rc<InfoQueue>,
     device: Device,
     adapter: Adapter,
@@ -183,10 +181,6 @@ impl HelloTriangleSample {
         let _debug_printer =
             ScopedDebugMessagePrinter::new(Rc::clone(&info_queue));
 
-        let fence = device
-            .create_fence(0, FenceFlags::None)
-            .expect("Cannot create fence");
-
         let rtv_descriptor_size = device
             .get_descriptor_handle_increment_size(DescriptorHeapType::Rtv);
 
@@ -234,13 +228,11 @@ impl HelloTriangleSample {
             index_buffers: vec![],
             vertex_buffers: vec![],
             current_frame: 0,
-            current_fence_value: 0,
             debug_layer: debug_layer,
             factory: factory,
             adapter: adapter,
             device: device,
             info_queue: info_queue,
-            fence: fence,
             rtv_descriptor_size,
             command_queue: command_queue,
             command_allocator: command_allocator,
@@ -423,7 +415,7 @@ float4 PS(VertexOut input) : SV_Target
         self.command_list.clear_render_target_view(
             rtv_handle,
             [0., 0.1, 0.8, 1.],
-            &[],
+            None,
         );
         self.command_list
             .set_render_targets(&mut [rtv_handle], false, None);
@@ -627,21 +619,9 @@ impl HelloTriangleSample {
     }
 
     fn flush_command_queue(&mut self) {
-        self.current_fence_value += 1;
         self.command_queue
-            .signal(&self.fence, self.current_fence_value)
-            .expect("Cannot signal fence from command queue");
-        if self.fence.get_completed_value() < self.current_fence_value {
-            let event_handle = Win32Event::default();
-            self.fence
-                .set_event_on_completion(
-                    self.current_fence_value,
-                    &event_handle,
-                )
-                .expect("Cannot set fence completion event");
-            event_handle.wait(None);
-            event_handle.close();
-        }
+            .wait_idle()
+            .expect("Cannot wait for command queue");
     }
 
     fn compile_shader(
@@ -673,6 +653,9 @@ impl HelloTriangleSample {
 
 impl Drop for HelloTriangleSample {
     fn drop(&mut self) {
+        self.command_queue
+            .wait_idle()
+            .expect("Cannot wait for command queue");
         self.info_queue
             .print_messages()
             .expect("Cannot print info queue messages");
@@ -1,11 +1,9 @@
 use std::cmp::max;
 use std::cmp::min;
 use std::ffi::c_void;
-use std::ffi::CStr;
 use std::intrinsics::copy_nonoverlapping;
 use std::mem::size_of;
 use std::mem::MaybeUninit;
-use std::os::raw::c_char;
 use std::rc::Rc;
 use std::slice;
 
@@ -44,51 +42,30 @@ pub static D3D12SDKVersion: u32 = 606;
 #[no_mangle]
 pub static D3D12SDKPath: &[u8; 9] = b".\\D3D12\\\0";
 
-#[allow(clippy::not_unsafe_ptr_arg_deref)]
-#[no_mangle]
-pub extern "C" fn debug_callback(
-    category: i32,
-    severity: i32,
-    id: i32,
-    description: *const c_char,
-    _context: *mut c_void,
+fn debug_callback(
+    category: MessageCategory,
+    severity: MessageSeverity,
+    id: MessageId,
+    description: &str,
 ) {
-    let severity: MessageSeverity = unsafe { std::mem::transmute(severity) };
-    let category: MessageCategory = unsafe { std::mem::transmute(category) };
-    let description = unsafe { CStr::from_ptr(description) };
-
+    let id = id as i32;
     match severity {
         MessageSeverity::Message | MessageSeverity::Info => {
             info!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
         MessageSeverity::Warning => {
             warn!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
         _ => {
             error!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
     }
@@ -323,6 +300,9 @@ struct Pipeline {
     devices: [Device; DEVICE_COUNT],
     debug_devices: Option<[DebugDevice; DEVICE_COUNT]>,
     info_queues: Option<[Rc<InfoQueue>; DEVICE_COUNT]>,
+    // unregister the debug callbacks when dropped
+    #[cfg(feature = "debug_callback")]
+    _message_callbacks: Vec<MessageCallbackCookie>,
     direct_command_queues: [CommandQueue; DEVICE_COUNT],
     direct_command_queue_timestamp_frequencies: [u64; DEVICE_COUNT],
     copy_command_queue: CommandQueue,
@@ -432,6 +412,8 @@ impl Pipeline {
             debug_devices = None;
         }
 
+        #[cfg(feature = "debug_callback")]
+        let mut message_callbacks = vec![];
         let info_queues;
         if USE_DEBUG {
             let mut temp_info_queues: [MaybeUninit<Rc<InfoQueue>>;
@@ -451,12 +433,14 @@ impl Pipeline {
                 );
 
                 #[cfg(feature = "debug_callback")]
-                info_queue
-                    .register_callback(
-                        debug_callback,
-                        MessageCallbackFlags::FlagNone,
-                    )
-                    .expect("Cannot set debug callback on info queue");
+                message_callbacks.push(
+                    info_queue
+                        .register_callback(
+                            debug_callback,
+                            MessageCallbackFlags::FlagNone,
+                        )
+                        .expect("Cannot set debug callback on info queue"),
+                );
 
                 temp_info_queues[device_idx] = MaybeUninit::new(info_queue);
             }
@@ -574,7 +558,12 @@ impl Pipeline {
 
         let heap_as_dc: DeviceChild = heap_primary.clone().into();
         let heap_handle = devices[0]
-            .create_shared_handle(&heap_as_dc, "SharedHeapHandle")
+            .create_shared_handle(
+                &heap_as_dc,
+                None,
+                AccessRights::All,
+                "SharedHeapHandle",
+            )
             .expect("Cannot create shared heap handle");
 
         let heap_secondary = devices[1]
@@ -808,6 +797,8 @@ impl Pipeline {
             devices,
             debug_devices,
             info_queues,
+            #[cfg(feature = "debug_callback")]
+            _message_callbacks: message_callbacks,
             direct_command_queues,
             direct_command_queue_timestamp_frequencies,
             copy_command_queue,
@@ -932,7 +923,7 @@ impl Pipeline {
             let secondary_adapter_texture_desc =
                 self.secondary_adapter_textures[self.frame_index].get_desc();
 
-            let (texture_layout, _, _, _) = self.devices[adapter_idx]
+            let texture_layout = self.devices[adapter_idx]
                 .get_copyable_footprints(
                     &secondary_adapter_texture_desc,
                     0,
@@ -947,7 +938,7 @@ impl Pipeline {
 
             let src = TextureCopyLocation::new_placed_footprint(
                 &self.cross_adapter_resources[adapter_idx][self.frame_index],
-                texture_layout[0],
+                texture_layout.subresource(0).layout,
             );
 
             let resource_box = Box::default()
@@ -1157,7 +1148,7 @@ impl Pipeline {
         } else {
             let render_target_desc =
                 self.render_targets[adapter_idx][self.frame_index].get_desc();
-            let (render_target_layout, _, _, _) = self.devices[adapter_idx]
+            let render_target_layout = self.devices[adapter_idx]
                 .get_copyable_footprints(
                     &render_target_desc,
                     0,
@@ -1167,7 +1158,7 @@ impl Pipeline {
 
             let dest = TextureCopyLocation::new_placed_footprint(
                 &self.cross_adapter_resources[adapter_idx][self.frame_index],
-                render_target_layout[0],
+                render_target_layout.subresource(0).layout,
             );
 
             let src = TextureCopyLocation::new_subresource_index(
@@ -1255,14 +1246,14 @@ impl Pipeline {
         self.direct_command_lists[adapter_idx].clear_render_target_view(
             rtv_handle,
             CLEAR_COLOR,
-            &[],
+            None,
         );
         self.direct_command_lists[adapter_idx].clear_depth_stencil_view(
             dsv_handle,
             ClearFlags::Depth,
             1.,
             0,
-            &[],
+            None,
         );
 
         self.direct_command_lists[adapter_idx]
@@ -1281,18 +1272,14 @@ impl Pipeline {
                 ),
             );
 
-        let cb_virtual_address =
-            self.triangle_constant_buffer.get_gpu_virtual_address();
         for tri_idx in 0..self.triangle_count {
-            let cb_location = GpuVirtualAddress(
-                cb_virtual_address.0
-                    + (self.frame_index
-                        * MAX_TRIANGLE_COUNT as usize
-                        * size_of::<SceneConstantBuffer>())
-                        as u64
-                    + (tri_idx as usize * size_of::<SceneConstantBuffer>())
-                        as u64,
-            );
+            let cb_location =
+                self.triangle_constant_buffer
+                    .gpu_address_at(ByteCount::from(
+                        (self.frame_index * MAX_TRIANGLE_COUNT as usize
+                            + tri_idx as usize)
+                            * size_of::<SceneConstantBuffer>(),
+                    ));
 
             self.direct_command_lists[adapter_idx]
                 .set_graphics_root_constant_buffer_view(0, cb_location);
@@ -1623,6 +1610,8 @@ fn create_fences(
     let fence_handle = devices[0]
         .create_shared_handle(
             &cross_adapter_fence_primary.clone().into(),
+            None,
+            AccessRights::All,
             "CrossAdapterFence",
         )
         .expect("Cannot create shared handle for cross adapter fence");
@@ -1962,8 +1951,7 @@ fn create_blur_vertex_buffer(
             &quad_vertex_buffer,
             &quad_vertex_buffer_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&quad_vertex_data),
         )
         .expect("Cannot upload quad_vertex buffer");
@@ -2058,8 +2046,7 @@ fn create_primary_vertex_buffer(
             &vertex_buffer,
             &vertex_buffer_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&vertex_data),
         )
         .expect("Cannot upload vertex buffer");
@@ -2405,25 +2392,24 @@ fn create_shared_resource_descs(
         texture_size = texture_info.size_in_bytes();
     } else {
         info!("Cross adapter textures are not supported");
-        let (layout, _, _, _) = devices[0].get_copyable_footprints(
-            &ResourceDesc::default()
-                .with_dimension(ResourceDimension::Texture2D)
-                .with_format(Format::R8G8B8A8Unorm)
-                .with_width(WINDOW_WIDTH.into())
-                .with_height(WINDOW_HEIGHT.into())
-                .with_flags(ResourceFlags::AllowRenderTarget),
-            0,
-            1,
-            ByteCount(0),
-        );
+        let layout = devices[0]
+            .get_copyable_footprints(
+                &ResourceDesc::default()
+                    .with_dimension(ResourceDimension::Texture2D)
+                    .with_format(Format::R8G8B8A8Unorm)
+                    .with_width(WINDOW_WIDTH.into())
+                    .with_height(WINDOW_HEIGHT.into())
+                    .with_flags(ResourceFlags::AllowRenderTarget),
+                0,
+                1,
+                ByteCount(0),
+            )
+            .subresource(0)
+            .layout;
 
-        texture_size = align_to_multiple(
-            (layout[0].footprint().row_pitch()
-                * layout[0].footprint().height())
-            .0,
-            DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT.0,
-        )
-        .into();
+        texture_size = (layout.footprint().row_pitch()
+            * layout.footprint().height())
+        .align_up(DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT);
 
         cross_adapter_desc = ResourceDesc::default()
             .with_dimension(ResourceDimension::Buffer)
@@ -2657,11 +2643,7 @@ fn create_swapchain(
         .with_buffer_count(FRAMES_IN_FLIGHT as u32);
     let swapchain = unsafe {
         factory
-            .create_swapchain(
-                &command_queue,
-                hwnd as *mut HWND__,
-                &swapchain_desc,
-            )
+            .create_swapchain(&command_queue, hwnd as HWND, &swapchain_desc)
             .expect("Cannot create swapchain")
     };
     factory
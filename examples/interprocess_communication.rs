@@ -1,11 +1,9 @@
 use std::cmp::max;
 use std::cmp::min;
 use std::ffi::c_void;
-use std::ffi::CStr;
 use std::intrinsics::copy_nonoverlapping;
 use std::mem::size_of;
 use std::mem::MaybeUninit;
-use std::os::raw::c_char;
 use std::rc::Rc;
 use std::slice;
 
@@ -46,51 +44,30 @@ pub static D3D12SDKVersion: u32 = 606;
 #[no_mangle]
 pub static D3D12SDKPath: &[u8; 9] = b".\\D3D12\\\0";
 
-#[allow(clippy::not_unsafe_ptr_arg_deref)]
-#[no_mangle]
-pub extern "C" fn debug_callback(
-    category: i32,
-    severity: i32,
-    id: i32,
-    description: *const c_char,
-    _context: *mut c_void,
+fn debug_callback(
+    category: MessageCategory,
+    severity: MessageSeverity,
+    id: MessageId,
+    description: &str,
 ) {
-    let severity: MessageSeverity = unsafe { std::mem::transmute(severity) };
-    let category: MessageCategory = unsafe { std::mem::transmute(category) };
-    let description = unsafe { CStr::from_ptr(description) };
-
+    let id = id as i32;
     match severity {
         MessageSeverity::Message | MessageSeverity::Info => {
             info!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
         MessageSeverity::Warning => {
             warn!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
         _ => {
             error!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
     }
@@ -183,6 +160,9 @@ struct Pipeline {
     device: Device,
     debug_device: Option<DebugDevice>,
     info_queue: Option<Rc<InfoQueue>>,
+    // unregisters the debug callback when dropped
+    #[cfg(feature = "debug_callback")]
+    _message_callback: Option<MessageCallbackCookie>,
     direct_command_queue: CommandQueue,
     swapchain: Swapchain,
     frame_index: usize,
@@ -244,6 +224,8 @@ impl Pipeline {
             debug_device = None;
         }
 
+        #[cfg(feature = "debug_callback")]
+        let mut message_callback = None;
         let info_queue;
         if USE_DEBUG {
             let temp_info_queue = Rc::from(
@@ -260,12 +242,16 @@ impl Pipeline {
             );
 
             #[cfg(feature = "debug_callback")]
-            temp_info_queue
-                .register_callback(
-                    debug_callback,
-                    MessageCallbackFlags::FlagNone,
-                )
-                .expect("Cannot set debug callback on info queue");
+            {
+                message_callback = Some(
+                    temp_info_queue
+                        .register_callback(
+                            debug_callback,
+                            MessageCallbackFlags::FlagNone,
+                        )
+                        .expect("Cannot set debug callback on info queue"),
+                );
+            }
 
             info_queue = Some(temp_info_queue);
         } else {
@@ -329,11 +315,19 @@ impl Pipeline {
 
             let heap_as_dc: DeviceChild = shared_heap.clone().into();
             let heap_handle = device
-                .create_shared_handle(&heap_as_dc, "SharedHeapHandle")
+                .create_shared_handle(
+                    &heap_as_dc,
+                    None,
+                    AccessRights::All,
+                    "SharedHeapHandle",
+                )
                 .expect("Cannot create shared heap handle");
         } else {
             let shared_heap_handle = device
-                .open_shared_handle_by_name("SharedHeapHandle")
+                .open_shared_handle_by_name(
+                    "SharedHeapHandle",
+                    AccessRights::All,
+                )
                 .expect("Cannot open SharedHeapHandle");
 
             shared_heap = device
@@ -426,6 +420,8 @@ impl Pipeline {
             device,
             debug_device,
             info_queue,
+            #[cfg(feature = "debug_callback")]
+            _message_callback: message_callback,
             direct_command_queue,
             swapchain,
             frame_index,
@@ -502,7 +498,7 @@ impl Pipeline {
         self.direct_command_list.clear_render_target_view(
             rtv_handle,
             CLEAR_COLOR,
-            &[],
+            None,
         );
 
         self.direct_command_list
@@ -752,6 +748,8 @@ fn create_fences(
         let shared_resource_fence_handle = device
             .create_shared_handle(
                 &shared_resource_fence.clone().into(),
+                None,
+                AccessRights::All,
                 "CrossProcessResourceFence",
             )
             .expect(
@@ -759,7 +757,10 @@ fn create_fences(
             );
     } else {
         let shared_resource_fence_handle = device
-            .open_shared_handle_by_name("CrossProcessResourceFence")
+            .open_shared_handle_by_name(
+                "CrossProcessResourceFence",
+                AccessRights::All,
+            )
             .expect("Cannot open CrossProcessConsumerFence handle");
         shared_resource_fence = device
             .open_shared_fence_handle(shared_resource_fence_handle)
@@ -897,8 +898,7 @@ fn create_vertex_buffer(
             &vertex_buffer,
             &vertex_buffer_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&vertex_data),
         )
         .expect("Cannot upload vertex buffer");
@@ -1039,14 +1039,14 @@ fn create_shared_resource_desc(device: &Device) -> (ByteCount, ResourceDesc) {
         .with_height(WINDOW_HEIGHT.into())
         .with_flags(ResourceFlags::AllowCrossAdapter);
 
-    let (layout, _, _, _) =
-        device.get_copyable_footprints(&cross_adapter_desc, 0, 1, 0.into());
+    let layout = device
+        .get_copyable_footprints(&cross_adapter_desc, 0, 1, 0.into())
+        .subresource(0)
+        .layout;
 
-    texture_size = align_to_multiple(
-        (layout[0].footprint().row_pitch() * layout[0].footprint().height()).0,
-        DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT.0,
-    )
-    .into();
+    texture_size = (layout.footprint().row_pitch()
+        * layout.footprint().height())
+    .align_up(DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT);
 
     (texture_size, cross_adapter_desc)
 }
@@ -1140,11 +1140,7 @@ fn create_swapchain(
         .with_buffer_count(FRAMES_IN_FLIGHT as u32);
     let swapchain = unsafe {
         factory
-            .create_swapchain(
-                &command_queue,
-                hwnd as *mut HWND__,
-                &swapchain_desc,
-            )
+            .create_swapchain(&command_queue, hwnd as HWND, &swapchain_desc)
             .expect("Cannot create swapchain")
     };
     factory
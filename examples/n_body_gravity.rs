@@ -4,10 +4,8 @@ extern crate rusty_d3d12;
 use std::cmp::max;
 use std::cmp::min;
 use std::ffi::c_void;
-use std::ffi::CStr;
 use std::intrinsics::copy_nonoverlapping;
 use std::mem::MaybeUninit;
-use std::os::raw::c_char;
 use std::rc::Rc;
 use std::slice;
 use std::sync::atomic::AtomicU64;
@@ -58,51 +56,30 @@ pub static D3D12SDKVersion: u32 = 606;
 #[no_mangle]
 pub static D3D12SDKPath: &[u8; 9] = b".\\D3D12\\\0";
 
-#[allow(clippy::not_unsafe_ptr_arg_deref)]
-#[no_mangle]
-pub extern "C" fn debug_callback(
-    category: i32,
-    severity: i32,
-    id: i32,
-    description: *const c_char,
-    _context: *mut c_void,
+fn debug_callback(
+    category: MessageCategory,
+    severity: MessageSeverity,
+    id: MessageId,
+    description: &str,
 ) {
-    let severity: MessageSeverity = unsafe { std::mem::transmute(severity) };
-    let category: MessageCategory = unsafe { std::mem::transmute(category) };
-    let description = unsafe { CStr::from_ptr(description) };
-
+    let id = id as i32;
     match severity {
         MessageSeverity::Message | MessageSeverity::Info => {
             info!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
         MessageSeverity::Warning => {
             warn!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
         _ => {
             error!(
                 "[D3D12 Message][{}][{}][{:#x}] {}",
-                severity,
-                category,
-                id as i32,
-                description
-                    .to_str()
-                    .expect("Cannot make Rust string from D3D12 layer message")
+                severity, category, id, description
             );
         }
     }
@@ -526,7 +503,7 @@ impl GraphicsContext {
                     context.direct_command_list.clear_render_target_view(
                         rtv_handle,
                         CLEAR_COLOR,
-                        &[],
+                        None,
                     );
 
                     let srv_index = if frame_idx == 0 {
@@ -867,6 +844,9 @@ struct Pipeline {
     device: Device,
     debug_device: Option<DebugDevice>,
     info_queue: Option<Rc<InfoQueue>>,
+    // unregisters the debug callback when dropped
+    #[cfg(feature = "debug_callback")]
+    _message_callback: Option<MessageCallbackCookie>,
     swapchain: Swapchain,
     swapchain_event: Win32Event,
     frame_index: usize,
@@ -934,6 +914,8 @@ impl Pipeline {
             debug_device = None;
         }
 
+        #[cfg(feature = "debug_callback")]
+        let mut message_callback = None;
         let info_queue;
         if USE_DEBUG {
             let temp_info_queue = Rc::from(
@@ -950,12 +932,16 @@ impl Pipeline {
             );
 
             #[cfg(feature = "debug_callback")]
-            temp_info_queue
-                .register_callback(
-                    debug_callback,
-                    MessageCallbackFlags::FlagNone,
-                )
-                .expect("Cannot set debug callback on info queue");
+            {
+                message_callback = Some(
+                    temp_info_queue
+                        .register_callback(
+                            debug_callback,
+                            MessageCallbackFlags::FlagNone,
+                        )
+                        .expect("Cannot set debug callback on info queue"),
+                );
+            }
 
             info_queue = Some(temp_info_queue);
         } else {
@@ -1112,6 +1098,8 @@ impl Pipeline {
             device,
             debug_device,
             info_queue,
+            #[cfg(feature = "debug_callback")]
+            _message_callback: message_callback,
             swapchain,
             swapchain_event,
             frame_index,
@@ -1418,8 +1406,7 @@ fn create_cs_constant_buffer(
             &constant_buffer_cs,
             &constant_buffer_cs_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&subresource_data),
         )
         .expect("Cannot upload vertex buffer");
@@ -1563,8 +1550,7 @@ fn create_particle_buffers(
             &particle_buffer_0,
             &particle_buffer_0_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&particle_data),
         )
         .expect("Cannot upload particle buffer");
@@ -1574,8 +1560,7 @@ fn create_particle_buffers(
             &particle_buffer_1,
             &particle_buffer_1_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&particle_data),
         )
         .expect("Cannot upload particle buffer");
@@ -1712,8 +1697,7 @@ fn create_vertex_buffer(
             &vertex_buffer,
             &vertex_buffer_upload,
             ByteCount(0),
-            0,
-            1,
+            &SubresourceRange::mip(0),
             slice::from_ref(&vertex_data),
         )
         .expect("Cannot upload vertex buffer");
@@ -1992,11 +1976,7 @@ fn create_swapchain(
         .with_buffer_count(FRAMES_IN_FLIGHT as u32);
     let swapchain = unsafe {
         factory
-            .create_swapchain(
-                &command_queue,
-                hwnd as *mut HWND__,
-                &swapchain_desc,
-            )
+            .create_swapchain(&command_queue, hwnd as HWND, &swapchain_desc)
             .expect("Cannot create swapchain")
     };
     factory
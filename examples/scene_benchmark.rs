@@ -0,0 +1,961 @@
+// Headless stress scene used to track the CPU overhead of the wrappers and
+// the GPU frame time: a large terrain made of procedural patches, whose
+// per-object data lives in buffers placed by the allocator and is accessed
+// bindlessly (SM 6.6 ResourceDescriptorHeap) through descriptors handed out
+// by the descriptor allocator. The draws are recorded in parallel by
+// a pool of worker threads, and the transitions between the terrain pass and
+// the copy of its output are derived by a small render graph. Results can be
+// saved as a baseline and later runs compared against it, failing with
+// a non-zero exit code on regressions
+
+use std::ops::Range;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+use log::{error, info};
+
+use rusty_d3d12::*;
+
+#[no_mangle]
+pub static D3D12SDKVersion: u32 = 606;
+
+#[no_mangle]
+pub static D3D12SDKPath: &[u8; 9] = b".\\D3D12\\\0";
+
+const SHADER: &str = r#"
+#define RS "RootFlags(CBV_SRV_UAV_HEAP_DIRECTLY_INDEXED), " \
+           "RootConstants(num32BitConstants=3, b0)"
+
+struct ObjectData
+{
+    float4 offset_scale_height;
+    float4 color;
+};
+
+struct Constants
+{
+    uint chunk_descriptor;
+    uint object_index;
+    uint patch_size;
+};
+
+ConstantBuffer<Constants> constants: register(b0);
+
+struct VertexOut
+{
+    float4 pos: SV_Position;
+    float4 color: Color;
+};
+
+[RootSignature(RS)]
+VertexOut VS(uint id: SV_VertexID)
+{
+    StructuredBuffer<ObjectData> objects =
+        ResourceDescriptorHeap[constants.chunk_descriptor];
+    ObjectData object = objects[constants.object_index];
+
+    // two triangles per quad, patch_size x patch_size quads per patch
+    static const uint2 corners[6] = {
+        uint2(0, 0), uint2(1, 0), uint2(0, 1),
+        uint2(0, 1), uint2(1, 0), uint2(1, 1)
+    };
+    uint quad = id / 6;
+    uint2 cell = uint2(quad % constants.patch_size, quad / constants.patch_size)
+        + corners[id % 6];
+    float2 uv = float2(cell) / constants.patch_size;
+
+    float2 xz = object.offset_scale_height.xy
+        + uv * object.offset_scale_height.z;
+    float y = object.offset_scale_height.w
+        * sin(xz.x * 7.) * cos(xz.y * 5.);
+
+    VertexOut result;
+    result.pos = float4(xz.x, xz.y * 0.5 + y * 0.1, 0.5, 1.);
+    result.color = object.color * (0.75 + 0.25 * y);
+    return result;
+}
+
+[RootSignature(RS)]
+float4 PS(VertexOut input): SV_Target
+{
+    return input.color;
+}
+"#;
+
+const RENDER_TARGET_WIDTH: u32 = 1280;
+const RENDER_TARGET_HEIGHT: u32 = 720;
+const RENDER_TARGET_FORMAT: Format = Format::R8G8B8A8Unorm;
+const FRAMES_IN_FLIGHT: usize = 2;
+const OBJECTS_PER_CHUNK: u32 = 256;
+const PATCH_SIZE: u32 = 8;
+const WARMUP_FRAMES: u32 = 10;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ObjectData {
+    offset_scale_height: [f32; 4],
+    color: [f32; 4],
+}
+
+struct Settings {
+    use_warp: bool,
+    object_count: u32,
+    worker_count: u32,
+    frame_count: u32,
+    baseline_path: Option<String>,
+    save_baseline_path: Option<String>,
+    max_regression_percent: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BenchmarkResults {
+    cpu_record_ms: f64,
+    gpu_frame_ms: f64,
+}
+
+impl BenchmarkResults {
+    fn to_baseline_string(self) -> String {
+        format!(
+            "cpu_record_ms {}\ngpu_frame_ms {}\n",
+            self.cpu_record_ms, self.gpu_frame_ms
+        )
+    }
+
+    fn from_baseline_string(text: &str) -> Option<Self> {
+        let mut cpu_record_ms = None;
+        let mut gpu_frame_ms = None;
+        for line in text.lines() {
+            let mut tokens = line.split_whitespace();
+            let key = tokens.next();
+            let value = tokens.next().and_then(|value| value.parse().ok());
+            match key {
+                Some("cpu_record_ms") => cpu_record_ms = value,
+                Some("gpu_frame_ms") => gpu_frame_ms = value,
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            cpu_record_ms: cpu_record_ms?,
+            gpu_frame_ms: gpu_frame_ms?,
+        })
+    }
+}
+
+struct Scene {
+    // kept alive since the chunk buffers are placed in its heaps
+    _allocator: Allocator,
+    // kept alive while the descriptors refer to them
+    _chunks: Vec<Allocation>,
+    descriptors: DescriptorAllocator<CbvSrvUavKind>,
+    chunk_descriptors: Vec<TypedDescriptorHandle<CbvSrvUavKind>>,
+    object_count: u32,
+}
+
+impl Scene {
+    fn new(device: &Device, object_count: u32) -> DxResult<Self> {
+        let allocator = Allocator::new(device, ByteCount(16 * 1024 * 1024));
+        let chunk_count = object_count.div_ceil(OBJECTS_PER_CHUNK);
+        let mut descriptors = DescriptorAllocator::<CbvSrvUavKind>::new(
+            device,
+            chunk_count,
+            DescriptorHeapFlags::ShaderVisible,
+        )?;
+
+        let grid_size = (object_count as f32).sqrt().ceil() as u32;
+        let tile_size = 2. / grid_size as f32;
+
+        let mut chunks = vec![];
+        let mut chunk_descriptors = vec![];
+        for chunk_index in 0..chunk_count {
+            let chunk = allocator.create_resource(
+                HeapType::Upload,
+                &ResourceDesc::default()
+                    .with_dimension(ResourceDimension::Buffer)
+                    .with_width((size_of!(ObjectData) * OBJECTS_PER_CHUNK).0)
+                    .with_layout(TextureLayout::RowMajor),
+                ResourceStates::GenericRead,
+                None,
+            )?;
+
+            let objects = chunk.resource().map(0, None)? as *mut ObjectData;
+            for local_index in 0..OBJECTS_PER_CHUNK {
+                let index = chunk_index * OBJECTS_PER_CHUNK + local_index;
+                let (x, z) = (index % grid_size, index / grid_size);
+                let shade = (x + z) % 4;
+                unsafe {
+                    *objects.add(local_index as usize) = ObjectData {
+                        offset_scale_height: [
+                            -1. + x as f32 * tile_size,
+                            -1. + z as f32 * tile_size,
+                            tile_size,
+                            1.,
+                        ],
+                        color: [
+                            0.2 + 0.1 * shade as f32,
+                            0.5,
+                            0.3 - 0.05 * shade as f32,
+                            1.,
+                        ],
+                    };
+                }
+            }
+            chunk.resource().unmap(0, None);
+
+            let descriptor = descriptors.allocate()?;
+            device.create_shader_resource_view(
+                chunk.resource(),
+                Some(
+                    &ShaderResourceViewDesc::default()
+                        .new_buffer(
+                            &BufferSrv::default()
+                                .with_first_element(0)
+                                .with_num_elements(OBJECTS_PER_CHUNK)
+                                .with_structure_byte_stride(size_of!(
+                                    ObjectData
+                                )),
+                        )
+                        .with_shader_4_component_mapping(
+                            ShaderComponentMapping::default(),
+                        ),
+                ),
+                descriptors.cpu_handle(&descriptor),
+            );
+
+            chunks.push(chunk);
+            chunk_descriptors.push(descriptor);
+        }
+
+        info!(
+            "Scene: {} objects in {} chunks, {} bytes reserved by allocator",
+            object_count,
+            chunk_count,
+            allocator.reserved_size().0
+        );
+
+        Ok(Self {
+            _allocator: allocator,
+            _chunks: chunks,
+            descriptors,
+            chunk_descriptors,
+            object_count,
+        })
+    }
+}
+
+// Command lists and allocators are not Send, since their clones could be
+// recorded on two threads at once. This wrapper moves a worker's objects to
+// its thread and a closed list back to the main thread, which only executes
+// it and drops its reference before the worker is asked to reset the list
+struct Owned<T>(T);
+
+unsafe impl<T> Send for Owned<T> {}
+
+struct WorkerCommands {
+    // one per frame in flight
+    command_allocators: Vec<CommandAllocator>,
+    command_list: CommandList,
+}
+
+// The state every worker binds before its draws, cloned for each worker
+// so that no wrapper is shared between threads
+#[derive(Clone)]
+struct DrawSetup {
+    root_signature: RootSignature,
+    pipeline_state: PipelineState,
+    descriptor_heap: DescriptorHeap,
+    rtv: CpuDescriptorHandle,
+    chunk_descriptors: Vec<u32>,
+}
+
+struct RecordJob {
+    slot: usize,
+    objects: Range<u32>,
+}
+
+type RecordResult = (usize, DxResult<Owned<CommandList>>);
+
+fn record_draws(
+    commands: &WorkerCommands,
+    setup: &DrawSetup,
+    job: &RecordJob,
+) -> DxResult<()> {
+    let allocator = &commands.command_allocators[job.slot];
+    let command_list = &commands.command_list;
+
+    allocator.reset()?;
+    command_list.reset(allocator, Some(&setup.pipeline_state))?;
+    command_list.set_render_targets(&[setup.rtv], false, None);
+    command_list
+        .set_descriptor_heaps(std::slice::from_ref(&setup.descriptor_heap));
+    command_list.set_graphics_root_signature(&setup.root_signature);
+    command_list.set_viewports(&[Viewport::default()
+        .with_width(RENDER_TARGET_WIDTH as f32)
+        .with_height(RENDER_TARGET_HEIGHT as f32)]);
+    command_list.set_scissor_rects(&[Rect::default()
+        .with_right(RENDER_TARGET_WIDTH as i32)
+        .with_bottom(RENDER_TARGET_HEIGHT as i32)]);
+    command_list.set_primitive_topology(PrimitiveTopology::TriangleList);
+    command_list.set_graphics_root_32bit_constant(0, PATCH_SIZE, 2);
+
+    for object_index in job.objects.clone() {
+        let descriptor = setup.chunk_descriptors
+            [(object_index / OBJECTS_PER_CHUNK) as usize];
+        command_list.set_graphics_root_32bit_constants(
+            0,
+            &[descriptor, object_index % OBJECTS_PER_CHUNK],
+            0,
+        );
+        command_list.draw_instanced(PATCH_SIZE * PATCH_SIZE * 6, 1, 0, 0);
+    }
+
+    command_list.close()
+}
+
+fn run_worker(
+    worker_index: usize,
+    commands: Owned<WorkerCommands>,
+    setup: DrawSetup,
+    jobs: Receiver<RecordJob>,
+    results: Sender<RecordResult>,
+) {
+    let commands = commands.0;
+    for job in jobs {
+        let result = record_draws(&commands, &setup, &job)
+            .map(|()| Owned(commands.command_list.clone()));
+        if results.send((worker_index, result)).is_err() {
+            break;
+        }
+    }
+}
+
+// Splits the draws of a frame between persistent worker threads, each of
+// which records them into its own command list
+struct ParallelRecorder {
+    job_senders: Vec<Sender<RecordJob>>,
+    result_receiver: Receiver<RecordResult>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ParallelRecorder {
+    fn new(
+        device: &Device,
+        setup: &DrawSetup,
+        worker_count: u32,
+    ) -> DxResult<Self> {
+        let (result_sender, result_receiver) = mpsc::channel();
+        let mut job_senders = vec![];
+        let mut workers = vec![];
+        for worker_index in 0..worker_count.max(1) as usize {
+            let mut command_allocators = vec![];
+            for _ in 0..FRAMES_IN_FLIGHT {
+                command_allocators.push(
+                    device.create_command_allocator(CommandListType::Direct)?,
+                );
+            }
+            let command_list = device.create_command_list(
+                CommandListType::Direct,
+                &command_allocators[0],
+                None,
+            )?;
+            command_list.close()?;
+
+            let commands = Owned(WorkerCommands {
+                command_allocators,
+                command_list,
+            });
+            let setup = setup.clone();
+            let (job_sender, job_receiver) = mpsc::channel();
+            let result_sender = result_sender.clone();
+            workers.push(
+                std::thread::Builder::new()
+                    .name(format!("recorder {}", worker_index))
+                    .spawn(move || {
+                        run_worker(
+                            worker_index,
+                            commands,
+                            setup,
+                            job_receiver,
+                            result_sender,
+                        )
+                    })
+                    .expect("Cannot spawn recording thread"),
+            );
+            job_senders.push(job_sender);
+        }
+
+        Ok(Self {
+            job_senders,
+            result_receiver,
+            workers,
+        })
+    }
+
+    /// Splits the objects evenly between the workers and returns their
+    /// closed command lists in the worker order
+    fn record(
+        &self,
+        slot: usize,
+        object_count: u32,
+    ) -> DxResult<Vec<CommandList>> {
+        let worker_count = self.job_senders.len() as u32;
+        let objects_per_worker = object_count.div_ceil(worker_count);
+        for (worker_index, job_sender) in self.job_senders.iter().enumerate() {
+            let start =
+                (worker_index as u32 * objects_per_worker).min(object_count);
+            let end = (start + objects_per_worker).min(object_count);
+            job_sender
+                .send(RecordJob {
+                    slot,
+                    objects: start..end,
+                })
+                .expect("Recording thread has exited");
+        }
+
+        // every worker has to report back before the next frame, even if
+        // one of them has failed
+        let mut command_lists: Vec<Option<CommandList>> =
+            (0..worker_count).map(|_| None).collect();
+        let mut first_error = None;
+        for _ in 0..worker_count {
+            let (worker_index, result) = self
+                .result_receiver
+                .recv()
+                .expect("Recording thread has exited");
+            match result {
+                Ok(Owned(command_list)) => {
+                    command_lists[worker_index] = Some(command_list)
+                }
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(command_lists.into_iter().flatten().collect()),
+        }
+    }
+}
+
+impl Drop for ParallelRecorder {
+    fn drop(&mut self) {
+        // closing the job channels stops the workers
+        self.job_senders.clear();
+        for worker in self.workers.drain(..) {
+            if worker.join().is_err() {
+                error!("Recording thread has panicked");
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PassId(usize);
+
+struct PassDesc {
+    name: &'static str,
+    accesses: Vec<(Resource, ResourceStates)>,
+}
+
+// Minimal render graph: resources are added with their initial states and
+// every pass declares the states it needs them in, so that the transitions
+// between the passes are derived by StateTracker instead of being spelled
+// out. The passes have to be begun in the order in which they were added
+#[derive(Default)]
+struct RenderGraph {
+    passes: Vec<PassDesc>,
+    state_tracker: StateTracker,
+    next_pass: usize,
+}
+
+impl RenderGraph {
+    fn add_resource(
+        &mut self,
+        resource: &Resource,
+        initial_state: ResourceStates,
+    ) {
+        self.state_tracker.register(resource, initial_state);
+    }
+
+    fn add_pass(
+        &mut self,
+        name: &'static str,
+        accesses: &[(&Resource, ResourceStates)],
+    ) -> PassId {
+        self.passes.push(PassDesc {
+            name,
+            accesses: accesses
+                .iter()
+                .map(|(resource, state)| ((*resource).clone(), *state))
+                .collect(),
+        });
+        PassId(self.passes.len() - 1)
+    }
+
+    /// Records the transitions the pass needs into the command list,
+    /// which has to be executed before the commands of the pass
+    fn begin_pass(
+        &mut self,
+        pass: PassId,
+        command_list: &CommandList,
+    ) -> DxResult<()> {
+        let pass_desc = &self.passes[pass.0];
+        assert_eq!(
+            pass.0, self.next_pass,
+            "Render graph pass '{}' has been begun out of order",
+            pass_desc.name
+        );
+
+        for (resource, state) in &pass_desc.accesses {
+            self.state_tracker.transition(resource, *state)?;
+        }
+        self.state_tracker.flush(command_list);
+        self.next_pass = (self.next_pass + 1) % self.passes.len();
+
+        Ok(())
+    }
+}
+
+struct Benchmark {
+    _device: Device,
+    command_queue: CommandQueue,
+    // the prologue and epilogue lists recorded on the main thread
+    // share the frame's allocator
+    command_allocators: Vec<CommandAllocator>,
+    prologue_list: CommandList,
+    epilogue_list: CommandList,
+    recorder: ParallelRecorder,
+    render_graph: RenderGraph,
+    terrain_pass: PassId,
+    copy_pass: PassId,
+    render_target: Resource,
+    // only written to, the benchmark doesn't read the image back
+    output: Resource,
+    rtv_heap: DescriptorHeap,
+    query_heap: QueryHeap,
+    timestamps: ReadbackRing,
+    timestamp_frequency: u64,
+    timeline: Timeline,
+    fence_values: [u64; FRAMES_IN_FLIGHT],
+    scene: Scene,
+}
+
+impl Benchmark {
+    fn new(settings: &Settings) -> DxResult<Self> {
+        let factory = Factory::new(CreateFactoryFlags::None)?;
+        let device = match settings.use_warp {
+            true => Device::new(&factory.enum_warp_adapter()?)?,
+            false => {
+                let (device, adapter_kind) = Device::new_with_fallback(
+                    &factory,
+                    GpuPreference::HighPerformance,
+                    FeatureLevel::L12_0,
+                )?;
+                info!("Running on {:?} adapter", adapter_kind);
+                device
+            }
+        };
+
+        let command_queue =
+            device.create_command_queue(&CommandQueueDesc::default())?;
+        let timestamp_frequency = command_queue.get_timestamp_frequency()?;
+
+        let mut command_allocators = vec![];
+        for _ in 0..FRAMES_IN_FLIGHT {
+            command_allocators.push(
+                device.create_command_allocator(CommandListType::Direct)?,
+            );
+        }
+        let create_closed_list = || -> DxResult<CommandList> {
+            let command_list = device.create_command_list(
+                CommandListType::Direct,
+                &command_allocators[0],
+                None,
+            )?;
+            command_list.close()?;
+            Ok(command_list)
+        };
+        let prologue_list = create_closed_list()?;
+        let epilogue_list = create_closed_list()?;
+
+        let compile = |entry_point, target| {
+            hassle_rs::utils::compile_hlsl(
+                "scene_benchmark.hlsl",
+                SHADER,
+                entry_point,
+                target,
+                &["-O3"],
+                &[],
+            )
+            .expect("Cannot compile shader")
+        };
+        let vs = compile("VS", "vs_6_6");
+        let ps = compile("PS", "ps_6_6");
+
+        let root_signature =
+            device.create_root_signature(0, &ShaderBytecode::new(&vs))?;
+        let pipeline_state = device.create_graphics_pipeline_state(
+            &GraphicsPipelineStateDesc::default()
+                .with_root_signature(&root_signature)
+                .with_vs_bytecode(&ShaderBytecode::new(&vs))
+                .with_ps_bytecode(&ShaderBytecode::new(&ps))
+                .with_depth_stencil_state(
+                    DepthStencilDesc::default().with_depth_enable(false),
+                )
+                .with_primitive_topology_type(PrimitiveTopologyType::Triangle)
+                .with_rtv_formats(&[RENDER_TARGET_FORMAT]),
+        )?;
+
+        let texture_desc = ResourceDesc::default()
+            .with_dimension(ResourceDimension::Texture2D)
+            .with_width(RENDER_TARGET_WIDTH.into())
+            .with_height(RENDER_TARGET_HEIGHT)
+            .with_format(RENDER_TARGET_FORMAT);
+        let render_target = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Default),
+            HeapFlags::None,
+            &texture_desc.with_flags(ResourceFlags::AllowRenderTarget),
+            ResourceStates::RenderTarget,
+            None,
+        )?;
+        let output = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Default),
+            HeapFlags::None,
+            &texture_desc,
+            ResourceStates::CopyDest,
+            None,
+        )?;
+        let rtv_heap = device.create_descriptor_heap(
+            &DescriptorHeapDesc::default()
+                .with_heap_type(DescriptorHeapType::Rtv)
+                .with_num_descriptors(1),
+        )?;
+        device.create_render_target_view(
+            &render_target,
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        // two timestamps per frame, resolved into the frame's ring region
+        let query_heap = device.create_query_heap(
+            &QueryHeapDesc::default()
+                .with_heap_type(QueryHeapType::Timestamp)
+                .with_count(2 * FRAMES_IN_FLIGHT as u32),
+        )?;
+        let timestamps = ReadbackRing::new(
+            &device,
+            size_of!(u64) * 2,
+            FRAMES_IN_FLIGHT as u32,
+        )?;
+
+        let mut render_graph = RenderGraph::default();
+        render_graph.add_resource(&render_target, ResourceStates::RenderTarget);
+        render_graph.add_resource(&output, ResourceStates::CopyDest);
+        let terrain_pass = render_graph.add_pass(
+            "terrain",
+            &[(&render_target, ResourceStates::RenderTarget)],
+        );
+        let copy_pass = render_graph.add_pass(
+            "copy",
+            &[
+                (&render_target, ResourceStates::CopySource),
+                (&output, ResourceStates::CopyDest),
+            ],
+        );
+
+        let timeline = Timeline::new(&device)?;
+        let scene = Scene::new(&device, settings.object_count)?;
+
+        let recorder = ParallelRecorder::new(
+            &device,
+            &DrawSetup {
+                root_signature,
+                pipeline_state,
+                descriptor_heap: scene.descriptors.heap().clone(),
+                rtv: rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+                chunk_descriptors: scene
+                    .chunk_descriptors
+                    .iter()
+                    .map(|descriptor| descriptor.index())
+                    .collect(),
+            },
+            settings.worker_count,
+        )?;
+        info!("Recording on {} threads", recorder.job_senders.len());
+
+        Ok(Self {
+            _device: device,
+            command_queue,
+            command_allocators,
+            prologue_list,
+            epilogue_list,
+            recorder,
+            render_graph,
+            terrain_pass,
+            copy_pass,
+            render_target,
+            output,
+            rtv_heap,
+            query_heap,
+            timestamps,
+            timestamp_frequency,
+            timeline,
+            fence_values: [0; FRAMES_IN_FLIGHT],
+            scene,
+        })
+    }
+
+    /// Returns the frame's command lists in the execution order and
+    /// the time spent recording them
+    fn record_frame(
+        &mut self,
+        frame_index: u64,
+    ) -> DxResult<(Vec<CommandList>, f64)> {
+        let slot = frame_index as usize % FRAMES_IN_FLIGHT;
+        let allocator = &self.command_allocators[slot];
+
+        let start = Instant::now();
+
+        allocator.reset()?;
+        let prologue_list = &self.prologue_list;
+        prologue_list.reset(allocator, None)?;
+        prologue_list.end_query(
+            &self.query_heap,
+            QueryType::Timestamp,
+            2 * slot as u32,
+        );
+        self.render_graph
+            .begin_pass(self.terrain_pass, prologue_list)?;
+        let rtv = self.rtv_heap.get_cpu_descriptor_handle_for_heap_start();
+        prologue_list.clear_render_target_view(rtv, [0.1, 0.1, 0.2, 1.], None);
+        prologue_list.close()?;
+
+        let draw_lists = self.recorder.record(slot, self.scene.object_count)?;
+
+        let epilogue_list = &self.epilogue_list;
+        epilogue_list.reset(allocator, None)?;
+        self.render_graph
+            .begin_pass(self.copy_pass, epilogue_list)?;
+        epilogue_list.copy_resource(&self.output, &self.render_target);
+        epilogue_list.end_query(
+            &self.query_heap,
+            QueryType::Timestamp,
+            2 * slot as u32 + 1,
+        );
+        self.timestamps.resolve_query_data(
+            epilogue_list,
+            frame_index,
+            ByteCount(0),
+            &self.query_heap,
+            QueryType::Timestamp,
+            2 * slot as u32,
+            2,
+        );
+        epilogue_list.close()?;
+
+        let record_time = start.elapsed().as_secs_f64() * 1000.;
+
+        let mut command_lists = Vec::with_capacity(draw_lists.len() + 2);
+        command_lists.push(prologue_list.clone());
+        command_lists.extend(draw_lists);
+        command_lists.push(epilogue_list.clone());
+
+        Ok((command_lists, record_time))
+    }
+
+    /// Returns the GPU time of the frame, which must have been completed
+    fn gpu_frame_time(&self, frame_index: u64) -> f64 {
+        let timestamps =
+            unsafe { self.timestamps.consume_as::<u64>(frame_index) };
+        let ticks = timestamps[1].saturating_sub(timestamps[0]);
+        ticks as f64 * 1000. / self.timestamp_frequency as f64
+    }
+
+    fn run(&mut self, frame_count: u32) -> DxResult<BenchmarkResults> {
+        let mut cpu_times = vec![];
+        let mut gpu_times = vec![];
+
+        let total_frames = (WARMUP_FRAMES + frame_count) as u64;
+        for frame_index in 0..total_frames + FRAMES_IN_FLIGHT as u64 {
+            let slot = frame_index as usize % FRAMES_IN_FLIGHT;
+            self.timeline.wait_on_cpu(self.fence_values[slot], None)?;
+
+            // the slot's previous frame is complete now, so collect its data
+            if frame_index >= FRAMES_IN_FLIGHT as u64 {
+                let completed_frame = frame_index - FRAMES_IN_FLIGHT as u64;
+                if completed_frame >= WARMUP_FRAMES as u64 {
+                    gpu_times.push(self.gpu_frame_time(completed_frame));
+                }
+            }
+            if frame_index >= total_frames {
+                continue;
+            }
+
+            let (command_lists, cpu_time) = self.record_frame(frame_index)?;
+            if frame_index >= WARMUP_FRAMES as u64 {
+                cpu_times.push(cpu_time);
+            }
+
+            self.command_queue.execute_command_lists(&command_lists);
+            self.fence_values[slot] =
+                self.timeline.signal_from(&self.command_queue)?;
+        }
+
+        Ok(BenchmarkResults {
+            cpu_record_ms: median(&mut cpu_times),
+            gpu_frame_ms: median(&mut gpu_times),
+        })
+    }
+}
+
+impl Drop for Benchmark {
+    fn drop(&mut self) {
+        if let Err(err) = self.timeline.flush(&self.command_queue) {
+            error!("Cannot wait for GPU on shutdown: {}", err);
+        }
+    }
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    if values.is_empty() {
+        return 0.;
+    }
+
+    values.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+    values[values.len() / 2]
+}
+
+fn parse_settings() -> Settings {
+    let command_args = clap::App::new("scene_benchmark")
+        .arg(
+            clap::Arg::with_name("warp")
+                .long("warp")
+                .help("Run on WARP instead of a hardware adapter"),
+        )
+        .arg(
+            clap::Arg::with_name("objects")
+                .long("objects")
+                .takes_value(true)
+                .value_name("NUMBER")
+                .default_value("16384")
+                .help("Number of terrain patches drawn per frame"),
+        )
+        .arg(
+            clap::Arg::with_name("threads")
+                .long("threads")
+                .takes_value(true)
+                .value_name("NUMBER")
+                .default_value("4")
+                .help("Number of threads recording the draws"),
+        )
+        .arg(
+            clap::Arg::with_name("frames")
+                .long("frames")
+                .takes_value(true)
+                .value_name("NUMBER")
+                .default_value("200")
+                .help("Number of measured frames"),
+        )
+        .arg(
+            clap::Arg::with_name("baseline")
+                .long("baseline")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Compare the results against a saved baseline"),
+        )
+        .arg(
+            clap::Arg::with_name("save_baseline")
+                .long("save-baseline")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Save the results as a baseline"),
+        )
+        .arg(
+            clap::Arg::with_name("max_regression")
+                .long("max-regression")
+                .takes_value(true)
+                .value_name("PERCENT")
+                .default_value("10")
+                .help("Maximum allowed slowdown relative to the baseline"),
+        )
+        .get_matches();
+
+    fn parse_value<T: std::str::FromStr>(
+        command_args: &clap::ArgMatches,
+        name: &str,
+    ) -> T {
+        command_args
+            .value_of(name)
+            .unwrap()
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid value of {}", name))
+    }
+
+    Settings {
+        use_warp: command_args.is_present("warp"),
+        object_count: parse_value(&command_args, "objects"),
+        worker_count: parse_value(&command_args, "threads"),
+        frame_count: parse_value(&command_args, "frames"),
+        baseline_path: command_args.value_of("baseline").map(String::from),
+        save_baseline_path: command_args
+            .value_of("save_baseline")
+            .map(String::from),
+        max_regression_percent: parse_value(&command_args, "max_regression"),
+    }
+}
+
+fn main() {
+    simple_logger::init_with_level(log::Level::Info).unwrap();
+
+    let settings = parse_settings();
+    let results = Benchmark::new(&settings)
+        .and_then(|mut benchmark| benchmark.run(settings.frame_count))
+        .expect("Cannot run benchmark");
+
+    println!(
+        "CPU record time: {:.3} ms, GPU frame time: {:.3} ms",
+        results.cpu_record_ms, results.gpu_frame_ms
+    );
+
+    if let Some(path) = &settings.save_baseline_path {
+        std::fs::write(path, results.to_baseline_string())
+            .expect("Cannot save baseline");
+    }
+
+    if let Some(path) = &settings.baseline_path {
+        let baseline = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| BenchmarkResults::from_baseline_string(&text))
+            .expect("Cannot read baseline");
+
+        let limit = 1. + settings.max_regression_percent / 100.;
+        let mut regressed = false;
+        for (name, current, base) in [
+            (
+                "CPU record time",
+                results.cpu_record_ms,
+                baseline.cpu_record_ms,
+            ),
+            (
+                "GPU frame time",
+                results.gpu_frame_ms,
+                baseline.gpu_frame_ms,
+            ),
+        ] {
+            if current > base * limit {
+                error!(
+                    "{} regressed: {:.3} ms vs {:.3} ms in the baseline",
+                    name, current, base
+                );
+                regressed = true;
+            }
+        }
+
+        if regressed {
+            std::process::exit(1);
+        }
+        info!("No regressions relative to the baseline");
+    }
+}
@@ -52,4 +52,9 @@ void pix_end_event_cmd_queue(ID3D12CommandQueue *command_queue)
 {
     if (gs_AnalysisInterface && gs_InitResult == S_OK)
         PIXEndEvent(command_queue);
+}
+
+void pix_report_counter(const wchar_t *name, float value)
+{
+    PIXReportCounter(name, value);
 }
\ No newline at end of file
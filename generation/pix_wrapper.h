@@ -14,6 +14,7 @@ extern "C"
     void pix_end_event_cmd_list(ID3D12GraphicsCommandList6 *command_list);
     void pix_begin_event_cmd_queue(ID3D12CommandQueue *command_queue, UINT64 color, const char *marker);
     void pix_end_event_cmd_queue(ID3D12CommandQueue *command_queue);
+    void pix_report_counter(const wchar_t *name, float value);
 
 #ifdef __cplusplus
 }
@@ -0,0 +1,167 @@
+use std::slice;
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    ByteCount, ClearValue, CommandList, CopyCapable, Device, DxError, DxResult,
+    Heap, HeapDesc, HeapFlags, HeapProperties, HeapType, Resource,
+    ResourceAliasingBarrier, ResourceBarrier, ResourceDesc, ResourceDimension,
+    ResourceFlags, ResourceStates,
+};
+
+/// Placed resources sharing the same memory, e.g. transient render targets
+/// of passes that never need their contents at the same time. All the
+/// members are placed at the beginning of a heap sized for the largest
+/// of them, and only one member is active at a time: [AliasGroup::activate]
+/// records the aliasing barrier, and the other members must not be used
+/// until they are activated again. The active member is tracked in
+/// recording order, so command lists have to be executed in the order
+/// they were recorded in
+#[derive(Debug)]
+pub struct AliasGroup {
+    heap: Heap,
+    members: Vec<Resource>,
+    active: Option<usize>,
+}
+
+impl AliasGroup {
+    /// Members are described by (desc, initial state, optimized clear
+    /// value) tuples. Heaps that mix buffers, render target textures and
+    /// other textures require resource heap tier 2
+    pub fn new(
+        device: &Device,
+        members: &[(ResourceDesc, ResourceStates, Option<ClearValue>)],
+    ) -> DxResult<Self> {
+        if members.is_empty() {
+            error!("Alias group must have at least one member");
+            return Err(DxError::new(
+                "AliasGroup::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let mut size = 0;
+        let mut alignment = 0;
+        let mut heap_flags = None;
+        for (desc, _, _) in members {
+            let allocation_info =
+                device.get_resource_allocation_info(0, slice::from_ref(desc));
+            size = size.max(allocation_info.size_in_bytes().0);
+            alignment = alignment.max(allocation_info.alignment().0);
+
+            let member_heap_flags = Self::heap_flags_for(desc);
+            heap_flags = match heap_flags {
+                None => Some(member_heap_flags),
+                Some(flags) if flags == member_heap_flags => Some(flags),
+                Some(_) => Some(HeapFlags::AllowAllBuffersAndTextures),
+            };
+        }
+
+        let heap = device.create_heap(
+            HeapDesc::default()
+                .with_size_in_bytes(ByteCount(size))
+                .with_properties(
+                    HeapProperties::default().with_heap_type(HeapType::Default),
+                )
+                .with_alignment(ByteCount(alignment))
+                .with_flags(
+                    heap_flags.expect("Alias group heap flags are not set"),
+                ),
+        )?;
+        if let Some(name) = device.make_object_name("Alias group heap") {
+            heap.set_name(&name)?;
+        }
+
+        let members = members
+            .iter()
+            .map(|(desc, initial_state, clear_value)| {
+                device.create_placed_resource(
+                    &heap,
+                    ByteCount(0),
+                    desc,
+                    *initial_state,
+                    clear_value.as_ref(),
+                )
+            })
+            .collect::<DxResult<Vec<_>>>()?;
+
+        Ok(Self {
+            heap,
+            members,
+            active: None,
+        })
+    }
+
+    fn heap_flags_for(desc: &ResourceDesc) -> HeapFlags {
+        match desc.dimension() {
+            ResourceDimension::Buffer => HeapFlags::AllowOnlyBuffers,
+            _ if desc.flags().intersects(
+                ResourceFlags::AllowRenderTarget
+                    | ResourceFlags::AllowDepthStencil,
+            ) =>
+            {
+                HeapFlags::AllowOnlyRtDsTextures
+            }
+            _ => HeapFlags::AllowOnlyNonRtDsTextures,
+        }
+    }
+
+    /// Records the aliasing barrier that makes `index` the active member
+    /// and returns it. Its contents are undefined after the switch, so
+    /// the first operation on it has to be a clear, a discard or a copy
+    /// that overwrites it fully
+    pub fn activate<K: CopyCapable>(
+        &mut self,
+        command_list: &CommandList<K>,
+        index: usize,
+    ) -> &Resource {
+        assert!(index < self.members.len(), "Alias group index out of range");
+        if self.active == Some(index) {
+            return &self.members[index];
+        }
+
+        let mut barrier = ResourceAliasingBarrier::default()
+            .with_resource_after(&self.members[index]);
+        if let Some(active) = self.active {
+            barrier.set_resource_before(&self.members[active]);
+        }
+        command_list
+            .resource_barrier(&[ResourceBarrier::new_aliasing(&barrier)]);
+        self.active = Some(index);
+
+        &self.members[index]
+    }
+
+    /// Returns the member, which must be the active one
+    pub fn get(&self, index: usize) -> &Resource {
+        debug_assert!(
+            self.active == Some(index),
+            "Accessing alias group member {} while {:?} is active",
+            index,
+            self.active
+        );
+
+        &self.members[index]
+    }
+
+    pub fn active_index(&self) -> Option<usize> {
+        self.active
+    }
+
+    pub fn active(&self) -> Option<&Resource> {
+        self.active.map(|index| &self.members[index])
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn heap(&self) -> &Heap {
+        &self.heap
+    }
+}
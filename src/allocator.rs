@@ -0,0 +1,404 @@
+use std::slice;
+use std::sync::{Arc, Mutex};
+
+use log::trace;
+
+use crate::{
+    ByteCount, ClearValue, Device, DxResult, Heap, HeapDesc, HeapFlags,
+    HeapProperties, HeapType, Metric, Metrics, Resource, ResourceDesc,
+    ResourceDimension, ResourceFlags, ResourceStates,
+    DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT,
+};
+
+// A single ID3D12Heap that placed resources are suballocated from
+#[derive(Debug)]
+struct MemoryBlock {
+    heap: Heap,
+    size: u64,
+    alignment: u64,
+    // blocks created for allocations larger than the regular block size
+    // are released as soon as they become empty
+    dedicated: bool,
+    // (offset, size) pairs sorted by offset, adjacent ranges are always merged
+    free_ranges: Vec<(u64, u64)>,
+}
+
+impl MemoryBlock {
+    // Returns (range index, aligned offset, leftover size) of the free range
+    // that leaves the least space unused after placing the allocation
+    fn find_best_fit(
+        &self,
+        size: u64,
+        alignment: u64,
+    ) -> Option<(usize, u64, u64)> {
+        if alignment > self.alignment {
+            return None;
+        }
+
+        self.free_ranges
+            .iter()
+            .enumerate()
+            .filter_map(|(range_index, &(offset, range_size))| {
+                let aligned_offset =
+                    ByteCount(offset).align_up(ByteCount(alignment)).0;
+                let required_size = aligned_offset - offset + size;
+                match required_size <= range_size {
+                    true => Some((
+                        range_index,
+                        aligned_offset,
+                        range_size - required_size,
+                    )),
+                    false => None,
+                }
+            })
+            .min_by_key(|&(_, _, leftover)| leftover)
+    }
+
+    fn allocate(&mut self, range_index: usize, aligned_offset: u64, size: u64) {
+        let (offset, range_size) = self.free_ranges[range_index];
+        let range_end = offset + range_size;
+        let allocation_end = aligned_offset + size;
+
+        let mut remainders = vec![];
+        if aligned_offset > offset {
+            remainders.push((offset, aligned_offset - offset));
+        }
+        if allocation_end < range_end {
+            remainders.push((allocation_end, range_end - allocation_end));
+        }
+        self.free_ranges
+            .splice(range_index..range_index + 1, remainders);
+    }
+
+    fn free(&mut self, offset: u64, size: u64) {
+        let range_index = self
+            .free_ranges
+            .partition_point(|&(range_offset, _)| range_offset < offset);
+        self.free_ranges.insert(range_index, (offset, size));
+
+        if range_index + 1 < self.free_ranges.len()
+            && offset + size == self.free_ranges[range_index + 1].0
+        {
+            self.free_ranges[range_index].1 +=
+                self.free_ranges[range_index + 1].1;
+            self.free_ranges.remove(range_index + 1);
+        }
+
+        if range_index > 0 {
+            let (previous_offset, previous_size) =
+                self.free_ranges[range_index - 1];
+            if previous_offset + previous_size == offset {
+                self.free_ranges[range_index - 1].1 +=
+                    self.free_ranges[range_index].1;
+                self.free_ranges.remove(range_index);
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.free_ranges.len() == 1 && self.free_ranges[0] == (0, self.size)
+    }
+}
+
+#[derive(Debug)]
+struct MemoryPool {
+    heap_type: HeapType,
+    heap_flags: HeapFlags,
+    // block indices must stay stable since allocations refer to them,
+    // so released blocks leave empty slots that are reused later
+    blocks: Vec<Option<MemoryBlock>>,
+}
+
+#[derive(Debug)]
+struct AllocatorState {
+    device: Device,
+    block_size: u64,
+    pools: Vec<MemoryPool>,
+}
+
+impl AllocatorState {
+    fn find_or_add_pool(
+        &mut self,
+        heap_type: HeapType,
+        heap_flags: HeapFlags,
+    ) -> usize {
+        let existing_pool = self.pools.iter().position(|pool| {
+            pool.heap_type as i32 == heap_type as i32
+                && pool.heap_flags == heap_flags
+        });
+
+        match existing_pool {
+            Some(pool_index) => pool_index,
+            None => {
+                self.pools.push(MemoryPool {
+                    heap_type,
+                    heap_flags,
+                    blocks: vec![],
+                });
+                self.pools.len() - 1
+            }
+        }
+    }
+
+    // Returns (block index, offset)
+    fn allocate(
+        &mut self,
+        pool_index: usize,
+        size: u64,
+        alignment: u64,
+    ) -> DxResult<(usize, u64)> {
+        let pool = &mut self.pools[pool_index];
+
+        let best_fit = pool
+            .blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(block_index, block)| {
+                block.as_ref()?.find_best_fit(size, alignment).map(
+                    |(range_index, offset, leftover)| {
+                        (block_index, range_index, offset, leftover)
+                    },
+                )
+            })
+            .min_by_key(|&(_, _, _, leftover)| leftover);
+
+        if let Some((block_index, range_index, offset, _)) = best_fit {
+            pool.blocks[block_index]
+                .as_mut()
+                .expect("Best fit block has been released")
+                .allocate(range_index, offset, size);
+            return Ok((block_index, offset));
+        }
+
+        let block_alignment =
+            alignment.max(DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT.0);
+        let dedicated = size > self.block_size;
+        let block_size = match dedicated {
+            true => ByteCount(size).align_up(ByteCount(block_alignment)).0,
+            false => self.block_size,
+        };
+
+        let heap = self.device.create_heap(
+            HeapDesc::default()
+                .with_size_in_bytes(ByteCount(block_size))
+                .with_properties(
+                    HeapProperties::default().with_heap_type(pool.heap_type),
+                )
+                .with_alignment(ByteCount(block_alignment))
+                .with_flags(pool.heap_flags),
+        )?;
+        if let Some(name) = self.device.make_object_name("Allocator block") {
+            heap.set_name(&name)?;
+        }
+
+        trace!(
+            "Allocated memory block of {} bytes for {:?} heap",
+            block_size,
+            pool.heap_type
+        );
+
+        let mut block = MemoryBlock {
+            heap,
+            size: block_size,
+            alignment: block_alignment,
+            dedicated,
+            free_ranges: vec![(0, block_size)],
+        };
+        block.allocate(0, 0, size);
+
+        let block_index =
+            match pool.blocks.iter().position(|block| block.is_none()) {
+                Some(free_slot) => {
+                    pool.blocks[free_slot] = Some(block);
+                    free_slot
+                }
+                None => {
+                    pool.blocks.push(Some(block));
+                    pool.blocks.len() - 1
+                }
+            };
+
+        Ok((block_index, 0))
+    }
+
+    fn free(
+        &mut self,
+        pool_index: usize,
+        block_index: usize,
+        offset: u64,
+        size: u64,
+    ) {
+        let block_slot = &mut self.pools[pool_index].blocks[block_index];
+        let block = block_slot
+            .as_mut()
+            .expect("Allocation refers to a released block");
+        block.free(offset, size);
+
+        if block.dedicated && block.is_empty() {
+            *block_slot = None;
+        }
+    }
+}
+
+/// Suballocates placed resources from large heaps, which are created on
+/// demand per heap type and resource category (buffers, render
+/// target/depth stencil textures and other textures are kept in
+/// separate heaps so that resource heap tier 1 is supported).
+/// Allocations larger than the block size get their own heaps
+#[derive(Debug, Clone)]
+pub struct Allocator {
+    state: Arc<Mutex<AllocatorState>>,
+}
+
+impl Allocator {
+    pub fn new(device: &Device, block_size: ByteCount) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(AllocatorState {
+                device: device.clone(),
+                block_size: block_size.0,
+                pools: vec![],
+            })),
+        }
+    }
+
+    pub fn create_resource(
+        &self,
+        heap_type: HeapType,
+        resource_desc: &ResourceDesc,
+        initial_state: ResourceStates,
+        optimized_clear_value: Option<&ClearValue>,
+    ) -> DxResult<Allocation> {
+        let heap_flags = match resource_desc.dimension() {
+            ResourceDimension::Buffer => HeapFlags::AllowOnlyBuffers,
+            _ if resource_desc.flags().intersects(
+                ResourceFlags::AllowRenderTarget
+                    | ResourceFlags::AllowDepthStencil,
+            ) =>
+            {
+                HeapFlags::AllowOnlyRtDsTextures
+            }
+            _ => HeapFlags::AllowOnlyNonRtDsTextures,
+        };
+
+        let mut state = self.state.lock().expect("Allocator mutex is poisoned");
+
+        let allocation_info = state
+            .device
+            .get_resource_allocation_info(0, slice::from_ref(resource_desc));
+        let size = allocation_info.size_in_bytes().0;
+        let alignment = allocation_info.alignment().0;
+
+        let pool_index = state.find_or_add_pool(heap_type, heap_flags);
+        let (block_index, offset) =
+            state.allocate(pool_index, size, alignment)?;
+
+        let resource = state.device.create_placed_resource(
+            &state.pools[pool_index].blocks[block_index]
+                .as_ref()
+                .expect("Allocated block has been released")
+                .heap,
+            ByteCount(offset),
+            resource_desc,
+            initial_state,
+            optimized_clear_value,
+        );
+        let resource = match resource {
+            Ok(resource) => resource,
+            Err(err) => {
+                state.free(pool_index, block_index, offset, size);
+                return Err(err);
+            }
+        };
+
+        Ok(Allocation {
+            resource,
+            allocator: Arc::clone(&self.state),
+            pool_index,
+            block_index,
+            offset,
+            size,
+        })
+    }
+
+    /// Total size of the heaps currently owned by the allocator
+    pub fn reserved_size(&self) -> ByteCount {
+        let state = self.state.lock().expect("Allocator mutex is poisoned");
+        ByteCount(
+            state
+                .pools
+                .iter()
+                .flat_map(|pool| pool.blocks.iter().flatten())
+                .map(|block| block.size)
+                .sum(),
+        )
+    }
+
+    /// Total size of the live allocations (including alignment padding)
+    pub fn allocated_size(&self) -> ByteCount {
+        let state = self.state.lock().expect("Allocator mutex is poisoned");
+        ByteCount(
+            state
+                .pools
+                .iter()
+                .flat_map(|pool| pool.blocks.iter().flatten())
+                .map(|block| {
+                    block.size
+                        - block
+                            .free_ranges
+                            .iter()
+                            .map(|&(_, size)| size)
+                            .sum::<u64>()
+                })
+                .sum(),
+        )
+    }
+}
+
+impl Metrics for Allocator {
+    fn metrics(&self) -> Vec<Metric> {
+        vec![
+            Metric::new("reserved bytes", self.reserved_size().0 as f64),
+            Metric::new("allocated bytes", self.allocated_size().0 as f64),
+        ]
+    }
+}
+
+/// A placed resource created by [Allocator]. Its memory range is returned
+/// to the allocator on drop, so the caller is responsible for keeping it
+/// alive while the GPU still uses the resource
+#[derive(Debug)]
+pub struct Allocation {
+    resource: Resource,
+    allocator: Arc<Mutex<AllocatorState>>,
+    pool_index: usize,
+    block_index: usize,
+    offset: u64,
+    size: u64,
+}
+
+impl Allocation {
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    pub fn heap_offset(&self) -> ByteCount {
+        ByteCount(self.offset)
+    }
+
+    pub fn size(&self) -> ByteCount {
+        ByteCount(self.size)
+    }
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.allocator.lock() {
+            state.free(
+                self.pool_index,
+                self.block_index,
+                self.offset,
+                self.size,
+            );
+        }
+    }
+}
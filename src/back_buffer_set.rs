@@ -0,0 +1,176 @@
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    CpuDescriptorHandle, DescriptorHeap, DescriptorHeapDesc,
+    DescriptorHeapType, Device, DxError, DxResult, Format,
+    RenderTargetViewDesc, Resource, Swapchain, Tex2DRtv,
+};
+
+/// Back buffers of a swapchain along with their RTVs, which live in
+/// an RTV heap of the set's own. By default the views use the format
+/// of the buffers, but each index can be given a format of its own,
+/// typically the sRGB counterpart of the buffer format, since flip model
+/// swapchains cannot be created with sRGB formats.
+///
+/// Before [Swapchain::resize_buffers] the set has to be emptied with
+/// [BackBufferSet::release], and [BackBufferSet::recreate] picks up the new
+/// buffers afterwards keeping the format overrides
+#[derive(Debug)]
+pub struct BackBufferSet {
+    device: Device,
+    rtv_heap: DescriptorHeap,
+    buffers: Vec<Resource>,
+    // None means that the view uses the format of the buffer
+    rtv_formats: Vec<Option<Format>>,
+}
+
+impl BackBufferSet {
+    pub fn new(device: &Device, swapchain: &Swapchain) -> DxResult<Self> {
+        Self::with_rtv_formats(device, swapchain, &[])
+    }
+
+    /// `rtv_formats` are the per-index view formats. Indices beyond
+    /// the slice (or set to None) use the format of the buffers
+    pub fn with_rtv_formats(
+        device: &Device,
+        swapchain: &Swapchain,
+        rtv_formats: &[Option<Format>],
+    ) -> DxResult<Self> {
+        let buffer_count = swapchain.get_buffer_count()?;
+        let mut back_buffer_set = Self {
+            device: device.clone(),
+            rtv_heap: create_rtv_heap(device, buffer_count)?,
+            buffers: vec![],
+            rtv_formats: rtv_formats.to_vec(),
+        };
+        back_buffer_set.recreate(swapchain)?;
+
+        Ok(back_buffer_set)
+    }
+
+    /// Drops the references to the back buffers, which
+    /// [Swapchain::resize_buffers] requires. The RTVs must not be used
+    /// until [BackBufferSet::recreate] is called
+    pub fn release(&mut self) {
+        self.buffers.clear();
+    }
+
+    /// Gets the buffers from the swapchain and recreates their views,
+    /// e.g. after [Swapchain::resize_buffers]. If the buffer count has
+    /// changed, the overrides of the indices that no longer exist are
+    /// dropped
+    pub fn recreate(&mut self, swapchain: &Swapchain) -> DxResult<()> {
+        let buffers = swapchain.buffers()?;
+        if buffers.len() as u32 > self.rtv_heap.len() {
+            self.rtv_heap =
+                create_rtv_heap(&self.device, buffers.len() as u32)?;
+        }
+        self.rtv_formats.resize(buffers.len(), None);
+        self.buffers = buffers;
+
+        for index in 0..self.len() {
+            self.create_rtv(index);
+        }
+
+        Ok(())
+    }
+
+    /// Overrides the view format of the buffer at `index` (None restores
+    /// the buffer format) and recreates its RTV. The GPU must not be
+    /// using the view at this point
+    pub fn set_rtv_format(
+        &mut self,
+        index: u32,
+        format: Option<Format>,
+    ) -> DxResult<()> {
+        if index >= self.len() {
+            error!(
+                "Back buffer index {} is out of range, the set has {} buffers",
+                index,
+                self.len()
+            );
+            return Err(DxError::new(
+                "BackBufferSet::set_rtv_format",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        self.rtv_formats[index as usize] = format;
+        self.create_rtv(index);
+
+        Ok(())
+    }
+
+    /// The format the RTV at `index` has been created with
+    pub fn rtv_format(&self, index: u32) -> Format {
+        match self.rtv_formats[index as usize] {
+            Some(format) => format,
+            None => self.buffers[index as usize].get_desc().format(),
+        }
+    }
+
+    pub fn len(&self) -> u32 {
+        self.buffers.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    pub fn buffers(&self) -> &[Resource] {
+        &self.buffers
+    }
+
+    pub fn buffer(&self, index: u32) -> &Resource {
+        &self.buffers[index as usize]
+    }
+
+    pub fn rtv(&self, index: u32) -> CpuDescriptorHandle {
+        debug_assert!(index < self.len(), "Back buffer index is out of range");
+        self.rtv_heap
+            .get_cpu_descriptor_handle_for_heap_start()
+            .advance(index, self.rtv_heap.handle_size())
+    }
+
+    /// The buffer the swapchain is going to present next and its RTV
+    pub fn current(
+        &self,
+        swapchain: &Swapchain,
+    ) -> (&Resource, CpuDescriptorHandle) {
+        let index = swapchain.get_current_back_buffer_index();
+        (self.buffer(index), self.rtv(index))
+    }
+
+    fn create_rtv(&self, index: u32) {
+        let buffer = &self.buffers[index as usize];
+        match self.rtv_formats[index as usize] {
+            Some(format) => self.device.create_render_target_view_with_desc(
+                buffer,
+                &RenderTargetViewDesc::default()
+                    .with_format(format)
+                    .new_texture_2d(Tex2DRtv::default()),
+                self.rtv(index),
+            ),
+            None => self
+                .device
+                .create_render_target_view(buffer, self.rtv(index)),
+        }
+    }
+}
+
+fn create_rtv_heap(
+    device: &Device,
+    buffer_count: u32,
+) -> DxResult<DescriptorHeap> {
+    let rtv_heap = device.create_descriptor_heap(
+        &DescriptorHeapDesc::default()
+            .with_heap_type(DescriptorHeapType::Rtv)
+            .with_num_descriptors(buffer_count),
+    )?;
+    if let Some(name) = device.make_object_name("Back buffer RTV heap") {
+        rtv_heap.set_name(&name)?;
+    }
+
+    Ok(rtv_heap)
+}
@@ -0,0 +1,480 @@
+use std::collections::HashMap;
+
+use log::{error, warn};
+use winapi::shared::winerror;
+
+use crate::{
+    CommandList, CopyCapable, DxError, DxResult, ID3D12Resource, Metric,
+    Metrics, Resource, ResourceBarrier, ResourceStates,
+    ResourceTransitionBarrier, ResourceUavBarrier,
+};
+
+#[derive(Debug)]
+enum PendingBarrier {
+    Transition {
+        resource: Resource,
+        subresource: Option<u32>,
+        before: ResourceStates,
+        after: ResourceStates,
+    },
+    // None means a barrier for all UAV accesses
+    Uav(Option<Resource>),
+}
+
+/// Collects transition and UAV barriers recorded by helpers (and the
+/// application) between two points of a command list and issues them
+/// in a single `resource_barrier` call on [BarrierBatcher::flush].
+///
+/// Pending transitions of the same resource are coalesced: A -> B followed
+/// by B -> C becomes A -> C, and a round trip like A -> B -> A is dropped
+/// altogether. Transitions are never merged across a pending UAV barrier
+/// of the same resource, or across a transition of a different
+/// subresource of it, so the order the barriers were requested in is kept.
+///
+/// Resources can also be tagged with their current state, after which
+/// [BarrierBatcher::require] derives the "before" state itself. This is
+/// how [MsaaTarget::resolve_batched](crate::MsaaTarget::resolve_batched),
+/// `Blitter::blit_batched` and `MipmapGenerator::generate_batched` share
+/// barriers with each other and with the caller. Tags hold a reference
+/// to the resource until they are removed
+#[derive(Debug, Default)]
+pub struct BarrierBatcher {
+    pending: Vec<PendingBarrier>,
+    // whole-resource states as they will be after the pending barriers
+    tags: HashMap<*mut ID3D12Resource, (Resource, ResourceStates)>,
+    requested_barrier_count: u64,
+    issued_barrier_count: u64,
+    flush_count: u64,
+}
+
+impl BarrierBatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking the state of the whole resource. `state` must be
+    /// the one the resource is in at this point of the command list
+    pub fn tag(&mut self, resource: &Resource, state: ResourceStates) {
+        self.tags.insert(resource.this, (resource.clone(), state));
+    }
+
+    /// Stops tracking the resource and returns the state it will be in
+    /// once the pending barriers are flushed
+    pub fn untag(&mut self, resource: &Resource) -> Option<ResourceStates> {
+        self.tags.remove(&resource.this).map(|(_, state)| state)
+    }
+
+    pub fn is_tagged(&self, resource: &Resource) -> bool {
+        self.tags.contains_key(&resource.this)
+    }
+
+    /// State of a tagged resource after the pending barriers
+    pub fn tagged_state(&self, resource: &Resource) -> Option<ResourceStates> {
+        self.tags.get(&resource.this).map(|(_, state)| *state)
+    }
+
+    /// Queues a transition of a tagged resource into `state`, unless it is
+    /// already there
+    pub fn require(
+        &mut self,
+        resource: &Resource,
+        state: ResourceStates,
+    ) -> DxResult<()> {
+        let before = match self.tagged_state(resource) {
+            Some(before) => before,
+            None => {
+                error!(
+                    "Resource {:p} is not tagged in the barrier batcher",
+                    resource.this
+                );
+                return Err(DxError::new(
+                    "BarrierBatcher::require",
+                    winerror::E_INVALIDARG,
+                ));
+            }
+        };
+
+        self.transition(resource, None, before, state);
+
+        Ok(())
+    }
+
+    /// Queues a transition of a subresource (or all of them if None).
+    /// Whole-resource transitions of tagged resources update their tags
+    pub fn transition(
+        &mut self,
+        resource: &Resource,
+        subresource: Option<u32>,
+        before: ResourceStates,
+        after: ResourceStates,
+    ) {
+        self.requested_barrier_count += 1;
+        if subresource.is_none() {
+            if let Some((_, state)) = self.tags.get_mut(&resource.this) {
+                if *state != before {
+                    warn!(
+                        "Transition of resource {:p} from {:?} does not match \
+                         its tagged state {:?}",
+                        resource.this, before, state
+                    );
+                }
+                *state = after;
+            }
+        }
+
+        if before == after {
+            return;
+        }
+
+        match self.find_mergeable(resource, subresource) {
+            Some(index) => {
+                let merged_before = match &mut self.pending[index] {
+                    PendingBarrier::Transition {
+                        before: pending_before,
+                        after: pending_after,
+                        ..
+                    } if *pending_after == before => {
+                        *pending_after = after;
+                        Some(*pending_before)
+                    }
+                    _ => None,
+                };
+                match merged_before {
+                    Some(merged_before) if merged_before == after => {
+                        self.pending.remove(index);
+                    }
+                    Some(_) => {}
+                    None => {
+                        warn!(
+                            "Transition of resource {:p} from {:?} does not \
+                             follow the pending one",
+                            resource.this, before
+                        );
+                        self.push_transition(
+                            resource,
+                            subresource,
+                            before,
+                            after,
+                        );
+                    }
+                }
+            }
+            None => self.push_transition(resource, subresource, before, after),
+        }
+    }
+
+    /// Queues a UAV barrier for the resource, or for all UAV accesses
+    /// if None. Repeated requests before the next flush are dropped
+    pub fn uav(&mut self, resource: Option<&Resource>) {
+        self.requested_barrier_count += 1;
+        let this = resource.map(|resource| resource.this);
+        let already_pending = self.pending.iter().any(|pending| {
+            matches!(
+                pending,
+                PendingBarrier::Uav(pending_resource)
+                    if pending_resource.as_ref().map(|resource| resource.this)
+                        == this
+            )
+        });
+
+        if !already_pending {
+            self.pending.push(PendingBarrier::Uav(resource.cloned()));
+        }
+    }
+
+    /// Records all the pending barriers in a single call. Does nothing
+    /// if there are none
+    pub fn flush<K: CopyCapable>(&mut self, command_list: &CommandList<K>) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        // the pending barriers keep the resources alive until recorded
+        let pending = std::mem::take(&mut self.pending);
+        let barriers = pending
+            .iter()
+            .map(|pending| match pending {
+                PendingBarrier::Transition {
+                    resource,
+                    subresource,
+                    before,
+                    after,
+                } => ResourceBarrier::new_transition(
+                    &ResourceTransitionBarrier::default()
+                        .with_resource(resource)
+                        .with_subresource(*subresource)
+                        .with_state_before(*before)
+                        .with_state_after(*after),
+                ),
+                PendingBarrier::Uav(resource) => {
+                    let mut desc = ResourceUavBarrier::default();
+                    if let Some(resource) = resource {
+                        desc.set_resource(resource);
+                    }
+                    ResourceBarrier::new_uav(&desc)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        command_list.resource_barrier(&barriers);
+        self.issued_barrier_count += barriers.len() as u64;
+        self.flush_count += 1;
+    }
+
+    /// Drops the pending barriers without recording them, e.g. when the
+    /// command list is discarded. Tags are kept as they are
+    pub fn discard(&mut self) {
+        self.pending.clear();
+    }
+
+    pub fn pending_count(&self) -> u32 {
+        self.pending.len() as u32
+    }
+
+    /// Number of barriers requested since creation
+    pub fn requested_barrier_count(&self) -> u64 {
+        self.requested_barrier_count
+    }
+
+    /// Number of barriers actually recorded since creation
+    pub fn issued_barrier_count(&self) -> u64 {
+        self.issued_barrier_count
+    }
+
+    pub fn flush_count(&self) -> u64 {
+        self.flush_count
+    }
+
+    fn push_transition(
+        &mut self,
+        resource: &Resource,
+        subresource: Option<u32>,
+        before: ResourceStates,
+        after: ResourceStates,
+    ) {
+        self.pending.push(PendingBarrier::Transition {
+            resource: resource.clone(),
+            subresource,
+            before,
+            after,
+        });
+    }
+
+    // Index of the latest pending transition of the same subresource,
+    // unless something that must stay in between has been queued after it
+    fn find_mergeable(
+        &self,
+        resource: &Resource,
+        subresource: Option<u32>,
+    ) -> Option<usize> {
+        for (index, pending) in self.pending.iter().enumerate().rev() {
+            match pending {
+                PendingBarrier::Transition {
+                    resource: pending_resource,
+                    subresource: pending_subresource,
+                    ..
+                } if pending_resource.this == resource.this => {
+                    return match *pending_subresource == subresource {
+                        true => Some(index),
+                        false => None,
+                    };
+                }
+                PendingBarrier::Uav(None) => return None,
+                PendingBarrier::Uav(Some(pending_resource))
+                    if pending_resource.this == resource.this =>
+                {
+                    return None
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+impl Metrics for BarrierBatcher {
+    fn metrics(&self) -> Vec<Metric> {
+        vec![
+            Metric::new(
+                "requested barriers",
+                self.requested_barrier_count as f64,
+            ),
+            Metric::new("issued barriers", self.issued_barrier_count as f64),
+            Metric::new("barrier flushes", self.flush_count as f64),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fake_resource;
+
+    // (subresource, before, after) of the pending transitions
+    fn pending_transitions(
+        batcher: &BarrierBatcher,
+    ) -> Vec<(Option<u32>, ResourceStates, ResourceStates)> {
+        batcher
+            .pending
+            .iter()
+            .filter_map(|pending| match pending {
+                PendingBarrier::Transition {
+                    subresource,
+                    before,
+                    after,
+                    ..
+                } => Some((*subresource, *before, *after)),
+                PendingBarrier::Uav(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chained_transitions_are_coalesced() {
+        let resource = fake_resource();
+        let mut batcher = BarrierBatcher::new();
+
+        batcher.transition(
+            &resource,
+            None,
+            ResourceStates::CopyDest,
+            ResourceStates::UnorderedAccess,
+        );
+        batcher.transition(
+            &resource,
+            None,
+            ResourceStates::UnorderedAccess,
+            ResourceStates::PixelShaderResource,
+        );
+
+        assert_eq!(
+            pending_transitions(&batcher),
+            vec![(
+                None,
+                ResourceStates::CopyDest,
+                ResourceStates::PixelShaderResource
+            )]
+        );
+        assert_eq!(batcher.requested_barrier_count(), 2);
+    }
+
+    #[test]
+    fn round_trips_are_dropped() {
+        let resource = fake_resource();
+        let mut batcher = BarrierBatcher::new();
+
+        batcher.transition(
+            &resource,
+            None,
+            ResourceStates::CopyDest,
+            ResourceStates::CopySource,
+        );
+        batcher.transition(
+            &resource,
+            None,
+            ResourceStates::CopySource,
+            ResourceStates::CopyDest,
+        );
+
+        assert_eq!(batcher.pending_count(), 0);
+    }
+
+    #[test]
+    fn uav_barriers_and_subresources_prevent_merging() {
+        let resource = fake_resource();
+        let mut batcher = BarrierBatcher::new();
+
+        batcher.transition(
+            &resource,
+            None,
+            ResourceStates::CopyDest,
+            ResourceStates::UnorderedAccess,
+        );
+        batcher.uav(Some(&resource));
+        batcher.uav(Some(&resource));
+        batcher.transition(
+            &resource,
+            None,
+            ResourceStates::UnorderedAccess,
+            ResourceStates::PixelShaderResource,
+        );
+        assert_eq!(batcher.pending_count(), 3);
+
+        batcher.discard();
+        batcher.transition(
+            &resource,
+            Some(0),
+            ResourceStates::CopyDest,
+            ResourceStates::CopySource,
+        );
+        batcher.transition(
+            &resource,
+            Some(1),
+            ResourceStates::CopySource,
+            ResourceStates::CopyDest,
+        );
+        assert_eq!(batcher.pending_count(), 2);
+    }
+
+    #[test]
+    fn different_resources_are_independent() {
+        let (first, second) = (fake_resource(), fake_resource());
+        let mut batcher = BarrierBatcher::new();
+
+        batcher.transition(
+            &first,
+            None,
+            ResourceStates::CopyDest,
+            ResourceStates::CopySource,
+        );
+        batcher.transition(
+            &second,
+            None,
+            ResourceStates::CopyDest,
+            ResourceStates::CopySource,
+        );
+        batcher.transition(
+            &first,
+            None,
+            ResourceStates::CopySource,
+            ResourceStates::PixelShaderResource,
+        );
+
+        assert_eq!(
+            pending_transitions(&batcher),
+            vec![
+                (
+                    None,
+                    ResourceStates::CopyDest,
+                    ResourceStates::PixelShaderResource
+                ),
+                (None, ResourceStates::CopyDest, ResourceStates::CopySource),
+            ]
+        );
+    }
+
+    #[test]
+    fn tags_track_the_state_after_pending_barriers() {
+        let (tagged, untagged) = (fake_resource(), fake_resource());
+        let mut batcher = BarrierBatcher::new();
+
+        batcher.tag(&tagged, ResourceStates::CopyDest);
+        batcher
+            .require(&tagged, ResourceStates::PixelShaderResource)
+            .unwrap();
+        batcher
+            .require(&tagged, ResourceStates::PixelShaderResource)
+            .unwrap();
+        assert!(batcher
+            .require(&untagged, ResourceStates::PixelShaderResource)
+            .is_err());
+
+        assert_eq!(batcher.pending_count(), 1);
+        assert_eq!(
+            batcher.untag(&tagged),
+            Some(ResourceStates::PixelShaderResource)
+        );
+        assert!(!batcher.is_tagged(&tagged));
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    BarrierBatcher, CbvSrvUavKind, CommandList, ComputeCapable,
+    ComputePipelineStateDesc, CopyCapable, DescriptorRing, Device, DxResult,
+    DxcCompiler, Format, PipelineState, Resource, ResourceStates,
+    RootSignature, ShaderBytecode, ShaderComponentMapping,
+    ShaderResourceViewDesc, Tex2DSrv, Tex2DUav, UnorderedAccessViewDesc,
+};
+
+const BLIT_SHADER: &str = r#"
+#define RS "RootConstants(num32BitConstants=3, b0), " \
+           "DescriptorTable(SRV(t0)), " \
+           "DescriptorTable(UAV(u0)), " \
+           "StaticSampler(s0, filter=FILTER_MIN_MAG_MIP_POINT, " \
+           "addressU=TEXTURE_ADDRESS_CLAMP, addressV=TEXTURE_ADDRESS_CLAMP), " \
+           "StaticSampler(s1, filter=FILTER_MIN_MAG_MIP_LINEAR, " \
+           "addressU=TEXTURE_ADDRESS_CLAMP, addressV=TEXTURE_ADDRESS_CLAMP)"
+
+static const uint FLAG_LINEAR_FILTER = 1;
+static const uint FLAG_FLIP_X = 2;
+static const uint FLAG_FLIP_Y = 4;
+static const uint FLAG_LINEAR_TO_SRGB = 8;
+static const uint FLAG_SRGB_TO_LINEAR = 16;
+
+struct Constants
+{
+    uint2 dest_size;
+    uint flags;
+};
+
+ConstantBuffer<Constants> constants: register(b0);
+Texture2D<float4> source: register(t0);
+RWTexture2D<float4> dest: register(u0);
+SamplerState point_sampler: register(s0);
+SamplerState linear_sampler: register(s1);
+
+float3 linear_to_srgb(float3 color)
+{
+    float3 low = color * 12.92;
+    float3 high = 1.055 * pow(color, 1. / 2.4) - 0.055;
+    return lerp(low, high, step(0.0031308, color));
+}
+
+float3 srgb_to_linear(float3 color)
+{
+    float3 low = color / 12.92;
+    float3 high = pow((color + 0.055) / 1.055, 2.4);
+    return lerp(low, high, step(0.04045, color));
+}
+
+[RootSignature(RS)]
+[numthreads(8, 8, 1)]
+void CS(uint3 id: SV_DispatchThreadID)
+{
+    if (any(id.xy >= constants.dest_size))
+    {
+        return;
+    }
+
+    float2 uv = (id.xy + 0.5) / constants.dest_size;
+    if (constants.flags & FLAG_FLIP_X)
+    {
+        uv.x = 1. - uv.x;
+    }
+    if (constants.flags & FLAG_FLIP_Y)
+    {
+        uv.y = 1. - uv.y;
+    }
+
+    float4 color;
+    if (constants.flags & FLAG_LINEAR_FILTER)
+    {
+        color = source.SampleLevel(linear_sampler, uv, 0);
+    }
+    else
+    {
+        color = source.SampleLevel(point_sampler, uv, 0);
+    }
+
+    if (constants.flags & FLAG_LINEAR_TO_SRGB)
+    {
+        color.rgb = linear_to_srgb(saturate(color.rgb));
+    }
+    else if (constants.flags & FLAG_SRGB_TO_LINEAR)
+    {
+        color.rgb = srgb_to_linear(saturate(color.rgb));
+    }
+
+    dest[id.xy] = color;
+}
+"#;
+
+const THREAD_GROUP_SIZE: u32 = 8;
+
+const FLAG_LINEAR_FILTER: u32 = 1;
+const FLAG_FLIP_X: u32 = 2;
+const FLAG_FLIP_Y: u32 = 4;
+const FLAG_LINEAR_TO_SRGB: u32 = 8;
+const FLAG_SRGB_TO_LINEAR: u32 = 16;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum BlitFilter {
+    Point,
+    Linear,
+}
+
+/// Conversion applied to the color channels (but not alpha) of the
+/// filtered source texels before they are written to the destination
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum BlitColorConversion {
+    None,
+    LinearToSrgb,
+    SrgbToLinear,
+}
+
+/// Describes how [Blitter::blit] maps the source onto the destination
+#[derive(Debug, Clone, Copy)]
+pub struct BlitDesc {
+    /// Format of the SRV the source is read through, can differ from
+    /// the resource format for typeless resources
+    pub source_format: Format,
+    pub source_mip: u32,
+    /// Format of the UAV the destination is written through, it must
+    /// support typed UAV stores, so e.g. sRGB formats have to be written
+    /// through their non-sRGB counterparts with [BlitColorConversion]
+    pub dest_format: Format,
+    pub dest_mip: u32,
+    pub filter: BlitFilter,
+    pub color_conversion: BlitColorConversion,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+impl BlitDesc {
+    pub fn new(source_format: Format, dest_format: Format) -> Self {
+        Self {
+            source_format,
+            source_mip: 0,
+            dest_format,
+            dest_mip: 0,
+            filter: BlitFilter::Linear,
+            color_conversion: BlitColorConversion::None,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+}
+
+/// Copies and scales 2D textures with format conversion using a compute
+/// shader, e.g. to blit into a back buffer of a different format or to
+/// convert a render target before reading it back, which CopyResource
+/// and CopyTextureRegion cannot do
+#[derive(Debug)]
+pub struct Blitter {
+    root_signature: RootSignature,
+    pipeline_state: PipelineState,
+}
+
+impl Blitter {
+    /// Compiles the embedded blit shader, so this is relatively slow
+    pub fn new(device: &Device, compiler: &DxcCompiler) -> DxResult<Self> {
+        let shader = compiler.compile_to_blob(
+            BLIT_SHADER,
+            "blit.hlsl",
+            "CS",
+            "cs_6_0",
+            &[],
+            &[],
+        )?;
+        let bytecode = ShaderBytecode::new(shader.get_buffer());
+
+        let root_signature = device.create_root_signature(0, &bytecode)?;
+        let pipeline_state = device.create_compute_pipeline_state(
+            &ComputePipelineStateDesc::default()
+                .with_root_signature(&root_signature)
+                .with_cs_bytecode(&bytecode),
+        )?;
+        if let Some(name) = device.make_object_name("Blitter PSO") {
+            pipeline_state.set_name(&name)?;
+        }
+
+        Ok(Self {
+            root_signature,
+            pipeline_state,
+        })
+    }
+
+    /// Records the blit of the whole source mip into the whole destination
+    /// mip, scaling it if the sizes differ. The source must be in a
+    /// non-pixel shader resource state and the destination in
+    /// UnorderedAccess state. The views are allocated from `descriptors`,
+    /// whose heap is bound to the command list along with the blitter's
+    /// root signature and pipeline state, so the caller has to restore
+    /// its own bindings afterwards
+    pub fn blit<K: ComputeCapable>(
+        &self,
+        device: &Device,
+        command_list: &CommandList<K>,
+        descriptors: &mut DescriptorRing<CbvSrvUavKind>,
+        source: &Resource,
+        dest: &Resource,
+        desc: &BlitDesc,
+    ) -> DxResult<()> {
+        let dest_desc = dest.get_desc();
+        let dest_width = ((dest_desc.width() >> desc.dest_mip) as u32).max(1);
+        let dest_height = (dest_desc.height() >> desc.dest_mip).max(1);
+
+        let (srv_cpu_handle, srv_gpu_handle) = descriptors.allocate(1)?;
+        device.create_shader_resource_view(
+            source,
+            Some(
+                &ShaderResourceViewDesc::default()
+                    .with_shader_4_component_mapping(
+                        ShaderComponentMapping::default(),
+                    )
+                    .with_format(desc.source_format)
+                    .new_texture_2d(
+                        &Tex2DSrv::default()
+                            .with_most_detailed_mip(desc.source_mip)
+                            .with_mip_levels(1),
+                    ),
+            ),
+            srv_cpu_handle,
+        );
+
+        let (uav_cpu_handle, uav_gpu_handle) = descriptors.allocate(1)?;
+        device.create_unordered_access_view(
+            dest,
+            None,
+            Some(
+                &UnorderedAccessViewDesc::default()
+                    .with_format(desc.dest_format)
+                    .new_texture_2d(
+                        &Tex2DUav::default().with_mip_slice(desc.dest_mip),
+                    ),
+            ),
+            uav_cpu_handle,
+        );
+
+        let mut flags = match desc.filter {
+            BlitFilter::Point => 0,
+            BlitFilter::Linear => FLAG_LINEAR_FILTER,
+        };
+        flags |= match desc.color_conversion {
+            BlitColorConversion::None => 0,
+            BlitColorConversion::LinearToSrgb => FLAG_LINEAR_TO_SRGB,
+            BlitColorConversion::SrgbToLinear => FLAG_SRGB_TO_LINEAR,
+        };
+        if desc.flip_x {
+            flags |= FLAG_FLIP_X;
+        }
+        if desc.flip_y {
+            flags |= FLAG_FLIP_Y;
+        }
+
+        command_list
+            .set_descriptor_heaps(std::slice::from_ref(descriptors.heap()));
+        command_list.set_compute_root_signature(&self.root_signature);
+        command_list.set_pipeline_state(&self.pipeline_state);
+        command_list.set_compute_root_32bit_constants(
+            0,
+            &[dest_width, dest_height, flags],
+            0,
+        );
+        command_list.set_compute_root_descriptor_table(1, srv_gpu_handle);
+        command_list.set_compute_root_descriptor_table(2, uav_gpu_handle);
+        command_list.dispatch(
+            (dest_width + THREAD_GROUP_SIZE - 1) / THREAD_GROUP_SIZE,
+            (dest_height + THREAD_GROUP_SIZE - 1) / THREAD_GROUP_SIZE,
+            1,
+        );
+
+        Ok(())
+    }
+
+    /// Same as [Blitter::blit], but `source` and `dest` must be tagged in
+    /// `barriers`, which transitions them to NonPixelShaderResource and
+    /// UnorderedAccess states respectively and flushes before the dispatch.
+    /// Both are left in these states, so work that accesses `dest` as
+    /// a UAV afterwards has to queue a UAV barrier for it first
+    #[allow(clippy::too_many_arguments)]
+    pub fn blit_batched<K: ComputeCapable + CopyCapable>(
+        &self,
+        device: &Device,
+        command_list: &CommandList<K>,
+        descriptors: &mut DescriptorRing<CbvSrvUavKind>,
+        barriers: &mut BarrierBatcher,
+        source: &Resource,
+        dest: &Resource,
+        desc: &BlitDesc,
+    ) -> DxResult<()> {
+        barriers.require(source, ResourceStates::NonPixelShaderResource)?;
+        barriers.require(dest, ResourceStates::UnorderedAccess)?;
+        barriers.flush(command_list);
+
+        self.blit(device, command_list, descriptors, source, dest, desc)
+    }
+}
@@ -0,0 +1,158 @@
+// Helpers for passing D3D12 objects across a C ABI boundary, e.g. between
+// a host application and plugin DLLs or native middleware. Raw pointers
+// are passed as void* since the other side usually has its own definitions
+// of the interfaces. All the conversions follow COM reference counting
+// rules, see FfiOwnership for the details. Besides the methods for Rust
+// code, the conversions and AddRef/Release are exported as unmangled
+// extern "C" functions, so that a cdylib built on top of the crate can
+// hand them to a C or C++ host
+
+use std::ffi::c_void;
+
+use winapi::shared::winerror;
+
+use crate::{
+    CommandQueue, Device, DxError, DxResult, Fence, IID_ID3D12CommandQueue,
+    IID_ID3D12Device2, IID_ID3D12Fence, IID_ID3D12Resource, IUnknown, Resource,
+    HRESULT, IID, ULONG,
+};
+
+/// Tells whether the reference held by a raw pointer passed into
+/// `from_ffi` constructors is handed over to the new wrapper
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum FfiOwnership {
+    /// The caller keeps its reference, the wrapper takes its own one
+    Borrowed = 0,
+    /// The caller's reference is moved into the wrapper, so the caller
+    /// must not release the pointer afterwards
+    Transferred = 1,
+}
+
+/// Queries `interface` from an arbitrary COM pointer, which also validates
+/// that the other side has passed an object of the expected type
+unsafe fn query_ffi_interface(
+    object: *mut c_void,
+    interface: &IID,
+    ownership: FfiOwnership,
+) -> DxResult<*mut c_void> {
+    if object.is_null() {
+        return Err(DxError::new("from_ffi", winerror::E_POINTER));
+    }
+
+    let object = object as *mut IUnknown;
+    let mut result: *mut c_void = std::ptr::null_mut();
+    let hr = dx_call!(object, QueryInterface, interface, &mut result);
+    if let FfiOwnership::Transferred = ownership {
+        dx_call!(object, Release,);
+    }
+    if fail!(hr) {
+        return Err(DxError::new("QueryInterface", hr));
+    }
+
+    Ok(result)
+}
+
+macro_rules! impl_ffi_conversions {
+    ($struct_type:ty, $interface_id:expr, $export_name:ident) => {
+        impl $struct_type {
+            /// # Safety
+            ///
+            /// `object` must be null or a valid COM interface pointer
+            pub unsafe fn from_ffi(
+                object: *mut c_void,
+                ownership: FfiOwnership,
+            ) -> DxResult<Self> {
+                let object =
+                    query_ffi_interface(object, &$interface_id, ownership)?;
+
+                Ok(Self {
+                    this: object as *mut _,
+                })
+            }
+
+            /// Moves the wrapper's reference to the returned pointer,
+            /// the receiving side is responsible for releasing it
+            pub fn into_ffi(self) -> *mut c_void {
+                let object = self.this as *mut c_void;
+                std::mem::forget(self);
+                object
+            }
+
+            /// Returns the pointer without adding a reference, so it
+            /// must not be used after the wrapper has been dropped
+            pub fn as_ffi(&self) -> *mut c_void {
+                self.this as *mut c_void
+            }
+        }
+
+        #[doc = concat!(
+            "C entry point for [", stringify!($struct_type), "::from_ffi] ",
+            "followed by [", stringify!($struct_type), "::into_ffi]: ",
+            "checks the type of `object` and stores a pointer owning ",
+            "a reference in `result`, or null on failure"
+        )]
+        ///
+        /// # Safety
+        ///
+        /// `object` must be null or a valid COM interface pointer,
+        /// `result` must be valid for writes
+        #[no_mangle]
+        pub unsafe extern "C" fn $export_name(
+            object: *mut c_void,
+            ownership: FfiOwnership,
+            result: *mut *mut c_void,
+        ) -> HRESULT {
+            if result.is_null() {
+                return winerror::E_POINTER;
+            }
+
+            match <$struct_type>::from_ffi(object, ownership) {
+                Ok(wrapper) => {
+                    *result = wrapper.into_ffi();
+                    winerror::S_OK
+                }
+                Err(err) => {
+                    *result = std::ptr::null_mut();
+                    err.hresult()
+                }
+            }
+        }
+    };
+}
+
+impl_ffi_conversions!(Device, IID_ID3D12Device2, rusty_d3d12_device_from_ffi);
+impl_ffi_conversions!(
+    CommandQueue,
+    IID_ID3D12CommandQueue,
+    rusty_d3d12_command_queue_from_ffi
+);
+impl_ffi_conversions!(
+    Resource,
+    IID_ID3D12Resource,
+    rusty_d3d12_resource_from_ffi
+);
+impl_ffi_conversions!(Fence, IID_ID3D12Fence, rusty_d3d12_fence_from_ffi);
+
+/// AddRef on an arbitrary COM pointer, e.g. to be put into a function
+/// table handed to a plugin that doesn't use COM headers
+///
+/// # Safety
+///
+/// `object` must be a valid COM interface pointer
+#[no_mangle]
+pub unsafe extern "C" fn rusty_d3d12_add_ref(object: *mut c_void) -> ULONG {
+    dx_call!(object as *mut IUnknown, AddRef,)
+}
+
+/// Release on an arbitrary COM pointer, see [rusty_d3d12_add_ref]
+///
+/// # Safety
+///
+/// `object` must be a valid COM interface pointer owning a reference
+#[no_mangle]
+pub unsafe extern "C" fn rusty_d3d12_release(object: *mut c_void) -> ULONG {
+    dx_call!(object as *mut IUnknown, Release,)
+}
@@ -0,0 +1,126 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    AccessRights, Device, DeviceChild, DxError, DxResult, Feature,
+    FeatureDataOptions, Heap, HeapDesc, Resource, ResourceDesc,
+    ResourceDimension, ResourceFlags, ResourceStates, TextureLayout,
+};
+
+// shared handle names must be unique system-wide
+static SHARED_HEAP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A resource living in a heap that is shared between two devices,
+/// typically created on different adapters, e.g. to render on a discrete
+/// GPU and present from an integrated one. The heap is created by
+/// the primary device and opened by the secondary one, and the resource
+/// is placed at the beginning of it on both devices. Synchronizing
+/// the access is up to the caller (usually via a shared fence)
+#[derive(Debug)]
+pub struct CrossAdapterResource {
+    primary_heap: Heap,
+    secondary_heap: Heap,
+    primary: Resource,
+    secondary: Resource,
+}
+
+impl CrossAdapterResource {
+    /// `desc` gets AllowCrossAdapter flag and row-major layout, which
+    /// cross-adapter resources require. Textures can only be shared if
+    /// both devices report cross_adapter_row_major_texture_supported,
+    /// otherwise a buffer has to be used as a staging area
+    pub fn new(
+        primary_device: &Device,
+        secondary_device: &Device,
+        desc: &ResourceDesc,
+        primary_state: ResourceStates,
+        secondary_state: ResourceStates,
+    ) -> DxResult<Self> {
+        let desc = desc
+            .clone()
+            .with_layout(TextureLayout::RowMajor)
+            .with_flags(desc.flags() | ResourceFlags::AllowCrossAdapter);
+
+        if !matches!(desc.dimension(), ResourceDimension::Buffer) {
+            for device in [primary_device, secondary_device] {
+                let mut options = FeatureDataOptions::default();
+                device.check_feature_support(
+                    Feature::D3D12Options,
+                    &mut options,
+                )?;
+                if !options.cross_adapter_row_major_texture_supported() {
+                    error!(
+                        "Cross-adapter row-major textures are not supported"
+                    );
+                    return Err(DxError::new(
+                        "CrossAdapterResource::new",
+                        winerror::E_INVALIDARG,
+                    ));
+                }
+            }
+        }
+
+        let size = primary_device
+            .get_resource_allocation_info(0, std::slice::from_ref(&desc))
+            .size_in_bytes();
+        let primary_heap =
+            primary_device.create_heap(HeapDesc::new_cross_adapter(size))?;
+
+        let handle_name = format!(
+            "rusty_d3d12_cross_adapter_heap_{}_{}",
+            std::process::id(),
+            SHARED_HEAP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        let handle = primary_device.create_shared_handle(
+            &DeviceChild::from(primary_heap.clone()),
+            None,
+            AccessRights::All,
+            &handle_name,
+        )?;
+        let secondary_heap = secondary_device.open_shared_heap_handle(handle);
+        handle.close();
+        let secondary_heap = secondary_heap?;
+
+        let primary = primary_device.create_placed_resource(
+            &primary_heap,
+            0.into(),
+            &desc,
+            primary_state,
+            None,
+        )?;
+        let secondary = secondary_device.create_placed_resource(
+            &secondary_heap,
+            0.into(),
+            &desc,
+            secondary_state,
+            None,
+        )?;
+
+        Ok(Self {
+            primary_heap,
+            secondary_heap,
+            primary,
+            secondary,
+        })
+    }
+
+    /// The resource as seen by the primary device
+    pub fn primary(&self) -> &Resource {
+        &self.primary
+    }
+
+    /// The resource as seen by the secondary device
+    pub fn secondary(&self) -> &Resource {
+        &self.secondary
+    }
+
+    pub fn primary_heap(&self) -> &Heap {
+        &self.primary_heap
+    }
+
+    pub fn secondary_heap(&self) -> &Heap {
+        &self.secondary_heap
+    }
+}
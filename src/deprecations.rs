@@ -0,0 +1,13 @@
+// Deprecated names kept for one minor release so that user code keeps
+// compiling after a rename. Everything here is scheduled for removal in
+// the next minor version, see "API stability" section of README.md
+
+use crate::ByteCount;
+
+#[deprecated(
+    since = "0.6.0",
+    note = "use ByteCount::align_up instead, this function will be removed in 0.7.0"
+)]
+pub fn align_to_multiple(value: u64, alignment: u64) -> u64 {
+    ByteCount(value).align_up(ByteCount(alignment)).0
+}
@@ -0,0 +1,704 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::sealed::Sealed;
+use crate::{
+    ByteCount, ConstantBufferViewDesc, CpuDescriptorHandle,
+    DepthStencilViewDesc, DescriptorHeap, DescriptorHeapDesc,
+    DescriptorHeapFlags, DescriptorHeapType, Device, DxError, DxResult, Format,
+    GpuDescriptorHandle, HeapLimits, Metric, Metrics, Resource, SamplerDesc,
+    ShaderResourceViewDesc, UnorderedAccessViewDesc,
+};
+
+/// Implemented by descriptor marker types, ties each of them
+/// to the corresponding [DescriptorHeapType]
+pub trait DescriptorKind: Sealed {
+    const HEAP_TYPE: DescriptorHeapType;
+}
+
+/// Descriptor kinds that can live in shader-visible heaps
+pub trait ShaderVisibleDescriptorKind: DescriptorKind {}
+
+macro_rules! impl_descriptor_kind {
+    ($kind:ident, $heap_type:expr $(, $capability:ident)*) => {
+        impl Sealed for $kind {}
+
+        impl DescriptorKind for $kind {
+            const HEAP_TYPE: DescriptorHeapType = $heap_type;
+        }
+
+        $(
+            impl $capability for $kind {}
+        )*
+    };
+}
+
+/// Marker type for render target view descriptors
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct RtvKind;
+impl_descriptor_kind!(RtvKind, DescriptorHeapType::Rtv);
+
+/// Marker type for depth stencil view descriptors
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct DsvKind;
+impl_descriptor_kind!(DsvKind, DescriptorHeapType::Dsv);
+
+/// Marker type for constant buffer, shader resource
+/// and unordered access view descriptors
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CbvSrvUavKind;
+impl_descriptor_kind!(
+    CbvSrvUavKind,
+    DescriptorHeapType::CbvSrvUav,
+    ShaderVisibleDescriptorKind
+);
+
+/// Marker type for sampler descriptors
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct SamplerKind;
+impl_descriptor_kind!(
+    SamplerKind,
+    DescriptorHeapType::Sampler,
+    ShaderVisibleDescriptorKind
+);
+
+/// A descriptor slot allocated by [DescriptorAllocator]. The generation
+/// counter allows the allocator to detect handles which have already
+/// been freed (and whose slots might have been reused since then)
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct TypedDescriptorHandle<K> {
+    index: u32,
+    generation: u32,
+    kind: PhantomData<K>,
+}
+
+impl<K> TypedDescriptorHandle<K> {
+    /// Slot number in the allocator's heap, e.g. for bindless access
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+pub type RtvHandle = TypedDescriptorHandle<RtvKind>;
+pub type DsvHandle = TypedDescriptorHandle<DsvKind>;
+pub type CbvSrvUavHandle = TypedDescriptorHandle<CbvSrvUavKind>;
+pub type SamplerHandle = TypedDescriptorHandle<SamplerKind>;
+
+/// Shader-visible heaps are checked against [HeapLimits] first, so that
+/// oversized heaps fail with [DxError::DescriptorHeapLimitExceeded]
+/// instead of an opaque creation error
+fn create_heap<K: DescriptorKind>(
+    device: &Device,
+    func_name: &'static str,
+    num_descriptors: u32,
+    flags: DescriptorHeapFlags,
+    base_name: &str,
+) -> DxResult<DescriptorHeap> {
+    if flags.contains(DescriptorHeapFlags::ShaderVisible) {
+        HeapLimits::new(device)?.check_shader_visible_heap(
+            func_name,
+            K::HEAP_TYPE,
+            num_descriptors,
+        )?;
+    }
+
+    let heap = device.create_descriptor_heap(
+        &DescriptorHeapDesc::default()
+            .with_heap_type(K::HEAP_TYPE)
+            .with_num_descriptors(num_descriptors)
+            .with_flags(flags),
+    )?;
+    if let Some(name) = device.make_object_name(base_name) {
+        heap.set_name(&name)?;
+    }
+
+    Ok(heap)
+}
+
+/// Kind of view written into a descriptor slot
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub enum DescriptorViewType {
+    Cbv,
+    Srv,
+    Uav,
+    Rtv,
+    Dsv,
+    Sampler,
+}
+
+impl fmt::Display for DescriptorViewType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DescriptorViewType::Cbv => "CBV",
+            DescriptorViewType::Srv => "SRV",
+            DescriptorViewType::Uav => "UAV",
+            DescriptorViewType::Rtv => "RTV",
+            DescriptorViewType::Dsv => "DSV",
+            DescriptorViewType::Sampler => "Sampler",
+        };
+        f.pad(name)
+    }
+}
+
+/// What [DescriptorAllocator] has written into a slot. The resource name
+/// is captured at the moment of writing, so renaming the resource later
+/// is not reflected here
+#[derive(Debug, Clone)]
+pub struct DescriptorRecord {
+    view_type: DescriptorViewType,
+    resource_name: Option<String>,
+    format: Option<Format>,
+}
+
+impl DescriptorRecord {
+    fn new(
+        view_type: DescriptorViewType,
+        resource: Option<&Resource>,
+        format: Option<Format>,
+    ) -> Self {
+        Self {
+            view_type,
+            resource_name: resource
+                .and_then(|resource| resource.get_name().ok().flatten()),
+            format,
+        }
+    }
+
+    pub fn view_type(&self) -> DescriptorViewType {
+        self.view_type
+    }
+
+    /// None if the view has no resource (CBVs, samplers)
+    /// or the resource is unnamed
+    pub fn resource_name(&self) -> Option<&str> {
+        self.resource_name.as_deref()
+    }
+
+    /// None for views that have no format (CBVs, samplers)
+    pub fn format(&self) -> Option<Format> {
+        self.format
+    }
+}
+
+/// Manages a descriptor heap of a single type with a free list, so that
+/// descriptors can be allocated and freed in arbitrary order. The heap
+/// can be either CPU-only (e.g. for staging descriptors or RTVs)
+/// or shader-visible (e.g. for bindless descriptor tables)
+#[derive(Debug)]
+pub struct DescriptorAllocator<K> {
+    heap: DescriptorHeap,
+    handle_size: ByteCount,
+    cpu_start: CpuDescriptorHandle,
+    gpu_start: Option<GpuDescriptorHandle>,
+    generations: Vec<u32>,
+    live: Vec<bool>,
+    free_list: Vec<u32>,
+    // None if write recording is disabled
+    records: Option<Vec<Option<DescriptorRecord>>>,
+    kind: PhantomData<K>,
+}
+
+impl<K: DescriptorKind> DescriptorAllocator<K> {
+    pub fn new(
+        device: &Device,
+        num_descriptors: u32,
+        flags: DescriptorHeapFlags,
+    ) -> DxResult<Self> {
+        let heap = create_heap::<K>(
+            device,
+            "DescriptorAllocator::new",
+            num_descriptors,
+            flags,
+            "Descriptor allocator heap",
+        )?;
+
+        Ok(Self {
+            handle_size: device
+                .get_descriptor_handle_increment_size(K::HEAP_TYPE),
+            cpu_start: heap.get_cpu_descriptor_handle_for_heap_start(),
+            gpu_start: match flags.contains(DescriptorHeapFlags::ShaderVisible)
+            {
+                true => Some(heap.get_gpu_descriptor_handle_for_heap_start()),
+                false => None,
+            },
+            heap,
+            generations: vec![0; num_descriptors as usize],
+            live: vec![false; num_descriptors as usize],
+            // reversed so that the lowest slots are handed out first
+            free_list: (0..num_descriptors).rev().collect(),
+            records: match cfg!(debug_assertions) {
+                true => Some(vec![None; num_descriptors as usize]),
+                false => None,
+            },
+            kind: PhantomData,
+        })
+    }
+
+    /// Enables or disables recording of the views written through
+    /// the allocator's `create_*` methods, see [DescriptorAllocator::dump].
+    /// Recording queries the resource name on every write, so it's
+    /// enabled by default only in debug builds. Disabling it drops
+    /// the records collected so far
+    pub fn set_write_recording(&mut self, enabled: bool) {
+        match (enabled, self.records.is_some()) {
+            (true, false) => {
+                self.records = Some(vec![None; self.capacity() as usize])
+            }
+            (false, true) => self.records = None,
+            _ => {}
+        }
+    }
+
+    pub fn is_write_recording_enabled(&self) -> bool {
+        self.records.is_some()
+    }
+
+    /// What has been written into the slot, None if recording is disabled
+    /// or nothing has been written through the allocator since the slot
+    /// was allocated
+    pub fn record(
+        &self,
+        handle: &TypedDescriptorHandle<K>,
+    ) -> Option<&DescriptorRecord> {
+        debug_assert!(self.is_valid(handle), "Stale descriptor handle");
+        self.records.as_ref()?.get(handle.index as usize)?.as_ref()
+    }
+
+    /// Returns a human-readable table of the allocated slots with their
+    /// view types, resource names and formats, e.g.
+    /// `log::debug!("{}", allocator.dump())`. It's most useful with CPU-only
+    /// heaps that descriptors are staged in, since that's where views
+    /// are written directly
+    pub fn dump(&self) -> DescriptorDump<'_, K> {
+        DescriptorDump { allocator: self }
+    }
+
+    fn write(
+        &mut self,
+        handle: &TypedDescriptorHandle<K>,
+        make_record: impl FnOnce() -> DescriptorRecord,
+    ) -> CpuDescriptorHandle {
+        let cpu_handle = self.cpu_handle(handle);
+        if let Some(records) = self.records.as_mut() {
+            records[handle.index as usize] = Some(make_record());
+        }
+
+        cpu_handle
+    }
+
+    pub fn allocate(&mut self) -> DxResult<TypedDescriptorHandle<K>> {
+        let index = self.free_list.pop().ok_or_else(|| {
+            DxError::new(
+                "DescriptorAllocator::allocate",
+                winerror::E_OUTOFMEMORY,
+            )
+        })?;
+        self.live[index as usize] = true;
+
+        Ok(TypedDescriptorHandle {
+            index,
+            generation: self.generations[index as usize],
+            kind: PhantomData,
+        })
+    }
+
+    /// Returns the slot to the free list. Freeing a handle twice or freeing
+    /// a handle that belongs to another allocator is reported as an error
+    pub fn free(&mut self, handle: TypedDescriptorHandle<K>) -> DxResult<()> {
+        if !self.is_valid(&handle) {
+            return Err(DxError::new(
+                "DescriptorAllocator::free",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let index = handle.index as usize;
+        self.live[index] = false;
+        self.generations[index] = self.generations[index].wrapping_add(1);
+        self.free_list.push(handle.index);
+        if let Some(records) = self.records.as_mut() {
+            records[index] = None;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_valid(&self, handle: &TypedDescriptorHandle<K>) -> bool {
+        let index = handle.index as usize;
+        index < self.live.len()
+            && self.live[index]
+            && self.generations[index] == handle.generation
+    }
+
+    pub fn cpu_handle(
+        &self,
+        handle: &TypedDescriptorHandle<K>,
+    ) -> CpuDescriptorHandle {
+        debug_assert!(self.is_valid(handle), "Stale descriptor handle");
+        self.cpu_start.advance(handle.index, self.handle_size)
+    }
+
+    /// Returns None if the heap is not shader-visible
+    pub fn gpu_handle(
+        &self,
+        handle: &TypedDescriptorHandle<K>,
+    ) -> Option<GpuDescriptorHandle> {
+        debug_assert!(self.is_valid(handle), "Stale descriptor handle");
+        self.gpu_start
+            .map(|gpu_start| gpu_start.advance(handle.index, self.handle_size))
+    }
+
+    pub fn heap(&self) -> &DescriptorHeap {
+        &self.heap
+    }
+
+    pub fn handle_size(&self) -> ByteCount {
+        self.handle_size
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.live.len() as u32
+    }
+
+    /// Number of currently allocated descriptors
+    pub fn len(&self) -> u32 {
+        self.capacity() - self.free_list.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl DescriptorAllocator<RtvKind> {
+    pub fn create_render_target_view(
+        &mut self,
+        device: &Device,
+        resource: &Resource,
+        handle: &RtvHandle,
+    ) {
+        let dest_descriptor = self.write(handle, || {
+            DescriptorRecord::new(
+                DescriptorViewType::Rtv,
+                Some(resource),
+                Some(resource.get_desc().format()),
+            )
+        });
+        device.create_render_target_view(resource, dest_descriptor);
+    }
+}
+
+impl DescriptorAllocator<DsvKind> {
+    pub fn create_depth_stencil_view(
+        &mut self,
+        device: &Device,
+        resource: &Resource,
+        desc: &DepthStencilViewDesc,
+        handle: &DsvHandle,
+    ) {
+        let dest_descriptor = self.write(handle, || {
+            DescriptorRecord::new(
+                DescriptorViewType::Dsv,
+                Some(resource),
+                Some(desc.format()),
+            )
+        });
+        device.create_depth_stencil_view(resource, desc, dest_descriptor);
+    }
+}
+
+impl DescriptorAllocator<CbvSrvUavKind> {
+    pub fn create_constant_buffer_view(
+        &mut self,
+        device: &Device,
+        desc: &ConstantBufferViewDesc,
+        handle: &CbvSrvUavHandle,
+    ) {
+        let dest_descriptor = self.write(handle, || {
+            DescriptorRecord::new(DescriptorViewType::Cbv, None, None)
+        });
+        device.create_constant_buffer_view(desc, dest_descriptor);
+    }
+
+    pub fn create_shader_resource_view(
+        &mut self,
+        device: &Device,
+        resource: &Resource,
+        desc: Option<&ShaderResourceViewDesc>,
+        handle: &CbvSrvUavHandle,
+    ) {
+        let dest_descriptor = self.write(handle, || {
+            DescriptorRecord::new(
+                DescriptorViewType::Srv,
+                Some(resource),
+                Some(match desc {
+                    Some(desc) => desc.format(),
+                    None => resource.get_desc().format(),
+                }),
+            )
+        });
+        device.create_shader_resource_view(resource, desc, dest_descriptor);
+    }
+
+    pub fn create_unordered_access_view(
+        &mut self,
+        device: &Device,
+        resource: &Resource,
+        counter_resource: Option<&Resource>,
+        desc: Option<&UnorderedAccessViewDesc>,
+        handle: &CbvSrvUavHandle,
+    ) {
+        let dest_descriptor = self.write(handle, || {
+            DescriptorRecord::new(
+                DescriptorViewType::Uav,
+                Some(resource),
+                Some(match desc {
+                    Some(desc) => desc.format(),
+                    None => resource.get_desc().format(),
+                }),
+            )
+        });
+        device.create_unordered_access_view(
+            resource,
+            counter_resource,
+            desc,
+            dest_descriptor,
+        );
+    }
+}
+
+impl DescriptorAllocator<SamplerKind> {
+    pub fn create_sampler(
+        &mut self,
+        device: &Device,
+        desc: &SamplerDesc,
+        handle: &SamplerHandle,
+    ) {
+        let dest_descriptor = self.write(handle, || {
+            DescriptorRecord::new(DescriptorViewType::Sampler, None, None)
+        });
+        device.create_sampler(desc, dest_descriptor);
+    }
+}
+
+/// Returned by [DescriptorAllocator::dump], formats the allocator's
+/// records as a table
+pub struct DescriptorDump<'a, K> {
+    allocator: &'a DescriptorAllocator<K>,
+}
+
+impl<'a, K: DescriptorKind> fmt::Display for DescriptorDump<'a, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let allocator = self.allocator;
+        writeln!(
+            f,
+            "{:?} descriptor heap, {} of {} slots allocated",
+            K::HEAP_TYPE,
+            allocator.len(),
+            allocator.capacity()
+        )?;
+
+        let records = match allocator.records.as_ref() {
+            Some(records) => records,
+            None => return writeln!(f, "(write recording is disabled)"),
+        };
+
+        writeln!(f, "{:>6}  {:<8}{:<40}format", "slot", "view", "resource")?;
+        for (index, record) in records.iter().enumerate() {
+            if !allocator.live[index] {
+                continue;
+            }
+
+            match record {
+                Some(record) => writeln!(
+                    f,
+                    "{:>6}  {:<8}{:<40}{}",
+                    index,
+                    record.view_type,
+                    record.resource_name.as_deref().unwrap_or("-"),
+                    record
+                        .format
+                        .map(|format| format!("{:?}", format))
+                        .as_deref()
+                        .unwrap_or("-")
+                )?,
+                None => writeln!(f, "{:>6}  (not written)", index)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A shader-visible heap used as a ring buffer for transient descriptor
+/// tables. Descriptors allocated during a frame are retired once the fence
+/// value passed to [DescriptorRing::finish_frame] has been reached
+#[derive(Debug)]
+pub struct DescriptorRing<K> {
+    heap: DescriptorHeap,
+    handle_size: ByteCount,
+    cpu_start: CpuDescriptorHandle,
+    gpu_start: GpuDescriptorHandle,
+    capacity: u32,
+    head: u32,
+    used: u32,
+    high_water_mark: u32,
+    current_frame_used: u32,
+    // (fence value, descriptors consumed) pairs of the frames in flight
+    pending_frames: VecDeque<(u64, u32)>,
+    kind: PhantomData<K>,
+}
+
+impl<K: ShaderVisibleDescriptorKind> DescriptorRing<K> {
+    pub fn new(device: &Device, num_descriptors: u32) -> DxResult<Self> {
+        if num_descriptors == 0 {
+            error!("Descriptor ring must hold at least one descriptor");
+            return Err(DxError::new(
+                "DescriptorRing::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let heap = create_heap::<K>(
+            device,
+            "DescriptorRing::new",
+            num_descriptors,
+            DescriptorHeapFlags::ShaderVisible,
+            "Descriptor ring heap",
+        )?;
+
+        Ok(Self {
+            handle_size: device
+                .get_descriptor_handle_increment_size(K::HEAP_TYPE),
+            cpu_start: heap.get_cpu_descriptor_handle_for_heap_start(),
+            gpu_start: heap.get_gpu_descriptor_handle_for_heap_start(),
+            heap,
+            capacity: num_descriptors,
+            head: 0,
+            used: 0,
+            high_water_mark: 0,
+            current_frame_used: 0,
+            pending_frames: VecDeque::new(),
+            kind: PhantomData,
+        })
+    }
+
+    /// Allocates `count` contiguous descriptors, e.g. for a descriptor table
+    pub fn allocate(
+        &mut self,
+        count: u32,
+    ) -> DxResult<(CpuDescriptorHandle, GpuDescriptorHandle)> {
+        // tables cannot wrap around, so the tail of the heap is skipped
+        let skipped = match self.head + count > self.capacity {
+            true => self.capacity - self.head,
+            false => 0,
+        };
+
+        if self.used + skipped + count > self.capacity {
+            return Err(DxError::new(
+                "DescriptorRing::allocate",
+                winerror::E_OUTOFMEMORY,
+            ));
+        }
+
+        let start = match skipped {
+            0 => self.head,
+            _ => 0,
+        };
+        self.head = (start + count) % self.capacity;
+        self.used += skipped + count;
+        self.current_frame_used += skipped + count;
+        self.high_water_mark = self.high_water_mark.max(self.used);
+
+        Ok((
+            self.cpu_start.advance(start, self.handle_size),
+            self.gpu_start.advance(start, self.handle_size),
+        ))
+    }
+
+    /// Allocates `count` descriptors and copies them from a CPU-only heap,
+    /// returning the GPU handle of the resulting table
+    pub fn stage(
+        &mut self,
+        device: &Device,
+        source: CpuDescriptorHandle,
+        count: u32,
+    ) -> DxResult<GpuDescriptorHandle> {
+        let (cpu_handle, gpu_handle) = self.allocate(count)?;
+        device.copy_descriptors_simple(count, cpu_handle, source, K::HEAP_TYPE);
+
+        Ok(gpu_handle)
+    }
+
+    /// Marks the end of the frame; its descriptors will be reused
+    /// once `fence_value` is passed to [DescriptorRing::release_completed]
+    pub fn finish_frame(&mut self, fence_value: u64) {
+        self.pending_frames
+            .push_back((fence_value, self.current_frame_used));
+        self.current_frame_used = 0;
+    }
+
+    pub fn release_completed(&mut self, completed_fence_value: u64) {
+        while let Some(&(fence_value, frame_used)) = self.pending_frames.front()
+        {
+            if fence_value > completed_fence_value {
+                break;
+            }
+
+            self.used -= frame_used;
+            self.pending_frames.pop_front();
+        }
+    }
+
+    pub fn heap(&self) -> &DescriptorHeap {
+        &self.heap
+    }
+
+    pub fn handle_size(&self) -> ByteCount {
+        self.handle_size
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Number of descriptors used by the frames which haven't been
+    /// released yet, including the current one
+    pub fn used(&self) -> u32 {
+        self.used
+    }
+
+    /// The largest value [DescriptorRing::used] has ever reached,
+    /// useful for sizing the ring
+    pub fn high_water_mark(&self) -> u32 {
+        self.high_water_mark
+    }
+}
+
+impl<K: DescriptorKind> Metrics for DescriptorAllocator<K> {
+    fn metrics(&self) -> Vec<Metric> {
+        vec![
+            Metric::new("allocated descriptors", self.len()),
+            Metric::new("capacity", self.capacity()),
+        ]
+    }
+}
+
+impl<K: ShaderVisibleDescriptorKind> Metrics for DescriptorRing<K> {
+    fn metrics(&self) -> Vec<Metric> {
+        vec![
+            Metric::new("used descriptors", self.used),
+            Metric::new("high water mark", self.high_water_mark),
+            Metric::new("frames in flight", self.pending_frames.len() as u32),
+            Metric::new("capacity", self.capacity),
+        ]
+    }
+}
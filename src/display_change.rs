@@ -0,0 +1,243 @@
+use log::{info, warn};
+
+use crate::{
+    ColorSpaceType, CreateFactoryFlags, DxError, DxResult, Factory, Format,
+    OutputDesc1, SwapChainColorSpaceSupportFlags, Swapchain, HMONITOR,
+};
+
+/// Result of [DisplayChangeHandler::check]
+#[derive(Debug, Clone)]
+pub struct DisplayChange {
+    output_desc: OutputDesc1,
+    output_changed: bool,
+    color_space: ColorSpaceType,
+    format: Format,
+    needs_resize: bool,
+}
+
+impl DisplayChange {
+    /// Up-to-date description of the output the window is on
+    pub fn output_desc(&self) -> &OutputDesc1 {
+        &self.output_desc
+    }
+
+    /// True if the window has moved to another output, false if only
+    /// the capabilities of the same output have changed
+    pub fn output_changed(&self) -> bool {
+        self.output_changed
+    }
+
+    pub fn color_space(&self) -> ColorSpaceType {
+        self.color_space
+    }
+
+    /// Back buffer format for [DisplayChange::color_space]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// True if the back buffers have to be recreated with
+    /// [DisplayChange::format], see [DisplayChangeHandler::apply]
+    pub fn needs_resize(&self) -> bool {
+        self.needs_resize
+    }
+
+    /// True for HDR10 and scRGB color spaces
+    pub fn is_hdr(&self) -> bool {
+        !matches!(self.color_space, ColorSpaceType::RgbFullG22NoneP709)
+    }
+}
+
+/// Keeps the swapchain color space in sync with the display the window
+/// is on. [DisplayChangeHandler::check] is meant to be called on
+/// WM_DISPLAYCHANGE, WM_MOVE and WM_SIZE (or the corresponding events of
+/// the windowing library) and compares the containing output with the one
+/// seen last time. The output capabilities are re-queried from a fresh
+/// factory if the old one is not current anymore, since DXGI caches them
+/// per factory.
+///
+/// Swapchains with [Format::R16G16B16A16Float] back buffers are kept in
+/// scRGB, which is valid on both SDR and HDR displays. Other swapchains are
+/// switched to HDR10 ([Format::R10G10B10A2Unorm] and
+/// [ColorSpaceType::RgbFullG2084NoneP2020]) on HDR displays if HDR is
+/// preferred, and to sRGB ([ColorSpaceType::RgbFullG22NoneP709]) otherwise
+#[derive(Debug)]
+pub struct DisplayChangeHandler {
+    factory: Factory,
+    factory_flags: CreateFactoryFlags,
+    prefer_hdr: bool,
+    monitor: Option<HMONITOR>,
+    output_desc: Option<OutputDesc1>,
+    color_space: Option<ColorSpaceType>,
+}
+
+impl DisplayChangeHandler {
+    /// `factory_flags` are used to recreate the internal factory when
+    /// the display configuration changes
+    pub fn new(
+        factory_flags: CreateFactoryFlags,
+        prefer_hdr: bool,
+    ) -> DxResult<Self> {
+        Ok(Self {
+            factory: Factory::new(factory_flags)?,
+            factory_flags,
+            prefer_hdr,
+            monitor: None,
+            output_desc: None,
+            color_space: None,
+        })
+    }
+
+    /// Returns None if nothing has changed since the previous call or
+    /// the window is not on any output (e.g. minimized). If the back
+    /// buffer format can stay the same, the new color space is applied
+    /// right away, otherwise the caller has to release the back buffers
+    /// and call [DisplayChangeHandler::apply]
+    pub fn check(
+        &mut self,
+        swapchain: &Swapchain,
+    ) -> DxResult<Option<DisplayChange>> {
+        let containing_output = match swapchain.get_containing_output() {
+            Ok(output) => output,
+            Err(DxError::DxgiNotFound { .. }) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let monitor = containing_output.get_desc()?.monitor();
+
+        if !self.factory.is_current() {
+            self.factory = Factory::new(self.factory_flags)?;
+        }
+        let output_desc = match self.find_output_desc(monitor)? {
+            Some(output_desc) => output_desc,
+            None => containing_output.get_desc1()?,
+        };
+
+        let output_changed = self.monitor != Some(monitor);
+        if !output_changed && self.output_desc.as_ref() == Some(&output_desc) {
+            return Ok(None);
+        }
+        info!(
+            "Window is on {} ({:?}, {} bits per color)",
+            output_desc.device_name().unwrap_or_default(),
+            output_desc.color_space(),
+            output_desc.bits_per_color()
+        );
+
+        let current_format = swapchain.get_desc()?.format();
+        let (color_space, format) =
+            self.choose_color_space(&output_desc, current_format);
+        let change = DisplayChange {
+            output_desc: output_desc.clone(),
+            output_changed,
+            color_space,
+            format,
+            needs_resize: format as i32 != current_format as i32,
+        };
+
+        self.monitor = Some(monitor);
+        self.output_desc = Some(output_desc);
+        if !change.needs_resize {
+            self.set_color_space(swapchain, color_space)?;
+        }
+
+        Ok(Some(change))
+    }
+
+    /// Recreates the back buffers in the format of `change` if needed and
+    /// sets its color space. All references to the back buffers must be
+    /// released and the GPU must be done with them (see
+    /// [CommandQueue::wait_idle](crate::CommandQueue::wait_idle)) before
+    /// the call
+    pub fn apply(
+        &mut self,
+        swapchain: &Swapchain,
+        change: &DisplayChange,
+    ) -> DxResult<()> {
+        if change.needs_resize {
+            let desc = swapchain.get_desc()?;
+            swapchain.resize_buffers(0, 0, 0, change.format, desc.flags())?;
+        }
+
+        self.set_color_space(swapchain, change.color_space)
+    }
+
+    /// None until the first change has been applied
+    pub fn color_space(&self) -> Option<ColorSpaceType> {
+        self.color_space
+    }
+
+    pub fn output_desc(&self) -> Option<&OutputDesc1> {
+        self.output_desc.as_ref()
+    }
+
+    pub fn set_prefer_hdr(&mut self, prefer_hdr: bool) {
+        self.prefer_hdr = prefer_hdr;
+        // makes the next check re-evaluate the color space
+        self.output_desc = None;
+    }
+
+    pub fn prefer_hdr(&self) -> bool {
+        self.prefer_hdr
+    }
+
+    fn find_output_desc(
+        &self,
+        monitor: HMONITOR,
+    ) -> DxResult<Option<OutputDesc1>> {
+        for adapter in self.factory.enum_adapters()? {
+            for output in adapter.enum_outputs()? {
+                let output_desc = output.get_desc1()?;
+                if output_desc.monitor() == monitor {
+                    return Ok(Some(output_desc));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn choose_color_space(
+        &self,
+        output_desc: &OutputDesc1,
+        current_format: Format,
+    ) -> (ColorSpaceType, Format) {
+        match current_format {
+            Format::R16G16B16A16Float => {
+                (ColorSpaceType::RgbFullG10NoneP709, current_format)
+            }
+            _ if self.prefer_hdr && output_desc.is_hdr() => (
+                ColorSpaceType::RgbFullG2084NoneP2020,
+                Format::R10G10B10A2Unorm,
+            ),
+            _ => (ColorSpaceType::RgbFullG22NoneP709, current_format),
+        }
+    }
+
+    fn set_color_space(
+        &mut self,
+        swapchain: &Swapchain,
+        color_space: ColorSpaceType,
+    ) -> DxResult<()> {
+        let color_space = match swapchain
+            .check_color_space_support(color_space)?
+            .contains(SwapChainColorSpaceSupportFlags::Present)
+        {
+            true => color_space,
+            false => {
+                warn!(
+                    "Swapchain cannot present in {:?}, falling back to sRGB",
+                    color_space
+                );
+                ColorSpaceType::RgbFullG22NoneP709
+            }
+        };
+
+        swapchain.set_color_space(color_space)?;
+        if !matches!(color_space, ColorSpaceType::RgbFullG2084NoneP2020) {
+            swapchain.set_hdr_metadata(None)?;
+        }
+        self.color_space = Some(color_space);
+
+        Ok(())
+    }
+}
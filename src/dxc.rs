@@ -0,0 +1,332 @@
+use log::error;
+#[cfg(feature = "log_ref_counting")]
+use log::trace;
+use widestring::U16CString;
+use winapi::shared::minwindef::HMODULE;
+use winapi::shared::winerror;
+use winapi::um::libloaderapi::{FreeLibrary, GetProcAddress, LoadLibraryW};
+
+use crate::raw_bindings::dxc::*;
+use crate::{cast_to_ppv, Blob, DxError, DxResult, ID3DBlob, HRESULT, ULONG};
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum DxcOutKind {
+    None = DXC_OUT_KIND_DXC_OUT_NONE,
+    Object = DXC_OUT_KIND_DXC_OUT_OBJECT,
+    Errors = DXC_OUT_KIND_DXC_OUT_ERRORS,
+    Pdb = DXC_OUT_KIND_DXC_OUT_PDB,
+    ShaderHash = DXC_OUT_KIND_DXC_OUT_SHADER_HASH,
+    Disassembly = DXC_OUT_KIND_DXC_OUT_DISASSEMBLY,
+    Hlsl = DXC_OUT_KIND_DXC_OUT_HLSL,
+    Text = DXC_OUT_KIND_DXC_OUT_TEXT,
+    Reflection = DXC_OUT_KIND_DXC_OUT_REFLECTION,
+    RootSignature = DXC_OUT_KIND_DXC_OUT_ROOT_SIGNATURE,
+    ExtraOutputs = DXC_OUT_KIND_DXC_OUT_EXTRA_OUTPUTS,
+    Remarks = DXC_OUT_KIND_DXC_OUT_REMARKS,
+    TimeReport = DXC_OUT_KIND_DXC_OUT_TIME_REPORT,
+}
+
+impl std::convert::TryFrom<i32> for DxcOutKind {
+    type Error = i32;
+
+    fn try_from(raw: i32) -> Result<Self, i32> {
+        match raw {
+            DXC_OUT_KIND_DXC_OUT_NONE => Ok(Self::None),
+            DXC_OUT_KIND_DXC_OUT_OBJECT => Ok(Self::Object),
+            DXC_OUT_KIND_DXC_OUT_ERRORS => Ok(Self::Errors),
+            DXC_OUT_KIND_DXC_OUT_PDB => Ok(Self::Pdb),
+            DXC_OUT_KIND_DXC_OUT_SHADER_HASH => Ok(Self::ShaderHash),
+            DXC_OUT_KIND_DXC_OUT_DISASSEMBLY => Ok(Self::Disassembly),
+            DXC_OUT_KIND_DXC_OUT_HLSL => Ok(Self::Hlsl),
+            DXC_OUT_KIND_DXC_OUT_TEXT => Ok(Self::Text),
+            DXC_OUT_KIND_DXC_OUT_REFLECTION => Ok(Self::Reflection),
+            DXC_OUT_KIND_DXC_OUT_ROOT_SIGNATURE => Ok(Self::RootSignature),
+            DXC_OUT_KIND_DXC_OUT_EXTRA_OUTPUTS => Ok(Self::ExtraOutputs),
+            DXC_OUT_KIND_DXC_OUT_REMARKS => Ok(Self::Remarks),
+            DXC_OUT_KIND_DXC_OUT_TIME_REPORT => Ok(Self::TimeReport),
+            _ => Err(raw),
+        }
+    }
+}
+
+/// Wrapper around IDxcResult interface
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct DxcCompilationResult {
+    pub(crate) this: *mut IDxcResult,
+}
+impl_com_object_refcount_unnamed!(DxcCompilationResult);
+impl_com_object_clone_drop!(DxcCompilationResult);
+impl_com_object_raw!(DxcCompilationResult, IDxcResult);
+
+impl DxcCompilationResult {
+    /// Returns the status of the compilation itself,
+    /// which can fail even if Compile() call has succeeded
+    pub fn get_status(&self) -> DxResult<HRESULT> {
+        let mut status: HRESULT = winerror::S_OK;
+        unsafe { dx_try!(self.this, GetStatus, &mut status) }
+
+        Ok(status)
+    }
+
+    pub fn has_output(&self, kind: DxcOutKind) -> bool {
+        unsafe { dx_call!(self.this, HasOutput, kind as i32) != 0 }
+    }
+
+    /// Returns the raw value as an error if the compiler reports
+    /// an output kind unknown to these bindings
+    pub fn primary_output(&self) -> Result<DxcOutKind, i32> {
+        DxcOutKind::try_from(unsafe { dx_call!(self.this, PrimaryOutput,) })
+    }
+
+    /// IDxcBlob is binary compatible with ID3DBlob,
+    /// so the outputs are returned as [Blob] objects
+    pub fn get_output(&self, kind: DxcOutKind) -> DxResult<Blob> {
+        let mut blob: *mut IDxcBlob = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                GetOutput,
+                kind as i32,
+                &IID_IDxcBlob,
+                cast_to_ppv(&mut blob),
+                std::ptr::null_mut()
+            );
+        }
+
+        // an output can be missing even if GetOutput() has succeeded
+        if blob.is_null() {
+            return Err(DxError::new(
+                "IDxcResult::GetOutput",
+                winerror::E_FAIL,
+            ));
+        }
+
+        Ok(Blob {
+            this: blob as *mut ID3DBlob,
+        })
+    }
+
+    /// Compiled DXIL bytecode
+    pub fn get_object(&self) -> DxResult<Blob> {
+        self.get_output(DxcOutKind::Object)
+    }
+
+    /// Returns None if no errors or warnings have been emitted
+    pub fn get_errors(&self) -> DxResult<Option<String>> {
+        if !self.has_output(DxcOutKind::Errors) {
+            return Ok(None);
+        }
+
+        let mut errors: *mut IDxcBlobUtf8 = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                GetOutput,
+                DxcOutKind::Errors as i32,
+                &IID_IDxcBlobUtf8,
+                cast_to_ppv(&mut errors),
+                std::ptr::null_mut()
+            );
+
+            if errors.is_null() {
+                return Ok(None);
+            }
+
+            let text = std::slice::from_raw_parts(
+                dx_call!(errors, GetStringPointer,) as *const u8,
+                dx_call!(errors, GetStringLength,) as usize,
+            );
+            let text = String::from_utf8_lossy(text).into_owned();
+            dx_call!(errors, Release,);
+
+            match text.is_empty() {
+                true => Ok(None),
+                false => Ok(Some(text)),
+            }
+        }
+    }
+}
+
+/// Runtime HLSL compiler backed by IDxcCompiler3 interface from
+/// dxcompiler.dll, which is loaded on creation
+#[derive(Debug)]
+pub struct DxcCompiler {
+    compiler: *mut IDxcCompiler3,
+    utils: *mut IDxcUtils,
+    include_handler: *mut IDxcIncludeHandler,
+    library: HMODULE,
+}
+
+impl DxcCompiler {
+    /// Loads dxcompiler.dll using the default DLL search order
+    pub fn new() -> DxResult<Self> {
+        Self::new_with_library_path("dxcompiler.dll")
+    }
+
+    pub fn new_with_library_path(library_path: &str) -> DxResult<Self> {
+        let library_path =
+            U16CString::from_str(library_path).map_err(|_| {
+                DxError::new("U16CString::from_str", winerror::E_INVALIDARG)
+            })?;
+
+        unsafe {
+            let library = LoadLibraryW(library_path.as_ptr());
+            if library.is_null() {
+                return Err(DxError::new("LoadLibraryW", winerror::E_FAIL));
+            }
+
+            let create_instance = GetProcAddress(
+                library,
+                "DxcCreateInstance\0".as_ptr() as *const i8,
+            );
+            if create_instance.is_null() {
+                FreeLibrary(library);
+                return Err(DxError::new("GetProcAddress", winerror::E_FAIL));
+            }
+            let create_instance: DxcCreateInstanceProc =
+                std::mem::transmute(create_instance);
+
+            let mut dxc_compiler = Self {
+                compiler: std::ptr::null_mut(),
+                utils: std::ptr::null_mut(),
+                include_handler: std::ptr::null_mut(),
+                library,
+            };
+
+            // from here on, Drop takes care of the cleanup on errors
+            dx_try!(create_instance(
+                &CLSID_DxcCompiler,
+                &IID_IDxcCompiler3,
+                cast_to_ppv(&mut dxc_compiler.compiler)
+            ));
+            dx_try!(create_instance(
+                &CLSID_DxcUtils,
+                &IID_IDxcUtils,
+                cast_to_ppv(&mut dxc_compiler.utils)
+            ));
+            dx_try!(
+                dxc_compiler.utils,
+                CreateDefaultIncludeHandler,
+                &mut dxc_compiler.include_handler
+            );
+
+            Ok(dxc_compiler)
+        }
+    }
+
+    /// Compiles HLSL source using the default include handler. Note that
+    /// the returned value has to be checked via
+    /// [DxcCompilationResult::get_status], see [DxcCompiler::compile_to_blob]
+    /// for a shortcut
+    pub fn compile(
+        &self,
+        source: &str,
+        source_name: &str,
+        entry_point: &str,
+        target_profile: &str,
+        args: &[&str],
+        defines: &[(&str, Option<&str>)],
+    ) -> DxResult<DxcCompilationResult> {
+        let mut all_args = vec![
+            source_name.to_owned(),
+            String::from("-E"),
+            entry_point.to_owned(),
+            String::from("-T"),
+            target_profile.to_owned(),
+        ];
+        for (name, value) in defines {
+            all_args.push(String::from("-D"));
+            all_args.push(match value {
+                Some(value) => format!("{}={}", name, value),
+                None => name.to_string(),
+            });
+        }
+        all_args.extend(args.iter().map(|arg| arg.to_string()));
+
+        let wide_args = all_args
+            .iter()
+            .map(|arg| U16CString::from_str(arg))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                DxError::new("U16CString::from_str", winerror::E_INVALIDARG)
+            })?;
+        let mut arg_pointers =
+            wide_args.iter().map(|arg| arg.as_ptr()).collect::<Vec<_>>();
+
+        let source_buffer = DxcBuffer {
+            Ptr: source.as_ptr() as *const std::ffi::c_void,
+            Size: source.len() as u64,
+            Encoding: DXC_CP_UTF8,
+        };
+
+        let mut result: *mut IDxcResult = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.compiler,
+                Compile,
+                &source_buffer,
+                arg_pointers.as_mut_ptr(),
+                arg_pointers.len() as u32,
+                self.include_handler,
+                &IID_IDxcResult,
+                cast_to_ppv(&mut result)
+            );
+        }
+
+        Ok(DxcCompilationResult { this: result })
+    }
+
+    /// Compiles the shader and returns its bytecode. Compilation errors
+    /// are logged and reported as the compilation status HRESULT
+    pub fn compile_to_blob(
+        &self,
+        source: &str,
+        source_name: &str,
+        entry_point: &str,
+        target_profile: &str,
+        args: &[&str],
+        defines: &[(&str, Option<&str>)],
+    ) -> DxResult<Blob> {
+        let result = self.compile(
+            source,
+            source_name,
+            entry_point,
+            target_profile,
+            args,
+            defines,
+        )?;
+
+        let status = result.get_status()?;
+        if fail!(status) {
+            error!(
+                "Cannot compile shader {}: {}",
+                source_name,
+                result.get_errors()?.unwrap_or_default()
+            );
+            return Err(DxError::new("IDxcCompiler3::Compile", status));
+        }
+
+        result.get_object()
+    }
+}
+
+impl Drop for DxcCompiler {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.include_handler.is_null() {
+                dx_call!(self.include_handler, Release,);
+            }
+            if !self.utils.is_null() {
+                dx_call!(self.utils, Release,);
+            }
+            if !self.compiler.is_null() {
+                dx_call!(self.compiler, Release,);
+            }
+            FreeLibrary(self.library);
+        }
+    }
+}
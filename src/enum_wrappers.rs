@@ -221,6 +221,21 @@ impl Format {
             _ => unimplemented!(),
         }
     }
+
+    /// Number of planes of the format, which is 2 for depth-stencil and
+    /// the common planar video formats and 1 for everything else
+    pub fn plane_count(self) -> u32 {
+        match self {
+            Self::R32G8X24Typeless
+            | Self::D32FloatS8X24Uint
+            | Self::R24G8Typeless
+            | Self::D24UnormS8Uint
+            | Self::Nv12
+            | Self::P010
+            | Self::P016 => 2,
+            _ => 1,
+        }
+    }
 }
 
 #[repr(i32)]
@@ -256,6 +271,11 @@ bitflags! {
     }
 }
 
+// GPU upload heaps have been added in Agility SDK 1.613, the bundled
+// headers are older, so the values are defined here until they are updated
+const D3D12_HEAP_TYPE_D3D12_HEAP_TYPE_GPU_UPLOAD: D3D12_HEAP_TYPE = 5;
+const D3D12_FEATURE_D3D12_FEATURE_D3D12_OPTIONS16: D3D12_FEATURE = 45;
+
 #[repr(i32)]
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -265,6 +285,11 @@ pub enum HeapType {
     Upload = D3D12_HEAP_TYPE_D3D12_HEAP_TYPE_UPLOAD,
     Readback = D3D12_HEAP_TYPE_D3D12_HEAP_TYPE_READBACK,
     Custom = D3D12_HEAP_TYPE_D3D12_HEAP_TYPE_CUSTOM,
+    /// CPU-writable video memory, which requires Resizable BAR, see
+    /// [FeatureDataOptions16::gpu_upload_heap_supported]
+    ///
+    /// [FeatureDataOptions16::gpu_upload_heap_supported]: crate::FeatureDataOptions16::gpu_upload_heap_supported
+    GpuUpload = D3D12_HEAP_TYPE_D3D12_HEAP_TYPE_GPU_UPLOAD,
 }
 
 #[repr(i32)]
@@ -280,6 +305,15 @@ pub enum CpuPageProperty {
     WriteBack = D3D12_CPU_PAGE_PROPERTY_D3D12_CPU_PAGE_PROPERTY_WRITE_BACK,
 }
 
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum MemorySegmentGroup {
+    Local = DXGI_MEMORY_SEGMENT_GROUP_DXGI_MEMORY_SEGMENT_GROUP_LOCAL,
+    NonLocal = DXGI_MEMORY_SEGMENT_GROUP_DXGI_MEMORY_SEGMENT_GROUP_NON_LOCAL,
+}
+
 #[repr(i32)]
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -629,6 +663,7 @@ pub enum Feature {
     D3D12Options10 = D3D12_FEATURE_D3D12_FEATURE_D3D12_OPTIONS10,
     D3D12Options11 = D3D12_FEATURE_D3D12_FEATURE_D3D12_OPTIONS11,
     D3D12Options12 = D3D12_FEATURE_D3D12_FEATURE_D3D12_OPTIONS12,
+    D3D12Options16 = D3D12_FEATURE_D3D12_FEATURE_D3D12_OPTIONS16,
 }
 
 #[repr(i32)]
@@ -822,6 +857,31 @@ bitflags! {
     }
 }
 
+bitflags! {
+    pub struct TileRangeFlags: i32 {
+        const None = D3D12_TILE_RANGE_FLAGS_D3D12_TILE_RANGE_FLAG_NONE;
+        const Null = D3D12_TILE_RANGE_FLAGS_D3D12_TILE_RANGE_FLAG_NULL;
+        const Skip = D3D12_TILE_RANGE_FLAGS_D3D12_TILE_RANGE_FLAG_SKIP;
+        const ReuseSingleTile = D3D12_TILE_RANGE_FLAGS_D3D12_TILE_RANGE_FLAG_REUSE_SINGLE_TILE;
+    }
+}
+
+bitflags! {
+    pub struct TileMappingFlags: i32 {
+        const None = D3D12_TILE_MAPPING_FLAGS_D3D12_TILE_MAPPING_FLAG_NONE;
+        const NoHazard = D3D12_TILE_MAPPING_FLAGS_D3D12_TILE_MAPPING_FLAG_NO_HAZARD;
+    }
+}
+
+bitflags! {
+    pub struct TileCopyFlags: i32 {
+        const None = D3D12_TILE_COPY_FLAGS_D3D12_TILE_COPY_FLAG_NONE;
+        const NoHazard = D3D12_TILE_COPY_FLAGS_D3D12_TILE_COPY_FLAG_NO_HAZARD;
+        const LinearBufferToSwizzledTiledResource = D3D12_TILE_COPY_FLAGS_D3D12_TILE_COPY_FLAG_LINEAR_BUFFER_TO_SWIZZLED_TILED_RESOURCE;
+        const SwizzledTiledResourceToLinearBuffer = D3D12_TILE_COPY_FLAGS_D3D12_TILE_COPY_FLAG_SWIZZLED_TILED_RESOURCE_TO_LINEAR_BUFFER;
+    }
+}
+
 bitflags! {
     pub struct BufferSrvFlags: i32 {
         const None = D3D12_BUFFER_SRV_FLAGS_D3D12_BUFFER_SRV_FLAG_NONE;
@@ -925,6 +985,165 @@ bitflags! {
     }
 }
 
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum BarrierLayout {
+    Undefined = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_UNDEFINED,
+    Common = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMMON,
+    GenericRead = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_GENERIC_READ,
+    RenderTarget = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_RENDER_TARGET,
+    UnorderedAccess =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_UNORDERED_ACCESS,
+    DepthStencilWrite =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DEPTH_STENCIL_WRITE,
+    DepthStencilRead =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DEPTH_STENCIL_READ,
+    ShaderResource = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_SHADER_RESOURCE,
+    CopySource = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COPY_SOURCE,
+    CopyDest = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COPY_DEST,
+    ResolveSource = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_RESOLVE_SOURCE,
+    ResolveDest = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_RESOLVE_DEST,
+    ShadingRateSource =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_SHADING_RATE_SOURCE,
+    VideoDecodeRead =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_DECODE_READ,
+    VideoDecodeWrite =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_DECODE_WRITE,
+    VideoProcessRead =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_PROCESS_READ,
+    VideoProcessWrite =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_PROCESS_WRITE,
+    VideoEncodeRead =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_ENCODE_READ,
+    VideoEncodeWrite =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_ENCODE_WRITE,
+    DirectQueueCommon =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DIRECT_QUEUE_COMMON,
+    DirectQueueGenericRead =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DIRECT_QUEUE_GENERIC_READ,
+    DirectQueueUnorderedAccess =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DIRECT_QUEUE_UNORDERED_ACCESS,
+    DirectQueueShaderResource =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DIRECT_QUEUE_SHADER_RESOURCE,
+    DirectQueueCopySource =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DIRECT_QUEUE_COPY_SOURCE,
+    DirectQueueCopyDest =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_DIRECT_QUEUE_COPY_DEST,
+    ComputeQueueCommon =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMPUTE_QUEUE_COMMON,
+    ComputeQueueGenericRead =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMPUTE_QUEUE_GENERIC_READ,
+    ComputeQueueUnorderedAccess = D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMPUTE_QUEUE_UNORDERED_ACCESS,
+    ComputeQueueShaderResource =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMPUTE_QUEUE_SHADER_RESOURCE,
+    ComputeQueueCopySource =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMPUTE_QUEUE_COPY_SOURCE,
+    ComputeQueueCopyDest =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_COMPUTE_QUEUE_COPY_DEST,
+    VideoQueueCommon =
+        D3D12_BARRIER_LAYOUT_D3D12_BARRIER_LAYOUT_VIDEO_QUEUE_COMMON,
+}
+
+impl BarrierLayout {
+    // has the same value as Common
+    pub const Present: Self = Self::Common;
+}
+
+bitflags! {
+    pub struct BarrierSync: i32 {
+        const None = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_NONE;
+        const All = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_ALL;
+        const Draw = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_DRAW;
+        const InputAssembler = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_INPUT_ASSEMBLER;
+        const VertexShading = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_VERTEX_SHADING;
+        const PixelShading = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_PIXEL_SHADING;
+        const DepthStencil = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_DEPTH_STENCIL;
+        const RenderTarget = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_RENDER_TARGET;
+        const ComputeShading = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_COMPUTE_SHADING;
+        const Raytracing = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_RAYTRACING;
+        const Copy = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_COPY;
+        const Resolve = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_RESOLVE;
+        const ExecuteIndirect = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_EXECUTE_INDIRECT;
+        const Predication = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_PREDICATION;
+        const AllShading = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_ALL_SHADING;
+        const NonPixelShading = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_NON_PIXEL_SHADING;
+        const EmitRaytracingAccelerationStructurePostbuildInfo = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_EMIT_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO;
+        const ClearUnorderedAccessView = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_CLEAR_UNORDERED_ACCESS_VIEW;
+        const VideoDecode = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_VIDEO_DECODE;
+        const VideoProcess = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_VIDEO_PROCESS;
+        const VideoEncode = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_VIDEO_ENCODE;
+        const BuildRaytracingAccelerationStructure = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_BUILD_RAYTRACING_ACCELERATION_STRUCTURE;
+        const CopyRaytracingAccelerationStructure = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_COPY_RAYTRACING_ACCELERATION_STRUCTURE;
+        const Split = D3D12_BARRIER_SYNC_D3D12_BARRIER_SYNC_SPLIT;
+    }
+}
+
+bitflags! {
+    pub struct BarrierAccess: i32 {
+        const Common = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_COMMON;
+        const VertexBuffer = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VERTEX_BUFFER;
+        const ConstantBuffer = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_CONSTANT_BUFFER;
+        const IndexBuffer = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_INDEX_BUFFER;
+        const RenderTarget = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_RENDER_TARGET;
+        const UnorderedAccess = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_UNORDERED_ACCESS;
+        const DepthStencilWrite = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_DEPTH_STENCIL_WRITE;
+        const DepthStencilRead = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_DEPTH_STENCIL_READ;
+        const ShaderResource = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_SHADER_RESOURCE;
+        const StreamOutput = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_STREAM_OUTPUT;
+        const IndirectArgument = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_INDIRECT_ARGUMENT;
+        const Predication = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_PREDICATION;
+        const CopyDest = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_COPY_DEST;
+        const CopySource = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_COPY_SOURCE;
+        const ResolveDest = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_RESOLVE_DEST;
+        const ResolveSource = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_RESOLVE_SOURCE;
+        const RaytracingAccelerationStructureRead = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_RAYTRACING_ACCELERATION_STRUCTURE_READ;
+        const RaytracingAccelerationStructureWrite = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_RAYTRACING_ACCELERATION_STRUCTURE_WRITE;
+        const ShadingRateSource = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_SHADING_RATE_SOURCE;
+        const VideoDecodeRead = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VIDEO_DECODE_READ;
+        const VideoDecodeWrite = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VIDEO_DECODE_WRITE;
+        const VideoProcessRead = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VIDEO_PROCESS_READ;
+        const VideoProcessWrite = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VIDEO_PROCESS_WRITE;
+        const VideoEncodeRead = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VIDEO_ENCODE_READ;
+        const VideoEncodeWrite = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_VIDEO_ENCODE_WRITE;
+        const NoAccess = D3D12_BARRIER_ACCESS_D3D12_BARRIER_ACCESS_NO_ACCESS;
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum BarrierType {
+    Global = D3D12_BARRIER_TYPE_D3D12_BARRIER_TYPE_GLOBAL,
+    Texture = D3D12_BARRIER_TYPE_D3D12_BARRIER_TYPE_TEXTURE,
+    Buffer = D3D12_BARRIER_TYPE_D3D12_BARRIER_TYPE_BUFFER,
+}
+
+bitflags! {
+    pub struct TextureBarrierFlags: i32 {
+        const None = D3D12_TEXTURE_BARRIER_FLAGS_D3D12_TEXTURE_BARRIER_FLAG_NONE;
+        const Discard = D3D12_TEXTURE_BARRIER_FLAGS_D3D12_TEXTURE_BARRIER_FLAG_DISCARD;
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum RtvDimension {
+    Unknown = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_UNKNOWN,
+    Buffer = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_BUFFER,
+    Texture1D = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE1D,
+    Texture1DArray = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE1DARRAY,
+    Texture2D = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE2D,
+    Texture2DArray = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE2DARRAY,
+    Texture2DMs = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE2DMS,
+    Texture2DMsArray = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE2DMSARRAY,
+    Texture3D = D3D12_RTV_DIMENSION_D3D12_RTV_DIMENSION_TEXTURE3D,
+}
+
 #[repr(i32)]
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -967,6 +1186,8 @@ pub enum ShaderModel {
     SM_6_4 = D3D_SHADER_MODEL_D3D_SHADER_MODEL_6_4,
     SM_6_5 = D3D_SHADER_MODEL_D3D_SHADER_MODEL_6_5,
     SM_6_6 = D3D_SHADER_MODEL_D3D_SHADER_MODEL_6_6,
+    SM_6_7 = D3D_SHADER_MODEL_D3D_SHADER_MODEL_6_7,
+    SM_6_8 = D3D_SHADER_MODEL_D3D_SHADER_MODEL_6_8,
 }
 
 #[repr(i32)]
@@ -1018,6 +1239,35 @@ pub enum GpuPreference {
     HighPerformance = DXGI_GPU_PREFERENCE_DXGI_GPU_PREFERENCE_HIGH_PERFORMANCE,
 }
 
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum FeatureLevel {
+    L11_0 = D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_11_0,
+    L11_1 = D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_11_1,
+    L12_0 = D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_0,
+    L12_1 = D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_1,
+    L12_2 = D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_2,
+}
+
+impl Default for FeatureLevel {
+    fn default() -> Self {
+        Self::L12_0
+    }
+}
+
+/// Tells which kind of adapter [Device::new_with_fallback](crate::Device::new_with_fallback)
+/// has created the device on
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum AdapterKind {
+    Hardware,
+    Warp,
+}
+
 #[repr(i32)]
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -1040,6 +1290,49 @@ pub enum MessageCategory {
     Shader = D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_SHADER,
 }
 
+impl std::convert::TryFrom<i32> for MessageCategory {
+    type Error = i32;
+
+    fn try_from(raw: i32) -> Result<Self, i32> {
+        match raw {
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_APPLICATION_DEFINED => {
+                Ok(Self::ApplicationDefined)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_MISCELLANEOUS => {
+                Ok(Self::Miscellaneous)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_INITIALIZATION => {
+                Ok(Self::Initialization)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_CLEANUP => {
+                Ok(Self::Cleanup)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_COMPILATION => {
+                Ok(Self::Compilation)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_STATE_CREATION => {
+                Ok(Self::StateCreation)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_STATE_SETTING => {
+                Ok(Self::StateSetting)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_STATE_GETTING => {
+                Ok(Self::StateGetting)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_RESOURCE_MANIPULATION => {
+                Ok(Self::ResourceManipulation)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_EXECUTION => {
+                Ok(Self::Execution)
+            }
+            D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_SHADER => {
+                Ok(Self::Shader)
+            }
+            _ => Err(raw),
+        }
+    }
+}
+
 // ToDo: macro for enum -> string
 impl std::fmt::Display for MessageCategory {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -1071,6 +1364,31 @@ pub enum MessageSeverity {
     Message = D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_MESSAGE,
 }
 
+impl std::convert::TryFrom<i32> for MessageSeverity {
+    type Error = i32;
+
+    fn try_from(raw: i32) -> Result<Self, i32> {
+        match raw {
+            D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_CORRUPTION => {
+                Ok(Self::Corruption)
+            }
+            D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_ERROR => {
+                Ok(Self::Error)
+            }
+            D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_WARNING => {
+                Ok(Self::Warning)
+            }
+            D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_INFO => {
+                Ok(Self::Info)
+            }
+            D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_MESSAGE => {
+                Ok(Self::Message)
+            }
+            _ => Err(raw),
+        }
+    }
+}
+
 impl std::fmt::Display for MessageSeverity {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -2403,6 +2721,899 @@ pub enum MessageId {
     D3D12MessagesEnd = D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_D3D12_MESSAGES_END,
 }
 
+impl std::convert::TryFrom<i32> for MessageId {
+    type Error = i32;
+
+    fn try_from(raw: i32) -> Result<Self, i32> {
+        match raw {
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNKNOWN => Ok(Self::Unknown),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_STRING_FROM_APPLICATION => Ok(Self::StringFromApplication),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_THIS => Ok(Self::CorruptedThis),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER1 => Ok(Self::CorruptedParameter1),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER2 => Ok(Self::CorruptedParameter2),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER3 => Ok(Self::CorruptedParameter3),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER4 => Ok(Self::CorruptedParameter4),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER5 => Ok(Self::CorruptedParameter5),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER6 => Ok(Self::CorruptedParameter6),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER7 => Ok(Self::CorruptedParameter7),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER8 => Ok(Self::CorruptedParameter8),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER9 => Ok(Self::CorruptedParameter9),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER10 => Ok(Self::CorruptedParameter10),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER11 => Ok(Self::CorruptedParameter11),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER12 => Ok(Self::CorruptedParameter12),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER13 => Ok(Self::CorruptedParameter13),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER14 => Ok(Self::CorruptedParameter14),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_PARAMETER15 => Ok(Self::CorruptedParameter15),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CORRUPTED_MULTITHREADING => Ok(Self::CorruptedMultithreading),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MESSAGE_REPORTING_OUTOFMEMORY => Ok(Self::MessageReportingOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETPRIVATEDATA_MOREDATA => Ok(Self::GetPrivateDataMoredata),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETPRIVATEDATA_INVALIDFREEDATA => Ok(Self::SetPrivateDataInvalidfreedata),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETPRIVATEDATA_CHANGINGPARAMS => Ok(Self::SetPrivateDataChangingparams),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETPRIVATEDATA_OUTOFMEMORY => Ok(Self::SetPrivateDataOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_UNRECOGNIZEDFORMAT => Ok(Self::CreateShaderResourceViewUnrecognizedformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDDESC => Ok(Self::CreateShaderResourceViewInvaliddesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDFORMAT => Ok(Self::CreateShaderResourceViewInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDVIDEOPLANESLICE => Ok(Self::CreateShaderResourceViewInvalidvideoplaneslice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDPLANESLICE => Ok(Self::CreateShaderResourceViewInvalidplaneslice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDDIMENSIONS => Ok(Self::CreateShaderResourceViewInvaliddimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDRESOURCE => Ok(Self::CreateShaderResourceViewInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_UNRECOGNIZEDFORMAT => Ok(Self::CreateRenderTargetViewUnrecognizedformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_UNSUPPORTEDFORMAT => Ok(Self::CreateRenderTargetViewUnsupportedformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDDESC => Ok(Self::CreateRenderTargetViewInvaliddesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDFORMAT => Ok(Self::CreateRenderTargetViewInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDVIDEOPLANESLICE => Ok(Self::CreateRenderTargetViewInvalidvideoplaneslice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDPLANESLICE => Ok(Self::CreateRenderTargetViewInvalidplaneslice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDDIMENSIONS => Ok(Self::CreateRenderTargetViewInvaliddimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDRESOURCE => Ok(Self::CreateRenderTargetViewInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_UNRECOGNIZEDFORMAT => Ok(Self::CreateDepthStencilViewUnrecognizedformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDDESC => Ok(Self::CreateDepthStencilViewInvaliddesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDFORMAT => Ok(Self::CreateDepthStencilViewInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDDIMENSIONS => Ok(Self::CreateDepthStencilViewInvaliddimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDRESOURCE => Ok(Self::CreateDepthStencilViewInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_OUTOFMEMORY => Ok(Self::CreateInputLayoutOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_TOOMANYELEMENTS => Ok(Self::CreateInputLayoutToomanyelements),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDFORMAT => Ok(Self::CreateInputLayoutInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INCOMPATIBLEFORMAT => Ok(Self::CreateInputLayoutIncompatibleformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDSLOT => Ok(Self::CreateInputLayoutInvalidslot),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDINPUTSLOTCLASS => Ok(Self::CreateInputLayoutInvalidinputslotclass),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_STEPRATESLOTCLASSMISMATCH => Ok(Self::CreateInputLayoutSteprateslotclassmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDSLOTCLASSCHANGE => Ok(Self::CreateInputLayoutInvalidslotclasschange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDSTEPRATECHANGE => Ok(Self::CreateInputLayoutInvalidstepratechange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDALIGNMENT => Ok(Self::CreateInputLayoutInvalidalignment),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_DUPLICATESEMANTIC => Ok(Self::CreateInputLayoutDuplicatesemantic),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_UNPARSEABLEINPUTSIGNATURE => Ok(Self::CreateInputLayoutUnparseableinputsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_NULLSEMANTIC => Ok(Self::CreateInputLayoutNullsemantic),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_MISSINGELEMENT => Ok(Self::CreateInputLayoutMissingelement),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEVERTEXSHADER_OUTOFMEMORY => Ok(Self::CreateVertexShaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEVERTEXSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreateVertexShaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEVERTEXSHADER_INVALIDSHADERTYPE => Ok(Self::CreateVertexShaderInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADER_OUTOFMEMORY => Ok(Self::CreateGeometryShaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreateGeometryShaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADER_INVALIDSHADERTYPE => Ok(Self::CreateGeometryShaderInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTOFMEMORY => Ok(Self::CreateGeometryShaderWithStreamOutputOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERBYTECODE => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERTYPE => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMENTRIES => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidnumentries),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSTREAMSTRIDEUNUSED => Ok(Self::CreateGeometryShaderWithStreamOutputOutputstreamstrideunused),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSLOT0EXPECTED => Ok(Self::CreateGeometryShaderWithStreamOutputOutputslot0Expected),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSLOT => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidoutputslot),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_ONLYONEELEMENTPERSLOT => Ok(Self::CreateGeometryShaderWithStreamOutputOnlyoneelementperslot),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCOMPONENTCOUNT => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidcomponentcount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTARTCOMPONENTANDCOMPONENTCOUNT => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidstartcomponentandcomponentcount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDGAPDEFINITION => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidgapdefinition),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_REPEATEDOUTPUT => Ok(Self::CreateGeometryShaderWithStreamOutputRepeatedOutput),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSTREAMSTRIDE => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidOutputStreamStride),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGSEMANTIC => Ok(Self::CreateGeometryShaderWithStreamOutputMissingSemantic),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MASKMISMATCH => Ok(Self::CreateGeometryShaderWithStreamOutputMaskMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_CANTHAVEONLYGAPS => Ok(Self::CreateGeometryShaderWithStreamOutputCantHaveOnlyGaps),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DECLTOOCOMPLEX => Ok(Self::CreateGeometryShaderWithStreamOutputDeclTooComplex),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGOUTPUTSIGNATURE => Ok(Self::CreateGeometryShaderWithStreamOutputMissingOutputSignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIXELSHADER_OUTOFMEMORY => Ok(Self::CreatePixelShaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIXELSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreatePixelShaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIXELSHADER_INVALIDSHADERTYPE => Ok(Self::CreatePixelShaderInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDFILLMODE => Ok(Self::CreateRasterizerStateInvalidfillmode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDCULLMODE => Ok(Self::CreateRasterizerStateInvalidcullmode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDDEPTHBIASCLAMP => Ok(Self::CreateRasterizerStateInvaliddepthbiasclamp),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDSLOPESCALEDDEPTHBIAS => Ok(Self::CreateRasterizerStateInvalidslopescaleddepthbias),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDDEPTHWRITEMASK => Ok(Self::CreatedepthstencilstateInvaliddepthwritemask),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDDEPTHFUNC => Ok(Self::CreatedepthstencilstateInvaliddepthfunc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFAILOP => Ok(Self::CreatedepthstencilstateInvalidfrontfacestencilfailop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILZFAILOP => Ok(Self::CreatedepthstencilstateInvalidfrontfacestencilzfailop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILPASSOP => Ok(Self::CreatedepthstencilstateInvalidfrontfacestencilpassop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFUNC => Ok(Self::CreatedepthstencilstateInvalidfrontfacestencilfunc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFAILOP => Ok(Self::CreatedepthstencilstateInvalidbackfacestencilfailop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILZFAILOP => Ok(Self::CreatedepthstencilstateInvalidbackfacestencilzfailop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILPASSOP => Ok(Self::CreatedepthstencilstateInvalidbackfacestencilpassop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFUNC => Ok(Self::CreatedepthstencilstateInvalidbackfacestencilfunc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDSRCBLEND => Ok(Self::CreateblendstateInvalidsrcblend),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDDESTBLEND => Ok(Self::CreateblendstateInvaliddestblend),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDBLENDOP => Ok(Self::CreateblendstateInvalidblendop),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDSRCBLENDALPHA => Ok(Self::CreateblendstateInvalidsrcblendalpha),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDDESTBLENDALPHA => Ok(Self::CreateblendstateInvaliddestblendalpha),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDBLENDOPALPHA => Ok(Self::CreateblendstateInvalidblendopalpha),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDRENDERTARGETWRITEMASK => Ok(Self::CreateblendstateInvalidrendertargetwritemask),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLEARDEPTHSTENCILVIEW_INVALID => Ok(Self::CleardepthstencilviewInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_ROOT_SIGNATURE_NOT_SET => Ok(Self::CommandListDrawRootSignatureNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_ROOT_SIGNATURE_MISMATCH => Ok(Self::CommandListDrawRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_VERTEX_BUFFER_NOT_SET => Ok(Self::CommandListDrawVertexBufferNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_VERTEX_BUFFER_STRIDE_TOO_SMALL => Ok(Self::CommandListDrawVertexBufferStrideTooSmall),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_VERTEX_BUFFER_TOO_SMALL => Ok(Self::CommandListDrawVertexBufferTooSmall),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_INDEX_BUFFER_NOT_SET => Ok(Self::CommandListDrawIndexBufferNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_INDEX_BUFFER_FORMAT_INVALID => Ok(Self::CommandListDrawIndexBufferFormatInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_INDEX_BUFFER_TOO_SMALL => Ok(Self::CommandListDrawIndexBufferTooSmall),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_INVALID_PRIMITIVETOPOLOGY => Ok(Self::CommandListDrawInvalidPrimitivetopology),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_VERTEX_STRIDE_UNALIGNED => Ok(Self::CommandListDrawVertexStrideUnaligned),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_INDEX_OFFSET_UNALIGNED => Ok(Self::CommandListDrawIndexOffsetUnaligned),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_REMOVAL_PROCESS_AT_FAULT => Ok(Self::DeviceRemovalProcessAtFault),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_REMOVAL_PROCESS_POSSIBLY_AT_FAULT => Ok(Self::DeviceRemovalProcessPossiblyAtFault),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_REMOVAL_PROCESS_NOT_AT_FAULT => Ok(Self::DeviceRemovalProcessNotAtFault),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_TRAILING_DIGIT_IN_SEMANTIC => Ok(Self::CreateInputLayoutTrailingDigitInSemantic),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_TRAILING_DIGIT_IN_SEMANTIC => Ok(Self::CreateGeometryShaderWithStreamOutputTrailingDigitInSemantic),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_TYPE_MISMATCH => Ok(Self::CreateInputLayoutTypeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEINPUTLAYOUT_EMPTY_LAYOUT => Ok(Self::CreateInputLayoutEmptyLayout),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_OBJECT_SUMMARY => Ok(Self::LiveObjectSummary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_DEVICE => Ok(Self::LiveDevice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_SWAPCHAIN => Ok(Self::LiveSwapchain),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDFLAGS => Ok(Self::CreateDepthStencilViewInvalidflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEVERTEXSHADER_INVALIDCLASSLINKAGE => Ok(Self::CreateVertexShaderInvalidclasslinkage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADER_INVALIDCLASSLINKAGE => Ok(Self::CreateGeometryShaderInvalidclasslinkage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAMTORASTERIZER => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidstreamtorasterizer),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIXELSHADER_INVALIDCLASSLINKAGE => Ok(Self::CreatePixelShaderInvalidclasslinkage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAM => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidstream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDENTRIES => Ok(Self::CreateGeometryShaderWithStreamOutputUnexpectedentries),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTRIDES => Ok(Self::CreateGeometryShaderWithStreamOutputUnexpectedstrides),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTRIDES => Ok(Self::CreateGeometryShaderWithStreamOutputInvalidnumstrides),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHULLSHADER_OUTOFMEMORY => Ok(Self::CreatehullshaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHULLSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreatehullshaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHULLSHADER_INVALIDSHADERTYPE => Ok(Self::CreatehullshaderInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHULLSHADER_INVALIDCLASSLINKAGE => Ok(Self::CreatehullshaderInvalidclasslinkage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDOMAINSHADER_OUTOFMEMORY => Ok(Self::CreatedomainshaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreatedomainshaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDSHADERTYPE => Ok(Self::CreatedomainshaderInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDCLASSLINKAGE => Ok(Self::CreatedomainshaderInvalidclasslinkage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_UNMAP_NOTMAPPED => Ok(Self::ResourceUnmapNotmapped),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CHECKFEATURESUPPORT_MISMATCHED_DATA_SIZE => Ok(Self::DeviceCheckfeaturesupportMismatchedDataSize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMPUTESHADER_OUTOFMEMORY => Ok(Self::CreateComputeShaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMPUTESHADER_INVALIDSHADERBYTECODE => Ok(Self::CreateComputeShaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMPUTESHADER_INVALIDCLASSLINKAGE => Ok(Self::CreateComputeShaderInvalidclasslinkage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreateVertexShaderDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEHULLSHADER_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreatehullshaderDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreatedomainshaderDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreateGeometryShaderDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreateGeometryShaderWithStreamOutputDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreatePixelShaderDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_DOUBLEFLOATOPSNOTSUPPORTED => Ok(Self::DeviceCreateComputeShaderDoublefloatopsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDRESOURCE => Ok(Self::CreateunorderedaccessviewInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDDESC => Ok(Self::CreateunorderedaccessviewInvaliddesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDFORMAT => Ok(Self::CreateunorderedaccessviewInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDVIDEOPLANESLICE => Ok(Self::CreateunorderedaccessviewInvalidvideoplaneslice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDPLANESLICE => Ok(Self::CreateunorderedaccessviewInvalidplaneslice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDDIMENSIONS => Ok(Self::CreateunorderedaccessviewInvaliddimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_UNRECOGNIZEDFORMAT => Ok(Self::CreateunorderedaccessviewUnrecognizedformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDFLAGS => Ok(Self::CreateunorderedaccessviewInvalidflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDFORCEDSAMPLECOUNT => Ok(Self::CreateRasterizerStateInvalidforcedsamplecount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_INVALIDLOGICOPS => Ok(Self::CreateblendstateInvalidlogicops),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreateVertexShaderDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEHULLSHADER_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreatehullshaderDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreatedomainshaderDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreateGeometryShaderDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreateGeometryShaderWithStreamOutputDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreatePixelShaderDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_DOUBLEEXTENSIONSNOTSUPPORTED => Ok(Self::DeviceCreateComputeShaderDoubleextensionsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_UAVSNOTSUPPORTED => Ok(Self::DeviceCreateVertexShaderUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEHULLSHADER_UAVSNOTSUPPORTED => Ok(Self::DeviceCreatehullshaderUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_UAVSNOTSUPPORTED => Ok(Self::DeviceCreatedomainshaderUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_UAVSNOTSUPPORTED => Ok(Self::DeviceCreateGeometryShaderUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UAVSNOTSUPPORTED => Ok(Self::DeviceCreateGeometryShaderWithStreamOutputUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_UAVSNOTSUPPORTED => Ok(Self::DeviceCreatePixelShaderUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_UAVSNOTSUPPORTED => Ok(Self::DeviceCreateComputeShaderUavsnotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CLEARVIEW_INVALIDSOURCERECT => Ok(Self::DeviceClearviewInvalidsourcerect),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CLEARVIEW_EMPTYRECT => Ok(Self::DeviceClearviewEmptyrect),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UPDATETILEMAPPINGS_INVALID_PARAMETER => Ok(Self::UpdatetilemappingsInvalidParameter),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTILEMAPPINGS_INVALID_PARAMETER => Ok(Self::CopytilemappingsInvalidParameter),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEVICE_INVALIDARGS => Ok(Self::CreatedeviceInvalidargs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEVICE_WARNING => Ok(Self::CreatedeviceWarning),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_TYPE => Ok(Self::ResourceBarrierInvalidType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_NULL_POINTER => Ok(Self::ResourceBarrierNullPointer),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_SUBRESOURCE => Ok(Self::ResourceBarrierInvalidSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_RESERVED_BITS => Ok(Self::ResourceBarrierReservedBits),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_MISSING_BIND_FLAGS => Ok(Self::ResourceBarrierMissingBindFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_MISMATCHING_MISC_FLAGS => Ok(Self::ResourceBarrierMismatchingMiscFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_MATCHING_STATES => Ok(Self::ResourceBarrierMatchingStates),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_COMBINATION => Ok(Self::ResourceBarrierInvalidCombination),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_BEFORE_AFTER_MISMATCH => Ok(Self::ResourceBarrierBeforeAfterMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_RESOURCE => Ok(Self::ResourceBarrierInvalidResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_SAMPLE_COUNT => Ok(Self::ResourceBarrierSampleCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_FLAGS => Ok(Self::ResourceBarrierInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_COMBINED_FLAGS => Ok(Self::ResourceBarrierInvalidCombinedFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_FLAGS_FOR_FORMAT => Ok(Self::ResourceBarrierInvalidFlagsForFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_SPLIT_BARRIER => Ok(Self::ResourceBarrierInvalidSplitBarrier),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_UNMATCHED_END => Ok(Self::ResourceBarrierUnmatchedEnd),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_UNMATCHED_BEGIN => Ok(Self::ResourceBarrierUnmatchedBegin),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_FLAG => Ok(Self::ResourceBarrierInvalidFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_COMMAND_LIST_TYPE => Ok(Self::ResourceBarrierInvalidCommandListType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INVALID_SUBRESOURCE_STATE => Ok(Self::InvalidSubresourceState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_ALLOCATOR_CONTENTION => Ok(Self::CommandAllocatorContention),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_ALLOCATOR_RESET => Ok(Self::CommandAllocatorReset),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_ALLOCATOR_RESET_BUNDLE => Ok(Self::CommandAllocatorResetBundle),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_ALLOCATOR_CANNOT_RESET => Ok(Self::CommandAllocatorCannotReset),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_OPEN => Ok(Self::CommandListOpen),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INVALID_BUNDLE_API => Ok(Self::InvalidBundleApi),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_CLOSED => Ok(Self::CommandListClosed),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRONG_COMMAND_ALLOCATOR_TYPE => Ok(Self::WrongCommandAllocatorType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_ALLOCATOR_SYNC => Ok(Self::CommandAllocatorSync),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_SYNC => Ok(Self::CommandListSync),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_DESCRIPTOR_HEAP_INVALID => Ok(Self::SetDescriptorHeapInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMANDQUEUE => Ok(Self::CreateCommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMANDALLOCATOR => Ok(Self::CreateCommandallocator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_PIPELINESTATE => Ok(Self::CreatePipelinestate),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMANDLIST12 => Ok(Self::CreateCommandlist12),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_RESOURCE => Ok(Self::CreateResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_DESCRIPTORHEAP => Ok(Self::CreateDescriptorheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOTSIGNATURE => Ok(Self::CreateRootsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_LIBRARY => Ok(Self::CreateLibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_HEAP => Ok(Self::CreateHeap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_MONITOREDFENCE => Ok(Self::CreateMonitoredfence),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_QUERYHEAP => Ok(Self::CreateQueryheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMANDSIGNATURE => Ok(Self::CreateCommandsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_COMMANDQUEUE => Ok(Self::LiveCommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_COMMANDALLOCATOR => Ok(Self::LiveCommandallocator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_PIPELINESTATE => Ok(Self::LivePipelinestate),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_COMMANDLIST12 => Ok(Self::LiveCommandlist12),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_RESOURCE => Ok(Self::LiveResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_DESCRIPTORHEAP => Ok(Self::LiveDescriptorheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_ROOTSIGNATURE => Ok(Self::LiveRootsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_LIBRARY => Ok(Self::LiveLibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_HEAP => Ok(Self::LiveHeap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_MONITOREDFENCE => Ok(Self::LiveMonitoredfence),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_QUERYHEAP => Ok(Self::LiveQueryheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_COMMANDSIGNATURE => Ok(Self::LiveCommandsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_COMMANDQUEUE => Ok(Self::DestroyCommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_COMMANDALLOCATOR => Ok(Self::DestroyCommandallocator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_PIPELINESTATE => Ok(Self::DestroyPipelinestate),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_COMMANDLIST12 => Ok(Self::DestroyCommandlist12),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_RESOURCE => Ok(Self::DestroyResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_DESCRIPTORHEAP => Ok(Self::DestroyDescriptorheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_ROOTSIGNATURE => Ok(Self::DestroyRootsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_LIBRARY => Ok(Self::DestroyLibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_HEAP => Ok(Self::DestroyHeap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_MONITOREDFENCE => Ok(Self::DestroyMonitoredfence),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_QUERYHEAP => Ok(Self::DestroyQueryheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_COMMANDSIGNATURE => Ok(Self::DestroyCommandsignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDDIMENSIONS => Ok(Self::CreateResourceInvalidDimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDMISCFLAGS => Ok(Self::CreateResourceInvalidMiscFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDARG_RETURN => Ok(Self::CreateResourceInvalidArgReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_OUTOFMEMORY_RETURN => Ok(Self::CreateResourceOutOfMemoryReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDDESC => Ok(Self::CreateResourceInvalidDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_POSSIBLY_INVALID_SUBRESOURCE_STATE => Ok(Self::PossiblyInvalidSubresourceState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INVALID_USE_OF_NON_RESIDENT_RESOURCE => Ok(Self::InvalidUseOfNonResidentResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_POSSIBLE_INVALID_USE_OF_NON_RESIDENT_RESOURCE => Ok(Self::PossibleInvalidUseOfNonResidentResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_BUNDLE_PIPELINE_STATE_MISMATCH => Ok(Self::BundlePipelineStateMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_PRIMITIVE_TOPOLOGY_MISMATCH_PIPELINE_STATE => Ok(Self::PrimitiveTopologyMismatchPipelineState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_TARGET_FORMAT_MISMATCH_PIPELINE_STATE => Ok(Self::RenderTargetFormatMismatchPipelineState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_TARGET_SAMPLE_DESC_MISMATCH_PIPELINE_STATE => Ok(Self::RenderTargetSampleDescMismatchPipelineState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEPTH_STENCIL_FORMAT_MISMATCH_PIPELINE_STATE => Ok(Self::DepthStencilFormatMismatchPipelineState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEPTH_STENCIL_SAMPLE_DESC_MISMATCH_PIPELINE_STATE => Ok(Self::DepthStencilSampleDescMismatchPipelineState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADER_INVALIDBYTECODE => Ok(Self::CreateshaderInvalidbytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_NULLDESC => Ok(Self::CreateHeapNulldesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_INVALIDSIZE => Ok(Self::CreateHeapInvalidsize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_UNRECOGNIZEDHEAPTYPE => Ok(Self::CreateHeapUnrecognizedheaptype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_UNRECOGNIZEDCPUPAGEPROPERTIES => Ok(Self::CreateHeapUnrecognizedcpupageproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_UNRECOGNIZEDMEMORYPOOL => Ok(Self::CreateHeapUnrecognizedmemorypool),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_INVALIDPROPERTIES => Ok(Self::CreateHeapInvalidproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_INVALIDALIGNMENT => Ok(Self::CreateHeapInvalidalignment),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_UNRECOGNIZEDMISCFLAGS => Ok(Self::CreateHeapUnrecognizedmiscflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_INVALIDMISCFLAGS => Ok(Self::CreateHeapInvalidmiscflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_INVALIDARG_RETURN => Ok(Self::CreateHeapInvalidargReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEHEAP_OUTOFMEMORY_RETURN => Ok(Self::CreateHeapOutOfMemoryReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_NULLHEAPPROPERTIES => Ok(Self::CreateResourceAndHeapNullheapproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_UNRECOGNIZEDHEAPTYPE => Ok(Self::CreateResourceAndHeapUnrecognizedheaptype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_UNRECOGNIZEDCPUPAGEPROPERTIES => Ok(Self::CreateResourceAndHeapUnrecognizedcpupageproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_UNRECOGNIZEDMEMORYPOOL => Ok(Self::CreateResourceAndHeapUnrecognizedmemorypool),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_INVALIDHEAPPROPERTIES => Ok(Self::CreateResourceAndHeapInvalidheapproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_UNRECOGNIZEDHEAPMISCFLAGS => Ok(Self::CreateResourceAndHeapUnrecognizedheapmiscflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_INVALIDHEAPMISCFLAGS => Ok(Self::CreateResourceAndHeapInvalidheapmiscflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_INVALIDARG_RETURN => Ok(Self::CreateResourceAndHeapInvalidargReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_OUTOFMEMORY_RETURN => Ok(Self::CreateResourceAndHeapOutOfMemoryReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETCUSTOMHEAPPROPERTIES_UNRECOGNIZEDHEAPTYPE => Ok(Self::GetCustomHeapPropertiesUnrecognizedheaptype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETCUSTOMHEAPPROPERTIES_INVALIDHEAPTYPE => Ok(Self::GetCustomHeapPropertiesInvalidheaptype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_DESCRIPTOR_HEAP_INVALID_DESC => Ok(Self::CreateDescriptorHeapInvalidDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INVALID_DESCRIPTOR_HANDLE => Ok(Self::InvalidDescriptorHandle),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERASTERIZERSTATE_INVALID_CONSERVATIVERASTERMODE => Ok(Self::CreateRasterizerStateInvalidConservativerastermode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_CONSTANT_BUFFER_VIEW_INVALID_RESOURCE => Ok(Self::CreateConstantBufferViewInvalidResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_CONSTANT_BUFFER_VIEW_INVALID_DESC => Ok(Self::CreateConstantBufferViewInvalidDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_UNORDEREDACCESS_VIEW_INVALID_COUNTER_USAGE => Ok(Self::CreateUnorderedaccessViewInvalidCounterUsage),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPY_DESCRIPTORS_INVALID_RANGES => Ok(Self::CopyDescriptorsInvalidRanges),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPY_DESCRIPTORS_WRITE_ONLY_DESCRIPTOR => Ok(Self::CopyDescriptorsWriteOnlyDescriptor),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_RTV_FORMAT_NOT_UNKNOWN => Ok(Self::CreateGraphicsPipelineStateRtvFormatNotUnknown),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_RENDER_TARGET_COUNT => Ok(Self::CreateGraphicsPipelineStateInvalidRenderTargetCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_VERTEX_SHADER_NOT_SET => Ok(Self::CreateGraphicsPipelineStateVertexShaderNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INPUTLAYOUT_NOT_SET => Ok(Self::CreateGraphicsPipelineStateInputlayoutNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_HS_DS_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateShaderLinkageHsDsSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_REGISTERINDEX => Ok(Self::CreateGraphicsPipelineStateShaderLinkageRegisterindex),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_COMPONENTTYPE => Ok(Self::CreateGraphicsPipelineStateShaderLinkageComponenttype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_REGISTERMASK => Ok(Self::CreateGraphicsPipelineStateShaderLinkageRegistermask),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_SYSTEMVALUE => Ok(Self::CreateGraphicsPipelineStateShaderLinkageSystemvalue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_NEVERWRITTEN_ALWAYSREADS => Ok(Self::CreateGraphicsPipelineStateShaderLinkageNeverwrittenAlwaysreads),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_MINPRECISION => Ok(Self::CreateGraphicsPipelineStateShaderLinkageMinprecision),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_LINKAGE_SEMANTICNAME_NOT_FOUND => Ok(Self::CreateGraphicsPipelineStateShaderLinkageSemanticnameNotFound),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_HS_XOR_DS_MISMATCH => Ok(Self::CreateGraphicsPipelineStateHsXorDsMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_HULL_SHADER_INPUT_TOPOLOGY_MISMATCH => Ok(Self::CreateGraphicsPipelineStateHullShaderInputTopologyMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_HS_DS_CONTROL_POINT_COUNT_MISMATCH => Ok(Self::CreateGraphicsPipelineStateHsDsControlPointCountMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_HS_DS_TESSELLATOR_DOMAIN_MISMATCH => Ok(Self::CreateGraphicsPipelineStateHsDsTessellatorDomainMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_USE_OF_CENTER_MULTISAMPLE_PATTERN => Ok(Self::CreateGraphicsPipelineStateInvalidUseOfCenterMultisamplePattern),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_USE_OF_FORCED_SAMPLE_COUNT => Ok(Self::CreateGraphicsPipelineStateInvalidUseOfForcedSampleCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_PRIMITIVETOPOLOGY => Ok(Self::CreateGraphicsPipelineStateInvalidPrimitivetopology),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_SYSTEMVALUE => Ok(Self::CreateGraphicsPipelineStateInvalidSystemvalue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_OM_DUAL_SOURCE_BLENDING_CAN_ONLY_HAVE_RENDER_TARGET_0 => Ok(Self::CreateGraphicsPipelineStateOmDualSourceBlendingCanOnlyHaveRenderTarget0),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_OM_RENDER_TARGET_DOES_NOT_SUPPORT_BLENDING => Ok(Self::CreateGraphicsPipelineStateOmRenderTargetDoesNotSupportBlending),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_PS_OUTPUT_TYPE_MISMATCH => Ok(Self::CreateGraphicsPipelineStatePsOutputTypeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_OM_RENDER_TARGET_DOES_NOT_SUPPORT_LOGIC_OPS => Ok(Self::CreateGraphicsPipelineStateOmRenderTargetDoesNotSupportLogicOps),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_RENDERTARGETVIEW_NOT_SET => Ok(Self::CreateGraphicsPipelineStateRendertargetviewNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_DEPTHSTENCILVIEW_NOT_SET => Ok(Self::CreateGraphicsPipelineStateDepthstencilviewNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_GS_INPUT_PRIMITIVE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateGsInputPrimitiveMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_POSITION_NOT_PRESENT => Ok(Self::CreateGraphicsPipelineStatePositionNotPresent),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_MISSING_ROOT_SIGNATURE_FLAGS => Ok(Self::CreateGraphicsPipelineStateMissingRootSignatureFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_INDEX_BUFFER_PROPERTIES => Ok(Self::CreateGraphicsPipelineStateInvalidIndexBufferProperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INVALID_SAMPLE_DESC => Ok(Self::CreateGraphicsPipelineStateInvalidSampleDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_HS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateHsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_DS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateDsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_VS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateVsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_GS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateGsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_PS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStatePsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_MISSING_ROOT_SIGNATURE => Ok(Self::CreateGraphicsPipelineStateMissingRootSignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTE_BUNDLE_OPEN_BUNDLE => Ok(Self::ExecuteBundleOpenBundle),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTE_BUNDLE_DESCRIPTOR_HEAP_MISMATCH => Ok(Self::ExecuteBundleDescriptorHeapMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTE_BUNDLE_TYPE => Ok(Self::ExecuteBundleType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DRAW_EMPTY_SCISSOR_RECTANGLE => Ok(Self::DrawEmptyScissorRectangle),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOT_SIGNATURE_BLOB_NOT_FOUND => Ok(Self::CreateRootSignatureBlobNotFound),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOT_SIGNATURE_DESERIALIZE_FAILED => Ok(Self::CreateRootSignatureDeserializeFailed),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOT_SIGNATURE_INVALID_CONFIGURATION => Ok(Self::CreateRootSignatureInvalidConfiguration),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOT_SIGNATURE_NOT_SUPPORTED_ON_DEVICE => Ok(Self::CreateRootSignatureNotSupportedOnDevice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_NULLRESOURCEPROPERTIES => Ok(Self::CreateResourceAndHeapNullresourceproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCEANDHEAP_NULLHEAP => Ok(Self::CreateResourceAndHeapNullheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETRESOURCEALLOCATIONINFO_INVALIDRDESCS => Ok(Self::GetresourceallocationinfoInvalidrdescs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAKERESIDENT_NULLOBJECTARRAY => Ok(Self::MakeresidentNullobjectarray),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EVICT_NULLOBJECTARRAY => Ok(Self::EvictNullobjectarray),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_DESCRIPTOR_TABLE_INVALID => Ok(Self::SetDescriptorTableInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_ROOT_CONSTANT_INVALID => Ok(Self::SetRootConstantInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_ROOT_CONSTANT_BUFFER_VIEW_INVALID => Ok(Self::SetRootConstantBufferViewInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_ROOT_SHADER_RESOURCE_VIEW_INVALID => Ok(Self::SetRootShaderResourceViewInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_ROOT_UNORDERED_ACCESS_VIEW_INVALID => Ok(Self::SetRootUnorderedAccessViewInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_VERTEX_BUFFERS_INVALID_DESC => Ok(Self::SetVertexBuffersInvalidDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_INDEX_BUFFER_INVALID_DESC => Ok(Self::SetIndexBufferInvalidDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_STREAM_OUTPUT_BUFFERS_INVALID_DESC => Ok(Self::SetStreamOutputBuffersInvalidDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_UNRECOGNIZEDDIMENSIONALITY => Ok(Self::CreateResourceUnrecognizeddimensionality),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_UNRECOGNIZEDLAYOUT => Ok(Self::CreateResourceUnrecognizedlayout),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDDIMENSIONALITY => Ok(Self::CreateResourceInvaliddimensionality),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDALIGNMENT => Ok(Self::CreateResourceInvalidalignment),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDMIPLEVELS => Ok(Self::CreateResourceInvalidmiplevels),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDSAMPLEDESC => Ok(Self::CreateResourceInvalidsampledesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDLAYOUT => Ok(Self::CreateResourceInvalidlayout),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_INDEX_BUFFER_INVALID => Ok(Self::SetIndexBufferInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_VERTEX_BUFFERS_INVALID => Ok(Self::SetVertexBuffersInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_STREAM_OUTPUT_BUFFERS_INVALID => Ok(Self::SetStreamOutputBuffersInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_RENDER_TARGETS_INVALID => Ok(Self::SetRenderTargetsInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEQUERY_HEAP_INVALID_PARAMETERS => Ok(Self::CreatequeryHeapInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_BEGIN_END_QUERY_INVALID_PARAMETERS => Ok(Self::BeginEndQueryInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLOSE_COMMAND_LIST_OPEN_QUERY => Ok(Self::CloseCommandListOpenQuery),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVE_QUERY_DATA_INVALID_PARAMETERS => Ok(Self::ResolveQueryDataInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_PREDICATION_INVALID_PARAMETERS => Ok(Self::SetPredicationInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_TIMESTAMPS_NOT_SUPPORTED => Ok(Self::TimestampsNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_UNRECOGNIZEDFORMAT => Ok(Self::CreateResourceUnrecognizedformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDFORMAT => Ok(Self::CreateResourceInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETCOPYABLEFOOTPRINTS_INVALIDSUBRESOURCERANGE => Ok(Self::GetCopyableFootprintsOrCopyableLayoutInvalidSubresourcerange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETCOPYABLEFOOTPRINTS_INVALIDBASEOFFSET => Ok(Self::GetCopyableFootprintsOrCopyableLayoutInvalidbaseoffset),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_INVALID_HEAP => Ok(Self::ResourceBarrierInvalidHeap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_SAMPLER_INVALID => Ok(Self::CreateSamplerInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMMANDSIGNATURE_INVALID => Ok(Self::CreatecommandsignatureInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTE_INDIRECT_INVALID_PARAMETERS => Ok(Self::ExecuteIndirectInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETGPUVIRTUALADDRESS_INVALID_RESOURCE_DIMENSION => Ok(Self::GetgpuvirtualaddressInvalidResourceDimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDCLEARVALUE => Ok(Self::CreateResourceInvalidclearvalue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_UNRECOGNIZEDCLEARVALUEFORMAT => Ok(Self::CreateResourceUnrecognizedclearvalueformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_INVALIDCLEARVALUEFORMAT => Ok(Self::CreateResourceInvalidclearvalueformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATERESOURCE_CLEARVALUEDENORMFLUSH => Ok(Self::CreateResourceClearvaluedenormflush),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLEARRENDERTARGETVIEW_MISMATCHINGCLEARVALUE => Ok(Self::ClearrendertargetviewMismatchingclearvalue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLEARDEPTHSTENCILVIEW_MISMATCHINGCLEARVALUE => Ok(Self::CleardepthstencilviewMismatchingclearvalue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALIDHEAP => Ok(Self::MapInvalidheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNMAP_INVALIDHEAP => Ok(Self::UnmapInvalidheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALIDRESOURCE => Ok(Self::MapInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNMAP_INVALIDRESOURCE => Ok(Self::UnmapInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALIDSUBRESOURCE => Ok(Self::MapInvalidSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNMAP_INVALIDSUBRESOURCE => Ok(Self::UnmapInvalidSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALIDRANGE => Ok(Self::MapInvalidrange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNMAP_INVALIDRANGE => Ok(Self::UnmapInvalidrange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALIDDATAPOINTER => Ok(Self::MapInvaliddatapointer),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALIDARG_RETURN => Ok(Self::MapInvalidargReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_OUTOFMEMORY_RETURN => Ok(Self::MapOutOfMemoryReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTECOMMANDLISTS_BUNDLENOTSUPPORTED => Ok(Self::ExecuteCommandListsBundlenotsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTECOMMANDLISTS_COMMANDLISTMISMATCH => Ok(Self::ExecuteCommandListsCommandlistmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTECOMMANDLISTS_OPENCOMMANDLIST => Ok(Self::ExecuteCommandListsOpenCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTECOMMANDLISTS_FAILEDCOMMANDLIST => Ok(Self::ExecuteCommandListsFailedCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_NULLDST => Ok(Self::CopyBufferRegionNulldst),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_INVALIDDSTRESOURCEDIMENSION => Ok(Self::CopyBufferRegionInvaliddstresourcedimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_DSTRANGEOUTOFBOUNDS => Ok(Self::CopyBufferRegionDstrangeoutofbounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_NULLSRC => Ok(Self::CopyBufferRegionNullsrc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_INVALIDSRCRESOURCEDIMENSION => Ok(Self::CopyBufferRegionInvalidsrcresourcedimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_SRCRANGEOUTOFBOUNDS => Ok(Self::CopyBufferRegionSrcrangeoutofbounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_INVALIDCOPYFLAGS => Ok(Self::CopyBufferRegionInvalidcopyflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_NULLDST => Ok(Self::CopyTextureRegionNulldst),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_UNRECOGNIZEDDSTTYPE => Ok(Self::CopyTextureRegionUnrecognizeddsttype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTRESOURCEDIMENSION => Ok(Self::CopyTextureRegionInvaliddstresourcedimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTRESOURCE => Ok(Self::CopyTextureRegionInvaliddstresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTSUBRESOURCE => Ok(Self::CopyTextureRegionInvaliddstSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTOFFSET => Ok(Self::CopyTextureRegionInvaliddstoffset),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_UNRECOGNIZEDDSTFORMAT => Ok(Self::CopyTextureRegionUnrecognizeddstformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTFORMAT => Ok(Self::CopyTextureRegionInvaliddstformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTDIMENSIONS => Ok(Self::CopyTextureRegionInvaliddstdimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTROWPITCH => Ok(Self::CopyTextureRegionInvaliddstrowpitch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTPLACEMENT => Ok(Self::CopyTextureRegionInvaliddstplacement),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTDSPLACEDFOOTPRINTFORMAT => Ok(Self::CopyTextureRegionInvaliddstdsplacedfootprintformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_DSTREGIONOUTOFBOUNDS => Ok(Self::CopyTextureRegionDstregionoutofbounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_NULLSRC => Ok(Self::CopyTextureRegionNullsrc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_UNRECOGNIZEDSRCTYPE => Ok(Self::CopyTextureRegionUnrecognizedsrctype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCRESOURCEDIMENSION => Ok(Self::CopyTextureRegionInvalidsrcresourcedimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCRESOURCE => Ok(Self::CopyTextureRegionInvalidsrcresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCSUBRESOURCE => Ok(Self::CopyTextureRegionInvalidsrcSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCOFFSET => Ok(Self::CopyTextureRegionInvalidsrcoffset),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_UNRECOGNIZEDSRCFORMAT => Ok(Self::CopyTextureRegionUnrecognizedsrcformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCFORMAT => Ok(Self::CopyTextureRegionInvalidsrcformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCDIMENSIONS => Ok(Self::CopyTextureRegionInvalidsrcdimensions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCROWPITCH => Ok(Self::CopyTextureRegionInvalidsrcrowpitch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCPLACEMENT => Ok(Self::CopyTextureRegionInvalidsrcplacement),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCDSPLACEDFOOTPRINTFORMAT => Ok(Self::CopyTextureRegionInvalidsrcdsplacedfootprintformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_SRCREGIONOUTOFBOUNDS => Ok(Self::CopyTextureRegionSrcregionoutofbounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDDSTCOORDINATES => Ok(Self::CopyTextureRegionInvaliddstcoordinates),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDSRCBOX => Ok(Self::CopyTextureRegionInvalidsrcbox),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_FORMATMISMATCH => Ok(Self::CopyTextureRegionFormatmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_EMPTYBOX => Ok(Self::CopyTextureRegionEmptybox),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_INVALIDCOPYFLAGS => Ok(Self::CopyTextureRegionInvalidcopyflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_INVALID_SUBRESOURCE_INDEX => Ok(Self::ResolveSubresourceInvalidSubresourceIndex),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_INVALID_FORMAT => Ok(Self::ResolveSubresourceInvalidFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_RESOURCE_MISMATCH => Ok(Self::ResolveSubresourceResourceMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_INVALID_SAMPLE_COUNT => Ok(Self::ResolveSubresourceInvalidSampleCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMPUTEPIPELINESTATE_INVALID_SHADER => Ok(Self::CreateComputePipelineStateInvalidShader),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMPUTEPIPELINESTATE_CS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateComputePipelineStateCsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMPUTEPIPELINESTATE_MISSING_ROOT_SIGNATURE => Ok(Self::CreateComputePipelineStateMissingRootSignature),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_INVALIDCACHEDBLOB => Ok(Self::CreatePipelineStateInvalidcachedblob),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_CACHEDBLOBADAPTERMISMATCH => Ok(Self::CreatePipelineStateCachedblobadaptermismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_CACHEDBLOBDRIVERVERSIONMISMATCH => Ok(Self::CreatePipelineStateCachedblobdriverversionmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_CACHEDBLOBDESCMISMATCH => Ok(Self::CreatePipelineStateCachedblobdescmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_CACHEDBLOBIGNORED => Ok(Self::CreatePipelineStateCachedblobignored),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITETOSUBRESOURCE_INVALIDHEAP => Ok(Self::WriteToSubresourceInvalidheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITETOSUBRESOURCE_INVALIDRESOURCE => Ok(Self::WriteToSubresourceInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITETOSUBRESOURCE_INVALIDBOX => Ok(Self::WriteToSubresourceInvalidbox),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITETOSUBRESOURCE_INVALIDSUBRESOURCE => Ok(Self::WriteToSubresourceInvalidSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITETOSUBRESOURCE_EMPTYBOX => Ok(Self::WriteToSubresourceEmptybox),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_READFROMSUBRESOURCE_INVALIDHEAP => Ok(Self::ReadFromSubresourceInvalidheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_READFROMSUBRESOURCE_INVALIDRESOURCE => Ok(Self::ReadFromSubresourceInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_READFROMSUBRESOURCE_INVALIDBOX => Ok(Self::ReadFromSubresourceInvalidbox),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_READFROMSUBRESOURCE_INVALIDSUBRESOURCE => Ok(Self::ReadFromSubresourceInvalidSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_READFROMSUBRESOURCE_EMPTYBOX => Ok(Self::ReadFromSubresourceEmptybox),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_TOO_MANY_NODES_SPECIFIED => Ok(Self::TooManyNodesSpecified),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INVALID_NODE_INDEX => Ok(Self::InvalidNodeIndex),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETHEAPPROPERTIES_INVALIDRESOURCE => Ok(Self::GetheappropertiesInvalidresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_NODE_MASK_MISMATCH => Ok(Self::NodeMaskMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_OUTOFMEMORY => Ok(Self::CommandListOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_MULTIPLE_SWAPCHAIN_BUFFER_REFERENCES => Ok(Self::CommandListMultipleSwapchainBufferReferences),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_TOO_MANY_SWAPCHAIN_REFERENCES => Ok(Self::CommandListTooManySwapchainReferences),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_QUEUE_TOO_MANY_SWAPCHAIN_REFERENCES => Ok(Self::CommandQueueTooManySwapchainReferences),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTECOMMANDLISTS_WRONGSWAPCHAINBUFFERREFERENCE => Ok(Self::ExecuteCommandListsWrongswapchainbufferreference),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_SETRENDERTARGETS_INVALIDNUMRENDERTARGETS => Ok(Self::CommandListSetrendertargetsInvalidnumrendertargets),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_QUEUE_INVALID_TYPE => Ok(Self::CreateQueueInvalidType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_QUEUE_INVALID_FLAGS => Ok(Self::CreateQueueInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHAREDRESOURCE_INVALIDFLAGS => Ok(Self::CreateSharedResourceInvalidflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHAREDRESOURCE_INVALIDFORMAT => Ok(Self::CreateSharedResourceInvalidformat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHAREDHEAP_INVALIDFLAGS => Ok(Self::CreateSharedHeapInvalidflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_REFLECTSHAREDPROPERTIES_UNRECOGNIZEDPROPERTIES => Ok(Self::ReflectsharedpropertiesUnrecognizedproperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_REFLECTSHAREDPROPERTIES_INVALIDSIZE => Ok(Self::ReflectsharedpropertiesInvalidsize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_REFLECTSHAREDPROPERTIES_INVALIDOBJECT => Ok(Self::ReflectsharedpropertiesInvalidobject),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_KEYEDMUTEX_INVALIDOBJECT => Ok(Self::KeyedmutexInvalidobject),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_KEYEDMUTEX_INVALIDKEY => Ok(Self::KeyedmutexInvalidkey),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_KEYEDMUTEX_WRONGSTATE => Ok(Self::KeyedmutexWrongstate),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_QUEUE_INVALID_PRIORITY => Ok(Self::CreateQueueInvalidPriority),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OBJECT_DELETED_WHILE_STILL_IN_USE => Ok(Self::ObjectDeletedWhileStillInUse),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_INVALID_FLAGS => Ok(Self::CreatePipelineStateInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_HEAP_ADDRESS_RANGE_HAS_NO_RESOURCE => Ok(Self::HeapAddressRangeHasNoResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DRAW_RENDER_TARGET_DELETED => Ok(Self::CommandListDrawRenderTargetDeleted),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_ALL_RENDER_TARGETS_HAVE_UNKNOWN_FORMAT => Ok(Self::CreateGraphicsPipelineStateAllRenderTargetsHaveUnknownFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_HEAP_ADDRESS_RANGE_INTERSECTS_MULTIPLE_BUFFERS => Ok(Self::HeapAddressRangeIntersectsMultipleBuffers),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTECOMMANDLISTS_GPU_WRITTEN_READBACK_RESOURCE_MAPPED => Ok(Self::ExecuteCommandListsGpuWrittenReadbackResourceMapped),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNMAP_RANGE_NOT_EMPTY => Ok(Self::UnmapRangeNotEmpty),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MAP_INVALID_NULLRANGE => Ok(Self::MapInvalidNullrange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNMAP_INVALID_NULLRANGE => Ok(Self::UnmapInvalidNullrange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_NO_GRAPHICS_API_SUPPORT => Ok(Self::NoGraphicsApiSupport),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_NO_COMPUTE_API_SUPPORT => Ok(Self::NoComputeApiSupport),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_RESOURCE_FLAGS_NOT_SUPPORTED => Ok(Self::ResolveSubresourceResourceFlagsNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_ROOT_ARGUMENT_UNINITIALIZED => Ok(Self::GpuBasedValidationRootArgumentUninitialized),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_DESCRIPTOR_HEAP_INDEX_OUT_OF_BOUNDS => Ok(Self::GpuBasedValidationDescriptorHeapIndexOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_DESCRIPTOR_TABLE_REGISTER_INDEX_OUT_OF_BOUNDS => Ok(Self::GpuBasedValidationDescriptorTableRegisterIndexOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_DESCRIPTOR_UNINITIALIZED => Ok(Self::GpuBasedValidationDescriptorUninitialized),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_DESCRIPTOR_TYPE_MISMATCH => Ok(Self::GpuBasedValidationDescriptorTypeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_SRV_RESOURCE_DIMENSION_MISMATCH => Ok(Self::GpuBasedValidationSrvResourceDimensionMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_UAV_RESOURCE_DIMENSION_MISMATCH => Ok(Self::GpuBasedValidationUavResourceDimensionMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_INCOMPATIBLE_RESOURCE_STATE => Ok(Self::GpuBasedValidationIncompatibleResourceState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYRESOURCE_NULLDST => Ok(Self::CopyresourceNulldst),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYRESOURCE_INVALIDDSTRESOURCE => Ok(Self::CopyresourceInvaliddstresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYRESOURCE_NULLSRC => Ok(Self::CopyresourceNullsrc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYRESOURCE_INVALIDSRCRESOURCE => Ok(Self::CopyresourceInvalidsrcresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_NULLDST => Ok(Self::ResolveSubresourceNulldst),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_INVALIDDSTRESOURCE => Ok(Self::ResolveSubresourceInvaliddstresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_NULLSRC => Ok(Self::ResolveSubresourceNullsrc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_INVALIDSRCRESOURCE => Ok(Self::ResolveSubresourceInvalidsrcresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_PIPELINE_STATE_TYPE_MISMATCH => Ok(Self::PipelineStateTypeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DISPATCH_ROOT_SIGNATURE_NOT_SET => Ok(Self::CommandListDispatchRootSignatureNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DISPATCH_ROOT_SIGNATURE_MISMATCH => Ok(Self::CommandListDispatchRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_ZERO_BARRIERS => Ok(Self::ResourceBarrierZeroBarriers),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_BEGIN_END_EVENT_MISMATCH => Ok(Self::BeginEndEventMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_POSSIBLE_BEFORE_AFTER_MISMATCH => Ok(Self::ResourceBarrierPossibleBeforeAfterMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_MISMATCHING_BEGIN_END => Ok(Self::ResourceBarrierMismatchingBeginEnd),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_INVALID_RESOURCE => Ok(Self::GpuBasedValidationInvalidResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_USE_OF_ZERO_REFCOUNT_OBJECT => Ok(Self::UseOfZeroRefcountObject),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OBJECT_EVICTED_WHILE_STILL_IN_USE => Ok(Self::ObjectEvictedWhileStillInUse),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_ROOT_DESCRIPTOR_ACCESS_OUT_OF_BOUNDS => Ok(Self::GpuBasedValidationRootDescriptorAccessOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINELIBRARY_INVALIDLIBRARYBLOB => Ok(Self::CreatepipelinelibraryInvalidlibraryblob),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINELIBRARY_DRIVERVERSIONMISMATCH => Ok(Self::CreatepipelinelibraryDriverversionmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINELIBRARY_ADAPTERVERSIONMISMATCH => Ok(Self::CreatepipelinelibraryAdapterversionmismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINELIBRARY_UNSUPPORTED => Ok(Self::CreatepipelinelibraryUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_PIPELINELIBRARY => Ok(Self::CreatePipelinelibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_PIPELINELIBRARY => Ok(Self::LivePipelinelibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_PIPELINELIBRARY => Ok(Self::DestroyPipelinelibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_STOREPIPELINE_NONAME => Ok(Self::StorepipelineNoname),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_STOREPIPELINE_DUPLICATENAME => Ok(Self::StorepipelineDuplicatename),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LOADPIPELINE_NAMENOTFOUND => Ok(Self::LoadpipelineNamenotfound),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LOADPIPELINE_INVALIDDESC => Ok(Self::LoadpipelineInvaliddesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_PIPELINELIBRARY_SERIALIZE_NOTENOUGHMEMORY => Ok(Self::PipelinelibrarySerializeNotenoughmemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_PS_OUTPUT_RT_OUTPUT_MISMATCH => Ok(Self::CreateGraphicsPipelineStatePsOutputRtOutputMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETEVENTONMULTIPLEFENCECOMPLETION_INVALIDFLAGS => Ok(Self::SeteventonmultiplefencecompletionInvalidflags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_QUEUE_VIDEO_NOT_SUPPORTED => Ok(Self::CreateQueueVideoNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_ALLOCATOR_VIDEO_NOT_SUPPORTED => Ok(Self::CreateCommandAllocatorVideoNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEQUERY_HEAP_VIDEO_DECODE_STATISTICS_NOT_SUPPORTED => Ok(Self::CreatequeryHeapVideoDecodeStatisticsNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEODECODECOMMANDLIST => Ok(Self::CreateVideodecodeCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEODECODER => Ok(Self::CreateVideodecoder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEODECODESTREAM => Ok(Self::CreateVideodecodestream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEODECODECOMMANDLIST => Ok(Self::LiveVideodecodeCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEODECODER => Ok(Self::LiveVideodecoder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEODECODESTREAM => Ok(Self::LiveVideodecodestream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEODECODECOMMANDLIST => Ok(Self::DestroyVideodecodeCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEODECODER => Ok(Self::DestroyVideodecoder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEODECODESTREAM => Ok(Self::DestroyVideodecodestream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DECODE_FRAME_INVALID_PARAMETERS => Ok(Self::DecodeFrameInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEPRECATED_API => Ok(Self::DeprecatedApi),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_MISMATCHING_COMMAND_LIST_TYPE => Ok(Self::ResourceBarrierMismatchingCommandListType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_DESCRIPTOR_TABLE_NOT_SET => Ok(Self::CommandListDescriptorTableNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_ROOT_CONSTANT_BUFFER_VIEW_NOT_SET => Ok(Self::CommandListRootConstantBufferViewNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_ROOT_SHADER_RESOURCE_VIEW_NOT_SET => Ok(Self::CommandListRootShaderResourceViewNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_ROOT_UNORDERED_ACCESS_VIEW_NOT_SET => Ok(Self::CommandListRootUnorderedAccessViewNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DISCARD_INVALID_SUBRESOURCE_RANGE => Ok(Self::DiscardInvalidSubresourceRange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DISCARD_ONE_SUBRESOURCE_FOR_MIPS_WITH_RECTS => Ok(Self::DiscardOneSubresourceForMipsWithRects),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DISCARD_NO_RECTS_FOR_NON_TEXTURE2D => Ok(Self::DiscardNoRectsForNonTexture2D),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPY_ON_SAME_SUBRESOURCE => Ok(Self::CopyOnSameSubresource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETRESIDENCYPRIORITY_INVALID_PAGEABLE => Ok(Self::SetresidencypriorityInvalidPageable),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_UNSUPPORTED => Ok(Self::GpuBasedValidationUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_STATIC_DESCRIPTOR_INVALID_DESCRIPTOR_CHANGE => Ok(Self::StaticDescriptorInvalidDescriptorChange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DATA_STATIC_DESCRIPTOR_INVALID_DATA_CHANGE => Ok(Self::DataStaticDescriptorInvalidDataChange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DATA_STATIC_WHILE_SET_AT_EXECUTE_DESCRIPTOR_INVALID_DATA_CHANGE => Ok(Self::DataStaticWhileSetAtExecuteDescriptorInvalidDataChange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EXECUTE_BUNDLE_STATIC_DESCRIPTOR_DATA_STATIC_NOT_SET => Ok(Self::ExecuteBundleStaticDescriptorDataStaticNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_RESOURCE_ACCESS_OUT_OF_BOUNDS => Ok(Self::GpuBasedValidationResourceAccessOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_SAMPLER_MODE_MISMATCH => Ok(Self::GpuBasedValidationSamplerModeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_FENCE_INVALID_FLAGS => Ok(Self::CreateFenceInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_BARRIER_DUPLICATE_SUBRESOURCE_TRANSITIONS => Ok(Self::ResourceBarrierDuplicateSubresourceTransitions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETRESIDENCYPRIORITY_INVALID_PRIORITY => Ok(Self::SetresidencypriorityInvalidPriority),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_DESCRIPTOR_HEAP_LARGE_NUM_DESCRIPTORS => Ok(Self::CreateDescriptorHeapLargeNumDescriptors),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_BEGIN_EVENT => Ok(Self::BeginEvent),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_END_EVENT => Ok(Self::EndEvent),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEVICE_DEBUG_LAYER_STARTUP_OPTIONS => Ok(Self::CreatedeviceDebugLayerStartupOptions),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_DEPTHBOUNDSTEST_UNSUPPORTED => Ok(Self::CreatedepthstencilstateDepthboundstestUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_DUPLICATE_SUBOBJECT => Ok(Self::CreatePipelineStateDuplicateSubobject),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_UNKNOWN_SUBOBJECT => Ok(Self::CreatePipelineStateUnknownSubobject),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_ZERO_SIZE_STREAM => Ok(Self::CreatePipelineStateZeroSizeStream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_INVALID_STREAM => Ok(Self::CreatePipelineStateInvalidStream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_CANNOT_DEDUCE_TYPE => Ok(Self::CreatePipelineStateCannotDeduceType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_STATIC_DESCRIPTOR_RESOURCE_DIMENSION_MISMATCH => Ok(Self::CommandListStaticDescriptorResourceDimensionMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_QUEUE_INSUFFICIENT_PRIVILEGE_FOR_GLOBAL_REALTIME => Ok(Self::CreateCommandQueueInsufficientPrivilegeForGlobalRealtime),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_QUEUE_INSUFFICIENT_HARDWARE_SUPPORT_FOR_GLOBAL_REALTIME => Ok(Self::CreateCommandQueueInsufficientHardwareSupportForGlobalRealtime),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_ARCHITECTURE => Ok(Self::AtomiccopybufferInvalidArchitecture),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_NULL_DST => Ok(Self::AtomiccopybufferNullDst),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_DST_RESOURCE_DIMENSION => Ok(Self::AtomiccopybufferInvalidDstResourceDimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_DST_RANGE_OUT_OF_BOUNDS => Ok(Self::AtomiccopybufferDstRangeOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_NULL_SRC => Ok(Self::AtomiccopybufferNullSrc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_SRC_RESOURCE_DIMENSION => Ok(Self::AtomiccopybufferInvalidSrcResourceDimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_SRC_RANGE_OUT_OF_BOUNDS => Ok(Self::AtomiccopybufferSrcRangeOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_OFFSET_ALIGNMENT => Ok(Self::AtomiccopybufferInvalidOffsetAlignment),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_NULL_DEPENDENT_RESOURCES => Ok(Self::AtomiccopybufferNullDependentResources),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_NULL_DEPENDENT_SUBRESOURCE_RANGES => Ok(Self::AtomiccopybufferNullDependentSubresourceRanges),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_DEPENDENT_RESOURCE => Ok(Self::AtomiccopybufferInvalidDependentResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_DEPENDENT_SUBRESOURCE_RANGE => Ok(Self::AtomiccopybufferInvalidDependentSubresourceRange),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_DEPENDENT_SUBRESOURCE_OUT_OF_BOUNDS => Ok(Self::AtomiccopybufferDependentSubresourceOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_DEPENDENT_RANGE_OUT_OF_BOUNDS => Ok(Self::AtomiccopybufferDependentRangeOutOfBounds),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_ZERO_DEPENDENCIES => Ok(Self::AtomiccopybufferZeroDependencies),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DEVICE_CREATE_SHARED_HANDLE_INVALIDARG => Ok(Self::DeviceCreateSharedHandleInvalidarg),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESCRIPTOR_HANDLE_WITH_INVALID_RESOURCE => Ok(Self::DescriptorHandleWithInvalidResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETDEPTHBOUNDS_INVALIDARGS => Ok(Self::SetdepthboundsInvalidargs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GPU_BASED_VALIDATION_RESOURCE_STATE_IMPRECISE => Ok(Self::GpuBasedValidationResourceStateImprecise),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_LIST_PIPELINE_STATE_NOT_SET => Ok(Self::CommandListPipelineStateNotSet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_SHADER_MODEL_MISMATCH => Ok(Self::CreateGraphicsPipelineStateShaderModelMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OBJECT_ACCESSED_WHILE_STILL_IN_USE => Ok(Self::ObjectAccessedWhileStillInUse),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_PROGRAMMABLE_MSAA_UNSUPPORTED => Ok(Self::ProgrammableMsaaUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETSAMPLEPOSITIONS_INVALIDARGS => Ok(Self::SetsamplepositionsInvalidargs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCEREGION_INVALID_RECT => Ok(Self::ResolveSubresourceregionInvalidRect),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEODECODECOMMANDQUEUE => Ok(Self::CreateVideodecodecommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOPROCESSCOMMANDLIST => Ok(Self::CreateVideoprocessCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOPROCESSCOMMANDQUEUE => Ok(Self::CreateVideoprocesscommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEODECODECOMMANDQUEUE => Ok(Self::LiveVideodecodecommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOPROCESSCOMMANDLIST => Ok(Self::LiveVideoprocessCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOPROCESSCOMMANDQUEUE => Ok(Self::LiveVideoprocesscommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEODECODECOMMANDQUEUE => Ok(Self::DestroyVideodecodecommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOPROCESSCOMMANDLIST => Ok(Self::DestroyVideoprocessCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOPROCESSCOMMANDQUEUE => Ok(Self::DestroyVideoprocesscommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOPROCESSOR => Ok(Self::CreateVideoprocessor),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOPROCESSSTREAM => Ok(Self::CreateVideoprocessstream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOPROCESSOR => Ok(Self::LiveVideoprocessor),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOPROCESSSTREAM => Ok(Self::LiveVideoprocessstream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOPROCESSOR => Ok(Self::DestroyVideoprocessor),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOPROCESSSTREAM => Ok(Self::DestroyVideoprocessstream),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_PROCESS_FRAME_INVALID_PARAMETERS => Ok(Self::ProcessFrameInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPY_INVALIDLAYOUT => Ok(Self::CopyInvalidlayout),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_CRYPTO_SESSION => Ok(Self::CreateCryptoSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_CRYPTO_SESSION_POLICY => Ok(Self::CreateCryptoSessionPolicy),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_PROTECTED_RESOURCE_SESSION => Ok(Self::CreateProtectedResourceSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_CRYPTO_SESSION => Ok(Self::LiveCryptoSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_CRYPTO_SESSION_POLICY => Ok(Self::LiveCryptoSessionPolicy),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_PROTECTED_RESOURCE_SESSION => Ok(Self::LiveProtectedResourceSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_CRYPTO_SESSION => Ok(Self::DestroyCryptoSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_CRYPTO_SESSION_POLICY => Ok(Self::DestroyCryptoSessionPolicy),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_PROTECTED_RESOURCE_SESSION => Ok(Self::DestroyProtectedResourceSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_PROTECTED_RESOURCE_SESSION_UNSUPPORTED => Ok(Self::ProtectedResourceSessionUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_FENCE_INVALIDOPERATION => Ok(Self::FenceInvalidoperation),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEQUERY_HEAP_COPY_QUEUE_TIMESTAMPS_NOT_SUPPORTED => Ok(Self::CreatequeryHeapCopyQueueTimestampsNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLEPOSITIONS_MISMATCH_DEFERRED => Ok(Self::SamplepositionsMismatchDeferred),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLEPOSITIONS_MISMATCH_RECORDTIME_ASSUMEDFROMFIRSTUSE => Ok(Self::SamplepositionsMismatchRecordtimeAssumedfromfirstuse),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLEPOSITIONS_MISMATCH_RECORDTIME_ASSUMEDFROMCLEAR => Ok(Self::SamplepositionsMismatchRecordtimeAssumedfromclear),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEODECODERHEAP => Ok(Self::CreateVideodecoderheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEODECODERHEAP => Ok(Self::LiveVideodecoderheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEODECODERHEAP => Ok(Self::DestroyVideodecoderheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OPENEXISTINGHEAP_INVALIDARG_RETURN => Ok(Self::OpenexistingheapInvalidargReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OPENEXISTINGHEAP_OUTOFMEMORY_RETURN => Ok(Self::OpenexistingheapOutOfMemoryReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OPENEXISTINGHEAP_INVALIDADDRESS => Ok(Self::OpenexistingheapInvalidaddress),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OPENEXISTINGHEAP_INVALIDHANDLE => Ok(Self::OpenexistingheapInvalidhandle),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITEBUFFERIMMEDIATE_INVALID_DEST => Ok(Self::WritebufferimmediateInvalidDest),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITEBUFFERIMMEDIATE_INVALID_MODE => Ok(Self::WritebufferimmediateInvalidMode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITEBUFFERIMMEDIATE_INVALID_ALIGNMENT => Ok(Self::WritebufferimmediateInvalidAlignment),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITEBUFFERIMMEDIATE_NOT_SUPPORTED => Ok(Self::WritebufferimmediateNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETVIEWINSTANCEMASK_INVALIDARGS => Ok(Self::SetviewinstancemaskInvalidargs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIEW_INSTANCING_UNSUPPORTED => Ok(Self::ViewInstancingUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIEW_INSTANCING_INVALIDARGS => Ok(Self::ViewInstancingInvalidargs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_MISMATCH_DECODE_REFERENCE_ONLY_FLAG => Ok(Self::CopyTextureRegionMismatchDecodeReferenceOnlyFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYRESOURCE_MISMATCH_DECODE_REFERENCE_ONLY_FLAG => Ok(Self::CopyresourceMismatchDecodeReferenceOnlyFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_DECODE_HEAP_CAPS_FAILURE => Ok(Self::CreateVideoDecodeHeapCapsFailure),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_DECODE_HEAP_CAPS_UNSUPPORTED => Ok(Self::CreateVideoDecodeHeapCapsUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_DECODE_SUPPORT_INVALID_INPUT => Ok(Self::VideoDecodeSupportInvalidInput),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_DECODER_UNSUPPORTED => Ok(Self::CreateVideoDecoderUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_METADATA_ERROR => Ok(Self::CreateGraphicsPipelineStateMetadataError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_VIEW_INSTANCING_VERTEX_SIZE_EXCEEDED => Ok(Self::CreateGraphicsPipelineStateViewInstancingVertexSizeExceeded),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_RUNTIME_INTERNAL_ERROR => Ok(Self::CreateGraphicsPipelineStateRuntimeInternalError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_NO_VIDEO_API_SUPPORT => Ok(Self::NoVideoApiSupport),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_PROCESS_SUPPORT_INVALID_INPUT => Ok(Self::VideoProcessSupportInvalidInput),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_PROCESSOR_CAPS_FAILURE => Ok(Self::CreateVideoProcessorCapsFailure),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_PROCESS_SUPPORT_UNSUPPORTED_FORMAT => Ok(Self::VideoProcessSupportUnsupportedFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_DECODE_FRAME_INVALID_ARGUMENT => Ok(Self::VideoDecodeFrameInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ENQUEUE_MAKE_RESIDENT_INVALID_FLAGS => Ok(Self::EnqueueMakeResidentInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OPENEXISTINGHEAP_UNSUPPORTED => Ok(Self::OpenexistingheapUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_PROCESS_FRAMES_INVALID_ARGUMENT => Ok(Self::VideoProcessFramesInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_DECODE_SUPPORT_UNSUPPORTED => Ok(Self::VideoDecodeSupportUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMANDRECORDER => Ok(Self::CreateCommandrecorder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_COMMANDRECORDER => Ok(Self::LiveCommandrecorder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_COMMANDRECORDER => Ok(Self::DestroyCommandrecorder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_RECORDER_VIDEO_NOT_SUPPORTED => Ok(Self::CreateCommandRecorderVideoNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_RECORDER_INVALID_SUPPORT_FLAGS => Ok(Self::CreateCommandRecorderInvalidSupportFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_RECORDER_INVALID_FLAGS => Ok(Self::CreateCommandRecorderInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_RECORDER_MORE_RECORDERS_THAN_LOGICAL_PROCESSORS => Ok(Self::CreateCommandRecorderMoreRecordersThanLogicalProcessors),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMANDPOOL => Ok(Self::CreateCommandpool),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_COMMANDPOOL => Ok(Self::LiveCommandpool),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_COMMANDPOOL => Ok(Self::DestroyCommandpool),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_POOL_INVALID_FLAGS => Ok(Self::CreateCommandPoolInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_LIST_VIDEO_NOT_SUPPORTED => Ok(Self::CreateCommandListVideoNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_RECORDER_SUPPORT_FLAGS_MISMATCH => Ok(Self::CommandRecorderSupportFlagsMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_RECORDER_CONTENTION => Ok(Self::CommandRecorderContention),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_RECORDER_USAGE_WITH_CREATECOMMANDLIST_COMMAND_LIST => Ok(Self::CommandRecorderUsageWithCreateCommandListCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_ALLOCATOR_USAGE_WITH_CREATECOMMANDLIST1_COMMAND_LIST => Ok(Self::CommandAllocatorUsageWithCreateCommandList1CommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CANNOT_EXECUTE_EMPTY_COMMAND_LIST => Ok(Self::CannotExecuteEmptyCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CANNOT_RESET_COMMAND_POOL_WITH_OPEN_COMMAND_LISTS => Ok(Self::CannotResetCommandPoolWithOpenCommandLists),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CANNOT_USE_COMMAND_RECORDER_WITHOUT_CURRENT_TARGET => Ok(Self::CannotUseCommandRecorderWithoutCurrentTarget),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CANNOT_CHANGE_COMMAND_RECORDER_TARGET_WHILE_RECORDING => Ok(Self::CannotChangeCommandRecorderTargetWhileRecording),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMMAND_POOL_SYNC => Ok(Self::CommandPoolSync),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EVICT_UNDERFLOW => Ok(Self::EvictUnderflow),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_META_COMMAND => Ok(Self::CreateMetaCommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_META_COMMAND => Ok(Self::LiveMetaCommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_META_COMMAND => Ok(Self::DestroyMetaCommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_INVALID_DST_RESOURCE => Ok(Self::CopyBufferRegionInvalidDstResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYBUFFERREGION_INVALID_SRC_RESOURCE => Ok(Self::CopyBufferRegionInvalidSrcResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_DST_RESOURCE => Ok(Self::AtomiccopybufferInvalidDstResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ATOMICCOPYBUFFER_INVALID_SRC_RESOURCE => Ok(Self::AtomiccopybufferInvalidSrcResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_NULL_BUFFER => Ok(Self::CreateplacedresourceonbufferNullBuffer),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_NULL_RESOURCE_DESC => Ok(Self::CreateplacedresourceonbufferNullResourceDesc),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_UNSUPPORTED => Ok(Self::CreateplacedresourceonbufferUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_INVALID_BUFFER_DIMENSION => Ok(Self::CreateplacedresourceonbufferInvalidBufferDimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_INVALID_BUFFER_FLAGS => Ok(Self::CreateplacedresourceonbufferInvalidBufferFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_INVALID_BUFFER_OFFSET => Ok(Self::CreateplacedresourceonbufferInvalidBufferOffset),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_INVALID_RESOURCE_DIMENSION => Ok(Self::CreateplacedresourceonbufferInvalidResourceDimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_INVALID_RESOURCE_FLAGS => Ok(Self::CreateplacedresourceonbufferInvalidResourceFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPLACEDRESOURCEONBUFFER_OUTOFMEMORY_RETURN => Ok(Self::CreateplacedresourceonbufferOutOfMemoryReturn),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CANNOT_CREATE_GRAPHICS_AND_VIDEO_COMMAND_RECORDER => Ok(Self::CannotCreateGraphicsAndVideoCommandRecorder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UPDATETILEMAPPINGS_POSSIBLY_MISMATCHING_PROPERTIES => Ok(Self::UpdatetilemappingsPossiblyMismatchingProperties),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_LIST_INVALID_COMMAND_LIST_TYPE => Ok(Self::CreateCommandListInvalidCommandListType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLEARUNORDEREDACCESSVIEW_INCOMPATIBLE_WITH_STRUCTURED_BUFFERS => Ok(Self::ClearunorderedaccessviewIncompatibleWithStructuredBuffers),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COMPUTE_ONLY_DEVICE_OPERATION_UNSUPPORTED => Ok(Self::ComputeOnlyDeviceOperationUnsupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_INVALID => Ok(Self::BuildRaytracingAccelerationStructureInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EMIT_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_INVALID => Ok(Self::EmitRaytracingAccelerationStructurePostbuildInfoInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPY_RAYTRACING_ACCELERATION_STRUCTURE_INVALID => Ok(Self::CopyRaytracingAccelerationStructureInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DISPATCH_RAYS_INVALID => Ok(Self::DispatchRaysInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GET_RAYTRACING_ACCELERATION_STRUCTURE_PREBUILD_INFO_INVALID => Ok(Self::GetRaytracingAccelerationStructurePrebuildInfoInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_LIFETIMETRACKER => Ok(Self::CreateLifetimetracker),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_LIFETIMETRACKER => Ok(Self::LiveLifetimetracker),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_LIFETIMETRACKER => Ok(Self::DestroyLifetimetracker),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROYOWNEDOBJECT_OBJECTNOTOWNED => Ok(Self::DestroyownedobjectObjectnotowned),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_TRACKEDWORKLOAD => Ok(Self::CreateTrackedworkload),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_TRACKEDWORKLOAD => Ok(Self::LiveTrackedworkload),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_TRACKEDWORKLOAD => Ok(Self::DestroyTrackedworkload),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_ERROR => Ok(Self::RenderPassError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_META_COMMAND_ID_INVALID => Ok(Self::MetaCommandIdInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_META_COMMAND_UNSUPPORTED_PARAMS => Ok(Self::MetaCommandUnsupportedParams),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_META_COMMAND_FAILED_ENUMERATION => Ok(Self::MetaCommandFailedEnumeration),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_META_COMMAND_PARAMETER_SIZE_MISMATCH => Ok(Self::MetaCommandParameterSizeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_UNINITIALIZED_META_COMMAND => Ok(Self::UninitializedMetaCommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_META_COMMAND_INVALID_GPU_VIRTUAL_ADDRESS => Ok(Self::MetaCommandInvalidGpuVirtualAddress),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOENCODECOMMANDLIST => Ok(Self::CreateVideoencodeCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOENCODECOMMANDLIST => Ok(Self::LiveVideoencodeCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOENCODECOMMANDLIST => Ok(Self::DestroyVideoencodeCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOENCODECOMMANDQUEUE => Ok(Self::CreateVideoencodecommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOENCODECOMMANDQUEUE => Ok(Self::LiveVideoencodecommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOENCODECOMMANDQUEUE => Ok(Self::DestroyVideoencodecommandqueue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOMOTIONESTIMATOR => Ok(Self::CreateVideomotionestimator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOMOTIONESTIMATOR => Ok(Self::LiveVideomotionestimator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOMOTIONESTIMATOR => Ok(Self::DestroyVideomotionestimator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOMOTIONVECTORHEAP => Ok(Self::CreateVideomotionvectorheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOMOTIONVECTORHEAP => Ok(Self::LiveVideomotionvectorheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOMOTIONVECTORHEAP => Ok(Self::DestroyVideomotionvectorheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MULTIPLE_TRACKED_WORKLOADS => Ok(Self::MultipleTrackedWorkloads),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MULTIPLE_TRACKED_WORKLOAD_PAIRS => Ok(Self::MultipleTrackedWorkloadPairs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OUT_OF_ORDER_TRACKED_WORKLOAD_PAIR => Ok(Self::OutOfOrderTrackedWorkloadPair),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CANNOT_ADD_TRACKED_WORKLOAD => Ok(Self::CannotAddTrackedWorkload),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INCOMPLETE_TRACKED_WORKLOAD_PAIR => Ok(Self::IncompleteTrackedWorkloadPair),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_STATE_OBJECT_ERROR => Ok(Self::CreateStateObjectError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GET_SHADER_IDENTIFIER_ERROR => Ok(Self::GetShaderIdentifierError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GET_SHADER_STACK_SIZE_ERROR => Ok(Self::GetShaderStackSizeError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GET_PIPELINE_STACK_SIZE_ERROR => Ok(Self::GetPipelineStackSizeError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_PIPELINE_STACK_SIZE_ERROR => Ok(Self::SetPipelineStackSizeError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GET_SHADER_IDENTIFIER_SIZE_INVALID => Ok(Self::GetShaderIdentifierSizeInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CHECK_DRIVER_MATCHING_IDENTIFIER_INVALID => Ok(Self::CheckDriverMatchingIdentifierInvalid),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CHECK_DRIVER_MATCHING_IDENTIFIER_DRIVER_REPORTED_ISSUE => Ok(Self::CheckDriverMatchingIdentifierDriverReportedIssue),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_INVALID_RESOURCE_BARRIER => Ok(Self::RenderPassInvalidResourceBarrier),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_DISALLOWED_API_CALLED => Ok(Self::RenderPassDisallowedApiCalled),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_CANNOT_NEST_RENDER_PASSES => Ok(Self::RenderPassCannotNestRenderPasses),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_CANNOT_END_WITHOUT_BEGIN => Ok(Self::RenderPassCannotEndWithoutBegin),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_CANNOT_CLOSE_COMMAND_LIST => Ok(Self::RenderPassCannotCloseCommandList),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_GPU_WORK_WHILE_SUSPENDED => Ok(Self::RenderPassGpuWorkWhileSuspended),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_MISMATCHING_SUSPEND_RESUME => Ok(Self::RenderPassMismatchingSuspendResume),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_NO_PRIOR_SUSPEND_WITHIN_EXECUTECOMMANDLISTS => Ok(Self::RenderPassNoPriorSuspendWithinExecuteCommandLists),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_NO_SUBSEQUENT_RESUME_WITHIN_EXECUTECOMMANDLISTS => Ok(Self::RenderPassNoSubsequentResumeWithinExecuteCommandLists),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_TRACKED_WORKLOAD_COMMAND_QUEUE_MISMATCH => Ok(Self::TrackedWorkloadCommandQueueMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_TRACKED_WORKLOAD_NOT_SUPPORTED => Ok(Self::TrackedWorkloadNotSupported),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_MISMATCHING_NO_ACCESS => Ok(Self::RenderPassMismatchingNoAccess),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RENDER_PASS_UNSUPPORTED_RESOLVE => Ok(Self::RenderPassUnsupportedResolve),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLEARUNORDEREDACCESSVIEW_INVALID_RESOURCE_PTR => Ok(Self::ClearunorderedaccessviewInvalidResourcePtr),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WINDOWS7_FENCE_OUTOFORDER_SIGNAL => Ok(Self::Windows7FenceOutoforderSignal),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WINDOWS7_FENCE_OUTOFORDER_WAIT => Ok(Self::Windows7FenceOutoforderWait),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_CREATE_MOTION_ESTIMATOR_INVALID_ARGUMENT => Ok(Self::VideoCreateMotionEstimatorInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_CREATE_MOTION_VECTOR_HEAP_INVALID_ARGUMENT => Ok(Self::VideoCreateMotionVectorHeapInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ESTIMATE_MOTION_INVALID_ARGUMENT => Ok(Self::EstimateMotionInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVE_MOTION_VECTOR_HEAP_INVALID_ARGUMENT => Ok(Self::ResolveMotionVectorHeapInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GETGPUVIRTUALADDRESS_INVALID_HEAP_TYPE => Ok(Self::GetgpuvirtualaddressInvalidHeapType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SET_BACKGROUND_PROCESSING_MODE_INVALID_ARGUMENT => Ok(Self::SetBackgroundProcessingModeInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_COMMAND_LIST_INVALID_COMMAND_LIST_TYPE_FOR_FEATURE_LEVEL => Ok(Self::CreateCommandListInvalidCommandListTypeForFeatureLevel),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOEXTENSIONCOMMAND => Ok(Self::CreateVideoextensioncommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOEXTENSIONCOMMAND => Ok(Self::LiveVideoextensioncommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOEXTENSIONCOMMAND => Ok(Self::DestroyVideoextensioncommand),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_INVALID_VIDEO_EXTENSION_COMMAND_ID => Ok(Self::InvalidVideoExtensionCommandId),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VIDEO_EXTENSION_COMMAND_INVALID_ARGUMENT => Ok(Self::VideoExtensionCommandInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOT_SIGNATURE_NOT_UNIQUE_IN_DXIL_LIBRARY => Ok(Self::CreateRootSignatureNotUniqueInDxilLibrary),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VARIABLE_SHADING_RATE_NOT_ALLOWED_WITH_TIR => Ok(Self::VariableShadingRateNotAllowedWithTir),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_GEOMETRY_SHADER_OUTPUTTING_BOTH_VIEWPORT_ARRAY_INDEX_AND_SHADING_RATE_NOT_SUPPORTED_ON_DEVICE => Ok(Self::GeometryShaderOutputtingBothViewportArrayIndexAndShadingRateNotSupportedOnDevice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RSSETSHADING_RATE_INVALID_SHADING_RATE => Ok(Self::RssetshadingRateInvalidShadingRate),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RSSETSHADING_RATE_SHADING_RATE_NOT_PERMITTED_BY_CAP => Ok(Self::RssetshadingRateShadingRateNotPermittedByCap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RSSETSHADING_RATE_INVALID_COMBINER => Ok(Self::RssetshadingRateInvalidCombiner),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RSSETSHADINGRATEIMAGE_REQUIRES_TIER_2 => Ok(Self::RssetshadingrateimageRequiresTier2),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RSSETSHADINGRATE_REQUIRES_TIER_1 => Ok(Self::RssetshadingrateRequiresTier1),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADING_RATE_IMAGE_INCORRECT_FORMAT => Ok(Self::ShadingRateImageIncorrectFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADING_RATE_IMAGE_INCORRECT_ARRAY_SIZE => Ok(Self::ShadingRateImageIncorrectArraySize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADING_RATE_IMAGE_INCORRECT_MIP_LEVEL => Ok(Self::ShadingRateImageIncorrectMipLevel),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADING_RATE_IMAGE_INCORRECT_SAMPLE_COUNT => Ok(Self::ShadingRateImageIncorrectSampleCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADING_RATE_IMAGE_INCORRECT_SAMPLE_QUALITY => Ok(Self::ShadingRateImageIncorrectSampleQuality),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_NON_RETAIL_SHADER_MODEL_WONT_VALIDATE => Ok(Self::NonRetailShaderModelWontValidate),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_AS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateAsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_MS_ROOT_SIGNATURE_MISMATCH => Ok(Self::CreateGraphicsPipelineStateMsRootSignatureMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ADD_TO_STATE_OBJECT_ERROR => Ok(Self::AddToStateObjectError),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_PROTECTED_RESOURCE_SESSION_INVALID_ARGUMENT => Ok(Self::CreateProtectedResourceSessionInvalidArgument),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_MS_PSO_DESC_MISMATCH => Ok(Self::CreateGraphicsPipelineStateMsPsoDescMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEPIPELINESTATE_MS_INCOMPLETE_TYPE => Ok(Self::CreatePipelineStateMsIncompleteType),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_AS_NOT_MS_MISMATCH => Ok(Self::CreateGraphicsPipelineStateAsNotMsMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_MS_NOT_PS_MISMATCH => Ok(Self::CreateGraphicsPipelineStateMsNotPsMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_NONZERO_SAMPLER_FEEDBACK_MIP_REGION_WITH_INCOMPATIBLE_FORMAT => Ok(Self::NonzeroSamplerFeedbackMipRegionWithIncompatibleFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEGRAPHICSPIPELINESTATE_INPUTLAYOUT_SHADER_MISMATCH => Ok(Self::CreateGraphicsPipelineStateInputlayoutShaderMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_EMPTY_DISPATCH => Ok(Self::EmptyDispatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOURCE_FORMAT_REQUIRES_SAMPLER_FEEDBACK_CAPABILITY => Ok(Self::ResourceFormatRequiresSamplerFeedbackCapability),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_MAP_INVALID_MIP_REGION => Ok(Self::SamplerFeedbackMapInvalidMipRegion),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_MAP_INVALID_DIMENSION => Ok(Self::SamplerFeedbackMapInvalidDimension),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_MAP_INVALID_SAMPLE_COUNT => Ok(Self::SamplerFeedbackMapInvalidSampleCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_MAP_INVALID_SAMPLE_QUALITY => Ok(Self::SamplerFeedbackMapInvalidSampleQuality),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_MAP_INVALID_LAYOUT => Ok(Self::SamplerFeedbackMapInvalidLayout),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_MAP_REQUIRES_UNORDERED_ACCESS_FLAG => Ok(Self::SamplerFeedbackMapRequiresUnorderedAccessFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_CREATE_UAV_NULL_ARGUMENTS => Ok(Self::SamplerFeedbackCreateUavNullArguments),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_UAV_REQUIRES_SAMPLER_FEEDBACK_CAPABILITY => Ok(Self::SamplerFeedbackUavRequiresSamplerFeedbackCapability),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_CREATE_UAV_REQUIRES_FEEDBACK_MAP_FORMAT => Ok(Self::SamplerFeedbackCreateUavRequiresFeedbackMapFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEMESHSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreateMeshShaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEMESHSHADER_OUTOFMEMORY => Ok(Self::CreateMeshShaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEMESHSHADERWITHSTREAMOUTPUT_INVALIDSHADERTYPE => Ok(Self::CreateMeshShaderWithStreamOutputInvalidshadertype),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_SAMPLER_FEEDBACK_TRANSCODE_INVALID_FORMAT => Ok(Self::ResolveSubresourceSamplerFeedbackTranscodeInvalidFormat),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_SAMPLER_FEEDBACK_INVALID_MIP_LEVEL_COUNT => Ok(Self::ResolveSubresourceSamplerFeedbackInvalidMipLevelCount),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVESUBRESOURCE_SAMPLER_FEEDBACK_TRANSCODE_ARRAY_SIZE_MISMATCH => Ok(Self::ResolveSubresourceSamplerFeedbackTranscodeArraySizeMismatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SAMPLER_FEEDBACK_CREATE_UAV_MISMATCHING_TARGETED_RESOURCE => Ok(Self::SamplerFeedbackCreateUavMismatchingTargetedResource),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEMESHSHADER_OUTPUTEXCEEDSMAXSIZE => Ok(Self::CreateMeshShaderOutputexceedsmaxsize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEMESHSHADER_GROUPSHAREDEXCEEDSMAXSIZE => Ok(Self::CreateMeshShaderGroupsharedexceedsmaxsize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_VERTEX_SHADER_OUTPUTTING_BOTH_VIEWPORT_ARRAY_INDEX_AND_SHADING_RATE_NOT_SUPPORTED_ON_DEVICE => Ok(Self::VertexShaderOutputtingBothViewportArrayIndexAndShadingRateNotSupportedOnDevice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_MESH_SHADER_OUTPUTTING_BOTH_VIEWPORT_ARRAY_INDEX_AND_SHADING_RATE_NOT_SUPPORTED_ON_DEVICE => Ok(Self::MeshShaderOutputtingBothViewportArrayIndexAndShadingRateNotSupportedOnDevice),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEMESHSHADER_MISMATCHEDASMSPAYLOADSIZE => Ok(Self::CreateMeshShaderMismatchedAsMsPayloadSize),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_ROOT_SIGNATURE_UNBOUNDED_STATIC_DESCRIPTORS => Ok(Self::CreateRootSignatureUnboundedStaticDescriptors),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEAMPLIFICATIONSHADER_INVALIDSHADERBYTECODE => Ok(Self::CreateAmplificationShaderInvalidShaderBytecode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEAMPLIFICATIONSHADER_OUTOFMEMORY => Ok(Self::CreateAmplificationShaderOutOfMemory),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_SHADERCACHESESSION => Ok(Self::CreateShaderCacheSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_SHADERCACHESESSION => Ok(Self::LiveShaderCacheSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_SHADERCACHESESSION => Ok(Self::DestroyShaderCacheSession),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERCACHESESSION_INVALIDARGS => Ok(Self::CreateShaderCacheSessionInvalidargs),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERCACHESESSION_DISABLED => Ok(Self::CreateShaderCacheSessionDisabled),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATESHADERCACHESESSION_ALREADYOPEN => Ok(Self::CreateShaderCacheSessionAlreadyopen),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHECONTROL_DEVELOPERMODE => Ok(Self::ShaderCacheControlDeveloperMode),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHECONTROL_INVALIDFLAGS => Ok(Self::ShaderCacheControlInvalidFlags),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHECONTROL_STATEALREADYSET => Ok(Self::ShaderCacheControlStatealReadySet),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHECONTROL_IGNOREDFLAG => Ok(Self::ShaderCacheControlIgnoredFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHESESSION_STOREVALUE_ALREADYPRESENT => Ok(Self::ShaderCacheSessionStoreValueAlreadyPresent),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHESESSION_STOREVALUE_HASHCOLLISION => Ok(Self::ShadercachesessionStorevalueHashCollision),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHESESSION_STOREVALUE_CACHEFULL => Ok(Self::ShaderCacheSessionStoreValueCacheFull),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHESESSION_FINDVALUE_NOTFOUND => Ok(Self::ShaderCacheSessionFindValueNotFound),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHESESSION_CORRUPT => Ok(Self::ShaderCacheSessionCorrupt),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SHADERCACHESESSION_DISABLED => Ok(Self::ShaderCacheSessionDisabled),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_OVERSIZED_DISPATCH => Ok(Self::OversizedDispatch),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOENCODER => Ok(Self::CreateVideoEncoder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOENCODER => Ok(Self::LiveVideoEncoder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOENCODER => Ok(Self::DestroyVideoEncoder),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEOENCODERHEAP => Ok(Self::CreateVideoEncoderheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_LIVE_VIDEOENCODERHEAP => Ok(Self::LiveVideoEncoderheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESTROY_VIDEOENCODERHEAP => Ok(Self::DestroyVideoEncoderheap),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYTEXTUREREGION_MISMATCH_ENCODE_REFERENCE_ONLY_FLAG => Ok(Self::CopyTextureRegionMismatchEncodeReferenceOnlyFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_COPYRESOURCE_MISMATCH_ENCODE_REFERENCE_ONLY_FLAG => Ok(Self::CopyresourceMismatchEncodeReferenceOnlyFlag),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ENCODE_FRAME_INVALID_PARAMETERS => Ok(Self::EncodeFrameInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_ENCODE_FRAME_UNSUPPORTED_PARAMETERS => Ok(Self::EncodeFrameUnsupportedParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVE_ENCODER_OUTPUT_METADATA_INVALID_PARAMETERS => Ok(Self::ResolveEncoderOutputMetadataInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVE_ENCODER_OUTPUT_METADATA_UNSUPPORTED_PARAMETERS => Ok(Self::ResolveEncoderOutputMetadataUnsupportedParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_ENCODER_INVALID_PARAMETERS => Ok(Self::CreateVideoEncoderInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_ENCODER_UNSUPPORTED_PARAMETERS => Ok(Self::CreateVideoEncoderUnsupportedParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_ENCODER_HEAP_INVALID_PARAMETERS => Ok(Self::CreateVideoEncoderHeapInvalidParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATE_VIDEO_ENCODER_HEAP_UNSUPPORTED_PARAMETERS => Ok(Self::CreateVideoEncoderHeapUnsupportedParameters),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATECOMMANDLIST_NULL_COMMANDALLOCATOR => Ok(Self::CreateCommandListNullCommandallocator),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CLEAR_UNORDERED_ACCESS_VIEW_INVALID_DESCRIPTOR_HANDLE => Ok(Self::ClearUnorderedAccessViewInvalidDescriptorHandle),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_DESCRIPTOR_HEAP_NOT_SHADER_VISIBLE => Ok(Self::DescriptorHeapNotShaderVisible),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_BLENDOP_WARNING => Ok(Self::CreateblendstateBlendopWarning),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_CREATEBLENDSTATE_BLENDOPALPHA_WARNING => Ok(Self::CreateblendstateBlendopalphaWarning),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_WRITE_COMBINE_PERFORMANCE_WARNING => Ok(Self::WriteCombinePerformanceWarning),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_RESOLVE_QUERY_INVALID_QUERY_STATE => Ok(Self::ResolveQueryInvalidQueryState),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_SETPRIVATEDATA_NO_ACCESS => Ok(Self::SetPrivateDataNoAccess),
+            D3D12_MESSAGE_ID_D3D12_MESSAGE_ID_D3D12_MESSAGES_END => Ok(Self::D3D12MessagesEnd),
+            _ => Err(raw),
+        }
+    }
+}
+
 #[repr(i32)]
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -2497,6 +3708,83 @@ pub enum ResourceHeapTier {
     Tier2 = D3D12_RESOURCE_HEAP_TIER_D3D12_RESOURCE_HEAP_TIER_2,
 }
 
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum MeshShaderTier {
+    NotSupported = D3D12_MESH_SHADER_TIER_D3D12_MESH_SHADER_TIER_NOT_SUPPORTED,
+    Tier1 = D3D12_MESH_SHADER_TIER_D3D12_MESH_SHADER_TIER_1,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum SamplerFeedbackTier {
+    NotSupported =
+        D3D12_SAMPLER_FEEDBACK_TIER_D3D12_SAMPLER_FEEDBACK_TIER_NOT_SUPPORTED,
+    Tier0_9 = D3D12_SAMPLER_FEEDBACK_TIER_D3D12_SAMPLER_FEEDBACK_TIER_0_9,
+    Tier1_0 = D3D12_SAMPLER_FEEDBACK_TIER_D3D12_SAMPLER_FEEDBACK_TIER_1_0,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum RaytracingTier {
+    NotSupported = D3D12_RAYTRACING_TIER_D3D12_RAYTRACING_TIER_NOT_SUPPORTED,
+    Tier1_0 = D3D12_RAYTRACING_TIER_D3D12_RAYTRACING_TIER_1_0,
+    Tier1_1 = D3D12_RAYTRACING_TIER_D3D12_RAYTRACING_TIER_1_1,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum RenderPassTier {
+    Tier0 = D3D12_RENDER_PASS_TIER_D3D12_RENDER_PASS_TIER_0,
+    Tier1 = D3D12_RENDER_PASS_TIER_D3D12_RENDER_PASS_TIER_1,
+    Tier2 = D3D12_RENDER_PASS_TIER_D3D12_RENDER_PASS_TIER_2,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum VariableShadingRateTier {
+    NotSupported = D3D12_VARIABLE_SHADING_RATE_TIER_D3D12_VARIABLE_SHADING_RATE_TIER_NOT_SUPPORTED,
+    Tier1 = D3D12_VARIABLE_SHADING_RATE_TIER_D3D12_VARIABLE_SHADING_RATE_TIER_1,
+    Tier2 = D3D12_VARIABLE_SHADING_RATE_TIER_D3D12_VARIABLE_SHADING_RATE_TIER_2,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ShadingRate {
+    Rate1x1 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_1X1,
+    Rate1x2 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_1X2,
+    Rate2x1 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_2X1,
+    Rate2x2 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_2X2,
+    Rate2x4 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_2X4,
+    Rate4x2 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_4X2,
+    Rate4x4 = D3D12_SHADING_RATE_D3D12_SHADING_RATE_4X4,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ShadingRateCombiner {
+    Passthrough =
+        D3D12_SHADING_RATE_COMBINER_D3D12_SHADING_RATE_COMBINER_PASSTHROUGH,
+    Override = D3D12_SHADING_RATE_COMBINER_D3D12_SHADING_RATE_COMBINER_OVERRIDE,
+    Min = D3D12_SHADING_RATE_COMBINER_D3D12_SHADING_RATE_COMBINER_MIN,
+    Max = D3D12_SHADING_RATE_COMBINER_D3D12_SHADING_RATE_COMBINER_MAX,
+    Sum = D3D12_SHADING_RATE_COMBINER_D3D12_SHADING_RATE_COMBINER_SUM,
+}
+
 bitflags! {
     pub struct Usage: u32 {
         const ShaderInput = DXGI_USAGE_SHADER_INPUT;
@@ -2522,6 +3810,116 @@ pub enum Scaling {
     AspectRatioStretch = DXGI_SCALING_DXGI_SCALING_ASPECT_RATIO_STRETCH,
 }
 
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ColorSpaceType {
+    RgbFullG22NoneP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_FULL_G22_NONE_P709,
+    RgbFullG10NoneP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_FULL_G10_NONE_P709,
+    RgbStudioG22NoneP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_STUDIO_G22_NONE_P709,
+    RgbStudioG22NoneP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_STUDIO_G22_NONE_P2020,
+    Reserved = DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RESERVED,
+    YcbcrFullG22NoneP709X601 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_FULL_G22_NONE_P709_X601,
+    YcbcrStudioG22LeftP601 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G22_LEFT_P601,
+    YcbcrFullG22LeftP601 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_FULL_G22_LEFT_P601,
+    YcbcrStudioG22LeftP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G22_LEFT_P709,
+    YcbcrFullG22LeftP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_FULL_G22_LEFT_P709,
+    YcbcrStudioG22LeftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G22_LEFT_P2020,
+    YcbcrFullG22LeftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_FULL_G22_LEFT_P2020,
+    RgbFullG2084NoneP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_FULL_G2084_NONE_P2020,
+    YcbcrStudioG2084LeftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G2084_LEFT_P2020,
+    RgbStudioG2084NoneP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_STUDIO_G2084_NONE_P2020,
+    YcbcrStudioG22TopleftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G22_TOPLEFT_P2020,
+    YcbcrStudioG2084TopleftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G2084_TOPLEFT_P2020,
+    RgbFullG22NoneP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_FULL_G22_NONE_P2020,
+    YcbcrStudioGhlgTopleftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_GHLG_TOPLEFT_P2020,
+    YcbcrFullGhlgTopleftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_FULL_GHLG_TOPLEFT_P2020,
+    RgbStudioG24NoneP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_STUDIO_G24_NONE_P709,
+    RgbStudioG24NoneP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_RGB_STUDIO_G24_NONE_P2020,
+    YcbcrStudioG24LeftP709 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G24_LEFT_P709,
+    YcbcrStudioG24LeftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G24_LEFT_P2020,
+    YcbcrStudioG24TopleftP2020 =
+        DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_YCBCR_STUDIO_G24_TOPLEFT_P2020,
+    Custom = DXGI_COLOR_SPACE_TYPE_DXGI_COLOR_SPACE_CUSTOM,
+}
+
+bitflags! {
+    pub struct SwapChainColorSpaceSupportFlags: i32 {
+        const None = 0;
+        const Present = DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_PRESENT;
+        const OverlayPresent = DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_OVERLAY_PRESENT;
+    }
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ModeScanlineOrder {
+    Unspecified = DXGI_MODE_SCANLINE_ORDER_DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED,
+    Progressive = DXGI_MODE_SCANLINE_ORDER_DXGI_MODE_SCANLINE_ORDER_PROGRESSIVE,
+    UpperFieldFirst =
+        DXGI_MODE_SCANLINE_ORDER_DXGI_MODE_SCANLINE_ORDER_UPPER_FIELD_FIRST,
+    LowerFieldFirst =
+        DXGI_MODE_SCANLINE_ORDER_DXGI_MODE_SCANLINE_ORDER_LOWER_FIELD_FIRST,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ModeScaling {
+    Unspecified = DXGI_MODE_SCALING_DXGI_MODE_SCALING_UNSPECIFIED,
+    Centered = DXGI_MODE_SCALING_DXGI_MODE_SCALING_CENTERED,
+    Stretched = DXGI_MODE_SCALING_DXGI_MODE_SCALING_STRETCHED,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ModeRotation {
+    Unspecified = DXGI_MODE_ROTATION_DXGI_MODE_ROTATION_UNSPECIFIED,
+    Identity = DXGI_MODE_ROTATION_DXGI_MODE_ROTATION_IDENTITY,
+    Rotate90 = DXGI_MODE_ROTATION_DXGI_MODE_ROTATION_ROTATE90,
+    Rotate180 = DXGI_MODE_ROTATION_DXGI_MODE_ROTATION_ROTATE180,
+    Rotate270 = DXGI_MODE_ROTATION_DXGI_MODE_ROTATION_ROTATE270,
+}
+
+bitflags! {
+    pub struct EnumModesFlags: u32 {
+        const None = 0;
+        const Interlaced = DXGI_ENUM_MODES_INTERLACED;
+        const Scaling = DXGI_ENUM_MODES_SCALING;
+        const Stereo = DXGI_ENUM_MODES_STEREO;
+        const DisabledStereo = DXGI_ENUM_MODES_DISABLED_STEREO;
+    }
+}
+
 #[repr(i32)]
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -2556,6 +3954,46 @@ pub enum AdapterFlag {
     ForceDword = DXGI_ADAPTER_FLAG_DXGI_ADAPTER_FLAG_FORCE_DWORD,
 }
 
+bitflags! {
+    pub struct AdapterFlags3: i32 {
+        const None = DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_NONE;
+        const Remote = DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_REMOTE;
+        const Software = DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_SOFTWARE;
+        const AcgCompatible =
+            DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_ACG_COMPATIBLE;
+        const SupportMonitoredFences =
+            DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_SUPPORT_MONITORED_FENCES;
+        const SupportNonMonitoredFences =
+            DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_SUPPORT_NON_MONITORED_FENCES;
+        const KeyedMutexConformance =
+            DXGI_ADAPTER_FLAG3_DXGI_ADAPTER_FLAG3_KEYED_MUTEX_CONFORMANCE;
+    }
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum GraphicsPreemptionGranularity {
+    DmaBufferBoundary = DXGI_GRAPHICS_PREEMPTION_GRANULARITY_DXGI_GRAPHICS_PREEMPTION_DMA_BUFFER_BOUNDARY,
+    PrimitiveBoundary = DXGI_GRAPHICS_PREEMPTION_GRANULARITY_DXGI_GRAPHICS_PREEMPTION_PRIMITIVE_BOUNDARY,
+    TriangleBoundary = DXGI_GRAPHICS_PREEMPTION_GRANULARITY_DXGI_GRAPHICS_PREEMPTION_TRIANGLE_BOUNDARY,
+    PixelBoundary = DXGI_GRAPHICS_PREEMPTION_GRANULARITY_DXGI_GRAPHICS_PREEMPTION_PIXEL_BOUNDARY,
+    InstructionBoundary = DXGI_GRAPHICS_PREEMPTION_GRANULARITY_DXGI_GRAPHICS_PREEMPTION_INSTRUCTION_BOUNDARY,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ComputePreemptionGranularity {
+    DmaBufferBoundary = DXGI_COMPUTE_PREEMPTION_GRANULARITY_DXGI_COMPUTE_PREEMPTION_DMA_BUFFER_BOUNDARY,
+    DispatchBoundary = DXGI_COMPUTE_PREEMPTION_GRANULARITY_DXGI_COMPUTE_PREEMPTION_DISPATCH_BOUNDARY,
+    ThreadGroupBoundary = DXGI_COMPUTE_PREEMPTION_GRANULARITY_DXGI_COMPUTE_PREEMPTION_THREAD_GROUP_BOUNDARY,
+    ThreadBoundary = DXGI_COMPUTE_PREEMPTION_GRANULARITY_DXGI_COMPUTE_PREEMPTION_THREAD_BOUNDARY,
+    InstructionBoundary = DXGI_COMPUTE_PREEMPTION_GRANULARITY_DXGI_COMPUTE_PREEMPTION_INSTRUCTION_BOUNDARY,
+}
+
 #[repr(i32)]
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
@@ -2644,3 +4082,134 @@ bitflags! {
         const EnableAll = D3D12_COLOR_WRITE_ENABLE_D3D12_COLOR_WRITE_ENABLE_ALL;
     }
 }
+
+bitflags! {
+    pub struct ResidencyFlags: i32 {
+        const None = D3D12_RESIDENCY_FLAGS_D3D12_RESIDENCY_FLAG_NONE;
+        const DenyOverbudget = D3D12_RESIDENCY_FLAGS_D3D12_RESIDENCY_FLAG_DENY_OVERBUDGET;
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum BackgroundProcessingMode {
+    Allowed = D3D12_BACKGROUND_PROCESSING_MODE_D3D12_BACKGROUND_PROCESSING_MODE_ALLOWED,
+    AllowIntrusiveMeasurements = D3D12_BACKGROUND_PROCESSING_MODE_D3D12_BACKGROUND_PROCESSING_MODE_ALLOW_INTRUSIVE_MEASUREMENTS,
+    DisableBackgroundWork = D3D12_BACKGROUND_PROCESSING_MODE_D3D12_BACKGROUND_PROCESSING_MODE_DISABLE_BACKGROUND_WORK,
+    DisableProfilingBySystem = D3D12_BACKGROUND_PROCESSING_MODE_D3D12_BACKGROUND_PROCESSING_MODE_DISABLE_PROFILING_BY_SYSTEM,
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum MeasurementsAction {
+    KeepAll = D3D12_MEASUREMENTS_ACTION_D3D12_MEASUREMENTS_ACTION_KEEP_ALL,
+    CommitResults =
+        D3D12_MEASUREMENTS_ACTION_D3D12_MEASUREMENTS_ACTION_COMMIT_RESULTS,
+    CommitResultsHighPriority = D3D12_MEASUREMENTS_ACTION_D3D12_MEASUREMENTS_ACTION_COMMIT_RESULTS_HIGH_PRIORITY,
+    DiscardPrevious =
+        D3D12_MEASUREMENTS_ACTION_D3D12_MEASUREMENTS_ACTION_DISCARD_PREVIOUS,
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ResolveMode {
+    Decompress = D3D12_RESOLVE_MODE_D3D12_RESOLVE_MODE_DECOMPRESS,
+    Min = D3D12_RESOLVE_MODE_D3D12_RESOLVE_MODE_MIN,
+    Max = D3D12_RESOLVE_MODE_D3D12_RESOLVE_MODE_MAX,
+    Average = D3D12_RESOLVE_MODE_D3D12_RESOLVE_MODE_AVERAGE,
+    EncodeSamplerFeedback =
+        D3D12_RESOLVE_MODE_D3D12_RESOLVE_MODE_ENCODE_SAMPLER_FEEDBACK,
+    DecodeSamplerFeedback =
+        D3D12_RESOLVE_MODE_D3D12_RESOLVE_MODE_DECODE_SAMPLER_FEEDBACK,
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum IndirectArgumentType {
+    Draw = D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_DRAW,
+    DrawIndexed =
+        D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_DRAW_INDEXED,
+    Dispatch =
+        D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_DISPATCH,
+    VertexBufferView = D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_VERTEX_BUFFER_VIEW,
+    IndexBufferView = D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_INDEX_BUFFER_VIEW,
+    Constant =
+        D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_CONSTANT,
+    ConstantBufferView = D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_CONSTANT_BUFFER_VIEW,
+    ShaderResourceView = D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_SHADER_RESOURCE_VIEW,
+    UnorderedAccessView = D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_UNORDERED_ACCESS_VIEW,
+    DispatchRays =
+        D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_DISPATCH_RAYS,
+    DispatchMesh =
+        D3D12_INDIRECT_ARGUMENT_TYPE_D3D12_INDIRECT_ARGUMENT_TYPE_DISPATCH_MESH,
+}
+
+/// Controls when the write is performed relative to the surrounding
+/// commands, see
+/// [CommandList::write_buffer_immediate](crate::CommandList::write_buffer_immediate)
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum WriteBufferImmediateMode {
+    /// Ordered like any other command
+    Default =
+        D3D12_WRITEBUFFERIMMEDIATE_MODE_D3D12_WRITEBUFFERIMMEDIATE_MODE_DEFAULT,
+    /// Written as soon as the preceding commands have started
+    MarkerIn = D3D12_WRITEBUFFERIMMEDIATE_MODE_D3D12_WRITEBUFFERIMMEDIATE_MODE_MARKER_IN,
+    /// Written only once the preceding commands have finished
+    MarkerOut = D3D12_WRITEBUFFERIMMEDIATE_MODE_D3D12_WRITEBUFFERIMMEDIATE_MODE_MARKER_OUT,
+}
+
+bitflags! {
+    /// Generic access rights from winnt.h used when creating and opening
+    /// shared handles. Note that D3D12 currently accepts only [AccessRights::All]
+    /// in [Device::create_shared_handle](crate::Device::create_shared_handle)
+    pub struct AccessRights: u32 {
+        const Read = 0x80000000; // GENERIC_READ
+        const Write = 0x40000000; // GENERIC_WRITE
+        const All = 0x10000000; // GENERIC_ALL
+    }
+}
+
+bitflags! {
+    pub struct MultisampleQualityLevelFlags: i32 {
+        const None = D3D12_MULTISAMPLE_QUALITY_LEVEL_FLAGS_D3D12_MULTISAMPLE_QUALITY_LEVELS_FLAG_NONE;
+        const TiledResource = D3D12_MULTISAMPLE_QUALITY_LEVEL_FLAGS_D3D12_MULTISAMPLE_QUALITY_LEVELS_FLAG_TILED_RESOURCE;
+    }
+}
+
+/// Note that D3D12_MULTIPLE_FENCE_WAIT_FLAG_NONE is the same as
+/// D3D12_MULTIPLE_FENCE_WAIT_FLAG_ALL, so it's not listed separately
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum MultipleFenceWaitFlags {
+    All = D3D12_MULTIPLE_FENCE_WAIT_FLAGS_D3D12_MULTIPLE_FENCE_WAIT_FLAG_ALL,
+    Any = D3D12_MULTIPLE_FENCE_WAIT_FLAGS_D3D12_MULTIPLE_FENCE_WAIT_FLAG_ANY,
+}
+
+bitflags! {
+    pub struct ProtectedResourceSessionFlags: i32 {
+        const None = D3D12_PROTECTED_RESOURCE_SESSION_FLAGS_D3D12_PROTECTED_RESOURCE_SESSION_FLAG_NONE;
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+pub enum ProtectedSessionStatus {
+    Ok = D3D12_PROTECTED_SESSION_STATUS_D3D12_PROTECTED_SESSION_STATUS_OK,
+    Invalid =
+        D3D12_PROTECTED_SESSION_STATUS_D3D12_PROTECTED_SESSION_STATUS_INVALID,
+}
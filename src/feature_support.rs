@@ -0,0 +1,333 @@
+use log::error;
+
+use crate::{
+    ConservativeRasterizationTier, DescriptorHeapType, Device, DxError,
+    DxResult, Feature, FeatureDataOptions, FeatureDataOptions1,
+    FeatureDataOptions12, FeatureDataOptions16, FeatureDataOptions5,
+    FeatureDataOptions6, FeatureDataOptions7, FeatureDataRootSignature,
+    FeatureDataShaderModel, MeshShaderTier, RaytracingTier, RenderPassTier,
+    ResourceBindingTier, ResourceHeapTier, RootSignatureVersion,
+    SamplerFeedbackTier, ShaderModel, ShadingRate, TiledResourcesTier,
+    VariableShadingRateTier, MAX_SHADER_VISIBLE_DESCRIPTOR_HEAP_SIZE_TIER_1,
+    MAX_SHADER_VISIBLE_DESCRIPTOR_HEAP_SIZE_TIER_2,
+    MAX_SHADER_VISIBLE_SAMPLER_HEAP_SIZE,
+};
+
+// CheckFeatureSupport fails with E_INVALIDARG for the shader models
+// the runtime does not know about, so they are probed from the highest one
+const SHADER_MODELS: [ShaderModel; 9] = [
+    ShaderModel::SM_6_8,
+    ShaderModel::SM_6_7,
+    ShaderModel::SM_6_6,
+    ShaderModel::SM_6_5,
+    ShaderModel::SM_6_4,
+    ShaderModel::SM_6_3,
+    ShaderModel::SM_6_2,
+    ShaderModel::SM_6_1,
+    ShaderModel::SM_6_0,
+];
+
+/// Queries the commonly used D3D12_FEATURE_DATA_* structures once and
+/// exposes the results via typed getters, similar to
+/// CD3DX12FeatureSupport from d3dx12.h. Feature data which is not known
+/// to the runtime (e.g. newer options on older Windows versions) is
+/// reported as unsupported
+#[derive(Debug, Clone)]
+pub struct FeatureSupport {
+    options: FeatureDataOptions,
+    options1: FeatureDataOptions1,
+    options5: FeatureDataOptions5,
+    options6: FeatureDataOptions6,
+    options7: FeatureDataOptions7,
+    options12: FeatureDataOptions12,
+    options16: FeatureDataOptions16,
+    highest_shader_model: ShaderModel,
+    highest_root_signature_version: RootSignatureVersion,
+}
+
+impl FeatureSupport {
+    pub fn new(device: &Device) -> DxResult<Self> {
+        let mut options = FeatureDataOptions::default();
+        device.check_feature_support(Feature::D3D12Options, &mut options)?;
+
+        let mut options1 = FeatureDataOptions1::default();
+        let mut options5 = FeatureDataOptions5::default();
+        let mut options6 = FeatureDataOptions6::default();
+        let mut options7 = FeatureDataOptions7::default();
+        let mut options12 = FeatureDataOptions12::default();
+        let mut options16 = FeatureDataOptions16::default();
+        // on failure the structures are left zeroed, i.e. unsupported
+        let _ =
+            device.check_feature_support(Feature::D3D12Options1, &mut options1);
+        let _ =
+            device.check_feature_support(Feature::D3D12Options5, &mut options5);
+        let _ =
+            device.check_feature_support(Feature::D3D12Options6, &mut options6);
+        let _ =
+            device.check_feature_support(Feature::D3D12Options7, &mut options7);
+        let _ = device
+            .check_feature_support(Feature::D3D12Options12, &mut options12);
+        let _ = device
+            .check_feature_support(Feature::D3D12Options16, &mut options16);
+
+        let highest_shader_model = SHADER_MODELS
+            .iter()
+            .find_map(|&shader_model| {
+                let mut data = FeatureDataShaderModel::new(shader_model);
+                device
+                    .check_feature_support(Feature::ShaderModel, &mut data)
+                    .ok()
+                    .map(|_| data.highest_shader_model())
+            })
+            .unwrap_or(ShaderModel::SM_5_1);
+
+        let mut root_signature =
+            FeatureDataRootSignature::new(RootSignatureVersion::V1_1);
+        let highest_root_signature_version = match device
+            .check_feature_support(Feature::RootSignature, &mut root_signature)
+        {
+            Ok(_) => root_signature.highest_version(),
+            Err(_) => RootSignatureVersion::V1_0,
+        };
+
+        Ok(Self {
+            options,
+            options1,
+            options5,
+            options6,
+            options7,
+            options12,
+            options16,
+            highest_shader_model,
+            highest_root_signature_version,
+        })
+    }
+
+    pub fn highest_shader_model(&self) -> ShaderModel {
+        self.highest_shader_model
+    }
+
+    pub fn highest_root_signature_version(&self) -> RootSignatureVersion {
+        self.highest_root_signature_version
+    }
+
+    pub fn resource_binding_tier(&self) -> ResourceBindingTier {
+        self.options.resource_binding_tier()
+    }
+
+    pub fn heap_limits(&self) -> HeapLimits {
+        HeapLimits::for_tier(self.resource_binding_tier())
+    }
+
+    pub fn resource_heap_tier(&self) -> ResourceHeapTier {
+        self.options.resource_heap_tier()
+    }
+
+    pub fn tiled_resources_tier(&self) -> TiledResourcesTier {
+        self.options.tiled_resources_tier()
+    }
+
+    pub fn conservative_rasterization_tier(
+        &self,
+    ) -> ConservativeRasterizationTier {
+        self.options.conservative_rasterization_tier()
+    }
+
+    pub fn wave_ops(&self) -> bool {
+        self.options1.wave_ops()
+    }
+
+    pub fn wave_lane_count_min(&self) -> u32 {
+        self.options1.wave_lane_count_min()
+    }
+
+    pub fn wave_lane_count_max(&self) -> u32 {
+        self.options1.wave_lane_count_max()
+    }
+
+    pub fn int64_shader_ops(&self) -> bool {
+        self.options1.int64_shader_ops()
+    }
+
+    pub fn raytracing_tier(&self) -> RaytracingTier {
+        self.options5.raytracing_tier()
+    }
+
+    pub fn render_passes_tier(&self) -> RenderPassTier {
+        self.options5.render_passes_tier()
+    }
+
+    pub fn variable_shading_rate_tier(&self) -> VariableShadingRateTier {
+        self.options6.variable_shading_rate_tier()
+    }
+
+    /// Whether `rate` can be passed to
+    /// [CommandList::set_shading_rate](crate::CommandList::set_shading_rate).
+    /// The coarsest rates (2x4, 4x2 and 4x4) additionally require
+    /// [FeatureDataOptions6::additional_shading_rates_supported]
+    pub fn shading_rate_supported(&self, rate: ShadingRate) -> bool {
+        match self.variable_shading_rate_tier() {
+            VariableShadingRateTier::NotSupported => false,
+            _ => match rate {
+                ShadingRate::Rate2x4
+                | ShadingRate::Rate4x2
+                | ShadingRate::Rate4x4 => {
+                    self.options6.additional_shading_rates_supported()
+                }
+                _ => true,
+            },
+        }
+    }
+
+    /// Shading rate images and combiners require tier 2
+    pub fn shading_rate_image_supported(&self) -> bool {
+        matches!(
+            self.variable_shading_rate_tier(),
+            VariableShadingRateTier::Tier2
+        )
+    }
+
+    /// Size in pixels of the screen tile covered by a texel of shading
+    /// rate images, 0 if they are not supported
+    pub fn shading_rate_image_tile_size(&self) -> u32 {
+        self.options6.shading_rate_image_tile_size()
+    }
+
+    pub fn mesh_shader_tier(&self) -> MeshShaderTier {
+        self.options7.mesh_shader_tier()
+    }
+
+    pub fn mesh_shaders_supported(&self) -> bool {
+        !matches!(self.mesh_shader_tier(), MeshShaderTier::NotSupported)
+    }
+
+    pub fn sampler_feedback_tier(&self) -> SamplerFeedbackTier {
+        self.options7.sampler_feedback_tier()
+    }
+
+    pub fn enhanced_barriers_supported(&self) -> bool {
+        self.options12.enhanced_barriers_supported()
+    }
+
+    pub fn gpu_upload_heap_supported(&self) -> bool {
+        self.options16.gpu_upload_heap_supported()
+    }
+
+    pub fn options(&self) -> &FeatureDataOptions {
+        &self.options
+    }
+
+    pub fn options1(&self) -> &FeatureDataOptions1 {
+        &self.options1
+    }
+
+    pub fn options5(&self) -> &FeatureDataOptions5 {
+        &self.options5
+    }
+
+    pub fn options6(&self) -> &FeatureDataOptions6 {
+        &self.options6
+    }
+
+    pub fn options7(&self) -> &FeatureDataOptions7 {
+        &self.options7
+    }
+
+    pub fn options12(&self) -> &FeatureDataOptions12 {
+        &self.options12
+    }
+
+    pub fn options16(&self) -> &FeatureDataOptions16 {
+        &self.options16
+    }
+}
+
+/// Shader-visible descriptor heap sizes allowed by the resource binding
+/// tier, e.g. for sizing bindless heaps up front
+#[derive(Debug, Clone, Copy)]
+pub struct HeapLimits {
+    resource_binding_tier: ResourceBindingTier,
+}
+
+impl HeapLimits {
+    /// Queries only the resource binding tier, which is cheaper than
+    /// creating [FeatureSupport]
+    pub fn new(device: &Device) -> DxResult<Self> {
+        let mut options = FeatureDataOptions::default();
+        device.check_feature_support(Feature::D3D12Options, &mut options)?;
+
+        Ok(Self::for_tier(options.resource_binding_tier()))
+    }
+
+    pub fn for_tier(resource_binding_tier: ResourceBindingTier) -> Self {
+        Self {
+            resource_binding_tier,
+        }
+    }
+
+    pub fn resource_binding_tier(&self) -> ResourceBindingTier {
+        self.resource_binding_tier
+    }
+
+    /// None on tier 3, where the limit is defined by the driver and is
+    /// at least [MAX_SHADER_VISIBLE_DESCRIPTOR_HEAP_SIZE_TIER_2]
+    pub fn max_cbv_srv_uav_heap_size(&self) -> Option<u32> {
+        match self.resource_binding_tier {
+            ResourceBindingTier::Tier1 => {
+                Some(MAX_SHADER_VISIBLE_DESCRIPTOR_HEAP_SIZE_TIER_1)
+            }
+            ResourceBindingTier::Tier2 => {
+                Some(MAX_SHADER_VISIBLE_DESCRIPTOR_HEAP_SIZE_TIER_2)
+            }
+            ResourceBindingTier::Tier3 => None,
+        }
+    }
+
+    /// The same on all tiers
+    pub fn max_sampler_heap_size(&self) -> u32 {
+        MAX_SHADER_VISIBLE_SAMPLER_HEAP_SIZE
+    }
+
+    /// Limit of a shader-visible heap of the given type. None if the heap
+    /// type cannot be shader-visible or the limit is driver-defined
+    pub fn max_shader_visible_heap_size(
+        &self,
+        heap_type: DescriptorHeapType,
+    ) -> Option<u32> {
+        match heap_type {
+            DescriptorHeapType::CbvSrvUav => self.max_cbv_srv_uav_heap_size(),
+            DescriptorHeapType::Sampler => Some(self.max_sampler_heap_size()),
+            _ => None,
+        }
+    }
+
+    /// Fails with [DxError::DescriptorHeapLimitExceeded] if a shader-visible
+    /// heap of `num_descriptors` cannot be created on this tier
+    pub fn check_shader_visible_heap(
+        &self,
+        func_name: &'static str,
+        heap_type: DescriptorHeapType,
+        num_descriptors: u32,
+    ) -> DxResult<()> {
+        match self.max_shader_visible_heap_size(heap_type) {
+            Some(limit) if num_descriptors > limit => {
+                error!(
+                    "{}: shader-visible {:?} heap of {} descriptors exceeds \
+                     the limit of {} on resource binding {:?}",
+                    func_name,
+                    heap_type,
+                    num_descriptors,
+                    limit,
+                    self.resource_binding_tier
+                );
+                Err(DxError::DescriptorHeapLimitExceeded {
+                    func_name,
+                    heap_type,
+                    requested: num_descriptors,
+                    limit,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use log::error;
+use winapi::shared::winerror;
+use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::handleapi::INVALID_HANDLE_VALUE;
+use winapi::um::threadpoollegacyapiset::UnregisterWaitEx;
+use winapi::um::winbase::RegisterWaitForSingleObject;
+use winapi::um::winnt::{BOOLEAN, HANDLE, PVOID, WT_EXECUTEONLYONCE};
+
+use crate::{DxError, DxResult, Fence, Win32Event};
+
+impl Fence {
+    /// Returns a future that resolves once the fence reaches `value`.
+    /// The wait is registered with the system thread pool on the first
+    /// poll, so no thread is blocked while the GPU is busy and the future
+    /// works with any executor
+    pub fn wait_async(&self, value: u64) -> FenceWait {
+        FenceWait {
+            fence: self.clone(),
+            value,
+            registration: None,
+        }
+    }
+}
+
+// Shared with the thread pool callback
+#[derive(Default)]
+struct WaitState {
+    signaled: bool,
+    waker: Option<Waker>,
+}
+
+struct Registration {
+    event: Win32Event,
+    wait_handle: HANDLE,
+    state: Arc<Mutex<WaitState>>,
+}
+
+// The thread pool only touches the wait handle during unregistration
+unsafe impl Send for Registration {}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        unsafe {
+            // INVALID_HANDLE_VALUE makes it wait for a running callback,
+            // after which the context pointer can be released
+            UnregisterWaitEx(self.wait_handle, INVALID_HANDLE_VALUE);
+            drop(Arc::from_raw(Arc::as_ptr(&self.state)));
+        }
+        self.event.close();
+    }
+}
+
+/// Future returned by [Fence::wait_async]
+pub struct FenceWait {
+    fence: Fence,
+    value: u64,
+    registration: Option<Registration>,
+}
+
+impl std::fmt::Debug for FenceWait {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FenceWait")
+            .field("fence", &self.fence)
+            .field("value", &self.value)
+            .field("registered", &self.registration.is_some())
+            .finish()
+    }
+}
+
+impl FenceWait {
+    fn register(&self, waker: &Waker) -> DxResult<Registration> {
+        let event = Win32Event::default();
+        if let Err(err) = self.fence.set_event_on_completion(self.value, &event)
+        {
+            event.close();
+            return Err(err);
+        }
+
+        let state = Arc::new(Mutex::new(WaitState {
+            signaled: false,
+            waker: Some(waker.clone()),
+        }));
+        let mut wait_handle: HANDLE = std::ptr::null_mut();
+        // the callback borrows the reference leaked here, Registration::drop
+        // takes it back after unregistering the wait
+        let context = Arc::into_raw(state.clone()) as PVOID;
+        let registered = unsafe {
+            RegisterWaitForSingleObject(
+                &mut wait_handle,
+                event.handle as HANDLE,
+                Some(on_fence_event),
+                context,
+                0xFFFFFFFF,
+                WT_EXECUTEONLYONCE,
+            )
+        };
+        if registered == 0 {
+            let hresult =
+                winerror::HRESULT_FROM_WIN32(unsafe { GetLastError() });
+            error!(
+                "Cannot register a thread pool wait for fence {:p}",
+                self.fence.this
+            );
+            unsafe { drop(Arc::from_raw(context as *const Mutex<WaitState>)) };
+            event.close();
+            return Err(DxError::new("RegisterWaitForSingleObject", hresult));
+        }
+
+        Ok(Registration {
+            event,
+            wait_handle,
+            state,
+        })
+    }
+}
+
+impl Future for FenceWait {
+    type Output = DxResult<()>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        if self.fence.get_completed_value() >= self.value {
+            self.registration = None;
+            return Poll::Ready(Ok(()));
+        }
+
+        match &self.registration {
+            Some(registration) => {
+                let mut state = registration
+                    .state
+                    .lock()
+                    .expect("Cannot lock fence wait state");
+                if state.signaled {
+                    // the event is only set once the value has been reached
+                    drop(state);
+                    self.registration = None;
+                    return Poll::Ready(Ok(()));
+                }
+                state.waker = Some(cx.waker().clone());
+            }
+            None => {
+                let registration = self.register(cx.waker())?;
+                self.registration = Some(registration);
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+unsafe extern "system" fn on_fence_event(context: PVOID, _timed_out: BOOLEAN) {
+    let state = &*(context as *const Mutex<WaitState>);
+    let waker = match state.lock() {
+        Ok(mut state) => {
+            state.signaled = true;
+            state.waker.take()
+        }
+        Err(_) => None,
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
@@ -0,0 +1,694 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender, TryRecvError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    CommandAllocator, CommandList, CommandQueue, CopyCapable, Device,
+    DirectKind, DxError, DxResult, Fence, Metric, Metrics, Timeline,
+    Win32Event,
+};
+
+// How often the pre-reset thread checks whether the pool is being dropped
+// while it waits for the GPU
+const PREWARM_POLL_INTERVAL_MS: u32 = 100;
+
+// Owns an allocator and a command list while they travel between the pool
+// and the pre-reset thread. The wrappers themselves are not Send since their
+// clones could be recorded on two threads at once. A pair is only sent once
+// CommandPair::is_exclusive has confirmed that no clones are left, and
+// nothing can clone it while it is in the channel or on the thread
+#[derive(Debug)]
+struct CommandPair<K> {
+    command_allocator: CommandAllocator,
+    command_list: CommandList<K>,
+}
+
+unsafe impl<K> Send for CommandPair<K> {}
+
+impl<K> CommandPair<K> {
+    // Whether the pair holds the only references to both objects. The
+    // clones cannot be on other threads, so they cannot appear meanwhile
+    fn is_exclusive(&self) -> bool {
+        let allocator_refs = self.command_allocator.add_ref();
+        self.command_allocator.release();
+        let command_list_refs = self.command_list.add_ref();
+        self.command_list.release();
+
+        allocator_refs == 2 && command_list_refs == 2
+    }
+}
+
+// Background thread that resets released pairs as soon as their fence
+// values are reached and hands them back open for recording
+#[derive(Debug)]
+struct PrewarmWorker<K: CopyCapable> {
+    retired_sender: Option<Sender<(u64, CommandPair<K>)>>,
+    prewarmed_receiver: Option<Receiver<DxResult<CommandPair<K>>>>,
+    // fence values of the pairs owned by the thread in the order of release
+    pending_fence_values: VecDeque<u64>,
+    stop: Arc<AtomicBool>,
+    reset_nanos: Arc<AtomicU64>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<K: CopyCapable + 'static> PrewarmWorker<K> {
+    fn new(fence: &Fence, prewarm_count: u32) -> Self {
+        let (retired_sender, retired_receiver) = mpsc::channel();
+        // the thread holds one more pair while it is blocked on sending
+        let (prewarmed_sender, prewarmed_receiver) =
+            mpsc::sync_channel(prewarm_count as usize - 1);
+        let stop = Arc::new(AtomicBool::new(false));
+        let reset_nanos = Arc::new(AtomicU64::new(0));
+
+        let thread = {
+            let fence = fence.clone();
+            let stop = Arc::clone(&stop);
+            let reset_nanos = Arc::clone(&reset_nanos);
+            std::thread::Builder::new()
+                .name("Command list pre-reset".to_owned())
+                .spawn(move || {
+                    run_prewarm_worker(
+                        fence,
+                        retired_receiver,
+                        prewarmed_sender,
+                        stop,
+                        reset_nanos,
+                    )
+                })
+                .expect("Cannot spawn command list pre-reset thread")
+        };
+
+        Self {
+            retired_sender: Some(retired_sender),
+            prewarmed_receiver: Some(prewarmed_receiver),
+            pending_fence_values: VecDeque::new(),
+            stop,
+            reset_nanos,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl<K: CopyCapable> PrewarmWorker<K> {
+    // Gives the pair back if the thread is gone
+    fn retire(
+        &mut self,
+        fence_value: u64,
+        command_pair: CommandPair<K>,
+    ) -> Result<(), CommandPair<K>> {
+        let sender = self
+            .retired_sender
+            .as_ref()
+            .expect("Pre-reset thread is shut down");
+        match sender.send((fence_value, command_pair)) {
+            Ok(()) => {
+                self.pending_fence_values.push_back(fence_value);
+                Ok(())
+            }
+            Err(mpsc::SendError((_, command_pair))) => Err(command_pair),
+        }
+    }
+
+    // None if no pair has been pre-reset yet. A pair whose fence value has
+    // already been reached is waited for, since the thread is about to
+    // hand it over
+    fn take(
+        &mut self,
+        completed_fence_value: u64,
+    ) -> Option<DxResult<CommandPair<K>>> {
+        let receiver = self
+            .prewarmed_receiver
+            .as_ref()
+            .expect("Pre-reset thread is shut down");
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => match self.pending_fence_values.front()
+            {
+                Some(fence_value) if *fence_value <= completed_fence_value => {
+                    receiver.recv().ok()?
+                }
+                _ => return None,
+            },
+            Err(TryRecvError::Disconnected) => return None,
+        };
+        self.pending_fence_values.pop_front();
+
+        Some(result)
+    }
+
+    fn reset_time(&self) -> Duration {
+        Duration::from_nanos(self.reset_nanos.load(Ordering::Relaxed))
+    }
+}
+
+impl<K: CopyCapable> Drop for PrewarmWorker<K> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        // disconnecting both channels wakes the thread up if it is blocked
+        self.retired_sender = None;
+        self.prewarmed_receiver = None;
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                error!("Command list pre-reset thread has panicked");
+            }
+        }
+    }
+}
+
+fn run_prewarm_worker<K: CopyCapable>(
+    fence: Fence,
+    retired_receiver: Receiver<(u64, CommandPair<K>)>,
+    prewarmed_sender: SyncSender<DxResult<CommandPair<K>>>,
+    stop: Arc<AtomicBool>,
+    reset_nanos: Arc<AtomicU64>,
+) {
+    let event = Win32Event::default();
+
+    while let Ok((
+        fence_value,
+        CommandPair {
+            command_allocator,
+            command_list,
+        },
+    )) = retired_receiver.recv()
+    {
+        let result = match wait_for_fence(&fence, &event, fence_value, &stop) {
+            Ok(true) => {
+                let start = Instant::now();
+                let result = command_allocator
+                    .reset()
+                    .and_then(|_| command_list.reset(&command_allocator, None))
+                    .map(|_| CommandPair {
+                        command_allocator,
+                        command_list,
+                    });
+                reset_nanos.fetch_add(
+                    start.elapsed().as_nanos() as u64,
+                    Ordering::Relaxed,
+                );
+                result
+            }
+            Ok(false) => break,
+            Err(err) => Err(err),
+        };
+
+        if prewarmed_sender.send(result).is_err() {
+            break;
+        }
+    }
+
+    event.close();
+}
+
+// Returns false if the pool is dropped before the GPU reaches the value
+fn wait_for_fence(
+    fence: &Fence,
+    event: &Win32Event,
+    fence_value: u64,
+    stop: &AtomicBool,
+) -> DxResult<bool> {
+    if fence.get_completed_value() >= fence_value {
+        return Ok(true);
+    }
+
+    fence.set_event_on_completion(fence_value, event)?;
+    while fence.get_completed_value() < fence_value {
+        if stop.load(Ordering::Acquire) {
+            return Ok(false);
+        }
+        event.wait(Some(PREWARM_POLL_INTERVAL_MS));
+    }
+
+    Ok(true)
+}
+
+/// Recycles command allocators and command lists of the type determined
+/// by `K`. An allocator is returned to the pool along with the fence value
+/// signaled after the work recorded into it, and is reset only once that
+/// value has been reached, whereas command lists are reused right away
+/// since they can be reset as soon as they have been submitted.
+///
+/// Resetting allocators that recorded large frames is not free, so
+/// a pool created with [CommandAllocatorPool::with_prewarm] moves it to
+/// a background thread: released pairs are reset there as soon as
+/// the fence reaches their values, and [CommandAllocatorPool::acquire]
+/// only picks up a list that is already open. If no pre-reset pair is
+/// ready, the pool falls back to creating a new one. Comparing
+/// [CommandAllocatorPool::inline_reset_time] and
+/// [CommandAllocatorPool::background_reset_time] (or the acquire times)
+/// of the two modes shows how much time the render thread saves
+#[derive(Debug)]
+pub struct CommandAllocatorPool<K: CopyCapable = DirectKind> {
+    device: Device,
+    // (fence value to wait for, allocator) in the order of release
+    retired_allocators: VecDeque<(u64, CommandAllocator)>,
+    free_command_lists: Vec<CommandList<K>>,
+    prewarm: Option<PrewarmWorker<K>>,
+    prewarm_count: u32,
+    last_fence_value: u64,
+    allocator_count: u32,
+    command_list_count: u32,
+    acquire_count: u64,
+    prewarmed_acquire_count: u64,
+    acquire_time: Duration,
+    max_acquire_time: Duration,
+    inline_reset_time: Duration,
+}
+
+impl<K: CopyCapable + 'static> CommandAllocatorPool<K> {
+    /// Creates a pool that keeps up to `prewarm_count` pairs reset and
+    /// open on a background thread. `fence` is the one the values passed
+    /// to [CommandAllocatorPool::release] are signaled on
+    pub fn with_prewarm(
+        device: &Device,
+        fence: &Fence,
+        prewarm_count: u32,
+    ) -> DxResult<Self> {
+        if prewarm_count == 0 {
+            error!("Command allocator pool needs at least one pre-reset pair");
+            return Err(DxError::new(
+                "CommandAllocatorPool::with_prewarm",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let mut pool = Self::new(device);
+        pool.prewarm = Some(PrewarmWorker::new(fence, prewarm_count));
+        pool.prewarm_count = prewarm_count;
+
+        Ok(pool)
+    }
+}
+
+impl<K: CopyCapable> CommandAllocatorPool<K> {
+    pub fn new(device: &Device) -> Self {
+        Self {
+            device: device.clone(),
+            retired_allocators: VecDeque::new(),
+            free_command_lists: vec![],
+            prewarm: None,
+            prewarm_count: 0,
+            last_fence_value: 0,
+            allocator_count: 0,
+            command_list_count: 0,
+            acquire_count: 0,
+            prewarmed_acquire_count: 0,
+            acquire_time: Duration::ZERO,
+            max_acquire_time: Duration::ZERO,
+            inline_reset_time: Duration::ZERO,
+        }
+    }
+
+    /// Returns a reset allocator and a command list open for recording
+    /// into it. Allocators whose fence values are not greater than
+    /// `completed_fence_value` are reused, new ones are created otherwise
+    pub fn acquire(
+        &mut self,
+        completed_fence_value: u64,
+    ) -> DxResult<(CommandAllocator, CommandList<K>)> {
+        let start = Instant::now();
+        let prewarmed = self
+            .prewarm
+            .as_mut()
+            .and_then(|prewarm| prewarm.take(completed_fence_value));
+        let result = match prewarmed {
+            Some(result) => {
+                self.prewarmed_acquire_count += 1;
+                result.map(|command_pair| {
+                    (command_pair.command_allocator, command_pair.command_list)
+                })
+            }
+            None => self.acquire_inline(completed_fence_value),
+        };
+
+        let elapsed = start.elapsed();
+        self.acquire_count += 1;
+        self.acquire_time += elapsed;
+        self.max_acquire_time = self.max_acquire_time.max(elapsed);
+
+        result
+    }
+
+    /// Returns a pair obtained from [CommandAllocatorPool::acquire]. The
+    /// command list must be closed and submitted (or at least closed)
+    /// by now, and `fence_value` must be signaled on the queue after it.
+    /// Fence values are expected to increase from call to call. With
+    /// pre-reset enabled, a pair is only handed to the background thread
+    /// if no clones of the allocator or the list are left
+    pub fn release(
+        &mut self,
+        command_allocator: CommandAllocator,
+        command_list: CommandList<K>,
+        fence_value: u64,
+    ) {
+        debug_assert!(
+            self.last_fence_value <= fence_value,
+            "Command allocators must be released in fence value order"
+        );
+        self.last_fence_value = fence_value;
+
+        let command_pair = CommandPair {
+            command_allocator,
+            command_list,
+        };
+        let command_pair = match &mut self.prewarm {
+            // pairs the caller still holds clones of are reset on acquire,
+            // so that they are never touched by two threads
+            Some(prewarm) if command_pair.is_exclusive() => {
+                match prewarm.retire(fence_value, command_pair) {
+                    Ok(()) => return,
+                    Err(command_pair) => {
+                        error!(
+                            "Command list pre-reset thread is gone, \
+                             resetting on acquire"
+                        );
+                        command_pair
+                    }
+                }
+            }
+            _ => command_pair,
+        };
+        let CommandPair {
+            command_allocator,
+            command_list,
+        } = command_pair;
+
+        self.retired_allocators
+            .push_back((fence_value, command_allocator));
+        self.free_command_lists.push(command_list);
+    }
+
+    /// Number of allocators created by the pool so far
+    pub fn allocator_count(&self) -> u32 {
+        self.allocator_count
+    }
+
+    /// Number of command lists created by the pool so far
+    pub fn command_list_count(&self) -> u32 {
+        self.command_list_count
+    }
+
+    /// Number of allocators waiting in the pool, including the ones
+    /// the GPU might still be using
+    pub fn retired_allocator_count(&self) -> u32 {
+        let pending_count = self
+            .prewarm
+            .as_ref()
+            .map_or(0, |prewarm| prewarm.pending_fence_values.len());
+        (self.retired_allocators.len() + pending_count) as u32
+    }
+
+    /// Zero unless the pool has been created with
+    /// [CommandAllocatorPool::with_prewarm]
+    pub fn prewarm_count(&self) -> u32 {
+        self.prewarm_count
+    }
+
+    pub fn acquire_count(&self) -> u64 {
+        self.acquire_count
+    }
+
+    /// Number of acquires served by a pair reset on the background thread
+    pub fn prewarmed_acquire_count(&self) -> u64 {
+        self.prewarmed_acquire_count
+    }
+
+    /// Total time spent in [CommandAllocatorPool::acquire]
+    pub fn acquire_time(&self) -> Duration {
+        self.acquire_time
+    }
+
+    pub fn max_acquire_time(&self) -> Duration {
+        self.max_acquire_time
+    }
+
+    /// Time spent resetting allocators and lists in
+    /// [CommandAllocatorPool::acquire], i.e. on the calling thread
+    pub fn inline_reset_time(&self) -> Duration {
+        self.inline_reset_time
+    }
+
+    /// Time spent resetting allocators and lists on the background thread
+    pub fn background_reset_time(&self) -> Duration {
+        self.prewarm
+            .as_ref()
+            .map_or(Duration::ZERO, |prewarm| prewarm.reset_time())
+    }
+
+    fn acquire_inline(
+        &mut self,
+        completed_fence_value: u64,
+    ) -> DxResult<(CommandAllocator, CommandList<K>)> {
+        let command_allocator = match self.retired_allocators.front() {
+            Some((fence_value, _)) if *fence_value <= completed_fence_value => {
+                let (_, command_allocator) = self
+                    .retired_allocators
+                    .pop_front()
+                    .expect("Retired allocator queue is empty");
+                let reset_start = Instant::now();
+                command_allocator.reset()?;
+                self.inline_reset_time += reset_start.elapsed();
+                command_allocator
+            }
+            _ => {
+                let command_allocator =
+                    self.device.create_command_allocator(K::TYPE)?;
+                if let Some(name) =
+                    self.device.make_object_name("Pooled command allocator")
+                {
+                    command_allocator.set_name(&name)?;
+                }
+                self.allocator_count += 1;
+                command_allocator
+            }
+        };
+
+        let command_list = match self.free_command_lists.pop() {
+            Some(command_list) => {
+                let reset_start = Instant::now();
+                command_list.reset(&command_allocator, None)?;
+                self.inline_reset_time += reset_start.elapsed();
+                command_list
+            }
+            None => {
+                let command_list = self
+                    .device
+                    .create_typed_command_list::<K>(&command_allocator, None)?;
+                if let Some(name) =
+                    self.device.make_object_name("Pooled command list")
+                {
+                    command_list.set_name(&name)?;
+                }
+                self.command_list_count += 1;
+                command_list
+            }
+        };
+
+        Ok((command_allocator, command_list))
+    }
+}
+
+impl<K: CopyCapable> Metrics for CommandAllocatorPool<K> {
+    fn metrics(&self) -> Vec<Metric> {
+        let average_acquire_time = match self.acquire_count {
+            0 => 0.,
+            count => self.acquire_time.as_secs_f64() / count as f64,
+        };
+
+        vec![
+            Metric::new("command allocators", self.allocator_count),
+            Metric::new(
+                "retired command allocators",
+                self.retired_allocator_count(),
+            ),
+            Metric::new("command lists", self.command_list_count),
+            Metric::new("acquires", self.acquire_count as f64),
+            Metric::new(
+                "prewarmed acquires",
+                self.prewarmed_acquire_count as f64,
+            ),
+            Metric::new(
+                "average acquire microseconds",
+                average_acquire_time * 1e6,
+            ),
+            Metric::new(
+                "max acquire microseconds",
+                self.max_acquire_time.as_secs_f64() * 1e6,
+            ),
+            Metric::new(
+                "inline reset microseconds",
+                self.inline_reset_time.as_secs_f64() * 1e6,
+            ),
+            Metric::new(
+                "background reset microseconds",
+                self.background_reset_time().as_secs_f64() * 1e6,
+            ),
+        ]
+    }
+}
+
+/// Per-frame command recording on a single queue: any number of command
+/// lists can be requested during a frame, and [FrameContext::end_frame]
+/// submits them and recycles their allocators through
+/// a [CommandAllocatorPool] once the GPU is done with the frame.
+/// [FrameContext::begin_frame] keeps the CPU at most `frames_in_flight`
+/// frames ahead of the GPU. Dropping the context waits for all
+/// the submitted frames
+#[derive(Debug)]
+pub struct FrameContext<K: CopyCapable = DirectKind> {
+    pool: CommandAllocatorPool<K>,
+    timeline: Timeline,
+    frames_in_flight: u32,
+    // timeline values of the submitted frames the GPU may still be busy with
+    frame_fence_values: VecDeque<u64>,
+    // command lists of the current frame in the order of requests
+    recording: Vec<(CommandAllocator, CommandList<K>)>,
+    frame_index: u64,
+}
+
+impl<K: CopyCapable> FrameContext<K> {
+    pub fn new(device: &Device, frames_in_flight: u32) -> DxResult<Self> {
+        if frames_in_flight == 0 {
+            error!("FrameContext needs at least one frame in flight");
+            return Err(DxError::new(
+                "FrameContext::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        Ok(Self {
+            pool: CommandAllocatorPool::new(device),
+            timeline: Timeline::new(device)?,
+            frames_in_flight,
+            frame_fence_values: VecDeque::new(),
+            recording: vec![],
+            frame_index: 0,
+        })
+    }
+
+    /// Blocks until fewer than `frames_in_flight` frames are
+    /// being processed by the GPU
+    pub fn begin_frame(&mut self) -> DxResult<()> {
+        if !self.recording.is_empty() {
+            error!("FrameContext::begin_frame called twice without end_frame");
+            return Err(DxError::new(
+                "FrameContext::begin_frame",
+                winerror::E_FAIL,
+            ));
+        }
+
+        while self.frame_fence_values.len() >= self.frames_in_flight as usize {
+            let fence_value = self
+                .frame_fence_values
+                .pop_front()
+                .expect("Frame fence value queue is empty");
+            self.timeline.wait_on_cpu(fence_value, None)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns a command list open for recording. The context keeps its
+    /// own reference, the list must not be closed or submitted directly.
+    /// With pre-reset enabled, the returned list should be dropped before
+    /// [FrameContext::end_frame], otherwise it is reset on this thread
+    pub fn command_list(&mut self) -> DxResult<CommandList<K>> {
+        let (command_allocator, command_list) =
+            self.pool.acquire(self.timeline.completed_value())?;
+        self.recording
+            .push((command_allocator, command_list.clone()));
+
+        Ok(command_list)
+    }
+
+    /// Closes and executes all the command lists requested since
+    /// [FrameContext::begin_frame] in the order of the requests, and
+    /// returns the timeline value that is reached once the GPU has
+    /// finished them
+    pub fn end_frame(&mut self, queue: &CommandQueue) -> DxResult<u64> {
+        let command_lists = self
+            .recording
+            .iter()
+            .map(|(_, command_list)| command_list.clone())
+            .collect::<Vec<_>>();
+        for command_list in &command_lists {
+            command_list.close()?;
+        }
+        if !command_lists.is_empty() {
+            queue.execute_command_lists(&command_lists);
+        }
+        // the pool only pre-resets lists it holds the only references to
+        drop(command_lists);
+
+        let fence_value = self.timeline.signal_from(queue)?;
+        for (command_allocator, command_list) in self.recording.drain(..) {
+            self.pool
+                .release(command_allocator, command_list, fence_value);
+        }
+        self.frame_fence_values.push_back(fence_value);
+        self.frame_index += 1;
+
+        Ok(fence_value)
+    }
+
+    /// Blocks until all the submitted frames are finished
+    pub fn wait_idle(&mut self) -> DxResult<()> {
+        self.timeline
+            .wait_on_cpu(self.timeline.last_signaled_value(), None)?;
+        self.frame_fence_values.clear();
+
+        Ok(())
+    }
+
+    /// Number of frames submitted so far
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+
+    pub fn frames_in_flight(&self) -> u32 {
+        self.frames_in_flight
+    }
+
+    pub fn pool(&self) -> &CommandAllocatorPool<K> {
+        &self.pool
+    }
+
+    /// E.g. to make another queue wait for a frame by the value returned
+    /// from [FrameContext::end_frame]
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+}
+
+impl<K: CopyCapable + 'static> FrameContext<K> {
+    /// Same as [FrameContext::new], but the command allocators are reset
+    /// on a background thread, see [CommandAllocatorPool::with_prewarm]
+    pub fn with_prewarm(
+        device: &Device,
+        frames_in_flight: u32,
+        prewarm_count: u32,
+    ) -> DxResult<Self> {
+        let mut frame_context = Self::new(device, frames_in_flight)?;
+        frame_context.pool = CommandAllocatorPool::with_prewarm(
+            device,
+            frame_context.timeline.fence(),
+            prewarm_count,
+        )?;
+
+        Ok(frame_context)
+    }
+}
+
+impl<K: CopyCapable> Drop for FrameContext<K> {
+    fn drop(&mut self) {
+        if let Err(err) = self.wait_idle() {
+            error!("Cannot wait for GPU on frame context shutdown: {}", err);
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use log::{debug, error, info};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::{Window, WindowBuilder},
+};
+
+use crate::{
+    ByteCount, CommandQueue, CommandQueueDesc, CpuDescriptorHandle,
+    CreateFactoryFlags, Debug, DescriptorHeap, DescriptorHeapDesc,
+    DescriptorHeapType, Device, DxError, DxResult, Factory, Format, InfoQueue,
+    PresentFlags, Rect, Resource, SwapChainDesc, Swapchain, Timeline, Viewport,
+};
+
+/// Window and device settings used by [run_sample]
+#[derive(Debug, Clone)]
+pub struct SampleConfig {
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+    pub back_buffer_count: u32,
+    pub back_buffer_format: Format,
+    pub vsync: bool,
+    /// Enables the debug layer and prints info queue messages every frame
+    pub debug_layer: bool,
+    /// Exit after rendering this many frames
+    pub max_frames: Option<u64>,
+}
+
+impl Default for SampleConfig {
+    fn default() -> Self {
+        Self {
+            title: String::from("rusty-d3d12 sample"),
+            width: 1280,
+            height: 720,
+            back_buffer_count: 2,
+            back_buffer_format: Format::R8G8B8A8Unorm,
+            vsync: true,
+            debug_layer: cfg!(debug_assertions),
+            max_frames: None,
+        }
+    }
+}
+
+/// The objects every sample needs, created by [run_sample] before
+/// [SampleApp::init] is called
+#[derive(Debug)]
+pub struct SampleContext {
+    pub config: SampleConfig,
+    pub factory: Factory,
+    pub device: Device,
+    pub info_queue: Option<InfoQueue>,
+    pub command_queue: CommandQueue,
+    pub swapchain: Swapchain,
+    rtv_heap: DescriptorHeap,
+    rtv_descriptor_size: ByteCount,
+    back_buffers: Vec<Resource>,
+    timeline: Timeline,
+    // the fence value signaled after the last frame rendered
+    // into the corresponding back buffer
+    frame_fence_values: Vec<u64>,
+    frame_number: u64,
+    // kept alive for the lifetime of the device
+    _debug_layer: Option<Debug>,
+}
+
+impl SampleContext {
+    fn new(config: SampleConfig, window: &Window) -> DxResult<Self> {
+        let debug_layer = match config.debug_layer {
+            true => {
+                let debug_layer = Debug::new()?;
+                debug_layer.enable_debug_layer();
+                Some(debug_layer)
+            }
+            false => None,
+        };
+
+        let factory = Factory::new(match config.debug_layer {
+            true => CreateFactoryFlags::Debug,
+            false => CreateFactoryFlags::None,
+        })?;
+        let adapter = match factory.enum_adapters()?.into_iter().next() {
+            Some(adapter) => adapter,
+            None => {
+                error!("No adapters found");
+                return Err(DxError::new(
+                    "Factory::enum_adapters",
+                    winapi::shared::winerror::DXGI_ERROR_NOT_FOUND,
+                ));
+            }
+        };
+        debug!(
+            "Running sample on {}",
+            adapter.get_desc()?.description().unwrap_or_default()
+        );
+
+        let device = Device::new(&adapter)?;
+        let info_queue = match config.debug_layer {
+            true => Some(InfoQueue::new(&device, None)?),
+            false => None,
+        };
+
+        let command_queue =
+            device.create_command_queue(&CommandQueueDesc::default())?;
+
+        let swapchain = factory.create_swapchain_for_window(
+            &command_queue,
+            window,
+            &SwapChainDesc::default()
+                .with_width(config.width)
+                .with_height(config.height)
+                .with_format(config.back_buffer_format)
+                .with_buffer_count(config.back_buffer_count),
+        )?;
+
+        let rtv_heap = device.create_descriptor_heap(
+            &DescriptorHeapDesc::default()
+                .with_heap_type(DescriptorHeapType::Rtv)
+                .with_num_descriptors(config.back_buffer_count),
+        )?;
+        let rtv_descriptor_size = device
+            .get_descriptor_handle_increment_size(DescriptorHeapType::Rtv);
+
+        let mut back_buffers = vec![];
+        for buffer_index in 0..config.back_buffer_count {
+            let buffer = swapchain.get_buffer(buffer_index)?;
+            device.create_render_target_view(
+                &buffer,
+                rtv_heap
+                    .get_cpu_descriptor_handle_for_heap_start()
+                    .advance(buffer_index, rtv_descriptor_size),
+            );
+            back_buffers.push(buffer);
+        }
+
+        let timeline = Timeline::new(&device)?;
+
+        Ok(Self {
+            frame_fence_values: vec![0; config.back_buffer_count as usize],
+            config,
+            factory,
+            device,
+            info_queue,
+            command_queue,
+            swapchain,
+            rtv_heap,
+            rtv_descriptor_size,
+            back_buffers,
+            timeline,
+            frame_number: 0,
+            _debug_layer: debug_layer,
+        })
+    }
+
+    /// Index of the back buffer the current frame renders into,
+    /// can be used to pick per-frame resources
+    pub fn back_buffer_index(&self) -> u32 {
+        self.swapchain.get_current_back_buffer_index()
+    }
+
+    pub fn back_buffer(&self) -> &Resource {
+        &self.back_buffers[self.back_buffer_index() as usize]
+    }
+
+    pub fn back_buffer_rtv(&self) -> CpuDescriptorHandle {
+        self.rtv_heap
+            .get_cpu_descriptor_handle_for_heap_start()
+            .advance(self.back_buffer_index(), self.rtv_descriptor_size)
+    }
+
+    /// Number of frames presented so far
+    pub fn frame_number(&self) -> u64 {
+        self.frame_number
+    }
+
+    /// Viewport covering the whole back buffer
+    pub fn viewport(&self) -> Viewport {
+        Viewport::default()
+            .with_width(self.config.width as f32)
+            .with_height(self.config.height as f32)
+    }
+
+    /// Scissor rect covering the whole back buffer
+    pub fn scissor_rect(&self) -> Rect {
+        Rect::default()
+            .with_right(self.config.width as i32)
+            .with_bottom(self.config.height as i32)
+    }
+
+    /// Blocks until the GPU has finished all submitted work
+    pub fn wait_for_gpu(&mut self) -> DxResult<()> {
+        let fence_value = self.signal()?;
+        self.wait_for_fence_value(fence_value)
+    }
+
+    fn signal(&mut self) -> DxResult<u64> {
+        self.timeline.signal_from(&self.command_queue)
+    }
+
+    fn wait_for_fence_value(&self, fence_value: u64) -> DxResult<()> {
+        self.timeline.wait_on_cpu(fence_value, None)?;
+
+        Ok(())
+    }
+
+    fn render_frame<S: SampleApp>(&mut self, app: &mut S) -> DxResult<()> {
+        // make sure the back buffer is no longer used by the GPU
+        let buffer_index = self.back_buffer_index() as usize;
+        self.wait_for_fence_value(self.frame_fence_values[buffer_index])?;
+
+        let render_result = app.render(self);
+        if let Some(info_queue) = &self.info_queue {
+            info_queue.print_messages()?;
+        }
+        render_result?;
+
+        self.swapchain.present(
+            match self.config.vsync {
+                true => 1,
+                false => 0,
+            },
+            PresentFlags::None,
+        )?;
+
+        self.frame_fence_values[buffer_index] = self.signal()?;
+        self.frame_number += 1;
+
+        Ok(())
+    }
+}
+
+impl Drop for SampleContext {
+    fn drop(&mut self) {
+        if let Err(err) = self.wait_for_gpu() {
+            error!("Cannot wait for GPU on sample shutdown: {}", err);
+        }
+    }
+}
+
+/// A sample application driven by [run_sample]
+pub trait SampleApp {
+    /// Called once after the device, queue and swapchain have been created
+    fn init(&mut self, context: &mut SampleContext) -> DxResult<()>;
+
+    /// Records and executes the frame's command lists on
+    /// `context.command_queue`, presenting is done by the framework.
+    /// The back buffer is expected to be in Present state after the call
+    fn render(&mut self, context: &mut SampleContext) -> DxResult<()>;
+
+    /// Called for every window event before the framework handles it
+    fn on_window_event(
+        &mut self,
+        _context: &mut SampleContext,
+        _event: &WindowEvent,
+    ) {
+    }
+}
+
+/// Creates a window, a device and a swapchain and runs the event loop,
+/// calling into `app` every frame. Does not return, the process exits
+/// when the window is closed or `config.max_frames` frames have been
+/// rendered
+pub fn run_sample(
+    mut app: impl SampleApp + 'static,
+    config: SampleConfig,
+) -> ! {
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title(&config.title)
+        .with_inner_size(LogicalSize::new(config.width, config.height))
+        .with_resizable(false)
+        .build(&event_loop)
+        .expect("Cannot create window");
+
+    let mut context = SampleContext::new(config, &window)
+        .expect("Cannot create sample context");
+    app.init(&mut context).expect("Cannot initialize sample");
+
+    // the context must be destroyed after the app so that the device
+    // outlives all the objects the app has created from it
+    let mut state = Some((app, context));
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        let (app, context) = match &mut state {
+            Some(state) => state,
+            None => return,
+        };
+
+        let mut should_exit = false;
+        match event {
+            Event::WindowEvent { event, .. } => {
+                app.on_window_event(context, &event);
+                if let WindowEvent::CloseRequested = event {
+                    should_exit = true;
+                }
+            }
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(_) => {
+                if let Err(err) = context.render_frame(app) {
+                    error!("Cannot render frame: {}", err);
+                    should_exit = true;
+                }
+
+                if let Some(max_frames) = context.config.max_frames {
+                    should_exit |= context.frame_number() >= max_frames;
+                }
+            }
+            _ => (),
+        }
+
+        if should_exit {
+            info!("Exiting sample");
+            if let Some((app, mut context)) = state.take() {
+                if let Err(err) = context.wait_for_gpu() {
+                    error!("Cannot wait for GPU: {}", err);
+                }
+                drop(app);
+                drop(context);
+            }
+            *control_flow = ControlFlow::Exit;
+        }
+    })
+}
@@ -0,0 +1,139 @@
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    CommandAllocator, CommandList, CommandQueue, CommandQueueDesc, ComputeKind,
+    CopyCapable, Device, DxError, DxResult, Timeline,
+};
+
+/// A frame loop without a swapchain for services that periodically run
+/// GPU jobs, e.g. compute workloads or offscreen rendering. It owns
+/// a queue of the type determined by `K`, a timeline fence and a command
+/// allocator per frame in flight, so recording of the next frame can
+/// start while the GPU is still busy with the previous ones. Dropping it
+/// waits for all the submitted work
+#[derive(Debug)]
+pub struct HeadlessFrame<K: CopyCapable = ComputeKind> {
+    queue: CommandQueue,
+    timeline: Timeline,
+    command_allocators: Vec<CommandAllocator>,
+    // timeline value signaled after the last submission of each allocator
+    allocator_fence_values: Vec<u64>,
+    command_list: CommandList<K>,
+    frame_index: u64,
+    recording: bool,
+}
+
+impl<K: CopyCapable> HeadlessFrame<K> {
+    pub fn new(device: &Device, frames_in_flight: u32) -> DxResult<Self> {
+        if frames_in_flight == 0 {
+            error!("HeadlessFrame needs at least one frame in flight");
+            return Err(DxError::new(
+                "HeadlessFrame::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let queue = device.create_command_queue(
+            &CommandQueueDesc::default().with_queue_type(K::TYPE),
+        )?;
+
+        let command_allocators = (0..frames_in_flight)
+            .map(|_| device.create_command_allocator(K::TYPE))
+            .collect::<DxResult<Vec<_>>>()?;
+        let command_list = device
+            .create_typed_command_list::<K>(&command_allocators[0], None)?;
+        command_list.close()?;
+
+        Ok(Self {
+            queue,
+            timeline: Timeline::new(device)?,
+            allocator_fence_values: vec![0; command_allocators.len()],
+            command_allocators,
+            command_list,
+            frame_index: 0,
+            recording: false,
+        })
+    }
+
+    /// Waits until the GPU is done with the allocator of the next frame,
+    /// then resets it and returns the command list ready for recording
+    pub fn begin(&mut self) -> DxResult<&CommandList<K>> {
+        if self.recording {
+            error!("HeadlessFrame::begin called twice without submit");
+            return Err(DxError::new("HeadlessFrame::begin", winerror::E_FAIL));
+        }
+
+        let allocator_index =
+            (self.frame_index % self.command_allocators.len() as u64) as usize;
+        self.timeline
+            .wait_on_cpu(self.allocator_fence_values[allocator_index], None)?;
+
+        let command_allocator = &self.command_allocators[allocator_index];
+        command_allocator.reset()?;
+        self.command_list.reset(command_allocator, None)?;
+        self.recording = true;
+
+        Ok(&self.command_list)
+    }
+
+    /// Closes and executes the command list recorded since
+    /// [HeadlessFrame::begin] and returns the timeline value that is
+    /// reached once the GPU has finished it
+    pub fn submit(&mut self) -> DxResult<u64> {
+        if !self.recording {
+            error!("HeadlessFrame::submit called without begin");
+            return Err(DxError::new(
+                "HeadlessFrame::submit",
+                winerror::E_FAIL,
+            ));
+        }
+        self.recording = false;
+
+        self.command_list.close()?;
+        self.queue
+            .execute_command_lists(std::slice::from_ref(&self.command_list));
+
+        let value = self.timeline.signal_from(&self.queue)?;
+        let allocator_index =
+            (self.frame_index % self.command_allocators.len() as u64) as usize;
+        self.allocator_fence_values[allocator_index] = value;
+        self.frame_index += 1;
+
+        Ok(value)
+    }
+
+    /// Blocks until all the submitted frames are finished. A frame that
+    /// is being recorded is not submitted, it can still be submitted
+    /// afterwards
+    pub fn wait_idle(&mut self) -> DxResult<()> {
+        self.timeline.flush(&self.queue)
+    }
+
+    /// Number of frames submitted so far
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+
+    pub fn frames_in_flight(&self) -> u32 {
+        self.command_allocators.len() as u32
+    }
+
+    pub fn queue(&self) -> &CommandQueue {
+        &self.queue
+    }
+
+    /// E.g. to make another queue wait for the results of a frame by
+    /// the value returned from [HeadlessFrame::submit]
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+}
+
+impl<K: CopyCapable> Drop for HeadlessFrame<K> {
+    fn drop(&mut self) {
+        if let Err(err) = self.wait_idle() {
+            error!("Cannot wait for GPU on headless frame shutdown: {}", err);
+        }
+    }
+}
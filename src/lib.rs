@@ -8,8 +8,43 @@ This project provides low-level bindings for D3D12 API. It utilizes `rust-bindge
 - `D3D12` and `DXGI` prefixes have been stripped from all types, functions and enum variants (e.g. this library exposes `CommandListType::Direct` instead of `D3D12_COMMAND_LIST_TYPE_DIRECT`) since it's very likely that people who use it already know the name of the API it wraps (it's mentioned in the crate name after all), and do not need to be constantly reminded about it :) Also all type and function names have been reshaped with respect to the official Rust code style (e.g. `get_gpu_descriptor_handle_for_heap_start` instead of `GetGPUDescriptorHandleForHeapStart`). Note that most, but *not* all the enum variant names have been converted yet, so some of them will be changed in future versions
 - D3D12 Agility SDK is integrated into the library and shipped along with it (see `heterogeneous_multiadapter.rs` for an example of exporting required symbols). Current SDK version is `1.600.10`
 - PIX markers (they require enabling `pix` feature which is off by default not to introduce a dependency on `WinPixEventRuntime.dll` for people who don't need it)
+- supported targets are `x86_64-pc-windows-msvc` and `aarch64-pc-windows-msvc` (the raw bindings are shared, since both targets use the same 64-bit data model). The shipped Agility SDK and PIX binaries are x64 ones, so for ARM64 builds the corresponding binaries have to be put into the `arm64` subdirectories of `extern/D3D12AgilitySDK/bin` and `extern/WinPixEventRuntime/bin`
+- pool metrics: descriptor allocators, descriptor rings and the memory allocator implement `Metrics` trait, which can also emit their usage as PIX counters (`Metrics::report_pix_counters`) so that it shows up on timing captures
+- upload ring: `UploadHeapRing` is a persistently mapped upload buffer that suballocates 256-byte-aligned chunks (CPU pointer and `GpuVirtualAddress`) for dynamic constants and staging data, and reuses them once the fence value of their frame has been reached
+- fence timelines: `Timeline` wraps a fence with a monotonically increasing value, so that GPU work can be signaled from a queue and waited for on the CPU or on another queue by the returned value; explicit signal values that do not increase and CPU waits for values that have not been signaled are reported as errors in debug builds, and `Timeline::rebase` resets the fence for processes that approach the end of the 64-bit range; for teardown and resizes `CommandQueue::wait_idle` and `Device::wait_all_queues_idle` block until queues are idle without any fence of the caller's
+- fence waits: `Fence::wait_blocking` waits for a value with an optional timeout using an event that is reused per thread, and `Fence::wait_async` (`fence_async` feature) returns a `FenceWait` future that is woken from a thread pool wait, so it works with any executor
+- memory aliasing: `AliasGroup` places transient resources at the same heap offset, tracks which of them is active and records aliasing barriers on switches
+- barrier batching: `BarrierBatcher` collects transitions requested by helpers (`MsaaTarget::resolve_batched`, `Blitter::blit_batched`, `MipmapGenerator::generate_batched`) and the application, coalesces redundant ones (A -> B -> C becomes A -> C, round trips are dropped) and records them in a single `resource_barrier` call on `flush`; resources can be tagged with their current state so that only the required state has to be passed
+- resource state tracking: `StateTracker` records per-subresource states of registered resources, so that transitions only need the desired state, and batches the resulting barriers until they are flushed before a draw or dispatch
+- PSO caching: `ContentHash` hashes pipeline state and root signature descriptions by the contents they point to (shader bytecode, input layouts, root parameters) with a stable FNV-1a hasher, and `PsoCache` uses these digests to return existing root signatures and PSOs for repeated requests. PSOs can be persisted between runs with `PipelineLibrary` (`PipelineLibrary::load_from_file`/`save_to_file`), either directly or attached to `PsoCache`, and with `PipelineState::get_cached_blob`
+- texture readback: `read_back_texture` copies a `SubresourceRange` of a texture into a readback buffer on the given queue, waits for the copy and returns a `CpuImage` per subresource with tightly packed rows along with its size and format (the plane format for depth-stencil planes), e.g. for screenshots and golden image tests; `Swapchain::capture_back_buffer` does the same for the current back buffer
+- typed query readback: `TimestampQueryPool` and `PipelineStatisticsQueryPool` own a query heap and a readback ring and return the results of a finished frame as `Duration`s and `PipelineStatistics` structures instead of raw mapped memory; `Device::try_set_stable_power_state` locks the GPU clocks for reproducible timings and reports a missing developer mode as `DxError::DeveloperModeRequired`
+- headless frame loop: `HeadlessFrame` rotates command allocators over a queue and a `Timeline` without a swapchain (`begin`/`submit`/`wait_idle`), for services that run periodic GPU compute jobs
+- command allocator recycling: `CommandAllocatorPool` hands out (allocator, command list) pairs and resets allocators only after the fence value they were released with has been reached (optionally on a background thread that keeps a few lists reset and open, with acquire and reset timings exposed as metrics), and `FrameContext` builds a frame loop on top of it that submits all the command lists requested during a frame and limits the number of frames in flight
+- descriptor heap dumps: views written through `DescriptorAllocator::create_*` methods are recorded (by default in debug builds), and `DescriptorAllocator::dump` formats the allocated slots as a table of view types, resource names and formats, which helps tracking down wrong-texture-bound issues
+- descriptor heap limits: `HeapLimits` reports the shader-visible heap sizes allowed by the resource binding tier, and descriptor allocators and rings refuse to create larger shader-visible heaps with `DxError::DescriptorHeapLimitExceeded`
+- display change handling (`dxgi` feature): `DisplayChangeHandler` notices when the window moves to another output or the HDR state of its display changes, re-queries `OutputDesc1` from a fresh factory if the old one is not current anymore and switches the swapchain color space, recreating the back buffers via `Swapchain::resize_buffers` when the format has to change
+- back buffer sets (`dxgi` feature): `BackBufferSet` holds `Swapchain::buffers()` along with their RTVs, which can be created in a per-index format (e.g. sRGB views of flip model back buffers), and recreates them after a resize
+- cargo features for trimming the API surface: `dxgi` (`Factory`, `Adapter`, `Output`, `Swapchain`), `debug-tools` (`Debug`, `DebugDevice`, `InfoQueue`) and `helpers` (descriptor allocators, `ReadbackRing`, `UploadHeapRing`, `MsaaTarget`, `CrossAdapterResource`, `Timeline`, `HeadlessFrame`, `FrameContext`, query pools, `PsoCache`, `AliasGroup`, `BarrierBatcher`, `StateTracker`, `read_back_texture`) are enabled by default along with `core`, so tools that only need compute contexts can use `default-features = false, features = ["core"]` (plus `eq` and `hash` if they need the derives) and create the device with `Device::new_on_default_adapter`. `raytracing` and `video` are reserved for the corresponding wrappers and are currently empty
+- embedding offline-compiled shaders (`dxc` feature): `export_shader` compiles a shader from a build script, and `include_dxil!` macro embeds its bytecode along with the generated constants like thread group size and root parameter indices
+- runtime shader compilation (`dxc` feature): `DxcCompiler` loads dxcompiler.dll and compiles HLSL to DXIL, reporting compilation errors as `DxResult`
+- `windows` crate interop (`windows-interop` feature): `From` conversions in both directions between `Device`, `CommandQueue`, `CommandAllocator`, `CommandList`, `Resource`, `Heap`, `Fence`, `RootSignature`, `PipelineState`, `QueryHeap` and the corresponding `windows::Win32::Graphics::Direct3D12` interfaces (`TryFrom` for command lists, whose type is checked at runtime); converting by value hands the reference over, converting by reference adds one
+- swapchains for winit windows (`raw-window-handle` feature): `Factory::create_swapchain_for_window` takes anything that implements `HasRawWindowHandle` instead of a raw `HWND`, so no unsafe code is needed to create a swapchain for a winit window
+- texture blits (`dxc` feature): `Blitter` copies textures between different formats and sizes with a compute shader, optionally converting to/from sRGB and flipping the image
+- mipmap generation (`dxc` feature): `MipmapGenerator` fills the mip chain of 2D textures and texture arrays from their most detailed mip with a compute shader (optionally filtering sRGB data in linear space), transitioning each source mip right before it is read through a `BarrierBatcher`
+- FFI interop (`capi` feature): `from_ffi`/`into_ffi`/`as_ffi` on `Device`, `CommandQueue`, `Resource` and `Fence` pass them across C ABI boundaries (e.g. to plugin DLLs or native middleware) with explicit reference ownership (`FfiOwnership`); the same conversions and AddRef/Release are exported as `#[no_mangle] extern "C"` functions (`rusty_d3d12_device_from_ffi`, `rusty_d3d12_add_ref`, ...) for C and C++ hosts
+- GPU memory suballocation (`allocator` feature): `Allocator` places resources into large heaps and returns `Allocation` handles that release their memory on drop
+- sample framework (`framework` feature): `run_sample` creates a window, a device and a swapchain and drives a `SampleApp` implementation, see `examples/framework_triangle.rs`
+- quickstart (`quickstart` feature): `quickstart::init` creates a process-wide factory, device and a queue of each type, new references to which are then returned by `quickstart::device()`, `quickstart::graphics_queue()` etc., so small experiments and tests don't have to pass them around
+- texture loading (`texture_loading` feature): `texture_loading::TextureData` parses DDS files (including cube maps, arrays, volumes and block-compressed formats) or decodes PNG/JPEG/other images through WIC, and `TextureData::upload` creates a committed texture, records `update_subresources` into a command list and returns the resource with a suggested `ShaderResourceViewDesc`, which `LoadedTexture::create_srv` (or `allocate_srv` with a `DescriptorAllocator`) writes into a descriptor
+- copy, indirect execution and draw validation (`validation` feature): `copy_buffer_region`, `copy_texture_region` and `copy_tiles` check offsets and sizes against the resource descriptions and log an error instead of recording an out-of-bounds copy, and `execute_indirect` checks the 4-byte alignment of argument and count buffer offsets and that the argument buffer can hold the maximum command count with the signature's stride. Draws are reported, but still recorded, if the primitive topology doesn't match the topology type of the bound pipeline state, or if the formats of the bound render targets differ from its `RTVFormats` (only checked for pipeline states created from `GraphicsPipelineStateDesc`). Recording any command into a command list that has been closed and not reset yet, closing it again included, panics in debug builds; release builds report the command and skip it, and `close` returns an error. The tracked pipeline states and command lists are forgotten when their last reference is released
+- resource usage statistics (`resource_stats` feature): barrier transitions, copies and clears are counted per resource name and summarized by `ResourceStats::report` with the most transitioned resources first, which helps to find barrier thrashing; the counters can also be emitted as PIX counters
 - automatic COM object reference counting via `Clone` and `Drop` traits implementations with optional logging possibilities (e.g. see `impl_com_object_refcount_named` macro)
 - D3D12 debug callback support (please note that `debug_callback` feature needs to be activated explicitly since `ID3D12InfoQueue1` interface is only supported on Windows 11), object autonaming and GPU validation
+- subresource indices: `SubresourceIndex` names a subresource by mip level, array slice and plane and converts to and from the flat index (`calc_subresource`, `from_subresource`, `Resource::subresource`), and `TextureCopyLocation::new_subresource` accepts it directly
+- object summaries: `describe()` of `Device`, `CommandQueue`, `Resource`, `Heap` and `Fence` returns a one-line summary with the object name, description and heap properties for logs and panic messages, since their `Debug` output is just the interface pointer
+- private data: the wrappers of D3D12 and DXGI objects have `set_private_data`, `get_private_data`, `set_private_data_interface` and the unsafe `get_private_data_interface`, which attach arbitrary bytes or a COM object under a `Guid` (the latter is returned as an owning `PrivateDataInterface`), e.g. for tools and crash dump libraries that look up their own GUIDs
+- adapter identity: `Device::get_adapter_luid` returns the `Luid` of the device's adapter, and `Factory::enum_adapter_by_luid`/`find_adapter_for_luid` and `Device::new_on_luid` go the other way, so that the device can be created on the adapter requested by OpenXR, CUDA or a video encoder; devices and queues created by such libraries can be wrapped with `Device::from_raw_with_luid` and `CommandQueue::from_raw_on_device`, which check that they are the expected ones
 - convenience macros for wrapping API calls (`dx_call!` and `dx_try!`)
 - not yet covered APIs can be accessed through raw bindings exports, and new APIs can be wrapped in semi-automatic mode with the help of `conversion_assist.py` script
 - most of the APIs provided by `rusty-d3d12` are *not* marked as `unsafe` since it pollutes client code while giving little in return: obviously, a lot of bad things can happen due to misusing D3D12, but guarding against something like that is a task for a *high*-level graphics library or engine. So `unsafe` is reserved for something unsafe that happens on Rust side, e.g. accessing unions (see `ClearValue::color()`)
@@ -73,24 +108,68 @@ let pso = device
     .create_pipeline_state(&pso_desc)
     .expect("Cannot create PSO");
 ```
+- or assemble a PSO stream from individual subobjects:
+```rust
+let pso_stream = PipelineStateStream::default()
+    .with_root_signature(root_signature)
+    .with_cs_bytecode(&cs_bytecode);
+
+let pso_desc = PipelineStateStreamDesc::default()
+    .with_pipeline_state_subobject_stream(pso_stream.as_byte_stream());
+```
 
 Please see the project [repository](https://github.com/curldivergence/rusty-d3d12) for more info, including runnable [examples](https://github.com/curldivergence/rusty-d3d12/tree/main/examples).
 */
 
-use log::{trace, warn};
+use log::{error, info, trace, warn};
 use std::default::Default;
 use std::ffi::{c_void, CString};
+use std::marker::PhantomData;
 use std::os::raw::c_char;
-use std::{slice, str};
+use std::str;
 use winapi::shared::winerror;
 
 #[macro_use]
 extern crate static_assertions;
 
+// The raw bindings are generated for 64-bit Windows. x86_64 and aarch64
+// share the LLP64 data model and the calling convention, so the same
+// bindings serve both of them, but 32-bit targets would need different ones
+#[cfg(not(target_pointer_width = "64"))]
+compile_error!("rusty-d3d12 supports only 64-bit targets (x86_64 and aarch64)");
+
 mod raw_bindings;
 
+assert_eq_size!(SIZE_T, usize);
+assert_eq_size!(LONG_PTR, isize);
+
+// The raw bindings are not a part of the supported API, so they are only
+// re-exported with `raw` feature and can change in any release
+#[cfg(feature = "raw")]
 #[doc(hidden)]
 pub use raw_bindings::d3d12::*;
+#[cfg(not(feature = "raw"))]
+use raw_bindings::d3d12::*;
+#[cfg(all(feature = "dxc", feature = "raw"))]
+#[doc(hidden)]
+pub use raw_bindings::dxc::*;
+#[cfg(all(feature = "dxc", not(feature = "raw")))]
+use raw_bindings::dxc::*;
+
+// Window, monitor and event handles, HRESULTs and the interfaces returned
+// by `as_raw` appear in the public signatures, so they are always exported
+pub use raw_bindings::d3d12::{
+    ID3D10Blob, ID3D12CommandAllocator, ID3D12CommandList, ID3D12CommandQueue,
+    ID3D12CommandSignature, ID3D12Debug5, ID3D12DebugDevice,
+    ID3D12DescriptorHeap, ID3D12Device2, ID3D12DeviceChild, ID3D12Fence,
+    ID3D12GraphicsCommandList6, ID3D12Heap, ID3D12InfoQueue, ID3D12InfoQueue1,
+    ID3D12Pageable, ID3D12PipelineLibrary1, ID3D12PipelineState,
+    ID3D12ProtectedResourceSession, ID3D12QueryHeap, ID3D12Resource,
+    ID3D12RootSignature, ID3DBlob, IDXGIAdapter3, IDXGIFactory6, IDXGIOutput6,
+    IDXGISwapChain4, IUnknown, HANDLE, HMONITOR, HRESULT, HWND,
+};
+#[cfg(feature = "dxc")]
+pub use raw_bindings::dxc::{IDxcBlob, IDxcResult};
 
 #[macro_use]
 mod utils;
@@ -105,11 +184,152 @@ pub use struct_wrappers::*;
 mod enum_wrappers;
 pub use enum_wrappers::*;
 
+#[cfg(feature = "helpers")]
+mod descriptor_allocator;
+#[cfg(feature = "helpers")]
+pub use descriptor_allocator::*;
+
+#[cfg(feature = "helpers")]
+mod readback_ring;
+#[cfg(feature = "helpers")]
+pub use readback_ring::*;
+#[cfg(feature = "helpers")]
+mod upload_heap_ring;
+#[cfg(feature = "helpers")]
+pub use upload_heap_ring::*;
+
+mod deprecations;
+pub use deprecations::*;
+
+mod metrics;
+pub use metrics::*;
+
+mod feature_support;
+pub use feature_support::*;
+
+#[cfg(feature = "helpers")]
+mod msaa_target;
+#[cfg(feature = "helpers")]
+pub use msaa_target::*;
+
+#[cfg(feature = "helpers")]
+mod cross_adapter;
+#[cfg(feature = "helpers")]
+pub use cross_adapter::*;
+
+#[cfg(feature = "helpers")]
+mod timeline;
+#[cfg(feature = "helpers")]
+pub use timeline::*;
+
+#[cfg(feature = "helpers")]
+mod headless_frame;
+#[cfg(feature = "helpers")]
+pub use headless_frame::*;
+
+#[cfg(feature = "helpers")]
+mod frame_context;
+#[cfg(feature = "helpers")]
+pub use frame_context::*;
+
+#[cfg(feature = "helpers")]
+mod query_pool;
+#[cfg(feature = "helpers")]
+pub use query_pool::*;
+
+#[cfg(feature = "helpers")]
+mod pso_cache;
+#[cfg(feature = "helpers")]
+pub use pso_cache::*;
+
+#[cfg(feature = "helpers")]
+mod alias_group;
+#[cfg(feature = "helpers")]
+pub use alias_group::*;
+#[cfg(feature = "helpers")]
+mod barrier_batcher;
+#[cfg(feature = "helpers")]
+pub use barrier_batcher::*;
+#[cfg(feature = "helpers")]
+mod state_tracker;
+#[cfg(feature = "helpers")]
+pub use state_tracker::*;
+#[cfg(feature = "helpers")]
+mod texture_readback;
+#[cfg(feature = "helpers")]
+pub use texture_readback::*;
+
+#[cfg(feature = "allocator")]
+mod allocator;
+#[cfg(feature = "allocator")]
+pub use allocator::*;
+
+#[cfg(feature = "dxgi")]
+mod display_change;
+#[cfg(feature = "dxgi")]
+pub use display_change::*;
+#[cfg(feature = "dxgi")]
+mod back_buffer_set;
+#[cfg(feature = "dxgi")]
+pub use back_buffer_set::*;
+
+#[cfg(feature = "validation")]
+mod validation;
+
+#[cfg(feature = "windows-interop")]
+mod windows_interop;
+
+#[cfg(feature = "fence_async")]
+mod fence_async;
+#[cfg(feature = "fence_async")]
+pub use fence_async::*;
+
+#[cfg(feature = "resource_stats")]
+mod resource_stats;
+#[cfg(feature = "resource_stats")]
+pub use resource_stats::*;
+
+#[cfg(feature = "framework")]
+mod framework;
+#[cfg(feature = "framework")]
+pub use framework::*;
+
+#[cfg(feature = "quickstart")]
+pub mod quickstart;
+
+#[cfg(feature = "texture_loading")]
+pub mod texture_loading;
+
+#[cfg(test)]
+mod test_utils;
+
+// Name as it is shown by the describe() methods
+fn describe_name(name: DxResult<Option<String>>) -> String {
+    match name {
+        Ok(Some(name)) => format!("'{}'", name),
+        _ => String::from("unnamed"),
+    }
+}
+
 // ToDo: macro?
 fn cast_to_ppv<T>(pointer: &mut *mut T) -> *mut *mut std::ffi::c_void {
     pointer as *mut *mut T as *mut *mut std::ffi::c_void
 }
 
+// Vtable entries of live COM objects are never null, so checking them on
+// every call (which is what Option::unwrap does) is only worth it in debug
+// builds, where it catches bindings that don't match the runtime interface
+macro_rules! vtbl_entry {
+    ($vtbl:expr, $method_name:ident) => {{
+        let entry = (*$vtbl).$method_name;
+        debug_assert!(
+            entry.is_some(),
+            concat!("Null vtable entry for ", stringify!($method_name))
+        );
+        entry.unwrap_unchecked()
+    }};
+}
+
 // Behold! This macro and the one below now can accept both methods and
 // plain functions.
 // ToDo: is there a way to fix the trailing comma issue?
@@ -117,7 +337,7 @@ fn cast_to_ppv<T>(pointer: &mut *mut T) -> *mut *mut std::ffi::c_void {
 macro_rules! dx_call {
     ($object_ptr:expr, $method_name:ident, $($args:expr),*) => {{
         let vtbl = (*$object_ptr).lpVtbl;
-        let raw_func = (*vtbl).$method_name.unwrap();
+        let raw_func = vtbl_entry!(vtbl, $method_name);
         raw_func($object_ptr, $($args),*)
     }};
     ($fn_name:ident $args:tt) => {$fn_name $args;}
@@ -127,7 +347,7 @@ macro_rules! dx_call {
 macro_rules! dx_try {
     ($object_ptr:expr, $method_name:ident, $($args:expr),*) => {{
         let vtbl = (*$object_ptr).lpVtbl;
-        let raw_func = (*vtbl).$method_name.unwrap();
+        let raw_func = vtbl_entry!(vtbl, $method_name);
         let ret_code =  raw_func($object_ptr, $($args),*);
         if fail!(ret_code) {
             return Err(DxError::new(
@@ -147,17 +367,133 @@ macro_rules! dx_try {
     }}
 }
 
-const MAX_FUNC_NAME_LEN: usize = 64;
+/// Checks that the command list is open before recording into it, see
+/// `validation::validate_recording`. The method name is used in the messages
+macro_rules! validate_recording {
+    ($command_list:expr, $method_name:literal) => {
+        #[cfg(feature = "validation")]
+        if !validation::validate_recording(
+            $command_list.this as usize,
+            $method_name,
+        ) {
+            return;
+        }
+    };
+    ($command_list:expr, $method_name:literal, Err) => {
+        #[cfg(feature = "validation")]
+        if !validation::validate_recording(
+            $command_list.this as usize,
+            $method_name,
+        ) {
+            return Err(DxError::new($method_name, winerror::E_FAIL));
+        }
+    };
+}
+
 const MAX_ERROR_MSG_LEN: usize = 512;
-pub struct DxError([u8; MAX_FUNC_NAME_LEN], HRESULT);
+
+/// Error returned by the fallible wrappers. The HRESULTs that are usually
+/// handled programmatically get their own variants, all the other ones
+/// end up in [DxError::Other]. Every variant keeps the name
+/// of the failed function. More variants can be added in minor releases
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "hash", derive(Hash))]
+#[non_exhaustive]
+pub enum DxError {
+    /// DXGI_ERROR_DEVICE_REMOVED, DXGI_ERROR_DEVICE_HUNG,
+    /// DXGI_ERROR_DEVICE_RESET or DXGI_ERROR_DRIVER_INTERNAL_ERROR.
+    /// If `reason` is DXGI_ERROR_DEVICE_REMOVED, the actual cause can be
+    /// obtained with [Device::get_device_removed_reason]
+    DeviceRemoved {
+        func_name: &'static str,
+        reason: HRESULT,
+    },
+    /// E_OUTOFMEMORY
+    OutOfMemory { func_name: &'static str },
+    /// E_INVALIDARG
+    InvalidArgs { func_name: &'static str },
+    /// DXGI_ERROR_NOT_FOUND
+    DxgiNotFound { func_name: &'static str },
+    /// A shader-visible descriptor heap larger than the resource binding
+    /// tier allows has been requested, see [HeapLimits]. Reported as
+    /// E_INVALIDARG
+    DescriptorHeapLimitExceeded {
+        func_name: &'static str,
+        heap_type: DescriptorHeapType,
+        requested: u32,
+        limit: u32,
+    },
+    /// The call is only allowed when Windows developer mode is on, see
+    /// [Device::is_developer_mode_enabled]. Reported as E_FAIL
+    DeveloperModeRequired { func_name: &'static str },
+    /// [Resource::map] has been called on a resource that lives in a heap
+    /// without CPU access. Only detected in debug builds, reported as
+    /// E_INVALIDARG
+    ResourceNotCpuAccessible {
+        func_name: &'static str,
+        heap_type: HeapType,
+    },
+    Other {
+        func_name: &'static str,
+        hresult: HRESULT,
+    },
+}
 
 impl DxError {
-    pub fn new(func_name: &str, err_code: HRESULT) -> Self {
-        use std::io::Write;
-        let mut func_name_owned = [0; MAX_FUNC_NAME_LEN];
-        write!(&mut func_name_owned[..], "{}", func_name,)
-            .expect("Ironically, DxError creation has failed");
-        Self(func_name_owned, err_code)
+    pub fn new(func_name: &'static str, err_code: HRESULT) -> Self {
+        match err_code {
+            winerror::DXGI_ERROR_DEVICE_REMOVED
+            | winerror::DXGI_ERROR_DEVICE_HUNG
+            | winerror::DXGI_ERROR_DEVICE_RESET
+            | winerror::DXGI_ERROR_DRIVER_INTERNAL_ERROR => {
+                Self::DeviceRemoved {
+                    func_name,
+                    reason: err_code,
+                }
+            }
+            winerror::E_OUTOFMEMORY => Self::OutOfMemory { func_name },
+            winerror::E_INVALIDARG => Self::InvalidArgs { func_name },
+            winerror::DXGI_ERROR_NOT_FOUND => Self::DxgiNotFound { func_name },
+            _ => Self::Other {
+                func_name,
+                hresult: err_code,
+            },
+        }
+    }
+
+    pub fn func_name(&self) -> &'static str {
+        match *self {
+            Self::DeviceRemoved { func_name, .. }
+            | Self::OutOfMemory { func_name }
+            | Self::InvalidArgs { func_name }
+            | Self::DxgiNotFound { func_name }
+            | Self::DescriptorHeapLimitExceeded { func_name, .. }
+            | Self::DeveloperModeRequired { func_name }
+            | Self::ResourceNotCpuAccessible { func_name, .. }
+            | Self::Other { func_name, .. } => func_name,
+        }
+    }
+
+    pub fn hresult(&self) -> HRESULT {
+        match *self {
+            Self::DeviceRemoved { reason, .. } => reason,
+            Self::OutOfMemory { .. } => winerror::E_OUTOFMEMORY,
+            Self::InvalidArgs { .. } => winerror::E_INVALIDARG,
+            Self::DxgiNotFound { .. } => winerror::DXGI_ERROR_NOT_FOUND,
+            Self::DescriptorHeapLimitExceeded { .. } => winerror::E_INVALIDARG,
+            Self::DeveloperModeRequired { .. } => winerror::E_FAIL,
+            Self::ResourceNotCpuAccessible { .. } => winerror::E_INVALIDARG,
+            Self::Other { hresult, .. } => hresult,
+        }
+    }
+
+    pub fn is_device_removed(&self) -> bool {
+        matches!(self, Self::DeviceRemoved { .. })
+    }
+
+    pub fn is_out_of_memory(&self) -> bool {
+        matches!(self, Self::OutOfMemory { .. })
     }
 
     fn write_as_str(
@@ -173,7 +509,7 @@ impl DxError {
             let _char_count = FormatMessageA(
                 FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS,
                 std::ptr::null(),
-                self.1 as u32,
+                self.hresult() as u32,
                 0,
                 &mut error_message as *mut _ as *mut i8,
                 MAX_ERROR_MSG_LEN as u32,
@@ -190,12 +526,36 @@ impl DxError {
             write!(
                 f,
                 "{} failed: [{:#010x}] {}",
-                std::str::from_utf8(&self.0)
-                    .expect("Cannot format error message: function name is not valid utf-8"),
-                self.1,
+                self.func_name(),
+                self.hresult(),
                 std::str::from_utf8(&error_message)
-                    .expect("Cannot format error message: error description is not valid utf-8"),
-            )
+                    .expect("Cannot format error message: error description is not valid utf-8")
+                    .trim_end_matches(|c| c == '\0' || c == ' '),
+            )?;
+        }
+
+        match *self {
+            Self::DescriptorHeapLimitExceeded {
+                heap_type,
+                requested,
+                limit,
+                ..
+            } => write!(
+                f,
+                " ({:?} heap of {} descriptors exceeds the limit of {})",
+                heap_type, requested, limit
+            ),
+            Self::DeveloperModeRequired { .. } => {
+                write!(f, " (Windows developer mode is not enabled)")
+            }
+            Self::ResourceNotCpuAccessible { heap_type, .. } => write!(
+                f,
+                " (resources in {:?} heaps are not CPU-accessible, copy the \
+                 data through an upload or readback buffer, e.g. with \
+                 UploadHeapRing or ReadbackRing)",
+                heap_type
+            ),
+            _ => Ok(()),
         }
     }
 }
@@ -228,6 +588,7 @@ macro_rules! fail {
     };
 }
 
+#[cfg(feature = "dxgi")]
 macro_rules! cast_to_iunknown {
     ($pointer:expr) => {{
         let mut result: *mut IUnknown = std::ptr::null_mut();
@@ -245,10 +606,10 @@ macro_rules! cast_to_iunknown {
 
 // ToDo: revise this (macro -> trait?)
 macro_rules! impl_com_object_clone_drop{
-    ($struct_type:ty
+    (impl<$($generic:ident),*> $struct_type:ty
         $(, $extra_member:ident)*
     ) => {
-        impl Clone for $struct_type {
+        impl<$($generic),*> Clone for $struct_type {
             fn clone(&self) -> Self {
                 self.add_ref();
                 Self {
@@ -260,12 +621,58 @@ macro_rules! impl_com_object_clone_drop{
             }
         }
 
-        impl Drop for $struct_type {
+        impl<$($generic),*> Drop for $struct_type {
             fn drop(&mut self) {
                 self.release();
             }
         }
     };
+    ($struct_type:ty
+        $(, $extra_member:ident)*
+    ) => {
+        impl_com_object_clone_drop!(impl<> $struct_type $(, $extra_member)*);
+    };
+}
+
+/// Interface pointer access for native libraries layered on top of
+/// the wrappers, e.g. vendor extensions
+macro_rules! impl_com_object_raw {
+    (impl<$($generic:ident),*> $struct_type:ty, $interface:ty
+        $(, $extra_member:ident: $extra_value:expr)*
+    ) => {
+        impl<$($generic),*> $struct_type {
+            /// Returns the interface pointer without adding a reference,
+            /// so it is valid as long as the wrapper is. Native code that
+            /// keeps the pointer must AddRef it on its own
+            pub fn as_raw(&self) -> *mut $interface {
+                self.this
+            }
+
+            /// Wraps the interface pointer, taking over one reference,
+            /// which is released when the wrapper is dropped. Pointers
+            /// that the caller keeps using must be AddRef'ed first
+            ///
+            /// # Safety
+            ///
+            /// `raw` must be a valid non-null pointer to the interface
+            pub unsafe fn from_raw(raw: *mut $interface) -> Self {
+                Self {
+                    this: raw,
+                    $(
+                        $extra_member: $extra_value,
+                    )*
+                }
+            }
+        }
+    };
+    ($struct_type:ty, $interface:ty
+        $(, $extra_member:ident: $extra_value:expr)*
+    ) => {
+        impl_com_object_raw!(
+            impl<> $struct_type, $interface
+            $(, $extra_member: $extra_value)*
+        );
+    };
 }
 
 macro_rules! impl_com_object_refcount_unnamed {
@@ -307,10 +714,10 @@ macro_rules! impl_com_object_refcount_unnamed {
 }
 
 macro_rules! impl_com_object_refcount_named {
-    ($struct_type:ty
+    (impl<$($generic:ident),*> $struct_type:ty
         $(, $extra_member:ident)*
         ) => {
-        impl $struct_type {
+        impl<$($generic),*> $struct_type {
             pub fn add_ref(&self) -> u64 {
                 unsafe {
                     let live_ref_count: ULONG = dx_call!(self.this, AddRef,);
@@ -320,10 +727,10 @@ macro_rules! impl_com_object_refcount_named {
                         trace!(
                                 "Increased refcount for {} '{}', live reference count: {}",
                                 stringify!($struct_type),
-                                match name.as_ref() {
-                                    Ok(name) => name,
-                                    Err(_) => "unnamed object"
-                                },
+                                name.as_ref()
+                                    .ok()
+                                    .and_then(|name| name.as_deref())
+                                    .unwrap_or("unnamed object"),
                             live_ref_count
                         )
                     }
@@ -341,10 +748,10 @@ macro_rules! impl_com_object_refcount_named {
                         trace!(
                             "Released {} '{}', live reference count: {}",
                             stringify!($struct_type),
-                            match name.as_ref() {
-                                Ok(name) => name,
-                                Err(_) => "unnamed object",
-                            },
+                            name.as_ref()
+                                .ok()
+                                .and_then(|name| name.as_deref())
+                                .unwrap_or("unnamed object"),
                             live_ref_count
                         );
                     }
@@ -352,14 +759,19 @@ macro_rules! impl_com_object_refcount_named {
                 }
             }
         }
-    }
+    };
+    ($struct_type:ty
+        $(, $extra_member:ident)*
+        ) => {
+        impl_com_object_refcount_named!(impl<> $struct_type $(, $extra_member)*);
+    };
 }
 
 macro_rules! impl_com_object_set_get_name {
-    ($struct_type:ty
+    (impl<$($generic:ident),*> $struct_type:ty
         $(, $extra_member:ident)*
     ) => {
-        impl $struct_type {
+        impl<$($generic),*> $struct_type {
             pub fn set_name(&self, name: &str) -> DxResult<()> {
                 let name_wstr = widestring::U16CString::from_str(name)
                     .expect("Cannot convert object name to utf-16");
@@ -369,34 +781,263 @@ macro_rules! impl_com_object_set_get_name {
                 Ok(())
             }
 
-            pub fn get_name(&self) -> DxResult<String> {
-                let mut buffer_size = 128u32;
-                let buffer = vec![0; buffer_size as usize];
+            /// Returns None if the object has not been named. Names set
+            /// via the ANSI WKPDID_D3DDebugObjectName GUID (e.g. by native
+            /// code) are returned as well
+            pub fn get_name(&self) -> DxResult<Option<String>> {
                 unsafe {
-                    dx_try!(
+                    let mut data_size = 0u32;
+                    let ret_code = dx_call!(
                         self.this,
                         GetPrivateData,
                         &WKPDID_D3DDebugObjectNameW,
-                        &mut buffer_size,
-                        buffer.as_ptr() as *mut std::ffi::c_void
+                        &mut data_size,
+                        std::ptr::null_mut()
+                    );
+                    if success!(ret_code) && data_size > 0 {
+                        // rounded up, names stored by native code may
+                        // have an odd byte length
+                        let mut buffer =
+                            vec![0u16; (data_size as usize).div_ceil(2)];
+                        dx_try!(
+                            self.this,
+                            GetPrivateData,
+                            &WKPDID_D3DDebugObjectNameW,
+                            &mut data_size,
+                            buffer.as_mut_ptr() as *mut std::ffi::c_void
+                        );
+
+                        let length = buffer
+                            .iter()
+                            .position(|&c| c == 0)
+                            .unwrap_or(buffer.len());
+                        return Ok(Some(String::from_utf16_lossy(
+                            &buffer[..length],
+                        )));
+                    } else if ret_code != winerror::DXGI_ERROR_NOT_FOUND
+                        && fail!(ret_code)
+                    {
+                        return Err(DxError::new("GetPrivateData", ret_code));
+                    }
+
+                    let ret_code = dx_call!(
+                        self.this,
+                        GetPrivateData,
+                        &WKPDID_D3DDebugObjectName,
+                        &mut data_size,
+                        std::ptr::null_mut()
+                    );
+                    if ret_code == winerror::DXGI_ERROR_NOT_FOUND
+                        || (success!(ret_code) && data_size == 0)
+                    {
+                        return Ok(None);
+                    } else if fail!(ret_code) {
+                        return Err(DxError::new("GetPrivateData", ret_code));
+                    }
+
+                    let mut buffer = vec![0u8; data_size as usize];
+                    dx_try!(
+                        self.this,
+                        GetPrivateData,
+                        &WKPDID_D3DDebugObjectName,
+                        &mut data_size,
+                        buffer.as_mut_ptr() as *mut std::ffi::c_void
+                    );
+
+                    let length = buffer
+                        .iter()
+                        .position(|&c| c == 0)
+                        .unwrap_or(buffer.len());
+                    Ok(Some(
+                        String::from_utf8_lossy(&buffer[..length]).into_owned(),
+                    ))
+                }
+            }
+        }
+    };
+    ($struct_type:ty
+        $(, $extra_member:ident)*
+    ) => {
+        impl_com_object_set_get_name!(impl<> $struct_type $(, $extra_member)*);
+    };
+}
+
+/// Generic private data access of ID3D12Object and IDXGIObject descendants
+macro_rules! impl_com_object_private_data {
+    (impl<$($generic:ident),*> $struct_type:ty) => {
+        impl<$($generic),*> $struct_type {
+            /// Attaches a copy of `data` to the object under `guid`,
+            /// replacing the previous data. Empty `data` removes the entry
+            pub fn set_private_data(
+                &self,
+                guid: &Guid,
+                data: &[u8],
+            ) -> DxResult<()> {
+                unsafe {
+                    dx_try!(
+                        self.this,
+                        SetPrivateData,
+                        &guid.0,
+                        data.len() as u32,
+                        match data.is_empty() {
+                            true => std::ptr::null(),
+                            false => data.as_ptr() as *const std::ffi::c_void,
+                        }
                     );
                 }
+                Ok(())
+            }
 
-                widestring::U16CString::from_vec_with_nul(buffer).map_or_else(
-                    |_| Err(DxError::new("U16CString::from_vec_with_nul", -1)),
-                    |name_wstr| {
-                        name_wstr
-                            .to_string()
-                            .and_then(|name_string| Ok(name_string))
-                            .or_else(|_| {
-                                Err(DxError::new("U16CString::to_string", -1))
-                            })
-                    },
-                )
+            /// Returns None if nothing has been stored under `guid`.
+            /// Entries stored with `set_private_data_interface` must be
+            /// read with `get_private_data_interface` instead, since
+            /// reading them adds a reference to the stored object
+            pub fn get_private_data(
+                &self,
+                guid: &Guid,
+            ) -> DxResult<Option<Vec<u8>>> {
+                unsafe {
+                    let mut data_size = 0u32;
+                    let ret_code = dx_call!(
+                        self.this,
+                        GetPrivateData,
+                        &guid.0,
+                        &mut data_size,
+                        std::ptr::null_mut()
+                    );
+                    if ret_code == winerror::DXGI_ERROR_NOT_FOUND {
+                        return Ok(None);
+                    } else if fail!(ret_code) {
+                        return Err(DxError::new("GetPrivateData", ret_code));
+                    }
+
+                    let mut data = vec![0u8; data_size as usize];
+                    if data_size > 0 {
+                        dx_try!(
+                            self.this,
+                            GetPrivateData,
+                            &guid.0,
+                            &mut data_size,
+                            data.as_mut_ptr() as *mut std::ffi::c_void
+                        );
+                    }
+                    Ok(Some(data))
+                }
+            }
+
+            /// Returns the COM object stored under `guid` with
+            /// `set_private_data_interface`, or None if nothing has been
+            /// stored. Fails if the entry is not pointer-sized
+            ///
+            /// # Safety
+            ///
+            /// The entry under `guid` must have been stored with
+            /// `set_private_data_interface`: plain data of the same size
+            /// would be taken for an interface pointer
+            pub unsafe fn get_private_data_interface(
+                &self,
+                guid: &Guid,
+            ) -> DxResult<Option<PrivateDataInterface>> {
+                let mut data_size = 0u32;
+                let ret_code = dx_call!(
+                    self.this,
+                    GetPrivateData,
+                    &guid.0,
+                    &mut data_size,
+                    std::ptr::null_mut()
+                );
+                if ret_code == winerror::DXGI_ERROR_NOT_FOUND {
+                    return Ok(None);
+                } else if fail!(ret_code) {
+                    return Err(DxError::new("GetPrivateData", ret_code));
+                }
+
+                if data_size as usize != std::mem::size_of::<*mut IUnknown>() {
+                    error!(
+                        "Private data entry is {} bytes long, \
+                         not an interface pointer",
+                        data_size
+                    );
+                    return Err(DxError::new(
+                        "GetPrivateData",
+                        winerror::E_INVALIDARG,
+                    ));
+                }
+
+                // the runtime adds a reference to the returned object,
+                // which the wrapper takes over
+                let mut object: *mut IUnknown = std::ptr::null_mut();
+                dx_try!(
+                    self.this,
+                    GetPrivateData,
+                    &guid.0,
+                    &mut data_size,
+                    &mut object as *mut *mut IUnknown as *mut std::ffi::c_void
+                );
+                Ok(match object.is_null() {
+                    true => None,
+                    false => Some(PrivateDataInterface { this: object }),
+                })
+            }
+
+            /// Stores a reference to a COM object under `guid`, which is
+            /// released when the entry is replaced or the object is
+            /// destroyed. A null `object` removes the entry
+            ///
+            /// # Safety
+            ///
+            /// `object` must be null or a valid IUnknown pointer
+            pub unsafe fn set_private_data_interface(
+                &self,
+                guid: &Guid,
+                object: *mut std::ffi::c_void,
+            ) -> DxResult<()> {
+                dx_try!(
+                    self.this,
+                    SetPrivateDataInterface,
+                    &guid.0,
+                    object as *const IUnknown
+                );
+                Ok(())
             }
         }
     };
+    ($struct_type:ty) => {
+        impl_com_object_private_data!(impl<> $struct_type);
+    };
+}
+
+/// Owning wrapper around a COM object returned by
+/// `get_private_data_interface`, see [PrivateDataInterface::as_raw]
+/// for querying the interface it has been stored as
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct PrivateDataInterface {
+    pub(crate) this: *mut IUnknown,
 }
+impl_com_object_refcount_unnamed!(PrivateDataInterface);
+impl_com_object_clone_drop!(PrivateDataInterface);
+impl_com_object_raw!(PrivateDataInterface, IUnknown);
+
+// declared after COM helper macros so that the module can use them
+#[cfg(feature = "dxc")]
+mod dxc;
+#[cfg(feature = "dxc")]
+pub use dxc::*;
+
+#[cfg(all(feature = "dxc", feature = "helpers"))]
+mod blitter;
+#[cfg(all(feature = "dxc", feature = "helpers"))]
+pub use blitter::*;
+#[cfg(all(feature = "dxc", feature = "helpers"))]
+mod mipmap_generator;
+#[cfg(all(feature = "dxc", feature = "helpers"))]
+pub use mipmap_generator::*;
+
+#[cfg(feature = "capi")]
+mod capi;
+#[cfg(feature = "capi")]
+pub use capi::*;
 
 pub fn d3d_enable_experimental_shader_models() -> DxResult<()> {
     unsafe {
@@ -418,14 +1059,20 @@ pub fn d3d_enable_experimental_shader_models() -> DxResult<()> {
     }
 }
 
+#[cfg(feature = "debug-tools")]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Debug {
-    pub this: *mut ID3D12Debug5,
+    pub(crate) this: *mut ID3D12Debug5,
 }
+#[cfg(feature = "debug-tools")]
 impl_com_object_refcount_unnamed!(Debug);
+#[cfg(feature = "debug-tools")]
 impl_com_object_clone_drop!(Debug);
+#[cfg(feature = "debug-tools")]
+impl_com_object_raw!(Debug, ID3D12Debug5);
 
+#[cfg(feature = "debug-tools")]
 impl Debug {
     pub fn new() -> DxResult<Self> {
         let mut debug_interface: *mut ID3D12Debug5 = std::ptr::null_mut();
@@ -458,19 +1105,26 @@ impl Debug {
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct InfoQueue {
-    pub this: *mut ID3D12InfoQueue1,
+    pub(crate) this: *mut ID3D12InfoQueue1,
 }
 
-#[cfg(not(feature = "debug_callback"))]
+#[cfg(all(feature = "debug-tools", not(feature = "debug_callback")))]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct InfoQueue {
-    pub this: *mut ID3D12InfoQueue,
+    pub(crate) this: *mut ID3D12InfoQueue,
 }
 
+#[cfg(feature = "debug-tools")]
 impl_com_object_refcount_unnamed!(InfoQueue);
+#[cfg(feature = "debug-tools")]
 impl_com_object_clone_drop!(InfoQueue);
+#[cfg(feature = "debug_callback")]
+impl_com_object_raw!(InfoQueue, ID3D12InfoQueue1);
+#[cfg(all(feature = "debug-tools", not(feature = "debug_callback")))]
+impl_com_object_raw!(InfoQueue, ID3D12InfoQueue);
 
+#[cfg(feature = "debug-tools")]
 impl InfoQueue {
     pub fn new(
         device: &Device,
@@ -549,6 +1203,172 @@ impl InfoQueue {
         Ok(())
     }
 
+    /// Convenience wrapper around [InfoQueue::add_storage_filter_entries]
+    /// that prevents messages with the given ids from being stored,
+    /// e.g. to silence known benign warnings
+    pub fn add_denied_ids(&self, ids: &[MessageId]) -> DxResult<()> {
+        let mut filter = InfoQueueFilter::default()
+            .with_deny_list(&InfoQueueFilterDesc::default().with_id_list(ids));
+
+        self.add_storage_filter_entries(&mut filter)
+    }
+
+    /// Pushes `filter` onto the storage filter stack, so that only
+    /// the messages it lets through are stored until it is popped with
+    /// [InfoQueue::pop_storage_filter]. The filter is copied, so the lists
+    /// it points to only have to be alive during the call
+    pub fn push_storage_filter(
+        &self,
+        filter: &InfoQueueFilter,
+    ) -> DxResult<()> {
+        unsafe {
+            dx_try!(
+                self.this,
+                PushStorageFilter,
+                &filter.0 as *const _ as *mut D3D12_INFO_QUEUE_FILTER
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Pushes a filter that lets all the messages through, e.g. to see
+    /// everything for a scope without losing the filters set up before
+    pub fn push_empty_storage_filter(&self) -> DxResult<()> {
+        unsafe {
+            dx_try!(self.this, PushEmptyStorageFilter,);
+        }
+
+        Ok(())
+    }
+
+    pub fn pop_storage_filter(&self) {
+        unsafe { dx_call!(self.this, PopStorageFilter,) }
+    }
+
+    pub fn get_storage_filter_stack_size(&self) -> u32 {
+        unsafe { dx_call!(self.this, GetStorageFilterStackSize,) }
+    }
+
+    /// Removes the entries of the filter on top of the storage stack
+    pub fn clear_storage_filter(&self) {
+        unsafe { dx_call!(self.this, ClearStorageFilter,) }
+    }
+
+    /// Same as [InfoQueue::push_storage_filter], but for the messages
+    /// returned by [InfoQueue::get_messages], stored messages which
+    /// don't pass the filter are kept
+    pub fn push_retrieval_filter(
+        &self,
+        filter: &InfoQueueFilter,
+    ) -> DxResult<()> {
+        unsafe {
+            dx_try!(
+                self.this,
+                PushRetrievalFilter,
+                &filter.0 as *const _ as *mut D3D12_INFO_QUEUE_FILTER
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn push_empty_retrieval_filter(&self) -> DxResult<()> {
+        unsafe {
+            dx_try!(self.this, PushEmptyRetrievalFilter,);
+        }
+
+        Ok(())
+    }
+
+    pub fn pop_retrieval_filter(&self) {
+        unsafe { dx_call!(self.this, PopRetrievalFilter,) }
+    }
+
+    pub fn get_retrieval_filter_stack_size(&self) -> u32 {
+        unsafe { dx_call!(self.this, GetRetrievalFilterStackSize,) }
+    }
+
+    pub fn clear_retrieval_filter(&self) {
+        unsafe { dx_call!(self.this, ClearRetrievalFilter,) }
+    }
+
+    /// Adds a message to the queue as if it has been reported by the
+    /// runtime, it goes through the storage filters and the callbacks
+    pub fn add_message(
+        &self,
+        category: MessageCategory,
+        severity: MessageSeverity,
+        id: MessageId,
+        description: &str,
+    ) -> DxResult<()> {
+        let description = match CString::new(description) {
+            Ok(description) => description,
+            Err(_) => {
+                error!("Message description contains a nul character");
+                return Err(DxError::new(
+                    "InfoQueue::add_message",
+                    winerror::E_INVALIDARG,
+                ));
+            }
+        };
+        unsafe {
+            dx_try!(
+                self.this,
+                AddMessage,
+                category as i32,
+                severity as i32,
+                id as i32,
+                description.as_ptr()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Adds a message with [MessageCategory::ApplicationDefined] category,
+    /// e.g. to mark where the application's own steps begin in the log
+    pub fn add_application_message(
+        &self,
+        severity: MessageSeverity,
+        description: &str,
+    ) -> DxResult<()> {
+        let description = match CString::new(description) {
+            Ok(description) => description,
+            Err(_) => {
+                error!("Message description contains a nul character");
+                return Err(DxError::new(
+                    "InfoQueue::add_application_message",
+                    winerror::E_INVALIDARG,
+                ));
+            }
+        };
+        unsafe {
+            dx_try!(
+                self.this,
+                AddApplicationMessage,
+                severity as i32,
+                description.as_ptr()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Sets the maximum number of messages that can be stored,
+    /// u64::MAX (-1 in C API) means no limit
+    pub fn set_message_count_limit(&self, limit: u64) -> DxResult<()> {
+        unsafe {
+            dx_try!(self.this, SetMessageCountLimit, limit);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_message_count_limit(&self) -> u64 {
+        unsafe { dx_call!(self.this, GetMessageCountLimit,) }
+    }
+
     pub fn get_messages(&self) -> DxResult<Vec<String>> {
         let mut messages: Vec<String> = Vec::new();
         unsafe {
@@ -580,7 +1400,7 @@ impl InfoQueue {
                 );
 
                 let message_string =
-                    str::from_utf8_unchecked(slice::from_raw_parts(
+                    str::from_utf8_unchecked(std::slice::from_raw_parts(
                         (*message_struct).pDescription as *const u8,
                         (*message_struct).DescriptionByteLength as usize,
                     ));
@@ -604,48 +1424,182 @@ impl InfoQueue {
         Ok(())
     }
 
+    /// Registers a closure that will be called by the debug layer for every
+    /// message that passes the filter. The returned cookie owns the closure
+    /// and unregisters it when dropped, so it has to be kept alive for as
+    /// long as the messages are of interest.
+    ///
+    /// The debug layer may report messages from several threads at once,
+    /// so the closure is called under a mutex and must not use the device
+    /// in a way that reports further messages. Message IDs this crate
+    /// doesn't know about are passed as [MessageId::Unknown]
     #[cfg(feature = "debug_callback")]
-    pub fn register_callback(
-        &self,
-        callback: unsafe extern "C" fn(
-            i32,
-            i32,
-            i32,
-            *const c_char,
-            *mut c_void,
-        ) -> (),
+    pub fn register_callback<F>(
+        &self,
+        callback: F,
         filter_flags: MessageCallbackFlags,
-        // ToDo: context and cookie
-    ) -> DxResult<()> {
+    ) -> DxResult<MessageCallbackCookie>
+    where
+        F: FnMut(MessageCategory, MessageSeverity, MessageId, &str)
+            + Send
+            + 'static,
+    {
+        let callback: *mut MessageCallback =
+            std::boxed::Box::into_raw(std::boxed::Box::new(
+                std::sync::Mutex::new(std::boxed::Box::new(callback)),
+            ));
+
+        let mut cookie = 0u32;
         unsafe {
-            let mut cookie = 0u32;
-            dx_try!(
+            let hr = dx_call!(
                 self.this,
                 RegisterMessageCallback,
-                Some(callback),
+                Some(message_callback_trampoline),
                 filter_flags as i32,
-                std::ptr::null_mut(),
+                callback as *mut c_void,
                 &mut cookie
             );
+            if fail!(hr) {
+                drop(std::boxed::Box::from_raw(callback));
+                return Err(DxError::new("RegisterMessageCallback", hr));
+            }
         }
 
-        Ok(())
+        Ok(MessageCallbackCookie {
+            info_queue: self.clone(),
+            cookie,
+            callback,
+        })
     }
-}
 
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct DebugDevice {
-    pub this: *mut ID3D12DebugDevice,
+    /// Same as dropping the cookie, but reports the failure to unregister
+    #[cfg(feature = "debug_callback")]
+    pub fn unregister_callback(
+        &self,
+        mut cookie: MessageCallbackCookie,
+    ) -> DxResult<()> {
+        cookie.unregister()
+    }
 }
-impl_com_object_refcount_unnamed!(DebugDevice);
-impl_com_object_clone_drop!(DebugDevice);
 
-impl DebugDevice {
-    pub fn new(device: &Device) -> DxResult<Self> {
-        let mut debug_device: *mut ID3D12DebugDevice = std::ptr::null_mut();
-        unsafe {
-            dx_try!(
+#[cfg(feature = "debug_callback")]
+type MessageCallback = std::sync::Mutex<
+    std::boxed::Box<
+        dyn FnMut(MessageCategory, MessageSeverity, MessageId, &str) + Send,
+    >,
+>;
+
+#[cfg(feature = "debug_callback")]
+unsafe extern "C" fn message_callback_trampoline(
+    category: D3D12_MESSAGE_CATEGORY,
+    severity: D3D12_MESSAGE_SEVERITY,
+    id: D3D12_MESSAGE_ID,
+    description: *const c_char,
+    context: *mut c_void,
+) {
+    use std::convert::TryFrom;
+
+    let callback = &*(context as *const MessageCallback);
+    let description = std::ffi::CStr::from_ptr(description).to_string_lossy();
+
+    let (category, severity) = match (
+        MessageCategory::try_from(category),
+        MessageSeverity::try_from(severity),
+    ) {
+        (Ok(category), Ok(severity)) => (category, severity),
+        _ => {
+            warn!(
+                "Unknown D3D12 message category {} or severity {}: {}",
+                category, severity, description
+            );
+            return;
+        }
+    };
+    let id = MessageId::try_from(id).unwrap_or(MessageId::Unknown);
+
+    // panics are caught below while the guard is alive, so the mutex
+    // is not expected to be poisoned
+    let mut callback = match callback.lock() {
+        Ok(callback) => callback,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    // unwinding into the debug layer is UB, so panics stop here
+    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (*callback)(category, severity, id, &description)
+    }))
+    .is_err()
+    {
+        warn!("D3D12 message callback panicked");
+    }
+}
+
+/// Identifies a callback registered with [InfoQueue::register_callback]
+/// and owns the closure passed to it. Dropping the cookie unregisters
+/// the callback
+#[cfg(feature = "debug_callback")]
+#[derive(Debug)]
+pub struct MessageCallbackCookie {
+    info_queue: InfoQueue,
+    cookie: u32,
+    // null once the callback has been unregistered
+    callback: *mut MessageCallback,
+}
+
+#[cfg(feature = "debug_callback")]
+impl MessageCallbackCookie {
+    pub fn cookie(&self) -> u32 {
+        self.cookie
+    }
+
+    fn unregister(&mut self) -> DxResult<()> {
+        if self.callback.is_null() {
+            return Ok(());
+        }
+
+        unsafe {
+            // the closure is leaked on failure since the debug layer
+            // might still call it
+            dx_try!(
+                self.info_queue.this,
+                UnregisterMessageCallback,
+                self.cookie
+            );
+            drop(std::boxed::Box::from_raw(self.callback));
+        }
+        self.callback = std::ptr::null_mut();
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "debug_callback")]
+impl Drop for MessageCallbackCookie {
+    fn drop(&mut self) {
+        if let Err(err) = self.unregister() {
+            error!("Cannot unregister D3D12 message callback: {}", err);
+        }
+    }
+}
+
+#[cfg(feature = "debug-tools")]
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct DebugDevice {
+    pub(crate) this: *mut ID3D12DebugDevice,
+}
+#[cfg(feature = "debug-tools")]
+impl_com_object_refcount_unnamed!(DebugDevice);
+#[cfg(feature = "debug-tools")]
+impl_com_object_clone_drop!(DebugDevice);
+#[cfg(feature = "debug-tools")]
+impl_com_object_raw!(DebugDevice, ID3D12DebugDevice);
+
+#[cfg(feature = "debug-tools")]
+impl DebugDevice {
+    pub fn new(device: &Device) -> DxResult<Self> {
+        let mut debug_device: *mut ID3D12DebugDevice = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
                 device.this,
                 QueryInterface,
                 &IID_ID3D12DebugDevice,
@@ -675,14 +1629,22 @@ impl DebugDevice {
     }
 }
 
+#[cfg(feature = "dxgi")]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Factory {
-    pub this: *mut IDXGIFactory6,
+    pub(crate) this: *mut IDXGIFactory6,
 }
+#[cfg(feature = "dxgi")]
 impl_com_object_refcount_unnamed!(Factory);
+#[cfg(feature = "dxgi")]
+impl_com_object_private_data!(Factory);
+#[cfg(feature = "dxgi")]
 impl_com_object_clone_drop!(Factory);
+#[cfg(feature = "dxgi")]
+impl_com_object_raw!(Factory, IDXGIFactory6);
 
+#[cfg(feature = "dxgi")]
 impl Factory {
     pub fn new(flags: CreateFactoryFlags) -> DxResult<Self> {
         let mut factory: *mut IDXGIFactory6 = std::ptr::null_mut();
@@ -696,6 +1658,14 @@ impl Factory {
         Ok(Factory { this: factory })
     }
 
+    /// Returns false after the adapter or output configuration has changed
+    /// (e.g. a monitor has been plugged in or HDR has been toggled), in
+    /// which case a new factory has to be created to enumerate
+    /// the up-to-date outputs
+    pub fn is_current(&self) -> bool {
+        unsafe { dx_call!(self.this, IsCurrent,) != 0 }
+    }
+
     pub fn enum_adapters(&self) -> DxResult<Vec<Adapter>> {
         let mut result: Vec<Adapter> = vec![];
 
@@ -769,6 +1739,50 @@ impl Factory {
         Ok(result)
     }
 
+    /// Finds the adapter with the given LUID, e.g. the one a device has been
+    /// created on (see [Device::get_adapter_luid]). Fails with
+    /// DXGI_ERROR_NOT_FOUND if there is no such adapter
+    pub fn enum_adapter_by_luid(&self, luid: Luid) -> DxResult<Adapter> {
+        let mut hw_adapter: *mut IDXGIAdapter3 = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                EnumAdapterByLuid,
+                luid.0,
+                &IID_IDXGIAdapter3,
+                cast_to_ppv(&mut hw_adapter)
+            );
+        }
+
+        Ok(Adapter { this: hw_adapter })
+    }
+
+    /// Same as [Factory::enum_adapter_by_luid], but returns None instead
+    /// of an error if no adapter has the LUID (e.g. the one requested by
+    /// an OpenXR runtime belongs to a GPU which has been removed since)
+    pub fn find_adapter_for_luid(
+        &self,
+        luid: Luid,
+    ) -> DxResult<Option<Adapter>> {
+        let mut hw_adapter: *mut IDXGIAdapter3 = std::ptr::null_mut();
+        let ret_code = unsafe {
+            dx_call!(
+                self.this,
+                EnumAdapterByLuid,
+                luid.0,
+                &IID_IDXGIAdapter3,
+                cast_to_ppv(&mut hw_adapter)
+            )
+        };
+        if ret_code == winerror::DXGI_ERROR_NOT_FOUND {
+            return Ok(None);
+        } else if ret_code != winerror::S_OK {
+            return Err(DxError::new("EnumAdapterByLuid", ret_code));
+        }
+
+        Ok(Some(Adapter { this: hw_adapter }))
+    }
+
     pub fn enum_warp_adapter(&self) -> DxResult<Adapter> {
         let mut hw_adapter: *mut IDXGIAdapter3 = std::ptr::null_mut();
         unsafe {
@@ -791,6 +1805,23 @@ impl Factory {
         command_queue: &CommandQueue,
         window_handle: HWND,
         desc: &SwapChainDesc,
+    ) -> DxResult<Swapchain> {
+        self.create_swapchain_for_hwnd(command_queue, window_handle, desc, None)
+    }
+
+    /// Same as [Factory::create_swapchain], but also allows passing
+    /// the fullscreen mode description, e.g. to create the swapchain
+    /// in fullscreen mode or with a specific refresh rate
+    ///
+    /// # Safety
+    ///
+    /// window_handle must be valid
+    pub unsafe fn create_swapchain_for_hwnd(
+        &self,
+        command_queue: &CommandQueue,
+        window_handle: HWND,
+        desc: &SwapChainDesc,
+        fullscreen_desc: Option<&SwapChainFullscreenDesc>,
     ) -> DxResult<Swapchain> {
         let mut temp_hw_swapchain: *mut IDXGISwapChain1 = std::ptr::null_mut();
 
@@ -800,20 +1831,93 @@ impl Factory {
             cast_to_iunknown!(command_queue.this),
             window_handle,
             &desc.0,
-            std::ptr::null(),
+            fullscreen_desc
+                .map_or(std::ptr::null(), |fullscreen_desc| &fullscreen_desc.0),
             std::ptr::null_mut(),
             &mut temp_hw_swapchain
         );
 
-        let mut hw_swapchain: *mut IDXGISwapChain4 = std::ptr::null_mut();
+        Swapchain::from_swapchain1(temp_hw_swapchain)
+    }
+
+    /// Same as [Factory::create_swapchain], but takes the window handle
+    /// from `window`, e.g. a winit window, so no unsafe code is needed
+    /// on the caller's side. Fails with E_INVALIDARG if `window` is not
+    /// a Win32 window
+    #[cfg(feature = "raw-window-handle")]
+    pub fn create_swapchain_for_window(
+        &self,
+        command_queue: &CommandQueue,
+        window: &impl raw_window_handle::HasRawWindowHandle,
+        desc: &SwapChainDesc,
+    ) -> DxResult<Swapchain> {
+        match window.raw_window_handle() {
+            raw_window_handle::RawWindowHandle::Windows(handle)
+                if !handle.hwnd.is_null() =>
+            unsafe {
+                self.create_swapchain(command_queue, handle.hwnd as HWND, desc)
+            },
+            _ => {
+                error!("Swapchains can only be created for Win32 windows");
+                Err(DxError::new(
+                    "Factory::create_swapchain_for_window",
+                    winerror::E_INVALIDARG,
+                ))
+            }
+        }
+    }
+
+    /// Creates a swapchain that is not bound to a window, so that it can
+    /// be set as a DirectComposition visual content (or as a XAML
+    /// SwapChainPanel's one) by passing [Swapchain::as_raw] to the compositor.
+    /// The swapchain must use one of the flip models and
+    /// [Scaling::Stretch], and its width and height must be set
+    pub fn create_swapchain_for_composition(
+        &self,
+        command_queue: &CommandQueue,
+        desc: &SwapChainDesc,
+    ) -> DxResult<Swapchain> {
+        let mut temp_hw_swapchain: *mut IDXGISwapChain1 = std::ptr::null_mut();
+
+        unsafe {
+            dx_try!(
+                self.this,
+                CreateSwapChainForComposition,
+                cast_to_iunknown!(command_queue.this),
+                &desc.0,
+                std::ptr::null_mut(),
+                &mut temp_hw_swapchain
+            );
+
+            Swapchain::from_swapchain1(temp_hw_swapchain)
+        }
+    }
+
+    /// Creates a swapchain for a UWP-style CoreWindow
+    ///
+    /// # Safety
+    ///
+    /// window must be a valid pointer to the IUnknown interface of
+    /// a CoreWindow object
+    pub unsafe fn create_swapchain_for_core_window(
+        &self,
+        command_queue: &CommandQueue,
+        window: *mut IUnknown,
+        desc: &SwapChainDesc,
+    ) -> DxResult<Swapchain> {
+        let mut temp_hw_swapchain: *mut IDXGISwapChain1 = std::ptr::null_mut();
+
         dx_try!(
-            temp_hw_swapchain,
-            QueryInterface,
-            &IID_IDXGISwapChain4,
-            cast_to_ppv(&mut hw_swapchain)
+            self.this,
+            CreateSwapChainForCoreWindow,
+            cast_to_iunknown!(command_queue.this),
+            window,
+            &desc.0,
+            std::ptr::null_mut(),
+            &mut temp_hw_swapchain
         );
 
-        Ok(Swapchain { this: hw_swapchain })
+        Swapchain::from_swapchain1(temp_hw_swapchain)
     }
 
     pub fn make_window_association(
@@ -835,14 +1939,22 @@ impl Factory {
 }
 
 /// Wrapper around IDXGIAdapter3 interface
+#[cfg(feature = "dxgi")]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Adapter {
-    pub this: *mut IDXGIAdapter3,
+    pub(crate) this: *mut IDXGIAdapter3,
 }
+#[cfg(feature = "dxgi")]
 impl_com_object_refcount_unnamed!(Adapter);
+#[cfg(feature = "dxgi")]
+impl_com_object_private_data!(Adapter);
+#[cfg(feature = "dxgi")]
 impl_com_object_clone_drop!(Adapter);
+#[cfg(feature = "dxgi")]
+impl_com_object_raw!(Adapter, IDXGIAdapter3);
 
+#[cfg(feature = "dxgi")]
 impl Adapter {
     pub fn get_desc(&self) -> DxResult<AdapterDesc> {
         let mut hw_adapter_desc = AdapterDesc::default();
@@ -851,162 +1963,618 @@ impl Adapter {
         }
         Ok(hw_adapter_desc)
     }
-}
-
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct Device {
-    pub this: *mut ID3D12Device2,
-}
-impl_com_object_refcount_unnamed!(Device);
-impl_com_object_clone_drop!(Device);
-
-// ToDo: clean up Send and Sync implementations
-unsafe impl Send for Device {}
-// unsafe impl Sync for Device {}
 
-impl Device {
-    pub fn check_feature_support<T>(
-        &self,
-        feature: Feature,
-        feature_support_data: &mut T,
-    ) -> DxResult<()> {
+    /// Queries IDXGIAdapter4, which is available starting from
+    /// Windows 10 1803, and fails with E_NOINTERFACE on older systems
+    pub fn get_desc3(&self) -> DxResult<AdapterDesc3> {
+        let mut adapter_desc = AdapterDesc3::default();
         unsafe {
-            let data = feature_support_data as *mut _ as *mut std::ffi::c_void;
-            let data_size = std::mem::size_of::<T>() as u32;
-
+            let mut adapter: *mut IDXGIAdapter4 = std::ptr::null_mut();
             dx_try!(
                 self.this,
-                CheckFeatureSupport,
-                feature as i32,
-                data,
-                data_size
+                QueryInterface,
+                &IID_IDXGIAdapter4,
+                cast_to_ppv(&mut adapter)
             );
+            let ret_code = dx_call!(adapter, GetDesc3, &mut adapter_desc.0);
+            dx_call!(adapter, Release,);
+            if fail!(ret_code) {
+                return Err(DxError::new("GetDesc3", ret_code));
+            }
         }
-
-        Ok(())
+        Ok(adapter_desc)
     }
 
-    pub fn create_command_allocator(
+    /// Returns the OS-provided memory budget and the process' current usage.
+    /// Local segment group is the dedicated video memory on discrete
+    /// adapters, non-local is the system memory available to the GPU
+    pub fn query_video_memory_info(
         &self,
-        command_list_type: CommandListType,
-    ) -> DxResult<CommandAllocator> {
-        let mut hw_command_allocator: *mut ID3D12CommandAllocator =
-            std::ptr::null_mut();
-
+        node_index: u32,
+        segment_group: MemorySegmentGroup,
+    ) -> DxResult<VideoMemoryInfo> {
+        let mut info = VideoMemoryInfo::default();
         unsafe {
             dx_try!(
                 self.this,
-                CreateCommandAllocator,
-                command_list_type as i32,
-                &IID_ID3D12CommandAllocator,
-                cast_to_ppv(&mut hw_command_allocator)
-            )
+                QueryVideoMemoryInfo,
+                node_index,
+                segment_group as i32,
+                &mut info.0
+            );
         }
-
-        Ok(CommandAllocator {
-            this: hw_command_allocator,
-        })
+        Ok(info)
     }
 
-    pub fn create_command_list(
+    pub fn set_video_memory_reservation(
         &self,
-        command_list_type: CommandListType,
-        command_allocator: &CommandAllocator,
-        initial_state: Option<&PipelineState>,
-    ) -> DxResult<CommandList> {
-        let mut hw_command_list: *mut ID3D12GraphicsCommandList6 =
-            std::ptr::null_mut();
-
+        node_index: u32,
+        segment_group: MemorySegmentGroup,
+        reservation: ByteCount,
+    ) -> DxResult<()> {
         unsafe {
             dx_try!(
                 self.this,
-                CreateCommandList,
-                0,
-                command_list_type as i32,
-                command_allocator.this,
-                match initial_state {
-                    Some(state) => state.this,
-                    None => std::ptr::null_mut(),
-                },
-                &IID_ID3D12CommandList,
-                cast_to_ppv(&mut hw_command_list)
-            )
+                SetVideoMemoryReservation,
+                node_index,
+                segment_group as i32,
+                reservation.0
+            );
         }
-
-        Ok(CommandList {
-            this: hw_command_list,
-        })
+        Ok(())
     }
 
-    pub fn create_command_queue(
+    /// The event is signaled whenever the budget returned by
+    /// [Adapter::query_video_memory_info] changes. Returns a cookie
+    /// to be passed to
+    /// [Adapter::unregister_video_memory_budget_change_notification]
+    pub fn register_video_memory_budget_change_notification(
         &self,
-        desc: &CommandQueueDesc,
-    ) -> DxResult<CommandQueue> {
-        let mut hw_queue: *mut ID3D12CommandQueue = std::ptr::null_mut();
+        event: &Win32Event,
+    ) -> DxResult<u32> {
+        let mut cookie = 0;
         unsafe {
             dx_try!(
                 self.this,
-                CreateCommandQueue,
-                &desc.0,
-                &IID_ID3D12CommandQueue,
-                cast_to_ppv(&mut hw_queue)
+                RegisterVideoMemoryBudgetChangeNotificationEvent,
+                event.handle,
+                &mut cookie
             );
         }
-
-        Ok(CommandQueue { this: hw_queue })
+        Ok(cookie)
     }
 
-    pub fn create_committed_resource(
+    pub fn unregister_video_memory_budget_change_notification(
         &self,
-        heap_props: &HeapProperties,
-        heap_flags: HeapFlags,
-        resource_desc: &ResourceDesc,
-        initial_state: ResourceStates,
-        optimized_clear_value: Option<&ClearValue>,
-    ) -> DxResult<Resource> {
-        let mut hw_resource: *mut ID3D12Resource = std::ptr::null_mut();
-
+        cookie: u32,
+    ) {
         unsafe {
-            dx_try!(
+            dx_call!(
                 self.this,
-                CreateCommittedResource,
-                &heap_props.0,
-                heap_flags.bits(),
-                &resource_desc.0,
-                initial_state.bits(),
-                match optimized_clear_value {
-                    Some(clear_value) => {
-                        &clear_value.0
-                    }
-                    None => std::ptr::null(),
-                },
-                &IID_ID3D12Resource,
-                cast_to_ppv(&mut hw_resource)
-            )
+                UnregisterVideoMemoryBudgetChangeNotification,
+                cookie
+            );
         }
-
-        Ok(Resource { this: hw_resource })
     }
 
-    pub fn create_compute_pipeline_state(
-        &self,
-        pso_desc: &ComputePipelineStateDesc,
-    ) -> DxResult<PipelineState> {
-        let mut hw_pipeline_state: *mut ID3D12PipelineState =
-            std::ptr::null_mut();
+    /// Returns the outputs (monitors) connected to the adapter. Note that
+    /// the outputs of hybrid systems are usually enumerated on
+    /// the integrated adapter, even if the discrete one does the rendering
+    pub fn enum_outputs(&self) -> DxResult<Vec<Output>> {
+        let mut result: Vec<Output> = vec![];
+
         unsafe {
-            dx_try!(
-                self.this,
+            let mut output_index = 0;
+            loop {
+                let mut temp_output: *mut IDXGIOutput = std::ptr::null_mut();
+
+                let ret_code = dx_call!(
+                    self.this,
+                    EnumOutputs,
+                    output_index,
+                    &mut temp_output
+                );
+                if ret_code == winerror::DXGI_ERROR_NOT_FOUND {
+                    break;
+                } else if ret_code != winerror::S_OK {
+                    return Err(DxError::new("EnumOutputs", ret_code));
+                }
+
+                let mut real_output: *mut IDXGIOutput6 = std::ptr::null_mut();
+                let ret_code = dx_call!(
+                    temp_output,
+                    QueryInterface,
+                    &IID_IDXGIOutput6,
+                    cast_to_ppv(&mut real_output)
+                );
+                dx_call!(temp_output, Release,);
+                if fail!(ret_code) {
+                    return Err(DxError::new("QueryInterface", ret_code));
+                }
+
+                result.push(Output { this: real_output });
+                output_index += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "dxgi")]
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Output {
+    pub(crate) this: *mut IDXGIOutput6,
+}
+#[cfg(feature = "dxgi")]
+impl_com_object_refcount_unnamed!(Output);
+#[cfg(feature = "dxgi")]
+impl_com_object_private_data!(Output);
+#[cfg(feature = "dxgi")]
+impl_com_object_clone_drop!(Output);
+#[cfg(feature = "dxgi")]
+impl_com_object_raw!(Output, IDXGIOutput6);
+
+#[cfg(feature = "dxgi")]
+unsafe impl Send for Output {}
+
+#[cfg(feature = "dxgi")]
+impl Output {
+    pub fn get_desc(&self) -> DxResult<OutputDesc> {
+        let mut desc = OutputDesc::default();
+        unsafe {
+            dx_try!(self.this, GetDesc, &mut desc.0);
+        }
+        Ok(desc)
+    }
+
+    /// Includes the HDR capabilities of the display. They are captured when
+    /// the factory is created, so outputs enumerated from a factory that is
+    /// not current anymore (see [Factory::is_current]) may report stale ones
+    pub fn get_desc1(&self) -> DxResult<OutputDesc1> {
+        let mut desc = OutputDesc1::default();
+        unsafe {
+            dx_try!(self.this, GetDesc1, &mut desc.0);
+        }
+        Ok(desc)
+    }
+
+    /// Returns the display modes the output supports for `format`
+    pub fn get_display_mode_list(
+        &self,
+        format: Format,
+        flags: EnumModesFlags,
+    ) -> DxResult<Vec<ModeDesc>> {
+        unsafe {
+            // the mode list can change between the two calls (e.g. when
+            // a monitor is plugged in), in which case we have to start over
+            loop {
+                let mut num_modes = 0;
+                dx_try!(
+                    self.this,
+                    GetDisplayModeList,
+                    format as i32,
+                    flags.bits(),
+                    &mut num_modes,
+                    std::ptr::null_mut()
+                );
+
+                let mut modes = vec![ModeDesc::default(); num_modes as usize];
+                let ret_code = dx_call!(
+                    self.this,
+                    GetDisplayModeList,
+                    format as i32,
+                    flags.bits(),
+                    &mut num_modes,
+                    modes.as_mut_ptr() as *mut DXGI_MODE_DESC
+                );
+                if ret_code == winerror::DXGI_ERROR_MORE_DATA {
+                    continue;
+                } else if fail!(ret_code) {
+                    return Err(DxError::new("GetDisplayModeList", ret_code));
+                }
+
+                modes.truncate(num_modes as usize);
+                return Ok(modes);
+            }
+        }
+    }
+
+    /// Zero width/height, refresh rate, format etc. in `mode_to_match` mean
+    /// "don't care". If `device` is passed, the format can be left unknown
+    /// and will be matched against the ones the device supports for
+    /// scan-out
+    pub fn find_closest_matching_mode(
+        &self,
+        mode_to_match: &ModeDesc,
+        device: Option<&Device>,
+    ) -> DxResult<ModeDesc> {
+        let mut closest_match = ModeDesc::default();
+        unsafe {
+            dx_try!(
+                self.this,
+                FindClosestMatchingMode,
+                &mode_to_match.0,
+                &mut closest_match.0,
+                device.map_or(std::ptr::null_mut(), |device| {
+                    device.this as *mut IUnknown
+                })
+            );
+        }
+        Ok(closest_match)
+    }
+
+    /// Blocks the calling thread until the next vertical blank
+    pub fn wait_for_vblank(&self) -> DxResult<()> {
+        unsafe {
+            dx_try!(self.this, WaitForVBlank,);
+        }
+        Ok(())
+    }
+}
+
+// {4C6A1E3D-9B52-4F1E-A7D0-3E5B8C2F61A9}
+const NAMING_POLICY_GUID: GUID = GUID {
+    Data1: 0x4c6a1e3d,
+    Data2: 0x9b52,
+    Data3: 0x4f1e,
+    Data4: [0xa7, 0xd0, 0x3e, 0x5b, 0x8c, 0x2f, 0x61, 0xa9],
+};
+
+static NAMING_POLICY_COUNTER: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(0);
+
+/// Debug name policy that the crate's helpers apply to the objects they
+/// create on behalf of the user (e.g. heap blocks, upload rings or internal
+/// PSOs). It is stored in device's private data, so every [Device] wrapper
+/// pointing to the same ID3D12Device (including the ones returned by
+/// `get_device()` methods) shares it
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NamingPolicy {
+    prefix: String,
+    numbering: bool,
+}
+
+impl NamingPolicy {
+    pub fn set_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.prefix = prefix.to_owned();
+        self
+    }
+
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.set_prefix(prefix);
+        self
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn set_numbering(&mut self, numbering: bool) -> &mut Self {
+        self.numbering = numbering;
+        self
+    }
+
+    pub fn with_numbering(mut self, numbering: bool) -> Self {
+        self.set_numbering(numbering);
+        self
+    }
+
+    pub fn numbering(&self) -> bool {
+        self.numbering
+    }
+
+    /// Builds the final object name; numbers are unique process-wide
+    pub fn make_name(&self, base_name: &str) -> String {
+        match self.numbering {
+            true => format!(
+                "{}{} #{}",
+                self.prefix,
+                base_name,
+                NAMING_POLICY_COUNTER
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            ),
+            false => format!("{}{}", self.prefix, base_name),
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.numbering as u8];
+        bytes.extend_from_slice(self.prefix.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (numbering, prefix) = bytes.split_first()?;
+        Some(Self {
+            prefix: String::from_utf8(prefix.to_vec()).ok()?,
+            numbering: *numbering != 0,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Device {
+    pub(crate) this: *mut ID3D12Device2,
+}
+impl_com_object_refcount_unnamed!(Device);
+impl_com_object_private_data!(Device);
+impl_com_object_clone_drop!(Device);
+impl_com_object_raw!(Device, ID3D12Device2);
+
+// ToDo: clean up Send and Sync implementations
+unsafe impl Send for Device {}
+// unsafe impl Sync for Device {}
+
+impl Device {
+    pub fn check_feature_support<T>(
+        &self,
+        feature: Feature,
+        feature_support_data: &mut T,
+    ) -> DxResult<()> {
+        unsafe {
+            let data = feature_support_data as *mut _ as *mut std::ffi::c_void;
+            let data_size = std::mem::size_of::<T>() as u32;
+
+            dx_try!(
+                self.this,
+                CheckFeatureSupport,
+                feature as i32,
+                data,
+                data_size
+            );
+        }
+
+        Ok(())
+    }
+
+    /// [HeapType::GpuUpload] if the device supports it (i.e. Resizable BAR
+    /// is enabled and the runtime knows about GPU upload heaps) and
+    /// [HeapType::Upload] otherwise. Data written by the CPU into a GPU
+    /// upload heap lives in video memory, so it can be read by the GPU
+    /// directly instead of being copied into a Default heap first
+    pub fn preferred_upload_heap_type(&self) -> HeapType {
+        let mut options16 = FeatureDataOptions16::default();
+        match self
+            .check_feature_support(Feature::D3D12Options16, &mut options16)
+        {
+            Ok(_) if options16.gpu_upload_heap_supported() => {
+                HeapType::GpuUpload
+            }
+            _ => HeapType::Upload,
+        }
+    }
+
+    pub fn check_max_supported_feature_level(&self) -> DxResult<FeatureLevel> {
+        let feature_levels = [
+            FeatureLevel::L11_0,
+            FeatureLevel::L11_1,
+            FeatureLevel::L12_0,
+            FeatureLevel::L12_1,
+            FeatureLevel::L12_2,
+        ];
+        let mut data = D3D12_FEATURE_DATA_FEATURE_LEVELS {
+            NumFeatureLevels: feature_levels.len() as u32,
+            pFeatureLevelsRequested: feature_levels.as_ptr() as *const i32,
+            MaxSupportedFeatureLevel: 0,
+        };
+
+        // runtimes that don't know about 12_2 reject the whole request
+        if self
+            .check_feature_support(Feature::FeatureLevels, &mut data)
+            .is_err()
+        {
+            data.NumFeatureLevels -= 1;
+            self.check_feature_support(Feature::FeatureLevels, &mut data)?;
+        }
+
+        Ok(unsafe { std::mem::transmute(data.MaxSupportedFeatureLevel) })
+    }
+
+    pub fn copy_descriptors_simple(
+        &self,
+        num_descriptors: u32,
+        dest_descriptor_range_start: CpuDescriptorHandle,
+        src_descriptor_range_start: CpuDescriptorHandle,
+        descriptor_heaps_type: DescriptorHeapType,
+    ) {
+        unsafe {
+            dx_call!(
+                self.this,
+                CopyDescriptorsSimple,
+                num_descriptors,
+                dest_descriptor_range_start.hw_handle,
+                src_descriptor_range_start.hw_handle,
+                descriptor_heaps_type as i32
+            )
+        }
+    }
+
+    pub fn create_command_allocator(
+        &self,
+        command_list_type: CommandListType,
+    ) -> DxResult<CommandAllocator> {
+        let mut hw_command_allocator: *mut ID3D12CommandAllocator =
+            std::ptr::null_mut();
+
+        unsafe {
+            dx_try!(
+                self.this,
+                CreateCommandAllocator,
+                command_list_type as i32,
+                &IID_ID3D12CommandAllocator,
+                cast_to_ppv(&mut hw_command_allocator)
+            )
+        }
+
+        Ok(CommandAllocator {
+            this: hw_command_allocator,
+        })
+    }
+
+    pub fn create_command_list(
+        &self,
+        command_list_type: CommandListType,
+        command_allocator: &CommandAllocator,
+        initial_state: Option<&PipelineState>,
+    ) -> DxResult<CommandList> {
+        let mut hw_command_list: *mut ID3D12GraphicsCommandList6 =
+            std::ptr::null_mut();
+
+        unsafe {
+            dx_try!(
+                self.this,
+                CreateCommandList,
+                0,
+                command_list_type as i32,
+                command_allocator.this,
+                match initial_state {
+                    Some(state) => state.this,
+                    None => std::ptr::null_mut(),
+                },
+                &IID_ID3D12CommandList,
+                cast_to_ppv(&mut hw_command_list)
+            )
+        }
+
+        // a new command list is open, same as after a reset
+        #[cfg(feature = "validation")]
+        validation::record_command_list_reset(
+            hw_command_list as usize,
+            initial_state,
+        );
+
+        Ok(CommandList {
+            this: hw_command_list,
+            kind: PhantomData,
+        })
+    }
+
+    pub fn create_command_queue(
+        &self,
+        desc: &CommandQueueDesc,
+    ) -> DxResult<CommandQueue> {
+        let mut hw_queue: *mut ID3D12CommandQueue = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                CreateCommandQueue,
+                &desc.0,
+                &IID_ID3D12CommandQueue,
+                cast_to_ppv(&mut hw_queue)
+            );
+        }
+
+        Ok(CommandQueue { this: hw_queue })
+    }
+
+    /// Tries to create a queue with [CommandQueuePriority::GlobalRealTime]
+    /// priority, falling back to [CommandQueuePriority::High] if the driver
+    /// does not support it for the queue type or the process lacks
+    /// the SeIncreaseBasePriorityPrivilege the runtime requires for it.
+    /// The priority in `desc` is ignored, the returned flag tells whether
+    /// realtime priority has been granted
+    pub fn create_realtime_command_queue(
+        &self,
+        desc: &CommandQueueDesc,
+    ) -> DxResult<(CommandQueue, bool)> {
+        let mut feature_data = FeatureDataCommandQueuePriority::new(
+            desc.queue_type(),
+            CommandQueuePriority::GlobalRealTime,
+        );
+        let is_supported = self
+            .check_feature_support(
+                Feature::CommandQueuePriority,
+                &mut feature_data,
+            )
+            .is_ok()
+            && feature_data.priority_for_type_is_supported();
+
+        if is_supported {
+            match self.create_command_queue(
+                &desc
+                    .clone()
+                    .with_queue_priority(CommandQueuePriority::GlobalRealTime),
+            ) {
+                Ok(queue) => return Ok((queue, true)),
+                Err(err) => warn!(
+                    "Cannot create a realtime priority {:?} queue, falling \
+                     back to high priority: {}",
+                    desc.queue_type(),
+                    err
+                ),
+            }
+        } else {
+            warn!(
+                "Realtime priority is not supported for {:?} queues, \
+                 falling back to high priority",
+                desc.queue_type()
+            );
+        }
+
+        let queue = self.create_command_queue(
+            &desc.clone().with_queue_priority(CommandQueuePriority::High),
+        )?;
+        Ok((queue, false))
+    }
+
+    pub fn create_committed_resource(
+        &self,
+        heap_props: &HeapProperties,
+        heap_flags: HeapFlags,
+        resource_desc: &ResourceDesc,
+        initial_state: ResourceStates,
+        optimized_clear_value: Option<&ClearValue>,
+    ) -> DxResult<Resource> {
+        let mut hw_resource: *mut ID3D12Resource = std::ptr::null_mut();
+
+        unsafe {
+            dx_try!(
+                self.this,
+                CreateCommittedResource,
+                &heap_props.0,
+                heap_flags.bits(),
+                &resource_desc.0,
+                initial_state.bits(),
+                match optimized_clear_value {
+                    Some(clear_value) => {
+                        &clear_value.0
+                    }
+                    None => std::ptr::null(),
+                },
+                &IID_ID3D12Resource,
+                cast_to_ppv(&mut hw_resource)
+            )
+        }
+
+        Ok(Resource { this: hw_resource })
+    }
+
+    pub fn create_compute_pipeline_state(
+        &self,
+        pso_desc: &ComputePipelineStateDesc,
+    ) -> DxResult<PipelineState> {
+        let mut hw_pipeline_state: *mut ID3D12PipelineState =
+            std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
                 CreateComputePipelineState,
                 &pso_desc.0,
                 &IID_ID3D12PipelineState,
                 cast_to_ppv(&mut hw_pipeline_state)
             );
         }
-        Ok(PipelineState {
+        let pipeline_state = PipelineState {
             this: hw_pipeline_state,
-        })
+        };
+
+        #[cfg(feature = "validation")]
+        validation::forget_pipeline_state(&pipeline_state);
+
+        Ok(pipeline_state)
     }
 
     pub fn create_constant_buffer_view(
@@ -1030,6 +2598,9 @@ impl Device {
         desc: &DepthStencilViewDesc,
         dest_descriptor: CpuDescriptorHandle,
     ) {
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_view(dest_descriptor, resource);
+
         unsafe {
             dx_call!(
                 self.this,
@@ -1041,6 +2612,51 @@ impl Device {
         }
     }
 
+    /// `root_signature` is required if the signature changes root
+    /// arguments, and must be None otherwise
+    pub fn create_command_signature(
+        &self,
+        desc: &CommandSignatureDesc,
+        root_signature: Option<&RootSignature>,
+    ) -> DxResult<CommandSignature> {
+        let command_type = match desc.argument_descs().last() {
+            Some(argument_desc) => argument_desc.argument_type(),
+            None => {
+                error!("Command signature must have at least one argument");
+                return Err(DxError::new(
+                    "CreateCommandSignature",
+                    winerror::E_INVALIDARG,
+                ));
+            }
+        };
+
+        let mut hw_command_signature: *mut ID3D12CommandSignature =
+            std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                CreateCommandSignature,
+                &desc.0,
+                match root_signature {
+                    Some(root_signature) => root_signature.this,
+                    None => std::ptr::null_mut(),
+                },
+                &IID_ID3D12CommandSignature,
+                cast_to_ppv(&mut hw_command_signature)
+            );
+        }
+        Ok(CommandSignature {
+            this: hw_command_signature,
+            byte_stride: desc.byte_stride(),
+            arguments_size: desc
+                .argument_descs()
+                .iter()
+                .map(|argument_desc| argument_desc.size())
+                .sum(),
+            command_type,
+        })
+    }
+
     pub fn create_descriptor_heap(
         &self,
         desc: &DescriptorHeapDesc,
@@ -1058,6 +2674,13 @@ impl Device {
         }
         Ok(DescriptorHeap {
             this: hw_descriptor_heap,
+            heap_type: desc.heap_type(),
+            num_descriptors: desc.num_descriptors(),
+            handle_size: self
+                .get_descriptor_handle_increment_size(desc.heap_type()),
+            shader_visible: desc
+                .flags()
+                .contains(DescriptorHeapFlags::ShaderVisible),
         })
     }
 
@@ -1082,6 +2705,64 @@ impl Device {
         Ok(Fence { this: hw_fence })
     }
 
+    /// Signals `event` when all (or any, depending on `flags`) of the fences
+    /// reach their corresponding values
+    pub fn set_event_on_multiple_fence_completion(
+        &self,
+        fences: &[(&Fence, u64)],
+        flags: MultipleFenceWaitFlags,
+        event: &Win32Event,
+    ) -> DxResult<()> {
+        let (raw_fences, values): (Vec<*mut ID3D12Fence>, Vec<u64>) = fences
+            .iter()
+            .map(|(fence, value)| (fence.this, *value))
+            .unzip();
+
+        unsafe {
+            dx_try!(
+                self.this,
+                SetEventOnMultipleFenceCompletion,
+                raw_fences.as_ptr(),
+                values.as_ptr(),
+                fences.len() as u32,
+                flags as i32,
+                event.handle
+            );
+        }
+        Ok(())
+    }
+
+    /// Blocks the calling thread until all (or any if `wait_all` is false)
+    /// of the fences reach their corresponding values. Returns false if
+    /// `timeout` (in milliseconds) has expired before that
+    pub fn wait_for_fences(
+        &self,
+        fences: &[(Fence, u64)],
+        wait_all: bool,
+        timeout: Option<u32>,
+    ) -> DxResult<bool> {
+        let flags = match wait_all {
+            true => MultipleFenceWaitFlags::All,
+            false => MultipleFenceWaitFlags::Any,
+        };
+
+        if fences_reached(fences, wait_all) {
+            return Ok(true);
+        }
+
+        let fence_refs: Vec<(&Fence, u64)> = fences
+            .iter()
+            .map(|(fence, value)| (fence, *value))
+            .collect();
+        wait_with_thread_event(fences, wait_all, timeout, |event| {
+            self.set_event_on_multiple_fence_completion(
+                &fence_refs,
+                flags,
+                event,
+            )
+        })
+    }
+
     pub fn create_graphics_pipeline_state(
         &self,
         pso_desc: &GraphicsPipelineStateDesc,
@@ -1097,9 +2778,14 @@ impl Device {
                 cast_to_ppv(&mut hw_pipeline_state)
             );
         }
-        Ok(PipelineState {
+        let pipeline_state = PipelineState {
             this: hw_pipeline_state,
-        })
+        };
+
+        #[cfg(feature = "validation")]
+        validation::record_graphics_pipeline_state(&pipeline_state, pso_desc);
+
+        Ok(pipeline_state)
     }
 
     pub fn create_heap(&self, heap_desc: HeapDesc) -> DxResult<Heap> {
@@ -1133,38 +2819,181 @@ impl Device {
                 cast_to_ppv(&mut hw_pipeline_state)
             );
         }
-        Ok(PipelineState {
+        let pipeline_state = PipelineState {
             this: hw_pipeline_state,
+        };
+
+        #[cfg(feature = "validation")]
+        validation::forget_pipeline_state(&pipeline_state);
+
+        Ok(pipeline_state)
+    }
+
+    /// Creates a library from [PipelineLibrary::serialize] output, or
+    /// an empty one if `data` is empty. Fails with
+    /// D3D12_ERROR_DRIVER_VERSION_MISMATCH or D3D12_ERROR_ADAPTER_NOT_FOUND
+    /// if the data has been produced by another driver or adapter, see
+    /// also [PipelineLibrary::load_from_file]
+    pub fn create_pipeline_library(
+        &self,
+        data: &[u8],
+    ) -> DxResult<PipelineLibrary> {
+        let data: Option<std::sync::Arc<[u8]>> = match data.is_empty() {
+            true => None,
+            false => Some(data.into()),
+        };
+
+        let mut hw_library: *mut ID3D12PipelineLibrary1 = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                CreatePipelineLibrary,
+                match data.as_ref() {
+                    Some(data) => data.as_ptr() as *const std::ffi::c_void,
+                    None => std::ptr::null(),
+                },
+                data.as_ref().map_or(0, |data| data.len()) as SIZE_T,
+                &IID_ID3D12PipelineLibrary1,
+                cast_to_ppv(&mut hw_library)
+            );
+        }
+
+        Ok(PipelineLibrary {
+            this: hw_library,
+            data,
         })
     }
 
-    pub fn create_placed_resource(
+    pub fn create_placed_resource(
+        &self,
+        heap: &Heap,
+        heap_offset: ByteCount,
+        resource_desc: &ResourceDesc,
+        initial_state: ResourceStates,
+        optimized_clear_value: Option<&ClearValue>,
+    ) -> DxResult<Resource> {
+        let mut hw_resource: *mut ID3D12Resource = std::ptr::null_mut();
+
+        unsafe {
+            dx_try!(
+                self.this,
+                CreatePlacedResource,
+                heap.this,
+                heap_offset.0,
+                &resource_desc.0,
+                initial_state.bits(),
+                match optimized_clear_value {
+                    Some(clear_value) => {
+                        &clear_value.0
+                    }
+                    None => std::ptr::null(),
+                },
+                &IID_ID3D12Resource,
+                cast_to_ppv(&mut hw_resource)
+            )
+        }
+
+        Ok(Resource { this: hw_resource })
+    }
+
+    /// Same as [Device::create_committed_resource], but takes the initial
+    /// barrier layout instead of the state (see enhanced barriers) and
+    /// the formats the resource can be viewed as in addition to its own
+    /// one. Castable formats require ID3D12Device10 support and
+    /// [FeatureDataOptions12::relaxed_format_casting_supported]
+    pub fn create_committed_resource3(
+        &self,
+        heap_props: &HeapProperties,
+        heap_flags: HeapFlags,
+        resource_desc: &ResourceDesc1,
+        initial_layout: BarrierLayout,
+        optimized_clear_value: Option<&ClearValue>,
+        castable_formats: &[Format],
+    ) -> DxResult<Resource> {
+        let mut device: *mut ID3D12Device10 = std::ptr::null_mut();
+        let mut hw_resource: *mut ID3D12Resource = std::ptr::null_mut();
+        // the API takes a non-const pointer, but doesn't write through it
+        let mut castable_formats: Vec<DXGI_FORMAT> = castable_formats
+            .iter()
+            .map(|&format| format as DXGI_FORMAT)
+            .collect();
+        unsafe {
+            dx_try!(
+                self.this,
+                QueryInterface,
+                &IID_ID3D12Device10,
+                cast_to_ppv(&mut device)
+            );
+            let ret_code = dx_call!(
+                device,
+                CreateCommittedResource3,
+                &heap_props.0,
+                heap_flags.bits(),
+                &resource_desc.0,
+                initial_layout as i32,
+                match optimized_clear_value {
+                    Some(clear_value) => &clear_value.0,
+                    None => std::ptr::null(),
+                },
+                std::ptr::null_mut(),
+                castable_formats.len() as u32,
+                castable_formats.as_mut_ptr(),
+                &IID_ID3D12Resource,
+                cast_to_ppv(&mut hw_resource)
+            );
+            dx_call!(device, Release,);
+            if ret_code != winerror::S_OK {
+                return Err(DxError::new("CreateCommittedResource3", ret_code));
+            }
+        }
+
+        Ok(Resource { this: hw_resource })
+    }
+
+    /// Placed counterpart of [Device::create_committed_resource3]
+    pub fn create_placed_resource2(
         &self,
         heap: &Heap,
         heap_offset: ByteCount,
-        resource_desc: &ResourceDesc,
-        initial_state: ResourceStates,
+        resource_desc: &ResourceDesc1,
+        initial_layout: BarrierLayout,
         optimized_clear_value: Option<&ClearValue>,
+        castable_formats: &[Format],
     ) -> DxResult<Resource> {
+        let mut device: *mut ID3D12Device10 = std::ptr::null_mut();
         let mut hw_resource: *mut ID3D12Resource = std::ptr::null_mut();
-
+        // the API takes a non-const pointer, but doesn't write through it
+        let mut castable_formats: Vec<DXGI_FORMAT> = castable_formats
+            .iter()
+            .map(|&format| format as DXGI_FORMAT)
+            .collect();
         unsafe {
             dx_try!(
                 self.this,
-                CreatePlacedResource,
+                QueryInterface,
+                &IID_ID3D12Device10,
+                cast_to_ppv(&mut device)
+            );
+            let ret_code = dx_call!(
+                device,
+                CreatePlacedResource2,
                 heap.this,
                 heap_offset.0,
                 &resource_desc.0,
-                initial_state.bits(),
+                initial_layout as i32,
                 match optimized_clear_value {
-                    Some(clear_value) => {
-                        &clear_value.0
-                    }
+                    Some(clear_value) => &clear_value.0,
                     None => std::ptr::null(),
                 },
+                castable_formats.len() as u32,
+                castable_formats.as_mut_ptr(),
                 &IID_ID3D12Resource,
                 cast_to_ppv(&mut hw_resource)
-            )
+            );
+            dx_call!(device, Release,);
+            if ret_code != winerror::S_OK {
+                return Err(DxError::new("CreatePlacedResource2", ret_code));
+            }
         }
 
         Ok(Resource { this: hw_resource })
@@ -1196,6 +3025,11 @@ impl Device {
         resource: &Resource,
         dest_descriptor: CpuDescriptorHandle,
     ) {
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_view(dest_descriptor, resource);
+        #[cfg(feature = "validation")]
+        validation::record_render_target_view(dest_descriptor, resource, None);
+
         unsafe {
             dx_call!(
                 self.this,
@@ -1207,6 +3041,35 @@ impl Device {
         }
     }
 
+    /// Same as [Device::create_render_target_view], but with an explicit
+    /// view description, e.g. to render into a back buffer through
+    /// an sRGB view
+    pub fn create_render_target_view_with_desc(
+        &self,
+        resource: &Resource,
+        desc: &RenderTargetViewDesc,
+        dest_descriptor: CpuDescriptorHandle,
+    ) {
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_view(dest_descriptor, resource);
+        #[cfg(feature = "validation")]
+        validation::record_render_target_view(
+            dest_descriptor,
+            resource,
+            Some(desc.format()),
+        );
+
+        unsafe {
+            dx_call!(
+                self.this,
+                CreateRenderTargetView,
+                resource.this,
+                &desc.0,
+                dest_descriptor.hw_handle
+            )
+        }
+    }
+
     pub fn create_reserved_resource(
         &self,
         resource_desc: &ResourceDesc,
@@ -1235,6 +3098,40 @@ impl Device {
         Ok(Resource { this: hw_resource })
     }
 
+    /// Tiling of a reserved resource, including all its subresources
+    pub fn get_resource_tiling(&self, resource: &Resource) -> ResourceTiling {
+        let desc = resource.get_desc();
+        let mut num_subresource_tilings =
+            desc.mip_levels() as u32 * desc.array_size();
+        let mut tiling = ResourceTiling {
+            subresource_tilings: vec![
+                SubresourceTiling::default();
+                num_subresource_tilings as usize
+            ],
+            ..Default::default()
+        };
+
+        unsafe {
+            dx_call!(
+                self.this,
+                GetResourceTiling,
+                resource.this,
+                &mut tiling.num_tiles_for_entire_resource,
+                &mut tiling.packed_mip_info.0,
+                &mut tiling.standard_tile_shape.0,
+                &mut num_subresource_tilings,
+                0,
+                tiling.subresource_tilings.as_mut_ptr()
+                    as *mut D3D12_SUBRESOURCE_TILING
+            )
+        }
+        tiling
+            .subresource_tilings
+            .truncate(num_subresource_tilings as usize);
+
+        tiling
+    }
+
     pub fn create_root_signature(
         &self,
         node_mask: UINT,
@@ -1293,9 +3190,13 @@ impl Device {
         }
     }
 
+    /// `attributes` can be used to restrict which processes are able
+    /// to open the handle, default security descriptor is used if None
     pub fn create_shared_handle(
         &self,
         object: &DeviceChild,
+        attributes: Option<&SecurityAttributes>,
+        access: AccessRights,
         name: &str,
     ) -> DxResult<Handle> {
         let mut hw_handle = std::ptr::null_mut();
@@ -1307,8 +3208,11 @@ impl Device {
                 self.this,
                 CreateSharedHandle,
                 hw_device_child,
-                std::ptr::null_mut(),
-                0x10000000, // GENERIC_ALL from winnt.h
+                match attributes {
+                    Some(attributes) => &attributes.0,
+                    None => std::ptr::null(),
+                },
+                access.bits(),
                 name.as_ptr(),
                 &mut hw_handle
             );
@@ -1317,6 +3221,37 @@ impl Device {
         Ok(Handle(hw_handle))
     }
 
+    /// Creates a command list whose type is determined by the marker type,
+    /// e.g. `create_typed_command_list::<CopyKind>(...)` returns
+    /// a [CopyCommandList] that exposes only copy-related commands
+    pub fn create_typed_command_list<K: CommandListKind>(
+        &self,
+        command_allocator: &CommandAllocator,
+        initial_state: Option<&PipelineState>,
+    ) -> DxResult<CommandList<K>> {
+        let command_list = self.create_command_list(
+            K::TYPE,
+            command_allocator,
+            initial_state,
+        )?;
+        Ok(command_list.into_kind_unchecked())
+    }
+
+    /// Same as `create_typed_command_list::<BundleKind>`. Bundles only
+    /// expose the commands D3D12 allows in them, i.e. no copies, barriers,
+    /// clears or render target and viewport changes, and `command_allocator`
+    /// must have been created with [CommandListType::Bundle]
+    pub fn create_bundle(
+        &self,
+        command_allocator: &CommandAllocator,
+        initial_state: Option<&PipelineState>,
+    ) -> DxResult<Bundle> {
+        self.create_typed_command_list::<BundleKind>(
+            command_allocator,
+            initial_state,
+        )
+    }
+
     pub fn create_unordered_access_view(
         &self,
         resource: &Resource,
@@ -1342,29 +3277,51 @@ impl Device {
         }
     }
 
+    /// Creates a UAV through which shaders write sampler feedback for
+    /// `targeted_resource` into `feedback_resource`, which must have one of
+    /// the sampler feedback formats (see [Format::SamplerFeedbackMinMipOpaque]
+    /// and [Format::SamplerFeedbackMipRegionUsedOpaque]). Requires
+    /// ID3D12Device8 support, see [FeatureDataOptions7::sampler_feedback_tier]
+    pub fn create_sampler_feedback_unordered_access_view(
+        &self,
+        targeted_resource: &Resource,
+        feedback_resource: &Resource,
+        dest_descriptor: CpuDescriptorHandle,
+    ) -> DxResult<()> {
+        let mut device: *mut ID3D12Device8 = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                QueryInterface,
+                &IID_ID3D12Device8,
+                cast_to_ppv(&mut device)
+            );
+            dx_call!(
+                device,
+                CreateSamplerFeedbackUnorderedAccessView,
+                targeted_resource.this,
+                feedback_resource.this,
+                dest_descriptor.hw_handle
+            );
+            dx_call!(device, Release,);
+        }
+
+        Ok(())
+    }
+
     pub fn get_copyable_footprints(
         &self,
         resource_desc: &ResourceDesc,
         first_subresouce: u32,
         num_subresources: u32,
         base_offset: ByteCount,
-    ) -> (
-        Vec<PlacedSubresourceFootprint>,
-        Vec<u32>,
-        Vec<ByteCount>,
-        ByteCount,
-    ) {
-        let mut placed_subresource_footprints: Vec<PlacedSubresourceFootprint> =
-            vec![
-                PlacedSubresourceFootprint::default();
-                num_subresources as usize
-            ];
-
-        let mut num_rows: Vec<u32> = vec![0; num_subresources as usize];
-
-        let mut row_sizes: Vec<ByteCount> =
-            vec![ByteCount(0); num_subresources as usize];
-
+    ) -> CopyableFootprints {
+        let mut layouts = vec![
+            PlacedSubresourceFootprint::default();
+            num_subresources as usize
+        ];
+        let mut num_rows = vec![0; num_subresources as usize];
+        let mut row_sizes = vec![ByteCount(0); num_subresources as usize];
         let mut total_bytes = 0u64;
 
         unsafe {
@@ -1375,20 +3332,19 @@ impl Device {
                 first_subresouce,
                 num_subresources,
                 base_offset.0,
-                placed_subresource_footprints.as_mut_ptr()
-                    as *mut D3D12_PLACED_SUBRESOURCE_FOOTPRINT,
+                layouts.as_mut_ptr() as *mut D3D12_PLACED_SUBRESOURCE_FOOTPRINT,
                 num_rows.as_mut_ptr(),
                 row_sizes.as_mut_ptr() as *mut u64,
                 &mut total_bytes
             )
         }
 
-        (
-            placed_subresource_footprints,
+        CopyableFootprints {
+            layouts,
             num_rows,
             row_sizes,
-            ByteCount(total_bytes),
-        )
+            total_size: ByteCount(total_bytes),
+        }
     }
 
     pub fn get_descriptor_handle_increment_size(
@@ -1411,6 +3367,161 @@ impl Device {
         }
     }
 
+    /// Blocks until all the `queues` are idle. The queues are signaled
+    /// first and waited for afterwards, so they drain in parallel
+    pub fn wait_all_queues_idle(
+        &self,
+        queues: &[&CommandQueue],
+    ) -> DxResult<()> {
+        let fences = queues
+            .iter()
+            .map(|queue| {
+                let fence = self.create_fence(0, FenceFlags::None)?;
+                queue.signal(&fence, 1)?;
+                Ok(fence)
+            })
+            .collect::<DxResult<Vec<_>>>()?;
+
+        for fence in &fences {
+            wait_for_idle_fence(fence)?;
+        }
+
+        Ok(())
+    }
+
+    /// Locks the GPU clocks to their base values so that timestamp
+    /// queries give reproducible results, or unlocks them. Only available
+    /// in developer mode: depending on the OS version, calling it without
+    /// developer mode fails with E_FAIL or removes the device, so prefer
+    /// [Device::try_set_stable_power_state]
+    pub fn set_stable_power_state(&self, enable: bool) -> DxResult<()> {
+        unsafe { dx_try!(self.this, SetStablePowerState, enable as i32) };
+        Ok(())
+    }
+
+    /// Same as [Device::set_stable_power_state], but checks developer mode
+    /// first and fails with [DxError::DeveloperModeRequired] instead of
+    /// calling into the runtime if it is off
+    pub fn try_set_stable_power_state(&self, enable: bool) -> DxResult<()> {
+        if !Self::is_developer_mode_enabled() {
+            error!(
+                "Stable power state requires Windows developer mode, see \
+                 Settings > For developers"
+            );
+            return Err(DxError::DeveloperModeRequired {
+                func_name: "SetStablePowerState",
+            });
+        }
+
+        match self.set_stable_power_state(enable) {
+            Err(DxError::Other { func_name, hresult })
+                if hresult == winerror::E_FAIL =>
+            {
+                Err(DxError::DeveloperModeRequired { func_name })
+            }
+            result => result,
+        }
+    }
+
+    /// Reads the developer mode switch from the registry
+    pub fn is_developer_mode_enabled() -> bool {
+        use winapi::um::winreg::{
+            RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD,
+        };
+
+        let key = widestring::U16CString::from_str(
+            "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\AppModelUnlock",
+        )
+        .expect("Cannot convert registry key name");
+        let value_name = widestring::U16CString::from_str(
+            "AllowDevelopmentWithoutDevLicense",
+        )
+        .expect("Cannot convert registry value name");
+        let mut value = 0u32;
+        let mut value_size = std::mem::size_of::<u32>() as u32;
+        let ret_code = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                key.as_ptr(),
+                value_name.as_ptr(),
+                RRF_RT_REG_DWORD,
+                std::ptr::null_mut(),
+                &mut value as *mut u32 as *mut _,
+                &mut value_size,
+            )
+        };
+
+        ret_code == winerror::ERROR_SUCCESS as i32 && value != 0
+    }
+
+    /// Number of physical adapters (nodes) behind the device,
+    /// which is greater than one only for linked adapters
+    pub fn get_node_count(&self) -> u32 {
+        unsafe { dx_call!(self.this, GetNodeCount,) }
+    }
+
+    /// LUID of the adapter the device has been created on, see
+    /// [Factory::enum_adapter_by_luid]
+    pub fn get_adapter_luid(&self) -> Luid {
+        let mut luid = Luid::default();
+        unsafe {
+            dx_call!(self.this, GetAdapterLuid, &mut luid.0);
+        }
+        luid
+    }
+
+    /// One-line summary for logs and panic messages, unlike Debug
+    /// which only prints the interface pointer
+    pub fn describe(&self) -> String {
+        let removed_reason = self.get_device_removed_reason();
+        format!(
+            "Device {:p}: {} node(s), {}",
+            self.this,
+            self.get_node_count(),
+            match removed_reason.hresult() {
+                winerror::S_OK => String::from("not removed"),
+                _ => format!("removed ({})", removed_reason),
+            }
+        )
+    }
+
+    pub fn get_naming_policy(&self) -> Option<NamingPolicy> {
+        let mut data_size = 0u32;
+        unsafe {
+            let ret_code = dx_call!(
+                self.this,
+                GetPrivateData,
+                &NAMING_POLICY_GUID,
+                &mut data_size,
+                std::ptr::null_mut()
+            );
+            if fail!(ret_code) || data_size == 0 {
+                return None;
+            }
+
+            let mut data = vec![0u8; data_size as usize];
+            let ret_code = dx_call!(
+                self.this,
+                GetPrivateData,
+                &NAMING_POLICY_GUID,
+                &mut data_size,
+                data.as_mut_ptr() as *mut std::ffi::c_void
+            );
+            if fail!(ret_code) {
+                return None;
+            }
+
+            NamingPolicy::from_bytes(&data)
+        }
+    }
+
+    /// Returns the name for an object created by a helper, or None if no
+    /// [NamingPolicy] has been set on this device
+    pub fn make_object_name(&self, base_name: &str) -> Option<String> {
+        self.get_naming_policy()
+            .map(|policy| policy.make_name(base_name))
+    }
+
     pub fn get_resource_allocation_info(
         &self,
         visible_mask: u32,
@@ -1431,99 +3542,544 @@ impl Device {
         ResourceAllocationInfo(hw_allocation_info)
     }
 
+    #[cfg(feature = "dxgi")]
     pub fn new(adapter: &Adapter) -> DxResult<Self> {
+        Self::new_with_level(adapter, FeatureLevel::L12_0)
+    }
+
+    /// Fails if the adapter doesn't support `min_feature_level`,
+    /// [Device::check_max_supported_feature_level] can be used afterwards
+    /// to find out the actual feature level of the device
+    #[cfg(feature = "dxgi")]
+    pub fn new_with_level(
+        adapter: &Adapter,
+        min_feature_level: FeatureLevel,
+    ) -> DxResult<Self> {
+        let adapter = unsafe { cast_to_iunknown!(adapter.this) };
+        Self::create(adapter, min_feature_level)
+    }
+
+    /// Creates the device on the default adapter (the first one DXGI
+    /// enumerates), which doesn't require `dxgi` feature
+    pub fn new_on_default_adapter(
+        min_feature_level: FeatureLevel,
+    ) -> DxResult<Self> {
+        Self::create(std::ptr::null_mut(), min_feature_level)
+    }
+
+    fn create(
+        adapter: *mut IUnknown,
+        min_feature_level: FeatureLevel,
+    ) -> DxResult<Self> {
         let mut hw_device: *mut ID3D12Device2 = std::ptr::null_mut();
         unsafe {
             dx_try!(D3D12CreateDevice(
-                cast_to_iunknown!(adapter.this),
-                D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_0,
+                adapter,
+                min_feature_level as i32,
                 &IID_ID3D12Device2,
                 cast_to_ppv(&mut hw_device),
             ));
         }
 
-        Ok(Device { this: hw_device })
+        Ok(Device { this: hw_device })
+    }
+
+    /// Creates the device on the first hardware adapter (in `preference`
+    /// order) that supports `min_feature_level`, falling back to WARP
+    /// adapter if there is no such adapter
+    #[cfg(feature = "dxgi")]
+    pub fn new_with_fallback(
+        factory: &Factory,
+        preference: GpuPreference,
+        min_feature_level: FeatureLevel,
+    ) -> DxResult<(Self, AdapterKind)> {
+        for adapter in factory.enum_adapters_by_gpu_preference(preference)? {
+            let desc = adapter.get_desc()?;
+            if desc.0.Flags
+                & DXGI_ADAPTER_FLAG_DXGI_ADAPTER_FLAG_SOFTWARE as u32
+                != 0
+            {
+                continue;
+            }
+
+            if let Ok(device) =
+                Self::new_with_level(&adapter, min_feature_level)
+            {
+                return Ok((device, AdapterKind::Hardware));
+            }
+        }
+
+        warn!(
+            "No hardware adapter supports feature level {:?}, \
+             falling back to WARP",
+            min_feature_level
+        );
+        let warp_adapter = factory.enum_warp_adapter()?;
+
+        Ok((
+            Self::new_with_level(&warp_adapter, min_feature_level)?,
+            AdapterKind::Warp,
+        ))
+    }
+
+    /// Creates the device on the adapter identified by `luid`, which is
+    /// how OpenXR (xrGetD3D12GraphicsRequirementsKHR), CUDA and video
+    /// encoders specify the adapter they have to share the device with
+    #[cfg(feature = "dxgi")]
+    pub fn new_on_luid(
+        factory: &Factory,
+        luid: Luid,
+        min_feature_level: FeatureLevel,
+    ) -> DxResult<Self> {
+        let adapter = factory.enum_adapter_by_luid(luid)?;
+        Self::new_with_level(&adapter, min_feature_level)
+    }
+
+    /// Same as `from_raw`, but also checks that the device has been created
+    /// on the adapter identified by `luid`, e.g. when the device comes
+    /// from a library that has been asked to use a particular adapter.
+    /// On mismatch the reference is released and E_INVALIDARG is returned
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid non-null pointer to the interface
+    pub unsafe fn from_raw_with_luid(
+        raw: *mut ID3D12Device2,
+        luid: Luid,
+    ) -> DxResult<Self> {
+        let device = Self::from_raw(raw);
+        let device_luid = device.get_adapter_luid();
+        if device_luid != luid {
+            error!(
+                "Device {:p} has been created on adapter {:?}, expected {:?}",
+                raw, device_luid, luid
+            );
+            return Err(DxError::new(
+                "Device::from_raw_with_luid",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        Ok(device)
+    }
+
+    #[cfg(feature = "dxgi")]
+    pub fn new_with_naming_policy(
+        adapter: &Adapter,
+        naming_policy: &NamingPolicy,
+    ) -> DxResult<Self> {
+        let device = Self::new(adapter)?;
+        device.set_naming_policy(naming_policy)?;
+
+        Ok(device)
+    }
+
+    pub fn open_shared_fence_handle(&self, handle: Handle) -> DxResult<Fence> {
+        let mut hw_fence = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                OpenSharedHandle,
+                handle.0,
+                &IID_ID3D12Fence,
+                &mut hw_fence
+            );
+        }
+
+        Ok(Fence {
+            this: hw_fence as *mut ID3D12Fence,
+        })
+    }
+
+    pub fn open_shared_handle_by_name(
+        &self,
+        name: &str,
+        access: AccessRights,
+    ) -> DxResult<Handle> {
+        let mut hw_handle = std::ptr::null_mut();
+        let name = widestring::U16CString::from_str(name)
+            .expect("Cannot convert handle name");
+        unsafe {
+            dx_try!(
+                self.this,
+                OpenSharedHandleByName,
+                name.as_ptr(),
+                access.bits(),
+                &mut hw_handle
+            );
+        }
+
+        Ok(Handle(hw_handle))
+    }
+
+    pub fn open_shared_heap_handle(&self, handle: Handle) -> DxResult<Heap> {
+        let mut hw_heap = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                OpenSharedHandle,
+                handle.0,
+                &IID_ID3D12Heap,
+                &mut hw_heap
+            );
+        }
+
+        Ok(Heap {
+            this: hw_heap as *mut ID3D12Heap,
+        })
+    }
+
+    pub fn open_shared_resource_handle(
+        &self,
+        handle: Handle,
+    ) -> DxResult<Resource> {
+        let mut hw_resource = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                OpenSharedHandle,
+                handle.0,
+                &IID_ID3D12Resource,
+                &mut hw_resource
+            );
+        }
+
+        Ok(Resource {
+            this: hw_resource as *mut ID3D12Resource,
+        })
+    }
+
+    pub fn set_naming_policy(
+        &self,
+        naming_policy: &NamingPolicy,
+    ) -> DxResult<()> {
+        let data = naming_policy.to_bytes();
+        unsafe {
+            dx_try!(
+                self.this,
+                SetPrivateData,
+                &NAMING_POLICY_GUID,
+                data.len() as u32,
+                data.as_ptr() as *const std::ffi::c_void
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Loads the objects into GPU memory, blocking until they are resident
+    pub fn make_resident(&self, objects: &[Pageable]) -> DxResult<()> {
+        unsafe {
+            dx_try!(
+                self.this,
+                MakeResident,
+                objects.len() as u32,
+                objects.as_ptr() as *const *mut ID3D12Pageable
+            );
+        }
+
+        Ok(())
     }
 
-    pub fn open_shared_fence_handle(&self, handle: Handle) -> DxResult<Fence> {
-        let mut hw_fence = std::ptr::null_mut();
+    /// Allows the OS to page the objects out of GPU memory. They must not
+    /// be used by the GPU until they are made resident again
+    pub fn evict(&self, objects: &[Pageable]) -> DxResult<()> {
         unsafe {
             dx_try!(
                 self.this,
-                OpenSharedHandle,
-                handle.0,
-                &IID_ID3D12Fence,
-                &mut hw_fence
+                Evict,
+                objects.len() as u32,
+                objects.as_ptr() as *const *mut ID3D12Pageable
             );
         }
 
-        Ok(Fence {
-            this: hw_fence as *mut ID3D12Fence,
-        })
+        Ok(())
     }
 
-    pub fn open_shared_handle_by_name(&self, name: &str) -> DxResult<Handle> {
-        let mut hw_handle = std::ptr::null_mut();
-        let name = widestring::U16CString::from_str(name)
-            .expect("Cannot convert handle name");
+    /// Non-blocking version of [Device::make_resident]: returns immediately
+    /// and signals `fence_to_signal` with `fence_value_to_signal` once the
+    /// objects are resident, so command queues can wait on the fence
+    /// instead of the CPU. Requires ID3D12Device3 support
+    pub fn enqueue_make_resident(
+        &self,
+        flags: ResidencyFlags,
+        objects: &[Pageable],
+        fence_to_signal: &Fence,
+        fence_value_to_signal: u64,
+    ) -> DxResult<()> {
+        let mut device: *mut ID3D12Device3 = std::ptr::null_mut();
         unsafe {
             dx_try!(
                 self.this,
-                OpenSharedHandleByName,
-                name.as_ptr(),
-                0x10000000, // GENERIC_ALL from winnt.h
-                &mut hw_handle
+                QueryInterface,
+                &IID_ID3D12Device3,
+                cast_to_ppv(&mut device)
+            );
+            let result = dx_call!(
+                device,
+                EnqueueMakeResident,
+                flags.bits(),
+                objects.len() as u32,
+                objects.as_ptr() as *const *mut ID3D12Pageable,
+                fence_to_signal.this,
+                fence_value_to_signal
             );
+            dx_call!(device, Release,);
+
+            if fail!(result) {
+                return Err(DxError::new("EnqueueMakeResident", result));
+            }
         }
 
-        Ok(Handle(hw_handle))
+        Ok(())
     }
 
-    pub fn open_shared_heap_handle(&self, handle: Handle) -> DxResult<Heap> {
-        let mut hw_heap = std::ptr::null_mut();
+    /// Controls the driver's background optimization of shaders and PSOs,
+    /// e.g. disables it to get deterministic benchmark results. The event,
+    /// if any, is signaled once the requested measurements action is
+    /// complete. Returns whether the driver wants further measurements.
+    /// Requires ID3D12Device6 support
+    pub fn set_background_processing_mode(
+        &self,
+        mode: BackgroundProcessingMode,
+        measurements_action: MeasurementsAction,
+        event_to_signal_upon_completion: Option<&Win32Event>,
+    ) -> DxResult<bool> {
+        let mut device: *mut ID3D12Device6 = std::ptr::null_mut();
+        let mut further_measurements_desired = 0;
         unsafe {
             dx_try!(
                 self.this,
-                OpenSharedHandle,
-                handle.0,
-                &IID_ID3D12Heap,
-                &mut hw_heap
+                QueryInterface,
+                &IID_ID3D12Device6,
+                cast_to_ppv(&mut device)
             );
+            let result = dx_call!(
+                device,
+                SetBackgroundProcessingMode,
+                mode as i32,
+                measurements_action as i32,
+                match event_to_signal_upon_completion {
+                    Some(event) => event.handle,
+                    None => std::ptr::null_mut(),
+                },
+                &mut further_measurements_desired
+            );
+            dx_call!(device, Release,);
+
+            if fail!(result) {
+                return Err(DxError::new(
+                    "SetBackgroundProcessingMode",
+                    result,
+                ));
+            }
         }
 
-        Ok(Heap {
-            this: hw_heap as *mut ID3D12Heap,
-        })
+        Ok(further_measurements_desired != 0)
     }
 
-    pub fn open_shared_resource_handle(
+    /// Creates a session that protected resources and command lists
+    /// accessing them are associated with. Requires ID3D12Device4 support
+    pub fn create_protected_resource_session(
         &self,
-        handle: Handle,
-    ) -> DxResult<Resource> {
-        let mut hw_resource = std::ptr::null_mut();
+        desc: &ProtectedResourceSessionDesc,
+    ) -> DxResult<ProtectedResourceSession> {
+        let mut device: *mut ID3D12Device4 = std::ptr::null_mut();
+        let mut hw_session: *mut ID3D12ProtectedResourceSession =
+            std::ptr::null_mut();
         unsafe {
             dx_try!(
                 self.this,
-                OpenSharedHandle,
-                handle.0,
-                &IID_ID3D12Resource,
-                &mut hw_resource
+                QueryInterface,
+                &IID_ID3D12Device4,
+                cast_to_ppv(&mut device)
+            );
+            let result = dx_call!(
+                device,
+                CreateProtectedResourceSession,
+                &desc.0,
+                &IID_ID3D12ProtectedResourceSession,
+                cast_to_ppv(&mut hw_session)
             );
+            dx_call!(device, Release,);
+
+            if fail!(result) {
+                return Err(DxError::new(
+                    "CreateProtectedResourceSession",
+                    result,
+                ));
+            }
         }
 
-        Ok(Resource {
-            this: hw_resource as *mut ID3D12Resource,
-        })
+        Ok(ProtectedResourceSession { this: hw_session })
+    }
+}
+
+/// Layout of a single subresource in a buffer it is copied to or from,
+/// see [CopyableFootprints]
+#[derive(Debug, Clone, Copy)]
+pub struct CopyableSubresource {
+    pub layout: PlacedSubresourceFootprint,
+    pub num_rows: u32,
+    /// Size of a row without the padding up to the row pitch
+    pub row_size: ByteCount,
+}
+
+/// Results of [Device::get_copyable_footprints], one entry per
+/// subresource in the requested range
+#[derive(Debug, Clone, Default)]
+pub struct CopyableFootprints {
+    layouts: Vec<PlacedSubresourceFootprint>,
+    num_rows: Vec<u32>,
+    row_sizes: Vec<ByteCount>,
+    total_size: ByteCount,
+}
+
+impl CopyableFootprints {
+    pub fn len(&self) -> usize {
+        self.layouts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layouts.is_empty()
+    }
+
+    /// `index` is relative to the first subresource of the range
+    pub fn subresource(&self, index: usize) -> CopyableSubresource {
+        CopyableSubresource {
+            layout: self.layouts[index],
+            num_rows: self.num_rows[index],
+            row_size: self.row_sizes[index],
+        }
+    }
+
+    pub fn layouts(&self) -> &[PlacedSubresourceFootprint] {
+        &self.layouts
+    }
+
+    /// Size of the buffer region holding all the subresources, starting
+    /// at the base offset
+    pub fn total_size(&self) -> ByteCount {
+        self.total_size
+    }
+
+    pub fn iter(&self) -> CopyableFootprintsIter<'_> {
+        CopyableFootprintsIter {
+            footprints: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a CopyableFootprints {
+    type Item = CopyableSubresource;
+    type IntoIter = CopyableFootprintsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CopyableFootprintsIter<'a> {
+    footprints: &'a CopyableFootprints,
+    index: usize,
+}
+
+impl<'a> Iterator for CopyableFootprintsIter<'a> {
+    type Item = CopyableSubresource;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.footprints.len() {
+            return None;
+        }
+
+        self.index += 1;
+        Some(self.footprints.subresource(self.index - 1))
     }
 }
 
+/// A range of tiles in a heap that [CommandQueue::update_tile_mappings]
+/// maps tiled resource regions to. With [TileRangeFlags::Null] the tiles
+/// are unmapped and `heap_start_offset` is ignored
+#[derive(Debug, Clone, Copy)]
+pub struct TileRange {
+    pub flags: TileRangeFlags,
+    /// Offset in tiles from the start of the heap
+    pub heap_start_offset: u32,
+    pub tile_count: u32,
+}
+
+impl TileRange {
+    pub fn new(heap_start_offset: u32, tile_count: u32) -> Self {
+        Self {
+            flags: TileRangeFlags::None,
+            heap_start_offset,
+            tile_count,
+        }
+    }
+}
+
+/// How a tiled (reserved) resource is broken into tiles, see
+/// [Device::get_resource_tiling]
+#[derive(Debug, Clone, Default)]
+pub struct ResourceTiling {
+    pub num_tiles_for_entire_resource: u32,
+    pub packed_mip_info: PackedMipInfo,
+    /// Tile shape of the mips that are not packed
+    pub standard_tile_shape: TileShape,
+    /// One entry per subresource, those of packed mips are zeroed
+    pub subresource_tilings: Vec<SubresourceTiling>,
+}
+
+/// Wrapper around ID3D12Pageable interface, i.e. an object whose
+/// residency can be managed via [Device::make_resident] and
+/// [Device::evict]. Can be created from any pageable wrapper
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Pageable {
+    pub(crate) this: *mut ID3D12Pageable,
+}
+impl_com_object_refcount_unnamed!(Pageable);
+impl_com_object_private_data!(Pageable);
+impl_com_object_clone_drop!(Pageable);
+impl_com_object_raw!(Pageable, ID3D12Pageable);
+
+macro_rules! impl_pageable_from {
+    ($($object_type:ty),*) => {
+        $(
+            impl From<&$object_type> for Pageable {
+                fn from(object: &$object_type) -> Self {
+                    let hw_ptr = object.this as *mut ID3D12Pageable;
+                    unsafe { dx_call!(hw_ptr, AddRef,) };
+
+                    Self { this: hw_ptr }
+                }
+            }
+        )*
+    };
+}
+
+impl_pageable_from!(
+    Heap,
+    Resource,
+    DescriptorHeap,
+    QueryHeap,
+    PipelineState,
+    Fence,
+    CommandAllocator,
+    CommandQueue
+);
+
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct DeviceChild {
-    pub this: *mut ID3D12DeviceChild,
+    pub(crate) this: *mut ID3D12DeviceChild,
 }
 impl_com_object_refcount_unnamed!(DeviceChild);
+impl_com_object_private_data!(DeviceChild);
 impl_com_object_clone_drop!(DeviceChild);
+impl_com_object_raw!(DeviceChild, ID3D12DeviceChild);
 
 impl From<Heap> for DeviceChild {
     fn from(heap: Heap) -> Self {
@@ -1558,22 +4114,160 @@ impl From<Fence> for DeviceChild {
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct CommandQueue {
-    pub this: *mut ID3D12CommandQueue,
+    pub(crate) this: *mut ID3D12CommandQueue,
 }
 impl_com_object_refcount_unnamed!(CommandQueue);
+impl_com_object_private_data!(CommandQueue);
 impl_com_object_clone_drop!(CommandQueue);
+impl_com_object_raw!(CommandQueue, ID3D12CommandQueue);
 
 unsafe impl Send for CommandQueue {}
 
 impl CommandQueue {
-    pub fn execute_command_lists(&self, command_lists: &[CommandList]) {
+    pub fn execute_command_lists<K: CopyCapable>(
+        &self,
+        command_lists: &[CommandList<K>],
+    ) {
+        self.execute_raw_command_lists(
+            command_lists
+                .iter()
+                .map(ExecutableCommandList::as_raw_command_list)
+                .collect(),
+        );
+    }
+
+    /// Same as [CommandQueue::execute_command_lists], but the lists don't
+    /// have to be stored contiguously, e.g. `&[&first_list, &second_list]`,
+    /// and their type is only known at runtime. Lists whose type doesn't
+    /// match the type of the queue are reported and nothing is executed
+    pub fn execute_command_list_refs(
+        &self,
+        command_lists: &[&dyn ExecutableCommandList],
+    ) -> DxResult<()> {
+        let queue_type = self.get_desc().queue_type();
+        if let Some(command_list) = command_lists.iter().find(|command_list| {
+            command_list.command_list_type() as i32 != queue_type as i32
+        }) {
+            error!(
+                "Cannot execute a {:?} command list on a {:?} queue",
+                command_list.command_list_type(),
+                queue_type
+            );
+            return Err(DxError::new(
+                "CommandQueue::execute_command_list_refs",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        self.execute_raw_command_lists(
+            command_lists
+                .iter()
+                .map(|command_list| command_list.as_raw_command_list())
+                .collect(),
+        );
+
+        Ok(())
+    }
+
+    fn execute_raw_command_lists(
+        &self,
+        command_lists: Vec<*mut ID3D12CommandList>,
+    ) {
         unsafe {
             dx_call!(
                 self.this,
                 ExecuteCommandLists,
                 command_lists.len() as std::os::raw::c_uint,
-                command_lists.as_ptr() as *const *mut ID3D12CommandList
+                command_lists.as_ptr()
+            );
+        }
+    }
+
+    /// Maps tile regions of a reserved resource to tile ranges of `heap`.
+    /// Regions and ranges are consumed in order, so a region can span
+    /// several ranges and vice versa. `heap` can only be None if all
+    /// the ranges are null or skipped. Fails if the region slices have
+    /// different lengths
+    pub fn update_tile_mappings(
+        &self,
+        resource: &Resource,
+        region_start_coordinates: &[TiledResourceCoordinate],
+        region_sizes: &[TileRegionSize],
+        heap: Option<&Heap>,
+        ranges: &[TileRange],
+        flags: TileMappingFlags,
+    ) -> DxResult<()> {
+        if region_start_coordinates.len() != region_sizes.len() {
+            error!(
+                "Cannot update tile mappings: {} region coordinates for {} \
+                 region sizes",
+                region_start_coordinates.len(),
+                region_sizes.len()
             );
+            return Err(DxError::new(
+                "CommandQueue::update_tile_mappings",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let range_flags = ranges
+            .iter()
+            .map(|range| range.flags.bits())
+            .collect::<Vec<_>>();
+        let heap_range_start_offsets = ranges
+            .iter()
+            .map(|range| range.heap_start_offset)
+            .collect::<Vec<_>>();
+        let range_tile_counts = ranges
+            .iter()
+            .map(|range| range.tile_count)
+            .collect::<Vec<_>>();
+        unsafe {
+            dx_call!(
+                self.this,
+                UpdateTileMappings,
+                resource.this,
+                region_start_coordinates.len() as u32,
+                region_start_coordinates.as_ptr()
+                    as *const D3D12_TILED_RESOURCE_COORDINATE,
+                region_sizes.as_ptr() as *const D3D12_TILE_REGION_SIZE,
+                match heap {
+                    Some(heap) => heap.this,
+                    None => std::ptr::null_mut(),
+                },
+                ranges.len() as u32,
+                range_flags.as_ptr(),
+                heap_range_start_offsets.as_ptr(),
+                range_tile_counts.as_ptr(),
+                flags.bits()
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Copies the tile mappings of a region of `source` to
+    /// `destination`, which can be the same resource
+    pub fn copy_tile_mappings(
+        &self,
+        destination: &Resource,
+        destination_start_coordinate: &TiledResourceCoordinate,
+        source: &Resource,
+        source_start_coordinate: &TiledResourceCoordinate,
+        region_size: &TileRegionSize,
+        flags: TileMappingFlags,
+    ) {
+        unsafe {
+            dx_call!(
+                self.this,
+                CopyTileMappings,
+                destination.this,
+                &destination_start_coordinate.0,
+                source.this,
+                &source_start_coordinate.0,
+                &region_size.0,
+                flags.bits()
+            )
         }
     }
 
@@ -1595,30 +4289,219 @@ impl CommandQueue {
         unsafe { dx_try!(self.this, Wait, fence.this, value) };
         Ok(())
     }
+
+    pub fn get_device(&self) -> DxResult<Device> {
+        let mut hw_device: *mut ID3D12Device2 = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                GetDevice,
+                &IID_ID3D12Device2,
+                cast_to_ppv(&mut hw_device)
+            );
+        }
+        Ok(Device { this: hw_device })
+    }
+
+    /// Same as `from_raw`, but also checks that the queue (e.g. one created
+    /// by an OpenXR runtime or a video encoder) belongs to `device`, since
+    /// fences and resources cannot be shared with queues of other devices
+    /// without explicit sharing. On mismatch the reference is released
+    /// and E_INVALIDARG is returned
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid non-null pointer to the interface
+    pub unsafe fn from_raw_on_device(
+        raw: *mut ID3D12CommandQueue,
+        device: &Device,
+    ) -> DxResult<Self> {
+        let queue = Self::from_raw(raw);
+        if queue.get_device()?.this != device.this {
+            error!(
+                "Command queue {:p} does not belong to device {:p}",
+                raw, device.this
+            );
+            return Err(DxError::new(
+                "CommandQueue::from_raw_on_device",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        Ok(queue)
+    }
+
+    pub fn get_desc(&self) -> CommandQueueDesc {
+        unsafe {
+            let mut hw_desc: D3D12_COMMAND_QUEUE_DESC = std::mem::zeroed();
+            dx_call!(self.this, GetDesc, &mut hw_desc);
+            CommandQueueDesc(hw_desc)
+        }
+    }
+
+    /// See [Device::describe]
+    pub fn describe(&self) -> String {
+        let desc = self.get_desc();
+        format!(
+            "CommandQueue {:p}: {:?}, priority {}, flags {:?}",
+            self.this,
+            desc.queue_type(),
+            desc.priority(),
+            desc.flags()
+        )
+    }
+
+    /// Blocks until the GPU has finished all the work submitted to the
+    /// queue so far. Signals a fence of its own, so it can be used at
+    /// teardown or before resizing without any fence bookkeeping. Returns
+    /// right away if the device has been removed
+    pub fn wait_idle(&self) -> DxResult<()> {
+        let fence = self.signal_idle_fence()?;
+        wait_for_idle_fence(&fence)
+    }
+
+    // Signals 1 on a new fence after the work submitted so far
+    fn signal_idle_fence(&self) -> DxResult<Fence> {
+        let fence = self.get_device()?.create_fence(0, FenceFlags::None)?;
+        self.signal(&fence, 1)?;
+
+        Ok(fence)
+    }
+}
+
+fn wait_for_idle_fence(fence: &Fence) -> DxResult<()> {
+    // a removed device reports u64::MAX as the completed value
+    if fence.get_completed_value() >= 1 {
+        return Ok(());
+    }
+
+    let event = Win32Event::default();
+    let result = fence.set_event_on_completion(1, &event);
+    if result.is_ok() {
+        event.wait(None);
+    }
+    event.close();
+
+    result
 }
 
+#[cfg(feature = "dxgi")]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Swapchain {
-    pub this: *mut IDXGISwapChain4,
+    pub(crate) this: *mut IDXGISwapChain4,
 }
+#[cfg(feature = "dxgi")]
 impl_com_object_refcount_unnamed!(Swapchain);
+#[cfg(feature = "dxgi")]
+impl_com_object_private_data!(Swapchain);
+#[cfg(feature = "dxgi")]
 impl_com_object_clone_drop!(Swapchain);
+#[cfg(feature = "dxgi")]
+impl_com_object_raw!(Swapchain, IDXGISwapChain4);
+
+#[cfg(feature = "dxgi")]
+impl Swapchain {
+    /// Takes ownership of the IDXGISwapChain1 returned by the factory
+    unsafe fn from_swapchain1(
+        temp_hw_swapchain: *mut IDXGISwapChain1,
+    ) -> DxResult<Self> {
+        let mut hw_swapchain: *mut IDXGISwapChain4 = std::ptr::null_mut();
+        let ret_code = dx_call!(
+            temp_hw_swapchain,
+            QueryInterface,
+            &IID_IDXGISwapChain4,
+            cast_to_ppv(&mut hw_swapchain)
+        );
+        dx_call!(temp_hw_swapchain, Release,);
+
+        if fail!(ret_code) {
+            return Err(DxError::new("QueryInterface", ret_code));
+        }
+
+        Ok(Swapchain { this: hw_swapchain })
+    }
+
+    pub fn get_buffer(&self, index: u32) -> DxResult<Resource> {
+        let mut buffer: *mut ID3D12Resource = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                GetBuffer,
+                index,
+                &IID_ID3D12Resource,
+                cast_to_ppv(&mut buffer)
+            )
+        }
+
+        Ok(Resource { this: buffer })
+    }
+
+    /// All the back buffers in the order of their indices
+    pub fn buffers(&self) -> DxResult<Vec<Resource>> {
+        (0..self.get_buffer_count()?)
+            .map(|index| self.get_buffer(index))
+            .collect()
+    }
+
+    pub fn get_buffer_count(&self) -> DxResult<u32> {
+        Ok(self.get_desc()?.buffer_count())
+    }
+
+    pub fn get_desc(&self) -> DxResult<SwapChainDesc> {
+        let mut desc = SwapChainDesc::default();
+        unsafe {
+            dx_try!(self.this, GetDesc1, &mut desc.0);
+        }
+        Ok(desc)
+    }
+
+    /// The output that contains the largest part of the swapchain's window.
+    /// Fails with DXGI_ERROR_NOT_FOUND e.g. if the window is minimized
+    pub fn get_containing_output(&self) -> DxResult<Output> {
+        let mut temp_output: *mut IDXGIOutput = std::ptr::null_mut();
+        unsafe {
+            dx_try!(self.this, GetContainingOutput, &mut temp_output);
+
+            let mut output: *mut IDXGIOutput6 = std::ptr::null_mut();
+            let ret_code = dx_call!(
+                temp_output,
+                QueryInterface,
+                &IID_IDXGIOutput6,
+                cast_to_ppv(&mut output)
+            );
+            dx_call!(temp_output, Release,);
+            if fail!(ret_code) {
+                return Err(DxError::new("QueryInterface", ret_code));
+            }
+
+            Ok(Output { this: output })
+        }
+    }
 
-impl Swapchain {
-    pub fn get_buffer(&self, index: u32) -> DxResult<Resource> {
-        let mut buffer: *mut ID3D12Resource = std::ptr::null_mut();
+    /// All references to the back buffers must be released before
+    /// the call. Zero `buffer_count`, `width` or `height` and
+    /// [Format::Unknown] keep the current values
+    pub fn resize_buffers(
+        &self,
+        buffer_count: u32,
+        width: u32,
+        height: u32,
+        format: Format,
+        flags: SwapChainFlags,
+    ) -> DxResult<()> {
         unsafe {
             dx_try!(
                 self.this,
-                GetBuffer,
-                index,
-                &IID_ID3D12Resource,
-                cast_to_ppv(&mut buffer)
-            )
+                ResizeBuffers,
+                buffer_count,
+                width,
+                height,
+                format as i32,
+                flags.bits() as u32
+            );
         }
-
-        Ok(Resource { this: buffer })
+        Ok(())
     }
 
     pub fn get_frame_latency_waitable_object(&self) -> Win32Event {
@@ -1629,6 +4512,22 @@ impl Swapchain {
         }
     }
 
+    /// Sets the number of frames that can be queued before
+    /// [Swapchain::get_frame_latency_waitable_object] blocks. Only valid
+    /// for swapchains created with FrameLatencyWaitableObject flag; D3D12
+    /// devices do not implement IDXGIDevice1, so there is no device-wide
+    /// equivalent
+    pub fn set_maximum_frame_latency(&self, max_latency: u32) -> DxResult<()> {
+        unsafe { dx_try!(self.this, SetMaximumFrameLatency, max_latency) };
+        Ok(())
+    }
+
+    pub fn get_maximum_frame_latency(&self) -> DxResult<u32> {
+        let mut max_latency = 0u32;
+        unsafe { dx_try!(self.this, GetMaximumFrameLatency, &mut max_latency) };
+        Ok(max_latency)
+    }
+
     pub fn get_current_back_buffer_index(&self) -> u32 {
         unsafe { dx_call!(self.this, GetCurrentBackBufferIndex,) }
     }
@@ -1641,21 +4540,160 @@ impl Swapchain {
         unsafe { dx_try!(self.this, Present, sync_interval, flags.bits()) };
         Ok(())
     }
+
+    /// Reads back the current back buffer, which is in `state` at this
+    /// point: RenderTarget if the frame has not been submitted yet,
+    /// or Present if the capture happens between its submission and
+    /// [Swapchain::present]. `queue` should be the direct queue
+    /// the swapchain presents on, it is flushed before returning.
+    /// See [read_back_texture]
+    #[cfg(feature = "helpers")]
+    pub fn capture_back_buffer(
+        &self,
+        queue: &CommandQueue,
+        state: ResourceStates,
+    ) -> DxResult<CpuImage> {
+        let back_buffer =
+            self.get_buffer(self.get_current_back_buffer_index())?;
+        let mut images = read_back_texture(
+            &queue.get_device()?,
+            queue,
+            &back_buffer,
+            &SubresourceRange::mip(0),
+            state,
+        )?;
+        // a single subresource has been requested
+        Ok(images.swap_remove(0))
+    }
+
+    /// Returns whether the swapchain can present in `color_space`, e.g.
+    /// [ColorSpaceType::RgbFullG2084NoneP2020] for HDR10 or
+    /// [ColorSpaceType::RgbFullG10NoneP709] for scRGB
+    pub fn check_color_space_support(
+        &self,
+        color_space: ColorSpaceType,
+    ) -> DxResult<SwapChainColorSpaceSupportFlags> {
+        let mut support = 0u32;
+        unsafe {
+            dx_try!(
+                self.this,
+                CheckColorSpaceSupport,
+                color_space as i32,
+                &mut support
+            );
+
+            Ok(SwapChainColorSpaceSupportFlags::from_bits_unchecked(
+                support as i32,
+            ))
+        }
+    }
+
+    /// The back buffer format has to match the color space, e.g.
+    /// [Format::R10G10B10A2Unorm] for HDR10 and [Format::R16G16B16A16Float]
+    /// for scRGB
+    pub fn set_color_space(&self, color_space: ColorSpaceType) -> DxResult<()> {
+        unsafe {
+            dx_try!(self.this, SetColorSpace1, color_space as i32);
+        }
+        Ok(())
+    }
+
+    /// Passes the mastering display and content light levels to the display,
+    /// None clears the previously set metadata
+    pub fn set_hdr_metadata(
+        &self,
+        metadata: Option<&HdrMetadataHdr10>,
+    ) -> DxResult<()> {
+        unsafe {
+            match metadata {
+                Some(metadata) => dx_try!(
+                    self.this,
+                    SetHDRMetaData,
+                    DXGI_HDR_METADATA_TYPE_DXGI_HDR_METADATA_TYPE_HDR10,
+                    std::mem::size_of::<DXGI_HDR_METADATA_HDR10>() as u32,
+                    &metadata.0 as *const DXGI_HDR_METADATA_HDR10
+                        as *mut c_void
+                ),
+                None => dx_try!(
+                    self.this,
+                    SetHDRMetaData,
+                    DXGI_HDR_METADATA_TYPE_DXGI_HDR_METADATA_TYPE_NONE,
+                    0,
+                    std::ptr::null_mut()
+                ),
+            }
+        }
+        Ok(())
+    }
 }
 
+/// Remembers its type, capacity and handle increment size, so that handles
+/// can be obtained by index without writing past the end of the heap
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct DescriptorHeap {
-    pub this: *mut ID3D12DescriptorHeap,
+    pub(crate) this: *mut ID3D12DescriptorHeap,
+    heap_type: DescriptorHeapType,
+    num_descriptors: u32,
+    handle_size: ByteCount,
+    shader_visible: bool,
 }
 
 impl_com_object_set_get_name!(DescriptorHeap);
 impl_com_object_refcount_unnamed!(DescriptorHeap);
-impl_com_object_clone_drop!(DescriptorHeap);
+impl_com_object_private_data!(DescriptorHeap);
+impl_com_object_clone_drop!(
+    DescriptorHeap,
+    heap_type,
+    num_descriptors,
+    handle_size,
+    shader_visible
+);
 
 unsafe impl Send for DescriptorHeap {}
 
 impl DescriptorHeap {
+    /// See [Device::as_raw]
+    pub fn as_raw(&self) -> *mut ID3D12DescriptorHeap {
+        self.this
+    }
+
+    /// Same as [Device::from_raw], but the type, capacity and handle size
+    /// are queried from the heap and its device, which can fail
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid non-null pointer to the interface
+    pub unsafe fn from_raw(raw: *mut ID3D12DescriptorHeap) -> DxResult<Self> {
+        let mut hw_desc: D3D12_DESCRIPTOR_HEAP_DESC = std::mem::zeroed();
+        dx_call!(raw, GetDesc, &mut hw_desc);
+        let desc = DescriptorHeapDesc(hw_desc);
+
+        // the wrapper owns the reference from now on, so that it
+        // is released on the error path as well
+        let mut descriptor_heap = Self {
+            this: raw,
+            heap_type: desc.heap_type(),
+            num_descriptors: desc.num_descriptors(),
+            handle_size: ByteCount(0),
+            shader_visible: desc
+                .flags()
+                .contains(DescriptorHeapFlags::ShaderVisible),
+        };
+
+        let mut hw_device: *mut ID3D12Device2 = std::ptr::null_mut();
+        dx_try!(
+            raw,
+            GetDevice,
+            &IID_ID3D12Device2,
+            cast_to_ppv(&mut hw_device)
+        );
+        let device = Device { this: hw_device };
+        descriptor_heap.handle_size =
+            device.get_descriptor_handle_increment_size(desc.heap_type());
+
+        Ok(descriptor_heap)
+    }
+
     pub fn get_cpu_descriptor_handle_for_heap_start(
         &self,
     ) -> CpuDescriptorHandle {
@@ -1683,15 +4721,107 @@ impl DescriptorHeap {
         }
         GpuDescriptorHandle { hw_handle }
     }
+
+    pub fn heap_type(&self) -> DescriptorHeapType {
+        self.heap_type
+    }
+
+    /// Number of descriptors the heap was created with
+    pub fn len(&self) -> u32 {
+        self.num_descriptors
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_descriptors == 0
+    }
+
+    pub fn handle_size(&self) -> ByteCount {
+        self.handle_size
+    }
+
+    pub fn is_shader_visible(&self) -> bool {
+        self.shader_visible
+    }
+
+    /// Returns None if `index` is out of the heap bounds
+    pub fn get_cpu_handle(&self, index: u32) -> Option<CpuDescriptorHandle> {
+        match index < self.num_descriptors {
+            true => Some(
+                self.get_cpu_descriptor_handle_for_heap_start()
+                    .advance(index, self.handle_size),
+            ),
+            false => None,
+        }
+    }
+
+    /// Returns None if `index` is out of the heap bounds or the heap
+    /// is not shader visible
+    pub fn get_gpu_handle(&self, index: u32) -> Option<GpuDescriptorHandle> {
+        match self.shader_visible && index < self.num_descriptors {
+            true => Some(
+                self.get_gpu_descriptor_handle_for_heap_start()
+                    .advance(index, self.handle_size),
+            ),
+            false => None,
+        }
+    }
+
+    /// Iterates over CPU handles of all the descriptors in the heap
+    pub fn cpu_handles(
+        &self,
+    ) -> impl ExactSizeIterator<Item = CpuDescriptorHandle> {
+        let start = self.get_cpu_descriptor_handle_for_heap_start();
+        let handle_size = self.handle_size;
+        (0..self.num_descriptors)
+            .map(move |index| start.advance(index, handle_size))
+    }
+
+    /// Iterates over GPU handles of all the descriptors in the heap,
+    /// the iterator is empty if the heap is not shader visible
+    pub fn gpu_handles(
+        &self,
+    ) -> impl ExactSizeIterator<Item = GpuDescriptorHandle> {
+        let (start, len) = match self.shader_visible {
+            true => (
+                self.get_gpu_descriptor_handle_for_heap_start(),
+                self.num_descriptors,
+            ),
+            false => (
+                GpuDescriptorHandle {
+                    hw_handle: D3D12_GPU_DESCRIPTOR_HANDLE { ptr: 0 },
+                },
+                0,
+            ),
+        };
+        let handle_size = self.handle_size;
+        (0..len).map(move |index| start.advance(index, handle_size))
+    }
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct CpuDescriptorHandle {
-    pub hw_handle: D3D12_CPU_DESCRIPTOR_HANDLE,
+    pub(crate) hw_handle: D3D12_CPU_DESCRIPTOR_HANDLE,
 }
 
 impl CpuDescriptorHandle {
+    /// Creates a handle from the value of D3D12_CPU_DESCRIPTOR_HANDLE,
+    /// e.g. one obtained from another library. Returns None for null
+    /// handles
+    pub fn from_raw_parts(ptr: usize) -> Option<Self> {
+        match ptr {
+            0 => None,
+            _ => Some(CpuDescriptorHandle {
+                hw_handle: D3D12_CPU_DESCRIPTOR_HANDLE { ptr: ptr as u64 },
+            }),
+        }
+    }
+
+    /// Value of the underlying D3D12_CPU_DESCRIPTOR_HANDLE
+    pub fn ptr(&self) -> usize {
+        self.hw_handle.ptr as usize
+    }
+
     #[must_use]
     pub fn advance(self, distance: u32, handle_size: ByteCount) -> Self {
         CpuDescriptorHandle {
@@ -1715,10 +4845,27 @@ impl CpuDescriptorHandle {
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct GpuDescriptorHandle {
-    pub hw_handle: D3D12_GPU_DESCRIPTOR_HANDLE,
+    pub(crate) hw_handle: D3D12_GPU_DESCRIPTOR_HANDLE,
 }
 
 impl GpuDescriptorHandle {
+    /// Creates a handle from the value of D3D12_GPU_DESCRIPTOR_HANDLE,
+    /// e.g. one obtained from another library. Returns None for null
+    /// handles
+    pub fn from_raw_parts(ptr: u64) -> Option<Self> {
+        match ptr {
+            0 => None,
+            _ => Some(GpuDescriptorHandle {
+                hw_handle: D3D12_GPU_DESCRIPTOR_HANDLE { ptr },
+            }),
+        }
+    }
+
+    /// Value of the underlying D3D12_GPU_DESCRIPTOR_HANDLE
+    pub fn ptr(&self) -> u64 {
+        self.hw_handle.ptr
+    }
+
     pub fn advance(self, distance: u32, handle_size: ByteCount) -> Self {
         GpuDescriptorHandle {
             hw_handle: D3D12_GPU_DESCRIPTOR_HANDLE {
@@ -1727,20 +4874,116 @@ impl GpuDescriptorHandle {
             },
         }
     }
+
+    /// Same as [GpuDescriptorHandle::advance], but returns None if the
+    /// resulting handle would be outside of the heap starting at `heap_start`
+    /// and containing `heap_len` descriptors, or if the address overflows
+    #[must_use]
+    pub fn advance_checked(
+        self,
+        distance: u32,
+        handle_size: ByteCount,
+        heap_start: GpuDescriptorHandle,
+        heap_len: u32,
+    ) -> Option<Self> {
+        let ptr = self
+            .hw_handle
+            .ptr
+            .checked_add((distance as u64).checked_mul(handle_size.0)?)?;
+        let heap_end = heap_start
+            .hw_handle
+            .ptr
+            .checked_add((heap_len as u64).checked_mul(handle_size.0)?)?;
+
+        match ptr >= heap_start.hw_handle.ptr && ptr < heap_end {
+            true => Some(GpuDescriptorHandle {
+                hw_handle: D3D12_GPU_DESCRIPTOR_HANDLE { ptr },
+            }),
+            false => None,
+        }
+    }
+
+    /// Returns the distance in bytes between `base` and this handle
+    #[must_use]
+    pub fn offset_from(&self, base: GpuDescriptorHandle) -> ByteCount {
+        debug_assert!(
+            self.hw_handle.ptr >= base.hw_handle.ptr,
+            "Base handle is located after the current one"
+        );
+        ByteCount(self.hw_handle.ptr - base.hw_handle.ptr)
+    }
+
+    /// Returns the slot number of this handle relative to `base`, e.g.
+    /// the index that should be used for bindless access when `base` is
+    /// the heap start
+    #[must_use]
+    pub fn index_from(
+        &self,
+        base: GpuDescriptorHandle,
+        handle_size: ByteCount,
+    ) -> u32 {
+        (self.offset_from(base).0 / handle_size.0) as u32
+    }
 }
 
 #[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Resource {
-    pub this: *mut ID3D12Resource,
+    pub(crate) this: *mut ID3D12Resource,
 }
 impl_com_object_clone_drop!(Resource);
+impl_com_object_raw!(Resource, ID3D12Resource);
 impl_com_object_refcount_named!(Resource);
+impl_com_object_private_data!(Resource);
 impl_com_object_set_get_name!(Resource);
 
 unsafe impl Send for Resource {}
 
 impl Resource {
+    /// Name, description and heap properties of the resource, see
+    /// [Device::describe]
+    pub fn describe(&self) -> String {
+        let desc = self.get_desc();
+        let size = match desc.dimension() {
+            ResourceDimension::Buffer => format!("{} bytes", desc.width()),
+            _ => format!(
+                "{}x{}x{}, {} mip(s), {:?}, {} sample(s)",
+                desc.width(),
+                desc.height(),
+                desc.depth_or_array_size(),
+                desc.mip_levels(),
+                desc.format(),
+                desc.sample_desc().count()
+            ),
+        };
+        // reserved resources have no heap of their own
+        let heap = match self.get_heap_properties() {
+            Ok((heap_properties, heap_flags)) => format!(
+                "{:?} heap, heap flags {:?}",
+                heap_properties.heap_type(),
+                heap_flags
+            ),
+            Err(_) => String::from("no heap"),
+        };
+
+        format!(
+            "Resource {:p} {}: {:?} {}, {:?}, flags {:?}, {}",
+            self.this,
+            describe_name(self.get_name()),
+            desc.dimension(),
+            size,
+            desc.layout(),
+            desc.flags(),
+            heap
+        )
+    }
+
+    /// Flat index of the subresource, e.g. for barriers, computed from
+    /// the mip levels and the array size of the resource
+    pub fn subresource(&self, index: SubresourceIndex) -> u32 {
+        index.subresource_in(&self.get_desc())
+    }
+
     pub fn get_desc(&self) -> ResourceDesc {
         unsafe {
             let mut hw_desc: D3D12_RESOURCE_DESC = std::mem::zeroed();
@@ -1766,31 +5009,97 @@ impl Resource {
         unsafe { GpuVirtualAddress(dx_call!(self.this, GetGPUVirtualAddress,)) }
     }
 
+    /// Returns the address range occupied by a buffer resource
+    pub fn get_gpu_virtual_address_range(&self) -> GpuVirtualAddressRange {
+        GpuVirtualAddressRange::default()
+            .with_start_address(self.get_gpu_virtual_address())
+            .with_size_in_bytes(ByteCount(self.get_desc().0.Width))
+    }
+
+    /// Returns the address located at `offset` bytes from the start of
+    /// a buffer resource. In debug builds the offset is checked against
+    /// the buffer size
+    pub fn gpu_address_at(&self, offset: ByteCount) -> GpuVirtualAddress {
+        debug_assert!(
+            offset.0 < self.get_desc().0.Width,
+            "Offset {} is out of bounds of the buffer",
+            offset.0
+        );
+        self.get_gpu_virtual_address() + offset
+    }
+
     // from d3dx12.h
     pub fn get_required_intermediate_size(
         &self,
-        first_subresouce: u32,
-        num_subresources: u32,
+        subresources: &SubresourceRange,
     ) -> DxResult<ByteCount> {
         let resource_desc = self.get_desc();
+        let (first_subresouce, num_subresources) =
+            contiguous_subresources(&resource_desc, subresources)?;
 
         let device = self.get_device()?;
-        let (_, _, _, total_size) = device.get_copyable_footprints(
-            &resource_desc,
-            first_subresouce,
-            num_subresources,
-            ByteCount(0),
-        );
+        let total_size = device
+            .get_copyable_footprints(
+                &resource_desc,
+                first_subresouce,
+                num_subresources,
+                ByteCount(0),
+            )
+            .total_size();
         device.release();
 
         Ok(total_size)
     }
 
+    /// Properties and flags of the heap the resource lives in. Fails for
+    /// reserved resources, which have no heap of their own
+    pub fn get_heap_properties(&self) -> DxResult<(HeapProperties, HeapFlags)> {
+        let mut heap_properties = HeapProperties::default();
+        let mut heap_flags: D3D12_HEAP_FLAGS = 0;
+        unsafe {
+            dx_try!(
+                self.this,
+                GetHeapProperties,
+                &mut heap_properties.0,
+                &mut heap_flags
+            );
+        }
+
+        Ok((heap_properties, HeapFlags::from_bits_truncate(heap_flags)))
+    }
+
+    /// In debug builds resources that are not CPU-accessible (i.e. live
+    /// in a Default heap or a custom one without CPU access) are reported
+    /// as [DxError::ResourceNotCpuAccessible] instead of a bare E_INVALIDARG
     pub fn map(
         &self,
         subresource: u32,
         range: Option<&Range>,
     ) -> DxResult<*mut u8> {
+        if cfg!(debug_assertions) {
+            if let Ok((heap_properties, _)) = self.get_heap_properties() {
+                let heap_type = heap_properties.heap_type();
+                let cpu_accessible = match heap_type {
+                    HeapType::Default => false,
+                    HeapType::Custom => !matches!(
+                        heap_properties.cpu_page_property(),
+                        CpuPageProperty::NotAvailable
+                    ),
+                    _ => true,
+                };
+                if !cpu_accessible {
+                    error!(
+                        "Resource {:p} in a {:?} heap cannot be mapped",
+                        self.this, heap_type
+                    );
+                    return Err(DxError::ResourceNotCpuAccessible {
+                        func_name: "ID3D12Resource::Map",
+                        heap_type,
+                    });
+                }
+            }
+        }
+
         let mut data: *mut u8 = std::ptr::null_mut();
         unsafe {
             dx_try!(
@@ -1807,55 +5116,280 @@ impl Resource {
         Ok(data)
     }
 
-    pub fn unmap(&self, subresource: UINT, range: Option<&Range>) {
+    pub fn unmap(&self, subresource: UINT, range: Option<&Range>) {
+        unsafe {
+            dx_call!(
+                self.this,
+                Unmap,
+                subresource,
+                match range {
+                    Some(rng) => &rng.0,
+                    None => std::ptr::null(),
+                }
+            )
+        }
+    }
+}
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct CommandAllocator {
+    pub(crate) this: *mut ID3D12CommandAllocator,
+}
+impl_com_object_set_get_name!(CommandAllocator);
+impl_com_object_refcount_named!(CommandAllocator);
+impl_com_object_private_data!(CommandAllocator);
+impl_com_object_clone_drop!(CommandAllocator);
+impl_com_object_raw!(CommandAllocator, ID3D12CommandAllocator);
+
+impl CommandAllocator {
+    pub fn reset(&self) -> DxResult<()> {
+        unsafe { dx_try!(self.this, Reset,) };
+        Ok(())
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented by command list marker types, ties each of them
+/// to the corresponding [CommandListType]
+pub trait CommandListKind: sealed::Sealed {
+    const TYPE: CommandListType;
+}
+
+/// Command list kinds that can record copies, queries and barriers
+/// (i.e. all of them except bundles)
+pub trait CopyCapable: CommandListKind {}
+
+/// Command list kinds that can record dispatches and bind
+/// compute root arguments (i.e. all of them except copy lists)
+pub trait ComputeCapable: CommandListKind {}
+
+/// Command list kinds that can record draws (direct lists and bundles)
+pub trait GraphicsCapable: ComputeCapable {}
+
+macro_rules! impl_command_list_kind {
+    ($kind:ident, $command_list_type:expr $(, $capability:ident)*) => {
+        impl sealed::Sealed for $kind {}
+
+        impl CommandListKind for $kind {
+            const TYPE: CommandListType = $command_list_type;
+        }
+
+        $(
+            impl $capability for $kind {}
+        )*
+    };
+}
+
+/// Marker type for direct command lists
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct DirectKind;
+impl_command_list_kind!(
+    DirectKind,
+    CommandListType::Direct,
+    CopyCapable,
+    ComputeCapable,
+    GraphicsCapable
+);
+
+/// Marker type for compute command lists
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct ComputeKind;
+impl_command_list_kind!(
+    ComputeKind,
+    CommandListType::Compute,
+    CopyCapable,
+    ComputeCapable
+);
+
+/// Marker type for copy command lists
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CopyKind;
+impl_command_list_kind!(CopyKind, CommandListType::Copy, CopyCapable);
+
+/// Marker type for bundles
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct BundleKind;
+impl_command_list_kind!(
+    BundleKind,
+    CommandListType::Bundle,
+    ComputeCapable,
+    GraphicsCapable
+);
+
+/// Command lists that can be submitted to a queue (i.e. all of them except
+/// bundles), see [CommandQueue::execute_command_list_refs]
+pub trait ExecutableCommandList: sealed::Sealed {
+    fn as_raw_command_list(&self) -> *mut ID3D12CommandList;
+
+    fn command_list_type(&self) -> CommandListType;
+}
+
+impl<K: CopyCapable> sealed::Sealed for CommandList<K> {}
+
+impl<K: CopyCapable> ExecutableCommandList for CommandList<K> {
+    fn as_raw_command_list(&self) -> *mut ID3D12CommandList {
+        self.this as *mut ID3D12CommandList
+    }
+
+    fn command_list_type(&self) -> CommandListType {
+        self.get_type()
+    }
+}
+
+pub type GraphicsCommandList = CommandList<DirectKind>;
+pub type ComputeCommandList = CommandList<ComputeKind>;
+pub type CopyCommandList = CommandList<CopyKind>;
+pub type Bundle = CommandList<BundleKind>;
+
+assert_eq_size!(CommandList, *mut ID3D12GraphicsCommandList6);
+assert_eq_size!(Bundle, *mut ID3D12GraphicsCommandList6);
+
+/// Wrapper around ID3D12GraphicsCommandList6 interface. The type parameter
+/// determines which commands can be recorded into the list. Untyped
+/// `CommandList` is a direct command list which exposes all of them
+#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CommandList<K = DirectKind> {
+    pub(crate) this: *mut ID3D12GraphicsCommandList6,
+    kind: PhantomData<K>,
+}
+impl_com_object_set_get_name!(impl<K> CommandList<K>);
+impl_com_object_refcount_named!(impl<K> CommandList<K>);
+impl_com_object_private_data!(impl<K> CommandList<K>);
+
+impl<K> Clone for CommandList<K> {
+    fn clone(&self) -> Self {
+        self.add_ref();
+        Self {
+            this: self.this,
+            kind: self.kind,
+        }
+    }
+}
+
+impl<K> Drop for CommandList<K> {
+    fn drop(&mut self) {
+        // the address can be reused by the next command list
+        // once this one is destroyed
+        #[cfg_attr(not(feature = "validation"), allow(unused_variables))]
+        let live_ref_count = self.release();
+        #[cfg(feature = "validation")]
+        if live_ref_count == 0 {
+            validation::forget_command_list(self.this as usize);
+        }
+    }
+}
+// the kind of the list is not checked, see CommandList::try_into_kind
+impl_com_object_raw!(
+    impl<K> CommandList<K>,
+    ID3D12GraphicsCommandList6,
+    kind: PhantomData
+);
+
+impl<K: CommandListKind> CommandList<K> {
+    pub fn close(&self) -> DxResult<()> {
+        validate_recording!(self, "CommandList::close", Err);
+
+        unsafe { dx_try!(self.this, Close,) };
+
+        #[cfg(feature = "validation")]
+        validation::record_command_list_close(self.this as usize);
+
+        Ok(())
+    }
+
+    pub fn get_type(&self) -> CommandListType {
+        unsafe { std::mem::transmute(dx_call!(self.this, GetType,)) }
+    }
+
+    pub fn reset(
+        &self,
+        command_allocator: &CommandAllocator,
+        pipeline_state: Option<&PipelineState>,
+    ) -> DxResult<()> {
+        unsafe {
+            dx_try!(
+                self.this,
+                Reset,
+                command_allocator.this,
+                match pipeline_state {
+                    Some(pso) => pso.this,
+                    None => std::ptr::null_mut(),
+                }
+            )
+        };
+
+        #[cfg(feature = "validation")]
+        validation::record_command_list_reset(
+            self.this as usize,
+            pipeline_state,
+        );
+
+        Ok(())
+    }
+
+    /// Converts the command list into a command list of another kind,
+    /// checking its actual type at runtime. Returns the original
+    /// command list if the types do not match
+    pub fn try_into_kind<T: CommandListKind>(
+        self,
+    ) -> Result<CommandList<T>, Self> {
+        if self.get_type() as i32 == T::TYPE as i32 {
+            Ok(self.into_kind_unchecked())
+        } else {
+            Err(self)
+        }
+    }
+
+    fn into_kind_unchecked<T>(self) -> CommandList<T> {
+        let command_list = CommandList {
+            this: self.this,
+            kind: PhantomData,
+        };
+        std::mem::forget(self);
+        command_list
+    }
+}
+
+/// Copy, query and barrier commands, not available in bundles
+impl<K: CopyCapable> CommandList<K> {
+    /// Subsequent commands can access protected resources only if they
+    /// belong to `session`. Passing None makes the command list
+    /// unprotected again
+    pub fn set_protected_resource_session(
+        &self,
+        session: Option<&ProtectedResourceSession>,
+    ) {
+        validate_recording!(
+            self,
+            "CommandList::set_protected_resource_session"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
-                Unmap,
-                subresource,
-                match range {
-                    Some(rng) => &rng.0,
-                    None => std::ptr::null(),
+                SetProtectedResourceSession,
+                match session {
+                    Some(session) => session.this,
+                    None => std::ptr::null_mut(),
                 }
             )
         }
     }
-}
-
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct CommandAllocator {
-    pub this: *mut ID3D12CommandAllocator,
-}
-impl_com_object_set_get_name!(CommandAllocator);
-impl_com_object_refcount_named!(CommandAllocator);
-impl_com_object_clone_drop!(CommandAllocator);
-
-impl CommandAllocator {
-    pub fn reset(&self) -> DxResult<()> {
-        unsafe { dx_try!(self.this, Reset,) };
-        Ok(())
-    }
-}
-
-assert_eq_size!(CommandList, *mut ID3D12GraphicsCommandList6);
-
-#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
-#[repr(transparent)]
-pub struct CommandList {
-    pub this: *mut ID3D12GraphicsCommandList6,
-}
-impl_com_object_set_get_name!(CommandList);
-impl_com_object_refcount_named!(CommandList);
-impl_com_object_clone_drop!(CommandList);
 
-impl CommandList {
+    #[inline]
     pub fn begin_query(
         &self,
         query_heap: &QueryHeap,
         query_type: QueryType,
         index: u32,
     ) {
+        validate_recording!(self, "CommandList::begin_query");
+
         unsafe {
             dx_call!(
                 self.this,
@@ -1867,51 +5401,118 @@ impl CommandList {
         }
     }
 
-    pub fn clear_depth_stencil_view(
+    /// Copies tiles between a region of a tiled (reserved) resource and
+    /// a buffer, which holds them one after another in 64KB chunks. The
+    /// direction is chosen by `flags`, which must contain either
+    /// [TileCopyFlags::LinearBufferToSwizzledTiledResource] or
+    /// [TileCopyFlags::SwizzledTiledResourceToLinearBuffer]. With
+    /// `validation` feature, missing or conflicting direction flags and
+    /// buffers too small for the region are reported and the copy is
+    /// skipped
+    pub fn copy_tiles(
         &self,
-        descriptor: CpuDescriptorHandle,
-        clear_flags: ClearFlags,
-        depth: f32,
-        stencil: u8,
-        rects: &[Rect],
+        tiled_resource: &Resource,
+        region_start_coordinate: &TiledResourceCoordinate,
+        region_size: &TileRegionSize,
+        buffer: &Resource,
+        buffer_start_offset: ByteCount,
+        flags: TileCopyFlags,
     ) {
+        validate_recording!(self, "CommandList::copy_tiles");
+
+        #[cfg(feature = "validation")]
+        if let Err(message) = validation::validate_tile_copy(
+            tiled_resource,
+            region_size,
+            buffer,
+            buffer_start_offset,
+            flags,
+        ) {
+            log::error!("Skipping invalid tile copy: {}", message);
+            return;
+        }
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_copy(
+            match flags
+                .contains(TileCopyFlags::LinearBufferToSwizzledTiledResource)
+            {
+                true => tiled_resource,
+                false => buffer,
+            },
+        );
+
         unsafe {
             dx_call!(
                 self.this,
-                ClearDepthStencilView,
-                descriptor.hw_handle,
-                clear_flags.bits(),
-                depth,
-                stencil,
-                rects.len() as u32,
-                rects.as_ptr() as *const D3D12_RECT
+                CopyTiles,
+                tiled_resource.this,
+                &region_start_coordinate.0,
+                &region_size.0,
+                buffer.this,
+                buffer_start_offset.0,
+                flags.bits()
             )
         }
     }
 
-    pub fn clear_render_target_view(
+    /// Atomically copies a 32-bit value between buffers. The subresource
+    /// ranges in `dependencies` are guaranteed to be written before the
+    /// copy, which makes it possible to signal the completion of a copy
+    /// to another GPU or to the CPU without a fence
+    pub fn atomic_copy_buffer_u32(
         &self,
-        descriptor: CpuDescriptorHandle,
-        color: [f32; 4],
-        rects: &[Rect],
+        dest: &Resource,
+        dest_offset: ByteCount,
+        source: &Resource,
+        source_offset: ByteCount,
+        dependencies: &[(&Resource, SubresourceRangeUint64)],
     ) {
+        validate_recording!(self, "CommandList::atomic_copy_buffer_u32");
+
+        let (resources, ranges) = split_atomic_copy_dependencies(dependencies);
         unsafe {
             dx_call!(
                 self.this,
-                ClearRenderTargetView,
-                descriptor.hw_handle,
-                color.as_ptr(),
-                rects.len() as u32,
-                rects.as_ptr() as *const D3D12_RECT
+                AtomicCopyBufferUINT,
+                dest.this,
+                dest_offset.0,
+                source.this,
+                source_offset.0,
+                dependencies.len() as u32,
+                resources.as_ptr(),
+                ranges.as_ptr()
             )
         }
     }
 
-    pub fn close(&self) -> DxResult<()> {
-        unsafe { dx_try!(self.this, Close,) };
-        Ok(())
+    /// Same as [CommandList::atomic_copy_buffer_u32] for 64-bit values
+    pub fn atomic_copy_buffer_u64(
+        &self,
+        dest: &Resource,
+        dest_offset: ByteCount,
+        source: &Resource,
+        source_offset: ByteCount,
+        dependencies: &[(&Resource, SubresourceRangeUint64)],
+    ) {
+        validate_recording!(self, "CommandList::atomic_copy_buffer_u64");
+
+        let (resources, ranges) = split_atomic_copy_dependencies(dependencies);
+        unsafe {
+            dx_call!(
+                self.this,
+                AtomicCopyBufferUINT64,
+                dest.this,
+                dest_offset.0,
+                source.this,
+                source_offset.0,
+                dependencies.len() as u32,
+                resources.as_ptr(),
+                ranges.as_ptr()
+            )
+        }
     }
 
+    #[inline]
     pub fn copy_buffer_region(
         &self,
         dest: &Resource,
@@ -1920,6 +5521,22 @@ impl CommandList {
         source_offset: ByteCount,
         span: ByteCount,
     ) {
+        validate_recording!(self, "CommandList::copy_buffer_region");
+
+        #[cfg(feature = "validation")]
+        if let Err(message) = validation::validate_buffer_copy(
+            dest,
+            dest_offset,
+            source,
+            source_offset,
+            span,
+        ) {
+            log::error!("Skipping invalid buffer copy: {}", message);
+            return;
+        }
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_copy(dest);
+
         unsafe {
             dx_call!(
                 self.this,
@@ -1933,10 +5550,49 @@ impl CommandList {
         }
     }
 
+    /// Writes 32-bit values to the given GPU addresses straight from
+    /// the command list, e.g. breadcrumb markers that survive a device
+    /// removal. Each write is given as (destination, value, mode)
+    pub fn write_buffer_immediate(
+        &self,
+        writes: &[(GpuVirtualAddress, u32, WriteBufferImmediateMode)],
+    ) {
+        validate_recording!(self, "CommandList::write_buffer_immediate");
+
+        let (hw_params, modes): (Vec<_>, Vec<_>) = writes
+            .iter()
+            .map(|(dest, value, mode)| {
+                (
+                    D3D12_WRITEBUFFERIMMEDIATE_PARAMETER {
+                        Dest: dest.0,
+                        Value: *value,
+                    },
+                    *mode as i32,
+                )
+            })
+            .unzip();
+        unsafe {
+            dx_call!(
+                self.this,
+                WriteBufferImmediate,
+                hw_params.len() as u32,
+                hw_params.as_ptr(),
+                modes.as_ptr()
+            )
+        }
+    }
+
+    #[inline]
     pub fn copy_resource(&self, dest: &Resource, source: &Resource) {
+        validate_recording!(self, "CommandList::copy_resource");
+
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_copy(dest);
+
         unsafe { dx_call!(self.this, CopyResource, dest.this, source.this) }
     }
 
+    #[inline]
     pub fn copy_texture_region(
         &self,
         dest_location: TextureCopyLocation,
@@ -1946,6 +5602,23 @@ impl CommandList {
         source_location: TextureCopyLocation,
         source_box: Option<&Box>,
     ) {
+        validate_recording!(self, "CommandList::copy_texture_region");
+
+        #[cfg(feature = "validation")]
+        if let Err(message) = validation::validate_texture_copy(
+            &dest_location,
+            dest_x,
+            dest_y,
+            dest_z,
+            &source_location,
+            source_box,
+        ) {
+            log::error!("Skipping invalid texture copy: {}", message);
+            return;
+        }
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_copy(&dest_location.resource());
+
         unsafe {
             dx_call!(
                 self.this,
@@ -1963,171 +5636,311 @@ impl CommandList {
         }
     }
 
-    pub fn dispatch(
+    #[inline]
+    pub fn end_query(
         &self,
-        thread_group_count_x: u32,
-        thread_group_count_y: u32,
-        thread_group_count_z: u32,
+        query_heap: &QueryHeap,
+        query_type: QueryType,
+        index: u32,
     ) {
+        validate_recording!(self, "CommandList::end_query");
+
         unsafe {
             dx_call!(
                 self.this,
-                Dispatch,
-                thread_group_count_x,
-                thread_group_count_y,
-                thread_group_count_z
-            )
+                EndQuery,
+                query_heap.this,
+                query_type as i32,
+                index
+            );
         }
     }
 
-    pub fn dispatch_mesh(
+    #[inline]
+    pub fn resolve_query_data(
         &self,
-        thread_group_count_x: u32,
-        thread_group_count_y: u32,
-        thread_group_count_z: u32,
+        query_heap: &QueryHeap,
+        query_type: QueryType,
+        start_index: u32,
+        num_queries: u32,
+        destination_buffer: &Resource,
+        aligned_destination_buffer_offset: ByteCount,
     ) {
+        validate_recording!(self, "CommandList::resolve_query_data");
+
         unsafe {
             dx_call!(
                 self.this,
-                DispatchMesh,
-                thread_group_count_x,
-                thread_group_count_y,
-                thread_group_count_z
-            )
+                ResolveQueryData,
+                query_heap.this,
+                query_type as i32,
+                start_index,
+                num_queries,
+                destination_buffer.this,
+                aligned_destination_buffer_offset.0
+            );
         }
     }
 
-    pub fn draw_indexed_instanced(
-        &self,
-        index_count_per_instance: u32,
-        instance_count: u32,
-        start_index_location: u32,
-        base_vertex_location: i32,
-        start_instance_location: u32,
-    ) {
+    #[inline]
+    pub fn resource_barrier(&self, barriers: &[ResourceBarrier]) {
+        validate_recording!(self, "CommandList::resource_barrier");
+
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_barriers(barriers);
+
         unsafe {
             dx_call!(
                 self.this,
-                DrawIndexedInstanced,
-                index_count_per_instance,
-                instance_count,
-                start_index_location,
-                base_vertex_location,
-                start_instance_location
-            )
+                ResourceBarrier,
+                barriers.len() as std::os::raw::c_uint,
+                barriers.as_ptr() as *const D3D12_RESOURCE_BARRIER
+            );
         }
     }
 
-    pub fn draw_instanced(
-        &self,
-        vertex_count_per_instance: u32,
-        instance_count: u32,
-        start_vertex_location: u32,
-        start_instance_location: u32,
-    ) {
+    /// Records enhanced barriers. Requires ID3D12GraphicsCommandList7
+    /// support, see [FeatureDataOptions12::enhanced_barriers_supported]
+    #[inline]
+    pub fn barrier(&self, barrier_groups: &[BarrierGroup]) -> DxResult<()> {
+        validate_recording!(self, "CommandList::barrier", Err);
+
+        let mut command_list: *mut ID3D12GraphicsCommandList7 =
+            std::ptr::null_mut();
         unsafe {
-            dx_call!(
+            dx_try!(
                 self.this,
-                DrawInstanced,
-                vertex_count_per_instance,
-                instance_count,
-                start_vertex_location,
-                start_instance_location
-            )
+                QueryInterface,
+                &IID_ID3D12GraphicsCommandList7,
+                cast_to_ppv(&mut command_list)
+            );
+            dx_call!(
+                command_list,
+                Barrier,
+                barrier_groups.len() as u32,
+                barrier_groups.as_ptr() as *const D3D12_BARRIER_GROUP
+            );
+            dx_call!(command_list, Release,);
+        }
+
+        Ok(())
+    }
+
+    // d3dx12.h helper
+    pub fn update_subresources(
+        &self,
+        destination_resource: &Resource,
+        intermediate_resource: &Resource,
+        subresources: &SubresourceRange,
+        footprints: &CopyableFootprints,
+        source_data: &[SubresourceData],
+    ) -> DxResult<ByteCount> {
+        validate_recording!(self, "CommandList::update_subresources", Err);
+
+        // ToDo: implement the rest of the validation of the original function
+        if source_data.len() != footprints.len() {
+            error!(
+                "update_subresources: {} source data entries given for {} \
+                 subresources",
+                source_data.len(),
+                footprints.len()
+            );
+            return Err(DxError::new(
+                "CommandList::update_subresources",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let data = intermediate_resource.map(0, None)?;
+
+        unsafe {
+            for (footprint, source) in footprints.iter().zip(source_data) {
+                let layout = footprint.layout.0;
+                let dest_data = D3D12_MEMCPY_DEST {
+                    pData: data.offset(layout.Offset as isize)
+                        as *mut std::ffi::c_void,
+                    RowPitch: layout.Footprint.RowPitch as u64,
+                    SlicePitch: (layout.Footprint.RowPitch as u64)
+                        * footprint.num_rows as u64,
+                };
+
+                memcpy_subresource(
+                    &dest_data,
+                    &source.0,
+                    footprint.row_size,
+                    footprint.num_rows,
+                    layout.Footprint.Depth,
+                );
+            }
+        }
+        intermediate_resource.unmap(0, None);
+
+        let layouts = footprints.layouts();
+        let destination_desc = destination_resource.get_desc();
+        if destination_desc.0.Dimension == ResourceDimension::Buffer as i32 {
+            self.copy_buffer_region(
+                destination_resource,
+                ByteCount(0),
+                intermediate_resource,
+                ByteCount(layouts[0].0.Offset),
+                ByteCount(layouts[0].0.Footprint.Width as u64),
+            );
+        } else {
+            let subresource_indices = subresources.subresource_indices(
+                destination_desc.mip_levels() as u32,
+                destination_desc.array_size(),
+            );
+            for (i, index) in subresource_indices.enumerate() {
+                let dest_location = TextureCopyLocation::new_subresource_index(
+                    destination_resource,
+                    index,
+                );
+                let source_location = TextureCopyLocation::new_placed_footprint(
+                    intermediate_resource,
+                    layouts[i],
+                );
+
+                self.copy_texture_region(
+                    dest_location,
+                    0,
+                    0,
+                    0,
+                    source_location,
+                    None,
+                );
+            }
         }
+
+        Ok(footprints.total_size())
+    }
+
+    // The stack-allocating version cannot be implemented without changing
+    // function signature since it would require function output parameters
+    pub fn update_subresources_heap_alloc(
+        &self,
+        destination_resource: &Resource,
+        intermediate_resource: &Resource,
+        intermediate_offset: ByteCount,
+        subresources: &SubresourceRange,
+        source_data: &[SubresourceData],
+    ) -> DxResult<ByteCount> {
+        validate_recording!(
+            self,
+            "CommandList::update_subresources_heap_alloc",
+            Err
+        );
+
+        let destination_desc = destination_resource.get_desc();
+        let (first_subresouce, num_subresources) =
+            contiguous_subresources(&destination_desc, subresources)?;
+
+        let allocation_size = ByteCount::from(
+            std::mem::size_of::<PlacedSubresourceFootprint>()
+                + std::mem::size_of::<u32>()
+                + std::mem::size_of::<u64>(),
+        ) * num_subresources;
+
+        let device = destination_resource.get_device()?;
+        let footprints = device.get_copyable_footprints(
+            &destination_desc,
+            first_subresouce,
+            num_subresources,
+            intermediate_offset,
+        );
+        self.update_subresources(
+            destination_resource,
+            intermediate_resource,
+            subresources,
+            &footprints,
+            source_data,
+        )
     }
+}
 
-    pub fn end_query(
+/// Dispatch and compute root argument commands, not available in copy
+/// command lists
+impl<K: ComputeCapable> CommandList<K> {
+    /// Executes up to `max_command_count` commands whose arguments are
+    /// read from `argument_buffer`. If `count_buffer` is given, the actual
+    /// number of commands is the minimum of the 32-bit value at
+    /// `count_buffer_offset` and `max_command_count`. With `validation`
+    /// feature, misaligned offsets, argument buffers that cannot hold
+    /// `max_command_count` commands and draw signatures on non-direct
+    /// lists are reported and the call is skipped
+    pub fn execute_indirect(
         &self,
-        query_heap: &QueryHeap,
-        query_type: QueryType,
-        index: u32,
+        command_signature: &CommandSignature,
+        max_command_count: u32,
+        argument_buffer: &Resource,
+        argument_buffer_offset: ByteCount,
+        count_buffer: Option<&Resource>,
+        count_buffer_offset: ByteCount,
     ) {
-        unsafe {
-            dx_call!(
-                self.this,
-                EndQuery,
-                query_heap.this,
-                query_type as i32,
-                index
-            );
+        validate_recording!(self, "CommandList::execute_indirect");
+
+        #[cfg(feature = "validation")]
+        if let Err(message) = validation::validate_execute_indirect(
+            K::TYPE,
+            command_signature,
+            max_command_count,
+            argument_buffer,
+            argument_buffer_offset,
+            count_buffer
+                .map(|count_buffer| (count_buffer, count_buffer_offset)),
+        ) {
+            log::error!("Skipping invalid indirect execution: {}", message);
+            return;
         }
-    }
 
-    pub fn execute_bundle(&self, command_list: &CommandList) {
         unsafe {
             dx_call!(
                 self.this,
-                ExecuteBundle,
-                // ToDo: is it 100% safe?
-                command_list.this as *mut ID3D12GraphicsCommandList
-            );
-        }
-    }
-
-    pub fn reset(
-        &self,
-        command_allocator: &CommandAllocator,
-        pipeline_state: Option<&PipelineState>,
-    ) -> DxResult<()> {
-        unsafe {
-            dx_try!(
-                self.this,
-                Reset,
-                command_allocator.this,
-                match pipeline_state {
-                    Some(pso) => pso.this,
+                ExecuteIndirect,
+                command_signature.this,
+                max_command_count,
+                argument_buffer.this,
+                argument_buffer_offset.0,
+                match count_buffer {
+                    Some(count_buffer) => count_buffer.this,
                     None => std::ptr::null_mut(),
-                }
+                },
+                count_buffer_offset.0
             )
-        };
-        Ok(())
+        }
     }
 
-    pub fn resolve_query_data(
+    #[inline]
+    pub fn dispatch(
         &self,
-        query_heap: &QueryHeap,
-        query_type: QueryType,
-        start_index: u32,
-        num_queries: u32,
-        destination_buffer: &Resource,
-        aligned_destination_buffer_offset: ByteCount,
+        thread_group_count_x: u32,
+        thread_group_count_y: u32,
+        thread_group_count_z: u32,
     ) {
-        unsafe {
-            dx_call!(
-                self.this,
-                ResolveQueryData,
-                query_heap.this,
-                query_type as i32,
-                start_index,
-                num_queries,
-                destination_buffer.this,
-                aligned_destination_buffer_offset.0
-            );
-        }
-    }
+        validate_recording!(self, "CommandList::dispatch");
 
-    pub fn resource_barrier(&self, barriers: &[ResourceBarrier]) {
         unsafe {
             dx_call!(
                 self.this,
-                ResourceBarrier,
-                barriers.len() as std::os::raw::c_uint,
-                barriers.as_ptr() as *const D3D12_RESOURCE_BARRIER
-            );
+                Dispatch,
+                thread_group_count_x,
+                thread_group_count_y,
+                thread_group_count_z
+            )
         }
     }
 
-    pub fn set_blend_factor(&self, blend_factor: [f32; 4]) {
-        unsafe { dx_call!(self.this, OMSetBlendFactor, blend_factor.as_ptr()) }
-    }
-
+    #[inline]
     pub fn set_compute_root_32bit_constant(
         &self,
         root_parameter_index: u32,
         src_data: u32,
         dest_offset: u32,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_compute_root_32bit_constant"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2140,12 +5953,18 @@ impl CommandList {
     }
 
     // ToDo: 32_bit
+    #[inline]
     pub fn set_compute_root_32bit_constants(
         &self,
         root_parameter_index: u32,
         src_data: &[u32],
         dest_offset: u32,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_compute_root_32bit_constants"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2158,11 +5977,17 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_compute_root_constant_buffer_view(
         &self,
         root_parameter_index: u32,
         buffer_location: GpuVirtualAddress,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_compute_root_constant_buffer_view"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2173,11 +5998,17 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_compute_root_descriptor_table(
         &self,
         parameter_index: u32,
         base_descriptor: GpuDescriptorHandle,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_compute_root_descriptor_table"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2188,11 +6019,17 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_compute_root_shader_resource_view(
         &self,
         root_parameter_index: u32,
         buffer_location: GpuVirtualAddress,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_compute_root_shader_resource_view"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2203,17 +6040,26 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_compute_root_signature(&self, root_signature: &RootSignature) {
+        validate_recording!(self, "CommandList::set_compute_root_signature");
+
         unsafe {
             dx_call!(self.this, SetComputeRootSignature, root_signature.this)
         }
     }
 
+    #[inline]
     pub fn set_compute_root_unordered_access_view(
         &self,
         root_parameter_index: u32,
         buffer_location: GpuVirtualAddress,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_compute_root_unordered_access_view"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2222,25 +6068,301 @@ impl CommandList {
                 buffer_location.0
             )
         }
-    }
+    }
+
+    /// Same as [CommandList::set_compute_root_constant_buffer_view], but
+    /// takes a buffer and an offset into it instead of a raw GPU address
+    #[inline]
+    pub fn set_compute_root_cbv_buffer(
+        &self,
+        root_parameter_index: u32,
+        buffer: &Resource,
+        offset: ByteCount,
+    ) {
+        self.set_compute_root_constant_buffer_view(
+            root_parameter_index,
+            root_descriptor_address(buffer, offset),
+        )
+    }
+
+    /// Same as [CommandList::set_compute_root_shader_resource_view], but
+    /// takes a buffer and an offset into it instead of a raw GPU address
+    #[inline]
+    pub fn set_compute_root_srv_buffer(
+        &self,
+        root_parameter_index: u32,
+        buffer: &Resource,
+        offset: ByteCount,
+    ) {
+        self.set_compute_root_shader_resource_view(
+            root_parameter_index,
+            root_descriptor_address(buffer, offset),
+        )
+    }
+
+    /// Same as [CommandList::set_compute_root_unordered_access_view], but
+    /// takes a buffer and an offset into it instead of a raw GPU address
+    #[inline]
+    pub fn set_compute_root_uav_buffer(
+        &self,
+        root_parameter_index: u32,
+        buffer: &Resource,
+        offset: ByteCount,
+    ) {
+        self.set_compute_root_unordered_access_view(
+            root_parameter_index,
+            root_descriptor_address(buffer, offset),
+        )
+    }
+
+    #[inline]
+    /// At most one CBV/SRV/UAV and one sampler heap can be bound at a time
+    pub fn set_descriptor_heaps(&self, heaps: &[DescriptorHeap]) {
+        validate_recording!(self, "CommandList::set_descriptor_heaps");
+
+        assert!(
+            heaps.len() <= 2,
+            "Cannot bind more than two descriptor heaps"
+        );
+        #[cfg(feature = "validation")]
+        if let Err(message) =
+            validation::validate_descriptor_heaps(self.this as usize, K::TYPE)
+        {
+            log::error!("Skipping descriptor heap binding: {}", message);
+            return;
+        }
+        let mut hw_heaps = [std::ptr::null_mut(); 2];
+        for (hw_heap, heap) in hw_heaps.iter_mut().zip(heaps) {
+            *hw_heap = heap.this;
+        }
+
+        unsafe {
+            dx_call!(
+                self.this,
+                SetDescriptorHeaps,
+                heaps.len() as std::os::raw::c_uint,
+                hw_heaps.as_ptr()
+            )
+        }
+    }
+
+    #[inline]
+    pub fn set_pipeline_state(&self, pipeline_state: &PipelineState) {
+        validate_recording!(self, "CommandList::set_pipeline_state");
+
+        #[cfg(feature = "validation")]
+        validation::record_pipeline_state_binding(
+            self.this as usize,
+            pipeline_state,
+        );
+
+        unsafe { dx_call!(self.this, SetPipelineState, pipeline_state.this) }
+    }
+}
+
+/// Draw and input assembler commands, available in direct command lists
+/// and bundles
+impl<K: GraphicsCapable> CommandList<K> {
+    #[inline]
+    pub fn dispatch_mesh(
+        &self,
+        thread_group_count_x: u32,
+        thread_group_count_y: u32,
+        thread_group_count_z: u32,
+    ) {
+        validate_recording!(self, "CommandList::dispatch_mesh");
+
+        unsafe {
+            dx_call!(
+                self.this,
+                DispatchMesh,
+                thread_group_count_x,
+                thread_group_count_y,
+                thread_group_count_z
+            )
+        }
+    }
+
+    #[inline]
+    pub fn draw_indexed_instanced(
+        &self,
+        index_count_per_instance: u32,
+        instance_count: u32,
+        start_index_location: u32,
+        base_vertex_location: i32,
+        start_instance_location: u32,
+    ) {
+        validate_recording!(self, "CommandList::draw_indexed_instanced");
+
+        #[cfg(feature = "validation")]
+        if let Err(message) = validation::validate_draw(self.this as usize) {
+            // the tracked state may be incomplete, so the draw is still
+            // recorded and the debug layer has the final word
+            log::error!("Invalid draw: {}", message);
+        }
+
+        unsafe {
+            dx_call!(
+                self.this,
+                DrawIndexedInstanced,
+                index_count_per_instance,
+                instance_count,
+                start_index_location,
+                base_vertex_location,
+                start_instance_location
+            )
+        }
+    }
+
+    #[inline]
+    pub fn draw_instanced(
+        &self,
+        vertex_count_per_instance: u32,
+        instance_count: u32,
+        start_vertex_location: u32,
+        start_instance_location: u32,
+    ) {
+        validate_recording!(self, "CommandList::draw_instanced");
+
+        #[cfg(feature = "validation")]
+        if let Err(message) = validation::validate_draw(self.this as usize) {
+            // the tracked state may be incomplete, so the draw is still
+            // recorded and the debug layer has the final word
+            log::error!("Invalid draw: {}", message);
+        }
+
+        unsafe {
+            dx_call!(
+                self.this,
+                DrawInstanced,
+                vertex_count_per_instance,
+                instance_count,
+                start_vertex_location,
+                start_instance_location
+            )
+        }
+    }
+
+    #[inline]
+    pub fn set_blend_factor(&self, blend_factor: [f32; 4]) {
+        validate_recording!(self, "CommandList::set_blend_factor");
+
+        unsafe { dx_call!(self.this, OMSetBlendFactor, blend_factor.as_ptr()) }
+    }
+
+    /// Sets the shading rate of the subsequent draws. `combiners` merge
+    /// it with the per-primitive rate and then with the rate from
+    /// the shading rate image, None means passthrough for both and is
+    /// the only option on tier 1, see [FeatureSupport::shading_rate_supported]
+    pub fn set_shading_rate(
+        &self,
+        base_shading_rate: ShadingRate,
+        combiners: Option<[ShadingRateCombiner; 2]>,
+    ) {
+        validate_recording!(self, "CommandList::set_shading_rate");
+
+        let combiners = combiners.map(|combiners| combiners.map(|c| c as i32));
+        unsafe {
+            dx_call!(
+                self.this,
+                RSSetShadingRate,
+                base_shading_rate as i32,
+                match &combiners {
+                    Some(combiners) => combiners.as_ptr(),
+                    None => std::ptr::null(),
+                }
+            )
+        }
+    }
+
+    /// Binds an R8Uint texture holding a [ShadingRate] per screen tile
+    /// (see [FeatureSupport::shading_rate_image_tile_size]), which must
+    /// be in ShadingRateSource state. None unbinds the current one
+    pub fn set_shading_rate_image(
+        &self,
+        shading_rate_image: Option<&Resource>,
+    ) {
+        validate_recording!(self, "CommandList::set_shading_rate_image");
+
+        unsafe {
+            dx_call!(
+                self.this,
+                RSSetShadingRateImage,
+                match shading_rate_image {
+                    Some(resource) => resource.this,
+                    None => std::ptr::null_mut(),
+                }
+            )
+        }
+    }
+
+    /// Sets the depth range outside of which pixels are discarded when
+    /// depth bounds test is enabled in the PSO
+    #[inline]
+    pub fn set_depth_bounds(&self, min: f32, max: f32) {
+        validate_recording!(self, "CommandList::set_depth_bounds");
+
+        unsafe { dx_call!(self.this, OMSetDepthBounds, min, max) }
+    }
+
+    /// Overrides the sample positions of the subsequent draws.
+    /// `positions` holds `num_samples_per_pixel` positions for each of
+    /// `num_pixels` (1 or 4, the latter covering a 2x2 quad) pixels, and
+    /// an empty slice restores the default positions. Slices of any other
+    /// length are reported and the call is skipped
+    pub fn set_sample_positions(
+        &self,
+        num_samples_per_pixel: u32,
+        num_pixels: u32,
+        positions: &[SamplePosition],
+    ) {
+        validate_recording!(self, "CommandList::set_sample_positions");
+
+        if !positions.is_empty()
+            && positions.len() as u32 != num_samples_per_pixel * num_pixels
+        {
+            log::error!(
+                "Skipping sample position update: {} positions for {} \
+                 samples per pixel and {} pixels",
+                positions.len(),
+                num_samples_per_pixel,
+                num_pixels
+            );
+            return;
+        }
 
-    pub fn set_descriptor_heaps(&self, heaps: &[DescriptorHeap]) {
+        let (num_samples_per_pixel, num_pixels, positions) =
+            match positions.is_empty() {
+                true => (0, 0, std::ptr::null_mut()),
+                false => (
+                    num_samples_per_pixel,
+                    num_pixels,
+                    positions.as_ptr() as *mut D3D12_SAMPLE_POSITION,
+                ),
+            };
         unsafe {
             dx_call!(
                 self.this,
-                SetDescriptorHeaps,
-                heaps.len() as std::os::raw::c_uint,
-                heaps.as_ptr() as *const *mut ID3D12DescriptorHeap
+                SetSamplePositions,
+                num_samples_per_pixel,
+                num_pixels,
+                positions
             )
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_32bit_constant(
         &self,
         root_parameter_index: u32,
         src_data: u32,
         dest_offset: u32,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_graphics_root_32bit_constant"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2252,12 +6374,18 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_32bit_constants(
         &self,
         root_parameter_index: u32,
         src_data: &[u32],
         dest_offset: u32,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_graphics_root_32bit_constants"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2270,11 +6398,17 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_constant_buffer_view(
         &self,
         root_parameter_index: u32,
         buffer_location: GpuVirtualAddress,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_graphics_root_constant_buffer_view"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2285,11 +6419,17 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_descriptor_table(
         &self,
         parameter_index: u32,
         base_descriptor: GpuDescriptorHandle,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_graphics_root_descriptor_table"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2300,11 +6440,17 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_shader_resource_view(
         &self,
         root_parameter_index: u32,
         buffer_location: GpuVirtualAddress,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_graphics_root_shader_resource_view"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2315,17 +6461,26 @@ impl CommandList {
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_signature(&self, root_signature: &RootSignature) {
+        validate_recording!(self, "CommandList::set_graphics_root_signature");
+
         unsafe {
             dx_call!(self.this, SetGraphicsRootSignature, root_signature.this)
         }
     }
 
+    #[inline]
     pub fn set_graphics_root_unordered_access_view(
         &self,
         root_parameter_index: u32,
         buffer_location: GpuVirtualAddress,
     ) {
+        validate_recording!(
+            self,
+            "CommandList::set_graphics_root_unordered_access_view"
+        );
+
         unsafe {
             dx_call!(
                 self.this,
@@ -2336,193 +6491,389 @@ impl CommandList {
         }
     }
 
-    pub fn set_index_buffer(&self, view: &IndexBufferView) {
-        unsafe { dx_call!(self.this, IASetIndexBuffer, &view.0) }
+    /// Same as [CommandList::set_graphics_root_constant_buffer_view], but
+    /// takes a buffer and an offset into it instead of a raw GPU address
+    #[inline]
+    pub fn set_graphics_root_cbv_buffer(
+        &self,
+        root_parameter_index: u32,
+        buffer: &Resource,
+        offset: ByteCount,
+    ) {
+        self.set_graphics_root_constant_buffer_view(
+            root_parameter_index,
+            root_descriptor_address(buffer, offset),
+        )
     }
 
-    pub fn set_pipeline_state(&self, pipeline_state: &PipelineState) {
-        unsafe { dx_call!(self.this, SetPipelineState, pipeline_state.this) }
+    /// Same as [CommandList::set_graphics_root_shader_resource_view], but
+    /// takes a buffer and an offset into it instead of a raw GPU address
+    #[inline]
+    pub fn set_graphics_root_srv_buffer(
+        &self,
+        root_parameter_index: u32,
+        buffer: &Resource,
+        offset: ByteCount,
+    ) {
+        self.set_graphics_root_shader_resource_view(
+            root_parameter_index,
+            root_descriptor_address(buffer, offset),
+        )
+    }
+
+    /// Same as [CommandList::set_graphics_root_unordered_access_view], but
+    /// takes a buffer and an offset into it instead of a raw GPU address
+    #[inline]
+    pub fn set_graphics_root_uav_buffer(
+        &self,
+        root_parameter_index: u32,
+        buffer: &Resource,
+        offset: ByteCount,
+    ) {
+        self.set_graphics_root_unordered_access_view(
+            root_parameter_index,
+            root_descriptor_address(buffer, offset),
+        )
+    }
+
+    #[inline]
+    pub fn set_index_buffer(&self, view: &IndexBufferView) {
+        validate_recording!(self, "CommandList::set_index_buffer");
+
+        unsafe { dx_call!(self.this, IASetIndexBuffer, &view.0) }
     }
 
+    #[inline]
     pub fn set_primitive_topology(&self, topology: PrimitiveTopology) {
+        validate_recording!(self, "CommandList::set_primitive_topology");
+
+        #[cfg(feature = "validation")]
+        validation::record_primitive_topology(self.this as usize, topology);
+
         unsafe { dx_call!(self.this, IASetPrimitiveTopology, topology as i32) }
     }
 
-    pub fn set_render_targets(
+    #[inline]
+    pub fn set_vertex_buffers(
         &self,
-        descriptors: &[CpuDescriptorHandle],
-        single_handle_to_descriptor_range: bool,
-        depth_stencil: Option<CpuDescriptorHandle>,
+        start_slot: u32,
+        views: &[VertexBufferView],
     ) {
+        validate_recording!(self, "CommandList::set_vertex_buffers");
+
         unsafe {
             dx_call!(
                 self.this,
-                OMSetRenderTargets,
-                descriptors.len() as std::os::raw::c_uint,
-                descriptors.as_ptr() as *mut D3D12_CPU_DESCRIPTOR_HANDLE,
-                match single_handle_to_descriptor_range {
-                    true => 1,
-                    false => 0,
-                },
-                match depth_stencil {
-                    Some(ref depth_desc) => &depth_desc.hw_handle,
-                    None => std::ptr::null_mut(),
-                }
+                IASetVertexBuffers,
+                start_slot,
+                views.len() as UINT,
+                views.as_ptr() as *const D3D12_VERTEX_BUFFER_VIEW
             )
         }
     }
+}
+
+/// Commands that can only be recorded into direct command lists
+impl CommandList<DirectKind> {
+    /// Selects the views of a view instancing PSO that the subsequent
+    /// draws are broadcast to, one bit per view
+    pub fn set_view_instance_mask(&self, mask: u32) {
+        validate_recording!(self, "CommandList::set_view_instance_mask");
+
+        unsafe { dx_call!(self.this, SetViewInstanceMask, mask) }
+    }
+
+    /// Resolves a multisampled subresource into a non-multisampled one
+    /// of the same size, e.g. an MSAA render target into a swapchain buffer.
+    /// The resources must be in ResolveSource and ResolveDest states, and
+    /// `format` must be compatible with both of them
+    pub fn resolve_subresource(
+        &self,
+        dst_resource: &Resource,
+        dst_subresource: u32,
+        src_resource: &Resource,
+        src_subresource: u32,
+        format: Format,
+    ) {
+        validate_recording!(self, "CommandList::resolve_subresource");
 
-    pub fn set_scissor_rects(&self, scissors: &[Rect]) {
         unsafe {
             dx_call!(
                 self.this,
-                RSSetScissorRects,
-                scissors.len() as std::os::raw::c_uint,
-                scissors.as_ptr() as *const D3D12_RECT
+                ResolveSubresource,
+                dst_resource.this,
+                dst_subresource,
+                src_resource.this,
+                src_subresource,
+                format as i32
+            )
+        }
+    }
+
+    /// Resolves all the subresources of `src_resource` into the
+    /// corresponding subresources of `dst_resource`, which must have
+    /// the same mip count and array size
+    pub fn resolve_resource(
+        &self,
+        dst_resource: &Resource,
+        src_resource: &Resource,
+        format: Format,
+    ) {
+        let src_desc = src_resource.get_desc();
+        debug_assert!(
+            {
+                let dst_desc = dst_resource.get_desc();
+                dst_desc.mip_levels() == src_desc.mip_levels()
+                    && dst_desc.array_size() == src_desc.array_size()
+            },
+            "Resolve source and destination have different subresource counts"
+        );
+
+        let subresources = SubresourceRange::all(&src_desc);
+        for subresource in subresources.subresource_indices(
+            src_desc.mip_levels() as u32,
+            src_desc.array_size(),
+        ) {
+            self.resolve_subresource(
+                dst_resource,
+                subresource,
+                src_resource,
+                subresource,
+                format,
             );
         }
     }
 
-    pub fn set_vertex_buffers(
+    /// Resolves a region of a multisampled resource, or transcodes sampler
+    /// feedback between its opaque format and a regular R8Uint texture when
+    /// used with [ResolveMode::DecodeSamplerFeedback] and
+    /// [ResolveMode::EncodeSamplerFeedback]. Passing None as `src_rect`
+    /// resolves the whole subresource
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve_subresource_region(
         &self,
-        start_slot: u32,
-        views: &[VertexBufferView],
+        dst_resource: &Resource,
+        dst_subresource: u32,
+        dst_x: u32,
+        dst_y: u32,
+        src_resource: &Resource,
+        src_subresource: u32,
+        src_rect: Option<&Rect>,
+        format: Format,
+        resolve_mode: ResolveMode,
     ) {
+        validate_recording!(self, "CommandList::resolve_subresource_region");
+
         unsafe {
             dx_call!(
                 self.this,
-                IASetVertexBuffers,
-                start_slot,
-                views.len() as UINT,
-                views.as_ptr() as *const D3D12_VERTEX_BUFFER_VIEW
+                ResolveSubresourceRegion,
+                dst_resource.this,
+                dst_subresource,
+                dst_x,
+                dst_y,
+                src_resource.this,
+                src_subresource,
+                match src_rect {
+                    Some(rect) =>
+                        &rect.0 as *const D3D12_RECT as *mut D3D12_RECT,
+                    None => std::ptr::null_mut(),
+                },
+                format as i32,
+                resolve_mode as i32
             )
         }
     }
 
-    pub fn set_viewports(&self, viewports: &[Viewport]) {
+    /// Passing None as `rects` clears the whole view
+    #[inline]
+    pub fn clear_depth_stencil_view(
+        &self,
+        descriptor: CpuDescriptorHandle,
+        clear_flags: ClearFlags,
+        depth: f32,
+        stencil: u8,
+        rects: Option<&[Rect]>,
+    ) {
+        validate_recording!(self, "CommandList::clear_depth_stencil_view");
+
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_clear(descriptor);
+
+        let rects = rects.unwrap_or(&[]);
         unsafe {
             dx_call!(
                 self.this,
-                RSSetViewports,
-                viewports.len() as std::os::raw::c_uint,
-                viewports.as_ptr() as *const D3D12_VIEWPORT
-            );
+                ClearDepthStencilView,
+                descriptor.hw_handle,
+                clear_flags.bits(),
+                depth,
+                stencil,
+                rects.len() as u32,
+                match rects.is_empty() {
+                    true => std::ptr::null(),
+                    false => rects.as_ptr() as *const D3D12_RECT,
+                }
+            )
         }
     }
 
-    // d3dx12.h helper
-    #[allow(clippy::too_many_arguments)]
-    pub fn update_subresources(
+    /// Passing None as `rects` clears the whole view
+    #[inline]
+    pub fn clear_render_target_view(
         &self,
-        destination_resource: &Resource,
-        intermediate_resource: &Resource,
-        first_subresouce: u32,
-        num_subresources: u32,
-        required_size: ByteCount,
-        layouts: &[PlacedSubresourceFootprint],
-        num_rows: &[u32],
-        row_sizes_in_bytes: &[ByteCount],
-        source_data: &[SubresourceData],
-    ) -> DxResult<ByteCount> {
-        // ToDo: implement validation as in the original function
+        descriptor: CpuDescriptorHandle,
+        color: impl Into<Color>,
+        rects: Option<&[Rect]>,
+    ) {
+        validate_recording!(self, "CommandList::clear_render_target_view");
 
-        let data = intermediate_resource.map(0, None)?;
+        #[cfg(feature = "resource_stats")]
+        resource_stats::record_clear(descriptor);
 
+        let color = color.into();
+        let rects = rects.unwrap_or(&[]);
         unsafe {
-            for i in 0..num_subresources as usize {
-                let dest_data = D3D12_MEMCPY_DEST {
-                    pData: data.offset(layouts[i].0.Offset as isize)
-                        as *mut std::ffi::c_void,
-                    RowPitch: layouts[i].0.Footprint.RowPitch as u64,
-                    SlicePitch: (layouts[i].0.Footprint.RowPitch as u64)
-                        * num_rows[i] as u64,
-                };
-
-                memcpy_subresource(
-                    &dest_data,
-                    &source_data[i].0,
-                    row_sizes_in_bytes[i],
-                    num_rows[i],
-                    layouts[i].0.Footprint.Depth,
-                );
-            }
+            dx_call!(
+                self.this,
+                ClearRenderTargetView,
+                descriptor.hw_handle,
+                color.0.as_ptr(),
+                rects.len() as u32,
+                match rects.is_empty() {
+                    true => std::ptr::null(),
+                    false => rects.as_ptr() as *const D3D12_RECT,
+                }
+            )
         }
-        intermediate_resource.unmap(0, None);
+    }
 
-        let destination_desc = destination_resource.get_desc();
-        if destination_desc.0.Dimension == ResourceDimension::Buffer as i32 {
-            self.copy_buffer_region(
-                destination_resource,
-                ByteCount(0),
-                intermediate_resource,
-                ByteCount(layouts[0].0.Offset),
-                ByteCount(layouts[0].0.Footprint.Width as u64),
-            );
-        } else {
-            for i in 0..num_subresources as usize {
-                let dest_location = TextureCopyLocation::new_subresource_index(
-                    destination_resource,
-                    i as u32 + first_subresouce,
-                );
-                let source_location = TextureCopyLocation::new_placed_footprint(
-                    intermediate_resource,
-                    layouts[i],
-                );
+    #[inline]
+    pub fn execute_bundle(&self, bundle: &Bundle) {
+        validate_recording!(self, "CommandList::execute_bundle");
 
-                self.copy_texture_region(
-                    dest_location,
-                    0,
-                    0,
-                    0,
-                    source_location,
-                    None,
-                );
-            }
+        #[cfg(feature = "validation")]
+        if let Err(message) =
+            validation::validate_bundle_execution(bundle.this as usize)
+        {
+            log::error!("Skipping bundle execution: {}", message);
+            return;
         }
 
-        Ok(required_size)
+        unsafe {
+            dx_call!(
+                self.this,
+                ExecuteBundle,
+                // ToDo: is it 100% safe?
+                bundle.this as *mut ID3D12GraphicsCommandList
+            );
+        }
     }
 
-    // The stack-allocating version cannot be implemented without changing
-    // function signature since it would require function output parameters
-    pub fn update_subresources_heap_alloc(
+    #[inline]
+    pub fn set_render_targets(
         &self,
-        destination_resource: &Resource,
-        intermediate_resource: &Resource,
-        intermediate_offset: ByteCount,
-        first_subresouce: u32,
-        num_subresources: u32,
-        source_data: &[SubresourceData],
-    ) -> DxResult<ByteCount> {
-        let allocation_size = ByteCount::from(
-            std::mem::size_of::<PlacedSubresourceFootprint>()
-                + std::mem::size_of::<u32>()
-                + std::mem::size_of::<u64>(),
-        ) * num_subresources;
+        descriptors: &[CpuDescriptorHandle],
+        single_handle_to_descriptor_range: bool,
+        depth_stencil: Option<CpuDescriptorHandle>,
+    ) {
+        validate_recording!(self, "CommandList::set_render_targets");
 
-        let destination_desc = destination_resource.get_desc();
-        let device = destination_resource.get_device()?;
-        let (layouts, num_rows, row_sizes_in_bytes, required_size) = device
-            .get_copyable_footprints(
-                &destination_desc,
-                first_subresouce,
-                num_subresources,
-                intermediate_offset,
+        #[cfg(feature = "validation")]
+        validation::record_render_targets(
+            self.this as usize,
+            descriptors,
+            single_handle_to_descriptor_range,
+        );
+
+        unsafe {
+            dx_call!(
+                self.this,
+                OMSetRenderTargets,
+                descriptors.len() as std::os::raw::c_uint,
+                descriptors.as_ptr() as *mut D3D12_CPU_DESCRIPTOR_HANDLE,
+                match single_handle_to_descriptor_range {
+                    true => 1,
+                    false => 0,
+                },
+                match depth_stencil {
+                    Some(ref depth_desc) => &depth_desc.hw_handle,
+                    None => std::ptr::null_mut(),
+                }
+            )
+        }
+    }
+
+    #[inline]
+    pub fn set_scissor_rects(&self, scissors: &[Rect]) {
+        validate_recording!(self, "CommandList::set_scissor_rects");
+
+        unsafe {
+            dx_call!(
+                self.this,
+                RSSetScissorRects,
+                scissors.len() as std::os::raw::c_uint,
+                scissors.as_ptr() as *const D3D12_RECT
             );
-        self.update_subresources(
-            destination_resource,
-            intermediate_resource,
-            first_subresouce,
-            num_subresources,
-            required_size,
-            &layouts,
-            &num_rows,
-            &row_sizes_in_bytes,
-            source_data,
-        )
+        }
+    }
+
+    #[inline]
+    pub fn set_viewports(&self, viewports: &[Viewport]) {
+        validate_recording!(self, "CommandList::set_viewports");
+
+        unsafe {
+            dx_call!(
+                self.this,
+                RSSetViewports,
+                viewports.len() as std::os::raw::c_uint,
+                viewports.as_ptr() as *const D3D12_VIEWPORT
+            );
+        }
     }
 }
 
+fn split_atomic_copy_dependencies(
+    dependencies: &[(&Resource, SubresourceRangeUint64)],
+) -> (
+    Vec<*mut ID3D12Resource>,
+    Vec<D3D12_SUBRESOURCE_RANGE_UINT64>,
+) {
+    dependencies
+        .iter()
+        .map(|(resource, range)| (resource.this, range.0))
+        .unzip()
+}
+
+// root descriptors can only point to buffers, textures need descriptor tables
+fn root_descriptor_address(
+    buffer: &Resource,
+    offset: ByteCount,
+) -> GpuVirtualAddress {
+    debug_assert!(
+        matches!(buffer.get_desc().dimension(), ResourceDimension::Buffer),
+        "Root descriptor must point to a buffer"
+    );
+    buffer.gpu_address_at(offset)
+}
+
+// GetCopyableFootprints accepts only consecutive subresource indices
+fn contiguous_subresources(
+    desc: &ResourceDesc,
+    subresources: &SubresourceRange,
+) -> DxResult<(u32, u32)> {
+    subresources
+        .as_contiguous(desc.mip_levels() as u32, desc.array_size())
+        .ok_or_else(|| {
+            error!(
+                "Subresource range {:?} does not map to consecutive \
+                 subresource indices",
+                subresources
+            );
+            DxError::new("contiguous_subresources", winerror::E_INVALIDARG)
+        })
+}
+
 // this function should not leak to the public API, so
 // there is no point in using struct wrappers
 unsafe fn memcpy_subresource(
@@ -2532,15 +6883,18 @@ unsafe fn memcpy_subresource(
     num_rows: u32,
     num_slices: u32,
 ) {
-    for z in 0..num_slices {
-        let dest_slice =
-            dest.pData.offset((dest.SlicePitch * z as u64) as isize);
-        let src_slice = src.pData.offset((src.SlicePitch * z as i64) as isize);
-
-        for y in 0..num_rows {
+    // pitches are pointer-sized (SIZE_T and LONG_PTR), so they are
+    // converted before the multiplication rather than after it
+    let dest_data = dest.pData as *mut u8;
+    let src_data = src.pData as *const u8;
+    for z in 0..num_slices as usize {
+        let dest_slice = dest_data.add(dest.SlicePitch as usize * z);
+        let src_slice = src_data.offset(src.SlicePitch as isize * z as isize);
+
+        for y in 0..num_rows as usize {
             std::ptr::copy_nonoverlapping(
-                src_slice.offset((src.RowPitch * y as i64) as isize),
-                dest_slice.offset((dest.RowPitch * y as u64) as isize),
+                src_slice.offset(src.RowPitch as isize * y as isize),
+                dest_slice.add(dest.RowPitch as usize * y),
                 row_sizes_in_bytes.0 as usize,
             );
         }
@@ -2550,12 +6904,14 @@ unsafe fn memcpy_subresource(
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Fence {
-    pub this: *mut ID3D12Fence,
+    pub(crate) this: *mut ID3D12Fence,
 }
 
 impl_com_object_set_get_name!(Fence);
 impl_com_object_refcount_named!(Fence);
+impl_com_object_private_data!(Fence);
 impl_com_object_clone_drop!(Fence);
+impl_com_object_raw!(Fence, ID3D12Fence);
 
 // ToDo: make sure ID3D12Fence is thread-safe
 unsafe impl Send for Fence {}
@@ -2565,6 +6921,16 @@ impl Fence {
         unsafe { dx_call!(self.this, GetCompletedValue,) }
     }
 
+    /// See [Device::describe]
+    pub fn describe(&self) -> String {
+        format!(
+            "Fence {:p} {}: completed value {}",
+            self.this,
+            describe_name(self.get_name()),
+            self.get_completed_value()
+        )
+    }
+
     pub fn set_event_on_completion(
         &self,
         value: u64,
@@ -2580,6 +6946,108 @@ impl Fence {
         unsafe { dx_try!(self.this, Signal, value) }
         Ok(())
     }
+
+    /// Blocks until the fence reaches `value`, returns false if `timeout`
+    /// (in milliseconds) has expired before that. The event is created
+    /// once per thread and reused by subsequent calls
+    pub fn wait_blocking(
+        &self,
+        value: u64,
+        timeout: Option<u32>,
+    ) -> DxResult<bool> {
+        if self.get_completed_value() >= value {
+            return Ok(true);
+        }
+
+        wait_with_thread_event(
+            &[(self.clone(), value)],
+            true,
+            timeout,
+            |event| self.set_event_on_completion(value, event),
+        )
+    }
+}
+
+fn fences_reached(fences: &[(Fence, u64)], wait_all: bool) -> bool {
+    let mut reached = fences
+        .iter()
+        .map(|(fence, value)| fence.get_completed_value() >= *value);
+    match wait_all {
+        true => reached.all(|reached| reached),
+        false => reached.any(|reached| reached),
+    }
+}
+
+// Arms the event of the calling thread with `set_event` and waits until
+// `fences` are reached. Since the event is auto-reset and may have been
+// set by an earlier wait that has timed out, waking up only means that
+// the fences have to be checked again
+fn wait_with_thread_event(
+    fences: &[(Fence, u64)],
+    wait_all: bool,
+    timeout: Option<u32>,
+    set_event: impl FnOnce(&Win32Event) -> DxResult<()>,
+) -> DxResult<bool> {
+    FENCE_WAIT_EVENT.with(|wait_event| {
+        let mut wait_event = wait_event.borrow_mut();
+        wait_event
+            .armed_waits
+            .retain(|(fences, wait_all)| !fences_reached(fences, *wait_all));
+
+        set_event(&wait_event.event)?;
+        let start = std::time::Instant::now();
+        loop {
+            if fences_reached(fences, wait_all) {
+                return Ok(true);
+            }
+
+            let remaining_timeout = timeout.map(|timeout| {
+                let elapsed = start.elapsed().as_millis();
+                timeout.saturating_sub(elapsed.min(u32::MAX as u128) as u32)
+            });
+            if !wait_event.event.wait_checked(remaining_timeout)? {
+                if fences_reached(fences, wait_all) {
+                    return Ok(true);
+                }
+
+                wait_event.armed_waits.push((fences.to_vec(), wait_all));
+                return Ok(false);
+            }
+        }
+    })
+}
+
+// Event used by the blocking fence waits of a thread. Fences keep the events
+// of the waits that have timed out and set them later, so the event is only
+// closed when the thread exits if all of those waits have completed
+struct FenceWaitEvent {
+    event: Win32Event,
+    // (fences, whether all of them must be reached) of the timed out waits
+    armed_waits: Vec<(Vec<(Fence, u64)>, bool)>,
+}
+
+impl Drop for FenceWaitEvent {
+    fn drop(&mut self) {
+        let completed = self
+            .armed_waits
+            .iter()
+            .all(|(fences, wait_all)| fences_reached(fences, *wait_all));
+        match completed {
+            true => self.event.close(),
+            false => warn!(
+                "Leaking fence wait event of an exiting thread, since \
+                 a fence is still going to set it"
+            ),
+        }
+    }
+}
+
+thread_local! {
+    static FENCE_WAIT_EVENT: std::cell::RefCell<FenceWaitEvent> =
+        std::cell::RefCell::new(FenceWaitEvent {
+            event: Win32Event::default(),
+            armed_waits: vec![],
+        });
 }
 
 // ToDo: use windows events from a different crate?
@@ -2621,6 +7089,27 @@ impl Win32Event {
             CloseHandle(self.handle);
         }
     }
+
+    // Returns false on timeout, unlike Win32Event::wait reports failures
+    pub(crate) fn wait_checked(
+        &self,
+        milliseconds: Option<u32>,
+    ) -> DxResult<bool> {
+        match unsafe {
+            WaitForSingleObject(self.handle, milliseconds.unwrap_or(0xFFFFFFFF))
+        } {
+            winerror::WAIT_TIMEOUT => Ok(false),
+            winapi::um::winbase::WAIT_FAILED => {
+                let error_code =
+                    std::io::Error::last_os_error().raw_os_error().unwrap_or(0);
+                Err(DxError::new(
+                    "WaitForSingleObject",
+                    winerror::HRESULT_FROM_WIN32(error_code as u32),
+                ))
+            }
+            _ => Ok(true),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -2636,15 +7125,72 @@ impl Handle {
     }
 }
 
+/// Remembers the byte stride and the layout of the arguments, so that
+/// [CommandList::execute_indirect] can validate the argument buffers
+#[derive(Debug)]
+pub struct CommandSignature {
+    pub(crate) this: *mut ID3D12CommandSignature,
+    byte_stride: ByteCount,
+    arguments_size: ByteCount,
+    command_type: IndirectArgumentType,
+}
+
+impl_com_object_set_get_name!(CommandSignature);
+impl_com_object_refcount_named!(CommandSignature);
+impl_com_object_private_data!(CommandSignature);
+impl_com_object_clone_drop!(
+    CommandSignature,
+    byte_stride,
+    arguments_size,
+    command_type
+);
+
+unsafe impl Send for CommandSignature {}
+
+impl CommandSignature {
+    /// See [Device::as_raw]. There is no `from_raw`, since the byte stride
+    /// and the arguments cannot be queried from the interface
+    pub fn as_raw(&self) -> *mut ID3D12CommandSignature {
+        self.this
+    }
+
+    pub fn byte_stride(&self) -> ByteCount {
+        self.byte_stride
+    }
+
+    /// Total size of the arguments of a single command, which can be
+    /// smaller than the stride
+    pub fn arguments_size(&self) -> ByteCount {
+        self.arguments_size
+    }
+
+    /// Type of the last argument, i.e. the command that is executed
+    pub fn command_type(&self) -> IndirectArgumentType {
+        self.command_type
+    }
+
+    /// True if the commands can only be executed on direct command lists
+    pub fn is_graphics(&self) -> bool {
+        matches!(
+            self.command_type,
+            IndirectArgumentType::Draw
+                | IndirectArgumentType::DrawIndexed
+                | IndirectArgumentType::DispatchMesh
+        )
+    }
+}
+
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct RootSignature {
-    pub this: *mut ID3D12RootSignature,
+    pub(crate) this: *mut ID3D12RootSignature,
 }
 
 impl_com_object_set_get_name!(RootSignature);
 impl_com_object_refcount_named!(RootSignature);
+impl_com_object_private_data!(RootSignature);
 impl_com_object_clone_drop!(RootSignature);
+impl_com_object_raw!(RootSignature, ID3D12RootSignature);
 
 unsafe impl Send for RootSignature {}
 
@@ -2680,30 +7226,342 @@ impl RootSignature {
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct PipelineState {
-    pub this: *mut ID3D12PipelineState,
+    pub(crate) this: *mut ID3D12PipelineState,
 }
 impl_com_object_set_get_name!(PipelineState);
 impl_com_object_refcount_named!(PipelineState);
-impl_com_object_clone_drop!(PipelineState);
+impl_com_object_private_data!(PipelineState);
+
+impl Clone for PipelineState {
+    fn clone(&self) -> Self {
+        self.add_ref();
+        Self { this: self.this }
+    }
+}
+
+impl Drop for PipelineState {
+    fn drop(&mut self) {
+        // the address can be reused by the next PSO once it's destroyed
+        #[cfg_attr(not(feature = "validation"), allow(unused_variables))]
+        let live_ref_count = self.release();
+        #[cfg(feature = "validation")]
+        if live_ref_count == 0 {
+            validation::forget_pipeline_state(self);
+        }
+    }
+}
+impl_com_object_raw!(PipelineState, ID3D12PipelineState);
 
 unsafe impl Send for PipelineState {}
 
+impl PipelineState {
+    /// Driver-specific compiled PSO that can be passed back as
+    /// [CachedPipelineState] to [GraphicsPipelineStateDesc::set_cached_pso]
+    /// (or its compute counterpart) on the next run. It's only valid for
+    /// the same adapter and driver version, PSO creation fails otherwise
+    pub fn get_cached_blob(&self) -> DxResult<Blob> {
+        let mut hw_blob: *mut ID3DBlob = std::ptr::null_mut();
+        unsafe {
+            dx_try!(self.this, GetCachedBlob, &mut hw_blob);
+        }
+
+        Ok(Blob { this: hw_blob })
+    }
+}
+
+/// Wrapper around ID3D12PipelineLibrary1 interface. The runtime references
+/// the serialized data the library has been created from instead of
+/// copying it, so the wrapper keeps its own copy alive
+#[derive(Debug)]
+pub struct PipelineLibrary {
+    pub(crate) this: *mut ID3D12PipelineLibrary1,
+    data: Option<std::sync::Arc<[u8]>>,
+}
+impl_com_object_set_get_name!(PipelineLibrary);
+impl_com_object_refcount_named!(PipelineLibrary);
+impl_com_object_private_data!(PipelineLibrary);
+
+impl Clone for PipelineLibrary {
+    fn clone(&self) -> Self {
+        self.add_ref();
+        Self {
+            this: self.this,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl Drop for PipelineLibrary {
+    fn drop(&mut self) {
+        // the data must outlive the interface
+        self.release();
+    }
+}
+
+// the serialized data of a wrapped library has to be kept alive
+// by the caller
+impl_com_object_raw!(PipelineLibrary, ID3D12PipelineLibrary1, data: None);
+
+unsafe impl Send for PipelineLibrary {}
+
+impl PipelineLibrary {
+    /// Loads a library previously written by [PipelineLibrary::save_to_file].
+    /// A missing file or data that the current driver rejects (e.g. after
+    /// a driver update or on another adapter) is not an error, an empty
+    /// library is created instead
+    pub fn load_from_file(
+        device: &Device,
+        path: impl AsRef<std::path::Path>,
+    ) -> DxResult<Self> {
+        let path = path.as_ref();
+        let data = match std::fs::read(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                info!(
+                    "Pipeline library {} does not exist, creating an empty one",
+                    path.display()
+                );
+                return device.create_pipeline_library(&[]);
+            }
+            Err(err) => {
+                error!(
+                    "Cannot read pipeline library {}: {}",
+                    path.display(),
+                    err
+                );
+                return Err(DxError::new("std::fs::read", winerror::E_FAIL));
+            }
+        };
+
+        match device.create_pipeline_library(&data) {
+            Ok(library) => Ok(library),
+            Err(err) => {
+                warn!(
+                    "Pipeline library {} cannot be used and will be \
+                     recreated: {}",
+                    path.display(),
+                    err
+                );
+                device.create_pipeline_library(&[])
+            }
+        }
+    }
+
+    /// Writes [PipelineLibrary::serialize] output to the file
+    pub fn save_to_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> DxResult<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.serialize()?).map_err(|err| {
+            error!("Cannot write pipeline library {}: {}", path.display(), err);
+            DxError::new("std::fs::write", winerror::E_FAIL)
+        })
+    }
+
+    /// Fails with E_INVALIDARG if a pipeline with the same name has
+    /// already been stored
+    pub fn store_pipeline(
+        &self,
+        name: &str,
+        pipeline_state: &PipelineState,
+    ) -> DxResult<()> {
+        let name = widestring::U16CString::from_str(name).map_err(|_| {
+            DxError::new(
+                "PipelineLibrary::store_pipeline",
+                winerror::E_INVALIDARG,
+            )
+        })?;
+        unsafe {
+            dx_try!(
+                self.this,
+                StorePipeline,
+                name.as_ptr(),
+                pipeline_state.this
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fails with E_INVALIDARG if there is no pipeline with this name or
+    /// the description doesn't match the stored one
+    pub fn load_graphics_pipeline(
+        &self,
+        name: &str,
+        desc: &GraphicsPipelineStateDesc,
+    ) -> DxResult<PipelineState> {
+        let name = widestring::U16CString::from_str(name).map_err(|_| {
+            DxError::new(
+                "PipelineLibrary::load_graphics_pipeline",
+                winerror::E_INVALIDARG,
+            )
+        })?;
+        let mut hw_pipeline_state: *mut ID3D12PipelineState =
+            std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                LoadGraphicsPipeline,
+                name.as_ptr(),
+                &desc.0,
+                &IID_ID3D12PipelineState,
+                cast_to_ppv(&mut hw_pipeline_state)
+            );
+        }
+        let pipeline_state = PipelineState {
+            this: hw_pipeline_state,
+        };
+
+        #[cfg(feature = "validation")]
+        validation::record_graphics_pipeline_state(&pipeline_state, desc);
+
+        Ok(pipeline_state)
+    }
+
+    /// See [PipelineLibrary::load_graphics_pipeline]
+    pub fn load_compute_pipeline(
+        &self,
+        name: &str,
+        desc: &ComputePipelineStateDesc,
+    ) -> DxResult<PipelineState> {
+        let name = widestring::U16CString::from_str(name).map_err(|_| {
+            DxError::new(
+                "PipelineLibrary::load_compute_pipeline",
+                winerror::E_INVALIDARG,
+            )
+        })?;
+        let mut hw_pipeline_state: *mut ID3D12PipelineState =
+            std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                LoadComputePipeline,
+                name.as_ptr(),
+                &desc.0,
+                &IID_ID3D12PipelineState,
+                cast_to_ppv(&mut hw_pipeline_state)
+            );
+        }
+        let pipeline_state = PipelineState {
+            this: hw_pipeline_state,
+        };
+
+        #[cfg(feature = "validation")]
+        validation::forget_pipeline_state(&pipeline_state);
+
+        Ok(pipeline_state)
+    }
+
+    /// See [PipelineLibrary::load_graphics_pipeline]
+    pub fn load_pipeline(
+        &self,
+        name: &str,
+        desc: &PipelineStateStreamDesc,
+    ) -> DxResult<PipelineState> {
+        let name = widestring::U16CString::from_str(name).map_err(|_| {
+            DxError::new(
+                "PipelineLibrary::load_pipeline",
+                winerror::E_INVALIDARG,
+            )
+        })?;
+        let mut hw_pipeline_state: *mut ID3D12PipelineState =
+            std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                LoadPipeline,
+                name.as_ptr(),
+                &desc.0,
+                &IID_ID3D12PipelineState,
+                cast_to_ppv(&mut hw_pipeline_state)
+            );
+        }
+        let pipeline_state = PipelineState {
+            this: hw_pipeline_state,
+        };
+
+        #[cfg(feature = "validation")]
+        validation::forget_pipeline_state(&pipeline_state);
+
+        Ok(pipeline_state)
+    }
+
+    pub fn get_serialized_size(&self) -> ByteCount {
+        unsafe { ByteCount(dx_call!(self.this, GetSerializedSize,)) }
+    }
+
+    /// Serializes all the stored pipelines along with the ones the library
+    /// has been created from
+    pub fn serialize(&self) -> DxResult<Vec<u8>> {
+        let mut data = vec![0u8; self.get_serialized_size().into()];
+        unsafe {
+            dx_try!(
+                self.this,
+                Serialize,
+                data.as_mut_ptr() as *mut std::ffi::c_void,
+                data.len() as SIZE_T
+            );
+        }
+
+        Ok(data)
+    }
+}
+
 /// Wrapper around ID3DBlob interface
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Blob {
-    pub this: *mut ID3DBlob,
+    pub(crate) this: *mut ID3DBlob,
 }
 impl_com_object_refcount_unnamed!(Blob);
 impl_com_object_clone_drop!(Blob);
+impl_com_object_raw!(Blob, ID3DBlob);
 
 impl Blob {
+    /// Creates a blob holding a copy of `data`, e.g. root signature or
+    /// PSO cache data loaded from disk that has to be passed to an API
+    /// expecting ID3DBlob
+    pub fn from_bytes(data: &[u8]) -> DxResult<Self> {
+        let mut blob: *mut winapi::um::d3dcommon::ID3D10Blob =
+            std::ptr::null_mut();
+        unsafe {
+            let ret_code =
+                winapi::um::d3dcompiler::D3DCreateBlob(data.len(), &mut blob);
+            if !success!(ret_code) {
+                return Err(DxError::new("D3DCreateBlob", ret_code));
+            }
+
+            let blob = Blob {
+                this: blob as *mut ID3DBlob,
+            };
+            // empty blobs may have no buffer at all
+            if !data.is_empty() {
+                std::ptr::copy_nonoverlapping(
+                    data.as_ptr(),
+                    dx_call!(blob.this, GetBufferPointer,) as *mut u8,
+                    data.len(),
+                );
+            }
+
+            Ok(blob)
+        }
+    }
+
+    /// Copies the contents of the blob and releases it
+    pub fn into_vec(self) -> Vec<u8> {
+        self.get_buffer().to_vec()
+    }
+
     pub fn get_buffer(&self) -> &[u8] {
         unsafe {
             let buffer_pointer: *mut u8 =
                 dx_call!(self.this, GetBufferPointer,) as *mut u8;
             let buffer_size: ByteCount =
                 ByteCount(dx_call!(self.this, GetBufferSize,));
+            if buffer_pointer.is_null() || buffer_size.0 == 0 {
+                return &[];
+            }
             std::slice::from_raw_parts(buffer_pointer, buffer_size.0 as usize)
         }
     }
@@ -2712,33 +7570,115 @@ impl Blob {
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct QueryHeap {
-    pub this: *mut ID3D12QueryHeap,
+    pub(crate) this: *mut ID3D12QueryHeap,
 }
 impl_com_object_set_get_name!(QueryHeap);
 impl_com_object_refcount_named!(QueryHeap);
+impl_com_object_private_data!(QueryHeap);
 impl_com_object_clone_drop!(QueryHeap);
+impl_com_object_raw!(QueryHeap, ID3D12QueryHeap);
 
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Heap {
-    pub this: *mut ID3D12Heap,
+    pub(crate) this: *mut ID3D12Heap,
 }
 impl_com_object_set_get_name!(Heap);
 impl_com_object_refcount_named!(Heap);
+impl_com_object_private_data!(Heap);
 impl_com_object_clone_drop!(Heap);
+impl_com_object_raw!(Heap, ID3D12Heap);
+
+unsafe impl Send for Heap {}
+
+impl Heap {
+    pub fn get_desc(&self) -> HeapDesc {
+        unsafe {
+            let mut hw_desc: D3D12_HEAP_DESC = std::mem::zeroed();
+            dx_call!(self.this, GetDesc, &mut hw_desc);
+            HeapDesc(hw_desc)
+        }
+    }
+
+    /// See [Device::describe]
+    pub fn describe(&self) -> String {
+        let desc = self.get_desc();
+        format!(
+            "Heap {:p} {}: {} bytes, {:?} heap, flags {:?}",
+            self.this,
+            describe_name(self.get_name()),
+            desc.size_in_bytes().0,
+            desc.properties().heap_type(),
+            desc.flags()
+        )
+    }
+}
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct ProtectedResourceSession {
+    pub(crate) this: *mut ID3D12ProtectedResourceSession,
+}
+impl_com_object_set_get_name!(ProtectedResourceSession);
+impl_com_object_refcount_named!(ProtectedResourceSession);
+impl_com_object_private_data!(ProtectedResourceSession);
+impl_com_object_clone_drop!(ProtectedResourceSession);
+impl_com_object_raw!(ProtectedResourceSession, ID3D12ProtectedResourceSession);
+
+impl ProtectedResourceSession {
+    pub fn get_desc(&self) -> ProtectedResourceSessionDesc {
+        unsafe {
+            let mut hw_desc: D3D12_PROTECTED_RESOURCE_SESSION_DESC =
+                std::mem::zeroed();
+            dx_call!(self.this, GetDesc, &mut hw_desc);
+            ProtectedResourceSessionDesc(hw_desc)
+        }
+    }
+
+    /// Becomes [ProtectedSessionStatus::Invalid] once the session has
+    /// been invalidated (e.g. on a display configuration change), after
+    /// which the protected content has to be re-created
+    pub fn get_session_status(&self) -> ProtectedSessionStatus {
+        unsafe { std::mem::transmute(dx_call!(self.this, GetSessionStatus,)) }
+    }
+
+    /// The fence is signaled by the runtime with a new value each time
+    /// the session status changes, so it can be waited on instead of
+    /// polling [ProtectedResourceSession::get_session_status]
+    pub fn get_status_fence(&self) -> DxResult<Fence> {
+        let mut hw_fence: *mut ID3D12Fence = std::ptr::null_mut();
+        unsafe {
+            dx_try!(
+                self.this,
+                GetStatusFence,
+                &IID_ID3D12Fence,
+                cast_to_ppv(&mut hw_fence)
+            );
+        }
+
+        Ok(Fence { this: hw_fence })
+    }
+}
+
 pub struct PIXSupport {}
 
+#[cfg(feature = "pix")]
+static PIX_INITIALIZED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
 impl PIXSupport {
     pub fn init() {
         #[cfg(feature = "pix")]
         unsafe {
             raw_bindings::pix::pix_init_analysis();
+            PIX_INITIALIZED.store(true, std::sync::atomic::Ordering::Release);
         }
     }
 
     pub fn shutdown() {
         #[cfg(feature = "pix")]
         unsafe {
+            PIX_INITIALIZED.store(false, std::sync::atomic::Ordering::Release);
             raw_bindings::pix::pix_shutdown_analysis();
         }
     }
@@ -2757,8 +7697,8 @@ impl PIXSupport {
         }
     }
 
-    pub fn begin_event_cmd_list(
-        cmd_list: &CommandList,
+    pub fn begin_event_cmd_list<K>(
+        cmd_list: &CommandList<K>,
         marker: &str,
         color: u64,
     ) {
@@ -2776,7 +7716,7 @@ impl PIXSupport {
         }
     }
 
-    pub fn end_event_cmd_list(cmd_list: &CommandList) {
+    pub fn end_event_cmd_list<K>(cmd_list: &CommandList<K>) {
         #[cfg(feature = "pix")]
         unsafe {
             raw_bindings::pix::pix_end_event_cmd_list(
@@ -2803,6 +7743,28 @@ impl PIXSupport {
         }
     }
 
+    /// Reports a value which is displayed as a counter track
+    /// on PIX timing captures. Does nothing until [PIXSupport::init]
+    /// has been called, names with interior NULs are reported and skipped
+    #[cfg_attr(not(feature = "pix"), allow(unused_variables))]
+    pub fn report_counter(name: &str, value: f32) {
+        #[cfg(feature = "pix")]
+        unsafe {
+            if !PIX_INITIALIZED.load(std::sync::atomic::Ordering::Acquire) {
+                return;
+            }
+
+            let name = match widestring::U16CString::from_str(name) {
+                Ok(name) => name,
+                Err(_) => {
+                    error!("PIX counter name {:?} contains a NUL", name);
+                    return;
+                }
+            };
+            raw_bindings::pix::pix_report_counter(name.as_ptr(), value);
+        }
+    }
+
     pub fn end_event_cmd_queue(cmd_queue: &CommandQueue) {
         #[cfg(feature = "pix")]
         unsafe {
@@ -0,0 +1,37 @@
+use crate::PIXSupport;
+
+/// A named value describing the current state of a pool or an allocator
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    pub name: &'static str,
+    pub value: f64,
+}
+
+impl Metric {
+    pub fn new(name: &'static str, value: impl Into<f64>) -> Self {
+        Self {
+            name,
+            value: value.into(),
+        }
+    }
+}
+
+/// Implemented by the crate's pools and allocators so that their usage
+/// can be displayed in a HUD or on PIX timing captures
+pub trait Metrics {
+    fn metrics(&self) -> Vec<Metric>;
+
+    /// Emits every metric as a PIX counter named `<prefix>/<metric name>`.
+    /// Does nothing unless `pix` feature is enabled and [PIXSupport::init]
+    /// has been called
+    fn report_pix_counters(&self, prefix: &str) {
+        if cfg!(feature = "pix") {
+            for metric in self.metrics() {
+                PIXSupport::report_counter(
+                    &format!("{}/{}", prefix, metric.name),
+                    metric.value as f32,
+                );
+            }
+        }
+    }
+}
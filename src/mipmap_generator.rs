@@ -0,0 +1,316 @@
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    calc_subresource, BarrierBatcher, CbvSrvUavKind, CommandList,
+    ComputeCapable, ComputePipelineStateDesc, CopyCapable, DescriptorRing,
+    Device, DxError, DxResult, DxcCompiler, Format, PipelineState, Resource,
+    ResourceDimension, ResourceFlags, ResourceStates, RootSignature,
+    ShaderBytecode, ShaderComponentMapping, ShaderResourceViewDesc,
+    Tex2DArraySrv, Tex2DArrayUav, UnorderedAccessViewDesc,
+};
+
+const MIPMAP_SHADER: &str = r#"
+#define RS "RootConstants(num32BitConstants=3, b0), " \
+           "DescriptorTable(SRV(t0), UAV(u0)), " \
+           "StaticSampler(s0, filter=FILTER_MIN_MAG_MIP_LINEAR, " \
+           "addressU=TEXTURE_ADDRESS_CLAMP, addressV=TEXTURE_ADDRESS_CLAMP)"
+
+static const uint FLAG_SRGB = 1;
+
+struct Constants
+{
+    uint2 dest_size;
+    uint flags;
+};
+
+ConstantBuffer<Constants> constants: register(b0);
+Texture2DArray<float4> source: register(t0);
+RWTexture2DArray<float4> dest: register(u0);
+SamplerState linear_sampler: register(s0);
+
+float3 linear_to_srgb(float3 color)
+{
+    float3 low = color * 12.92;
+    float3 high = 1.055 * pow(color, 1. / 2.4) - 0.055;
+    return lerp(low, high, step(0.0031308, color));
+}
+
+float3 srgb_to_linear(float3 color)
+{
+    float3 low = color / 12.92;
+    float3 high = pow((color + 0.055) / 1.055, 2.4);
+    return lerp(low, high, step(0.04045, color));
+}
+
+float4 load_texel(float2 uv, uint slice)
+{
+    float4 color = source.SampleLevel(linear_sampler, float3(uv, slice), 0);
+    if (constants.flags & FLAG_SRGB)
+    {
+        color.rgb = srgb_to_linear(saturate(color.rgb));
+    }
+    return color;
+}
+
+[RootSignature(RS)]
+[numthreads(8, 8, 1)]
+void CS(uint3 id: SV_DispatchThreadID)
+{
+    if (any(id.xy >= constants.dest_size))
+    {
+        return;
+    }
+
+    // four bilinear taps cover the 2x2 source block, and with odd source
+    // sizes also the texels an exact box filter would take a part of
+    float2 texel_size = 1. / constants.dest_size;
+    float2 uv = (id.xy + 0.5) * texel_size;
+    float4 color = 0.25 * (
+        load_texel(uv + float2(-0.25, -0.25) * texel_size, id.z) +
+        load_texel(uv + float2(0.25, -0.25) * texel_size, id.z) +
+        load_texel(uv + float2(-0.25, 0.25) * texel_size, id.z) +
+        load_texel(uv + float2(0.25, 0.25) * texel_size, id.z));
+
+    if (constants.flags & FLAG_SRGB)
+    {
+        color.rgb = linear_to_srgb(saturate(color.rgb));
+    }
+
+    dest[id] = color;
+}
+"#;
+
+const THREAD_GROUP_SIZE: u32 = 8;
+
+const FLAG_SRGB: u32 = 1;
+
+/// Describes how [MipmapGenerator] reads and writes the texture
+#[derive(Debug, Clone, Copy)]
+pub struct MipmapDesc {
+    /// Format of the views the mips are read and written through. It must
+    /// support typed UAV stores, so sRGB textures have to be created
+    /// typeless and processed through their non-sRGB counterparts with
+    /// [MipmapDesc::srgb] set
+    pub view_format: Format,
+    /// Texels hold sRGB-encoded colors, which are converted to linear
+    /// space for filtering and back
+    pub srgb: bool,
+}
+
+impl MipmapDesc {
+    pub fn new(view_format: Format) -> Self {
+        Self {
+            view_format,
+            srgb: false,
+        }
+    }
+}
+
+/// Fills the mip chain of 2D textures (and texture arrays) from their
+/// most detailed mip using a compute shader, one dispatch per mip.
+/// Textures must have been created with
+/// [ResourceFlags::AllowUnorderedAccess]
+#[derive(Debug)]
+pub struct MipmapGenerator {
+    root_signature: RootSignature,
+    pipeline_state: PipelineState,
+}
+
+impl MipmapGenerator {
+    /// Compiles the embedded shader, so this is relatively slow
+    pub fn new(device: &Device, compiler: &DxcCompiler) -> DxResult<Self> {
+        let shader = compiler.compile_to_blob(
+            MIPMAP_SHADER,
+            "mipmaps.hlsl",
+            "CS",
+            "cs_6_0",
+            &[],
+            &[],
+        )?;
+        let bytecode = ShaderBytecode::new(shader.get_buffer());
+
+        let root_signature = device.create_root_signature(0, &bytecode)?;
+        let pipeline_state = device.create_compute_pipeline_state(
+            &ComputePipelineStateDesc::default()
+                .with_root_signature(&root_signature)
+                .with_cs_bytecode(&bytecode),
+        )?;
+        if let Some(name) = device.make_object_name("Mipmap generator PSO") {
+            pipeline_state.set_name(&name)?;
+        }
+
+        Ok(Self {
+            root_signature,
+            pipeline_state,
+        })
+    }
+
+    /// Records the generation of mips 1.. of `resource`, which is
+    /// transitioned from `state_before` and left in `state_after`.
+    /// The views are allocated from `descriptors`, whose heap is bound
+    /// to the command list along with the generator's root signature
+    /// and pipeline state, so the caller has to restore its own bindings
+    /// afterwards
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate<K: ComputeCapable + CopyCapable>(
+        &self,
+        device: &Device,
+        command_list: &CommandList<K>,
+        descriptors: &mut DescriptorRing<CbvSrvUavKind>,
+        resource: &Resource,
+        desc: &MipmapDesc,
+        state_before: ResourceStates,
+        state_after: ResourceStates,
+    ) -> DxResult<()> {
+        let mut barriers = BarrierBatcher::new();
+        barriers.tag(resource, state_before);
+        self.generate_batched(
+            device,
+            command_list,
+            descriptors,
+            &mut barriers,
+            resource,
+            desc,
+        )?;
+        barriers.require(resource, state_after)?;
+        barriers.flush(command_list);
+
+        Ok(())
+    }
+
+    /// Same as [MipmapGenerator::generate], but `resource` must be tagged
+    /// in `barriers`. Each source mip is transitioned to
+    /// NonPixelShaderResource right before it is read, and once all the
+    /// mips are written the whole resource is left (and re-tagged) in
+    /// NonPixelShaderResource state, with the transition of the last mip
+    /// still pending
+    pub fn generate_batched<K: ComputeCapable + CopyCapable>(
+        &self,
+        device: &Device,
+        command_list: &CommandList<K>,
+        descriptors: &mut DescriptorRing<CbvSrvUavKind>,
+        barriers: &mut BarrierBatcher,
+        resource: &Resource,
+        desc: &MipmapDesc,
+    ) -> DxResult<()> {
+        let resource_desc = resource.get_desc();
+        if !matches!(resource_desc.dimension(), ResourceDimension::Texture2D)
+            || !resource_desc
+                .flags()
+                .contains(ResourceFlags::AllowUnorderedAccess)
+        {
+            error!(
+                "Mipmaps can only be generated for 2D textures that allow \
+                 unordered access"
+            );
+            return Err(DxError::new(
+                "MipmapGenerator::generate",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let mip_levels = resource_desc.mip_levels() as u32;
+        if mip_levels < 2 {
+            return Ok(());
+        }
+        let array_size = resource_desc.array_size();
+        let flags = match desc.srgb {
+            true => FLAG_SRGB,
+            false => 0,
+        };
+
+        barriers.require(resource, ResourceStates::UnorderedAccess)?;
+
+        command_list
+            .set_descriptor_heaps(std::slice::from_ref(descriptors.heap()));
+        command_list.set_compute_root_signature(&self.root_signature);
+        command_list.set_pipeline_state(&self.pipeline_state);
+
+        for dest_mip in 1..mip_levels {
+            for array_slice in 0..array_size {
+                barriers.transition(
+                    resource,
+                    Some(calc_subresource(
+                        dest_mip - 1,
+                        array_slice,
+                        0,
+                        mip_levels,
+                        array_size,
+                    )),
+                    ResourceStates::UnorderedAccess,
+                    ResourceStates::NonPixelShaderResource,
+                );
+            }
+            barriers.flush(command_list);
+
+            // SRV and UAV form a single descriptor table
+            let (cpu_handle, gpu_handle) = descriptors.allocate(2)?;
+            device.create_shader_resource_view(
+                resource,
+                Some(
+                    &ShaderResourceViewDesc::default()
+                        .with_shader_4_component_mapping(
+                            ShaderComponentMapping::default(),
+                        )
+                        .with_format(desc.view_format)
+                        .new_texture_2d_array(
+                            &Tex2DArraySrv::default()
+                                .with_most_detailed_mip(dest_mip - 1)
+                                .with_mip_levels(1)
+                                .with_array_size(array_size),
+                        ),
+                ),
+                cpu_handle,
+            );
+            device.create_unordered_access_view(
+                resource,
+                None,
+                Some(
+                    &UnorderedAccessViewDesc::default()
+                        .with_format(desc.view_format)
+                        .new_texture_2d_array(
+                            &Tex2DArrayUav::default()
+                                .with_mip_slice(dest_mip)
+                                .with_array_size(array_size),
+                        ),
+                ),
+                cpu_handle.advance(1, descriptors.handle_size()),
+            );
+
+            let dest_width =
+                ((resource_desc.width() >> dest_mip) as u32).max(1);
+            let dest_height = (resource_desc.height() >> dest_mip).max(1);
+            command_list.set_compute_root_32bit_constants(
+                0,
+                &[dest_width, dest_height, flags],
+                0,
+            );
+            command_list.set_compute_root_descriptor_table(1, gpu_handle);
+            command_list.dispatch(
+                (dest_width + THREAD_GROUP_SIZE - 1) / THREAD_GROUP_SIZE,
+                (dest_height + THREAD_GROUP_SIZE - 1) / THREAD_GROUP_SIZE,
+                array_size,
+            );
+        }
+
+        // the last mip is the only one still in UnorderedAccess state
+        for array_slice in 0..array_size {
+            barriers.transition(
+                resource,
+                Some(calc_subresource(
+                    mip_levels - 1,
+                    array_slice,
+                    0,
+                    mip_levels,
+                    array_size,
+                )),
+                ResourceStates::UnorderedAccess,
+                ResourceStates::NonPixelShaderResource,
+            );
+        }
+        barriers.tag(resource, ResourceStates::NonPixelShaderResource);
+
+        Ok(())
+    }
+}
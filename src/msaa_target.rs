@@ -0,0 +1,273 @@
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    BarrierBatcher, ByteCount, ClearFlags, ClearValue, CommandList,
+    CpuDescriptorHandle, DepthStencilValue, DepthStencilViewDesc,
+    DescriptorHeap, DescriptorHeapDesc, DescriptorHeapType, Device, DirectKind,
+    DxError, DxResult, Feature, FeatureDataMultisampleQualityLevels, Format,
+    HeapFlags, HeapProperties, HeapType, MultisampleQualityLevelFlags,
+    Resource, ResourceDesc, ResourceDimension, ResourceFlags, ResourceStates,
+    SampleDesc, Tex2DmsDsv,
+};
+
+/// Parameters of an [MsaaTarget], [MsaaTargetDesc::new] fills in
+/// the defaults for the common 4x MSAA case
+#[derive(Debug, Clone, Copy)]
+pub struct MsaaTargetDesc {
+    pub width: u32,
+    pub height: u32,
+    pub color_format: Format,
+    pub depth_format: Format,
+    pub sample_count: u32,
+    pub sample_quality: u32,
+    /// Optimized clear values, clearing to other values works
+    /// but is slower on some hardware
+    pub clear_color: [f32; 4],
+    pub clear_depth: f32,
+}
+
+impl MsaaTargetDesc {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            color_format: Format::R8G8B8A8Unorm,
+            depth_format: Format::D32Float,
+            // 4x MSAA is guaranteed to be supported for all render target
+            // and depth formats starting with feature level 11_0
+            sample_count: 4,
+            sample_quality: 0,
+            clear_color: [0., 0., 0., 1.],
+            clear_depth: 1.,
+        }
+    }
+}
+
+/// Multisampled color and depth targets of the same size along with
+/// their RTV and DSV. The color target lives in RenderTarget state
+/// and is resolved into a single-sampled resource (e.g. a back buffer)
+/// with [MsaaTarget::resolve]
+#[derive(Debug)]
+pub struct MsaaTarget {
+    desc: MsaaTargetDesc,
+    color: Resource,
+    depth: Resource,
+    rtv_heap: DescriptorHeap,
+    dsv_heap: DescriptorHeap,
+}
+
+impl MsaaTarget {
+    pub fn new(device: &Device, desc: &MsaaTargetDesc) -> DxResult<Self> {
+        Self::validate_sample_desc(device, desc, desc.color_format)?;
+        Self::validate_sample_desc(device, desc, desc.depth_format)?;
+
+        let resource_desc = ResourceDesc::default()
+            .with_dimension(ResourceDimension::Texture2D)
+            // MSAA textures require 4 MB alignment, 0 picks it automatically
+            .with_alignment(ByteCount(0))
+            .with_width(desc.width.into())
+            .with_height(desc.height)
+            .with_sample_desc(
+                SampleDesc::default()
+                    .with_count(desc.sample_count)
+                    .with_quality(desc.sample_quality),
+            );
+
+        let color = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Default),
+            HeapFlags::None,
+            &resource_desc
+                .clone()
+                .with_format(desc.color_format)
+                .with_flags(ResourceFlags::AllowRenderTarget),
+            ResourceStates::RenderTarget,
+            Some(
+                &ClearValue::default()
+                    .with_format(desc.color_format)
+                    .with_color(desc.clear_color),
+            ),
+        )?;
+        if let Some(name) = device.make_object_name("MSAA color target") {
+            color.set_name(&name)?;
+        }
+
+        let depth = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Default),
+            HeapFlags::None,
+            &resource_desc
+                .with_format(desc.depth_format)
+                .with_flags(ResourceFlags::AllowDepthStencil),
+            ResourceStates::DepthWrite,
+            Some(
+                &ClearValue::default()
+                    .with_format(desc.depth_format)
+                    .with_depth_stencil(
+                        &DepthStencilValue::default()
+                            .with_depth(desc.clear_depth),
+                    ),
+            ),
+        )?;
+        if let Some(name) = device.make_object_name("MSAA depth target") {
+            depth.set_name(&name)?;
+        }
+
+        let rtv_heap = device.create_descriptor_heap(
+            &DescriptorHeapDesc::default()
+                .with_heap_type(DescriptorHeapType::Rtv)
+                .with_num_descriptors(1),
+        )?;
+        device.create_render_target_view(
+            &color,
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        let dsv_heap = device.create_descriptor_heap(
+            &DescriptorHeapDesc::default()
+                .with_heap_type(DescriptorHeapType::Dsv)
+                .with_num_descriptors(1),
+        )?;
+        device.create_depth_stencil_view(
+            &depth,
+            &DepthStencilViewDesc::default()
+                .with_format(desc.depth_format)
+                .new_texture_2d_ms(Tex2DmsDsv::default()),
+            dsv_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        Ok(Self {
+            desc: *desc,
+            color,
+            depth,
+            rtv_heap,
+            dsv_heap,
+        })
+    }
+
+    fn validate_sample_desc(
+        device: &Device,
+        desc: &MsaaTargetDesc,
+        format: Format,
+    ) -> DxResult<()> {
+        let mut quality_levels = FeatureDataMultisampleQualityLevels::new(
+            format,
+            desc.sample_count,
+            MultisampleQualityLevelFlags::None,
+        );
+        device.check_feature_support(
+            Feature::MultisampleQualityLevels,
+            &mut quality_levels,
+        )?;
+
+        if desc.sample_quality >= quality_levels.num_quality_levels() {
+            error!(
+                "Format {:?} does not support {}x MSAA with quality {} \
+                 (quality levels available: {})",
+                format,
+                desc.sample_count,
+                desc.sample_quality,
+                quality_levels.num_quality_levels()
+            );
+            return Err(DxError::new(
+                "MsaaTarget::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn desc(&self) -> &MsaaTargetDesc {
+        &self.desc
+    }
+
+    pub fn color(&self) -> &Resource {
+        &self.color
+    }
+
+    pub fn depth(&self) -> &Resource {
+        &self.depth
+    }
+
+    pub fn rtv(&self) -> CpuDescriptorHandle {
+        self.rtv_heap.get_cpu_descriptor_handle_for_heap_start()
+    }
+
+    pub fn dsv(&self) -> CpuDescriptorHandle {
+        self.dsv_heap.get_cpu_descriptor_handle_for_heap_start()
+    }
+
+    /// Clears both targets to their optimized clear values
+    pub fn clear(&self, command_list: &CommandList<DirectKind>) {
+        command_list.clear_render_target_view(
+            self.rtv(),
+            self.desc.clear_color,
+            None,
+        );
+        command_list.clear_depth_stencil_view(
+            self.dsv(),
+            ClearFlags::Depth,
+            self.desc.clear_depth,
+            0,
+            None,
+        );
+    }
+
+    /// Binds the targets as the only render target and the depth buffer
+    pub fn set_as_render_target(&self, command_list: &CommandList<DirectKind>) {
+        command_list.set_render_targets(&[self.rtv()], false, Some(self.dsv()));
+    }
+
+    /// Resolves the color target into the subresource 0 of `dest`, which
+    /// must have the same size and format and be in ResolveDest state.
+    /// Note that resolving is not supported for integer formats
+    pub fn resolve(
+        &self,
+        command_list: &CommandList<DirectKind>,
+        dest: &Resource,
+    ) {
+        let mut barriers = BarrierBatcher::new();
+        self.resolve_batched(command_list, dest, &mut barriers);
+        barriers.flush(command_list);
+    }
+
+    /// Same as [MsaaTarget::resolve], but the transitions go through
+    /// `barriers`: the ones needed for the resolve are flushed right before
+    /// it, and the transition of the color target back to RenderTarget
+    /// state is left pending, so consecutive resolves or a following
+    /// helper can coalesce it. `dest` is transitioned to ResolveDest state
+    /// if it is tagged in `barriers`
+    pub fn resolve_batched(
+        &self,
+        command_list: &CommandList<DirectKind>,
+        dest: &Resource,
+        barriers: &mut BarrierBatcher,
+    ) {
+        barriers.transition(
+            &self.color,
+            None,
+            ResourceStates::RenderTarget,
+            ResourceStates::ResolveSource,
+        );
+        if barriers.is_tagged(dest) {
+            barriers
+                .require(dest, ResourceStates::ResolveDest)
+                .expect("Resolve destination is not tagged");
+        }
+        barriers.flush(command_list);
+
+        command_list.resolve_subresource(
+            dest,
+            0,
+            &self.color,
+            0,
+            self.desc.color_format,
+        );
+        barriers.transition(
+            &self.color,
+            None,
+            ResourceStates::ResolveSource,
+            ResourceStates::RenderTarget,
+        );
+    }
+}
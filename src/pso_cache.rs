@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::hash::{Hash, Hasher};
+use std::os::raw::c_char;
+
+use log::{error, warn};
+
+use crate::{
+    ComputePipelineStateDesc, Device, DxResult, GraphicsPipelineStateDesc,
+    ID3D12RootSignature, PipelineLibrary, PipelineState, RootParameterType,
+    RootSignature, RootSignatureDesc, ShaderBytecode,
+    VersionedRootSignatureDesc, D3D12_INPUT_LAYOUT_DESC, D3D12_RASTERIZER_DESC,
+    D3D12_SHADER_BYTECODE, D3D12_STATIC_SAMPLER_DESC, D3D12_STREAM_OUTPUT_DESC,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// 64-bit FNV-1a hasher. Unlike `DefaultHasher`, its algorithm is fixed,
+/// so the digests can be stored on disk, e.g. as shader cache keys
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hashes the contents a description refers to (shader bytecode, input
+/// layout elements, root parameters etc.) instead of the pointers stored
+/// in it, which is what the derived Hash implementations do. Root
+/// signature objects referenced by PSO descriptions and cached PSO blobs
+/// are not hashed
+pub trait ContentHash {
+    fn content_hash<H: Hasher>(&self, state: &mut H);
+
+    /// Digest computed with [StableHasher]
+    fn content_digest(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        self.content_hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+fn hash_slice<T: Hash, H: Hasher>(data: *const T, len: usize, state: &mut H) {
+    if len == 0 {
+        0usize.hash(state);
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }.hash(state);
+    }
+}
+
+fn hash_semantic_name<H: Hasher>(name: *const c_char, state: &mut H) {
+    if !name.is_null() {
+        unsafe { CStr::from_ptr(name) }.to_bytes().hash(state);
+    }
+}
+
+fn hash_bytecode<H: Hasher>(bytecode: &D3D12_SHADER_BYTECODE, state: &mut H) {
+    hash_slice(
+        bytecode.pShaderBytecode as *const u8,
+        bytecode.BytecodeLength as usize,
+        state,
+    );
+}
+
+fn hash_input_layout<H: Hasher>(
+    input_layout: &D3D12_INPUT_LAYOUT_DESC,
+    state: &mut H,
+) {
+    input_layout.NumElements.hash(state);
+    for index in 0..input_layout.NumElements as usize {
+        let element = unsafe { &*input_layout.pInputElementDescs.add(index) };
+        hash_semantic_name(element.SemanticName, state);
+        element.SemanticIndex.hash(state);
+        element.Format.hash(state);
+        element.InputSlot.hash(state);
+        element.AlignedByteOffset.hash(state);
+        element.InputSlotClass.hash(state);
+        element.InstanceDataStepRate.hash(state);
+    }
+}
+
+fn hash_stream_output<H: Hasher>(
+    stream_output: &D3D12_STREAM_OUTPUT_DESC,
+    state: &mut H,
+) {
+    stream_output.NumEntries.hash(state);
+    for index in 0..stream_output.NumEntries as usize {
+        let entry = unsafe { &*stream_output.pSODeclaration.add(index) };
+        entry.Stream.hash(state);
+        hash_semantic_name(entry.SemanticName, state);
+        entry.SemanticIndex.hash(state);
+        entry.StartComponent.hash(state);
+        entry.ComponentCount.hash(state);
+        entry.OutputSlot.hash(state);
+    }
+    hash_slice(
+        stream_output.pBufferStrides,
+        stream_output.NumStrides as usize,
+        state,
+    );
+    stream_output.RasterizedStream.hash(state);
+}
+
+fn hash_rasterizer_state<H: Hasher>(
+    rasterizer_state: &D3D12_RASTERIZER_DESC,
+    state: &mut H,
+) {
+    rasterizer_state.FillMode.hash(state);
+    rasterizer_state.CullMode.hash(state);
+    rasterizer_state.FrontCounterClockwise.hash(state);
+    rasterizer_state.DepthBias.hash(state);
+    rasterizer_state.DepthBiasClamp.to_bits().hash(state);
+    rasterizer_state.SlopeScaledDepthBias.to_bits().hash(state);
+    rasterizer_state.DepthClipEnable.hash(state);
+    rasterizer_state.MultisampleEnable.hash(state);
+    rasterizer_state.AntialiasedLineEnable.hash(state);
+    rasterizer_state.ForcedSampleCount.hash(state);
+    rasterizer_state.ConservativeRaster.hash(state);
+}
+
+fn hash_static_sampler<H: Hasher>(
+    sampler: &D3D12_STATIC_SAMPLER_DESC,
+    state: &mut H,
+) {
+    sampler.Filter.hash(state);
+    sampler.AddressU.hash(state);
+    sampler.AddressV.hash(state);
+    sampler.AddressW.hash(state);
+    sampler.MipLODBias.to_bits().hash(state);
+    sampler.MaxAnisotropy.hash(state);
+    sampler.ComparisonFunc.hash(state);
+    sampler.BorderColor.hash(state);
+    sampler.MinLOD.to_bits().hash(state);
+    sampler.MaxLOD.to_bits().hash(state);
+    sampler.ShaderRegister.hash(state);
+    sampler.RegisterSpace.hash(state);
+    sampler.ShaderVisibility.hash(state);
+}
+
+impl<'a> ContentHash for ShaderBytecode<'a> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        hash_bytecode(&self.0, state);
+    }
+}
+
+impl<'rs, 'sh, 'so, 'il> ContentHash
+    for GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il>
+{
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        let desc = &self.0;
+        for bytecode in [&desc.VS, &desc.PS, &desc.DS, &desc.HS, &desc.GS] {
+            hash_bytecode(bytecode, state);
+        }
+        hash_stream_output(&desc.StreamOutput, state);
+        desc.BlendState.hash(state);
+        desc.SampleMask.hash(state);
+        hash_rasterizer_state(&desc.RasterizerState, state);
+        desc.DepthStencilState.hash(state);
+        hash_input_layout(&desc.InputLayout, state);
+        desc.IBStripCutValue.hash(state);
+        desc.PrimitiveTopologyType.hash(state);
+        desc.NumRenderTargets.hash(state);
+        desc.RTVFormats.hash(state);
+        desc.DSVFormat.hash(state);
+        desc.SampleDesc.hash(state);
+        desc.NodeMask.hash(state);
+        desc.Flags.hash(state);
+    }
+}
+
+impl<'rs, 'sh> ContentHash for ComputePipelineStateDesc<'rs, 'sh> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        hash_bytecode(&self.0.CS, state);
+        self.0.NodeMask.hash(state);
+        self.0.Flags.hash(state);
+    }
+}
+
+impl<'a, 'b> ContentHash for RootSignatureDesc<'a, 'b> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        let desc = &self.0;
+        desc.NumParameters.hash(state);
+        for index in 0..desc.NumParameters as usize {
+            let parameter = unsafe { &*desc.pParameters.add(index) };
+            parameter.ParameterType.hash(state);
+            parameter.ShaderVisibility.hash(state);
+            let parameter_type: RootParameterType =
+                unsafe { std::mem::transmute(parameter.ParameterType) };
+            match parameter_type {
+                RootParameterType::DescriptorTable => {
+                    let table =
+                        unsafe { &parameter.__bindgen_anon_1.DescriptorTable };
+                    hash_slice(
+                        table.pDescriptorRanges,
+                        table.NumDescriptorRanges as usize,
+                        state,
+                    );
+                }
+                RootParameterType::T32BitConstants => {
+                    unsafe { parameter.__bindgen_anon_1.Constants }.hash(state)
+                }
+                RootParameterType::Cbv
+                | RootParameterType::Srv
+                | RootParameterType::Uav => {
+                    unsafe { parameter.__bindgen_anon_1.Descriptor }.hash(state)
+                }
+            }
+        }
+
+        desc.NumStaticSamplers.hash(state);
+        for index in 0..desc.NumStaticSamplers as usize {
+            hash_static_sampler(
+                unsafe { &*desc.pStaticSamplers.add(index) },
+                state,
+            );
+        }
+        desc.Flags.hash(state);
+    }
+}
+
+/// Returns existing root signatures and PSOs for repeated requests with
+/// the same contents, keyed by [ContentHash::content_digest]. PSOs are
+/// additionally keyed by the root signature object they are created
+/// with, so using root signatures from the same cache makes identical
+/// descriptions share PSOs. Note that 64-bit digests are trusted, i.e.
+/// the descriptions themselves are not compared.
+///
+/// With a [PipelineLibrary] attached, PSOs missing from the cache are
+/// loaded from the library by their digest before falling back to
+/// compilation, and newly compiled ones are stored in it, so saving
+/// the library on exit makes the next run skip driver compilation
+#[derive(Debug, Default)]
+pub struct PsoCache {
+    library: Option<PipelineLibrary>,
+    root_signatures: HashMap<u64, RootSignature>,
+    // the root signature is kept alive so that its address cannot be
+    // reused by another one while it's a part of the key
+    pipeline_states:
+        HashMap<(usize, u64), (Option<RootSignature>, PipelineState)>,
+}
+
+impl PsoCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_pipeline_library(mut self, library: PipelineLibrary) -> Self {
+        self.library = Some(library);
+        self
+    }
+
+    /// E.g. to call [PipelineLibrary::save_to_file] on exit
+    pub fn pipeline_library(&self) -> Option<&PipelineLibrary> {
+        self.library.as_ref()
+    }
+
+    pub fn get_or_create_root_signature(
+        &mut self,
+        device: &Device,
+        desc: &RootSignatureDesc,
+    ) -> DxResult<RootSignature> {
+        let digest = desc.content_digest();
+        if let Some(root_signature) = self.root_signatures.get(&digest) {
+            return Ok(root_signature.clone());
+        }
+
+        let (blob, serialization_result) = RootSignature::serialize_versioned(
+            &VersionedRootSignatureDesc::default().with_desc_1_1(desc),
+        );
+        if let Err(err) = serialization_result {
+            error!(
+                "Cannot serialize root signature: {}",
+                String::from_utf8_lossy(blob.get_buffer())
+            );
+            return Err(err);
+        }
+
+        let root_signature = device.create_root_signature(
+            0,
+            &ShaderBytecode::new(blob.get_buffer()),
+        )?;
+        self.root_signatures.insert(digest, root_signature.clone());
+
+        Ok(root_signature)
+    }
+
+    pub fn get_or_create_graphics_pipeline_state(
+        &mut self,
+        device: &Device,
+        desc: &GraphicsPipelineStateDesc,
+    ) -> DxResult<PipelineState> {
+        // different seeds keep graphics and compute digests apart
+        let mut hasher = StableHasher::default();
+        0u8.hash(&mut hasher);
+        desc.content_hash(&mut hasher);
+
+        self.get_or_create(
+            desc.0.pRootSignature,
+            hasher.finish(),
+            || {
+                Self::own_root_signature(desc.0.pRootSignature, || {
+                    desc.root_signature()
+                })
+            },
+            |library, name| library.load_graphics_pipeline(name, desc),
+            || device.create_graphics_pipeline_state(desc),
+        )
+    }
+
+    pub fn get_or_create_compute_pipeline_state(
+        &mut self,
+        device: &Device,
+        desc: &ComputePipelineStateDesc,
+    ) -> DxResult<PipelineState> {
+        let mut hasher = StableHasher::default();
+        1u8.hash(&mut hasher);
+        desc.content_hash(&mut hasher);
+
+        self.get_or_create(
+            desc.0.pRootSignature,
+            hasher.finish(),
+            || {
+                Self::own_root_signature(desc.0.pRootSignature, || {
+                    desc.root_signature()
+                })
+            },
+            |library, name| library.load_compute_pipeline(name, desc),
+            || device.create_compute_pipeline_state(desc),
+        )
+    }
+
+    /// Root signature can be null if it's embedded into the shaders
+    fn own_root_signature(
+        root_signature: *mut ID3D12RootSignature,
+        get: impl FnOnce() -> RootSignature,
+    ) -> Option<RootSignature> {
+        match root_signature.is_null() {
+            true => None,
+            false => Some(get()),
+        }
+    }
+
+    fn get_or_create(
+        &mut self,
+        root_signature: *mut ID3D12RootSignature,
+        digest: u64,
+        own_root_signature: impl FnOnce() -> Option<RootSignature>,
+        load: impl FnOnce(&PipelineLibrary, &str) -> DxResult<PipelineState>,
+        create: impl FnOnce() -> DxResult<PipelineState>,
+    ) -> DxResult<PipelineState> {
+        let key = (root_signature as usize, digest);
+        if let Some((_, pipeline_state)) = self.pipeline_states.get(&key) {
+            return Ok(pipeline_state.clone());
+        }
+
+        let pipeline_state = match self.library.as_ref() {
+            Some(library) => {
+                // a failed load means that the pipeline is not in the library
+                // or it has been stored with a different root signature
+                let name = format!("{:016x}", digest);
+                match load(library, &name) {
+                    Ok(pipeline_state) => pipeline_state,
+                    Err(_) => {
+                        let pipeline_state = create()?;
+                        if let Err(err) =
+                            library.store_pipeline(&name, &pipeline_state)
+                        {
+                            warn!(
+                                "Cannot store pipeline {} in the library: {}",
+                                name, err
+                            );
+                        }
+                        pipeline_state
+                    }
+                }
+            }
+            None => create()?,
+        };
+        self.pipeline_states
+            .insert(key, (own_root_signature(), pipeline_state.clone()));
+
+        Ok(pipeline_state)
+    }
+
+    pub fn root_signature_count(&self) -> usize {
+        self.root_signatures.len()
+    }
+
+    pub fn pipeline_state_count(&self) -> usize {
+        self.pipeline_states.len()
+    }
+
+    /// Releases the cache's references to all the objects except
+    /// the pipeline library
+    pub fn clear(&mut self) {
+        self.root_signatures.clear();
+        self.pipeline_states.clear();
+    }
+}
@@ -0,0 +1,298 @@
+use std::mem::size_of;
+use std::time::Duration;
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    ByteCount, CommandList, CommandQueue, ComputeCapable, CopyCapable, Device,
+    DxError, DxResult, PipelineStatistics, QueryHeap, QueryHeapDesc,
+    QueryHeapType, QueryType, ReadbackRing,
+};
+
+/// A query heap split into per-frame regions of `capacity` queries each,
+/// resolved into a [ReadbackRing] with the same frame layout
+#[derive(Debug)]
+struct QueryPool {
+    heap: QueryHeap,
+    readback: ReadbackRing,
+    query_type: QueryType,
+    capacity: u32,
+    // (frame index, number of queries used) per frame region
+    frames: Vec<(u64, u32)>,
+    current_frame: usize,
+}
+
+impl QueryPool {
+    fn new(
+        device: &Device,
+        heap_type: QueryHeapType,
+        query_type: QueryType,
+        result_size: usize,
+        capacity: u32,
+        frame_count: u32,
+        name: &str,
+    ) -> DxResult<Self> {
+        if capacity == 0 || frame_count == 0 {
+            return Err(DxError::new("QueryPool::new", winerror::E_INVALIDARG));
+        }
+
+        let heap = device.create_query_heap(
+            &QueryHeapDesc::default()
+                .with_heap_type(heap_type)
+                .with_count(capacity * frame_count),
+        )?;
+        if let Some(name) = device.make_object_name(name) {
+            heap.set_name(&name)?;
+        }
+
+        Ok(Self {
+            heap,
+            readback: ReadbackRing::new(
+                device,
+                ByteCount((result_size * capacity as usize) as u64),
+                frame_count,
+            )?,
+            query_type,
+            capacity,
+            frames: vec![(0, 0); frame_count as usize],
+            current_frame: 0,
+        })
+    }
+
+    fn begin_frame(&mut self, frame_index: u64) {
+        self.current_frame = (frame_index % self.frames.len() as u64) as usize;
+        self.frames[self.current_frame] = (frame_index, 0);
+    }
+
+    /// Returns the index of the query in the heap
+    fn allocate(&mut self, func_name: &'static str) -> DxResult<u32> {
+        let used = &mut self.frames[self.current_frame].1;
+        if *used == self.capacity {
+            error!(
+                "{}: all {} queries of the frame are used",
+                func_name, self.capacity
+            );
+            return Err(DxError::new(func_name, winerror::E_OUTOFMEMORY));
+        }
+        *used += 1;
+        let index_in_frame = *used - 1;
+
+        Ok(self.heap_index(index_in_frame))
+    }
+
+    fn heap_index(&self, index_in_frame: u32) -> u32 {
+        self.current_frame as u32 * self.capacity + index_in_frame
+    }
+
+    fn resolve<K: CopyCapable>(&self, command_list: &CommandList<K>) {
+        let (frame_index, used) = self.frames[self.current_frame];
+        if used == 0 {
+            return;
+        }
+
+        self.readback.resolve_query_data(
+            command_list,
+            frame_index,
+            ByteCount(0),
+            &self.heap,
+            self.query_type,
+            self.heap_index(0),
+            used,
+        );
+    }
+
+    fn results<T: Copy>(&self, frame_index: u64) -> &[T] {
+        let (slot_frame_index, used) =
+            self.frames[(frame_index % self.frames.len() as u64) as usize];
+        if slot_frame_index != frame_index {
+            error!(
+                "Query results of frame {} have been overwritten by frame {}",
+                frame_index, slot_frame_index
+            );
+            return &[];
+        }
+
+        // only used with u64 timestamps and PipelineStatistics, which
+        // are plain integers
+        unsafe { &self.readback.consume_as::<T>(frame_index)[..used as usize] }
+    }
+}
+
+/// Timestamp queries for direct and compute queues, read back as
+/// [Duration]s. The usual frame looks like
+/// `begin_frame` -> `write_timestamp` (any number of times, up to the pool
+/// capacity) -> `resolve` at the end of the last command list, and after
+/// the GPU has finished the frame (e.g. a fence has been reached) its
+/// results can be read with `timestamps` or `elapsed`
+#[derive(Debug)]
+pub struct TimestampQueryPool {
+    pool: QueryPool,
+    frequency: u64,
+}
+
+impl TimestampQueryPool {
+    /// `capacity` is the number of timestamps per frame, and the results of
+    /// the last `frame_count` frames are available for reading. `queue` is
+    /// the queue the command lists are executed on, it provides the tick
+    /// frequency
+    pub fn new(
+        device: &Device,
+        queue: &CommandQueue,
+        capacity: u32,
+        frame_count: u32,
+    ) -> DxResult<Self> {
+        Ok(Self {
+            pool: QueryPool::new(
+                device,
+                QueryHeapType::Timestamp,
+                QueryType::Timestamp,
+                size_of::<u64>(),
+                capacity,
+                frame_count,
+                "Timestamp query heap",
+            )?,
+            frequency: queue.get_timestamp_frequency()?,
+        })
+    }
+
+    /// `frame_index` can be an ever-increasing frame counter, it's wrapped
+    /// around internally. Previous results of the frame region are lost
+    pub fn begin_frame(&mut self, frame_index: u64) {
+        self.pool.begin_frame(frame_index);
+    }
+
+    /// Returns the index of the timestamp within the current frame
+    pub fn write_timestamp<K: CopyCapable>(
+        &mut self,
+        command_list: &CommandList<K>,
+    ) -> DxResult<u32> {
+        let heap_index =
+            self.pool.allocate("TimestampQueryPool::write_timestamp")?;
+        command_list.end_query(
+            &self.pool.heap,
+            QueryType::Timestamp,
+            heap_index,
+        );
+
+        Ok(heap_index - self.pool.heap_index(0))
+    }
+
+    /// Records resolving of all the timestamps written during
+    /// the current frame
+    pub fn resolve<K: CopyCapable>(&self, command_list: &CommandList<K>) {
+        self.pool.resolve(command_list);
+    }
+
+    /// Raw timestamps of `frame_index` in ticks. The caller must make sure
+    /// the GPU has finished that frame
+    pub fn timestamps(&self, frame_index: u64) -> &[u64] {
+        self.pool.results(frame_index)
+    }
+
+    /// Time between two timestamps of `frame_index`, None if any of
+    /// the indices is out of range
+    pub fn elapsed(
+        &self,
+        frame_index: u64,
+        start: u32,
+        end: u32,
+    ) -> Option<Duration> {
+        let timestamps = self.timestamps(frame_index);
+        let start = *timestamps.get(start as usize)?;
+        let end = *timestamps.get(end as usize)?;
+
+        Some(self.ticks_to_duration(end.saturating_sub(start)))
+    }
+
+    pub fn ticks_to_duration(&self, ticks: u64) -> Duration {
+        Duration::from_nanos(
+            (ticks as u128 * 1_000_000_000 / self.frequency as u128) as u64,
+        )
+    }
+
+    /// Ticks per second
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    pub fn query_heap(&self) -> &QueryHeap {
+        &self.pool.heap
+    }
+}
+
+/// Pipeline statistics queries read back as [PipelineStatistics]. Used
+/// the same way as [TimestampQueryPool], but every query is a begin/end
+/// pair around the measured commands
+#[derive(Debug)]
+pub struct PipelineStatisticsQueryPool {
+    pool: QueryPool,
+}
+
+impl PipelineStatisticsQueryPool {
+    pub fn new(
+        device: &Device,
+        capacity: u32,
+        frame_count: u32,
+    ) -> DxResult<Self> {
+        Ok(Self {
+            pool: QueryPool::new(
+                device,
+                QueryHeapType::PipelineStatistics,
+                QueryType::PipelineStatistics,
+                size_of::<PipelineStatistics>(),
+                capacity,
+                frame_count,
+                "Pipeline statistics query heap",
+            )?,
+        })
+    }
+
+    pub fn begin_frame(&mut self, frame_index: u64) {
+        self.pool.begin_frame(frame_index);
+    }
+
+    /// Returns the index of the query within the current frame, which is
+    /// then passed to [PipelineStatisticsQueryPool::end]. Copy command lists
+    /// and bundles cannot collect pipeline statistics
+    pub fn begin<K: CopyCapable + ComputeCapable>(
+        &mut self,
+        command_list: &CommandList<K>,
+    ) -> DxResult<u32> {
+        let heap_index =
+            self.pool.allocate("PipelineStatisticsQueryPool::begin")?;
+        command_list.begin_query(
+            &self.pool.heap,
+            QueryType::PipelineStatistics,
+            heap_index,
+        );
+
+        Ok(heap_index - self.pool.heap_index(0))
+    }
+
+    pub fn end<K: CopyCapable + ComputeCapable>(
+        &self,
+        command_list: &CommandList<K>,
+        index: u32,
+    ) {
+        command_list.end_query(
+            &self.pool.heap,
+            QueryType::PipelineStatistics,
+            self.pool.heap_index(index),
+        );
+    }
+
+    pub fn resolve<K: CopyCapable>(&self, command_list: &CommandList<K>) {
+        self.pool.resolve(command_list);
+    }
+
+    /// Statistics of `frame_index` in the order of the queries. The caller
+    /// must make sure the GPU has finished that frame
+    pub fn statistics(&self, frame_index: u64) -> &[PipelineStatistics] {
+        self.pool.results(frame_index)
+    }
+
+    pub fn query_heap(&self) -> &QueryHeap {
+        &self.pool.heap
+    }
+}
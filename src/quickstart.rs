@@ -0,0 +1,207 @@
+//! Process-wide device and queues for quick experiments and tests.
+//!
+//! ```no_run
+//! use rusty_d3d12::{quickstart, CommandListType};
+//!
+//! quickstart::init().expect("Cannot initialize D3D12");
+//! let command_allocator = quickstart::device()
+//!     .create_command_allocator(CommandListType::Direct)
+//!     .expect("Cannot create command allocator");
+//! ```
+//!
+//! The objects live until the end of the process. Applications that
+//! care about the lifetime of their device (or need more than one)
+//! should create and pass it explicitly instead
+
+use std::sync::{Mutex, MutexGuard};
+
+use log::{error, info, warn};
+use once_cell::sync::OnceCell;
+
+use crate::{
+    Adapter, CommandListType, CommandQueue, CommandQueueDesc,
+    CreateFactoryFlags, Debug, Device, DxError, DxResult, Factory, InfoQueue,
+};
+
+/// Settings used by [init_with]
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Enables the debug layer and creates an info queue
+    pub debug_layer: bool,
+    /// Runs on the WARP software adapter instead of the first hardware one,
+    /// e.g. for tests on CI machines without GPUs
+    pub use_warp: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            debug_layer: cfg!(debug_assertions),
+            use_warp: false,
+        }
+    }
+}
+
+struct Context {
+    factory: Factory,
+    adapter: Adapter,
+    device: Device,
+    graphics_queue: CommandQueue,
+    compute_queue: CommandQueue,
+    copy_queue: CommandQueue,
+    info_queue: Option<InfoQueue>,
+    // kept alive for the lifetime of the device
+    _debug_layer: Option<Debug>,
+}
+
+// D3D12 devices, queues and DXGI factories can be released on any thread.
+// The wrappers are not Sync, so the context is only accessed under the lock
+// and the accessors hand out new references
+unsafe impl Send for Context {}
+
+static CONTEXT: OnceCell<Mutex<Context>> = OnceCell::new();
+
+impl Context {
+    fn new(config: &Config) -> DxResult<Self> {
+        let debug_layer = match config.debug_layer {
+            true => {
+                let debug_layer = Debug::new()?;
+                debug_layer.enable_debug_layer();
+                Some(debug_layer)
+            }
+            false => None,
+        };
+
+        let factory = Factory::new(match config.debug_layer {
+            true => CreateFactoryFlags::Debug,
+            false => CreateFactoryFlags::None,
+        })?;
+        let adapter = match config.use_warp {
+            true => factory.enum_warp_adapter()?,
+            false => match factory.enum_adapters()?.into_iter().next() {
+                Some(adapter) => adapter,
+                None => {
+                    error!("No adapters found");
+                    return Err(DxError::new(
+                        "Factory::enum_adapters",
+                        winapi::shared::winerror::DXGI_ERROR_NOT_FOUND,
+                    ));
+                }
+            },
+        };
+        info!(
+            "Quickstart device runs on {}",
+            adapter.get_desc()?.description().unwrap_or_default()
+        );
+
+        let device = Device::new(&adapter)?;
+        let info_queue = match config.debug_layer {
+            true => Some(InfoQueue::new(&device, None)?),
+            false => None,
+        };
+
+        let create_queue = |queue_type| {
+            device.create_command_queue(
+                &CommandQueueDesc::default().with_queue_type(queue_type),
+            )
+        };
+
+        Ok(Self {
+            graphics_queue: create_queue(CommandListType::Direct)?,
+            compute_queue: create_queue(CommandListType::Compute)?,
+            copy_queue: create_queue(CommandListType::Copy)?,
+            factory,
+            adapter,
+            device,
+            info_queue,
+            _debug_layer: debug_layer,
+        })
+    }
+}
+
+/// Same as [init_with] with the default [Config]
+pub fn init() -> DxResult<()> {
+    init_with(&Config::default())
+}
+
+/// Creates the factory, the device and a queue of each type. Calling it
+/// again (e.g. from every test) does nothing, the config of the first
+/// successful call wins
+pub fn init_with(config: &Config) -> DxResult<()> {
+    let mut created = false;
+    CONTEXT.get_or_try_init(|| {
+        created = true;
+        Context::new(config).map(Mutex::new)
+    })?;
+
+    if !created {
+        warn!("Quickstart context is already initialized, config is ignored");
+    }
+
+    Ok(())
+}
+
+pub fn is_initialized() -> bool {
+    CONTEXT.get().is_some()
+}
+
+fn context() -> MutexGuard<'static, Context> {
+    CONTEXT
+        .get()
+        .expect("quickstart::init has not been called")
+        .lock()
+        .expect("Quickstart context mutex is poisoned")
+}
+
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn factory() -> Factory {
+    context().factory.clone()
+}
+
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn adapter() -> Adapter {
+    context().adapter.clone()
+}
+
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn device() -> Device {
+    context().device.clone()
+}
+
+/// Direct queue
+///
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn graphics_queue() -> CommandQueue {
+    context().graphics_queue.clone()
+}
+
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn compute_queue() -> CommandQueue {
+    context().compute_queue.clone()
+}
+
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn copy_queue() -> CommandQueue {
+    context().copy_queue.clone()
+}
+
+/// None if the debug layer is disabled
+///
+/// # Panics
+///
+/// Panics if [init] has not been called
+pub fn info_queue() -> Option<InfoQueue> {
+    context().info_queue.clone()
+}
@@ -3529,6 +3529,7 @@ pub const D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_11_0: D3D_FEATURE_LEVEL = 45056;
 pub const D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_11_1: D3D_FEATURE_LEVEL = 45312;
 pub const D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_0: D3D_FEATURE_LEVEL = 49152;
 pub const D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_1: D3D_FEATURE_LEVEL = 49408;
+pub const D3D_FEATURE_LEVEL_D3D_FEATURE_LEVEL_12_2: D3D_FEATURE_LEVEL = 49664;
 pub type D3D_FEATURE_LEVEL = ::std::os::raw::c_int;
 pub const D3D_PRIMITIVE_TOPOLOGY_D3D_PRIMITIVE_TOPOLOGY_UNDEFINED:
     D3D_PRIMITIVE_TOPOLOGY = 0;
@@ -0,0 +1,223 @@
+// Hand-written bindings for the subset of dxcapi.h used by dxc module
+// (interfaces are declared up to the last used method of their vtables)
+
+use super::d3d12::{
+    IUnknown, BOOL, GUID, HRESULT, IID, LPCSTR, LPCVOID, LPCWSTR, LPVOID,
+    SIZE_T, UINT32, ULONG,
+};
+
+pub type DXC_OUT_KIND = ::std::os::raw::c_int;
+pub const DXC_OUT_KIND_DXC_OUT_NONE: DXC_OUT_KIND = 0;
+pub const DXC_OUT_KIND_DXC_OUT_OBJECT: DXC_OUT_KIND = 1;
+pub const DXC_OUT_KIND_DXC_OUT_ERRORS: DXC_OUT_KIND = 2;
+pub const DXC_OUT_KIND_DXC_OUT_PDB: DXC_OUT_KIND = 3;
+pub const DXC_OUT_KIND_DXC_OUT_SHADER_HASH: DXC_OUT_KIND = 4;
+pub const DXC_OUT_KIND_DXC_OUT_DISASSEMBLY: DXC_OUT_KIND = 5;
+pub const DXC_OUT_KIND_DXC_OUT_HLSL: DXC_OUT_KIND = 6;
+pub const DXC_OUT_KIND_DXC_OUT_TEXT: DXC_OUT_KIND = 7;
+pub const DXC_OUT_KIND_DXC_OUT_REFLECTION: DXC_OUT_KIND = 8;
+pub const DXC_OUT_KIND_DXC_OUT_ROOT_SIGNATURE: DXC_OUT_KIND = 9;
+pub const DXC_OUT_KIND_DXC_OUT_EXTRA_OUTPUTS: DXC_OUT_KIND = 10;
+pub const DXC_OUT_KIND_DXC_OUT_REMARKS: DXC_OUT_KIND = 11;
+pub const DXC_OUT_KIND_DXC_OUT_TIME_REPORT: DXC_OUT_KIND = 12;
+
+pub const DXC_CP_ACP: UINT32 = 0;
+pub const DXC_CP_UTF8: UINT32 = 65001;
+
+pub const CLSID_DxcCompiler: GUID = GUID {
+    Data1: 0x73e22d93,
+    Data2: 0xe6ce,
+    Data3: 0x47f3,
+    Data4: [0xb5, 0xbf, 0xf0, 0x66, 0x4f, 0x39, 0xc1, 0xb0],
+};
+
+pub const CLSID_DxcUtils: GUID = GUID {
+    Data1: 0x6245d6af,
+    Data2: 0x66e0,
+    Data3: 0x48fd,
+    Data4: [0x80, 0xb4, 0x4d, 0x27, 0x17, 0x96, 0x74, 0x8c],
+};
+
+pub const IID_IDxcBlob: IID = GUID {
+    Data1: 0x8ba5fb08,
+    Data2: 0x5195,
+    Data3: 0x40e2,
+    Data4: [0xac, 0x58, 0x0d, 0x98, 0x9c, 0x3a, 0x01, 0x02],
+};
+
+pub const IID_IDxcBlobUtf8: IID = GUID {
+    Data1: 0x3da636c9,
+    Data2: 0xba71,
+    Data3: 0x4024,
+    Data4: [0xa3, 0x01, 0x30, 0xcb, 0xf1, 0x25, 0x30, 0x5b],
+};
+
+pub const IID_IDxcResult: IID = GUID {
+    Data1: 0x58346cda,
+    Data2: 0xdde7,
+    Data3: 0x4497,
+    Data4: [0x94, 0x61, 0x6f, 0x87, 0xaf, 0x5e, 0x06, 0x59],
+};
+
+pub const IID_IDxcCompiler3: IID = GUID {
+    Data1: 0x228b4687,
+    Data2: 0x5a6a,
+    Data3: 0x4730,
+    Data4: [0x90, 0x0c, 0x97, 0x02, 0xb2, 0x20, 0x3f, 0x54],
+};
+
+pub const IID_IDxcUtils: IID = GUID {
+    Data1: 0x4605c4cb,
+    Data2: 0x2019,
+    Data3: 0x492a,
+    Data4: [0xad, 0xa4, 0x65, 0xf2, 0x0b, 0xb7, 0xd6, 0x7f],
+};
+
+pub type DxcCreateInstanceProc = unsafe extern "C" fn(
+    rclsid: *const GUID,
+    riid: *const IID,
+    ppv: *mut LPVOID,
+) -> HRESULT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DxcBuffer {
+    pub Ptr: LPCVOID,
+    pub Size: SIZE_T,
+    pub Encoding: UINT32,
+}
+
+macro_rules! declare_interface {
+    ($interface:ident, $vtbl:ident { $($method:ident: fn($($arg:ident: $arg_type:ty),*) -> $ret:ty,)* }) => {
+        #[repr(C)]
+        #[derive(Debug, Copy, Clone)]
+        pub struct $vtbl {
+            pub QueryInterface: ::std::option::Option<
+                unsafe extern "C" fn(
+                    This: *mut $interface,
+                    riid: *const IID,
+                    ppvObject: *mut *mut ::std::os::raw::c_void,
+                ) -> HRESULT,
+            >,
+            pub AddRef: ::std::option::Option<
+                unsafe extern "C" fn(This: *mut $interface) -> ULONG,
+            >,
+            pub Release: ::std::option::Option<
+                unsafe extern "C" fn(This: *mut $interface) -> ULONG,
+            >,
+            $(
+                pub $method: ::std::option::Option<
+                    unsafe extern "C" fn(This: *mut $interface $(, $arg: $arg_type)*) -> $ret,
+                >,
+            )*
+        }
+
+        #[repr(C)]
+        #[derive(Debug, Copy, Clone)]
+        pub struct $interface {
+            pub lpVtbl: *mut $vtbl,
+        }
+    };
+}
+
+declare_interface!(IDxcBlob, IDxcBlobVtbl {
+    GetBufferPointer: fn() -> LPVOID,
+    GetBufferSize: fn() -> SIZE_T,
+});
+
+declare_interface!(IDxcBlobEncoding, IDxcBlobEncodingVtbl {
+    GetBufferPointer: fn() -> LPVOID,
+    GetBufferSize: fn() -> SIZE_T,
+    GetEncoding: fn(pKnown: *mut BOOL, pCodePage: *mut UINT32) -> HRESULT,
+});
+
+declare_interface!(IDxcBlobUtf8, IDxcBlobUtf8Vtbl {
+    GetBufferPointer: fn() -> LPVOID,
+    GetBufferSize: fn() -> SIZE_T,
+    GetEncoding: fn(pKnown: *mut BOOL, pCodePage: *mut UINT32) -> HRESULT,
+    GetStringPointer: fn() -> LPCSTR,
+    GetStringLength: fn() -> SIZE_T,
+});
+
+declare_interface!(IDxcIncludeHandler, IDxcIncludeHandlerVtbl {
+    LoadSource: fn(
+        pFilename: LPCWSTR,
+        ppIncludeSource: *mut *mut IDxcBlob
+    ) -> HRESULT,
+});
+
+declare_interface!(IDxcResult, IDxcResultVtbl {
+    GetStatus: fn(pStatus: *mut HRESULT) -> HRESULT,
+    GetResult: fn(ppResult: *mut *mut IDxcBlob) -> HRESULT,
+    GetErrorBuffer: fn(ppErrors: *mut *mut IDxcBlobEncoding) -> HRESULT,
+    HasOutput: fn(dxcOutKind: DXC_OUT_KIND) -> BOOL,
+    GetOutput: fn(
+        dxcOutKind: DXC_OUT_KIND,
+        iid: *const IID,
+        ppvObject: *mut LPVOID,
+        ppOutputName: *mut *mut IUnknown
+    ) -> HRESULT,
+    GetNumOutputs: fn() -> UINT32,
+    GetOutputByIndex: fn(Index: UINT32) -> DXC_OUT_KIND,
+    PrimaryOutput: fn() -> DXC_OUT_KIND,
+});
+
+declare_interface!(IDxcCompiler3, IDxcCompiler3Vtbl {
+    Compile: fn(
+        pSource: *const DxcBuffer,
+        pArguments: *mut LPCWSTR,
+        argCount: UINT32,
+        pIncludeHandler: *mut IDxcIncludeHandler,
+        riid: *const IID,
+        ppResult: *mut LPVOID
+    ) -> HRESULT,
+    Disassemble: fn(
+        pObject: *const DxcBuffer,
+        riid: *const IID,
+        ppResult: *mut LPVOID
+    ) -> HRESULT,
+});
+
+declare_interface!(IDxcUtils, IDxcUtilsVtbl {
+    CreateBlobFromBlob: fn(
+        pBlob: *mut IDxcBlob,
+        offset: UINT32,
+        length: UINT32,
+        ppResult: *mut *mut IDxcBlob
+    ) -> HRESULT,
+    CreateBlobFromPinned: fn(
+        pData: LPCVOID,
+        size: UINT32,
+        codePage: UINT32,
+        pBlobEncoding: *mut *mut IDxcBlobEncoding
+    ) -> HRESULT,
+    MoveToBlob: fn(
+        pData: LPCVOID,
+        pIMalloc: *mut IUnknown,
+        size: UINT32,
+        codePage: UINT32,
+        pBlobEncoding: *mut *mut IDxcBlobEncoding
+    ) -> HRESULT,
+    CreateBlob: fn(
+        pData: LPCVOID,
+        size: UINT32,
+        codePage: UINT32,
+        pBlobEncoding: *mut *mut IDxcBlobEncoding
+    ) -> HRESULT,
+    LoadFile: fn(
+        pFileName: LPCWSTR,
+        pCodePage: *mut UINT32,
+        pBlobEncoding: *mut *mut IDxcBlobEncoding
+    ) -> HRESULT,
+    CreateReadOnlyStreamFromBlob: fn(
+        pBlob: *mut IDxcBlob,
+        ppStream: *mut *mut IUnknown
+    ) -> HRESULT,
+    CreateDefaultIncludeHandler: fn(
+        ppResult: *mut *mut IDxcIncludeHandler
+    ) -> HRESULT,
+    GetBlobAsUtf8: fn(
+        pBlob: *mut IDxcBlob,
+        pBlobEncoding: *mut *mut IDxcBlobUtf8
+    ) -> HRESULT,
+});
@@ -19,3 +19,6 @@ pub mod pix {
 
 #[cfg(not(feature = "devel"))]
 pub mod pix;
+
+#[cfg(feature = "dxc")]
+pub mod dxc;
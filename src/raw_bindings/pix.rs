@@ -4292,3 +4292,6 @@ extern "C" {
 extern "C" {
     pub fn pix_end_event_cmd_queue(command_queue: *mut ID3D12CommandQueue);
 }
+extern "C" {
+    pub fn pix_report_counter(name: *const wchar_t, value: f32);
+}
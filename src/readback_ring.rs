@@ -0,0 +1,183 @@
+use std::mem::{align_of, size_of};
+use std::slice;
+
+use winapi::shared::winerror;
+
+use crate::{
+    ByteCount, CommandList, CopyCapable, Device, DxError, DxResult, HeapFlags,
+    HeapProperties, HeapType, QueryHeap, QueryType, Range, Resource,
+    ResourceDesc, ResourceDimension, ResourceStates, TextureLayout,
+};
+
+/// Offsets passed to ResolveQueryData must be aligned to 8 bytes
+const RESOLVE_OFFSET_ALIGNMENT: ByteCount = ByteCount(8);
+
+/// A readback buffer split into per-frame regions that stays mapped for its
+/// whole lifetime. Query data (timestamps, pipeline statistics) and
+/// arbitrary buffer contents (e.g. UAV counters) are resolved into the
+/// region of the current frame and read back with [ReadbackRing::consume]
+/// once the GPU has finished that frame, without any Map/Unmap calls
+#[derive(Debug)]
+pub struct ReadbackRing {
+    buffer: Resource,
+    mapped_data: *const u8,
+    frame_size: u64,
+    frame_count: u32,
+}
+
+impl ReadbackRing {
+    /// `frame_size` is rounded up to a multiple of 8 bytes so that every
+    /// frame region is a valid ResolveQueryData destination
+    pub fn new(
+        device: &Device,
+        frame_size: ByteCount,
+        frame_count: u32,
+    ) -> DxResult<Self> {
+        if frame_size.0 == 0 || frame_count == 0 {
+            return Err(DxError::new(
+                "ReadbackRing::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let frame_size = frame_size.align_up(RESOLVE_OFFSET_ALIGNMENT).0;
+
+        let buffer = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Readback),
+            HeapFlags::None,
+            &ResourceDesc::default()
+                .with_dimension(ResourceDimension::Buffer)
+                .with_width(frame_size * frame_count as u64)
+                .with_layout(TextureLayout::RowMajor),
+            ResourceStates::CopyDest,
+            None,
+        )?;
+        if let Some(name) = device.make_object_name("Readback ring buffer") {
+            buffer.set_name(&name)?;
+        }
+
+        let mapped_data = buffer.map(0, None)?;
+
+        Ok(Self {
+            buffer,
+            mapped_data,
+            frame_size,
+            frame_count,
+        })
+    }
+
+    /// Offset of the region that belongs to `frame_index` (which can be
+    /// an ever-increasing frame counter, it is wrapped around internally)
+    pub fn frame_offset(&self, frame_index: u64) -> ByteCount {
+        ByteCount((frame_index % self.frame_count as u64) * self.frame_size)
+    }
+
+    /// Records ResolveQueryData into the region of `frame_index`
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve_query_data<K: CopyCapable>(
+        &self,
+        command_list: &CommandList<K>,
+        frame_index: u64,
+        offset_in_frame: ByteCount,
+        query_heap: &QueryHeap,
+        query_type: QueryType,
+        start_index: u32,
+        num_queries: u32,
+    ) {
+        debug_assert!(
+            offset_in_frame.is_aligned(RESOLVE_OFFSET_ALIGNMENT),
+            "Query data offset must be aligned to 8 bytes"
+        );
+
+        command_list.resolve_query_data(
+            query_heap,
+            query_type,
+            start_index,
+            num_queries,
+            &self.buffer,
+            self.frame_offset(frame_index) + offset_in_frame,
+        );
+    }
+
+    /// Records CopyBufferRegion from `source` into the region
+    /// of `frame_index`, e.g. to read back UAV counters
+    pub fn copy_buffer_region<K: CopyCapable>(
+        &self,
+        command_list: &CommandList<K>,
+        frame_index: u64,
+        offset_in_frame: ByteCount,
+        source: &Resource,
+        source_offset: ByteCount,
+        span: ByteCount,
+    ) {
+        debug_assert!(
+            offset_in_frame.0 + span.0 <= self.frame_size,
+            "Copy exceeds readback ring frame size"
+        );
+
+        command_list.copy_buffer_region(
+            &self.buffer,
+            self.frame_offset(frame_index) + offset_in_frame,
+            source,
+            source_offset,
+            span,
+        );
+    }
+
+    /// Returns the data resolved during `frame_index`. The caller must make
+    /// sure the GPU has finished that frame (e.g. by waiting on its fence)
+    /// and that the region is not being resolved into again
+    pub fn consume(&self, frame_index: u64) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(
+                self.mapped_data
+                    .add(self.frame_offset(frame_index).0 as usize),
+                self.frame_size as usize,
+            )
+        }
+    }
+
+    /// Same as [ReadbackRing::consume], but reinterprets the region as
+    /// an array of `T`, e.g. u64 timestamps or
+    /// D3D12_QUERY_DATA_PIPELINE_STATISTICS structures. Panics if the region
+    /// is not aligned for `T`
+    ///
+    /// # Safety
+    ///
+    /// Any bit pattern the GPU writes must be a valid `T`, i.e. `T` must be
+    /// a plain-old-data type without padding, references, enums or bools
+    pub unsafe fn consume_as<T: Copy>(&self, frame_index: u64) -> &[T] {
+        let data = self.consume(frame_index);
+        assert!(
+            data.as_ptr() as usize % align_of::<T>() == 0,
+            "Readback ring frame region is misaligned for the requested type"
+        );
+
+        slice::from_raw_parts(
+            data.as_ptr() as *const T,
+            data.len() / size_of::<T>(),
+        )
+    }
+
+    pub fn buffer(&self) -> &Resource {
+        &self.buffer
+    }
+
+    pub fn frame_size(&self) -> ByteCount {
+        ByteCount(self.frame_size)
+    }
+
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+}
+
+impl Drop for ReadbackRing {
+    fn drop(&mut self) {
+        // no data has been written by the CPU
+        self.buffer.unmap(0, Some(&Range::default()));
+    }
+}
+
+// the mapped pointer is only dereferenced through &self
+unsafe impl Send for ReadbackRing {}
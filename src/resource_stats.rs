@@ -0,0 +1,274 @@
+// Per-resource counters gathered from command list recording when
+// `resource_stats` feature is enabled
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::{CpuDescriptorHandle, PIXSupport, Resource, ResourceBarrier};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Counters {
+    transitions: u64,
+    copies: u64,
+    clears: u64,
+}
+
+#[derive(Debug, Default)]
+struct Entry {
+    total: Counters,
+    current_frame: Counters,
+    peak_frame_transitions: u64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    resources: HashMap<String, Entry>,
+    // RTV/DSV descriptor address -> name of the resource the view points to,
+    // since clears only receive the descriptor
+    views: HashMap<u64, String>,
+    frame_count: u64,
+}
+
+fn resource_name(resource: &Resource) -> String {
+    match resource.get_name() {
+        Ok(Some(name)) => name,
+        _ => format!("<unnamed {:p}>", resource.this),
+    }
+}
+
+fn with_state(f: impl FnOnce(&mut State)) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut state = STATE.lock().expect("Resource stats mutex is poisoned");
+    f(state.get_or_insert_with(State::default));
+}
+
+fn count(name: String, update: impl FnOnce(&mut Counters)) {
+    with_state(|state| {
+        update(&mut state.resources.entry(name).or_default().current_frame)
+    });
+}
+
+pub(crate) fn record_barriers(barriers: &[ResourceBarrier]) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    for barrier in barriers {
+        if let Some(transition) = barrier.transition() {
+            count(resource_name(&transition.resource()), |counters| {
+                counters.transitions += 1
+            });
+        }
+    }
+}
+
+pub(crate) fn record_copy(dest: &Resource) {
+    if ENABLED.load(Ordering::Relaxed) {
+        count(resource_name(dest), |counters| counters.copies += 1);
+    }
+}
+
+pub(crate) fn record_view(
+    descriptor: CpuDescriptorHandle,
+    resource: &Resource,
+) {
+    if ENABLED.load(Ordering::Relaxed) {
+        let name = resource_name(resource);
+        with_state(|state| {
+            state.views.insert(descriptor.hw_handle.ptr, name);
+        });
+    }
+}
+
+pub(crate) fn record_clear(descriptor: CpuDescriptorHandle) {
+    with_state(|state| {
+        let name = state
+            .views
+            .get(&descriptor.hw_handle.ptr)
+            .cloned()
+            .unwrap_or_else(|| {
+                format!("<view {:#x}>", descriptor.hw_handle.ptr)
+            });
+        state
+            .resources
+            .entry(name)
+            .or_default()
+            .current_frame
+            .clears += 1;
+    });
+}
+
+/// Counts barrier transitions, copies and clears per resource name while
+/// command lists are recorded, to find the resources that thrash between
+/// states. Counters are collected process-wide, so the names should be
+/// unique (see [Device::make_object_name](crate::Device::make_object_name)).
+/// Copies are attributed to their destination, and clears are matched with
+/// resources by the descriptors passed to `create_render_target_view` and
+/// `create_depth_stencil_view`
+pub struct ResourceStats {}
+
+impl ResourceStats {
+    /// Recording is enabled by default when the feature is on
+    pub fn set_enabled(enabled: bool) {
+        ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Closes the current frame: its counters are added to the totals
+    /// and the per-frame peaks are updated. Should be called once per
+    /// frame, e.g. after presenting
+    pub fn end_frame() {
+        let mut state = STATE.lock().expect("Resource stats mutex is poisoned");
+        let state = state.get_or_insert_with(State::default);
+        for entry in state.resources.values_mut() {
+            let frame = std::mem::take(&mut entry.current_frame);
+            entry.total.transitions += frame.transitions;
+            entry.total.copies += frame.copies;
+            entry.total.clears += frame.clears;
+            entry.peak_frame_transitions =
+                entry.peak_frame_transitions.max(frame.transitions);
+        }
+        state.frame_count += 1;
+    }
+
+    /// Snapshot of the closed frames sorted by the number of transitions,
+    /// most transitioned resources first
+    pub fn report() -> ResourceStatsReport {
+        let state = STATE.lock().expect("Resource stats mutex is poisoned");
+        let state = match state.as_ref() {
+            Some(state) => state,
+            None => return ResourceStatsReport::default(),
+        };
+
+        let mut resources = state
+            .resources
+            .iter()
+            .map(|(name, entry)| ResourceUsage {
+                name: name.clone(),
+                transitions: entry.total.transitions,
+                copies: entry.total.copies,
+                clears: entry.total.clears,
+                peak_frame_transitions: entry.peak_frame_transitions,
+            })
+            .collect::<Vec<_>>();
+        resources.sort_by(|lhs, rhs| {
+            rhs.transitions
+                .cmp(&lhs.transitions)
+                .then_with(|| lhs.name.cmp(&rhs.name))
+        });
+
+        ResourceStatsReport {
+            frame_count: state.frame_count,
+            resources,
+        }
+    }
+
+    /// Drops all the counters. Descriptor to resource mapping is kept,
+    /// so clears of the existing views are still attributed correctly
+    pub fn reset() {
+        let mut state = STATE.lock().expect("Resource stats mutex is poisoned");
+        if let Some(state) = state.as_mut() {
+            state.resources.clear();
+            state.frame_count = 0;
+        }
+    }
+
+    /// Emits per-frame average transition counts of the `count` most
+    /// transitioned resources as PIX counters named
+    /// `<prefix>/<resource name>`, so that they show up on timing captures.
+    /// Does nothing unless `pix` feature is enabled and [PIXSupport::init]
+    /// has been called
+    pub fn report_pix_counters(prefix: &str, count: usize) {
+        if cfg!(feature = "pix") {
+            let report = Self::report();
+            for usage in report.most_transitioned(count) {
+                PIXSupport::report_counter(
+                    &format!("{}/{}", prefix, usage.name),
+                    usage.transitions_per_frame(report.frame_count) as f32,
+                );
+            }
+        }
+    }
+}
+
+/// Counters of a single resource accumulated over the closed frames
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceUsage {
+    pub name: String,
+    pub transitions: u64,
+    pub copies: u64,
+    pub clears: u64,
+    /// The largest number of transitions recorded within one frame
+    pub peak_frame_transitions: u64,
+}
+
+impl ResourceUsage {
+    pub fn transitions_per_frame(&self, frame_count: u64) -> f64 {
+        match frame_count {
+            0 => 0.,
+            _ => self.transitions as f64 / frame_count as f64,
+        }
+    }
+}
+
+/// Returned by [ResourceStats::report], prints as a table
+#[derive(Debug, Clone, Default)]
+pub struct ResourceStatsReport {
+    frame_count: u64,
+    resources: Vec<ResourceUsage>,
+}
+
+impl ResourceStatsReport {
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// All resources, most transitioned first
+    pub fn resources(&self) -> &[ResourceUsage] {
+        &self.resources
+    }
+
+    pub fn most_transitioned(&self, count: usize) -> &[ResourceUsage] {
+        &self.resources[..count.min(self.resources.len())]
+    }
+}
+
+impl fmt::Display for ResourceStatsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Resource usage over {} frames, most transitioned first",
+            self.frame_count
+        )?;
+        writeln!(
+            f,
+            "{:<40}{:>14}{:>14}{:>10}{:>10}",
+            "resource", "transitions", "avg (peak)", "copies", "clears"
+        )?;
+        for usage in &self.resources {
+            writeln!(
+                f,
+                "{:<40}{:>14}{:>9.1} ({:>2}){:>10}{:>10}",
+                usage.name,
+                usage.transitions,
+                usage.transitions_per_frame(self.frame_count),
+                usage.peak_frame_transitions,
+                usage.copies,
+                usage.clears
+            )?;
+        }
+
+        Ok(())
+    }
+}
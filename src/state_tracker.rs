@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    BarrierBatcher, CommandList, CopyCapable, DxError, DxResult,
+    ID3D12Resource, Metric, Metrics, Resource, ResourceDimension,
+    ResourceStates, SubresourceRange,
+};
+
+#[derive(Debug)]
+struct TrackedResource {
+    // keeps the pointer used as the key valid
+    _resource: Resource,
+    mip_levels: u32,
+    array_size: u32,
+    // one entry per subresource
+    states: Vec<ResourceStates>,
+}
+
+impl TrackedResource {
+    fn uniform_state(&self) -> Option<ResourceStates> {
+        let first = self.states[0];
+        match self.states.iter().all(|state| *state == first) {
+            true => Some(first),
+            false => None,
+        }
+    }
+}
+
+/// Opt-in tracking of per-subresource states of registered resources.
+/// Instead of spelling out "before" states, the application asks for
+/// the state a resource (or some of its subresources) has to be in, and
+/// the tracker queues the transitions that are needed into
+/// a [BarrierBatcher]. The usual pattern is `transition` for every
+/// resource the next draw or dispatch uses -> `flush` -> the draw or
+/// dispatch, so that all the barriers between two draws are recorded
+/// in a single call.
+///
+/// The tracked states are the ones the resources will be in at this
+/// point of the recording, so a tracker must follow the order in which
+/// command lists are executed. Implicit state promotion and decay are
+/// not tracked, resources that rely on them should be re-registered
+#[derive(Debug, Default)]
+pub struct StateTracker {
+    resources: HashMap<*mut ID3D12Resource, TrackedResource>,
+    barriers: BarrierBatcher,
+}
+
+impl StateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking the resource, all of whose subresources have to be
+    /// in `state` at this point. Registering a tracked resource again
+    /// overwrites its states
+    pub fn register(&mut self, resource: &Resource, state: ResourceStates) {
+        let desc = resource.get_desc();
+        let (mip_levels, array_size) = match desc.dimension() {
+            ResourceDimension::Buffer => (1, 1),
+            _ => (desc.mip_levels() as u32, desc.array_size()),
+        };
+        let subresource_count =
+            mip_levels * array_size * desc.format().plane_count();
+
+        self.resources.insert(
+            resource.this,
+            TrackedResource {
+                _resource: resource.clone(),
+                mip_levels,
+                array_size,
+                states: vec![state; subresource_count as usize],
+            },
+        );
+    }
+
+    /// Stops tracking the resource. Pending barriers are not affected
+    pub fn unregister(&mut self, resource: &Resource) {
+        self.resources.remove(&resource.this);
+    }
+
+    pub fn is_registered(&self, resource: &Resource) -> bool {
+        self.resources.contains_key(&resource.this)
+    }
+
+    /// State of the whole resource, None if it is not registered or its
+    /// subresources are in different states
+    pub fn state(&self, resource: &Resource) -> Option<ResourceStates> {
+        self.resources
+            .get(&resource.this)
+            .and_then(|tracked| tracked.uniform_state())
+    }
+
+    pub fn subresource_state(
+        &self,
+        resource: &Resource,
+        subresource: u32,
+    ) -> Option<ResourceStates> {
+        self.resources
+            .get(&resource.this)
+            .and_then(|tracked| tracked.states.get(subresource as usize))
+            .copied()
+    }
+
+    /// Queues the transitions that bring every subresource into `state`.
+    /// A resource whose subresources are all in the same state gets
+    /// a single whole-resource barrier
+    pub fn transition(
+        &mut self,
+        resource: &Resource,
+        state: ResourceStates,
+    ) -> DxResult<()> {
+        let tracked = Self::tracked(&mut self.resources, resource)?;
+        match tracked.uniform_state() {
+            Some(before) => {
+                self.barriers.transition(resource, None, before, state)
+            }
+            None => {
+                for (subresource, before) in tracked.states.iter().enumerate() {
+                    if *before != state {
+                        self.barriers.transition(
+                            resource,
+                            Some(subresource as u32),
+                            *before,
+                            state,
+                        );
+                    }
+                }
+            }
+        }
+        tracked.states.fill(state);
+
+        Ok(())
+    }
+
+    pub fn transition_subresource(
+        &mut self,
+        resource: &Resource,
+        subresource: u32,
+        state: ResourceStates,
+    ) -> DxResult<()> {
+        let tracked = Self::tracked(&mut self.resources, resource)?;
+        let before = match tracked.states.get_mut(subresource as usize) {
+            Some(before) => before,
+            None => {
+                error!(
+                    "Subresource {} of resource {:p} is out of range",
+                    subresource, resource.this
+                );
+                return Err(DxError::new(
+                    "StateTracker::transition_subresource",
+                    winerror::E_INVALIDARG,
+                ));
+            }
+        };
+
+        if *before != state {
+            self.barriers.transition(
+                resource,
+                Some(subresource),
+                *before,
+                state,
+            );
+            *before = state;
+        }
+
+        Ok(())
+    }
+
+    /// Same as [StateTracker::transition_subresource] for every
+    /// subresource in `range`
+    pub fn transition_range(
+        &mut self,
+        resource: &Resource,
+        range: &SubresourceRange,
+        state: ResourceStates,
+    ) -> DxResult<()> {
+        let (mip_levels, array_size) = {
+            let tracked = Self::tracked(&mut self.resources, resource)?;
+            (tracked.mip_levels, tracked.array_size)
+        };
+        for subresource in range.subresource_indices(mip_levels, array_size) {
+            self.transition_subresource(resource, subresource, state)?;
+        }
+
+        Ok(())
+    }
+
+    /// Queues a UAV barrier for the resource, or for all UAV accesses
+    /// if None
+    pub fn uav(&mut self, resource: Option<&Resource>) {
+        self.barriers.uav(resource);
+    }
+
+    /// Records the pending barriers, must be called before the commands
+    /// that rely on the requested states
+    pub fn flush<K: CopyCapable>(&mut self, command_list: &CommandList<K>) {
+        self.barriers.flush(command_list);
+    }
+
+    pub fn pending_count(&self) -> u32 {
+        self.barriers.pending_count()
+    }
+
+    pub fn resource_count(&self) -> u32 {
+        self.resources.len() as u32
+    }
+
+    fn tracked<'a>(
+        resources: &'a mut HashMap<*mut ID3D12Resource, TrackedResource>,
+        resource: &Resource,
+    ) -> DxResult<&'a mut TrackedResource> {
+        match resources.get_mut(&resource.this) {
+            Some(tracked) => Ok(tracked),
+            None => {
+                error!(
+                    "Resource {:p} is not registered in the state tracker",
+                    resource.this
+                );
+                Err(DxError::new(
+                    "StateTracker::transition",
+                    winerror::E_INVALIDARG,
+                ))
+            }
+        }
+    }
+}
+
+impl Metrics for StateTracker {
+    fn metrics(&self) -> Vec<Metric> {
+        let mut metrics =
+            vec![Metric::new("tracked resources", self.resource_count())];
+        metrics.extend(self.barriers.metrics());
+        metrics
+    }
+}
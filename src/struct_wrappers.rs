@@ -12,9 +12,9 @@ use widestring::WideCStr;
 use crate::utils::*;
 use crate::{const_wrappers::*, PipelineState};
 use crate::{enum_wrappers::*, RootSignature};
-use crate::{raw_bindings::d3d12::*, DxError};
+use crate::{raw_bindings::d3d12::*, DxError, DxResult};
 
-use crate::Resource;
+use crate::{Device, Resource, SubresourceIndex};
 
 // Only newtypes for data structs etc. live here;
 // if a struct is not identical to the raw one,
@@ -23,10 +23,96 @@ use crate::Resource;
 // ToDo: make namespaces for DXGI types and D3D12 since currently they're
 // mixed up??
 
+// Reports an error found by one of the `validate` methods
+fn invalid_desc(func_name: &'static str, message: String) -> DxError {
+    log::error!("{}: {}", func_name, message);
+    DxError::new(func_name, winapi::shared::winerror::E_INVALIDARG)
+}
+
 /// Wrapper around D3D12_GPU_VIRTUAL_ADDRESS structure
 #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy)]
 pub struct GpuVirtualAddress(pub D3D12_GPU_VIRTUAL_ADDRESS);
 
+impl GpuVirtualAddress {
+    pub fn checked_add(self, offset: ByteCount) -> Option<Self> {
+        self.0.checked_add(offset.0).map(Self)
+    }
+}
+
+// GpuVirtualAddress + ByteCount = GpuVirtualAddress
+impl std::ops::Add<ByteCount> for GpuVirtualAddress {
+    type Output = Self;
+
+    fn add(self, rhs: ByteCount) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::AddAssign<ByteCount> for GpuVirtualAddress {
+    fn add_assign(&mut self, rhs: ByteCount) {
+        *self = *self + rhs;
+    }
+}
+
+// GpuVirtualAddress - GpuVirtualAddress = ByteCount
+impl std::ops::Sub<GpuVirtualAddress> for GpuVirtualAddress {
+    type Output = ByteCount;
+
+    fn sub(self, rhs: GpuVirtualAddress) -> Self::Output {
+        ByteCount(self.0 - rhs.0)
+    }
+}
+
+/// Wrapper around D3D12_GPU_VIRTUAL_ADDRESS_RANGE structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct GpuVirtualAddressRange(pub(crate) D3D12_GPU_VIRTUAL_ADDRESS_RANGE);
+
+impl GpuVirtualAddressRange {
+    pub fn set_start_address(
+        &mut self,
+        start_address: GpuVirtualAddress,
+    ) -> &mut Self {
+        self.0.StartAddress = start_address.0;
+        self
+    }
+
+    pub fn with_start_address(
+        mut self,
+        start_address: GpuVirtualAddress,
+    ) -> Self {
+        self.set_start_address(start_address);
+        self
+    }
+
+    pub fn start_address(&self) -> GpuVirtualAddress {
+        GpuVirtualAddress(self.0.StartAddress)
+    }
+
+    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
+        self.0.SizeInBytes = size_in_bytes.0;
+        self
+    }
+
+    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
+        self.set_size_in_bytes(size_in_bytes);
+        self
+    }
+
+    pub fn size_in_bytes(&self) -> ByteCount {
+        ByteCount(self.0.SizeInBytes)
+    }
+
+    /// Returns the address located at `offset` bytes from the range start,
+    /// or None if it doesn't fall into the range
+    pub fn checked_add(&self, offset: ByteCount) -> Option<GpuVirtualAddress> {
+        match offset.0 < self.0.SizeInBytes {
+            true => self.start_address().checked_add(offset),
+            false => None,
+        }
+    }
+}
+
 /// Wrapper around DXGI_SWAP_CHAIN_DESC1 structure
 #[repr(transparent)]
 #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone)]
@@ -205,5333 +291,5404 @@ impl SwapChainDesc {
     pub fn flags(&self) -> SwapChainFlags {
         unsafe { std::mem::transmute(self.0.Flags) }
     }
-}
 
-/// Wrapper around DXGI_ADAPTER_DESC1 structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
-#[repr(transparent)]
-pub struct AdapterDesc(pub(crate) DXGI_ADAPTER_DESC1);
+    /// Checks the constraints D3D12 swapchains have to satisfy: flip
+    /// presentation model, 2 to [MAX_SWAP_CHAIN_BUFFERS] buffers,
+    /// no multisampling and one of the flip model formats
+    pub fn validate(&self) -> DxResult<()> {
+        self.check()
+            .map_err(|message| invalid_desc("SwapChainDesc::validate", message))
+    }
 
-impl AdapterDesc {
-    pub fn is_software(&self) -> bool {
-        self.0.Flags & DXGI_ADAPTER_FLAG_DXGI_ADAPTER_FLAG_SOFTWARE as u32 != 0
+    /// Finishes building the description, see [SwapChainDesc::validate]
+    pub fn finish(self) -> DxResult<Self> {
+        self.validate()?;
+        Ok(self)
     }
 
-    // ToDo: clean up?
-    pub fn description(&self) -> Option<String> {
-        WideCStr::from_slice_with_nul(&self.0.Description)
-            .map(|wide_cstr| wide_cstr.to_string_lossy())
-            .ok()
+    fn check(&self) -> Result<(), String> {
+        if !matches!(
+            self.swap_effect(),
+            SwapEffect::FlipSequential | SwapEffect::FlipDiscard
+        ) {
+            return Err(format!(
+                "D3D12 swapchains must use a flip model swap effect, got {:?}",
+                self.swap_effect()
+            ));
+        }
+
+        if !(2..=MAX_SWAP_CHAIN_BUFFERS).contains(&self.buffer_count()) {
+            return Err(format!(
+                "flip model swapchains need 2 to {} buffers, got {}",
+                MAX_SWAP_CHAIN_BUFFERS,
+                self.buffer_count()
+            ));
+        }
+
+        let sample_desc = self.sample_desc();
+        if sample_desc.count() != 1 || sample_desc.quality() != 0 {
+            return Err(String::from(
+                "flip model swapchains cannot be multisampled, render \
+                 to an MSAA target and resolve it into the back buffer instead",
+            ));
+        }
+
+        if !matches!(
+            self.format(),
+            Format::R16G16B16A16Float
+                | Format::B8G8R8A8Unorm
+                | Format::R8G8B8A8Unorm
+                | Format::R10G10B10A2Unorm
+        ) {
+            return Err(format!(
+                "format {:?} is not supported by flip model swapchains \
+                 (sRGB back buffers are accessed through sRGB RTVs instead)",
+                self.format()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Wrapper around DXGI_RATIONAL structure
+#[repr(transparent)]
+#[derive(Default, Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Rational(pub(crate) DXGI_RATIONAL);
+
+impl Rational {
+    pub fn new(numerator: u32, denominator: u32) -> Self {
+        Rational(DXGI_RATIONAL {
+            Numerator: numerator,
+            Denominator: denominator,
+        })
     }
 
-    pub fn set_vendor_id(&mut self, vendor_id: u32) -> &mut Self {
-        self.0.VendorId = vendor_id;
+    pub fn set_numerator(&mut self, numerator: u32) -> &mut Self {
+        self.0.Numerator = numerator;
         self
     }
 
-    pub fn with_vendor_id(mut self, vendor_id: u32) -> Self {
-        self.set_vendor_id(vendor_id);
+    pub fn with_numerator(mut self, numerator: u32) -> Self {
+        self.set_numerator(numerator);
         self
     }
 
-    pub fn vendor_id(&self) -> u32 {
-        self.0.VendorId
+    pub fn numerator(&self) -> u32 {
+        self.0.Numerator
     }
 
-    pub fn set_device_id(&mut self, device_id: u32) -> &mut Self {
-        self.0.DeviceId = device_id;
+    pub fn set_denominator(&mut self, denominator: u32) -> &mut Self {
+        self.0.Denominator = denominator;
         self
     }
 
-    pub fn with_device_id(mut self, device_id: u32) -> Self {
-        self.set_device_id(device_id);
+    pub fn with_denominator(mut self, denominator: u32) -> Self {
+        self.set_denominator(denominator);
         self
     }
 
-    pub fn device_id(&self) -> u32 {
-        self.0.DeviceId
+    pub fn denominator(&self) -> u32 {
+        self.0.Denominator
     }
 
-    pub fn set_sub_sys_id(&mut self, sub_sys_id: u32) -> &mut Self {
-        self.0.SubSysId = sub_sys_id;
+    /// None if the denominator is zero
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.0.Denominator {
+            0 => None,
+            denominator => Some(self.0.Numerator as f64 / denominator as f64),
+        }
+    }
+}
+
+/// Wrapper around DXGI_MODE_DESC structure
+#[repr(transparent)]
+#[derive(Default, Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct ModeDesc(pub(crate) DXGI_MODE_DESC);
+
+impl ModeDesc {
+    pub fn set_width(&mut self, width: u32) -> &mut Self {
+        self.0.Width = width;
         self
     }
 
-    pub fn with_sub_sys_id(mut self, sub_sys_id: u32) -> Self {
-        self.set_sub_sys_id(sub_sys_id);
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.set_width(width);
         self
     }
 
-    pub fn sub_sys_id(&self) -> u32 {
-        self.0.SubSysId
+    pub fn width(&self) -> u32 {
+        self.0.Width
     }
 
-    pub fn set_revision(&mut self, revision: u32) -> &mut Self {
-        self.0.Revision = revision;
+    pub fn set_height(&mut self, height: u32) -> &mut Self {
+        self.0.Height = height;
         self
     }
 
-    pub fn with_revision(mut self, revision: u32) -> Self {
-        self.set_revision(revision);
+    pub fn with_height(mut self, height: u32) -> Self {
+        self.set_height(height);
         self
     }
 
-    pub fn revision(&self) -> u32 {
-        self.0.Revision
+    pub fn height(&self) -> u32 {
+        self.0.Height
     }
 
-    pub fn set_dedicated_video_memory(
-        &mut self,
-        dedicated_video_memory: u64,
-    ) -> &mut Self {
-        self.0.DedicatedVideoMemory = dedicated_video_memory;
+    pub fn set_refresh_rate(&mut self, refresh_rate: Rational) -> &mut Self {
+        self.0.RefreshRate = refresh_rate.0;
         self
     }
 
-    pub fn with_dedicated_video_memory(
-        mut self,
-        dedicated_video_memory: u64,
-    ) -> Self {
-        self.set_dedicated_video_memory(dedicated_video_memory);
+    pub fn with_refresh_rate(mut self, refresh_rate: Rational) -> Self {
+        self.set_refresh_rate(refresh_rate);
         self
     }
 
-    pub fn dedicated_video_memory(&self) -> u64 {
-        self.0.DedicatedVideoMemory
+    pub fn refresh_rate(&self) -> Rational {
+        Rational(self.0.RefreshRate)
     }
 
-    pub fn set_dedicated_system_memory(
-        &mut self,
-        dedicated_system_memory: u64,
-    ) -> &mut Self {
-        self.0.DedicatedSystemMemory = dedicated_system_memory;
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
         self
     }
 
-    pub fn with_dedicated_system_memory(
-        mut self,
-        dedicated_system_memory: u64,
-    ) -> Self {
-        self.set_dedicated_system_memory(dedicated_system_memory);
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
         self
     }
 
-    pub fn dedicated_system_memory(&self) -> u64 {
-        self.0.DedicatedSystemMemory
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
     }
 
-    pub fn set_shared_system_memory(
+    pub fn set_scanline_ordering(
         &mut self,
-        shared_system_memory: u64,
+        scanline_ordering: ModeScanlineOrder,
     ) -> &mut Self {
-        self.0.SharedSystemMemory = shared_system_memory;
+        self.0.ScanlineOrdering = scanline_ordering as i32;
         self
     }
 
-    pub fn with_shared_system_memory(
+    pub fn with_scanline_ordering(
         mut self,
-        shared_system_memory: u64,
+        scanline_ordering: ModeScanlineOrder,
     ) -> Self {
-        self.set_shared_system_memory(shared_system_memory);
+        self.set_scanline_ordering(scanline_ordering);
         self
     }
 
-    pub fn shared_system_memory(&self) -> u64 {
-        self.0.SharedSystemMemory
+    pub fn scanline_ordering(&self) -> ModeScanlineOrder {
+        unsafe { std::mem::transmute(self.0.ScanlineOrdering) }
     }
 
-    pub fn set_flags(&mut self, flags: AdapterFlag) -> &mut Self {
-        self.0.Flags = flags as u32;
+    pub fn set_scaling(&mut self, scaling: ModeScaling) -> &mut Self {
+        self.0.Scaling = scaling as i32;
         self
     }
 
-    pub fn with_flags(mut self, flags: AdapterFlag) -> Self {
-        self.set_flags(flags);
+    pub fn with_scaling(mut self, scaling: ModeScaling) -> Self {
+        self.set_scaling(scaling);
         self
     }
 
-    pub fn flags(&self) -> AdapterFlag {
-        unsafe { std::mem::transmute(self.0.Flags) }
+    pub fn scaling(&self) -> ModeScaling {
+        unsafe { std::mem::transmute(self.0.Scaling) }
     }
 }
 
-impl Default for AdapterDesc {
-    fn default() -> Self {
-        AdapterDesc(DXGI_ADAPTER_DESC1 {
-            Description: [0; 128],
-            VendorId: 0,
-            DeviceId: 0,
-            SubSysId: 0,
-            Revision: 0,
-            DedicatedVideoMemory: 0,
-            DedicatedSystemMemory: 0,
-            SharedSystemMemory: 0,
-            AdapterLuid: LUID {
-                LowPart: 0,
-                HighPart: 0,
-            },
-            Flags: 0,
-        })
+/// Wrapper around DXGI_OUTPUT_DESC structure
+#[repr(transparent)]
+#[derive(Default, Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone)]
+pub struct OutputDesc(pub(crate) DXGI_OUTPUT_DESC);
+
+impl OutputDesc {
+    /// GDI name of the output, e.g. `\\.\DISPLAY1`
+    pub fn device_name(&self) -> Option<String> {
+        WideCStr::from_slice_with_nul(&self.0.DeviceName)
+            .map(|wide_cstr| wide_cstr.to_string_lossy())
+            .ok()
     }
-}
 
-impl std::fmt::Display for AdapterDesc {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            concat!(
-                "Description: {}, VendorId: {:x}, DeviceId: {:x}, ",
-                "SubSysId: {:x}, Revision: {:x}, DedicatedVideoMemory: {}, ",
-                "DedicatedSystemMemory: {}, SharedSystemMemory: {}, ",
-                "AdapterLuid.LowPart: {:x}, AdapterLuid.HighPart: {:x}, Flags: {:x}"
-            ),
-            WideCStr::from_slice_with_nul(&self.0.Description)
-                .expect("Adapter desc is not valid utf-16")
-                .to_string_lossy(),
-            self.0.VendorId,
-            self.0.DeviceId,
-            self.0.SubSysId,
-            self.0.Revision,
-            self.0.DedicatedVideoMemory,
-            self.0.DedicatedSystemMemory,
-            self.0.SharedSystemMemory,
-            self.0.AdapterLuid.LowPart,
-            self.0.AdapterLuid.HighPart,
-            self.0.Flags
-        )
+    /// Bounds of the output in desktop coordinates
+    pub fn desktop_coordinates(&self) -> Rect {
+        Rect(self.0.DesktopCoordinates)
     }
-}
 
-impl std::fmt::Debug for AdapterDesc {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self)
+    pub fn attached_to_desktop(&self) -> bool {
+        self.0.AttachedToDesktop != 0
+    }
+
+    pub fn rotation(&self) -> ModeRotation {
+        unsafe { std::mem::transmute(self.0.Rotation) }
+    }
+
+    pub fn monitor(&self) -> HMONITOR {
+        self.0.Monitor
     }
 }
 
-/// Wrapper around DXGI_SAMPLE_DESC structure
-#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+/// Wrapper around DXGI_OUTPUT_DESC1 structure, which extends
+/// [OutputDesc] with the color capabilities of the display
 #[repr(transparent)]
-pub struct SampleDesc(pub(crate) DXGI_SAMPLE_DESC);
+#[derive(PartialOrd, PartialEq, Debug, Clone)]
+pub struct OutputDesc1(pub(crate) DXGI_OUTPUT_DESC1);
 
-impl Default for SampleDesc {
+impl Default for OutputDesc1 {
     fn default() -> Self {
-        Self(DXGI_SAMPLE_DESC {
-            Count: 1,
-            Quality: 0,
-        })
+        Self(unsafe { std::mem::zeroed() })
     }
 }
 
-impl SampleDesc {
-    pub fn set_count(&mut self, count: u32) -> &mut Self {
-        self.0.Count = count;
-        self
+impl OutputDesc1 {
+    /// GDI name of the output, e.g. `\\.\DISPLAY1`
+    pub fn device_name(&self) -> Option<String> {
+        WideCStr::from_slice_with_nul(&self.0.DeviceName)
+            .map(|wide_cstr| wide_cstr.to_string_lossy())
+            .ok()
     }
 
-    pub fn with_count(mut self, count: u32) -> Self {
-        self.set_count(count);
-        self
+    /// Bounds of the output in desktop coordinates
+    pub fn desktop_coordinates(&self) -> Rect {
+        Rect(self.0.DesktopCoordinates)
     }
 
-    pub fn count(&self) -> u32 {
-        self.0.Count
+    pub fn attached_to_desktop(&self) -> bool {
+        self.0.AttachedToDesktop != 0
     }
 
-    pub fn set_quality(&mut self, quality: u32) -> &mut Self {
-        self.0.Quality = quality;
-        self
+    pub fn rotation(&self) -> ModeRotation {
+        unsafe { std::mem::transmute(self.0.Rotation) }
     }
 
-    pub fn with_quality(mut self, quality: u32) -> Self {
-        self.set_quality(quality);
-        self
+    pub fn monitor(&self) -> HMONITOR {
+        self.0.Monitor
     }
 
-    pub fn quality(&self) -> u32 {
-        self.0.Quality
+    pub fn bits_per_color(&self) -> u32 {
+        self.0.BitsPerColor
     }
-}
 
-/// Wrapper around D3D12_RESOURCE_DESC structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-pub struct ResourceDesc(pub(crate) D3D12_RESOURCE_DESC);
+    /// Color space the display currently expects, e.g.
+    /// [ColorSpaceType::RgbFullG2084NoneP2020] when HDR is enabled in
+    /// Windows display settings
+    pub fn color_space(&self) -> ColorSpaceType {
+        unsafe { std::mem::transmute(self.0.ColorSpace) }
+    }
 
-impl Default for ResourceDesc {
-    fn default() -> Self {
-        ResourceDesc(D3D12_RESOURCE_DESC {
-            Dimension: ResourceDimension::Unknown as i32,
-            Alignment: D3D12_DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT as u64,
-            Width: 0,
-            Height: 1,
-            DepthOrArraySize: 1,
-            MipLevels: 1,
-            Format: Format::Unknown as i32,
-            SampleDesc: SampleDesc::default().0,
-            Layout: TextureLayout::Unknown as i32,
-            Flags: ResourceFlags::None.bits(),
-        })
+    pub fn is_hdr(&self) -> bool {
+        matches!(self.color_space(), ColorSpaceType::RgbFullG2084NoneP2020)
     }
-}
 
-impl ResourceDesc {
-    pub fn set_dimension(&mut self, dimension: ResourceDimension) -> &mut Self {
-        self.0.Dimension = dimension as i32;
-        self
+    /// CIE xy chromaticity coordinates
+    pub fn red_primary(&self) -> [f32; 2] {
+        self.0.RedPrimary
     }
 
-    pub fn with_dimension(mut self, dimension: ResourceDimension) -> Self {
-        self.set_dimension(dimension);
-        self
+    pub fn green_primary(&self) -> [f32; 2] {
+        self.0.GreenPrimary
     }
 
-    pub fn dimension(&self) -> ResourceDimension {
-        unsafe { std::mem::transmute(self.0.Dimension) }
+    pub fn blue_primary(&self) -> [f32; 2] {
+        self.0.BluePrimary
     }
 
-    pub fn set_alignment(&mut self, alignment: u64) -> &mut Self {
-        self.0.Alignment = alignment;
-        self
+    pub fn white_point(&self) -> [f32; 2] {
+        self.0.WhitePoint
     }
 
-    pub fn with_alignment(mut self, alignment: u64) -> Self {
-        self.set_alignment(alignment);
-        self
+    /// In nits
+    pub fn min_luminance(&self) -> f32 {
+        self.0.MinLuminance
     }
 
-    pub fn alignment(&self) -> u64 {
-        self.0.Alignment
+    /// Peak luminance in nits, usually achievable only on a small area
+    pub fn max_luminance(&self) -> f32 {
+        self.0.MaxLuminance
     }
 
-    pub fn set_width(&mut self, width: u64) -> &mut Self {
-        self.0.Width = width;
-        self
+    /// Luminance in nits the display can sustain on the full frame
+    pub fn max_full_frame_luminance(&self) -> f32 {
+        self.0.MaxFullFrameLuminance
     }
+}
 
-    pub fn with_width(mut self, width: u64) -> Self {
-        self.set_width(width);
-        self
-    }
+/// Wrapper around DXGI_SWAP_CHAIN_FULLSCREEN_DESC structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone)]
+pub struct SwapChainFullscreenDesc(pub(crate) DXGI_SWAP_CHAIN_FULLSCREEN_DESC);
 
-    pub fn width(&self) -> u64 {
-        self.0.Width
+impl Default for SwapChainFullscreenDesc {
+    fn default() -> Self {
+        SwapChainFullscreenDesc(DXGI_SWAP_CHAIN_FULLSCREEN_DESC {
+            RefreshRate: DXGI_RATIONAL::default(),
+            ScanlineOrdering:
+                DXGI_MODE_SCANLINE_ORDER_DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED,
+            Scaling: DXGI_MODE_SCALING_DXGI_MODE_SCALING_UNSPECIFIED,
+            Windowed: 1,
+        })
     }
+}
 
-    pub fn set_height(&mut self, height: u32) -> &mut Self {
-        self.0.Height = height;
+impl SwapChainFullscreenDesc {
+    pub fn set_refresh_rate(&mut self, refresh_rate: Rational) -> &mut Self {
+        self.0.RefreshRate = refresh_rate.0;
         self
     }
 
-    pub fn with_height(mut self, height: u32) -> Self {
-        self.set_height(height);
+    pub fn with_refresh_rate(mut self, refresh_rate: Rational) -> Self {
+        self.set_refresh_rate(refresh_rate);
         self
     }
 
-    pub fn height(&self) -> u32 {
-        self.0.Height
+    pub fn refresh_rate(&self) -> Rational {
+        Rational(self.0.RefreshRate)
     }
 
-    pub fn set_depth_or_array_size(
+    pub fn set_scanline_ordering(
         &mut self,
-        depth_or_array_size: u16,
+        scanline_ordering: ModeScanlineOrder,
     ) -> &mut Self {
-        self.0.DepthOrArraySize = depth_or_array_size;
+        self.0.ScanlineOrdering = scanline_ordering as i32;
         self
     }
 
-    pub fn with_depth_or_array_size(
+    pub fn with_scanline_ordering(
         mut self,
-        depth_or_array_size: u16,
+        scanline_ordering: ModeScanlineOrder,
     ) -> Self {
-        self.set_depth_or_array_size(depth_or_array_size);
+        self.set_scanline_ordering(scanline_ordering);
         self
     }
 
-    pub fn depth_or_array_size(&self) -> u16 {
-        self.0.DepthOrArraySize
+    pub fn scanline_ordering(&self) -> ModeScanlineOrder {
+        unsafe { std::mem::transmute(self.0.ScanlineOrdering) }
     }
 
-    pub fn set_mip_levels(&mut self, mip_levels: u16) -> &mut Self {
-        self.0.MipLevels = mip_levels;
+    pub fn set_scaling(&mut self, scaling: ModeScaling) -> &mut Self {
+        self.0.Scaling = scaling as i32;
         self
     }
 
-    pub fn with_mip_levels(mut self, mip_levels: u16) -> Self {
-        self.set_mip_levels(mip_levels);
+    pub fn with_scaling(mut self, scaling: ModeScaling) -> Self {
+        self.set_scaling(scaling);
         self
     }
 
-    pub fn mip_levels(&self) -> u16 {
-        self.0.MipLevels
+    pub fn scaling(&self) -> ModeScaling {
+        unsafe { std::mem::transmute(self.0.Scaling) }
     }
 
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
+    /// Swapchains are created in windowed mode by default
+    pub fn set_windowed(&mut self, windowed: bool) -> &mut Self {
+        self.0.Windowed = windowed as i32;
         self
     }
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
+    pub fn with_windowed(mut self, windowed: bool) -> Self {
+        self.set_windowed(windowed);
         self
     }
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
+    pub fn windowed(&self) -> bool {
+        self.0.Windowed != 0
     }
+}
 
-    pub fn set_sample_desc(&mut self, sample_desc: SampleDesc) -> &mut Self {
-        self.0.SampleDesc = sample_desc.0;
+/// Wrapper around DXGI_HDR_METADATA_HDR10 structure
+#[repr(transparent)]
+#[derive(Default, Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct HdrMetadataHdr10(pub(crate) DXGI_HDR_METADATA_HDR10);
+
+impl HdrMetadataHdr10 {
+    /// Chromaticity coordinates of the mastering display red primary
+    /// in 0.00002 units, e.g. `[35400, 14600]` for Rec. 2020
+    pub fn set_red_primary(&mut self, red_primary: [u16; 2]) -> &mut Self {
+        self.0.RedPrimary = red_primary;
         self
     }
 
-    pub fn with_sample_desc(mut self, sample_desc: SampleDesc) -> Self {
-        self.set_sample_desc(sample_desc);
+    pub fn with_red_primary(mut self, red_primary: [u16; 2]) -> Self {
+        self.set_red_primary(red_primary);
         self
     }
 
-    pub fn sample_desc(&self) -> SampleDesc {
-        SampleDesc(self.0.SampleDesc)
+    pub fn red_primary(&self) -> [u16; 2] {
+        self.0.RedPrimary
     }
 
-    pub fn set_layout(&mut self, layout: TextureLayout) -> &mut Self {
-        self.0.Layout = layout as i32;
+    pub fn set_green_primary(&mut self, green_primary: [u16; 2]) -> &mut Self {
+        self.0.GreenPrimary = green_primary;
         self
     }
 
-    pub fn with_layout(mut self, layout: TextureLayout) -> Self {
-        self.set_layout(layout);
+    pub fn with_green_primary(mut self, green_primary: [u16; 2]) -> Self {
+        self.set_green_primary(green_primary);
         self
     }
 
-    pub fn layout(&self) -> TextureLayout {
-        unsafe { std::mem::transmute(self.0.Layout) }
+    pub fn green_primary(&self) -> [u16; 2] {
+        self.0.GreenPrimary
     }
 
-    pub fn set_flags(&mut self, flags: ResourceFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    pub fn set_blue_primary(&mut self, blue_primary: [u16; 2]) -> &mut Self {
+        self.0.BluePrimary = blue_primary;
         self
     }
 
-    pub fn with_flags(mut self, flags: ResourceFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_blue_primary(mut self, blue_primary: [u16; 2]) -> Self {
+        self.set_blue_primary(blue_primary);
         self
     }
 
-    pub fn flags(&self) -> ResourceFlags {
-        unsafe { ResourceFlags::from_bits_unchecked(self.0.Flags) }
-    }
-}
-
-/// Wrapper around D3D12_MESSAGE structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone)]
-pub struct Message(pub(crate) D3D12_MESSAGE);
-
-impl Default for Message {
-    fn default() -> Self {
-        Message(D3D12_MESSAGE {
-            Category:
-                D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_MISCELLANEOUS,
-            Severity: D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_MESSAGE,
-            ID: 0,
-            pDescription: std::ptr::null(),
-            DescriptionByteLength: 0,
-        })
+    pub fn blue_primary(&self) -> [u16; 2] {
+        self.0.BluePrimary
     }
-}
-
-/// Wrapper around D3D12_HEAP_PROPERTIES structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
-#[repr(transparent)]
-pub struct HeapProperties(pub(crate) D3D12_HEAP_PROPERTIES);
 
-impl HeapProperties {
-    pub fn set_heap_type(&mut self, heap_type: HeapType) -> &mut Self {
-        self.0.Type = heap_type as i32;
+    pub fn set_white_point(&mut self, white_point: [u16; 2]) -> &mut Self {
+        self.0.WhitePoint = white_point;
         self
     }
 
-    pub fn with_heap_type(mut self, heap_type: HeapType) -> Self {
-        self.set_heap_type(heap_type);
+    pub fn with_white_point(mut self, white_point: [u16; 2]) -> Self {
+        self.set_white_point(white_point);
         self
     }
 
-    pub fn heap_type(&self) -> HeapType {
-        unsafe { std::mem::transmute(self.0.Type) }
+    pub fn white_point(&self) -> [u16; 2] {
+        self.0.WhitePoint
     }
 
-    pub fn set_cpu_page_property(
+    /// In 0.0001 nit units, e.g. 10_000_000 for a 1000 nit display
+    pub fn set_max_mastering_luminance(
         &mut self,
-        cpu_page_property: CpuPageProperty,
+        max_mastering_luminance: u32,
     ) -> &mut Self {
-        self.0.CPUPageProperty = cpu_page_property as i32;
+        self.0.MaxMasteringLuminance = max_mastering_luminance;
         self
     }
 
-    pub fn with_cpu_page_property(
+    pub fn with_max_mastering_luminance(
         mut self,
-        cpu_page_property: CpuPageProperty,
+        max_mastering_luminance: u32,
     ) -> Self {
-        self.set_cpu_page_property(cpu_page_property);
+        self.set_max_mastering_luminance(max_mastering_luminance);
         self
     }
 
-    pub fn cpu_page_property(&self) -> CpuPageProperty {
-        unsafe { std::mem::transmute(self.0.CPUPageProperty) }
+    pub fn max_mastering_luminance(&self) -> u32 {
+        self.0.MaxMasteringLuminance
     }
 
-    pub fn set_memory_pool_preference(
+    /// In 0.0001 nit units
+    pub fn set_min_mastering_luminance(
         &mut self,
-        memory_pool_preference: MemoryPool,
+        min_mastering_luminance: u32,
     ) -> &mut Self {
-        self.0.MemoryPoolPreference = memory_pool_preference as i32;
+        self.0.MinMasteringLuminance = min_mastering_luminance;
         self
     }
 
-    pub fn with_memory_pool_preference(
+    pub fn with_min_mastering_luminance(
         mut self,
-        memory_pool_preference: MemoryPool,
+        min_mastering_luminance: u32,
     ) -> Self {
-        self.set_memory_pool_preference(memory_pool_preference);
+        self.set_min_mastering_luminance(min_mastering_luminance);
         self
     }
 
-    pub fn memory_pool_preference(&self) -> MemoryPool {
-        unsafe { std::mem::transmute(self.0.MemoryPoolPreference) }
+    pub fn min_mastering_luminance(&self) -> u32 {
+        self.0.MinMasteringLuminance
     }
 
-    pub fn set_creation_node_mask(
+    /// Brightest pixel of the content in nits (MaxCLL)
+    pub fn set_max_content_light_level(
         &mut self,
-        creation_node_mask: u32,
+        max_content_light_level: u16,
     ) -> &mut Self {
-        self.0.CreationNodeMask = creation_node_mask;
+        self.0.MaxContentLightLevel = max_content_light_level;
         self
     }
 
-    pub fn with_creation_node_mask(mut self, creation_node_mask: u32) -> Self {
-        self.set_creation_node_mask(creation_node_mask);
+    pub fn with_max_content_light_level(
+        mut self,
+        max_content_light_level: u16,
+    ) -> Self {
+        self.set_max_content_light_level(max_content_light_level);
         self
     }
 
-    pub fn creation_node_mask(&self) -> u32 {
-        self.0.CreationNodeMask
+    pub fn max_content_light_level(&self) -> u16 {
+        self.0.MaxContentLightLevel
     }
 
-    pub fn set_visible_node_mask(
+    /// Brightest frame average of the content in nits (MaxFALL)
+    pub fn set_max_frame_average_light_level(
         &mut self,
-        visible_node_mask: u32,
+        max_frame_average_light_level: u16,
     ) -> &mut Self {
-        self.0.VisibleNodeMask = visible_node_mask;
+        self.0.MaxFrameAverageLightLevel = max_frame_average_light_level;
         self
     }
 
-    pub fn with_visible_node_mask(mut self, visible_node_mask: u32) -> Self {
-        self.set_visible_node_mask(visible_node_mask);
+    pub fn with_max_frame_average_light_level(
+        mut self,
+        max_frame_average_light_level: u16,
+    ) -> Self {
+        self.set_max_frame_average_light_level(max_frame_average_light_level);
         self
     }
 
-    pub fn visible_node_mask(&self) -> u32 {
-        self.0.VisibleNodeMask
+    pub fn max_frame_average_light_level(&self) -> u16 {
+        self.0.MaxFrameAverageLightLevel
     }
 }
 
-/// Wrapper around D3D12_RANGE structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+/// Wrapper around LUID structure. Identifies an adapter until the system
+/// is restarted, which is how external APIs (OpenXR, CUDA, video encoders)
+/// usually specify the adapter to use
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
 #[repr(transparent)]
-pub struct Range(pub(crate) D3D12_RANGE);
-
-impl Range {
-    pub fn set_begin(&mut self, begin: ByteCount) -> &mut Self {
-        self.0.Begin = begin.0;
-        self
-    }
+pub struct Luid(pub(crate) LUID);
 
-    pub fn with_begin(mut self, begin: ByteCount) -> Self {
-        self.set_begin(begin);
-        self
+impl Luid {
+    pub fn new(low_part: u32, high_part: i32) -> Self {
+        Self(LUID {
+            LowPart: low_part,
+            HighPart: high_part,
+        })
     }
 
-    pub fn begin(&self) -> ByteCount {
-        ByteCount(self.0.Begin)
+    pub fn low_part(&self) -> u32 {
+        self.0.LowPart
     }
 
-    pub fn set_end(&mut self, end: ByteCount) -> &mut Self {
-        self.0.End = end.0;
-        self
+    pub fn high_part(&self) -> i32 {
+        self.0.HighPart
     }
+}
 
-    pub fn with_end(mut self, end: ByteCount) -> Self {
-        self.set_end(end);
-        self
+impl From<u64> for Luid {
+    fn from(value: u64) -> Self {
+        Self::new(value as u32, (value >> 32) as i32)
     }
+}
 
-    pub fn end(&self) -> ByteCount {
-        ByteCount(self.0.End)
+impl From<Luid> for u64 {
+    fn from(luid: Luid) -> Self {
+        (luid.high_part() as u32 as u64) << 32 | luid.low_part() as u64
     }
 }
 
-// ToDo: impl Hash where it's needed but cannot be derived
-/// Wrapper around D3D12_RESOURCE_BARRIER structure. Note this type is not Clone since it contains a raw pointer
+/// Wrapper around DXGI_ADAPTER_DESC1 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
 #[repr(transparent)]
-#[derive(Debug)]
-pub struct ResourceBarrier(pub(crate) D3D12_RESOURCE_BARRIER);
+pub struct AdapterDesc(pub(crate) DXGI_ADAPTER_DESC1);
 
-impl ResourceBarrier {
-    pub fn barrier_type(&self) -> ResourceBarrierType {
-        unsafe { std::mem::transmute(self.0.Type) }
+impl AdapterDesc {
+    pub fn is_software(&self) -> bool {
+        self.0.Flags & DXGI_ADAPTER_FLAG_DXGI_ADAPTER_FLAG_SOFTWARE as u32 != 0
     }
 
-    pub fn set_flags(&mut self, flags: ResourceBarrierFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    // ToDo: clean up?
+    pub fn description(&self) -> Option<String> {
+        WideCStr::from_slice_with_nul(&self.0.Description)
+            .map(|wide_cstr| wide_cstr.to_string_lossy())
+            .ok()
+    }
+
+    pub fn set_vendor_id(&mut self, vendor_id: u32) -> &mut Self {
+        self.0.VendorId = vendor_id;
         self
     }
 
-    pub fn with_flags(mut self, flags: ResourceBarrierFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_vendor_id(mut self, vendor_id: u32) -> Self {
+        self.set_vendor_id(vendor_id);
         self
     }
 
-    pub fn flags(&self) -> ResourceBarrierFlags {
-        unsafe { ResourceBarrierFlags::from_bits_unchecked(self.0.Flags) }
+    pub fn vendor_id(&self) -> u32 {
+        self.0.VendorId
     }
 
-    // ToDo: rename it??
-    pub fn new_transition(desc: &ResourceTransitionBarrier) -> Self {
-        Self(D3D12_RESOURCE_BARRIER {
-            Type: ResourceBarrierType::Transition as i32,
-            Flags: ResourceBarrierFlags::None.bits(),
-            __bindgen_anon_1: D3D12_RESOURCE_BARRIER__bindgen_ty_1 {
-                Transition: desc.0,
-            },
-        })
+    pub fn set_device_id(&mut self, device_id: u32) -> &mut Self {
+        self.0.DeviceId = device_id;
+        self
     }
 
-    pub fn transition(&self) -> Option<ResourceTransitionBarrier> {
-        unsafe {
-            match self.barrier_type() {
-                ResourceBarrierType::Transition => {
-                    Some(ResourceTransitionBarrier(
-                        self.0.__bindgen_anon_1.Transition,
-                    ))
-                }
-                _ => None,
-            }
-        }
+    pub fn with_device_id(mut self, device_id: u32) -> Self {
+        self.set_device_id(device_id);
+        self
     }
 
-    pub fn new_aliasing(desc: &ResourceAliasingBarrier) -> Self {
-        Self(D3D12_RESOURCE_BARRIER {
-            Type: ResourceBarrierType::Aliasing as i32,
-            Flags: ResourceBarrierFlags::None.bits(),
-            __bindgen_anon_1: D3D12_RESOURCE_BARRIER__bindgen_ty_1 {
-                Aliasing: desc.0,
-            },
-        })
+    pub fn device_id(&self) -> u32 {
+        self.0.DeviceId
     }
 
-    pub fn aliasing(&self) -> Option<ResourceAliasingBarrier> {
-        unsafe {
-            match self.barrier_type() {
-                ResourceBarrierType::Aliasing => Some(ResourceAliasingBarrier(
-                    self.0.__bindgen_anon_1.Aliasing,
-                )),
-                _ => None,
-            }
-        }
+    pub fn adapter_luid(&self) -> Luid {
+        Luid(self.0.AdapterLuid)
     }
 
-    pub fn new_uav(desc: &ResourceUavBarrier) -> Self {
-        Self(D3D12_RESOURCE_BARRIER {
-            Type: ResourceBarrierType::Uav as i32,
-            Flags: ResourceBarrierFlags::None.bits(),
-            __bindgen_anon_1: D3D12_RESOURCE_BARRIER__bindgen_ty_1 {
-                UAV: desc.0,
-            },
-        })
-    }
-
-    pub fn uav(&self) -> Option<ResourceUavBarrier> {
-        unsafe {
-            match self.barrier_type() {
-                ResourceBarrierType::Uav => {
-                    Some(ResourceUavBarrier(self.0.__bindgen_anon_1.UAV))
-                }
-                _ => None,
-            }
-        }
-    }
-}
-
-/// Wrapper around D3D12_RESOURCE_TRANSITION_BARRIER structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
-#[repr(transparent)]
-pub struct ResourceTransitionBarrier(
-    pub(crate) D3D12_RESOURCE_TRANSITION_BARRIER,
-);
-
-impl ResourceTransitionBarrier {
-    pub fn set_resource(&mut self, resource: &Resource) -> &mut Self {
-        self.0.pResource = resource.this;
+    pub fn set_sub_sys_id(&mut self, sub_sys_id: u32) -> &mut Self {
+        self.0.SubSysId = sub_sys_id;
         self
     }
 
-    pub fn with_resource(mut self, resource: &Resource) -> Self {
-        self.set_resource(resource);
+    pub fn with_sub_sys_id(mut self, sub_sys_id: u32) -> Self {
+        self.set_sub_sys_id(sub_sys_id);
         self
     }
 
-    // ToDo: return reference?
-    pub fn resource(&self) -> Resource {
-        let resource = Resource {
-            this: self.0.pResource,
-        };
-        resource.add_ref();
-        resource
+    pub fn sub_sys_id(&self) -> u32 {
+        self.0.SubSysId
     }
 
-    // None value means "all subresources"
-    pub fn set_subresource(&mut self, subresource: Option<u32>) -> &mut Self {
-        match subresource {
-            Some(index) => self.0.Subresource = index,
-            None => {
-                self.0.Subresource = D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES
-            }
-        }
+    pub fn set_revision(&mut self, revision: u32) -> &mut Self {
+        self.0.Revision = revision;
         self
     }
 
-    pub fn with_subresource(mut self, subresource: Option<u32>) -> Self {
-        self.set_subresource(subresource);
+    pub fn with_revision(mut self, revision: u32) -> Self {
+        self.set_revision(revision);
         self
     }
 
-    pub fn subresource(&self) -> Option<u32> {
-        match self.0.Subresource {
-            D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES => None,
-            _ => Some(self.0.Subresource),
-        }
+    pub fn revision(&self) -> u32 {
+        self.0.Revision
     }
 
-    pub fn set_state_before(
+    pub fn set_dedicated_video_memory(
         &mut self,
-        state_before: ResourceStates,
+        dedicated_video_memory: u64,
     ) -> &mut Self {
-        self.0.StateBefore = state_before.bits();
+        self.0.DedicatedVideoMemory = dedicated_video_memory;
         self
     }
 
-    pub fn with_state_before(mut self, state_before: ResourceStates) -> Self {
-        self.set_state_before(state_before);
+    pub fn with_dedicated_video_memory(
+        mut self,
+        dedicated_video_memory: u64,
+    ) -> Self {
+        self.set_dedicated_video_memory(dedicated_video_memory);
         self
     }
 
-    pub fn state_before(&self) -> ResourceStates {
-        unsafe { ResourceStates::from_bits_unchecked(self.0.StateBefore) }
+    pub fn dedicated_video_memory(&self) -> u64 {
+        self.0.DedicatedVideoMemory
     }
 
-    pub fn set_state_after(
+    pub fn set_dedicated_system_memory(
         &mut self,
-        state_after: ResourceStates,
+        dedicated_system_memory: u64,
     ) -> &mut Self {
-        self.0.StateAfter = state_after.bits();
+        self.0.DedicatedSystemMemory = dedicated_system_memory;
         self
     }
 
-    pub fn with_state_after(mut self, state_after: ResourceStates) -> Self {
-        self.set_state_after(state_after);
+    pub fn with_dedicated_system_memory(
+        mut self,
+        dedicated_system_memory: u64,
+    ) -> Self {
+        self.set_dedicated_system_memory(dedicated_system_memory);
         self
     }
 
-    pub fn state_after(&self) -> ResourceStates {
-        unsafe { ResourceStates::from_bits_unchecked(self.0.StateAfter) }
+    pub fn dedicated_system_memory(&self) -> u64 {
+        self.0.DedicatedSystemMemory
     }
-}
-
-/// Wrapper around D3D12_RESOURCE_ALIASING_BARRIER structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
-#[repr(transparent)]
-pub struct ResourceAliasingBarrier(pub(crate) D3D12_RESOURCE_ALIASING_BARRIER);
 
-impl ResourceAliasingBarrier {
-    pub fn set_resource_before(
+    pub fn set_shared_system_memory(
         &mut self,
-        resource_before: &Resource,
+        shared_system_memory: u64,
     ) -> &mut Self {
-        self.0.pResourceBefore = resource_before.this;
+        self.0.SharedSystemMemory = shared_system_memory;
         self
     }
 
-    pub fn with_resource_before(mut self, resource_before: &Resource) -> Self {
-        self.set_resource_before(resource_before);
+    pub fn with_shared_system_memory(
+        mut self,
+        shared_system_memory: u64,
+    ) -> Self {
+        self.set_shared_system_memory(shared_system_memory);
         self
     }
 
-    pub fn resource_before(&self) -> Resource {
-        let resource = Resource {
-            this: self.0.pResourceBefore,
-        };
-        resource.add_ref();
-        resource
+    pub fn shared_system_memory(&self) -> u64 {
+        self.0.SharedSystemMemory
     }
 
-    pub fn set_resource_after(
-        &mut self,
-        resource_after: &Resource,
-    ) -> &mut Self {
-        self.0.pResourceAfter = resource_after.this;
+    pub fn set_flags(&mut self, flags: AdapterFlag) -> &mut Self {
+        self.0.Flags = flags as u32;
         self
     }
 
-    pub fn with_resource_after(mut self, resource_after: &Resource) -> Self {
-        self.set_resource_after(resource_after);
+    pub fn with_flags(mut self, flags: AdapterFlag) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn resource_after(&self) -> Resource {
-        let resource = Resource {
-            this: self.0.pResourceAfter,
-        };
-        resource.add_ref();
-        resource
+    pub fn flags(&self) -> AdapterFlag {
+        unsafe { std::mem::transmute(self.0.Flags) }
     }
 }
 
-/// Wrapper around D3D12_RESOURCE_UAV_BARRIER structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+/// Wrapper around DXGI_ADAPTER_DESC3 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
 #[repr(transparent)]
-pub struct ResourceUavBarrier(pub(crate) D3D12_RESOURCE_UAV_BARRIER);
+pub struct AdapterDesc3(pub(crate) DXGI_ADAPTER_DESC3);
 
-impl ResourceUavBarrier {
-    pub fn set_resource(&mut self, resource: &Resource) -> &mut Self {
-        self.0.pResource = resource.this;
-        self
+impl AdapterDesc3 {
+    pub fn description(&self) -> Option<String> {
+        WideCStr::from_slice_with_nul(&self.0.Description)
+            .map(|wide_cstr| wide_cstr.to_string_lossy())
+            .ok()
     }
 
-    pub fn with_resource(mut self, resource: &Resource) -> Self {
-        self.set_resource(resource);
-        self
+    pub fn vendor_id(&self) -> u32 {
+        self.0.VendorId
     }
 
-    pub fn resource(&self) -> Resource {
-        let resource = Resource {
-            this: self.0.pResource,
-        };
-        resource.add_ref();
-        resource
+    pub fn device_id(&self) -> u32 {
+        self.0.DeviceId
     }
-}
 
-/// Wrapper around D3D12_VIEWPORT structure
-#[derive(Debug, PartialOrd, PartialEq, Clone, Copy)]
-#[repr(transparent)]
-pub struct Viewport(pub(crate) D3D12_VIEWPORT);
+    pub fn adapter_luid(&self) -> Luid {
+        Luid(self.0.AdapterLuid)
+    }
 
-impl Default for Viewport {
-    fn default() -> Self {
-        Viewport(D3D12_VIEWPORT {
-            TopLeftX: 0.,
-            TopLeftY: 0.,
-            Width: 0.,
-            Height: 0.,
-            MinDepth: 0.,
-            MaxDepth: 1.,
-        })
+    pub fn sub_sys_id(&self) -> u32 {
+        self.0.SubSysId
     }
-}
 
-impl Viewport {
-    pub fn set_top_left_x(&mut self, top_left_x: f32) -> &mut Self {
-        self.0.TopLeftX = top_left_x;
-        self
+    pub fn revision(&self) -> u32 {
+        self.0.Revision
     }
 
-    pub fn with_top_left_x(mut self, top_left_x: f32) -> Self {
-        self.set_top_left_x(top_left_x);
-        self
+    pub fn dedicated_video_memory(&self) -> u64 {
+        self.0.DedicatedVideoMemory
     }
 
-    pub fn top_left_x(&self) -> f32 {
-        self.0.TopLeftX
+    pub fn dedicated_system_memory(&self) -> u64 {
+        self.0.DedicatedSystemMemory
     }
 
-    pub fn set_top_left_y(&mut self, top_left_y: f32) -> &mut Self {
-        self.0.TopLeftY = top_left_y;
-        self
+    pub fn shared_system_memory(&self) -> u64 {
+        self.0.SharedSystemMemory
     }
 
-    pub fn with_top_left_y(mut self, top_left_y: f32) -> Self {
-        self.set_top_left_y(top_left_y);
-        self
+    pub fn flags(&self) -> AdapterFlags3 {
+        AdapterFlags3::from_bits_truncate(self.0.Flags)
     }
 
-    pub fn top_left_y(&self) -> f32 {
-        self.0.TopLeftY
+    pub fn is_software(&self) -> bool {
+        self.flags().contains(AdapterFlags3::Software)
     }
 
-    pub fn set_width(&mut self, width: f32) -> &mut Self {
-        self.0.Width = width;
-        self
+    /// True if the driver is compatible with Arbitrary Code Guard, i.e.
+    /// it can be loaded into processes that forbid dynamic code generation
+    pub fn is_acg_compatible(&self) -> bool {
+        self.flags().contains(AdapterFlags3::AcgCompatible)
     }
 
-    pub fn with_width(mut self, width: f32) -> Self {
-        self.set_width(width);
-        self
+    /// The finest point at which the GPU can interrupt graphics work
+    /// to switch to another context
+    pub fn graphics_preemption_granularity(
+        &self,
+    ) -> GraphicsPreemptionGranularity {
+        unsafe { std::mem::transmute(self.0.GraphicsPreemptionGranularity) }
     }
 
-    pub fn width(&self) -> f32 {
-        self.0.Width
+    pub fn compute_preemption_granularity(
+        &self,
+    ) -> ComputePreemptionGranularity {
+        unsafe { std::mem::transmute(self.0.ComputePreemptionGranularity) }
     }
+}
 
-    pub fn set_height(&mut self, height: f32) -> &mut Self {
-        self.0.Height = height;
-        self
+impl Default for AdapterDesc3 {
+    fn default() -> Self {
+        AdapterDesc3(DXGI_ADAPTER_DESC3 {
+            Description: [0; 128],
+            VendorId: 0,
+            DeviceId: 0,
+            SubSysId: 0,
+            Revision: 0,
+            DedicatedVideoMemory: 0,
+            DedicatedSystemMemory: 0,
+            SharedSystemMemory: 0,
+            AdapterLuid: LUID {
+                LowPart: 0,
+                HighPart: 0,
+            },
+            Flags: 0,
+            GraphicsPreemptionGranularity: 0,
+            ComputePreemptionGranularity: 0,
+        })
     }
+}
 
-    pub fn with_height(mut self, height: f32) -> Self {
-        self.set_height(height);
-        self
+impl std::fmt::Debug for AdapterDesc3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdapterDesc3")
+            .field("description", &self.description())
+            .field("vendor_id", &self.vendor_id())
+            .field("device_id", &self.device_id())
+            .field("dedicated_video_memory", &self.dedicated_video_memory())
+            .field("flags", &self.flags())
+            .field(
+                "graphics_preemption_granularity",
+                &self.graphics_preemption_granularity(),
+            )
+            .field(
+                "compute_preemption_granularity",
+                &self.compute_preemption_granularity(),
+            )
+            .finish()
     }
+}
 
-    pub fn height(&self) -> f32 {
-        self.0.Height
-    }
+/// Wrapper around DXGI_QUERY_VIDEO_MEMORY_INFO structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct VideoMemoryInfo(pub(crate) DXGI_QUERY_VIDEO_MEMORY_INFO);
 
-    pub fn set_min_depth(&mut self, min_depth: f32) -> &mut Self {
-        self.0.MinDepth = min_depth;
+impl VideoMemoryInfo {
+    pub fn set_budget(&mut self, budget: ByteCount) -> &mut Self {
+        self.0.Budget = budget.0;
         self
     }
 
-    pub fn with_min_depth(mut self, min_depth: f32) -> Self {
-        self.set_min_depth(min_depth);
+    pub fn with_budget(mut self, budget: ByteCount) -> Self {
+        self.set_budget(budget);
         self
     }
 
-    pub fn min_depth(&self) -> f32 {
-        self.0.MinDepth
+    pub fn budget(&self) -> ByteCount {
+        ByteCount(self.0.Budget)
     }
 
-    pub fn set_max_depth(&mut self, max_depth: f32) -> &mut Self {
-        self.0.MaxDepth = max_depth;
+    pub fn set_current_usage(&mut self, current_usage: ByteCount) -> &mut Self {
+        self.0.CurrentUsage = current_usage.0;
         self
     }
 
-    pub fn with_max_depth(mut self, max_depth: f32) -> Self {
-        self.set_max_depth(max_depth);
+    pub fn with_current_usage(mut self, current_usage: ByteCount) -> Self {
+        self.set_current_usage(current_usage);
         self
     }
 
-    pub fn max_depth(&self) -> f32 {
-        self.0.MaxDepth
-    }
-}
-
-/// Wrapper around D3D12_RECT structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Debug)]
-#[repr(transparent)]
-pub struct Rect(pub(crate) D3D12_RECT);
-
-impl Default for Rect {
-    fn default() -> Self {
-        Rect(D3D12_RECT {
-            left: 0,
-            top: 0,
-            right: 0,
-            bottom: 0,
-        })
+    pub fn current_usage(&self) -> ByteCount {
+        ByteCount(self.0.CurrentUsage)
     }
-}
 
-impl Rect {
-    pub fn set_left(&mut self, left: i32) -> &mut Self {
-        self.0.left = left;
+    pub fn set_available_for_reservation(
+        &mut self,
+        available_for_reservation: ByteCount,
+    ) -> &mut Self {
+        self.0.AvailableForReservation = available_for_reservation.0;
         self
     }
 
-    pub fn with_left(mut self, left: i32) -> Self {
-        self.set_left(left);
+    pub fn with_available_for_reservation(
+        mut self,
+        available_for_reservation: ByteCount,
+    ) -> Self {
+        self.set_available_for_reservation(available_for_reservation);
         self
     }
 
-    pub fn left(&self) -> i32 {
-        self.0.left
+    pub fn available_for_reservation(&self) -> ByteCount {
+        ByteCount(self.0.AvailableForReservation)
     }
 
-    pub fn set_top(&mut self, top: i32) -> &mut Self {
-        self.0.top = top;
+    pub fn set_current_reservation(
+        &mut self,
+        current_reservation: ByteCount,
+    ) -> &mut Self {
+        self.0.CurrentReservation = current_reservation.0;
         self
     }
 
-    pub fn with_top(mut self, top: i32) -> Self {
-        self.set_top(top);
+    pub fn with_current_reservation(
+        mut self,
+        current_reservation: ByteCount,
+    ) -> Self {
+        self.set_current_reservation(current_reservation);
         self
     }
 
-    pub fn top(&self) -> i32 {
-        self.0.top
+    pub fn current_reservation(&self) -> ByteCount {
+        ByteCount(self.0.CurrentReservation)
     }
+}
 
-    pub fn set_right(&mut self, right: i32) -> &mut Self {
-        self.0.right = right;
-        self
+impl Default for AdapterDesc {
+    fn default() -> Self {
+        AdapterDesc(DXGI_ADAPTER_DESC1 {
+            Description: [0; 128],
+            VendorId: 0,
+            DeviceId: 0,
+            SubSysId: 0,
+            Revision: 0,
+            DedicatedVideoMemory: 0,
+            DedicatedSystemMemory: 0,
+            SharedSystemMemory: 0,
+            AdapterLuid: LUID {
+                LowPart: 0,
+                HighPart: 0,
+            },
+            Flags: 0,
+        })
     }
+}
 
-    pub fn with_right(mut self, right: i32) -> Self {
-        self.set_right(right);
-        self
+impl std::fmt::Display for AdapterDesc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            concat!(
+                "Description: {}, VendorId: {:x}, DeviceId: {:x}, ",
+                "SubSysId: {:x}, Revision: {:x}, DedicatedVideoMemory: {}, ",
+                "DedicatedSystemMemory: {}, SharedSystemMemory: {}, ",
+                "AdapterLuid.LowPart: {:x}, AdapterLuid.HighPart: {:x}, Flags: {:x}"
+            ),
+            WideCStr::from_slice_with_nul(&self.0.Description)
+                .expect("Adapter desc is not valid utf-16")
+                .to_string_lossy(),
+            self.0.VendorId,
+            self.0.DeviceId,
+            self.0.SubSysId,
+            self.0.Revision,
+            self.0.DedicatedVideoMemory,
+            self.0.DedicatedSystemMemory,
+            self.0.SharedSystemMemory,
+            self.0.AdapterLuid.LowPart,
+            self.0.AdapterLuid.HighPart,
+            self.0.Flags
+        )
     }
+}
 
-    pub fn right(&self) -> i32 {
-        self.0.right
+impl std::fmt::Debug for AdapterDesc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
     }
+}
 
-    pub fn set_bottom(&mut self, bottom: i32) -> &mut Self {
-        self.0.bottom = bottom;
-        self
+/// Wrapper around DXGI_SAMPLE_DESC structure
+#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct SampleDesc(pub(crate) DXGI_SAMPLE_DESC);
+
+impl Default for SampleDesc {
+    fn default() -> Self {
+        Self(DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        })
     }
+}
 
-    pub fn with_bottom(mut self, bottom: i32) -> Self {
-        self.set_bottom(bottom);
+impl SampleDesc {
+    pub fn set_count(&mut self, count: u32) -> &mut Self {
+        self.0.Count = count;
         self
     }
 
-    pub fn bottom(&self) -> i32 {
-        self.0.bottom
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.set_count(count);
+        self
     }
-}
-
-/// Wrapper around D3D12_TEXTURE_COPY_LOCATION structure
-// ToDo: add lifetime since we're taking `this` from a Resource?
-#[repr(transparent)]
-#[derive(Debug)]
-pub struct TextureCopyLocation(pub(crate) D3D12_TEXTURE_COPY_LOCATION);
 
-impl TextureCopyLocation {
-    pub fn new_placed_footprint(
-        resource: &Resource,
-        footprint: PlacedSubresourceFootprint,
-    ) -> Self {
-        Self(D3D12_TEXTURE_COPY_LOCATION {
-            pResource: resource.this,
-            Type: TextureCopyType::PlacedFootprint as i32,
-            __bindgen_anon_1: D3D12_TEXTURE_COPY_LOCATION__bindgen_ty_1 {
-                PlacedFootprint: footprint.0,
-            },
-        })
+    pub fn count(&self) -> u32 {
+        self.0.Count
     }
 
-    pub fn new_subresource_index(resource: &Resource, index: u32) -> Self {
-        Self(D3D12_TEXTURE_COPY_LOCATION {
-            pResource: resource.this,
-            Type: TextureCopyType::SubresourceIndex as i32,
-            __bindgen_anon_1: D3D12_TEXTURE_COPY_LOCATION__bindgen_ty_1 {
-                SubresourceIndex: index,
-            },
-        })
+    pub fn set_quality(&mut self, quality: u32) -> &mut Self {
+        self.0.Quality = quality;
+        self
     }
 
-    pub fn resource(&self) -> Resource {
-        let resource = Resource {
-            this: self.0.pResource,
-        };
-        resource.add_ref();
-        resource
+    pub fn with_quality(mut self, quality: u32) -> Self {
+        self.set_quality(quality);
+        self
     }
 
-    pub fn copy_type(&self) -> TextureCopyType {
-        unsafe { std::mem::transmute(self.0.Type) }
+    pub fn quality(&self) -> u32 {
+        self.0.Quality
     }
 }
 
-/// Wrapper around D3D12_BOX structure
-#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+/// Wrapper around D3D12_RESOURCE_DESC structure
 #[repr(transparent)]
-pub struct Box(pub(crate) D3D12_BOX);
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct ResourceDesc(pub(crate) D3D12_RESOURCE_DESC);
 
-impl Default for Box {
+impl Default for ResourceDesc {
     fn default() -> Self {
-        Self(D3D12_BOX {
-            left: 0,
-            top: 0,
-            front: 0,
-            right: 0,
-            bottom: 1,
-            back: 1,
+        ResourceDesc(D3D12_RESOURCE_DESC {
+            Dimension: ResourceDimension::Unknown as i32,
+            Alignment: D3D12_DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT as u64,
+            Width: 0,
+            Height: 1,
+            DepthOrArraySize: 1,
+            MipLevels: 1,
+            Format: Format::Unknown as i32,
+            SampleDesc: SampleDesc::default().0,
+            Layout: TextureLayout::Unknown as i32,
+            Flags: ResourceFlags::None.bits(),
         })
     }
 }
 
-impl Box {
-    pub fn set_left(&mut self, left: u32) -> &mut Self {
-        self.0.left = left;
+impl ResourceDesc {
+    pub fn set_dimension(&mut self, dimension: ResourceDimension) -> &mut Self {
+        self.0.Dimension = dimension as i32;
         self
     }
 
-    pub fn with_left(mut self, left: u32) -> Self {
-        self.set_left(left);
+    pub fn with_dimension(mut self, dimension: ResourceDimension) -> Self {
+        self.set_dimension(dimension);
         self
     }
 
-    pub fn left(&self) -> u32 {
-        self.0.left
+    pub fn dimension(&self) -> ResourceDimension {
+        unsafe { std::mem::transmute(self.0.Dimension) }
     }
 
-    pub fn set_top(&mut self, top: u32) -> &mut Self {
-        self.0.top = top;
+    pub fn set_alignment(&mut self, alignment: ByteCount) -> &mut Self {
+        self.0.Alignment = alignment.0;
         self
     }
 
-    pub fn with_top(mut self, top: u32) -> Self {
-        self.set_top(top);
+    pub fn with_alignment(mut self, alignment: ByteCount) -> Self {
+        self.set_alignment(alignment);
         self
     }
 
-    pub fn top(&self) -> u32 {
-        self.0.top
+    pub fn alignment(&self) -> ByteCount {
+        ByteCount(self.0.Alignment)
     }
 
-    pub fn set_front(&mut self, front: u32) -> &mut Self {
-        self.0.front = front;
+    pub fn set_width(&mut self, width: u64) -> &mut Self {
+        self.0.Width = width;
         self
     }
 
-    pub fn with_front(mut self, front: u32) -> Self {
-        self.set_front(front);
+    pub fn with_width(mut self, width: u64) -> Self {
+        self.set_width(width);
         self
     }
 
-    pub fn front(&self) -> u32 {
-        self.0.front
+    pub fn width(&self) -> u64 {
+        self.0.Width
     }
 
-    pub fn set_right(&mut self, right: u32) -> &mut Self {
-        self.0.right = right;
+    pub fn set_height(&mut self, height: u32) -> &mut Self {
+        self.0.Height = height;
         self
     }
 
-    pub fn with_right(mut self, right: u32) -> Self {
-        self.set_right(right);
+    pub fn with_height(mut self, height: u32) -> Self {
+        self.set_height(height);
         self
     }
 
-    pub fn right(&self) -> u32 {
-        self.0.right
+    pub fn height(&self) -> u32 {
+        self.0.Height
     }
 
-    pub fn set_bottom(&mut self, bottom: u32) -> &mut Self {
-        self.0.bottom = bottom;
+    pub fn set_depth_or_array_size(
+        &mut self,
+        depth_or_array_size: u16,
+    ) -> &mut Self {
+        self.0.DepthOrArraySize = depth_or_array_size;
         self
     }
 
-    pub fn with_bottom(mut self, bottom: u32) -> Self {
-        self.set_bottom(bottom);
+    pub fn with_depth_or_array_size(
+        mut self,
+        depth_or_array_size: u16,
+    ) -> Self {
+        self.set_depth_or_array_size(depth_or_array_size);
         self
     }
 
-    pub fn bottom(&self) -> u32 {
-        self.0.bottom
+    pub fn depth_or_array_size(&self) -> u16 {
+        self.0.DepthOrArraySize
     }
 
-    pub fn set_back(&mut self, back: u32) -> &mut Self {
-        self.0.back = back;
-        self
+    /// Number of array slices, which is 1 for buffers and 3D textures
+    pub fn array_size(&self) -> u32 {
+        match self.dimension() {
+            ResourceDimension::Texture3D => 1,
+            _ => self.0.DepthOrArraySize as u32,
+        }
     }
 
-    pub fn with_back(mut self, back: u32) -> Self {
-        self.set_back(back);
+    pub fn set_mip_levels(&mut self, mip_levels: u16) -> &mut Self {
+        self.0.MipLevels = mip_levels;
         self
     }
 
-    pub fn back(&self) -> u32 {
-        self.0.back
+    pub fn with_mip_levels(mut self, mip_levels: u16) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
     }
-}
 
-/// Wrapper around D3D12_VERTEX_BUFFER_VIEW structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
-#[repr(transparent)]
-pub struct VertexBufferView(pub(crate) D3D12_VERTEX_BUFFER_VIEW);
+    pub fn mip_levels(&self) -> u16 {
+        self.0.MipLevels
+    }
 
-impl VertexBufferView {
-    pub fn set_buffer_location(
-        &mut self,
-        buffer_location: GpuVirtualAddress,
-    ) -> &mut Self {
-        self.0.BufferLocation = buffer_location.0;
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
         self
     }
 
-    pub fn with_buffer_location(
-        mut self,
-        buffer_location: GpuVirtualAddress,
-    ) -> Self {
-        self.set_buffer_location(buffer_location);
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
         self
     }
 
-    pub fn buffer_location(&self) -> GpuVirtualAddress {
-        GpuVirtualAddress(self.0.BufferLocation)
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
     }
 
-    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
-        self.0.SizeInBytes = size_in_bytes.0 as u32;
+    pub fn set_sample_desc(&mut self, sample_desc: SampleDesc) -> &mut Self {
+        self.0.SampleDesc = sample_desc.0;
         self
     }
 
-    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
-        self.set_size_in_bytes(size_in_bytes);
+    pub fn with_sample_desc(mut self, sample_desc: SampleDesc) -> Self {
+        self.set_sample_desc(sample_desc);
         self
     }
 
-    pub fn size_in_bytes(&self) -> ByteCount {
-        ByteCount::from(self.0.SizeInBytes)
+    pub fn sample_desc(&self) -> SampleDesc {
+        SampleDesc(self.0.SampleDesc)
     }
 
-    pub fn set_stride_in_bytes(
-        &mut self,
-        stride_in_bytes: ByteCount,
-    ) -> &mut Self {
-        self.0.StrideInBytes = stride_in_bytes.0 as u32;
+    pub fn set_layout(&mut self, layout: TextureLayout) -> &mut Self {
+        self.0.Layout = layout as i32;
         self
     }
 
-    pub fn with_stride_in_bytes(mut self, stride_in_bytes: ByteCount) -> Self {
-        self.set_stride_in_bytes(stride_in_bytes);
+    pub fn with_layout(mut self, layout: TextureLayout) -> Self {
+        self.set_layout(layout);
         self
     }
 
-    pub fn stride_in_bytes(&self) -> ByteCount {
-        ByteCount::from(self.0.StrideInBytes)
+    pub fn layout(&self) -> TextureLayout {
+        unsafe { std::mem::transmute(self.0.Layout) }
     }
-}
 
-/// Wrapper around D3D12_INPUT_ELEMENT_DESC structure
-#[repr(transparent)]
-#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
-pub struct InputElementDesc<'a>(
-    pub D3D12_INPUT_ELEMENT_DESC,
-    PhantomData<&'a CStr>,
-);
+    pub fn set_flags(&mut self, flags: ResourceFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
+        self
+    }
 
-impl<'a> Default for InputElementDesc<'a> {
-    fn default() -> InputElementDesc<'a> {
-        InputElementDesc(D3D12_INPUT_ELEMENT_DESC {
-            SemanticName: std::ptr::null(),
-            SemanticIndex: 0,
-            Format: Format::Unknown as i32,
-            InputSlot: 0,
-            AlignedByteOffset: 0,
-            InputSlotClass:
-        D3D12_INPUT_CLASSIFICATION_D3D12_INPUT_CLASSIFICATION_PER_VERTEX_DATA,
-            InstanceDataStepRate: 0,
-        },
-        PhantomData
-    )
+    pub fn with_flags(mut self, flags: ResourceFlags) -> Self {
+        self.set_flags(flags);
+        self
     }
-}
 
-// ToDo: macro for generating input element desc from vertex struct type?
+    pub fn flags(&self) -> ResourceFlags {
+        unsafe { ResourceFlags::from_bits_unchecked(self.0.Flags) }
+    }
 
-impl<'a> InputElementDesc<'a> {
-    pub fn set_semantic_name(
-        &mut self,
-        name: &'a str,
-    ) -> Result<&mut Self, NulError> {
-        let owned = CString::new(name)?;
-        self.0.SemanticName = owned.into_raw() as *const i8;
-        self.1 = PhantomData;
+    /// Checks the description against the limits that don't depend on
+    /// the device: dimensions required for buffers, maximal texture
+    /// sizes, mip chain length, MSAA and flag combinations
+    pub fn validate(&self) -> DxResult<()> {
+        self.check()
+            .map_err(|message| invalid_desc("ResourceDesc::validate", message))
+    }
+
+    /// Finishes building the description, see [ResourceDesc::validate]
+    pub fn finish(self) -> DxResult<Self> {
+        self.validate()?;
         Ok(self)
     }
 
-    pub fn with_semantic_name(
-        mut self,
-        name: &'a str,
-    ) -> Result<Self, NulError> {
-        match self.set_semantic_name(name) {
-            Ok(_) => Ok(self),
-            Err(err) => Err(err),
+    fn check(&self) -> Result<(), String> {
+        let flags = self.flags();
+        if flags.contains(
+            ResourceFlags::AllowDepthStencil
+                | ResourceFlags::AllowUnorderedAccess,
+        ) {
+            return Err(String::from(
+                "depth stencil resources cannot allow unordered access",
+            ));
         }
-    }
 
-    pub fn semantic_name(&self) -> Result<&'a str, Utf8Error> {
-        Ok(unsafe { std::ffi::CStr::from_ptr(self.0.SemanticName).to_str()? })
+        let sample_desc = self.sample_desc();
+        let alignment = self.alignment();
+        match self.dimension() {
+            ResourceDimension::Unknown => {
+                Err(String::from("resource dimension is not set"))
+            }
+            ResourceDimension::Buffer => {
+                if self.width() == 0 {
+                    return Err(String::from("buffer width is zero"));
+                }
+                if self.height() != 1
+                    || self.depth_or_array_size() != 1
+                    || self.mip_levels() != 1
+                {
+                    return Err(format!(
+                        "buffers must have height, depth_or_array_size and \
+                         mip_levels of 1, got {}, {} and {}",
+                        self.height(),
+                        self.depth_or_array_size(),
+                        self.mip_levels()
+                    ));
+                }
+                if !matches!(self.format(), Format::Unknown) {
+                    return Err(format!(
+                        "buffers must have unknown format, got {:?}",
+                        self.format()
+                    ));
+                }
+                if !matches!(self.layout(), TextureLayout::RowMajor) {
+                    return Err(format!(
+                        "buffers must have row major layout, got {:?}",
+                        self.layout()
+                    ));
+                }
+                if sample_desc.count() != 1 || sample_desc.quality() != 0 {
+                    return Err(String::from("buffers cannot be multisampled"));
+                }
+                if flags.intersects(
+                    ResourceFlags::AllowRenderTarget
+                        | ResourceFlags::AllowDepthStencil,
+                ) {
+                    return Err(String::from(
+                        "buffers cannot be render targets or depth stencils",
+                    ));
+                }
+                if alignment.0 != 0
+                    && alignment != DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT
+                {
+                    return Err(format!(
+                        "buffer alignment must be 0 or {}, got {}",
+                        DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT.0, alignment.0
+                    ));
+                }
+
+                Ok(())
+            }
+            dimension => {
+                let width = self.width();
+                let height = self.height() as u64;
+                let depth_or_array_size = self.depth_or_array_size() as u64;
+                if width == 0 || height == 0 || depth_or_array_size == 0 {
+                    return Err(format!(
+                        "texture dimensions must be non-zero, got \
+                         {}x{}x{}",
+                        width, height, depth_or_array_size
+                    ));
+                }
+                if matches!(self.format(), Format::Unknown) {
+                    return Err(String::from("texture format is not set"));
+                }
+
+                // (max width and height, max depth or array size)
+                let (max_extent, max_depth_or_array_size, depth) =
+                    match dimension {
+                        ResourceDimension::Texture1D => {
+                            if height != 1 {
+                                return Err(format!(
+                                    "1D textures must have height of 1, \
+                                     got {}",
+                                    height
+                                ));
+                            }
+                            (
+                                REQ_TEXTURE1D_U_DIMENSION,
+                                REQ_TEXTURE1D_ARRAY_AXIS_DIMENSION,
+                                1,
+                            )
+                        }
+                        ResourceDimension::Texture2D => (
+                            REQ_TEXTURE2D_U_OR_V_DIMENSION,
+                            REQ_TEXTURE2D_ARRAY_AXIS_DIMENSION,
+                            1,
+                        ),
+                        _ => (
+                            REQ_TEXTURE3D_U_V_OR_W_DIMENSION,
+                            REQ_TEXTURE3D_U_V_OR_W_DIMENSION,
+                            depth_or_array_size,
+                        ),
+                    };
+                if width > max_extent as u64
+                    || height > max_extent as u64
+                    || depth_or_array_size > max_depth_or_array_size as u64
+                {
+                    return Err(format!(
+                        "{:?} of {}x{}x{} exceeds the maximal size of \
+                         {}x{}x{}",
+                        dimension,
+                        width,
+                        height,
+                        depth_or_array_size,
+                        max_extent,
+                        max_extent,
+                        max_depth_or_array_size
+                    ));
+                }
+
+                let largest_extent = width.max(height).max(depth);
+                let max_mip_levels = 64 - largest_extent.leading_zeros();
+                if self.mip_levels() as u32 > max_mip_levels {
+                    return Err(format!(
+                        "{} mip levels requested, but a {}x{}x{} texture \
+                         can have at most {}",
+                        self.mip_levels(),
+                        width,
+                        height,
+                        depth,
+                        max_mip_levels
+                    ));
+                }
+
+                if sample_desc.count() > 1 {
+                    if !matches!(dimension, ResourceDimension::Texture2D) {
+                        return Err(String::from(
+                            "only 2D textures can be multisampled",
+                        ));
+                    }
+                    if self.mip_levels() != 1 {
+                        return Err(String::from(
+                            "multisampled textures must have a single mip",
+                        ));
+                    }
+                    if !flags.intersects(
+                        ResourceFlags::AllowRenderTarget
+                            | ResourceFlags::AllowDepthStencil,
+                    ) {
+                        return Err(String::from(
+                            "multisampled textures must allow render \
+                             target or depth stencil usage",
+                        ));
+                    }
+                }
+
+                if alignment.0 != 0
+                    && alignment != SMALL_RESOURCE_PLACEMENT_ALIGNMENT
+                    && alignment != DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT
+                    && alignment != DEFAULT_MSAA_RESOURCE_PLACEMENT_ALIGNMENT
+                {
+                    return Err(format!(
+                        "texture alignment must be 0, {}, {} or {}, got {}",
+                        SMALL_RESOURCE_PLACEMENT_ALIGNMENT.0,
+                        DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT.0,
+                        DEFAULT_MSAA_RESOURCE_PLACEMENT_ALIGNMENT.0,
+                        alignment.0
+                    ));
+                }
+
+                Ok(())
+            }
+        }
     }
+}
 
-    pub fn set_semantic_index(&mut self, semantic_index: u32) -> &mut Self {
-        self.0.SemanticIndex = semantic_index;
-        self
+/// Wrapper around D3D12_MIP_REGION structure
+#[repr(transparent)]
+#[derive(Default, Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct MipRegion(pub(crate) D3D12_MIP_REGION);
+
+impl MipRegion {
+    pub fn new(width: u32, height: u32, depth: u32) -> Self {
+        Self(D3D12_MIP_REGION {
+            Width: width,
+            Height: height,
+            Depth: depth,
+        })
     }
 
-    pub fn with_semantic_index(mut self, semantic_index: u32) -> Self {
-        self.set_semantic_index(semantic_index);
-        self
+    pub fn width(&self) -> u32 {
+        self.0.Width
     }
 
-    pub fn semantic_index(&self) -> u32 {
-        self.0.SemanticIndex
+    pub fn height(&self) -> u32 {
+        self.0.Height
     }
 
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
-        self
+    pub fn depth(&self) -> u32 {
+        self.0.Depth
     }
+}
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
-        self
+/// Wrapper around D3D12_RESOURCE_DESC1 structure. It only adds the mip
+/// region of sampler feedback maps to [ResourceDesc], so the rest
+/// of the fields are accessed through [ResourceDesc1::desc]
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct ResourceDesc1(pub(crate) D3D12_RESOURCE_DESC1);
+
+impl Default for ResourceDesc1 {
+    fn default() -> Self {
+        ResourceDesc::default().into()
     }
+}
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
+impl From<ResourceDesc> for ResourceDesc1 {
+    fn from(desc: ResourceDesc) -> Self {
+        Self(D3D12_RESOURCE_DESC1 {
+            Dimension: desc.0.Dimension,
+            Alignment: desc.0.Alignment,
+            Width: desc.0.Width,
+            Height: desc.0.Height,
+            DepthOrArraySize: desc.0.DepthOrArraySize,
+            MipLevels: desc.0.MipLevels,
+            Format: desc.0.Format,
+            SampleDesc: desc.0.SampleDesc,
+            Layout: desc.0.Layout,
+            Flags: desc.0.Flags,
+            SamplerFeedbackMipRegion: MipRegion::default().0,
+        })
     }
+}
 
-    pub fn set_input_slot(&mut self, input_slot: u32) -> &mut Self {
-        self.0.InputSlot = input_slot;
-        self
+impl ResourceDesc1 {
+    pub fn set_desc(&mut self, desc: ResourceDesc) -> &mut Self {
+        let sampler_feedback_mip_region = self.sampler_feedback_mip_region();
+        *self = desc.into();
+        self.set_sampler_feedback_mip_region(sampler_feedback_mip_region)
     }
 
-    pub fn with_input_slot(mut self, input_slot: u32) -> Self {
-        self.set_input_slot(input_slot);
+    pub fn with_desc(mut self, desc: ResourceDesc) -> Self {
+        self.set_desc(desc);
         self
     }
 
-    pub fn input_slot(&self) -> u32 {
-        self.0.InputSlot
+    pub fn desc(&self) -> ResourceDesc {
+        ResourceDesc(D3D12_RESOURCE_DESC {
+            Dimension: self.0.Dimension,
+            Alignment: self.0.Alignment,
+            Width: self.0.Width,
+            Height: self.0.Height,
+            DepthOrArraySize: self.0.DepthOrArraySize,
+            MipLevels: self.0.MipLevels,
+            Format: self.0.Format,
+            SampleDesc: self.0.SampleDesc,
+            Layout: self.0.Layout,
+            Flags: self.0.Flags,
+        })
     }
 
-    pub fn set_aligned_byte_offset(
+    pub fn set_sampler_feedback_mip_region(
         &mut self,
-        aligned_byte_offset: ByteCount,
+        sampler_feedback_mip_region: MipRegion,
     ) -> &mut Self {
-        self.0.AlignedByteOffset = aligned_byte_offset.0 as u32;
+        self.0.SamplerFeedbackMipRegion = sampler_feedback_mip_region.0;
         self
     }
 
-    pub fn with_aligned_byte_offset(
+    pub fn with_sampler_feedback_mip_region(
         mut self,
-        aligned_byte_offset: ByteCount,
+        sampler_feedback_mip_region: MipRegion,
     ) -> Self {
-        self.set_aligned_byte_offset(aligned_byte_offset);
+        self.set_sampler_feedback_mip_region(sampler_feedback_mip_region);
         self
     }
 
-    pub fn aligned_byte_offset(&self) -> ByteCount {
-        ByteCount::from(self.0.AlignedByteOffset)
+    pub fn sampler_feedback_mip_region(&self) -> MipRegion {
+        MipRegion(self.0.SamplerFeedbackMipRegion)
     }
+}
 
-    pub fn set_input_slot_class(
-        &mut self,
-        input_slot_class: InputClassification,
-    ) -> &mut Self {
-        self.0.InputSlotClass = input_slot_class as i32;
+/// Wrapper around D3D12_MESSAGE structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone)]
+pub struct Message(pub(crate) D3D12_MESSAGE);
+
+impl Default for Message {
+    fn default() -> Self {
+        Message(D3D12_MESSAGE {
+            Category:
+                D3D12_MESSAGE_CATEGORY_D3D12_MESSAGE_CATEGORY_MISCELLANEOUS,
+            Severity: D3D12_MESSAGE_SEVERITY_D3D12_MESSAGE_SEVERITY_MESSAGE,
+            ID: 0,
+            pDescription: std::ptr::null(),
+            DescriptionByteLength: 0,
+        })
+    }
+}
+
+/// Wrapper around D3D12_HEAP_PROPERTIES structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+#[repr(transparent)]
+pub struct HeapProperties(pub(crate) D3D12_HEAP_PROPERTIES);
+
+impl HeapProperties {
+    pub fn set_heap_type(&mut self, heap_type: HeapType) -> &mut Self {
+        self.0.Type = heap_type as i32;
         self
     }
 
-    pub fn with_input_slot_class(
-        mut self,
-        input_slot_class: InputClassification,
-    ) -> Self {
-        self.set_input_slot_class(input_slot_class);
+    pub fn with_heap_type(mut self, heap_type: HeapType) -> Self {
+        self.set_heap_type(heap_type);
         self
     }
 
-    pub fn input_slot_class(&self) -> InputClassification {
-        unsafe { std::mem::transmute(self.0.InputSlotClass) }
+    pub fn heap_type(&self) -> HeapType {
+        unsafe { std::mem::transmute(self.0.Type) }
     }
 
-    pub fn set_instance_data_step_rate(
+    pub fn set_cpu_page_property(
         &mut self,
-        instance_data_step_rate: u32,
+        cpu_page_property: CpuPageProperty,
     ) -> &mut Self {
-        self.0.InstanceDataStepRate = instance_data_step_rate;
+        self.0.CPUPageProperty = cpu_page_property as i32;
         self
     }
 
-    pub fn with_instance_data_step_rate(
+    pub fn with_cpu_page_property(
         mut self,
-        instance_data_step_rate: u32,
+        cpu_page_property: CpuPageProperty,
     ) -> Self {
-        self.set_instance_data_step_rate(instance_data_step_rate);
+        self.set_cpu_page_property(cpu_page_property);
         self
     }
 
-    pub fn instance_data_step_rate(&self) -> u32 {
-        self.0.InstanceDataStepRate
-    }
-}
-
-// We need this because we transfer ownership of the CString "name" into
-// the raw C string (const char*) "SemanticName". Since this memory has to be
-// valid until the destruction of this struct, we need to regain that memory
-// back so it can be destroyed correctly
-impl<'a> Drop for InputElementDesc<'a> {
-    fn drop(&mut self) {
-        unsafe {
-            let _regained_name = CString::from_raw(
-                self.0.SemanticName as *mut std::os::raw::c_char,
-            );
-        }
+    pub fn cpu_page_property(&self) -> CpuPageProperty {
+        unsafe { std::mem::transmute(self.0.CPUPageProperty) }
     }
-}
-
-/// Wrapper around D3D12_INDEX_BUFFER_VIEW structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
-#[repr(transparent)]
-pub struct IndexBufferView(pub(crate) D3D12_INDEX_BUFFER_VIEW);
 
-impl IndexBufferView {
-    pub fn set_buffer_location(
+    pub fn set_memory_pool_preference(
         &mut self,
-        buffer_location: GpuVirtualAddress,
+        memory_pool_preference: MemoryPool,
     ) -> &mut Self {
-        self.0.BufferLocation = buffer_location.0;
+        self.0.MemoryPoolPreference = memory_pool_preference as i32;
         self
     }
 
-    pub fn with_buffer_location(
+    pub fn with_memory_pool_preference(
         mut self,
-        buffer_location: GpuVirtualAddress,
+        memory_pool_preference: MemoryPool,
     ) -> Self {
-        self.set_buffer_location(buffer_location);
+        self.set_memory_pool_preference(memory_pool_preference);
         self
     }
 
-    pub fn buffer_location(&self) -> GpuVirtualAddress {
-        GpuVirtualAddress(self.0.BufferLocation)
+    pub fn memory_pool_preference(&self) -> MemoryPool {
+        unsafe { std::mem::transmute(self.0.MemoryPoolPreference) }
     }
 
-    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
-        self.0.SizeInBytes = size_in_bytes.0 as u32;
+    pub fn set_creation_node_mask(
+        &mut self,
+        creation_node_mask: u32,
+    ) -> &mut Self {
+        self.0.CreationNodeMask = creation_node_mask;
         self
     }
 
-    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
-        self.set_size_in_bytes(size_in_bytes);
+    pub fn with_creation_node_mask(mut self, creation_node_mask: u32) -> Self {
+        self.set_creation_node_mask(creation_node_mask);
         self
     }
 
-    pub fn size_in_bytes(&self) -> ByteCount {
-        ByteCount::from(self.0.SizeInBytes)
+    pub fn creation_node_mask(&self) -> u32 {
+        self.0.CreationNodeMask
     }
 
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
+    pub fn set_visible_node_mask(
+        &mut self,
+        visible_node_mask: u32,
+    ) -> &mut Self {
+        self.0.VisibleNodeMask = visible_node_mask;
         self
     }
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
+    pub fn with_visible_node_mask(mut self, visible_node_mask: u32) -> Self {
+        self.set_visible_node_mask(visible_node_mask);
         self
     }
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
+    pub fn visible_node_mask(&self) -> u32 {
+        self.0.VisibleNodeMask
     }
-}
 
-/// Wrapper around D3D12_SHADER_BYTECODE structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
-pub struct ShaderBytecode<'a>(
-    pub(crate) D3D12_SHADER_BYTECODE,
-    PhantomData<&'a [u8]>,
-);
+    /// Sets both node masks so that the heap is created on and visible
+    /// to a single node; fails if `node_index` is not below
+    /// [Device::get_node_count]
+    pub fn set_node_affinity(
+        &mut self,
+        device: &Device,
+        node_index: u32,
+    ) -> DxResult<&mut Self> {
+        let node_count = device.get_node_count();
+        if node_index >= node_count {
+            return Err(invalid_desc(
+                "HeapProperties::set_node_affinity",
+                format!(
+                    "node index {} is out of range, the device has {} node(s)",
+                    node_index, node_count
+                ),
+            ));
+        }
 
-impl<'a> Default for ShaderBytecode<'a> {
-    fn default() -> ShaderBytecode<'a> {
-        ShaderBytecode(
-            D3D12_SHADER_BYTECODE {
-                pShaderBytecode: std::ptr::null(),
-                BytecodeLength: 0,
-            },
-            PhantomData,
-        )
+        self.set_creation_node_mask(1 << node_index);
+        self.set_visible_node_mask(1 << node_index);
+        Ok(self)
     }
-}
 
-impl<'a> ShaderBytecode<'a> {
-    pub fn new(data: &'a [u8]) -> ShaderBytecode<'a> {
-        Self(
-            D3D12_SHADER_BYTECODE {
-                pShaderBytecode: data.as_ptr() as *const std::ffi::c_void,
-                BytecodeLength: data.len() as u64,
-            },
-            PhantomData,
-        )
+    pub fn with_node_affinity(
+        mut self,
+        device: &Device,
+        node_index: u32,
+    ) -> DxResult<Self> {
+        self.set_node_affinity(device, node_index)?;
+        Ok(self)
     }
 }
 
-/// Wrapper around D3D12_SO_DECLARATION_ENTRY structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
-pub struct SoDeclarationEntry<'a>(
-    pub D3D12_SO_DECLARATION_ENTRY,
-    PhantomData<&'a str>,
-);
+/// Wrapper around D3D12_RANGE structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+#[repr(transparent)]
+pub struct Range(pub(crate) D3D12_RANGE);
 
-impl<'a> SoDeclarationEntry<'a> {
-    pub fn set_stream(&mut self, stream: u32) -> &mut Self {
-        self.0.Stream = stream;
+impl Range {
+    pub fn set_begin(&mut self, begin: ByteCount) -> &mut Self {
+        self.0.Begin = begin.0;
         self
     }
 
-    pub fn with_stream(mut self, stream: u32) -> Self {
-        self.set_stream(stream);
+    pub fn with_begin(mut self, begin: ByteCount) -> Self {
+        self.set_begin(begin);
         self
     }
 
-    pub fn stream(&self) -> u32 {
-        self.0.Stream
+    pub fn begin(&self) -> ByteCount {
+        ByteCount(self.0.Begin)
     }
 
-    pub fn set_semantic_name(
-        &mut self,
-        name: &'a str,
-    ) -> Result<&mut Self, NulError> {
-        let owned = CString::new(name)?;
-        self.0.SemanticName = owned.into_raw() as *const i8;
-        self.1 = PhantomData;
-        Ok(self)
+    pub fn set_end(&mut self, end: ByteCount) -> &mut Self {
+        self.0.End = end.0;
+        self
     }
 
-    pub fn with_semantic_name(
-        mut self,
-        name: &'a str,
-    ) -> Result<Self, NulError> {
-        match self.set_semantic_name(name) {
-            Ok(_) => Ok(self),
-            Err(err) => Err(err),
-        }
+    pub fn with_end(mut self, end: ByteCount) -> Self {
+        self.set_end(end);
+        self
     }
 
-    pub fn semantic_name(&self) -> Result<&'a str, Utf8Error> {
-        Ok(unsafe { std::ffi::CStr::from_ptr(self.0.SemanticName).to_str()? })
+    pub fn end(&self) -> ByteCount {
+        ByteCount(self.0.End)
     }
+}
 
-    pub fn set_semantic_index(&mut self, semantic_index: u32) -> &mut Self {
-        self.0.SemanticIndex = semantic_index;
+/// Wrapper around D3D12_RANGE_UINT64 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct RangeUint64(pub(crate) D3D12_RANGE_UINT64);
+
+impl RangeUint64 {
+    pub fn set_begin(&mut self, begin: ByteCount) -> &mut Self {
+        self.0.Begin = begin.0;
         self
     }
 
-    pub fn with_semantic_index(mut self, semantic_index: u32) -> Self {
-        self.set_semantic_index(semantic_index);
+    pub fn with_begin(mut self, begin: ByteCount) -> Self {
+        self.set_begin(begin);
         self
     }
 
-    pub fn semantic_index(&self) -> u32 {
-        self.0.SemanticIndex
+    pub fn begin(&self) -> ByteCount {
+        ByteCount(self.0.Begin)
     }
 
-    pub fn set_start_component(&mut self, start_component: u8) -> &mut Self {
-        self.0.StartComponent = start_component;
+    pub fn set_end(&mut self, end: ByteCount) -> &mut Self {
+        self.0.End = end.0;
         self
     }
 
-    pub fn with_start_component(mut self, start_component: u8) -> Self {
-        self.set_start_component(start_component);
+    pub fn with_end(mut self, end: ByteCount) -> Self {
+        self.set_end(end);
         self
     }
 
-    pub fn start_component(&self) -> u8 {
-        self.0.StartComponent
+    pub fn end(&self) -> ByteCount {
+        ByteCount(self.0.End)
     }
+}
 
-    pub fn set_component_count(&mut self, component_count: u8) -> &mut Self {
-        self.0.ComponentCount = component_count;
+/// Wrapper around D3D12_SUBRESOURCE_RANGE_UINT64 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct SubresourceRangeUint64(pub(crate) D3D12_SUBRESOURCE_RANGE_UINT64);
+
+impl SubresourceRangeUint64 {
+    pub fn set_subresource(&mut self, subresource: u32) -> &mut Self {
+        self.0.Subresource = subresource;
         self
     }
 
-    pub fn with_component_count(mut self, component_count: u8) -> Self {
-        self.set_component_count(component_count);
+    pub fn with_subresource(mut self, subresource: u32) -> Self {
+        self.set_subresource(subresource);
         self
     }
 
-    pub fn component_count(&self) -> u8 {
-        self.0.ComponentCount
+    pub fn subresource(&self) -> u32 {
+        self.0.Subresource
     }
 
-    pub fn set_output_slot(&mut self, output_slot: u8) -> &mut Self {
-        self.0.OutputSlot = output_slot;
+    pub fn set_range(&mut self, range: RangeUint64) -> &mut Self {
+        self.0.Range = range.0;
         self
     }
 
-    pub fn with_output_slot(mut self, output_slot: u8) -> Self {
-        self.set_output_slot(output_slot);
+    pub fn with_range(mut self, range: RangeUint64) -> Self {
+        self.set_range(range);
         self
     }
 
-    pub fn output_slot(&self) -> u8 {
-        self.0.OutputSlot
-    }
-}
-
-// We need this because we transfer ownership of the CString "name" into
-// the raw C string (const char*) "SemanticName". Since this memory has to be
-// valid until the destruction of this struct, we need to regain that memory
-// back so it can be destroyed correctly
-impl<'a> Drop for SoDeclarationEntry<'a> {
-    fn drop(&mut self) {
-        unsafe {
-            let _regained_name = CString::from_raw(
-                self.0.SemanticName as *mut std::os::raw::c_char,
-            );
-        }
+    pub fn range(&self) -> RangeUint64 {
+        RangeUint64(self.0.Range)
     }
 }
 
-/// Wrapper around D3D12_STREAM_OUTPUT_DESC structure
+/// Wrapper around D3D12_SAMPLE_POSITION structure. Coordinates are
+/// in 1/16 pixel units relative to the pixel center, in [-8, 7] range
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
 #[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
-pub struct StreamOutputDesc<'a>(
-    pub D3D12_STREAM_OUTPUT_DESC,
-    PhantomData<&'a [SoDeclarationEntry<'a>]>,
-);
+pub struct SamplePosition(pub(crate) D3D12_SAMPLE_POSITION);
 
-impl<'a> Default for StreamOutputDesc<'a> {
-    fn default() -> Self {
-        Self(
-            D3D12_STREAM_OUTPUT_DESC {
-                pSODeclaration: std::ptr::null(),
-                NumEntries: 0,
-                pBufferStrides: std::ptr::null(),
-                NumStrides: 0,
-                RasterizedStream: 0,
-            },
-            PhantomData,
-        )
+impl SamplePosition {
+    pub fn new(x: i8, y: i8) -> Self {
+        Self(D3D12_SAMPLE_POSITION { X: x, Y: y })
     }
-}
 
-impl<'a> StreamOutputDesc<'a> {
-    pub fn set_so_declarations(
-        &mut self,
-        so_declarations: &'a [SoDeclarationEntry],
-    ) -> &mut StreamOutputDesc<'a> {
-        self.0.pSODeclaration =
-            so_declarations.as_ptr() as *const D3D12_SO_DECLARATION_ENTRY;
-        self.0.NumEntries = so_declarations.len() as u32;
-        self.1 = PhantomData;
+    pub fn set_x(&mut self, x: i8) -> &mut Self {
+        self.0.X = x;
         self
     }
 
-    pub fn with_so_declarations(
-        mut self,
-        so_declarations: &'a [SoDeclarationEntry],
-    ) -> Self {
-        self.set_so_declarations(so_declarations);
+    pub fn with_x(mut self, x: i8) -> Self {
+        self.set_x(x);
         self
     }
 
-    pub fn so_declarations(&self) -> &'a [SoDeclarationEntry] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pSODeclaration as *const SoDeclarationEntry,
-                self.0.NumEntries as usize,
-            )
-        }
-    }
-
-    // Note there are no setters since they are both useless and can break the invariant
-    pub fn num_entries(&self) -> u32 {
-        self.0.NumEntries
+    pub fn x(&self) -> i8 {
+        self.0.X
     }
 
-    pub fn set_buffer_strides(&mut self, buffer_strides: &[u32]) -> &mut Self {
-        self.0.pBufferStrides = buffer_strides.as_ptr();
-        self.0.NumStrides = buffer_strides.len() as u32;
-        self.1 = PhantomData;
+    pub fn set_y(&mut self, y: i8) -> &mut Self {
+        self.0.Y = y;
         self
     }
 
-    pub fn with_buffer_strides(mut self, buffer_strides: &[u32]) -> Self {
-        self.set_buffer_strides(buffer_strides);
+    pub fn with_y(mut self, y: i8) -> Self {
+        self.set_y(y);
         self
     }
 
-    pub fn buffer_strides(&self) -> &'a [u32] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pBufferStrides as *const u32,
-                self.0.NumStrides as usize,
-            )
-        }
+    pub fn y(&self) -> i8 {
+        self.0.Y
     }
+}
 
-    // Note there are no setters since they are both useless and can break the invariant
-    pub fn num_strides(&self) -> u32 {
-        self.0.NumStrides
+// ToDo: impl Hash where it's needed but cannot be derived
+/// Wrapper around D3D12_RESOURCE_BARRIER structure. Note this type is not Clone since it contains a raw pointer
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ResourceBarrier(pub(crate) D3D12_RESOURCE_BARRIER);
+
+impl ResourceBarrier {
+    pub fn barrier_type(&self) -> ResourceBarrierType {
+        unsafe { std::mem::transmute(self.0.Type) }
     }
 
-    pub fn set_rasterized_stream(
-        &mut self,
-        rasterized_stream: u32,
-    ) -> &mut Self {
-        self.0.RasterizedStream = rasterized_stream;
+    pub fn set_flags(&mut self, flags: ResourceBarrierFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
         self
     }
 
-    pub fn with_rasterized_stream(mut self, rasterized_stream: u32) -> Self {
-        self.set_rasterized_stream(rasterized_stream);
+    pub fn with_flags(mut self, flags: ResourceBarrierFlags) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn rasterized_stream(&self) -> u32 {
-        self.0.RasterizedStream
+    pub fn flags(&self) -> ResourceBarrierFlags {
+        unsafe { ResourceBarrierFlags::from_bits_unchecked(self.0.Flags) }
     }
-}
-
-/// Wrapper around D3D12_RENDER_TARGET_BLEND_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct RenderTargetBlendDesc(pub(crate) D3D12_RENDER_TARGET_BLEND_DESC);
 
-// defaults from d3dx12.h
-impl Default for RenderTargetBlendDesc {
-    fn default() -> Self {
-        Self(D3D12_RENDER_TARGET_BLEND_DESC {
-            BlendEnable: 0,
-            LogicOpEnable: 0,
-            SrcBlend: Blend::One as i32,
-            DestBlend: Blend::Zero as i32,
-            BlendOp: BlendOp::Add as i32,
-            SrcBlendAlpha: Blend::One as i32,
-            DestBlendAlpha: Blend::Zero as i32,
-            BlendOpAlpha: BlendOp::Add as i32,
-            LogicOp: LogicOp::NoOp as i32,
-            RenderTargetWriteMask:
-                D3D12_COLOR_WRITE_ENABLE_D3D12_COLOR_WRITE_ENABLE_ALL as u8,
+    // ToDo: rename it??
+    pub fn new_transition(desc: &ResourceTransitionBarrier) -> Self {
+        Self(D3D12_RESOURCE_BARRIER {
+            Type: ResourceBarrierType::Transition as i32,
+            Flags: ResourceBarrierFlags::None.bits(),
+            __bindgen_anon_1: D3D12_RESOURCE_BARRIER__bindgen_ty_1 {
+                Transition: desc.0,
+            },
         })
     }
-}
 
-impl RenderTargetBlendDesc {
-    pub fn set_blend_enable(&mut self, blend_enable: bool) -> &mut Self {
-        self.0.BlendEnable = blend_enable as i32;
-        self
+    /// Transition barriers address either a single subresource or all of
+    /// them, so this creates one barrier per subresource in the range.
+    /// `mip_levels` and `array_size` are the ones of the whole resource
+    pub fn new_transitions(
+        desc: &ResourceTransitionBarrier,
+        range: &SubresourceRange,
+        mip_levels: u32,
+        array_size: u32,
+    ) -> Vec<Self> {
+        range
+            .subresource_indices(mip_levels, array_size)
+            .map(|index| {
+                Self::new_transition(&ResourceTransitionBarrier(
+                    D3D12_RESOURCE_TRANSITION_BARRIER {
+                        Subresource: index,
+                        ..desc.0
+                    },
+                ))
+            })
+            .collect()
     }
 
-    pub fn with_blend_enable(mut self, blend_enable: bool) -> Self {
-        self.set_blend_enable(blend_enable);
-        self
+    pub fn transition(&self) -> Option<ResourceTransitionBarrier> {
+        unsafe {
+            match self.barrier_type() {
+                ResourceBarrierType::Transition => {
+                    Some(ResourceTransitionBarrier(
+                        self.0.__bindgen_anon_1.Transition,
+                    ))
+                }
+                _ => None,
+            }
+        }
     }
 
-    pub fn blend_enable(&self) -> bool {
-        self.0.BlendEnable != 0
+    pub fn new_aliasing(desc: &ResourceAliasingBarrier) -> Self {
+        Self(D3D12_RESOURCE_BARRIER {
+            Type: ResourceBarrierType::Aliasing as i32,
+            Flags: ResourceBarrierFlags::None.bits(),
+            __bindgen_anon_1: D3D12_RESOURCE_BARRIER__bindgen_ty_1 {
+                Aliasing: desc.0,
+            },
+        })
     }
 
-    pub fn set_logic_op_enable(&mut self, logic_op_enable: bool) -> &mut Self {
-        self.0.LogicOpEnable = logic_op_enable as i32;
-        self
+    pub fn aliasing(&self) -> Option<ResourceAliasingBarrier> {
+        unsafe {
+            match self.barrier_type() {
+                ResourceBarrierType::Aliasing => Some(ResourceAliasingBarrier(
+                    self.0.__bindgen_anon_1.Aliasing,
+                )),
+                _ => None,
+            }
+        }
     }
 
-    pub fn with_logic_op_enable(mut self, logic_op_enable: bool) -> Self {
-        self.set_logic_op_enable(logic_op_enable);
-        self
+    pub fn new_uav(desc: &ResourceUavBarrier) -> Self {
+        Self(D3D12_RESOURCE_BARRIER {
+            Type: ResourceBarrierType::Uav as i32,
+            Flags: ResourceBarrierFlags::None.bits(),
+            __bindgen_anon_1: D3D12_RESOURCE_BARRIER__bindgen_ty_1 {
+                UAV: desc.0,
+            },
+        })
     }
 
-    pub fn logic_op_enable(&self) -> bool {
-        self.0.LogicOpEnable != 0
+    pub fn uav(&self) -> Option<ResourceUavBarrier> {
+        unsafe {
+            match self.barrier_type() {
+                ResourceBarrierType::Uav => {
+                    Some(ResourceUavBarrier(self.0.__bindgen_anon_1.UAV))
+                }
+                _ => None,
+            }
+        }
     }
+}
 
-    pub fn set_src_blend(&mut self, src_blend: Blend) -> &mut Self {
-        self.0.SrcBlend = src_blend as i32;
+/// Wrapper around D3D12_RESOURCE_TRANSITION_BARRIER structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct ResourceTransitionBarrier(
+    pub(crate) D3D12_RESOURCE_TRANSITION_BARRIER,
+);
+
+impl ResourceTransitionBarrier {
+    pub fn set_resource(&mut self, resource: &Resource) -> &mut Self {
+        self.0.pResource = resource.this;
         self
     }
 
-    pub fn with_src_blend(mut self, src_blend: Blend) -> Self {
-        self.set_src_blend(src_blend);
+    pub fn with_resource(mut self, resource: &Resource) -> Self {
+        self.set_resource(resource);
         self
     }
 
-    pub fn src_blend(&self) -> Blend {
-        unsafe { std::mem::transmute(self.0.SrcBlend) }
+    // ToDo: return reference?
+    pub fn resource(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResource,
+        };
+        resource.add_ref();
+        resource
     }
 
-    pub fn set_dest_blend(&mut self, dest_blend: Blend) -> &mut Self {
-        self.0.DestBlend = dest_blend as i32;
+    // None value means "all subresources"
+    pub fn set_subresource(&mut self, subresource: Option<u32>) -> &mut Self {
+        match subresource {
+            Some(index) => self.0.Subresource = index,
+            None => {
+                self.0.Subresource = D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES
+            }
+        }
         self
     }
 
-    pub fn with_dest_blend(mut self, dest_blend: Blend) -> Self {
-        self.set_dest_blend(dest_blend);
+    pub fn with_subresource(mut self, subresource: Option<u32>) -> Self {
+        self.set_subresource(subresource);
         self
     }
 
-    pub fn dest_blend(&self) -> Blend {
-        unsafe { std::mem::transmute(self.0.DestBlend) }
+    pub fn subresource(&self) -> Option<u32> {
+        match self.0.Subresource {
+            D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES => None,
+            _ => Some(self.0.Subresource),
+        }
     }
 
-    pub fn set_blend_op(&mut self, blend_op: BlendOp) -> &mut Self {
-        self.0.BlendOp = blend_op as i32;
+    pub fn set_state_before(
+        &mut self,
+        state_before: ResourceStates,
+    ) -> &mut Self {
+        self.0.StateBefore = state_before.bits();
         self
     }
 
-    pub fn with_blend_op(mut self, blend_op: BlendOp) -> Self {
-        self.set_blend_op(blend_op);
+    pub fn with_state_before(mut self, state_before: ResourceStates) -> Self {
+        self.set_state_before(state_before);
         self
     }
 
-    pub fn blend_op(&self) -> BlendOp {
-        unsafe { std::mem::transmute(self.0.BlendOp) }
+    pub fn state_before(&self) -> ResourceStates {
+        unsafe { ResourceStates::from_bits_unchecked(self.0.StateBefore) }
     }
 
-    pub fn set_src_blend_alpha(&mut self, src_blend_alpha: Blend) -> &mut Self {
-        self.0.SrcBlendAlpha = src_blend_alpha as i32;
+    pub fn set_state_after(
+        &mut self,
+        state_after: ResourceStates,
+    ) -> &mut Self {
+        self.0.StateAfter = state_after.bits();
         self
     }
 
-    pub fn with_src_blend_alpha(mut self, src_blend_alpha: Blend) -> Self {
-        self.set_src_blend_alpha(src_blend_alpha);
+    pub fn with_state_after(mut self, state_after: ResourceStates) -> Self {
+        self.set_state_after(state_after);
         self
     }
 
-    pub fn src_blend_alpha(&self) -> Blend {
-        unsafe { std::mem::transmute(self.0.SrcBlendAlpha) }
+    pub fn state_after(&self) -> ResourceStates {
+        unsafe { ResourceStates::from_bits_unchecked(self.0.StateAfter) }
     }
+}
 
-    pub fn set_dest_blend_alpha(
+/// Wrapper around D3D12_RESOURCE_ALIASING_BARRIER structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct ResourceAliasingBarrier(pub(crate) D3D12_RESOURCE_ALIASING_BARRIER);
+
+impl ResourceAliasingBarrier {
+    pub fn set_resource_before(
         &mut self,
-        dest_blend_alpha: Blend,
+        resource_before: &Resource,
     ) -> &mut Self {
-        self.0.DestBlendAlpha = dest_blend_alpha as i32;
+        self.0.pResourceBefore = resource_before.this;
         self
     }
 
-    pub fn with_dest_blend_alpha(mut self, dest_blend_alpha: Blend) -> Self {
-        self.set_dest_blend_alpha(dest_blend_alpha);
+    pub fn with_resource_before(mut self, resource_before: &Resource) -> Self {
+        self.set_resource_before(resource_before);
         self
     }
 
-    pub fn dest_blend_alpha(&self) -> Blend {
-        unsafe { std::mem::transmute(self.0.DestBlendAlpha) }
+    pub fn resource_before(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResourceBefore,
+        };
+        resource.add_ref();
+        resource
     }
 
-    pub fn set_blend_op_alpha(&mut self, blend_op_alpha: BlendOp) -> &mut Self {
-        self.0.BlendOpAlpha = blend_op_alpha as i32;
+    pub fn set_resource_after(
+        &mut self,
+        resource_after: &Resource,
+    ) -> &mut Self {
+        self.0.pResourceAfter = resource_after.this;
         self
     }
 
-    pub fn with_blend_op_alpha(mut self, blend_op_alpha: BlendOp) -> Self {
-        self.set_blend_op_alpha(blend_op_alpha);
-        self
-    }
-
-    pub fn blend_op_alpha(&self) -> BlendOp {
-        unsafe { std::mem::transmute(self.0.BlendOpAlpha) }
-    }
-
-    pub fn set_logic_op(&mut self, logic_op: LogicOp) -> &mut Self {
-        self.0.LogicOp = logic_op as i32;
+    pub fn with_resource_after(mut self, resource_after: &Resource) -> Self {
+        self.set_resource_after(resource_after);
         self
     }
 
-    pub fn with_logic_op(mut self, logic_op: LogicOp) -> Self {
-        self.set_logic_op(logic_op);
-        self
+    pub fn resource_after(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResourceAfter,
+        };
+        resource.add_ref();
+        resource
     }
+}
 
-    pub fn logic_op(&self) -> LogicOp {
-        unsafe { std::mem::transmute(self.0.LogicOp) }
-    }
+/// Wrapper around D3D12_RESOURCE_UAV_BARRIER structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct ResourceUavBarrier(pub(crate) D3D12_RESOURCE_UAV_BARRIER);
 
-    pub fn set_render_target_write_mask(
-        &mut self,
-        render_target_write_mask: ColorWriteEnable,
-    ) -> &mut Self {
-        self.0.RenderTargetWriteMask = render_target_write_mask.bits() as u8;
+impl ResourceUavBarrier {
+    pub fn set_resource(&mut self, resource: &Resource) -> &mut Self {
+        self.0.pResource = resource.this;
         self
     }
 
-    pub fn with_render_target_write_mask(
-        mut self,
-        render_target_write_mask: ColorWriteEnable,
-    ) -> Self {
-        self.set_render_target_write_mask(render_target_write_mask);
+    pub fn with_resource(mut self, resource: &Resource) -> Self {
+        self.set_resource(resource);
         self
     }
 
-    pub fn render_target_write_mask(&self) -> ColorWriteEnable {
-        unsafe {
-            ColorWriteEnable::from_bits_unchecked(
-                self.0.RenderTargetWriteMask as i32,
-            )
-        }
+    pub fn resource(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResource,
+        };
+        resource.add_ref();
+        resource
     }
 }
 
-/// Wrapper around D3D12_BLEND_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+/// Wrapper around D3D12_BARRIER_SUBRESOURCE_RANGE structure. The default
+/// value covers all subresources
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy)]
 #[repr(transparent)]
-pub struct BlendDesc(pub(crate) D3D12_BLEND_DESC);
+pub struct BarrierSubresourceRange(pub(crate) D3D12_BARRIER_SUBRESOURCE_RANGE);
 
-// defaults from d3dx12.h
-impl Default for BlendDesc {
+impl Default for BarrierSubresourceRange {
     fn default() -> Self {
-        Self(D3D12_BLEND_DESC {
-            AlphaToCoverageEnable: 0,
-            IndependentBlendEnable: 0,
-            RenderTarget: [RenderTargetBlendDesc::default().0; 8usize],
+        Self(D3D12_BARRIER_SUBRESOURCE_RANGE {
+            IndexOrFirstMipLevel: D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES,
+            NumMipLevels: 0,
+            FirstArraySlice: 0,
+            NumArraySlices: 0,
+            FirstPlane: 0,
+            NumPlanes: 0,
         })
     }
 }
 
-impl BlendDesc {
-    pub fn set_alpha_to_coverage_enable(
+impl BarrierSubresourceRange {
+    /// A range consisting of the single subresource with the given index
+    pub fn new_subresource(index: u32) -> Self {
+        Self::default().with_index_or_first_mip_level(index)
+    }
+
+    /// If num_mip_levels is zero, this value is a subresource index
+    /// rather than the first mip level
+    pub fn set_index_or_first_mip_level(
         &mut self,
-        alpha_to_coverage_enable: bool,
+        index_or_first_mip_level: u32,
     ) -> &mut Self {
-        self.0.AlphaToCoverageEnable = alpha_to_coverage_enable as i32;
+        self.0.IndexOrFirstMipLevel = index_or_first_mip_level;
         self
     }
 
-    pub fn with_alpha_to_coverage_enable(
+    pub fn with_index_or_first_mip_level(
         mut self,
-        alpha_to_coverage_enable: bool,
+        index_or_first_mip_level: u32,
     ) -> Self {
-        self.set_alpha_to_coverage_enable(alpha_to_coverage_enable);
+        self.set_index_or_first_mip_level(index_or_first_mip_level);
         self
     }
 
-    pub fn alpha_to_coverage_enable(&self) -> bool {
-        self.0.AlphaToCoverageEnable != 0
+    pub fn index_or_first_mip_level(&self) -> u32 {
+        self.0.IndexOrFirstMipLevel
     }
 
-    pub fn set_independent_blend_enable(
-        &mut self,
-        independent_blend_enable: bool,
-    ) -> &mut Self {
-        self.0.IndependentBlendEnable = independent_blend_enable as i32;
+    pub fn set_num_mip_levels(&mut self, num_mip_levels: u32) -> &mut Self {
+        self.0.NumMipLevels = num_mip_levels;
         self
     }
 
-    pub fn with_independent_blend_enable(
-        mut self,
-        independent_blend_enable: bool,
-    ) -> Self {
-        self.set_independent_blend_enable(independent_blend_enable);
+    pub fn with_num_mip_levels(mut self, num_mip_levels: u32) -> Self {
+        self.set_num_mip_levels(num_mip_levels);
         self
     }
 
-    pub fn independent_blend_enable(&self) -> bool {
-        self.0.IndependentBlendEnable != 0
+    pub fn num_mip_levels(&self) -> u32 {
+        self.0.NumMipLevels
     }
 
-    pub fn set_render_targets(
+    pub fn set_first_array_slice(
         &mut self,
-        rt_blend_descs: &[RenderTargetBlendDesc],
+        first_array_slice: u32,
     ) -> &mut Self {
-        for rt_index in 0..rt_blend_descs.len() {
-            // transmute is okay due to repr::transparent
-            self.0.RenderTarget[rt_index] =
-                unsafe { std::mem::transmute(rt_blend_descs[rt_index]) };
-        }
+        self.0.FirstArraySlice = first_array_slice;
         self
     }
 
-    pub fn with_render_targets(
-        mut self,
-        rt_blend_descs: &[RenderTargetBlendDesc],
-    ) -> Self {
-        self.set_render_targets(rt_blend_descs);
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
         self
     }
 
-    pub fn render_targets(
-        &self,
-    ) -> [RenderTargetBlendDesc; SIMULTANEOUS_RENDER_TARGET_COUNT as usize]
-    {
-        // transmute is okay due to repr::transparent
-        unsafe { std::mem::transmute(self.0.RenderTarget) }
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
     }
-}
 
-/// Wrapper around D3D12_RASTERIZER_DESC structure
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug)]
-pub struct RasterizerDesc(pub(crate) D3D12_RASTERIZER_DESC);
+    pub fn set_num_array_slices(&mut self, num_array_slices: u32) -> &mut Self {
+        self.0.NumArraySlices = num_array_slices;
+        self
+    }
 
-// defaults from d3dx12.h
-impl Default for RasterizerDesc {
-    fn default() -> Self {
-        Self(D3D12_RASTERIZER_DESC {
-            FillMode: FillMode::Solid as i32,
-            CullMode: CullMode::Back as i32,
-            FrontCounterClockwise: 0,
-            DepthBias: DEFAULT_DEPTH_BIAS as i32,
-            DepthBiasClamp: DEFAULT_DEPTH_BIAS_CLAMP as f32,
-            SlopeScaledDepthBias: DEFAULT_SLOPE_SCALED_DEPTH_BIAS as f32,
-            DepthClipEnable: 1,
-            MultisampleEnable: 0,
-            AntialiasedLineEnable: 0,
-            ForcedSampleCount: 0,
-            ConservativeRaster: ConservativeRasterizationMode::Off as i32,
-        })
+    pub fn with_num_array_slices(mut self, num_array_slices: u32) -> Self {
+        self.set_num_array_slices(num_array_slices);
+        self
     }
-}
 
-// Padding fields are zeroed in Default impl, so this should be okay
-#[cfg(feature = "hash")]
-impl std::hash::Hash for RasterizerDesc {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        unsafe {
-            let slice = std::slice::from_raw_parts(
-                self as *const _ as *const u8,
-                std::mem::size_of::<Self>(),
-            );
+    pub fn num_array_slices(&self) -> u32 {
+        self.0.NumArraySlices
+    }
 
-            slice.hash(state);
-        }
+    pub fn set_first_plane(&mut self, first_plane: u32) -> &mut Self {
+        self.0.FirstPlane = first_plane;
+        self
     }
-}
 
-#[cfg(feature = "eq")]
-impl PartialEq for RasterizerDesc {
-    fn eq(&self, other: &Self) -> bool {
-        unsafe {
-            let self_slice = std::slice::from_raw_parts(
-                self as *const _ as *const u8,
-                std::mem::size_of::<Self>(),
-            );
+    pub fn with_first_plane(mut self, first_plane: u32) -> Self {
+        self.set_first_plane(first_plane);
+        self
+    }
 
-            let other_slice = std::slice::from_raw_parts(
-                other as *const _ as *const u8,
-                std::mem::size_of::<Self>(),
-            );
+    pub fn first_plane(&self) -> u32 {
+        self.0.FirstPlane
+    }
 
-            self_slice == other_slice
-        }
+    pub fn set_num_planes(&mut self, num_planes: u32) -> &mut Self {
+        self.0.NumPlanes = num_planes;
+        self
+    }
+
+    pub fn with_num_planes(mut self, num_planes: u32) -> Self {
+        self.set_num_planes(num_planes);
+        self
+    }
+
+    pub fn num_planes(&self) -> u32 {
+        self.0.NumPlanes
     }
 }
 
-impl Eq for RasterizerDesc {}
+/// Wrapper around D3D12_GLOBAL_BARRIER structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct GlobalBarrier(pub(crate) D3D12_GLOBAL_BARRIER);
 
-impl RasterizerDesc {
-    pub fn set_fill_mode(&mut self, fill_mode: FillMode) -> &mut Self {
-        self.0.FillMode = fill_mode as i32;
+impl GlobalBarrier {
+    pub fn set_sync_before(&mut self, sync_before: BarrierSync) -> &mut Self {
+        self.0.SyncBefore = sync_before.bits();
         self
     }
 
-    pub fn with_fill_mode(mut self, fill_mode: FillMode) -> Self {
-        self.set_fill_mode(fill_mode);
+    pub fn with_sync_before(mut self, sync_before: BarrierSync) -> Self {
+        self.set_sync_before(sync_before);
         self
     }
 
-    pub fn fill_mode(&self) -> FillMode {
-        unsafe { std::mem::transmute(self.0.FillMode) }
+    pub fn sync_before(&self) -> BarrierSync {
+        unsafe { BarrierSync::from_bits_unchecked(self.0.SyncBefore) }
     }
 
-    pub fn set_cull_mode(&mut self, cull_mode: CullMode) -> &mut Self {
-        self.0.CullMode = cull_mode as i32;
+    pub fn set_sync_after(&mut self, sync_after: BarrierSync) -> &mut Self {
+        self.0.SyncAfter = sync_after.bits();
         self
     }
 
-    pub fn with_cull_mode(mut self, cull_mode: CullMode) -> Self {
-        self.set_cull_mode(cull_mode);
+    pub fn with_sync_after(mut self, sync_after: BarrierSync) -> Self {
+        self.set_sync_after(sync_after);
         self
     }
 
-    pub fn cull_mode(&self) -> CullMode {
-        unsafe { std::mem::transmute(self.0.CullMode) }
+    pub fn sync_after(&self) -> BarrierSync {
+        unsafe { BarrierSync::from_bits_unchecked(self.0.SyncAfter) }
     }
 
-    pub fn set_front_counter_clockwise(
+    pub fn set_access_before(
         &mut self,
-        front_counter_clockwise: bool,
+        access_before: BarrierAccess,
     ) -> &mut Self {
-        self.0.FrontCounterClockwise = front_counter_clockwise as i32;
+        self.0.AccessBefore = access_before.bits();
         self
     }
 
-    pub fn with_front_counter_clockwise(
-        mut self,
-        front_counter_clockwise: bool,
-    ) -> Self {
-        self.set_front_counter_clockwise(front_counter_clockwise);
+    pub fn with_access_before(mut self, access_before: BarrierAccess) -> Self {
+        self.set_access_before(access_before);
         self
     }
 
-    pub fn front_counter_clockwise(&self) -> bool {
-        self.0.FrontCounterClockwise != 0
+    pub fn access_before(&self) -> BarrierAccess {
+        unsafe { BarrierAccess::from_bits_unchecked(self.0.AccessBefore) }
     }
 
-    pub fn set_depth_bias(&mut self, depth_bias: i32) -> &mut Self {
-        self.0.DepthBias = depth_bias;
+    pub fn set_access_after(
+        &mut self,
+        access_after: BarrierAccess,
+    ) -> &mut Self {
+        self.0.AccessAfter = access_after.bits();
         self
     }
 
-    pub fn with_depth_bias(mut self, depth_bias: i32) -> Self {
-        self.set_depth_bias(depth_bias);
+    pub fn with_access_after(mut self, access_after: BarrierAccess) -> Self {
+        self.set_access_after(access_after);
         self
     }
 
-    pub fn depth_bias(&self) -> i32 {
-        self.0.DepthBias
+    pub fn access_after(&self) -> BarrierAccess {
+        unsafe { BarrierAccess::from_bits_unchecked(self.0.AccessAfter) }
     }
+}
 
-    pub fn set_depth_bias_clamp(&mut self, depth_bias_clamp: f32) -> &mut Self {
-        self.0.DepthBiasClamp = depth_bias_clamp;
+/// Wrapper around D3D12_TEXTURE_BARRIER structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct TextureBarrier(pub(crate) D3D12_TEXTURE_BARRIER);
+
+impl Default for TextureBarrier {
+    fn default() -> Self {
+        Self(D3D12_TEXTURE_BARRIER {
+            SyncBefore: BarrierSync::None.bits(),
+            SyncAfter: BarrierSync::None.bits(),
+            AccessBefore: BarrierAccess::NoAccess.bits(),
+            AccessAfter: BarrierAccess::NoAccess.bits(),
+            LayoutBefore: BarrierLayout::Undefined as i32,
+            LayoutAfter: BarrierLayout::Undefined as i32,
+            pResource: std::ptr::null_mut(),
+            Subresources: BarrierSubresourceRange::default().0,
+            Flags: TextureBarrierFlags::None.bits(),
+        })
+    }
+}
+
+impl TextureBarrier {
+    pub fn set_sync_before(&mut self, sync_before: BarrierSync) -> &mut Self {
+        self.0.SyncBefore = sync_before.bits();
         self
     }
 
-    pub fn with_depth_bias_clamp(mut self, depth_bias_clamp: f32) -> Self {
-        self.set_depth_bias_clamp(depth_bias_clamp);
+    pub fn with_sync_before(mut self, sync_before: BarrierSync) -> Self {
+        self.set_sync_before(sync_before);
         self
     }
 
-    pub fn depth_bias_clamp(&self) -> f32 {
-        self.0.DepthBiasClamp
+    pub fn sync_before(&self) -> BarrierSync {
+        unsafe { BarrierSync::from_bits_unchecked(self.0.SyncBefore) }
     }
 
-    pub fn set_slope_scaled_depth_bias(
-        &mut self,
-        slope_scaled_depth_bias: f32,
-    ) -> &mut Self {
-        self.0.SlopeScaledDepthBias = slope_scaled_depth_bias;
+    pub fn set_sync_after(&mut self, sync_after: BarrierSync) -> &mut Self {
+        self.0.SyncAfter = sync_after.bits();
         self
     }
 
-    pub fn with_slope_scaled_depth_bias(
-        mut self,
-        slope_scaled_depth_bias: f32,
-    ) -> Self {
-        self.set_slope_scaled_depth_bias(slope_scaled_depth_bias);
+    pub fn with_sync_after(mut self, sync_after: BarrierSync) -> Self {
+        self.set_sync_after(sync_after);
         self
     }
 
-    pub fn slope_scaled_depth_bias(&self) -> f32 {
-        self.0.SlopeScaledDepthBias
+    pub fn sync_after(&self) -> BarrierSync {
+        unsafe { BarrierSync::from_bits_unchecked(self.0.SyncAfter) }
     }
 
-    pub fn set_depth_clip_enable(
+    pub fn set_access_before(
         &mut self,
-        depth_clip_enable: bool,
+        access_before: BarrierAccess,
     ) -> &mut Self {
-        self.0.DepthClipEnable = depth_clip_enable as i32;
+        self.0.AccessBefore = access_before.bits();
         self
     }
 
-    pub fn with_depth_clip_enable(mut self, depth_clip_enable: bool) -> Self {
-        self.set_depth_clip_enable(depth_clip_enable);
+    pub fn with_access_before(mut self, access_before: BarrierAccess) -> Self {
+        self.set_access_before(access_before);
         self
     }
 
-    pub fn depth_clip_enable(&self) -> bool {
-        self.0.DepthClipEnable != 0
+    pub fn access_before(&self) -> BarrierAccess {
+        unsafe { BarrierAccess::from_bits_unchecked(self.0.AccessBefore) }
     }
 
-    pub fn set_multisample_enable(
+    pub fn set_access_after(
         &mut self,
-        multisample_enable: bool,
+        access_after: BarrierAccess,
     ) -> &mut Self {
-        self.0.MultisampleEnable = multisample_enable as i32;
+        self.0.AccessAfter = access_after.bits();
         self
     }
 
-    pub fn with_multisample_enable(mut self, multisample_enable: bool) -> Self {
-        self.set_multisample_enable(multisample_enable);
+    pub fn with_access_after(mut self, access_after: BarrierAccess) -> Self {
+        self.set_access_after(access_after);
         self
     }
 
-    pub fn multisample_enable(&self) -> bool {
-        self.0.MultisampleEnable != 0
+    pub fn access_after(&self) -> BarrierAccess {
+        unsafe { BarrierAccess::from_bits_unchecked(self.0.AccessAfter) }
     }
 
-    pub fn set_antialiased_line_enable(
+    pub fn set_layout_before(
         &mut self,
-        antialiased_line_enable: bool,
+        layout_before: BarrierLayout,
     ) -> &mut Self {
-        self.0.AntialiasedLineEnable = antialiased_line_enable as i32;
+        self.0.LayoutBefore = layout_before as i32;
         self
     }
 
-    pub fn with_antialiased_line_enable(
-        mut self,
-        antialiased_line_enable: bool,
-    ) -> Self {
-        self.set_antialiased_line_enable(antialiased_line_enable);
+    pub fn with_layout_before(mut self, layout_before: BarrierLayout) -> Self {
+        self.set_layout_before(layout_before);
         self
     }
 
-    pub fn antialiased_line_enable(&self) -> bool {
-        self.0.AntialiasedLineEnable != 0
+    pub fn layout_before(&self) -> BarrierLayout {
+        unsafe { std::mem::transmute(self.0.LayoutBefore) }
     }
 
-    pub fn set_forced_sample_count(
+    pub fn set_layout_after(
         &mut self,
-        forced_sample_count: u32,
+        layout_after: BarrierLayout,
     ) -> &mut Self {
-        self.0.ForcedSampleCount = forced_sample_count;
+        self.0.LayoutAfter = layout_after as i32;
         self
     }
 
-    pub fn with_forced_sample_count(
-        mut self,
-        forced_sample_count: u32,
-    ) -> Self {
-        self.set_forced_sample_count(forced_sample_count);
+    pub fn with_layout_after(mut self, layout_after: BarrierLayout) -> Self {
+        self.set_layout_after(layout_after);
         self
     }
 
-    pub fn forced_sample_count(&self) -> u32 {
-        self.0.ForcedSampleCount
+    pub fn layout_after(&self) -> BarrierLayout {
+        unsafe { std::mem::transmute(self.0.LayoutAfter) }
     }
 
-    pub fn set_conservative_raster(
+    pub fn set_resource(&mut self, resource: &Resource) -> &mut Self {
+        self.0.pResource = resource.this;
+        self
+    }
+
+    pub fn with_resource(mut self, resource: &Resource) -> Self {
+        self.set_resource(resource);
+        self
+    }
+
+    pub fn resource(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResource,
+        };
+        resource.add_ref();
+        resource
+    }
+
+    pub fn set_subresources(
         &mut self,
-        conservative_raster: ConservativeRasterizationMode,
+        subresources: BarrierSubresourceRange,
     ) -> &mut Self {
-        self.0.ConservativeRaster = conservative_raster as i32;
+        self.0.Subresources = subresources.0;
         self
     }
 
-    pub fn with_conservative_raster(
+    pub fn with_subresources(
         mut self,
-        conservative_raster: ConservativeRasterizationMode,
+        subresources: BarrierSubresourceRange,
     ) -> Self {
-        self.set_conservative_raster(conservative_raster);
+        self.set_subresources(subresources);
         self
     }
 
-    pub fn conservative_raster(&self) -> ConservativeRasterizationMode {
-        unsafe { std::mem::transmute(self.0.ConservativeRaster) }
+    pub fn subresources(&self) -> BarrierSubresourceRange {
+        BarrierSubresourceRange(self.0.Subresources)
+    }
+
+    pub fn set_flags(&mut self, flags: TextureBarrierFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
+        self
+    }
+
+    pub fn with_flags(mut self, flags: TextureBarrierFlags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    pub fn flags(&self) -> TextureBarrierFlags {
+        unsafe { TextureBarrierFlags::from_bits_unchecked(self.0.Flags) }
     }
 }
 
-/// Wrapper around D3D12_DEPTH_STENCILOP_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+/// Wrapper around D3D12_BUFFER_BARRIER structure. Note that the runtime
+/// currently requires barriers to cover the whole buffer, which is
+/// what the default offset and size do
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
 #[repr(transparent)]
-pub struct DepthStencilOpDesc(pub(crate) D3D12_DEPTH_STENCILOP_DESC);
+pub struct BufferBarrier(pub(crate) D3D12_BUFFER_BARRIER);
 
-// defaults from d3dx12.h
-impl Default for DepthStencilOpDesc {
+impl Default for BufferBarrier {
     fn default() -> Self {
-        Self(D3D12_DEPTH_STENCILOP_DESC {
-            StencilFailOp: StencilOp::Keep as i32,
-            StencilDepthFailOp: StencilOp::Keep as i32,
-            StencilPassOp: StencilOp::Keep as i32,
-            StencilFunc: ComparisonFunc::Always as i32,
+        Self(D3D12_BUFFER_BARRIER {
+            SyncBefore: BarrierSync::None.bits(),
+            SyncAfter: BarrierSync::None.bits(),
+            AccessBefore: BarrierAccess::NoAccess.bits(),
+            AccessAfter: BarrierAccess::NoAccess.bits(),
+            pResource: std::ptr::null_mut(),
+            Offset: 0,
+            Size: std::u64::MAX,
         })
     }
 }
 
-impl DepthStencilOpDesc {
-    pub fn set_stencil_fail_op(
-        &mut self,
-        stencil_fail_op: StencilOp,
-    ) -> &mut Self {
-        self.0.StencilFailOp = stencil_fail_op as i32;
+impl BufferBarrier {
+    pub fn set_sync_before(&mut self, sync_before: BarrierSync) -> &mut Self {
+        self.0.SyncBefore = sync_before.bits();
         self
     }
 
-    pub fn with_stencil_fail_op(mut self, stencil_fail_op: StencilOp) -> Self {
-        self.set_stencil_fail_op(stencil_fail_op);
+    pub fn with_sync_before(mut self, sync_before: BarrierSync) -> Self {
+        self.set_sync_before(sync_before);
         self
     }
 
-    pub fn stencil_fail_op(&self) -> StencilOp {
-        unsafe { std::mem::transmute(self.0.StencilFailOp) }
+    pub fn sync_before(&self) -> BarrierSync {
+        unsafe { BarrierSync::from_bits_unchecked(self.0.SyncBefore) }
     }
 
-    pub fn set_stencil_depth_fail_op(
-        &mut self,
-        stencil_depth_fail_op: StencilOp,
-    ) -> &mut Self {
-        self.0.StencilDepthFailOp = stencil_depth_fail_op as i32;
+    pub fn set_sync_after(&mut self, sync_after: BarrierSync) -> &mut Self {
+        self.0.SyncAfter = sync_after.bits();
         self
     }
 
-    pub fn with_stencil_depth_fail_op(
-        mut self,
-        stencil_depth_fail_op: StencilOp,
-    ) -> Self {
-        self.set_stencil_depth_fail_op(stencil_depth_fail_op);
+    pub fn with_sync_after(mut self, sync_after: BarrierSync) -> Self {
+        self.set_sync_after(sync_after);
         self
     }
 
-    pub fn stencil_depth_fail_op(&self) -> StencilOp {
-        unsafe { std::mem::transmute(self.0.StencilDepthFailOp) }
+    pub fn sync_after(&self) -> BarrierSync {
+        unsafe { BarrierSync::from_bits_unchecked(self.0.SyncAfter) }
     }
 
-    pub fn set_stencil_pass_op(
+    pub fn set_access_before(
         &mut self,
-        stencil_pass_op: StencilOp,
+        access_before: BarrierAccess,
     ) -> &mut Self {
-        self.0.StencilPassOp = stencil_pass_op as i32;
+        self.0.AccessBefore = access_before.bits();
         self
     }
 
-    pub fn with_stencil_pass_op(mut self, stencil_pass_op: StencilOp) -> Self {
-        self.set_stencil_pass_op(stencil_pass_op);
+    pub fn with_access_before(mut self, access_before: BarrierAccess) -> Self {
+        self.set_access_before(access_before);
         self
     }
 
-    pub fn stencil_pass_op(&self) -> StencilOp {
-        unsafe { std::mem::transmute(self.0.StencilPassOp) }
+    pub fn access_before(&self) -> BarrierAccess {
+        unsafe { BarrierAccess::from_bits_unchecked(self.0.AccessBefore) }
     }
 
-    pub fn set_stencil_func(
+    pub fn set_access_after(
         &mut self,
-        stencil_func: ComparisonFunc,
+        access_after: BarrierAccess,
     ) -> &mut Self {
-        self.0.StencilFunc = stencil_func as i32;
+        self.0.AccessAfter = access_after.bits();
         self
     }
 
-    pub fn with_stencil_func(mut self, stencil_func: ComparisonFunc) -> Self {
-        self.set_stencil_func(stencil_func);
+    pub fn with_access_after(mut self, access_after: BarrierAccess) -> Self {
+        self.set_access_after(access_after);
         self
     }
 
-    pub fn stencil_func(&self) -> ComparisonFunc {
-        unsafe { std::mem::transmute(self.0.StencilFunc) }
-    }
-}
-
-/// Wrapper around D3D12_DEPTH_STENCIL_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct DepthStencilDesc(pub(crate) D3D12_DEPTH_STENCIL_DESC);
-
-// defaults from d3dx12.h: less depth test with writes; no stencil
-impl Default for DepthStencilDesc {
-    fn default() -> Self {
-        Self(D3D12_DEPTH_STENCIL_DESC {
-            DepthEnable: 1,
-            DepthWriteMask: DepthWriteMask::All as i32,
-            DepthFunc: ComparisonFunc::Less as i32,
-            StencilEnable: 0,
-            StencilReadMask: DEFAULT_STENCIL_READ_MASK as u8,
-            StencilWriteMask: DEFAULT_STENCIL_WRITE_MASK as u8,
-            FrontFace: DepthStencilOpDesc::default().0,
-            BackFace: DepthStencilOpDesc::default().0,
-        })
+    pub fn access_after(&self) -> BarrierAccess {
+        unsafe { BarrierAccess::from_bits_unchecked(self.0.AccessAfter) }
     }
-}
 
-impl DepthStencilDesc {
-    pub fn set_depth_enable(&mut self, depth_enable: bool) -> &mut Self {
-        self.0.DepthEnable = depth_enable as i32;
+    pub fn set_resource(&mut self, resource: &Resource) -> &mut Self {
+        self.0.pResource = resource.this;
         self
     }
 
-    pub fn with_depth_enable(mut self, depth_enable: bool) -> Self {
-        self.set_depth_enable(depth_enable);
+    pub fn with_resource(mut self, resource: &Resource) -> Self {
+        self.set_resource(resource);
         self
     }
 
-    pub fn depth_enable(&self) -> bool {
-        self.0.DepthEnable != 0
+    pub fn resource(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResource,
+        };
+        resource.add_ref();
+        resource
     }
 
-    pub fn set_depth_write_mask(
-        &mut self,
-        depth_write_mask: DepthWriteMask,
-    ) -> &mut Self {
-        self.0.DepthWriteMask = depth_write_mask as i32;
+    pub fn set_offset(&mut self, offset: ByteCount) -> &mut Self {
+        self.0.Offset = offset.0;
         self
     }
 
-    pub fn with_depth_write_mask(
-        mut self,
-        depth_write_mask: DepthWriteMask,
-    ) -> Self {
-        self.set_depth_write_mask(depth_write_mask);
+    pub fn with_offset(mut self, offset: ByteCount) -> Self {
+        self.set_offset(offset);
         self
     }
 
-    pub fn depth_write_mask(&self) -> DepthWriteMask {
-        unsafe { std::mem::transmute(self.0.DepthWriteMask) }
+    pub fn offset(&self) -> ByteCount {
+        ByteCount(self.0.Offset)
     }
 
-    pub fn set_depth_func(&mut self, depth_func: ComparisonFunc) -> &mut Self {
-        self.0.DepthFunc = depth_func as i32;
+    pub fn set_size(&mut self, size: ByteCount) -> &mut Self {
+        self.0.Size = size.0;
         self
     }
 
-    pub fn with_depth_func(mut self, depth_func: ComparisonFunc) -> Self {
-        self.set_depth_func(depth_func);
+    pub fn with_size(mut self, size: ByteCount) -> Self {
+        self.set_size(size);
         self
     }
 
-    pub fn depth_func(&self) -> ComparisonFunc {
-        unsafe { std::mem::transmute(self.0.DepthFunc) }
+    pub fn size(&self) -> ByteCount {
+        ByteCount(self.0.Size)
     }
+}
 
-    pub fn set_stencil_enable(&mut self, stencil_enable: bool) -> &mut Self {
-        self.0.StencilEnable = stencil_enable as i32;
-        self
+/// Wrapper around D3D12_BARRIER_GROUP structure
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct BarrierGroup<'a>(
+    pub(crate) D3D12_BARRIER_GROUP,
+    PhantomData<&'a ()>,
+);
+
+impl<'a> BarrierGroup<'a> {
+    pub fn new_global(barriers: &'a [GlobalBarrier]) -> Self {
+        Self(
+            D3D12_BARRIER_GROUP {
+                Type: BarrierType::Global as i32,
+                NumBarriers: barriers.len() as u32,
+                __bindgen_anon_1: D3D12_BARRIER_GROUP__bindgen_ty_1 {
+                    pGlobalBarriers: barriers.as_ptr()
+                        as *const D3D12_GLOBAL_BARRIER,
+                },
+            },
+            PhantomData,
+        )
     }
 
-    pub fn with_stencil_enable(mut self, stencil_enable: bool) -> Self {
-        self.set_stencil_enable(stencil_enable);
-        self
+    pub fn new_texture(barriers: &'a [TextureBarrier]) -> Self {
+        Self(
+            D3D12_BARRIER_GROUP {
+                Type: BarrierType::Texture as i32,
+                NumBarriers: barriers.len() as u32,
+                __bindgen_anon_1: D3D12_BARRIER_GROUP__bindgen_ty_1 {
+                    pTextureBarriers: barriers.as_ptr()
+                        as *const D3D12_TEXTURE_BARRIER,
+                },
+            },
+            PhantomData,
+        )
     }
 
-    pub fn stencil_enable(&self) -> bool {
-        self.0.StencilEnable != 0
+    pub fn new_buffer(barriers: &'a [BufferBarrier]) -> Self {
+        Self(
+            D3D12_BARRIER_GROUP {
+                Type: BarrierType::Buffer as i32,
+                NumBarriers: barriers.len() as u32,
+                __bindgen_anon_1: D3D12_BARRIER_GROUP__bindgen_ty_1 {
+                    pBufferBarriers: barriers.as_ptr()
+                        as *const D3D12_BUFFER_BARRIER,
+                },
+            },
+            PhantomData,
+        )
     }
 
-    pub fn set_stencil_read_mask(
-        &mut self,
-        stencil_read_mask: u8,
-    ) -> &mut Self {
-        self.0.StencilReadMask = stencil_read_mask;
-        self
+    pub fn barrier_type(&self) -> BarrierType {
+        unsafe { std::mem::transmute(self.0.Type) }
     }
 
-    pub fn with_stencil_read_mask(mut self, stencil_read_mask: u8) -> Self {
-        self.set_stencil_read_mask(stencil_read_mask);
-        self
+    pub fn num_barriers(&self) -> u32 {
+        self.0.NumBarriers
     }
 
-    pub fn stencil_read_mask(&self) -> u8 {
-        self.0.StencilReadMask
+    pub fn global_barriers(&self) -> Option<&'a [GlobalBarrier]> {
+        match self.barrier_type() {
+            BarrierType::Global => Some(unsafe {
+                slice::from_raw_parts(
+                    self.0.__bindgen_anon_1.pGlobalBarriers
+                        as *const GlobalBarrier,
+                    self.0.NumBarriers as usize,
+                )
+            }),
+            _ => None,
+        }
     }
 
-    pub fn set_stencil_write_mask(
-        &mut self,
-        stencil_write_mask: u8,
-    ) -> &mut Self {
-        self.0.StencilWriteMask = stencil_write_mask;
-        self
+    pub fn texture_barriers(&self) -> Option<&'a [TextureBarrier]> {
+        match self.barrier_type() {
+            BarrierType::Texture => Some(unsafe {
+                slice::from_raw_parts(
+                    self.0.__bindgen_anon_1.pTextureBarriers
+                        as *const TextureBarrier,
+                    self.0.NumBarriers as usize,
+                )
+            }),
+            _ => None,
+        }
     }
 
-    pub fn with_stencil_write_mask(mut self, stencil_write_mask: u8) -> Self {
-        self.set_stencil_write_mask(stencil_write_mask);
-        self
+    pub fn buffer_barriers(&self) -> Option<&'a [BufferBarrier]> {
+        match self.barrier_type() {
+            BarrierType::Buffer => Some(unsafe {
+                slice::from_raw_parts(
+                    self.0.__bindgen_anon_1.pBufferBarriers
+                        as *const BufferBarrier,
+                    self.0.NumBarriers as usize,
+                )
+            }),
+            _ => None,
+        }
     }
+}
 
-    pub fn stencil_write_mask(&self) -> u8 {
-        self.0.StencilWriteMask
+/// Wrapper around D3D12_VIEWPORT structure
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct Viewport(pub(crate) D3D12_VIEWPORT);
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Viewport(D3D12_VIEWPORT {
+            TopLeftX: 0.,
+            TopLeftY: 0.,
+            Width: 0.,
+            Height: 0.,
+            MinDepth: 0.,
+            MaxDepth: 1.,
+        })
     }
+}
 
-    pub fn set_front_face(
-        &mut self,
-        front_face: DepthStencilOpDesc,
-    ) -> &mut Self {
-        self.0.FrontFace = front_face.0;
+impl Viewport {
+    pub fn set_top_left_x(&mut self, top_left_x: f32) -> &mut Self {
+        self.0.TopLeftX = top_left_x;
         self
     }
 
-    pub fn with_front_face(mut self, front_face: DepthStencilOpDesc) -> Self {
-        self.set_front_face(front_face);
+    pub fn with_top_left_x(mut self, top_left_x: f32) -> Self {
+        self.set_top_left_x(top_left_x);
         self
     }
 
-    pub fn front_face(&self) -> DepthStencilOpDesc {
-        DepthStencilOpDesc(self.0.FrontFace)
+    pub fn top_left_x(&self) -> f32 {
+        self.0.TopLeftX
     }
 
-    pub fn set_back_face(
-        &mut self,
-        back_face: DepthStencilOpDesc,
-    ) -> &mut Self {
-        self.0.BackFace = back_face.0;
+    pub fn set_top_left_y(&mut self, top_left_y: f32) -> &mut Self {
+        self.0.TopLeftY = top_left_y;
         self
     }
 
-    pub fn with_back_face(mut self, back_face: DepthStencilOpDesc) -> Self {
-        self.set_back_face(back_face);
+    pub fn with_top_left_y(mut self, top_left_y: f32) -> Self {
+        self.set_top_left_y(top_left_y);
         self
     }
 
-    pub fn back_face(&self) -> DepthStencilOpDesc {
-        DepthStencilOpDesc(self.0.BackFace)
+    pub fn top_left_y(&self) -> f32 {
+        self.0.TopLeftY
     }
-}
 
-/// Wrapper around D3D12_INPUT_LAYOUT_DESC structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
-pub struct InputLayoutDesc<'a>(
-    pub D3D12_INPUT_LAYOUT_DESC,
-    PhantomData<&'a [InputElementDesc<'a>]>,
-);
+    pub fn set_width(&mut self, width: f32) -> &mut Self {
+        self.0.Width = width;
+        self
+    }
 
-impl Default for InputLayoutDesc<'_> {
-    fn default() -> Self {
-        Self(
-            D3D12_INPUT_LAYOUT_DESC {
-                pInputElementDescs: std::ptr::null(),
-                NumElements: 0,
-            },
-            PhantomData,
-        )
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.set_width(width);
+        self
     }
-}
 
-// ToDo: ShaderBytecode is a similar struct, but it uses new() method
-impl<'a> InputLayoutDesc<'a> {
-    pub fn set_input_elements(
-        &mut self,
-        layout: &'a [InputElementDesc<'a>],
-    ) -> &mut Self {
-        self.0.pInputElementDescs =
-            layout.as_ptr() as *const D3D12_INPUT_ELEMENT_DESC;
-        self.0.NumElements = layout.len() as u32;
-        self.1 = PhantomData;
+    pub fn width(&self) -> f32 {
+        self.0.Width
+    }
+
+    pub fn set_height(&mut self, height: f32) -> &mut Self {
+        self.0.Height = height;
         self
     }
 
-    pub fn with_input_elements(
-        mut self,
-        layout: &'a [InputElementDesc<'a>],
-    ) -> Self {
-        self.set_input_elements(layout);
+    pub fn with_height(mut self, height: f32) -> Self {
+        self.set_height(height);
         self
     }
 
-    pub fn input_elements(&self) -> &'a [InputElementDesc] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pInputElementDescs as *const InputElementDesc,
-                self.0.NumElements as usize,
-            )
-        }
+    pub fn height(&self) -> f32 {
+        self.0.Height
     }
-}
 
-/// Wrapper around D3D12_CACHED_PIPELINE_STATE structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
-pub struct CachedPipelineState<'a>(
-    pub D3D12_CACHED_PIPELINE_STATE,
-    PhantomData<&'a [u8]>,
-);
+    pub fn set_min_depth(&mut self, min_depth: f32) -> &mut Self {
+        self.0.MinDepth = min_depth;
+        self
+    }
 
-impl<'a> Default for CachedPipelineState<'a> {
-    fn default() -> Self {
-        Self(
-            D3D12_CACHED_PIPELINE_STATE {
-                pCachedBlob: std::ptr::null_mut(),
-                CachedBlobSizeInBytes: 0,
-            },
-            PhantomData,
-        )
+    pub fn with_min_depth(mut self, min_depth: f32) -> Self {
+        self.set_min_depth(min_depth);
+        self
     }
-}
 
-impl<'a> CachedPipelineState<'a> {
-    pub fn set_cached_blob(&mut self, cached_blob: &'a [u8]) -> &mut Self {
-        self.0.pCachedBlob = cached_blob.as_ptr() as *const std::ffi::c_void;
-        self.0.CachedBlobSizeInBytes = cached_blob.len() as u64;
-        self.1 = PhantomData;
+    pub fn min_depth(&self) -> f32 {
+        self.0.MinDepth
+    }
+
+    pub fn set_max_depth(&mut self, max_depth: f32) -> &mut Self {
+        self.0.MaxDepth = max_depth;
         self
     }
 
-    pub fn with_cached_blob(mut self, cached_blob: &'a [u8]) -> Self {
-        self.set_cached_blob(cached_blob);
+    pub fn with_max_depth(mut self, max_depth: f32) -> Self {
+        self.set_max_depth(max_depth);
         self
     }
 
-    pub fn cached_blob(&self) -> &'a [u8] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pCachedBlob as *const u8,
-                self.0.CachedBlobSizeInBytes as usize,
-            )
-        }
+    pub fn max_depth(&self) -> f32 {
+        self.0.MaxDepth
     }
 }
 
-// ToDo: do we need different lifetimes for all shaders?
-/// Wrapper around D3D12_GRAPHICS_PIPELINE_STATE_DESC structure
+/// Wrapper around D3D12_RECT structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Debug)]
 #[repr(transparent)]
-#[derive(Debug)]
-pub struct GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il>(
-    pub D3D12_GRAPHICS_PIPELINE_STATE_DESC,
-    PhantomData<&'rs RootSignature>,
-    PhantomData<&'sh ShaderBytecode<'sh>>,
-    PhantomData<&'so StreamOutputDesc<'so>>,
-    PhantomData<&'il InputLayoutDesc<'il>>,
-);
+pub struct Rect(pub(crate) D3D12_RECT);
 
-impl<'rs, 'sh, 'so, 'il> Default
-    for GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il>
-{
+impl Default for Rect {
     fn default() -> Self {
-        Self(
-            D3D12_GRAPHICS_PIPELINE_STATE_DESC {
-                pRootSignature: std::ptr::null_mut(),
-                VS: ShaderBytecode::default().0,
-                PS: ShaderBytecode::default().0,
-                DS: ShaderBytecode::default().0,
-                HS: ShaderBytecode::default().0,
-                GS: ShaderBytecode::default().0,
-                StreamOutput: StreamOutputDesc::default().0,
-                BlendState: BlendDesc::default().0,
-                SampleMask: std::u32::MAX,
-                RasterizerState: RasterizerDesc::default().0,
-                DepthStencilState: DepthStencilDesc::default().0,
-                InputLayout: InputLayoutDesc::default().0,
-                IBStripCutValue: IndexBufferStripCutValue::Disabled as i32,
-                PrimitiveTopologyType: PrimitiveTopologyType::Undefined as i32,
-                NumRenderTargets: SIMULTANEOUS_RENDER_TARGET_COUNT as u32,
-                RTVFormats: [Format::Unknown as i32;
-                    SIMULTANEOUS_RENDER_TARGET_COUNT as usize],
-                DSVFormat: Format::Unknown as i32,
-                SampleDesc: SampleDesc::default().0,
-                NodeMask: 0,
-                CachedPSO: CachedPipelineState::default().0,
-                Flags: PipelineStateFlags::None.bits(),
-            },
-            PhantomData, // rs
-            PhantomData, // sh
-            PhantomData, // so
-            PhantomData, // il
-        )
+        Rect(D3D12_RECT {
+            left: 0,
+            top: 0,
+            right: 0,
+            bottom: 0,
+        })
     }
 }
 
-impl<'rs, 'sh, 'so, 'il> GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il> {
-    pub fn set_root_signature(
-        &mut self,
-        root_signature: &'rs RootSignature,
-    ) -> &mut Self {
-        self.0.pRootSignature = root_signature.this;
-        self.1 = PhantomData;
+impl Rect {
+    pub fn set_left(&mut self, left: i32) -> &mut Self {
+        self.0.left = left;
         self
     }
 
-    pub fn with_root_signature(
-        mut self,
-        root_signature: &'rs RootSignature,
-    ) -> GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il> {
-        self.set_root_signature(root_signature);
+    pub fn with_left(mut self, left: i32) -> Self {
+        self.set_left(left);
         self
     }
 
-    pub fn root_signature(&self) -> RootSignature {
-        let root_signature = RootSignature {
-            this: self.0.pRootSignature,
-        };
-        root_signature.add_ref();
-        root_signature
+    pub fn left(&self) -> i32 {
+        self.0.left
     }
 
-    pub fn set_vs_bytecode(
-        &mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> &mut Self {
-        self.0.VS = bytecode.0;
-        self.2 = PhantomData;
+    pub fn set_top(&mut self, top: i32) -> &mut Self {
+        self.0.top = top;
         self
     }
 
-    pub fn with_vs_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_vs_bytecode(bytecode);
+    pub fn with_top(mut self, top: i32) -> Self {
+        self.set_top(top);
         self
     }
 
-    pub fn vs_bytecode(&self) -> &'sh ShaderBytecode {
-        unsafe {
-            &*(&self.0.VS as *const D3D12_SHADER_BYTECODE
-                as *const ShaderBytecode)
-        }
+    pub fn top(&self) -> i32 {
+        self.0.top
     }
 
-    pub fn set_ps_bytecode(
-        &mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> &mut Self {
-        self.0.PS = bytecode.0;
-        self.2 = PhantomData;
+    pub fn set_right(&mut self, right: i32) -> &mut Self {
+        self.0.right = right;
         self
     }
 
-    pub fn with_ps_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_ps_bytecode(bytecode);
+    pub fn with_right(mut self, right: i32) -> Self {
+        self.set_right(right);
         self
     }
 
-    pub fn ps_bytecode(&self) -> &'sh ShaderBytecode {
-        unsafe {
-            &*(&self.0.PS as *const D3D12_SHADER_BYTECODE
-                as *const ShaderBytecode)
-        }
+    pub fn right(&self) -> i32 {
+        self.0.right
     }
 
-    pub fn set_ds_bytecode(
-        &mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> &mut Self {
-        self.0.DS = bytecode.0;
-        self.2 = PhantomData;
+    pub fn set_bottom(&mut self, bottom: i32) -> &mut Self {
+        self.0.bottom = bottom;
         self
     }
 
-    pub fn with_ds_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_ds_bytecode(bytecode);
+    pub fn with_bottom(mut self, bottom: i32) -> Self {
+        self.set_bottom(bottom);
         self
     }
 
-    pub fn ds_bytecode(&self) -> &'sh ShaderBytecode {
-        unsafe {
-            &*(&self.0.DS as *const D3D12_SHADER_BYTECODE
-                as *const ShaderBytecode)
-        }
-    }
-
-    pub fn set_hs_bytecode(
-        &mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> &mut Self {
-        self.0.HS = bytecode.0;
-        self.2 = PhantomData;
-        self
+    pub fn bottom(&self) -> i32 {
+        self.0.bottom
     }
+}
 
-    pub fn with_hs_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_hs_bytecode(bytecode);
-        self
+/// Wrapper around D3D12_TEXTURE_COPY_LOCATION structure
+// ToDo: add lifetime since we're taking `this` from a Resource?
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct TextureCopyLocation(pub(crate) D3D12_TEXTURE_COPY_LOCATION);
+
+impl TextureCopyLocation {
+    pub fn new_placed_footprint(
+        resource: &Resource,
+        footprint: PlacedSubresourceFootprint,
+    ) -> Self {
+        Self(D3D12_TEXTURE_COPY_LOCATION {
+            pResource: resource.this,
+            Type: TextureCopyType::PlacedFootprint as i32,
+            __bindgen_anon_1: D3D12_TEXTURE_COPY_LOCATION__bindgen_ty_1 {
+                PlacedFootprint: footprint.0,
+            },
+        })
     }
 
-    pub fn hs_bytecode(&self) -> &'sh ShaderBytecode {
-        unsafe {
-            &*(&self.0.HS as *const D3D12_SHADER_BYTECODE
-                as *const ShaderBytecode)
-        }
+    /// Same as [TextureCopyLocation::new_subresource_index] with the index
+    /// computed from the description of `resource`
+    pub fn new_subresource(
+        resource: &Resource,
+        subresource: SubresourceIndex,
+    ) -> Self {
+        Self::new_subresource_index(
+            resource,
+            subresource.subresource_in(&resource.get_desc()),
+        )
     }
 
-    pub fn set_gs_bytecode(
-        &mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> &mut Self {
-        self.0.GS = bytecode.0;
-        self.2 = PhantomData;
-        self
+    pub fn new_subresource_index(resource: &Resource, index: u32) -> Self {
+        Self(D3D12_TEXTURE_COPY_LOCATION {
+            pResource: resource.this,
+            Type: TextureCopyType::SubresourceIndex as i32,
+            __bindgen_anon_1: D3D12_TEXTURE_COPY_LOCATION__bindgen_ty_1 {
+                SubresourceIndex: index,
+            },
+        })
     }
 
-    pub fn with_gs_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_gs_bytecode(bytecode);
-        self
+    pub fn resource(&self) -> Resource {
+        let resource = Resource {
+            this: self.0.pResource,
+        };
+        resource.add_ref();
+        resource
     }
 
-    pub fn gs_bytecode(&self) -> &'sh ShaderBytecode {
-        unsafe {
-            &*(&self.0.GS as *const D3D12_SHADER_BYTECODE
-                as *const ShaderBytecode)
+    pub fn copy_type(&self) -> TextureCopyType {
+        unsafe { std::mem::transmute(self.0.Type) }
+    }
+
+    pub fn subresource_index(&self) -> Option<u32> {
+        match self.copy_type() {
+            TextureCopyType::SubresourceIndex => {
+                Some(unsafe { self.0.__bindgen_anon_1.SubresourceIndex })
+            }
+            _ => None,
         }
     }
 
-    pub fn set_stream_output(
-        &mut self,
-        stream_output: StreamOutputDesc,
-    ) -> &mut Self {
-        self.0.StreamOutput = stream_output.0;
+    pub fn placed_footprint(&self) -> Option<PlacedSubresourceFootprint> {
+        match self.copy_type() {
+            TextureCopyType::PlacedFootprint => {
+                Some(PlacedSubresourceFootprint(unsafe {
+                    self.0.__bindgen_anon_1.PlacedFootprint
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Wrapper around D3D12_BOX structure
+#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+#[repr(transparent)]
+pub struct Box(pub(crate) D3D12_BOX);
+
+impl Default for Box {
+    fn default() -> Self {
+        Self(D3D12_BOX {
+            left: 0,
+            top: 0,
+            front: 0,
+            right: 0,
+            bottom: 1,
+            back: 1,
+        })
+    }
+}
+
+impl Box {
+    pub fn set_left(&mut self, left: u32) -> &mut Self {
+        self.0.left = left;
         self
     }
 
-    pub fn with_stream_output(
-        mut self,
-        stream_output: StreamOutputDesc,
-    ) -> Self {
-        self.set_stream_output(stream_output);
+    pub fn with_left(mut self, left: u32) -> Self {
+        self.set_left(left);
         self
     }
 
-    pub fn stream_output(&self) -> &'so StreamOutputDesc {
-        unsafe {
-            &*(&self.0.StreamOutput as *const D3D12_STREAM_OUTPUT_DESC
-                as *const StreamOutputDesc)
-        }
+    pub fn left(&self) -> u32 {
+        self.0.left
     }
 
-    pub fn set_blend_state(&mut self, blend_state: BlendDesc) -> &mut Self {
-        self.0.BlendState = blend_state.0;
+    pub fn set_top(&mut self, top: u32) -> &mut Self {
+        self.0.top = top;
         self
     }
 
-    pub fn with_blend_state(mut self, blend_state: BlendDesc) -> Self {
-        self.set_blend_state(blend_state);
+    pub fn with_top(mut self, top: u32) -> Self {
+        self.set_top(top);
         self
     }
 
-    pub fn blend_state(&self) -> BlendDesc {
-        BlendDesc(self.0.BlendState)
+    pub fn top(&self) -> u32 {
+        self.0.top
     }
 
-    pub fn set_sample_mask(&mut self, sample_mask: u32) -> &mut Self {
-        self.0.SampleMask = sample_mask;
+    pub fn set_front(&mut self, front: u32) -> &mut Self {
+        self.0.front = front;
         self
     }
 
-    pub fn with_sample_mask(mut self, sample_mask: u32) -> Self {
-        self.set_sample_mask(sample_mask);
+    pub fn with_front(mut self, front: u32) -> Self {
+        self.set_front(front);
         self
     }
-    pub fn sample_mask(&self) -> u32 {
-        self.0.SampleMask
+
+    pub fn front(&self) -> u32 {
+        self.0.front
     }
 
-    pub fn set_rasterizer_state(
-        &mut self,
-        rasterizer_state: RasterizerDesc,
-    ) -> &mut Self {
-        self.0.RasterizerState = rasterizer_state.0;
+    pub fn set_right(&mut self, right: u32) -> &mut Self {
+        self.0.right = right;
         self
     }
 
-    pub fn with_rasterizer_state(
-        mut self,
-        rasterizer_state: RasterizerDesc,
-    ) -> Self {
-        self.set_rasterizer_state(rasterizer_state);
+    pub fn with_right(mut self, right: u32) -> Self {
+        self.set_right(right);
         self
     }
 
-    pub fn rasterizer_state(&self) -> RasterizerDesc {
-        RasterizerDesc(self.0.RasterizerState)
+    pub fn right(&self) -> u32 {
+        self.0.right
     }
 
-    pub fn set_depth_stencil_state(
-        &mut self,
-        depth_stencil_state: DepthStencilDesc,
-    ) -> &mut Self {
-        self.0.DepthStencilState = depth_stencil_state.0;
+    pub fn set_bottom(&mut self, bottom: u32) -> &mut Self {
+        self.0.bottom = bottom;
         self
     }
 
-    pub fn with_depth_stencil_state(
-        mut self,
-        depth_stencil_state: DepthStencilDesc,
-    ) -> Self {
-        self.set_depth_stencil_state(depth_stencil_state);
+    pub fn with_bottom(mut self, bottom: u32) -> Self {
+        self.set_bottom(bottom);
         self
     }
 
-    pub fn depth_stencil_state(&self) -> DepthStencilDesc {
-        DepthStencilDesc(self.0.DepthStencilState)
+    pub fn bottom(&self) -> u32 {
+        self.0.bottom
     }
 
-    pub fn set_input_layout(
-        &mut self,
-        input_layout: &'il InputLayoutDesc,
-    ) -> &mut Self {
-        self.0.InputLayout = input_layout.0;
-        self.4 = PhantomData;
+    pub fn set_back(&mut self, back: u32) -> &mut Self {
+        self.0.back = back;
         self
     }
 
-    pub fn with_input_layout(
-        mut self,
-        input_layout: &'il InputLayoutDesc,
-    ) -> Self {
-        self.set_input_layout(input_layout);
+    pub fn with_back(mut self, back: u32) -> Self {
+        self.set_back(back);
         self
     }
 
-    pub fn input_layout(&self) -> &'il InputLayoutDesc {
-        unsafe {
-            &*(&self.0.InputLayout as *const D3D12_INPUT_LAYOUT_DESC
-                as *const InputLayoutDesc)
-        }
+    pub fn back(&self) -> u32 {
+        self.0.back
     }
+}
 
-    pub fn set_ib_strip_cut_value(
-        &mut self,
-        ib_strip_cut_value: IndexBufferStripCutValue,
-    ) -> &mut Self {
-        self.0.IBStripCutValue = ib_strip_cut_value as i32;
+/// Wrapper around D3D12_TILED_RESOURCE_COORDINATE structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct TiledResourceCoordinate(pub(crate) D3D12_TILED_RESOURCE_COORDINATE);
+
+impl TiledResourceCoordinate {
+    pub fn set_x(&mut self, x: u32) -> &mut Self {
+        self.0.X = x;
         self
     }
 
-    pub fn with_ib_strip_cut_value(
-        mut self,
-        ib_strip_cut_value: IndexBufferStripCutValue,
-    ) -> Self {
-        self.set_ib_strip_cut_value(ib_strip_cut_value);
+    pub fn with_x(mut self, x: u32) -> Self {
+        self.set_x(x);
         self
     }
 
-    pub fn ib_strip_cut_value(&self) -> IndexBufferStripCutValue {
-        unsafe { std::mem::transmute(self.0.IBStripCutValue) }
+    pub fn x(&self) -> u32 {
+        self.0.X
     }
 
-    pub fn set_primitive_topology_type(
-        &mut self,
-        primitive_topology_type: PrimitiveTopologyType,
-    ) -> &mut Self {
-        self.0.PrimitiveTopologyType = primitive_topology_type as i32;
+    pub fn set_y(&mut self, y: u32) -> &mut Self {
+        self.0.Y = y;
         self
     }
 
-    pub fn with_primitive_topology_type(
-        mut self,
-        primitive_topology_type: PrimitiveTopologyType,
-    ) -> Self {
-        self.set_primitive_topology_type(primitive_topology_type);
+    pub fn with_y(mut self, y: u32) -> Self {
+        self.set_y(y);
         self
     }
 
-    pub fn primitive_topology_type(&self) -> PrimitiveTopologyType {
-        unsafe { std::mem::transmute(self.0.PrimitiveTopologyType) }
+    pub fn y(&self) -> u32 {
+        self.0.Y
     }
 
-    pub fn set_rtv_formats(&mut self, rtv_formats: &[Format]) -> &mut Self {
-        for format_index in 0..rtv_formats.len() {
-            self.0.RTVFormats[format_index] = rtv_formats[format_index] as i32;
-        }
-        self.0.NumRenderTargets = rtv_formats.len() as u32;
+    pub fn set_z(&mut self, z: u32) -> &mut Self {
+        self.0.Z = z;
         self
     }
 
-    pub fn with_rtv_formats(mut self, rtv_formats: &[Format]) -> Self {
-        self.set_rtv_formats(rtv_formats);
+    pub fn with_z(mut self, z: u32) -> Self {
+        self.set_z(z);
         self
     }
 
-    pub fn rtv_formats(&self) -> &[Format] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.RTVFormats.as_ptr() as *const Format,
-                self.0.NumRenderTargets as usize,
-            )
-        }
-    }
-
-    // Note there are no setters since they are both useless and can break the invariant
-    pub fn num_render_targets(&self) -> u32 {
-        self.0.NumRenderTargets
+    pub fn z(&self) -> u32 {
+        self.0.Z
     }
 
-    pub fn set_dsv_format(&mut self, dsv_format: Format) -> &mut Self {
-        self.0.DSVFormat = dsv_format as i32;
+    pub fn set_subresource(&mut self, subresource: u32) -> &mut Self {
+        self.0.Subresource = subresource;
         self
     }
 
-    pub fn with_dsv_format(mut self, dsv_format: Format) -> Self {
-        self.set_dsv_format(dsv_format);
+    pub fn with_subresource(mut self, subresource: u32) -> Self {
+        self.set_subresource(subresource);
         self
     }
 
-    pub fn dsv_format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.DSVFormat) }
+    pub fn subresource(&self) -> u32 {
+        self.0.Subresource
     }
+}
 
-    pub fn set_sample_desc(&mut self, sample_desc: SampleDesc) -> &mut Self {
-        self.0.SampleDesc = sample_desc.0;
+/// Wrapper around D3D12_TILE_REGION_SIZE structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct TileRegionSize(pub(crate) D3D12_TILE_REGION_SIZE);
+
+impl TileRegionSize {
+    pub fn set_num_tiles(&mut self, num_tiles: u32) -> &mut Self {
+        self.0.NumTiles = num_tiles;
         self
     }
 
-    pub fn with_sample_desc(mut self, sample_desc: SampleDesc) -> Self {
-        self.set_sample_desc(sample_desc);
+    pub fn with_num_tiles(mut self, num_tiles: u32) -> Self {
+        self.set_num_tiles(num_tiles);
         self
     }
 
-    pub fn sample_desc(&self) -> SampleDesc {
-        SampleDesc(self.0.SampleDesc)
+    pub fn num_tiles(&self) -> u32 {
+        self.0.NumTiles
     }
 
-    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
-        self.0.NodeMask = node_mask;
+    pub fn set_use_box(&mut self, use_box: bool) -> &mut Self {
+        self.0.UseBox = use_box as i32;
         self
     }
 
-    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
-        self.set_node_mask(node_mask);
+    pub fn with_use_box(mut self, use_box: bool) -> Self {
+        self.set_use_box(use_box);
         self
     }
 
-    pub fn node_mask(&self) -> u32 {
-        self.0.NodeMask
-    }
-
-    pub fn set_cached_pso(
-        &mut self,
-        cached_pso: &'sh CachedPipelineState,
-    ) -> &mut Self {
-        self.0.CachedPSO = cached_pso.0;
-        self.2 = PhantomData;
-        self
-    }
-
-    pub fn with_cached_pso(
-        mut self,
-        cached_pso: &'sh CachedPipelineState,
-    ) -> Self {
-        self.set_cached_pso(cached_pso);
-        self
-    }
-
-    // ToDo: probably it'd be simpler to just have one lifetime
-    // parameter on GraphicsPipelineStateDesc?
-    pub fn cached_pso(&self) -> &'sh CachedPipelineState {
-        unsafe {
-            &*(&self.0.CachedPSO as *const D3D12_CACHED_PIPELINE_STATE
-                as *const CachedPipelineState)
-        }
+    pub fn use_box(&self) -> bool {
+        self.0.UseBox != 0
     }
 
-    pub fn set_flags(&mut self, flags: PipelineStateFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    pub fn set_width(&mut self, width: u32) -> &mut Self {
+        self.0.Width = width;
         self
     }
 
-    pub fn with_flags(mut self, flags: PipelineStateFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.set_width(width);
         self
     }
 
-    pub fn flags(&self) -> PipelineStateFlags {
-        unsafe { std::mem::transmute(self.0.Flags) }
+    pub fn width(&self) -> u32 {
+        self.0.Width
     }
-}
-
-/// Wrapper around D3D12_COMPUTE_PIPELINE_STATE_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
-#[repr(transparent)]
-pub struct ComputePipelineStateDesc<'rs, 'sh>(
-    pub D3D12_COMPUTE_PIPELINE_STATE_DESC,
-    PhantomData<&'rs RootSignature>,
-    PhantomData<&'sh ShaderBytecode<'sh>>,
-);
 
-impl<'rs, 'sh> ComputePipelineStateDesc<'rs, 'sh> {
-    pub fn set_root_signature(
-        &mut self,
-        root_signature: &'rs RootSignature,
-    ) -> &mut ComputePipelineStateDesc<'rs, 'sh> {
-        self.0.pRootSignature = root_signature.this;
-        self.1 = PhantomData;
+    pub fn set_height(&mut self, height: u16) -> &mut Self {
+        self.0.Height = height;
         self
     }
 
-    pub fn with_root_signature(
-        mut self,
-        root_signature: &'rs RootSignature,
-    ) -> ComputePipelineStateDesc<'rs, 'sh> {
-        self.set_root_signature(root_signature);
+    pub fn with_height(mut self, height: u16) -> Self {
+        self.set_height(height);
         self
     }
 
-    pub fn root_signature(&self) -> RootSignature {
-        let root_signature = RootSignature {
-            this: self.0.pRootSignature,
-        };
-        root_signature.add_ref();
-        root_signature
+    pub fn height(&self) -> u16 {
+        self.0.Height
     }
 
-    pub fn set_cs_bytecode(
-        &mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> &mut ComputePipelineStateDesc<'rs, 'sh> {
-        self.0.CS = bytecode.0;
-        self.2 = PhantomData;
+    pub fn set_depth(&mut self, depth: u16) -> &mut Self {
+        self.0.Depth = depth;
         self
     }
 
-    pub fn with_cs_bytecode(
-        mut self,
-        bytecode: &'sh ShaderBytecode,
-    ) -> ComputePipelineStateDesc<'rs, 'sh> {
-        self.set_cs_bytecode(bytecode);
+    pub fn with_depth(mut self, depth: u16) -> Self {
+        self.set_depth(depth);
         self
     }
 
-    pub fn cs_bytecode(&self) -> &'sh ShaderBytecode {
-        unsafe {
-            &*(&self.0.CS as *const D3D12_SHADER_BYTECODE
-                as *const ShaderBytecode)
-        }
+    pub fn depth(&self) -> u16 {
+        self.0.Depth
     }
+}
 
-    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
-        self.0.NodeMask = node_mask;
-        self
-    }
+/// Wrapper around D3D12_SUBRESOURCE_TILING structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct SubresourceTiling(pub(crate) D3D12_SUBRESOURCE_TILING);
 
-    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
-        self.set_node_mask(node_mask);
-        self
+impl SubresourceTiling {
+    pub fn width_in_tiles(&self) -> u32 {
+        self.0.WidthInTiles
     }
 
-    pub fn node_mask(&self) -> u32 {
-        self.0.NodeMask
+    pub fn height_in_tiles(&self) -> u16 {
+        self.0.HeightInTiles
     }
 
-    pub fn set_cached_pso(
-        &mut self,
-        cached_pso: &'sh CachedPipelineState,
-    ) -> &mut ComputePipelineStateDesc<'rs, 'sh> {
-        self.0.CachedPSO = cached_pso.0;
-        self.2 = PhantomData;
-        self
+    pub fn depth_in_tiles(&self) -> u16 {
+        self.0.DepthInTiles
     }
 
-    pub fn with_cached_pso(
-        mut self,
-        cached_pso: &'sh CachedPipelineState,
-    ) -> ComputePipelineStateDesc<'rs, 'sh> {
-        self.set_cached_pso(cached_pso);
-        self
+    pub fn start_tile_index_in_overall_resource(&self) -> u32 {
+        self.0.StartTileIndexInOverallResource
     }
+}
 
-    // ToDo: probably it'd be simpler to just have one lifetime
-    // parameter on ComputePipelineStateDesc?
-    pub fn cached_pso(&self) -> &'sh CachedPipelineState {
-        unsafe {
-            &*(&self.0.CachedPSO as *const D3D12_CACHED_PIPELINE_STATE
-                as *const CachedPipelineState)
-        }
-    }
+/// Wrapper around D3D12_TILE_SHAPE structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct TileShape(pub(crate) D3D12_TILE_SHAPE);
 
-    pub fn set_flags(
-        &mut self,
-        pipeline_state_flags: PipelineStateFlags,
-    ) -> &mut Self {
-        self.0.Flags = pipeline_state_flags.bits();
-        self
+impl TileShape {
+    pub fn width_in_texels(&self) -> u32 {
+        self.0.WidthInTexels
     }
 
-    pub fn with_flags(
-        mut self,
-        pipeline_state_flags: PipelineStateFlags,
-    ) -> Self {
-        self.set_flags(pipeline_state_flags);
-        self
+    pub fn height_in_texels(&self) -> u32 {
+        self.0.HeightInTexels
     }
 
-    pub fn flags(&self) -> PipelineStateFlags {
-        unsafe { std::mem::transmute(self.0.Flags) }
+    pub fn depth_in_texels(&self) -> u32 {
+        self.0.DepthInTexels
     }
 }
 
-/// Wrapper around D3D12_SUBRESOURCE_FOOTPRINT structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+/// Wrapper around D3D12_PACKED_MIP_INFO structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
 #[repr(transparent)]
-pub struct SubresourceFootprint(pub(crate) D3D12_SUBRESOURCE_FOOTPRINT);
-
-impl Default for SubresourceFootprint {
-    fn default() -> Self {
-        Self(D3D12_SUBRESOURCE_FOOTPRINT {
-            Format: Format::R8G8B8A8Unorm as i32,
-            Width: 0,
-            Height: 1,
-            Depth: 1,
-            RowPitch: 0,
-        })
-    }
-}
-
-impl SubresourceFootprint {
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
-        self
-    }
+pub struct PackedMipInfo(pub(crate) D3D12_PACKED_MIP_INFO);
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
-        self
+impl PackedMipInfo {
+    pub fn num_standard_mips(&self) -> u8 {
+        self.0.NumStandardMips
     }
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
+    pub fn num_packed_mips(&self) -> u8 {
+        self.0.NumPackedMips
     }
 
-    pub fn set_width(&mut self, width: u32) -> &mut Self {
-        self.0.Width = width;
-        self
+    pub fn num_tiles_for_packed_mips(&self) -> u32 {
+        self.0.NumTilesForPackedMips
     }
 
-    pub fn with_width(mut self, width: u32) -> Self {
-        self.set_width(width);
-        self
+    pub fn start_tile_index_in_overall_resource(&self) -> u32 {
+        self.0.StartTileIndexInOverallResource
     }
+}
 
-    pub fn width(&self) -> u32 {
-        self.0.Width
-    }
+/// Wrapper around D3D12_VERTEX_BUFFER_VIEW structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct VertexBufferView(pub(crate) D3D12_VERTEX_BUFFER_VIEW);
 
-    pub fn set_height(&mut self, height: u32) -> &mut Self {
-        self.0.Height = height;
+impl VertexBufferView {
+    pub fn set_buffer_location(
+        &mut self,
+        buffer_location: GpuVirtualAddress,
+    ) -> &mut Self {
+        self.0.BufferLocation = buffer_location.0;
         self
     }
 
-    pub fn with_height(mut self, height: u32) -> Self {
-        self.set_height(height);
+    pub fn with_buffer_location(
+        mut self,
+        buffer_location: GpuVirtualAddress,
+    ) -> Self {
+        self.set_buffer_location(buffer_location);
         self
     }
 
-    pub fn height(&self) -> u32 {
-        self.0.Height
+    pub fn buffer_location(&self) -> GpuVirtualAddress {
+        GpuVirtualAddress(self.0.BufferLocation)
     }
 
-    pub fn set_depth(&mut self, depth: u32) -> &mut Self {
-        self.0.Depth = depth;
+    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
+        self.0.SizeInBytes = size_in_bytes.0 as u32;
         self
     }
 
-    pub fn with_depth(mut self, depth: u32) -> Self {
-        self.set_depth(depth);
+    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
+        self.set_size_in_bytes(size_in_bytes);
         self
     }
 
-    pub fn depth(&self) -> u32 {
-        self.0.Depth
+    pub fn size_in_bytes(&self) -> ByteCount {
+        ByteCount::from(self.0.SizeInBytes)
     }
 
-    pub fn set_row_pitch(&mut self, row_pitch: ByteCount) -> &mut Self {
-        self.0.RowPitch = row_pitch.0 as u32;
+    pub fn set_stride_in_bytes(
+        &mut self,
+        stride_in_bytes: ByteCount,
+    ) -> &mut Self {
+        self.0.StrideInBytes = stride_in_bytes.0 as u32;
         self
     }
 
-    pub fn with_row_pitch(mut self, row_pitch: ByteCount) -> Self {
-        self.set_row_pitch(row_pitch);
+    pub fn with_stride_in_bytes(mut self, stride_in_bytes: ByteCount) -> Self {
+        self.set_stride_in_bytes(stride_in_bytes);
         self
     }
 
-    pub fn row_pitch(&self) -> ByteCount {
-        ByteCount::from(self.0.RowPitch)
+    pub fn stride_in_bytes(&self) -> ByteCount {
+        ByteCount::from(self.0.StrideInBytes)
     }
 }
 
-/// Wrapper around D3D12_PLACED_SUBRESOURCE_FOOTPRINT structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+/// Wrapper around D3D12_INPUT_ELEMENT_DESC structure
 #[repr(transparent)]
-pub struct PlacedSubresourceFootprint(
-    pub(crate) D3D12_PLACED_SUBRESOURCE_FOOTPRINT,
+#[derive(Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct InputElementDesc<'a>(
+    pub D3D12_INPUT_ELEMENT_DESC,
+    PhantomData<&'a CStr>,
 );
 
-impl Default for PlacedSubresourceFootprint {
-    fn default() -> Self {
-        Self(D3D12_PLACED_SUBRESOURCE_FOOTPRINT {
-            Offset: 0,
-            Footprint: SubresourceFootprint::default().0,
-        })
+impl<'a> Default for InputElementDesc<'a> {
+    fn default() -> InputElementDesc<'a> {
+        InputElementDesc(D3D12_INPUT_ELEMENT_DESC {
+            SemanticName: std::ptr::null(),
+            SemanticIndex: 0,
+            Format: Format::Unknown as i32,
+            InputSlot: 0,
+            AlignedByteOffset: 0,
+            InputSlotClass:
+        D3D12_INPUT_CLASSIFICATION_D3D12_INPUT_CLASSIFICATION_PER_VERTEX_DATA,
+            InstanceDataStepRate: 0,
+        },
+        PhantomData
+    )
     }
 }
 
-impl PlacedSubresourceFootprint {
-    pub fn set_offset(&mut self, offset: ByteCount) -> &mut Self {
-        self.0.Offset = offset.0 as u64;
-        self
+// ToDo: macro for generating input element desc from vertex struct type?
+
+impl<'a> InputElementDesc<'a> {
+    pub fn set_semantic_name(
+        &mut self,
+        name: &'a str,
+    ) -> Result<&mut Self, NulError> {
+        let owned = CString::new(name)?;
+        self.0.SemanticName = owned.into_raw() as *const i8;
+        self.1 = PhantomData;
+        Ok(self)
     }
 
-    pub fn with_offset(mut self, offset: ByteCount) -> Self {
-        self.set_offset(offset);
-        self
+    pub fn with_semantic_name(
+        mut self,
+        name: &'a str,
+    ) -> Result<Self, NulError> {
+        match self.set_semantic_name(name) {
+            Ok(_) => Ok(self),
+            Err(err) => Err(err),
+        }
     }
 
-    pub fn offset(&self) -> ByteCount {
-        ByteCount::from(self.0.Offset)
+    pub fn semantic_name(&self) -> Result<&'a str, Utf8Error> {
+        Ok(unsafe { std::ffi::CStr::from_ptr(self.0.SemanticName).to_str()? })
     }
 
-    pub fn set_footprint(
-        &mut self,
-        footprint: SubresourceFootprint,
-    ) -> &mut Self {
-        self.0.Footprint = footprint.0;
+    pub fn set_semantic_index(&mut self, semantic_index: u32) -> &mut Self {
+        self.0.SemanticIndex = semantic_index;
         self
     }
 
-    pub fn with_footprint(mut self, footprint: SubresourceFootprint) -> Self {
-        self.set_footprint(footprint);
+    pub fn with_semantic_index(mut self, semantic_index: u32) -> Self {
+        self.set_semantic_index(semantic_index);
         self
     }
 
-    pub fn footprint(&self) -> SubresourceFootprint {
-        SubresourceFootprint(self.0.Footprint)
+    pub fn semantic_index(&self) -> u32 {
+        self.0.SemanticIndex
     }
-}
-
-/// Wrapper around D3D12_CONSTANT_BUFFER_VIEW_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct ConstantBufferViewDesc(pub(crate) D3D12_CONSTANT_BUFFER_VIEW_DESC);
 
-impl ConstantBufferViewDesc {
-    pub fn set_buffer_location(
-        &mut self,
-        buffer_location: GpuVirtualAddress,
-    ) -> &mut Self {
-        self.0.BufferLocation = buffer_location.0;
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
         self
     }
 
-    pub fn with_buffer_location(
-        mut self,
-        buffer_location: GpuVirtualAddress,
-    ) -> Self {
-        self.set_buffer_location(buffer_location);
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
         self
     }
 
-    pub fn buffer_location(&self) -> GpuVirtualAddress {
-        GpuVirtualAddress(self.0.BufferLocation)
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
     }
 
-    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
-        self.0.SizeInBytes = size_in_bytes.0 as u32;
+    pub fn set_input_slot(&mut self, input_slot: u32) -> &mut Self {
+        self.0.InputSlot = input_slot;
         self
     }
 
-    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
-        self.set_size_in_bytes(size_in_bytes);
+    pub fn with_input_slot(mut self, input_slot: u32) -> Self {
+        self.set_input_slot(input_slot);
         self
     }
 
-    pub fn size_in_bytes(&self) -> ByteCount {
-        ByteCount::from(self.0.SizeInBytes)
-    }
-}
-
-// ToDo: rethink the 'pub's in such wrappers
-/// Wrapper around D3D12_DESCRIPTOR_HEAP_DESC structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-pub struct DescriptorHeapDesc(pub(crate) D3D12_DESCRIPTOR_HEAP_DESC);
-
-impl Default for DescriptorHeapDesc {
-    fn default() -> Self {
-        Self(D3D12_DESCRIPTOR_HEAP_DESC {
-            Type: DescriptorHeapType::CbvSrvUav as i32,
-            NumDescriptors: 0,
-            Flags: DescriptorHeapFlags::None.bits(),
-            NodeMask: 0,
-        })
+    pub fn input_slot(&self) -> u32 {
+        self.0.InputSlot
     }
-}
 
-impl DescriptorHeapDesc {
-    pub fn set_heap_type(
+    pub fn set_aligned_byte_offset(
         &mut self,
-        heap_type: DescriptorHeapType,
+        aligned_byte_offset: ByteCount,
     ) -> &mut Self {
-        self.0.Type = heap_type as i32;
+        self.0.AlignedByteOffset = aligned_byte_offset.0 as u32;
         self
     }
 
-    pub fn with_heap_type(mut self, heap_type: DescriptorHeapType) -> Self {
-        self.set_heap_type(heap_type);
+    pub fn with_aligned_byte_offset(
+        mut self,
+        aligned_byte_offset: ByteCount,
+    ) -> Self {
+        self.set_aligned_byte_offset(aligned_byte_offset);
         self
     }
 
-    pub fn heap_type(&self) -> DescriptorHeapType {
-        unsafe { std::mem::transmute(self.0.Type) }
+    pub fn aligned_byte_offset(&self) -> ByteCount {
+        ByteCount::from(self.0.AlignedByteOffset)
     }
 
-    pub fn set_num_descriptors(&mut self, num_descriptors: u32) -> &mut Self {
-        self.0.NumDescriptors = num_descriptors;
+    pub fn set_input_slot_class(
+        &mut self,
+        input_slot_class: InputClassification,
+    ) -> &mut Self {
+        self.0.InputSlotClass = input_slot_class as i32;
         self
     }
 
-    pub fn with_num_descriptors(mut self, num_descriptors: u32) -> Self {
-        self.set_num_descriptors(num_descriptors);
+    pub fn with_input_slot_class(
+        mut self,
+        input_slot_class: InputClassification,
+    ) -> Self {
+        self.set_input_slot_class(input_slot_class);
         self
     }
 
-    pub fn num_descriptors(&self) -> u32 {
-        self.0.NumDescriptors
-    }
-
-    pub fn set_flags(&mut self, flags: DescriptorHeapFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
-        self
+    pub fn input_slot_class(&self) -> InputClassification {
+        unsafe { std::mem::transmute(self.0.InputSlotClass) }
     }
 
-    pub fn with_flags(mut self, flags: DescriptorHeapFlags) -> Self {
-        self.set_flags(flags);
+    pub fn set_instance_data_step_rate(
+        &mut self,
+        instance_data_step_rate: u32,
+    ) -> &mut Self {
+        self.0.InstanceDataStepRate = instance_data_step_rate;
         self
     }
 
-    pub fn flags(&self) -> DescriptorHeapFlags {
-        unsafe { DescriptorHeapFlags::from_bits_unchecked(self.0.Flags) }
-    }
-
-    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
-        self.0.NodeMask = node_mask;
+    pub fn with_instance_data_step_rate(
+        mut self,
+        instance_data_step_rate: u32,
+    ) -> Self {
+        self.set_instance_data_step_rate(instance_data_step_rate);
         self
     }
 
-    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
-        self.set_node_mask(node_mask);
-        self
+    pub fn instance_data_step_rate(&self) -> u32 {
+        self.0.InstanceDataStepRate
     }
+}
 
-    pub fn node_mask(&self) -> u32 {
-        self.0.NodeMask
+// We need this because we transfer ownership of the CString "name" into
+// the raw C string (const char*) "SemanticName". Since this memory has to be
+// valid until the destruction of this struct, we need to regain that memory
+// back so it can be destroyed correctly
+impl<'a> Drop for InputElementDesc<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            let _regained_name = CString::from_raw(
+                self.0.SemanticName as *mut std::os::raw::c_char,
+            );
+        }
     }
 }
 
-/// Wrapper around D3D12_COMMAND_QUEUE_DESC structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+/// Wrapper around D3D12_INDEX_BUFFER_VIEW structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
 #[repr(transparent)]
-pub struct CommandQueueDesc(pub(crate) D3D12_COMMAND_QUEUE_DESC);
-
-impl CommandQueueDesc {
-    pub fn set_queue_type(&mut self, queue_type: CommandListType) -> &mut Self {
-        self.0.Type = queue_type as i32;
-        self
-    }
-
-    pub fn with_queue_type(mut self, queue_type: CommandListType) -> Self {
-        self.set_queue_type(queue_type);
-        self
-    }
-
-    pub fn queue_type(&self) -> CommandListType {
-        unsafe { std::mem::transmute(self.0.Type) }
-    }
+pub struct IndexBufferView(pub(crate) D3D12_INDEX_BUFFER_VIEW);
 
-    pub fn set_priority(&mut self, priority: i32) -> &mut Self {
-        self.0.Priority = priority;
+impl IndexBufferView {
+    pub fn set_buffer_location(
+        &mut self,
+        buffer_location: GpuVirtualAddress,
+    ) -> &mut Self {
+        self.0.BufferLocation = buffer_location.0;
         self
     }
 
-    pub fn with_priority(mut self, priority: i32) -> Self {
-        self.set_priority(priority);
+    pub fn with_buffer_location(
+        mut self,
+        buffer_location: GpuVirtualAddress,
+    ) -> Self {
+        self.set_buffer_location(buffer_location);
         self
     }
 
-    pub fn priority(&self) -> i32 {
-        self.0.Priority
+    pub fn buffer_location(&self) -> GpuVirtualAddress {
+        GpuVirtualAddress(self.0.BufferLocation)
     }
 
-    pub fn set_flags(&mut self, flags: CommandQueueFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
+        self.0.SizeInBytes = size_in_bytes.0 as u32;
         self
     }
 
-    pub fn with_flags(mut self, flags: CommandQueueFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
+        self.set_size_in_bytes(size_in_bytes);
         self
     }
 
-    pub fn flags(&self) -> CommandQueueFlags {
-        unsafe { CommandQueueFlags::from_bits_unchecked(self.0.Flags) }
+    pub fn size_in_bytes(&self) -> ByteCount {
+        ByteCount::from(self.0.SizeInBytes)
     }
 
-    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
-        self.0.NodeMask = node_mask;
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
         self
     }
 
-    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
-        self.set_node_mask(node_mask);
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
         self
     }
 
-    pub fn node_mask(&self) -> u32 {
-        self.0.NodeMask
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
     }
 }
 
-/// Wrapper around D3D12_FEATURE_DATA_ROOT_SIGNATURE structure
+/// Wrapper around D3D12_SHADER_BYTECODE structure
 #[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-pub struct FeatureDataRootSignature(
-    pub(crate) D3D12_FEATURE_DATA_ROOT_SIGNATURE,
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
+pub struct ShaderBytecode<'a>(
+    pub(crate) D3D12_SHADER_BYTECODE,
+    PhantomData<&'a [u8]>,
 );
 
-impl FeatureDataRootSignature {
-    pub fn new(version: RootSignatureVersion) -> Self {
-        Self(D3D12_FEATURE_DATA_ROOT_SIGNATURE {
-            HighestVersion: version as i32,
-        })
-    }
-
-    pub fn set_highest_version(
-        &mut self,
-        highest_version: RootSignatureVersion,
-    ) -> &mut Self {
-        self.0.HighestVersion = highest_version as i32;
-        self
-    }
-
-    pub fn with_highest_version(
-        mut self,
-        highest_version: RootSignatureVersion,
-    ) -> Self {
-        self.set_highest_version(highest_version);
-        self
-    }
-
-    pub fn highest_version(&self) -> RootSignatureVersion {
-        unsafe { std::mem::transmute(self.0.HighestVersion) }
-    }
-}
-
-/// Newtype around [u32] since it has a special value of [DESCRIPTOR_RANGE_OFFSET_APPEND]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-pub struct DescriptorRangeOffset(pub(crate) u32);
-
-impl From<u32> for DescriptorRangeOffset {
-    fn from(count: u32) -> Self {
-        Self(count)
+impl<'a> Default for ShaderBytecode<'a> {
+    fn default() -> ShaderBytecode<'a> {
+        ShaderBytecode(
+            D3D12_SHADER_BYTECODE {
+                pShaderBytecode: std::ptr::null(),
+                BytecodeLength: 0,
+            },
+            PhantomData,
+        )
     }
 }
 
-impl DescriptorRangeOffset {
-    pub fn append() -> Self {
-        Self(D3D12_DESCRIPTOR_RANGE_OFFSET_APPEND)
+impl<'a> ShaderBytecode<'a> {
+    pub fn new(data: &'a [u8]) -> ShaderBytecode<'a> {
+        Self(
+            D3D12_SHADER_BYTECODE {
+                pShaderBytecode: data.as_ptr() as *const std::ffi::c_void,
+                BytecodeLength: data.len() as u64,
+            },
+            PhantomData,
+        )
     }
 }
 
-/// Wrapper around D3D12_DESCRIPTOR_RANGE1 structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
-#[repr(transparent)]
-pub struct DescriptorRange(pub(crate) D3D12_DESCRIPTOR_RANGE1);
+/// Wrapper around D3D12_SO_DECLARATION_ENTRY structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
+pub struct SoDeclarationEntry<'a>(
+    pub D3D12_SO_DECLARATION_ENTRY,
+    PhantomData<&'a str>,
+);
 
-impl DescriptorRange {
-    pub fn set_range_type(
-        &mut self,
-        range_type: DescriptorRangeType,
-    ) -> &mut Self {
-        self.0.RangeType = range_type as i32;
+impl<'a> SoDeclarationEntry<'a> {
+    pub fn set_stream(&mut self, stream: u32) -> &mut Self {
+        self.0.Stream = stream;
         self
     }
 
-    pub fn with_range_type(mut self, range_type: DescriptorRangeType) -> Self {
-        self.set_range_type(range_type);
+    pub fn with_stream(mut self, stream: u32) -> Self {
+        self.set_stream(stream);
         self
     }
 
-    pub fn range_type(&self) -> DescriptorRangeType {
-        unsafe { std::mem::transmute(self.0.RangeType) }
+    pub fn stream(&self) -> u32 {
+        self.0.Stream
     }
 
-    pub fn set_num_descriptors(&mut self, num_descriptors: u32) -> &mut Self {
-        self.0.NumDescriptors = num_descriptors;
-        self
+    pub fn set_semantic_name(
+        &mut self,
+        name: &'a str,
+    ) -> Result<&mut Self, NulError> {
+        let owned = CString::new(name)?;
+        self.0.SemanticName = owned.into_raw() as *const i8;
+        self.1 = PhantomData;
+        Ok(self)
     }
 
-    pub fn with_num_descriptors(mut self, num_descriptors: u32) -> Self {
-        self.set_num_descriptors(num_descriptors);
-        self
+    pub fn with_semantic_name(
+        mut self,
+        name: &'a str,
+    ) -> Result<Self, NulError> {
+        match self.set_semantic_name(name) {
+            Ok(_) => Ok(self),
+            Err(err) => Err(err),
+        }
     }
 
-    pub fn num_descriptors(&self) -> u32 {
-        self.0.NumDescriptors
+    pub fn semantic_name(&self) -> Result<&'a str, Utf8Error> {
+        Ok(unsafe { std::ffi::CStr::from_ptr(self.0.SemanticName).to_str()? })
     }
 
-    pub fn set_base_shader_register(
-        &mut self,
-        base_shader_register: u32,
-    ) -> &mut Self {
-        self.0.BaseShaderRegister = base_shader_register;
+    pub fn set_semantic_index(&mut self, semantic_index: u32) -> &mut Self {
+        self.0.SemanticIndex = semantic_index;
         self
     }
 
-    pub fn with_base_shader_register(
-        mut self,
-        base_shader_register: u32,
-    ) -> Self {
-        self.set_base_shader_register(base_shader_register);
+    pub fn with_semantic_index(mut self, semantic_index: u32) -> Self {
+        self.set_semantic_index(semantic_index);
         self
     }
 
-    pub fn base_shader_register(&self) -> u32 {
-        self.0.BaseShaderRegister
+    pub fn semantic_index(&self) -> u32 {
+        self.0.SemanticIndex
     }
 
-    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
-        self.0.RegisterSpace = register_space;
+    pub fn set_start_component(&mut self, start_component: u8) -> &mut Self {
+        self.0.StartComponent = start_component;
         self
     }
 
-    pub fn with_register_space(mut self, register_space: u32) -> Self {
-        self.set_register_space(register_space);
+    pub fn with_start_component(mut self, start_component: u8) -> Self {
+        self.set_start_component(start_component);
         self
     }
 
-    pub fn register_space(&self) -> u32 {
-        self.0.RegisterSpace
+    pub fn start_component(&self) -> u8 {
+        self.0.StartComponent
     }
 
-    pub fn set_flags(&mut self, flags: DescriptorRangeFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    pub fn set_component_count(&mut self, component_count: u8) -> &mut Self {
+        self.0.ComponentCount = component_count;
         self
     }
 
-    pub fn with_flags(mut self, flags: DescriptorRangeFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_component_count(mut self, component_count: u8) -> Self {
+        self.set_component_count(component_count);
         self
     }
 
-    pub fn flags(&self) -> DescriptorRangeFlags {
-        unsafe { DescriptorRangeFlags::from_bits_unchecked(self.0.Flags) }
+    pub fn component_count(&self) -> u8 {
+        self.0.ComponentCount
     }
 
-    pub fn set_offset_in_descriptors_from_table_start(
-        &mut self,
-        offset_in_descriptors_from_table_start: DescriptorRangeOffset,
-    ) -> &mut Self {
-        self.0.OffsetInDescriptorsFromTableStart =
-            offset_in_descriptors_from_table_start.0;
+    pub fn set_output_slot(&mut self, output_slot: u8) -> &mut Self {
+        self.0.OutputSlot = output_slot;
         self
     }
 
-    pub fn with_offset_in_descriptors_from_table_start(
-        mut self,
-        offset_in_descriptors_from_table_start: DescriptorRangeOffset,
-    ) -> Self {
-        self.set_offset_in_descriptors_from_table_start(
-            offset_in_descriptors_from_table_start,
-        );
+    pub fn with_output_slot(mut self, output_slot: u8) -> Self {
+        self.set_output_slot(output_slot);
         self
     }
 
-    pub fn offset_in_descriptors_from_table_start(
-        &self,
-    ) -> DescriptorRangeOffset {
-        self.0.OffsetInDescriptorsFromTableStart.into()
+    pub fn output_slot(&self) -> u8 {
+        self.0.OutputSlot
     }
 }
 
-/// Wrapper around D3D12_ROOT_PARAMETER1 structure
-#[derive(Debug, Default)]
+// We need this because we transfer ownership of the CString "name" into
+// the raw C string (const char*) "SemanticName". Since this memory has to be
+// valid until the destruction of this struct, we need to regain that memory
+// back so it can be destroyed correctly
+impl<'a> Drop for SoDeclarationEntry<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            let _regained_name = CString::from_raw(
+                self.0.SemanticName as *mut std::os::raw::c_char,
+            );
+        }
+    }
+}
+
+/// Wrapper around D3D12_STREAM_OUTPUT_DESC structure
 #[repr(transparent)]
-pub struct RootParameter<'a>(
-    pub(crate) D3D12_ROOT_PARAMETER1,
-    PhantomData<&'a RootDescriptorTable<'a>>,
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
+pub struct StreamOutputDesc<'a>(
+    pub D3D12_STREAM_OUTPUT_DESC,
+    PhantomData<&'a [SoDeclarationEntry<'a>]>,
 );
 
-impl<'a> RootParameter<'a> {
-    pub fn parameter_type(&self) -> RootParameterType {
-        unsafe { std::mem::transmute(self.0.ParameterType) }
+impl<'a> Default for StreamOutputDesc<'a> {
+    fn default() -> Self {
+        Self(
+            D3D12_STREAM_OUTPUT_DESC {
+                pSODeclaration: std::ptr::null(),
+                NumEntries: 0,
+                pBufferStrides: std::ptr::null(),
+                NumStrides: 0,
+                RasterizedStream: 0,
+            },
+            PhantomData,
+        )
+    }
+}
+
+impl<'a> StreamOutputDesc<'a> {
+    pub fn set_so_declarations(
+        &mut self,
+        so_declarations: &'a [SoDeclarationEntry],
+    ) -> &mut StreamOutputDesc<'a> {
+        self.0.pSODeclaration =
+            so_declarations.as_ptr() as *const D3D12_SO_DECLARATION_ENTRY;
+        self.0.NumEntries = so_declarations.len() as u32;
+        self.1 = PhantomData;
+        self
     }
 
-    pub fn new_descriptor_table(
+    pub fn with_so_declarations(
         mut self,
-        descriptor_table: &'a RootDescriptorTable<'a>,
+        so_declarations: &'a [SoDeclarationEntry],
     ) -> Self {
-        self.0.ParameterType = RootParameterType::DescriptorTable as i32;
-        self.0.__bindgen_anon_1.DescriptorTable = descriptor_table.0;
-        self.1 = PhantomData;
+        self.set_so_declarations(so_declarations);
         self
     }
 
-    pub fn descriptor_table(&self) -> Option<RootDescriptorTable> {
+    pub fn so_declarations(&self) -> &'a [SoDeclarationEntry] {
         unsafe {
-            match self.parameter_type() {
-                RootParameterType::DescriptorTable => {
-                    Some(RootDescriptorTable(
-                        self.0.__bindgen_anon_1.DescriptorTable,
-                        PhantomData,
-                    ))
-                }
-                _ => None,
-            }
+            slice::from_raw_parts(
+                self.0.pSODeclaration as *const SoDeclarationEntry,
+                self.0.NumEntries as usize,
+            )
         }
     }
 
-    pub fn new_constants(mut self, constants: &RootConstants) -> Self {
-        self.0.ParameterType = RootParameterType::T32BitConstants as i32;
-        self.0.__bindgen_anon_1.Constants = constants.0;
+    // Note there are no setters since they are both useless and can break the invariant
+    pub fn num_entries(&self) -> u32 {
+        self.0.NumEntries
+    }
+
+    pub fn set_buffer_strides(&mut self, buffer_strides: &[u32]) -> &mut Self {
+        self.0.pBufferStrides = buffer_strides.as_ptr();
+        self.0.NumStrides = buffer_strides.len() as u32;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn constants(&self) -> Option<RootConstants> {
-        unsafe {
-            match self.parameter_type() {
-                RootParameterType::T32BitConstants => {
-                    Some(RootConstants(self.0.__bindgen_anon_1.Constants))
-                }
-                _ => None,
-            }
-        }
-    }
-
-    pub fn new_descriptor(
-        mut self,
-        descriptor: &RootDescriptor,
-        descriptor_type: RootParameterType,
-    ) -> Self {
-        assert!(
-            descriptor_type == RootParameterType::Cbv
-                || descriptor_type == RootParameterType::Srv
-                || descriptor_type == RootParameterType::Uav
-        );
-        self.0.ParameterType = descriptor_type as i32;
-        self.0.__bindgen_anon_1.Descriptor = descriptor.0;
+    pub fn with_buffer_strides(mut self, buffer_strides: &[u32]) -> Self {
+        self.set_buffer_strides(buffer_strides);
         self
     }
 
-    pub fn descriptor(&self) -> Option<RootDescriptor> {
+    pub fn buffer_strides(&self) -> &'a [u32] {
         unsafe {
-            match self.parameter_type() {
-                RootParameterType::Cbv
-                | RootParameterType::Srv
-                | RootParameterType::Uav => {
-                    Some(RootDescriptor(self.0.__bindgen_anon_1.Descriptor))
-                }
-                _ => None,
-            }
+            slice::from_raw_parts(
+                self.0.pBufferStrides as *const u32,
+                self.0.NumStrides as usize,
+            )
         }
     }
 
-    pub fn set_shader_visibility(
+    // Note there are no setters since they are both useless and can break the invariant
+    pub fn num_strides(&self) -> u32 {
+        self.0.NumStrides
+    }
+
+    pub fn set_rasterized_stream(
         &mut self,
-        shader_visibility: ShaderVisibility,
+        rasterized_stream: u32,
     ) -> &mut Self {
-        self.0.ShaderVisibility = shader_visibility as i32;
+        self.0.RasterizedStream = rasterized_stream;
         self
     }
 
-    pub fn with_shader_visibility(
-        mut self,
-        shader_visibility: ShaderVisibility,
-    ) -> Self {
-        self.set_shader_visibility(shader_visibility);
+    pub fn with_rasterized_stream(mut self, rasterized_stream: u32) -> Self {
+        self.set_rasterized_stream(rasterized_stream);
         self
     }
 
-    pub fn shader_visibility(&self) -> ShaderVisibility {
-        unsafe { std::mem::transmute(self.0.ShaderVisibility) }
+    pub fn rasterized_stream(&self) -> u32 {
+        self.0.RasterizedStream
     }
 }
 
-/// Wrapper around D3D12_ROOT_DESCRIPTOR_TABLE1 structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+/// Wrapper around D3D12_RENDER_TARGET_BLEND_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
 #[repr(transparent)]
-pub struct RootDescriptorTable<'a>(
-    pub D3D12_ROOT_DESCRIPTOR_TABLE1,
-    PhantomData<&'a DescriptorRange>,
-);
-
-impl<'a> RootDescriptorTable<'a> {
-    pub fn set_descriptor_ranges(
-        &mut self,
-        ranges: &'a [DescriptorRange],
-    ) -> &mut Self {
-        self.0.NumDescriptorRanges = ranges.len() as u32;
-        self.0.pDescriptorRanges =
-            ranges.as_ptr() as *const D3D12_DESCRIPTOR_RANGE1;
-        self.1 = PhantomData;
-        self
-    }
-
-    pub fn with_descriptor_ranges(
-        mut self,
-        ranges: &'a [DescriptorRange],
-    ) -> Self {
-        self.set_descriptor_ranges(ranges);
-        self
-    }
+pub struct RenderTargetBlendDesc(pub(crate) D3D12_RENDER_TARGET_BLEND_DESC);
 
-    pub fn descriptor_ranges(&self) -> &'a [DescriptorRange] {
-        unsafe {
-            std::slice::from_raw_parts(
-                self.0.pDescriptorRanges as *const D3D12_DESCRIPTOR_RANGE1
-                    as *const DescriptorRange,
-                self.0.NumDescriptorRanges as usize,
-            )
-        }
+// defaults from d3dx12.h
+impl Default for RenderTargetBlendDesc {
+    fn default() -> Self {
+        Self(D3D12_RENDER_TARGET_BLEND_DESC {
+            BlendEnable: 0,
+            LogicOpEnable: 0,
+            SrcBlend: Blend::One as i32,
+            DestBlend: Blend::Zero as i32,
+            BlendOp: BlendOp::Add as i32,
+            SrcBlendAlpha: Blend::One as i32,
+            DestBlendAlpha: Blend::Zero as i32,
+            BlendOpAlpha: BlendOp::Add as i32,
+            LogicOp: LogicOp::NoOp as i32,
+            RenderTargetWriteMask:
+                D3D12_COLOR_WRITE_ENABLE_D3D12_COLOR_WRITE_ENABLE_ALL as u8,
+        })
     }
 }
 
-/// Wrapper around D3D12_ROOT_CONSTANTS structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
-#[repr(transparent)]
-pub struct RootConstants(pub(crate) D3D12_ROOT_CONSTANTS);
-
-impl RootConstants {
-    pub fn set_shader_register(&mut self, shader_register: u32) -> &mut Self {
-        self.0.ShaderRegister = shader_register;
+impl RenderTargetBlendDesc {
+    pub fn set_blend_enable(&mut self, blend_enable: bool) -> &mut Self {
+        self.0.BlendEnable = blend_enable as i32;
         self
     }
 
-    pub fn with_shader_register(mut self, shader_register: u32) -> Self {
-        self.set_shader_register(shader_register);
+    pub fn with_blend_enable(mut self, blend_enable: bool) -> Self {
+        self.set_blend_enable(blend_enable);
         self
     }
 
-    pub fn shader_register(&self) -> u32 {
-        self.0.ShaderRegister
+    pub fn blend_enable(&self) -> bool {
+        self.0.BlendEnable != 0
     }
 
-    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
-        self.0.RegisterSpace = register_space;
+    pub fn set_logic_op_enable(&mut self, logic_op_enable: bool) -> &mut Self {
+        self.0.LogicOpEnable = logic_op_enable as i32;
         self
     }
 
-    pub fn with_register_space(mut self, register_space: u32) -> Self {
-        self.set_register_space(register_space);
+    pub fn with_logic_op_enable(mut self, logic_op_enable: bool) -> Self {
+        self.set_logic_op_enable(logic_op_enable);
         self
     }
 
-    pub fn register_space(&self) -> u32 {
-        self.0.RegisterSpace
+    pub fn logic_op_enable(&self) -> bool {
+        self.0.LogicOpEnable != 0
     }
 
-    pub fn set_num_32_bit_values(
-        &mut self,
-        num_32_bit_values: u32,
-    ) -> &mut Self {
-        self.0.Num32BitValues = num_32_bit_values;
+    pub fn set_src_blend(&mut self, src_blend: Blend) -> &mut Self {
+        self.0.SrcBlend = src_blend as i32;
         self
     }
 
-    pub fn with_num_32_bit_values(mut self, num_32_bit_values: u32) -> Self {
-        self.set_num_32_bit_values(num_32_bit_values);
+    pub fn with_src_blend(mut self, src_blend: Blend) -> Self {
+        self.set_src_blend(src_blend);
         self
     }
 
-    pub fn num_32_bit_values(&self) -> u32 {
-        self.0.Num32BitValues
+    pub fn src_blend(&self) -> Blend {
+        unsafe { std::mem::transmute(self.0.SrcBlend) }
     }
-}
-
-/// Wrapper around D3D12_ROOT_DESCRIPTOR1 structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct RootDescriptor(pub(crate) D3D12_ROOT_DESCRIPTOR1);
 
-impl RootDescriptor {
-    pub fn set_shader_register(&mut self, shader_register: u32) -> &mut Self {
-        self.0.ShaderRegister = shader_register;
+    pub fn set_dest_blend(&mut self, dest_blend: Blend) -> &mut Self {
+        self.0.DestBlend = dest_blend as i32;
         self
     }
 
-    pub fn with_shader_register(mut self, shader_register: u32) -> Self {
-        self.set_shader_register(shader_register);
+    pub fn with_dest_blend(mut self, dest_blend: Blend) -> Self {
+        self.set_dest_blend(dest_blend);
         self
     }
 
-    pub fn shader_register(&self) -> u32 {
-        self.0.ShaderRegister
+    pub fn dest_blend(&self) -> Blend {
+        unsafe { std::mem::transmute(self.0.DestBlend) }
     }
 
-    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
-        self.0.RegisterSpace = register_space;
+    pub fn set_blend_op(&mut self, blend_op: BlendOp) -> &mut Self {
+        self.0.BlendOp = blend_op as i32;
         self
     }
 
-    pub fn with_register_space(mut self, register_space: u32) -> Self {
-        self.set_register_space(register_space);
+    pub fn with_blend_op(mut self, blend_op: BlendOp) -> Self {
+        self.set_blend_op(blend_op);
         self
     }
 
-    pub fn register_space(&self) -> u32 {
-        self.0.RegisterSpace
+    pub fn blend_op(&self) -> BlendOp {
+        unsafe { std::mem::transmute(self.0.BlendOp) }
     }
 
-    pub fn set_flags(&mut self, flags: RootDescriptorFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    pub fn set_src_blend_alpha(&mut self, src_blend_alpha: Blend) -> &mut Self {
+        self.0.SrcBlendAlpha = src_blend_alpha as i32;
         self
     }
 
-    pub fn with_flags(mut self, flags: RootDescriptorFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_src_blend_alpha(mut self, src_blend_alpha: Blend) -> Self {
+        self.set_src_blend_alpha(src_blend_alpha);
         self
     }
 
-    pub fn flags(&self) -> RootDescriptorFlags {
-        unsafe { RootDescriptorFlags::from_bits_unchecked(self.0.Flags) }
+    pub fn src_blend_alpha(&self) -> Blend {
+        unsafe { std::mem::transmute(self.0.SrcBlendAlpha) }
     }
-}
-
-/// Wrapper around D3D12_SAMPLER_DESC structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct SamplerDesc(pub(crate) D3D12_SAMPLER_DESC);
 
-impl SamplerDesc {
-    pub fn set_filter(&mut self, filter: Filter) -> &mut Self {
-        self.0.Filter = filter as i32;
+    pub fn set_dest_blend_alpha(
+        &mut self,
+        dest_blend_alpha: Blend,
+    ) -> &mut Self {
+        self.0.DestBlendAlpha = dest_blend_alpha as i32;
         self
     }
 
-    pub fn with_filter(mut self, filter: Filter) -> Self {
-        self.set_filter(filter);
+    pub fn with_dest_blend_alpha(mut self, dest_blend_alpha: Blend) -> Self {
+        self.set_dest_blend_alpha(dest_blend_alpha);
         self
     }
 
-    pub fn filter(&self) -> Filter {
-        unsafe { std::mem::transmute(self.0.Filter) }
+    pub fn dest_blend_alpha(&self) -> Blend {
+        unsafe { std::mem::transmute(self.0.DestBlendAlpha) }
     }
 
-    pub fn set_address_u(
-        &mut self,
-        address_u: TextureAddressMode,
-    ) -> &mut Self {
-        self.0.AddressU = address_u as i32;
+    pub fn set_blend_op_alpha(&mut self, blend_op_alpha: BlendOp) -> &mut Self {
+        self.0.BlendOpAlpha = blend_op_alpha as i32;
         self
     }
 
-    pub fn with_address_u(mut self, address_u: TextureAddressMode) -> Self {
-        self.set_address_u(address_u);
+    pub fn with_blend_op_alpha(mut self, blend_op_alpha: BlendOp) -> Self {
+        self.set_blend_op_alpha(blend_op_alpha);
         self
     }
 
-    pub fn address_u(&self) -> TextureAddressMode {
-        unsafe { std::mem::transmute(self.0.AddressU) }
+    pub fn blend_op_alpha(&self) -> BlendOp {
+        unsafe { std::mem::transmute(self.0.BlendOpAlpha) }
     }
 
-    pub fn set_address_v(
-        &mut self,
-        address_v: TextureAddressMode,
-    ) -> &mut Self {
-        self.0.AddressV = address_v as i32;
+    pub fn set_logic_op(&mut self, logic_op: LogicOp) -> &mut Self {
+        self.0.LogicOp = logic_op as i32;
         self
     }
 
-    pub fn with_address_v(mut self, address_v: TextureAddressMode) -> Self {
-        self.set_address_v(address_v);
+    pub fn with_logic_op(mut self, logic_op: LogicOp) -> Self {
+        self.set_logic_op(logic_op);
         self
     }
 
-    pub fn address_v(&self) -> TextureAddressMode {
-        unsafe { std::mem::transmute(self.0.AddressV) }
+    pub fn logic_op(&self) -> LogicOp {
+        unsafe { std::mem::transmute(self.0.LogicOp) }
     }
 
-    pub fn set_address_w(
+    pub fn set_render_target_write_mask(
         &mut self,
-        address_w: TextureAddressMode,
+        render_target_write_mask: ColorWriteEnable,
     ) -> &mut Self {
-        self.0.AddressW = address_w as i32;
+        self.0.RenderTargetWriteMask = render_target_write_mask.bits() as u8;
         self
     }
 
-    pub fn with_address_w(mut self, address_w: TextureAddressMode) -> Self {
-        self.set_address_w(address_w);
+    pub fn with_render_target_write_mask(
+        mut self,
+        render_target_write_mask: ColorWriteEnable,
+    ) -> Self {
+        self.set_render_target_write_mask(render_target_write_mask);
         self
     }
 
-    pub fn address_w(&self) -> TextureAddressMode {
-        unsafe { std::mem::transmute(self.0.AddressW) }
-    }
-
-    pub fn set_mip_lod_bias(&mut self, mip_lod_bias: f32) -> &mut Self {
-        self.0.MipLODBias = mip_lod_bias;
-        self
+    pub fn render_target_write_mask(&self) -> ColorWriteEnable {
+        unsafe {
+            ColorWriteEnable::from_bits_unchecked(
+                self.0.RenderTargetWriteMask as i32,
+            )
+        }
     }
+}
 
-    pub fn with_mip_lod_bias(mut self, mip_lod_bias: f32) -> Self {
-        self.set_mip_lod_bias(mip_lod_bias);
-        self
-    }
+/// Wrapper around D3D12_BLEND_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct BlendDesc(pub(crate) D3D12_BLEND_DESC);
 
-    pub fn mip_lod_bias(&self) -> f32 {
-        self.0.MipLODBias
+// defaults from d3dx12.h
+impl Default for BlendDesc {
+    fn default() -> Self {
+        Self(D3D12_BLEND_DESC {
+            AlphaToCoverageEnable: 0,
+            IndependentBlendEnable: 0,
+            RenderTarget: [RenderTargetBlendDesc::default().0; 8usize],
+        })
     }
+}
 
-    pub fn set_max_anisotropy(&mut self, max_anisotropy: u32) -> &mut Self {
-        self.0.MaxAnisotropy = max_anisotropy;
+impl BlendDesc {
+    pub fn set_alpha_to_coverage_enable(
+        &mut self,
+        alpha_to_coverage_enable: bool,
+    ) -> &mut Self {
+        self.0.AlphaToCoverageEnable = alpha_to_coverage_enable as i32;
         self
     }
 
-    pub fn with_max_anisotropy(mut self, max_anisotropy: u32) -> Self {
-        self.set_max_anisotropy(max_anisotropy);
+    pub fn with_alpha_to_coverage_enable(
+        mut self,
+        alpha_to_coverage_enable: bool,
+    ) -> Self {
+        self.set_alpha_to_coverage_enable(alpha_to_coverage_enable);
         self
     }
 
-    pub fn max_anisotropy(&self) -> u32 {
-        self.0.MaxAnisotropy
+    pub fn alpha_to_coverage_enable(&self) -> bool {
+        self.0.AlphaToCoverageEnable != 0
     }
 
-    pub fn set_comparison_func(
+    pub fn set_independent_blend_enable(
         &mut self,
-        comparison_func: ComparisonFunc,
+        independent_blend_enable: bool,
     ) -> &mut Self {
-        self.0.ComparisonFunc = comparison_func as i32;
+        self.0.IndependentBlendEnable = independent_blend_enable as i32;
         self
     }
 
-    pub fn with_comparison_func(
+    pub fn with_independent_blend_enable(
         mut self,
-        comparison_func: ComparisonFunc,
+        independent_blend_enable: bool,
     ) -> Self {
-        self.set_comparison_func(comparison_func);
+        self.set_independent_blend_enable(independent_blend_enable);
         self
     }
 
-    pub fn comparison_func(&self) -> ComparisonFunc {
-        unsafe { std::mem::transmute(self.0.ComparisonFunc) }
+    pub fn independent_blend_enable(&self) -> bool {
+        self.0.IndependentBlendEnable != 0
     }
 
-    pub fn set_border_color(
+    pub fn set_render_targets(
         &mut self,
-        border_color: [f32; 4usize],
+        rt_blend_descs: &[RenderTargetBlendDesc],
     ) -> &mut Self {
-        self.0.BorderColor = border_color;
-        self
-    }
-
-    pub fn with_border_color(mut self, border_color: [f32; 4usize]) -> Self {
-        self.set_border_color(border_color);
+        for rt_index in 0..rt_blend_descs.len() {
+            // transmute is okay due to repr::transparent
+            self.0.RenderTarget[rt_index] =
+                unsafe { std::mem::transmute(rt_blend_descs[rt_index]) };
+        }
         self
     }
 
-    pub fn border_color(&self) -> [f32; 4usize] {
-        self.0.BorderColor
-    }
-
-    pub fn set_min_lod(&mut self, min_lod: f32) -> &mut Self {
-        self.0.MinLOD = min_lod;
+    pub fn with_render_targets(
+        mut self,
+        rt_blend_descs: &[RenderTargetBlendDesc],
+    ) -> Self {
+        self.set_render_targets(rt_blend_descs);
         self
     }
 
-    pub fn with_min_lod(mut self, min_lod: f32) -> Self {
-        self.set_min_lod(min_lod);
-        self
+    pub fn render_targets(
+        &self,
+    ) -> [RenderTargetBlendDesc; SIMULTANEOUS_RENDER_TARGET_COUNT as usize]
+    {
+        // transmute is okay due to repr::transparent
+        unsafe { std::mem::transmute(self.0.RenderTarget) }
     }
+}
 
-    pub fn min_lod(&self) -> f32 {
-        self.0.MinLOD
-    }
+/// Wrapper around D3D12_RASTERIZER_DESC structure
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct RasterizerDesc(pub(crate) D3D12_RASTERIZER_DESC);
 
-    pub fn set_max_lod(&mut self, max_lod: f32) -> &mut Self {
-        self.0.MaxLOD = max_lod;
-        self
+// defaults from d3dx12.h
+impl Default for RasterizerDesc {
+    fn default() -> Self {
+        Self(D3D12_RASTERIZER_DESC {
+            FillMode: FillMode::Solid as i32,
+            CullMode: CullMode::Back as i32,
+            FrontCounterClockwise: 0,
+            DepthBias: DEFAULT_DEPTH_BIAS as i32,
+            DepthBiasClamp: DEFAULT_DEPTH_BIAS_CLAMP as f32,
+            SlopeScaledDepthBias: DEFAULT_SLOPE_SCALED_DEPTH_BIAS as f32,
+            DepthClipEnable: 1,
+            MultisampleEnable: 0,
+            AntialiasedLineEnable: 0,
+            ForcedSampleCount: 0,
+            ConservativeRaster: ConservativeRasterizationMode::Off as i32,
+        })
     }
+}
 
-    pub fn with_max_lod(mut self, max_lod: f32) -> Self {
-        self.set_max_lod(max_lod);
-        self
-    }
+// Padding fields are zeroed in Default impl, so this should be okay
+#[cfg(feature = "hash")]
+impl std::hash::Hash for RasterizerDesc {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        unsafe {
+            let slice = std::slice::from_raw_parts(
+                self as *const _ as *const u8,
+                std::mem::size_of::<Self>(),
+            );
 
-    pub fn max_lod(&self) -> f32 {
-        self.0.MaxLOD
+            slice.hash(state);
+        }
     }
 }
 
-/// Wrapper around D3D12_STATIC_SAMPLER_DESC structure
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug)]
-pub struct StaticSamplerDesc(pub(crate) D3D12_STATIC_SAMPLER_DESC);
+#[cfg(feature = "eq")]
+impl PartialEq for RasterizerDesc {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let self_slice = std::slice::from_raw_parts(
+                self as *const _ as *const u8,
+                std::mem::size_of::<Self>(),
+            );
 
-// based on the first constructor of CD3DX12_STATIC_SAMPLER_DESC
-impl Default for StaticSamplerDesc {
-    fn default() -> Self {
-        Self(D3D12_STATIC_SAMPLER_DESC {
-            Filter: D3D12_FILTER_D3D12_FILTER_ANISOTROPIC,
-            AddressU:
-                D3D12_TEXTURE_ADDRESS_MODE_D3D12_TEXTURE_ADDRESS_MODE_WRAP,
-            AddressV:
-                D3D12_TEXTURE_ADDRESS_MODE_D3D12_TEXTURE_ADDRESS_MODE_WRAP,
-            AddressW:
-                D3D12_TEXTURE_ADDRESS_MODE_D3D12_TEXTURE_ADDRESS_MODE_WRAP,
-            MipLODBias: 0.,
-            MaxAnisotropy: 16,
-            ComparisonFunc:
-                D3D12_COMPARISON_FUNC_D3D12_COMPARISON_FUNC_LESS_EQUAL,
-            BorderColor:
-                D3D12_STATIC_BORDER_COLOR_D3D12_STATIC_BORDER_COLOR_OPAQUE_WHITE,
-            MinLOD: 0.,
-            // ToDo: D3D12_FLOAT32_MAX - for some reason bindgen did not include this constant
-            MaxLOD: 3.402823466e+38,
-            ShaderRegister: 0,
-            RegisterSpace: 0,
-            ShaderVisibility:
-                D3D12_SHADER_VISIBILITY_D3D12_SHADER_VISIBILITY_ALL,
-        })
+            let other_slice = std::slice::from_raw_parts(
+                other as *const _ as *const u8,
+                std::mem::size_of::<Self>(),
+            );
+
+            self_slice == other_slice
+        }
     }
 }
 
-impl StaticSamplerDesc {
-    pub fn set_filter(&mut self, filter: Filter) -> &mut Self {
-        self.0.Filter = filter as i32;
+#[cfg(feature = "eq")]
+impl Eq for RasterizerDesc {}
+
+impl RasterizerDesc {
+    pub fn set_fill_mode(&mut self, fill_mode: FillMode) -> &mut Self {
+        self.0.FillMode = fill_mode as i32;
         self
     }
 
-    pub fn with_filter(mut self, filter: Filter) -> Self {
-        self.set_filter(filter);
+    pub fn with_fill_mode(mut self, fill_mode: FillMode) -> Self {
+        self.set_fill_mode(fill_mode);
         self
     }
 
-    pub fn filter(&self) -> Filter {
-        unsafe { std::mem::transmute(self.0.Filter) }
+    pub fn fill_mode(&self) -> FillMode {
+        unsafe { std::mem::transmute(self.0.FillMode) }
     }
 
-    pub fn set_address_u(
-        &mut self,
-        address_u: TextureAddressMode,
-    ) -> &mut Self {
-        self.0.AddressU = address_u as i32;
+    pub fn set_cull_mode(&mut self, cull_mode: CullMode) -> &mut Self {
+        self.0.CullMode = cull_mode as i32;
         self
     }
 
-    pub fn with_address_u(mut self, address_u: TextureAddressMode) -> Self {
-        self.set_address_u(address_u);
+    pub fn with_cull_mode(mut self, cull_mode: CullMode) -> Self {
+        self.set_cull_mode(cull_mode);
         self
     }
 
-    pub fn address_u(&self) -> TextureAddressMode {
-        unsafe { std::mem::transmute(self.0.AddressU) }
+    pub fn cull_mode(&self) -> CullMode {
+        unsafe { std::mem::transmute(self.0.CullMode) }
     }
 
-    pub fn set_address_v(
+    pub fn set_front_counter_clockwise(
         &mut self,
-        address_v: TextureAddressMode,
+        front_counter_clockwise: bool,
     ) -> &mut Self {
-        self.0.AddressV = address_v as i32;
+        self.0.FrontCounterClockwise = front_counter_clockwise as i32;
         self
     }
 
-    pub fn with_address_v(mut self, address_v: TextureAddressMode) -> Self {
-        self.set_address_v(address_v);
+    pub fn with_front_counter_clockwise(
+        mut self,
+        front_counter_clockwise: bool,
+    ) -> Self {
+        self.set_front_counter_clockwise(front_counter_clockwise);
         self
     }
 
-    pub fn address_v(&self) -> TextureAddressMode {
-        unsafe { std::mem::transmute(self.0.AddressV) }
+    pub fn front_counter_clockwise(&self) -> bool {
+        self.0.FrontCounterClockwise != 0
     }
 
-    pub fn set_address_w(
-        &mut self,
-        address_w: TextureAddressMode,
-    ) -> &mut Self {
-        self.0.AddressW = address_w as i32;
+    pub fn set_depth_bias(&mut self, depth_bias: i32) -> &mut Self {
+        self.0.DepthBias = depth_bias;
         self
     }
 
-    pub fn with_address_w(mut self, address_w: TextureAddressMode) -> Self {
-        self.set_address_w(address_w);
+    pub fn with_depth_bias(mut self, depth_bias: i32) -> Self {
+        self.set_depth_bias(depth_bias);
         self
     }
 
-    pub fn address_w(&self) -> TextureAddressMode {
-        unsafe { std::mem::transmute(self.0.AddressW) }
+    pub fn depth_bias(&self) -> i32 {
+        self.0.DepthBias
     }
 
-    pub fn set_mip_lod_bias(&mut self, mip_lod_bias: f32) -> &mut Self {
-        self.0.MipLODBias = mip_lod_bias;
+    pub fn set_depth_bias_clamp(&mut self, depth_bias_clamp: f32) -> &mut Self {
+        self.0.DepthBiasClamp = depth_bias_clamp;
         self
     }
 
-    pub fn with_mip_lod_bias(mut self, mip_lod_bias: f32) -> Self {
-        self.set_mip_lod_bias(mip_lod_bias);
+    pub fn with_depth_bias_clamp(mut self, depth_bias_clamp: f32) -> Self {
+        self.set_depth_bias_clamp(depth_bias_clamp);
         self
     }
 
-    pub fn mip_lod_bias(&self) -> f32 {
-        self.0.MipLODBias
+    pub fn depth_bias_clamp(&self) -> f32 {
+        self.0.DepthBiasClamp
     }
 
-    pub fn set_max_anisotropy(&mut self, max_anisotropy: u32) -> &mut Self {
-        self.0.MaxAnisotropy = max_anisotropy;
+    pub fn set_slope_scaled_depth_bias(
+        &mut self,
+        slope_scaled_depth_bias: f32,
+    ) -> &mut Self {
+        self.0.SlopeScaledDepthBias = slope_scaled_depth_bias;
         self
     }
 
-    pub fn with_max_anisotropy(mut self, max_anisotropy: u32) -> Self {
-        self.set_max_anisotropy(max_anisotropy);
+    pub fn with_slope_scaled_depth_bias(
+        mut self,
+        slope_scaled_depth_bias: f32,
+    ) -> Self {
+        self.set_slope_scaled_depth_bias(slope_scaled_depth_bias);
         self
     }
 
-    pub fn max_anisotropy(&self) -> u32 {
-        self.0.MaxAnisotropy
+    pub fn slope_scaled_depth_bias(&self) -> f32 {
+        self.0.SlopeScaledDepthBias
     }
 
-    pub fn set_comparison_func(
+    pub fn set_depth_clip_enable(
         &mut self,
-        comparison_func: ComparisonFunc,
+        depth_clip_enable: bool,
     ) -> &mut Self {
-        self.0.ComparisonFunc = comparison_func as i32;
+        self.0.DepthClipEnable = depth_clip_enable as i32;
         self
     }
 
-    pub fn with_comparison_func(
-        mut self,
-        comparison_func: ComparisonFunc,
-    ) -> Self {
-        self.set_comparison_func(comparison_func);
+    pub fn with_depth_clip_enable(mut self, depth_clip_enable: bool) -> Self {
+        self.set_depth_clip_enable(depth_clip_enable);
         self
     }
 
-    pub fn comparison_func(&self) -> ComparisonFunc {
-        unsafe { std::mem::transmute(self.0.ComparisonFunc) }
+    pub fn depth_clip_enable(&self) -> bool {
+        self.0.DepthClipEnable != 0
     }
 
-    pub fn set_border_color(
+    pub fn set_multisample_enable(
         &mut self,
-        border_color: StaticBorderColor,
+        multisample_enable: bool,
     ) -> &mut Self {
-        self.0.BorderColor = border_color as i32;
+        self.0.MultisampleEnable = multisample_enable as i32;
         self
     }
 
-    pub fn with_border_color(
-        mut self,
-        border_color: StaticBorderColor,
-    ) -> Self {
-        self.set_border_color(border_color);
+    pub fn with_multisample_enable(mut self, multisample_enable: bool) -> Self {
+        self.set_multisample_enable(multisample_enable);
         self
     }
 
-    pub fn border_color(&self) -> StaticBorderColor {
-        unsafe { std::mem::transmute(self.0.BorderColor) }
+    pub fn multisample_enable(&self) -> bool {
+        self.0.MultisampleEnable != 0
     }
 
-    pub fn set_min_lod(&mut self, min_lod: f32) -> &mut Self {
-        self.0.MinLOD = min_lod;
+    pub fn set_antialiased_line_enable(
+        &mut self,
+        antialiased_line_enable: bool,
+    ) -> &mut Self {
+        self.0.AntialiasedLineEnable = antialiased_line_enable as i32;
         self
     }
 
-    pub fn with_min_lod(mut self, min_lod: f32) -> Self {
-        self.set_min_lod(min_lod);
+    pub fn with_antialiased_line_enable(
+        mut self,
+        antialiased_line_enable: bool,
+    ) -> Self {
+        self.set_antialiased_line_enable(antialiased_line_enable);
         self
     }
 
-    pub fn min_lod(&self) -> f32 {
-        self.0.MinLOD
+    pub fn antialiased_line_enable(&self) -> bool {
+        self.0.AntialiasedLineEnable != 0
     }
 
-    pub fn set_max_lod(&mut self, max_lod: f32) -> &mut Self {
-        self.0.MaxLOD = max_lod;
+    pub fn set_forced_sample_count(
+        &mut self,
+        forced_sample_count: u32,
+    ) -> &mut Self {
+        self.0.ForcedSampleCount = forced_sample_count;
         self
     }
 
-    pub fn with_max_lod(mut self, max_lod: f32) -> Self {
-        self.set_max_lod(max_lod);
+    pub fn with_forced_sample_count(
+        mut self,
+        forced_sample_count: u32,
+    ) -> Self {
+        self.set_forced_sample_count(forced_sample_count);
         self
     }
 
-    pub fn max_lod(&self) -> f32 {
-        self.0.MaxLOD
+    pub fn forced_sample_count(&self) -> u32 {
+        self.0.ForcedSampleCount
     }
 
-    pub fn set_shader_register(&mut self, shader_register: u32) -> &mut Self {
-        self.0.ShaderRegister = shader_register;
+    pub fn set_conservative_raster(
+        &mut self,
+        conservative_raster: ConservativeRasterizationMode,
+    ) -> &mut Self {
+        self.0.ConservativeRaster = conservative_raster as i32;
         self
     }
 
-    pub fn with_shader_register(mut self, shader_register: u32) -> Self {
-        self.set_shader_register(shader_register);
+    pub fn with_conservative_raster(
+        mut self,
+        conservative_raster: ConservativeRasterizationMode,
+    ) -> Self {
+        self.set_conservative_raster(conservative_raster);
         self
     }
 
-    pub fn shader_register(&self) -> u32 {
-        self.0.ShaderRegister
+    pub fn conservative_raster(&self) -> ConservativeRasterizationMode {
+        unsafe { std::mem::transmute(self.0.ConservativeRaster) }
     }
+}
 
-    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
-        self.0.RegisterSpace = register_space;
+/// Wrapper around D3D12_DEPTH_STENCILOP_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct DepthStencilOpDesc(pub(crate) D3D12_DEPTH_STENCILOP_DESC);
+
+// defaults from d3dx12.h
+impl Default for DepthStencilOpDesc {
+    fn default() -> Self {
+        Self(D3D12_DEPTH_STENCILOP_DESC {
+            StencilFailOp: StencilOp::Keep as i32,
+            StencilDepthFailOp: StencilOp::Keep as i32,
+            StencilPassOp: StencilOp::Keep as i32,
+            StencilFunc: ComparisonFunc::Always as i32,
+        })
+    }
+}
+
+impl DepthStencilOpDesc {
+    pub fn set_stencil_fail_op(
+        &mut self,
+        stencil_fail_op: StencilOp,
+    ) -> &mut Self {
+        self.0.StencilFailOp = stencil_fail_op as i32;
         self
     }
 
-    pub fn with_register_space(mut self, register_space: u32) -> Self {
-        self.set_register_space(register_space);
+    pub fn with_stencil_fail_op(mut self, stencil_fail_op: StencilOp) -> Self {
+        self.set_stencil_fail_op(stencil_fail_op);
         self
     }
 
-    pub fn register_space(&self) -> u32 {
-        self.0.RegisterSpace
+    pub fn stencil_fail_op(&self) -> StencilOp {
+        unsafe { std::mem::transmute(self.0.StencilFailOp) }
     }
 
-    pub fn set_shader_visibility(
+    pub fn set_stencil_depth_fail_op(
         &mut self,
-        shader_visibility: ShaderVisibility,
+        stencil_depth_fail_op: StencilOp,
     ) -> &mut Self {
-        self.0.ShaderVisibility = shader_visibility as i32;
+        self.0.StencilDepthFailOp = stencil_depth_fail_op as i32;
         self
     }
 
-    pub fn with_shader_visibility(
+    pub fn with_stencil_depth_fail_op(
         mut self,
-        shader_visibility: ShaderVisibility,
+        stencil_depth_fail_op: StencilOp,
     ) -> Self {
-        self.set_shader_visibility(shader_visibility);
+        self.set_stencil_depth_fail_op(stencil_depth_fail_op);
         self
     }
 
-    pub fn shader_visibility(&self) -> ShaderVisibility {
-        unsafe { std::mem::transmute(self.0.ShaderVisibility) }
+    pub fn stencil_depth_fail_op(&self) -> StencilOp {
+        unsafe { std::mem::transmute(self.0.StencilDepthFailOp) }
     }
-}
 
-/// Wrapper around D3D12_VERSIONED_ROOT_SIGNATURE_DESC structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct VersionedRootSignatureDesc(
-    pub(crate) D3D12_VERSIONED_ROOT_SIGNATURE_DESC,
-);
-
-impl VersionedRootSignatureDesc {
-    // RS v1.0 is not supported
-    // pub fn set_desc_1_0(self, _desc_1_0: &RootSignatureDesc) -> Self {
-    //     unimplemented!();
-    // }
-
-    pub fn set_desc_1_1(&mut self, desc_1_1: &RootSignatureDesc) -> &mut Self {
-        self.0.Version =
-            D3D_ROOT_SIGNATURE_VERSION_D3D_ROOT_SIGNATURE_VERSION_1_1;
-        self.0.__bindgen_anon_1.Desc_1_1 = desc_1_1.0;
+    pub fn set_stencil_pass_op(
+        &mut self,
+        stencil_pass_op: StencilOp,
+    ) -> &mut Self {
+        self.0.StencilPassOp = stencil_pass_op as i32;
         self
     }
 
-    pub fn with_desc_1_1(mut self, desc_1_1: &RootSignatureDesc) -> Self {
-        self.set_desc_1_1(desc_1_1);
+    pub fn with_stencil_pass_op(mut self, stencil_pass_op: StencilOp) -> Self {
+        self.set_stencil_pass_op(stencil_pass_op);
         self
     }
 
-    pub fn desc_1_1(&self) -> RootSignatureDesc {
-        unsafe {
-            RootSignatureDesc(
-                self.0.__bindgen_anon_1.Desc_1_1,
-                PhantomData,
-                PhantomData,
-            )
-        }
+    pub fn stencil_pass_op(&self) -> StencilOp {
+        unsafe { std::mem::transmute(self.0.StencilPassOp) }
     }
-}
-
-/// Wrapper around D3D12_ROOT_SIGNATURE_DESC1 structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
-#[repr(transparent)]
-pub struct RootSignatureDesc<'a, 'b>(
-    pub D3D12_ROOT_SIGNATURE_DESC1,
-    PhantomData<&'a RootParameter<'a>>,
-    PhantomData<&'b StaticSamplerDesc>,
-);
 
-impl<'a, 'b> RootSignatureDesc<'a, 'b> {
-    pub fn set_parameters(
+    pub fn set_stencil_func(
         &mut self,
-        parameters: &'a [RootParameter],
+        stencil_func: ComparisonFunc,
     ) -> &mut Self {
-        self.0.NumParameters = parameters.len() as u32;
-        self.0.pParameters =
-            parameters.as_ptr() as *const D3D12_ROOT_PARAMETER1;
-        self.1 = PhantomData;
+        self.0.StencilFunc = stencil_func as i32;
         self
     }
 
-    pub fn with_parameters(mut self, parameters: &'a [RootParameter]) -> Self {
-        self.set_parameters(parameters);
+    pub fn with_stencil_func(mut self, stencil_func: ComparisonFunc) -> Self {
+        self.set_stencil_func(stencil_func);
         self
     }
 
-    pub fn parameters(&self) -> &'a [RootParameter] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pParameters as *const D3D12_ROOT_PARAMETER1
-                    as *const RootParameter,
-                self.0.NumParameters as usize,
-            )
-        }
+    pub fn stencil_func(&self) -> ComparisonFunc {
+        unsafe { std::mem::transmute(self.0.StencilFunc) }
     }
+}
 
-    pub fn set_static_samplers(
-        &mut self,
-        static_samplers: &'b [StaticSamplerDesc],
-    ) -> &mut Self {
-        self.0.NumStaticSamplers = static_samplers.len() as u32;
-        self.0.pStaticSamplers =
-            static_samplers.as_ptr() as *const D3D12_STATIC_SAMPLER_DESC;
-        self.2 = PhantomData;
-        self
+/// Wrapper around D3D12_DEPTH_STENCIL_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct DepthStencilDesc(pub(crate) D3D12_DEPTH_STENCIL_DESC);
+
+// defaults from d3dx12.h: less depth test with writes; no stencil
+impl Default for DepthStencilDesc {
+    fn default() -> Self {
+        Self(D3D12_DEPTH_STENCIL_DESC {
+            DepthEnable: 1,
+            DepthWriteMask: DepthWriteMask::All as i32,
+            DepthFunc: ComparisonFunc::Less as i32,
+            StencilEnable: 0,
+            StencilReadMask: DEFAULT_STENCIL_READ_MASK as u8,
+            StencilWriteMask: DEFAULT_STENCIL_WRITE_MASK as u8,
+            FrontFace: DepthStencilOpDesc::default().0,
+            BackFace: DepthStencilOpDesc::default().0,
+        })
     }
+}
 
-    pub fn with_static_samplers(
-        mut self,
-        static_samplers: &'b [StaticSamplerDesc],
-    ) -> Self {
-        self.set_static_samplers(static_samplers);
+impl DepthStencilDesc {
+    pub fn set_depth_enable(&mut self, depth_enable: bool) -> &mut Self {
+        self.0.DepthEnable = depth_enable as i32;
         self
     }
 
-    pub fn static_samplers(&self) -> &'a [StaticSamplerDesc] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pStaticSamplers as *const D3D12_STATIC_SAMPLER_DESC
-                    as *const StaticSamplerDesc,
-                self.0.NumStaticSamplers as usize,
-            )
-        }
+    pub fn with_depth_enable(mut self, depth_enable: bool) -> Self {
+        self.set_depth_enable(depth_enable);
+        self
     }
 
-    pub fn set_flags(&mut self, flags: RootSignatureFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
-        self
+    pub fn depth_enable(&self) -> bool {
+        self.0.DepthEnable != 0
     }
 
-    pub fn with_flags(mut self, flags: RootSignatureFlags) -> Self {
-        self.set_flags(flags);
+    pub fn set_depth_write_mask(
+        &mut self,
+        depth_write_mask: DepthWriteMask,
+    ) -> &mut Self {
+        self.0.DepthWriteMask = depth_write_mask as i32;
         self
     }
 
-    pub fn flags(&self) -> RootSignatureFlags {
-        unsafe { RootSignatureFlags::from_bits_unchecked(self.0.Flags) }
+    pub fn with_depth_write_mask(
+        mut self,
+        depth_write_mask: DepthWriteMask,
+    ) -> Self {
+        self.set_depth_write_mask(depth_write_mask);
+        self
     }
-}
 
-/// Wrapper around D3D12_SUBRESOURCE_DATA structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
-#[repr(transparent)]
-pub struct SubresourceData<'a>(
-    pub D3D12_SUBRESOURCE_DATA,
-    PhantomData<&'a [()]>,
-);
+    pub fn depth_write_mask(&self) -> DepthWriteMask {
+        unsafe { std::mem::transmute(self.0.DepthWriteMask) }
+    }
 
-impl<'a> SubresourceData<'a> {
-    pub fn set_data<T>(&mut self, data: &'a [T]) -> &mut Self {
-        self.0.pData = data.as_ptr() as *const std::ffi::c_void;
-        self.1 = PhantomData;
+    pub fn set_depth_func(&mut self, depth_func: ComparisonFunc) -> &mut Self {
+        self.0.DepthFunc = depth_func as i32;
         self
     }
 
-    pub fn with_data<T>(mut self, data: &'a [T]) -> Self {
-        self.set_data(data);
+    pub fn with_depth_func(mut self, depth_func: ComparisonFunc) -> Self {
+        self.set_depth_func(depth_func);
         self
     }
 
-    // ToDo?
-    // pub fn data<T>(&self) -> &'a [T] {
-    //     unsafe {
-    //         slice::from_raw_parts(
-    //             self.0.pData as *const T,
-    //             self.0.SizeInBytes as usize,
-    //         )
-    //     }
-    // }
+    pub fn depth_func(&self) -> ComparisonFunc {
+        unsafe { std::mem::transmute(self.0.DepthFunc) }
+    }
 
-    pub fn set_row_pitch(&mut self, row_pitch: ByteCount) -> &mut Self {
-        self.0.RowPitch = row_pitch.0 as i64;
+    pub fn set_stencil_enable(&mut self, stencil_enable: bool) -> &mut Self {
+        self.0.StencilEnable = stencil_enable as i32;
         self
     }
 
-    pub fn with_row_pitch(mut self, row_pitch: ByteCount) -> Self {
-        self.set_row_pitch(row_pitch);
+    pub fn with_stencil_enable(mut self, stencil_enable: bool) -> Self {
+        self.set_stencil_enable(stencil_enable);
         self
     }
 
-    pub fn row_pitch(&self) -> ByteCount {
-        ByteCount::from(self.0.RowPitch)
+    pub fn stencil_enable(&self) -> bool {
+        self.0.StencilEnable != 0
     }
 
-    pub fn set_slice_pitch(&mut self, slice_pitch: ByteCount) -> &mut Self {
-        self.0.SlicePitch = slice_pitch.0 as i64;
+    pub fn set_stencil_read_mask(
+        &mut self,
+        stencil_read_mask: u8,
+    ) -> &mut Self {
+        self.0.StencilReadMask = stencil_read_mask;
         self
     }
 
-    pub fn with_slice_pitch(mut self, slice_pitch: ByteCount) -> Self {
-        self.set_slice_pitch(slice_pitch);
+    pub fn with_stencil_read_mask(mut self, stencil_read_mask: u8) -> Self {
+        self.set_stencil_read_mask(stencil_read_mask);
         self
     }
 
-    pub fn slice_pitch(&self) -> ByteCount {
-        ByteCount::from(self.0.SlicePitch)
+    pub fn stencil_read_mask(&self) -> u8 {
+        self.0.StencilReadMask
     }
-}
-
-/// Wrapper around D3D12_SHADER_RESOURCE_VIEW_DESC structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct ShaderResourceViewDesc(pub(crate) D3D12_SHADER_RESOURCE_VIEW_DESC);
 
-impl ShaderResourceViewDesc {
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
+    pub fn set_stencil_write_mask(
+        &mut self,
+        stencil_write_mask: u8,
+    ) -> &mut Self {
+        self.0.StencilWriteMask = stencil_write_mask;
         self
     }
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
+    pub fn with_stencil_write_mask(mut self, stencil_write_mask: u8) -> Self {
+        self.set_stencil_write_mask(stencil_write_mask);
         self
     }
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
-    }
-
-    pub fn view_dimension(&self) -> SrvDimension {
-        unsafe { std::mem::transmute(self.0.ViewDimension) }
+    pub fn stencil_write_mask(&self) -> u8 {
+        self.0.StencilWriteMask
     }
 
-    pub fn set_shader_4_component_mapping(
+    pub fn set_front_face(
         &mut self,
-        shader4_component_mapping: ShaderComponentMapping,
+        front_face: DepthStencilOpDesc,
     ) -> &mut Self {
-        self.0.Shader4ComponentMapping = shader4_component_mapping.into();
+        self.0.FrontFace = front_face.0;
         self
     }
 
-    pub fn with_shader_4_component_mapping(
-        mut self,
-        shader4_component_mapping: ShaderComponentMapping,
-    ) -> Self {
-        self.set_shader_4_component_mapping(shader4_component_mapping);
+    pub fn with_front_face(mut self, front_face: DepthStencilOpDesc) -> Self {
+        self.set_front_face(front_face);
         self
     }
 
-    pub fn shader_4_component_mapping(&self) -> ShaderComponentMapping {
-        self.0.Shader4ComponentMapping.into()
+    pub fn front_face(&self) -> DepthStencilOpDesc {
+        DepthStencilOpDesc(self.0.FrontFace)
     }
 
-    // ToDo: rename these new* since at the call site they look
-    // like a regular setter. Another option is to remove Default derive
-    pub fn new_buffer(mut self, buffer: &BufferSrv) -> Self {
-        self.0.ViewDimension = SrvDimension::Buffer as i32;
-        self.0.__bindgen_anon_1.Buffer = buffer.0;
+    pub fn set_back_face(
+        &mut self,
+        back_face: DepthStencilOpDesc,
+    ) -> &mut Self {
+        self.0.BackFace = back_face.0;
         self
     }
 
-    pub fn buffer(&self) -> Option<BufferSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Buffer => {
-                    Some(BufferSrv(self.0.__bindgen_anon_1.Buffer))
-                }
-                _ => None,
-            }
-        }
+    pub fn with_back_face(mut self, back_face: DepthStencilOpDesc) -> Self {
+        self.set_back_face(back_face);
+        self
     }
 
-    pub fn new_texture_1d(mut self, texture_1d: &Tex1DSrv) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture1D as i32;
-        self.0.__bindgen_anon_1.Texture1D = texture_1d.0;
-        self
+    pub fn back_face(&self) -> DepthStencilOpDesc {
+        DepthStencilOpDesc(self.0.BackFace)
     }
+}
 
-    pub fn texture_1d(&self) -> Option<Tex1DSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture1D => {
-                    Some(Tex1DSrv(self.0.__bindgen_anon_1.Texture1D))
-                }
-                _ => None,
-            }
-        }
+/// Wrapper around D3D12_INDIRECT_ARGUMENT_DESC structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct IndirectArgumentDesc(pub(crate) D3D12_INDIRECT_ARGUMENT_DESC);
+
+impl IndirectArgumentDesc {
+    pub fn new_draw() -> Self {
+        Self::new(IndirectArgumentType::Draw)
     }
 
-    pub fn new_texture_1d_array(
-        mut self,
-        texture_1d_array: &Tex1DArraySrv,
-    ) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture1DArray as i32;
-        self.0.__bindgen_anon_1.Texture1DArray = texture_1d_array.0;
-        self
+    pub fn new_draw_indexed() -> Self {
+        Self::new(IndirectArgumentType::DrawIndexed)
     }
 
-    pub fn texture_1d_array(&self) -> Option<Tex1DArraySrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture1DArray => {
-                    Some(Tex1DArraySrv(self.0.__bindgen_anon_1.Texture1DArray))
-                }
-                _ => None,
-            }
-        }
+    pub fn new_dispatch() -> Self {
+        Self::new(IndirectArgumentType::Dispatch)
     }
 
-    pub fn new_texture_2d(mut self, texture_2d: &Tex2DSrv) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture2D as i32;
-        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
-        self
+    pub fn new_dispatch_rays() -> Self {
+        Self::new(IndirectArgumentType::DispatchRays)
     }
 
-    pub fn texture_2d(&self) -> Option<Tex2DSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture2D => {
-                    Some(Tex2DSrv(self.0.__bindgen_anon_1.Texture2D))
-                }
-                _ => None,
-            }
-        }
+    pub fn new_dispatch_mesh() -> Self {
+        Self::new(IndirectArgumentType::DispatchMesh)
     }
 
-    pub fn new_texture_2d_array(
-        mut self,
-        texture_2d_array: &Tex2DArraySrv,
-    ) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture2DArray as i32;
-        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
-        self
+    pub fn new_vertex_buffer_view(slot: u32) -> Self {
+        let mut desc = Self::new(IndirectArgumentType::VertexBufferView);
+        desc.0.__bindgen_anon_1.VertexBuffer.Slot = slot;
+        desc
     }
 
-    pub fn texture_2d_array(&self) -> Option<Tex2DArraySrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture2DArray => {
-                    Some(Tex2DArraySrv(self.0.__bindgen_anon_1.Texture2DArray))
-                }
-                _ => None,
-            }
-        }
+    pub fn new_index_buffer_view() -> Self {
+        Self::new(IndirectArgumentType::IndexBufferView)
     }
 
-    pub fn new_texture_2d_ms(mut self, texture_2d_ms: &Tex2DMsSrv) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture2DMs as i32;
-        self.0.__bindgen_anon_1.Texture2DMS = texture_2d_ms.0;
-        self
+    pub fn new_constant(
+        root_parameter_index: u32,
+        dest_offset_in_32bit_values: u32,
+        num_32bit_values_to_set: u32,
+    ) -> Self {
+        let mut desc = Self::new(IndirectArgumentType::Constant);
+        desc.0.__bindgen_anon_1.Constant =
+            D3D12_INDIRECT_ARGUMENT_DESC__bindgen_ty_1__bindgen_ty_2 {
+                RootParameterIndex: root_parameter_index,
+                DestOffsetIn32BitValues: dest_offset_in_32bit_values,
+                Num32BitValuesToSet: num_32bit_values_to_set,
+            };
+        desc
+    }
+
+    pub fn new_constant_buffer_view(root_parameter_index: u32) -> Self {
+        let mut desc = Self::new(IndirectArgumentType::ConstantBufferView);
+        desc.0
+            .__bindgen_anon_1
+            .ConstantBufferView
+            .RootParameterIndex = root_parameter_index;
+        desc
+    }
+
+    pub fn new_shader_resource_view(root_parameter_index: u32) -> Self {
+        let mut desc = Self::new(IndirectArgumentType::ShaderResourceView);
+        desc.0
+            .__bindgen_anon_1
+            .ShaderResourceView
+            .RootParameterIndex = root_parameter_index;
+        desc
+    }
+
+    pub fn new_unordered_access_view(root_parameter_index: u32) -> Self {
+        let mut desc = Self::new(IndirectArgumentType::UnorderedAccessView);
+        desc.0
+            .__bindgen_anon_1
+            .UnorderedAccessView
+            .RootParameterIndex = root_parameter_index;
+        desc
+    }
+
+    pub fn argument_type(&self) -> IndirectArgumentType {
+        unsafe { std::mem::transmute(self.0.Type) }
     }
 
-    pub fn texture_2d_ms(&self) -> Option<Tex2DMsSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture2DMs => {
-                    Some(Tex2DMsSrv(self.0.__bindgen_anon_1.Texture2DMS))
-                }
-                _ => None,
+    /// Size of the argument in the argument buffer
+    pub fn size(&self) -> ByteCount {
+        let size = match self.argument_type() {
+            IndirectArgumentType::Draw => size_of::<D3D12_DRAW_ARGUMENTS>(),
+            IndirectArgumentType::DrawIndexed => {
+                size_of::<D3D12_DRAW_INDEXED_ARGUMENTS>()
             }
-        }
-    }
-
-    pub fn new_texture_2d_ms_array(
-        mut self,
-        texture_2d_ms_array: &Tex2DMsArraySrv,
-    ) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture2DMsArray as i32;
-        self.0.__bindgen_anon_1.Texture2DMSArray = texture_2d_ms_array.0;
-        self
-    }
-
-    pub fn texture_2d_ms_array(&self) -> Option<Tex2DMsArraySrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture2DMsArray => Some(Tex2DMsArraySrv(
-                    self.0.__bindgen_anon_1.Texture2DMSArray,
-                )),
-                _ => None,
+            IndirectArgumentType::Dispatch => {
+                size_of::<D3D12_DISPATCH_ARGUMENTS>()
             }
-        }
-    }
-
-    pub fn new_texture_3d(mut self, texture_3d: &Tex3DSrv) -> Self {
-        self.0.ViewDimension = SrvDimension::Texture3D as i32;
-        self.0.__bindgen_anon_1.Texture3D = texture_3d.0;
-        self
-    }
-
-    pub fn texture_3d(&self) -> Option<Tex3DSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::Texture3D => {
-                    Some(Tex3DSrv(self.0.__bindgen_anon_1.Texture3D))
-                }
-                _ => None,
+            IndirectArgumentType::VertexBufferView => {
+                size_of::<D3D12_VERTEX_BUFFER_VIEW>()
             }
-        }
-    }
-
-    pub fn new_texture_cube(mut self, texture_cube: &TexcubeSrv) -> Self {
-        self.0.ViewDimension = SrvDimension::TextureCube as i32;
-        self.0.__bindgen_anon_1.TextureCube = texture_cube.0;
-        self
-    }
-
-    pub fn texture_cube(&self) -> Option<TexcubeSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::TextureCube => {
-                    Some(TexcubeSrv(self.0.__bindgen_anon_1.TextureCube))
-                }
-                _ => None,
+            IndirectArgumentType::IndexBufferView => {
+                size_of::<D3D12_INDEX_BUFFER_VIEW>()
             }
-        }
-    }
-
-    pub fn new_texture_cube_array(
-        mut self,
-        texture_cube_array: &TexcubeArraySrv,
-    ) -> Self {
-        self.0.ViewDimension = SrvDimension::TextureCubeArray as i32;
-        self.0.__bindgen_anon_1.TextureCubeArray = texture_cube_array.0;
-        self
-    }
-
-    pub fn texture_cube_array(&self) -> Option<TexcubeArraySrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::TextureCubeArray => Some(TexcubeArraySrv(
-                    self.0.__bindgen_anon_1.TextureCubeArray,
-                )),
-                _ => None,
+            IndirectArgumentType::Constant => unsafe {
+                self.0.__bindgen_anon_1.Constant.Num32BitValuesToSet as usize
+                    * size_of::<u32>()
+            },
+            IndirectArgumentType::ConstantBufferView
+            | IndirectArgumentType::ShaderResourceView
+            | IndirectArgumentType::UnorderedAccessView => {
+                size_of::<D3D12_GPU_VIRTUAL_ADDRESS>()
             }
-        }
-    }
+            IndirectArgumentType::DispatchRays => {
+                size_of::<D3D12_DISPATCH_RAYS_DESC>()
+            }
+            IndirectArgumentType::DispatchMesh => {
+                size_of::<D3D12_DISPATCH_MESH_ARGUMENTS>()
+            }
+        };
 
-    pub fn new_raytracing_acceleration_structure(
-        mut self,
-        raytracing_acceleration_structure: &RaytracingAccelerationStructureSrv,
-    ) -> Self {
-        self.0.ViewDimension =
-            SrvDimension::RaytracingAccelerationStructure as i32;
-        self.0.__bindgen_anon_1.RaytracingAccelerationStructure =
-            raytracing_acceleration_structure.0;
-        self
+        ByteCount::from(size)
     }
 
-    pub fn raytracing_acceleration_structure(
-        &self,
-    ) -> Option<RaytracingAccelerationStructureSrv> {
-        unsafe {
-            match self.view_dimension() {
-                SrvDimension::RaytracingAccelerationStructure => {
-                    Some(RaytracingAccelerationStructureSrv(
-                        self.0.__bindgen_anon_1.RaytracingAccelerationStructure,
-                    ))
-                }
-                _ => None,
-            }
-        }
+    fn new(argument_type: IndirectArgumentType) -> Self {
+        let mut desc = Self::default();
+        desc.0.Type = argument_type as i32;
+        desc
     }
 }
 
-/// Wrapper around D3D12_BUFFER_SRV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+/// Wrapper around D3D12_COMMAND_SIGNATURE_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
 #[repr(transparent)]
-pub struct BufferSrv(pub(crate) D3D12_BUFFER_SRV);
-
-impl BufferSrv {
-    pub fn set_first_element(&mut self, first_element: u64) -> &mut Self {
-        self.0.FirstElement = first_element;
-        self
-    }
-
-    pub fn with_first_element(mut self, first_element: u64) -> Self {
-        self.set_first_element(first_element);
-        self
-    }
+pub struct CommandSignatureDesc<'a>(
+    pub(crate) D3D12_COMMAND_SIGNATURE_DESC,
+    PhantomData<&'a [IndirectArgumentDesc]>,
+);
 
-    pub fn first_element(&self) -> u64 {
-        self.0.FirstElement
+impl Default for CommandSignatureDesc<'_> {
+    fn default() -> Self {
+        Self(D3D12_COMMAND_SIGNATURE_DESC::default(), PhantomData)
     }
+}
 
-    pub fn set_num_elements(&mut self, num_elements: u32) -> &mut Self {
-        self.0.NumElements = num_elements;
+impl<'a> CommandSignatureDesc<'a> {
+    pub fn set_byte_stride(&mut self, byte_stride: ByteCount) -> &mut Self {
+        self.0.ByteStride = byte_stride.0 as u32;
         self
     }
 
-    pub fn with_num_elements(mut self, num_elements: u32) -> Self {
-        self.set_num_elements(num_elements);
+    pub fn with_byte_stride(mut self, byte_stride: ByteCount) -> Self {
+        self.set_byte_stride(byte_stride);
         self
     }
 
-    pub fn num_elements(&self) -> u32 {
-        self.0.NumElements
+    pub fn byte_stride(&self) -> ByteCount {
+        ByteCount::from(self.0.ByteStride)
     }
 
-    pub fn set_structure_byte_stride(
+    /// The last argument determines the command, the preceding ones
+    /// change the bindings it uses
+    pub fn set_argument_descs(
         &mut self,
-        structure_byte_stride: ByteCount,
+        argument_descs: &'a [IndirectArgumentDesc],
     ) -> &mut Self {
-        self.0.StructureByteStride = structure_byte_stride.0 as u32;
+        self.0.pArgumentDescs =
+            argument_descs.as_ptr() as *const D3D12_INDIRECT_ARGUMENT_DESC;
+        self.0.NumArgumentDescs = argument_descs.len() as u32;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_structure_byte_stride(
+    pub fn with_argument_descs(
         mut self,
-        structure_byte_stride: ByteCount,
+        argument_descs: &'a [IndirectArgumentDesc],
     ) -> Self {
-        self.set_structure_byte_stride(structure_byte_stride);
+        self.set_argument_descs(argument_descs);
         self
     }
 
-    pub fn structure_byte_stride(&self) -> ByteCount {
-        ByteCount::from(self.0.StructureByteStride)
+    pub fn argument_descs(&self) -> &'a [IndirectArgumentDesc] {
+        if self.0.NumArgumentDescs == 0 {
+            return &[];
+        }
+
+        unsafe {
+            slice::from_raw_parts(
+                self.0.pArgumentDescs as *const IndirectArgumentDesc,
+                self.0.NumArgumentDescs as usize,
+            )
+        }
     }
 
-    pub fn set_flags(&mut self, flags: BufferSrvFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.0.NodeMask = node_mask;
         self
     }
 
-    pub fn with_flags(mut self, flags: BufferSrvFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
         self
     }
 
-    // ToDo: truncate instead of unchecked?
-    pub fn flags(&self) -> BufferSrvFlags {
-        unsafe { BufferSrvFlags::from_bits_unchecked(self.0.Flags) }
+    pub fn node_mask(&self) -> u32 {
+        self.0.NodeMask
     }
 }
 
-/// Wrapper around D3D12_TEX1D_SRV structure
-#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
+/// Wrapper around D3D12_INPUT_LAYOUT_DESC structure
 #[repr(transparent)]
-pub struct Tex1DSrv(pub(crate) D3D12_TEX1D_SRV);
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
+pub struct InputLayoutDesc<'a>(
+    pub D3D12_INPUT_LAYOUT_DESC,
+    PhantomData<&'a [InputElementDesc<'a>]>,
+);
 
-impl Tex1DSrv {
-    pub fn set_most_detailed_mip(
+impl Default for InputLayoutDesc<'_> {
+    fn default() -> Self {
+        Self(
+            D3D12_INPUT_LAYOUT_DESC {
+                pInputElementDescs: std::ptr::null(),
+                NumElements: 0,
+            },
+            PhantomData,
+        )
+    }
+}
+
+// ToDo: ShaderBytecode is a similar struct, but it uses new() method
+impl<'a> InputLayoutDesc<'a> {
+    pub fn set_input_elements(
         &mut self,
-        most_detailed_mip: u32,
+        layout: &'a [InputElementDesc<'a>],
     ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
+        self.0.pInputElementDescs =
+            layout.as_ptr() as *const D3D12_INPUT_ELEMENT_DESC;
+        self.0.NumElements = layout.len() as u32;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+    pub fn with_input_elements(
+        mut self,
+        layout: &'a [InputElementDesc<'a>],
+    ) -> Self {
+        self.set_input_elements(layout);
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
-    }
-
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
-        self
+    pub fn input_elements(&self) -> &'a [InputElementDesc] {
+        unsafe {
+            slice::from_raw_parts(
+                self.0.pInputElementDescs as *const InputElementDesc,
+                self.0.NumElements as usize,
+            )
+        }
     }
+}
 
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
-        self
-    }
+/// Wrapper around D3D12_CACHED_PIPELINE_STATE structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug)]
+pub struct CachedPipelineState<'a>(
+    pub D3D12_CACHED_PIPELINE_STATE,
+    PhantomData<&'a [u8]>,
+);
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+impl<'a> Default for CachedPipelineState<'a> {
+    fn default() -> Self {
+        Self(
+            D3D12_CACHED_PIPELINE_STATE {
+                pCachedBlob: std::ptr::null_mut(),
+                CachedBlobSizeInBytes: 0,
+            },
+            PhantomData,
+        )
     }
+}
 
-    pub fn set_resource_min_lod_clamp(
-        &mut self,
-        resource_min_lod_clamp: f32,
-    ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+impl<'a> CachedPipelineState<'a> {
+    pub fn set_cached_blob(&mut self, cached_blob: &'a [u8]) -> &mut Self {
+        self.0.pCachedBlob = cached_blob.as_ptr() as *const std::ffi::c_void;
+        self.0.CachedBlobSizeInBytes = cached_blob.len() as u64;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_resource_min_lod_clamp(
-        mut self,
-        resource_min_lod_clamp: f32,
-    ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+    pub fn with_cached_blob(mut self, cached_blob: &'a [u8]) -> Self {
+        self.set_cached_blob(cached_blob);
         self
     }
 
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
-    }
-}
-
-/// Wrapper around D3D12_TEX1D_ARRAY_SRV structure
-#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
+    pub fn cached_blob(&self) -> &'a [u8] {
+        unsafe {
+            slice::from_raw_parts(
+                self.0.pCachedBlob as *const u8,
+                self.0.CachedBlobSizeInBytes as usize,
+            )
+        }
+    }
+}
+
+// ToDo: do we need different lifetimes for all shaders?
+/// Wrapper around D3D12_GRAPHICS_PIPELINE_STATE_DESC structure
 #[repr(transparent)]
-pub struct Tex1DArraySrv(pub(crate) D3D12_TEX1D_ARRAY_SRV);
+#[derive(Debug)]
+pub struct GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il>(
+    pub D3D12_GRAPHICS_PIPELINE_STATE_DESC,
+    PhantomData<&'rs RootSignature>,
+    PhantomData<&'sh ShaderBytecode<'sh>>,
+    PhantomData<&'so StreamOutputDesc<'so>>,
+    PhantomData<&'il InputLayoutDesc<'il>>,
+);
 
-impl Tex1DArraySrv {
-    pub fn set_most_detailed_mip(
+impl<'rs, 'sh, 'so, 'il> Default
+    for GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il>
+{
+    fn default() -> Self {
+        Self(
+            D3D12_GRAPHICS_PIPELINE_STATE_DESC {
+                pRootSignature: std::ptr::null_mut(),
+                VS: ShaderBytecode::default().0,
+                PS: ShaderBytecode::default().0,
+                DS: ShaderBytecode::default().0,
+                HS: ShaderBytecode::default().0,
+                GS: ShaderBytecode::default().0,
+                StreamOutput: StreamOutputDesc::default().0,
+                BlendState: BlendDesc::default().0,
+                SampleMask: std::u32::MAX,
+                RasterizerState: RasterizerDesc::default().0,
+                DepthStencilState: DepthStencilDesc::default().0,
+                InputLayout: InputLayoutDesc::default().0,
+                IBStripCutValue: IndexBufferStripCutValue::Disabled as i32,
+                PrimitiveTopologyType: PrimitiveTopologyType::Undefined as i32,
+                NumRenderTargets: SIMULTANEOUS_RENDER_TARGET_COUNT as u32,
+                RTVFormats: [Format::Unknown as i32;
+                    SIMULTANEOUS_RENDER_TARGET_COUNT as usize],
+                DSVFormat: Format::Unknown as i32,
+                SampleDesc: SampleDesc::default().0,
+                NodeMask: 0,
+                CachedPSO: CachedPipelineState::default().0,
+                Flags: PipelineStateFlags::None.bits(),
+            },
+            PhantomData, // rs
+            PhantomData, // sh
+            PhantomData, // so
+            PhantomData, // il
+        )
+    }
+}
+
+impl<'rs, 'sh, 'so, 'il> GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il> {
+    pub fn set_root_signature(
         &mut self,
-        most_detailed_mip: u32,
+        root_signature: &'rs RootSignature,
     ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
+        self.0.pRootSignature = root_signature.this;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+    pub fn with_root_signature(
+        mut self,
+        root_signature: &'rs RootSignature,
+    ) -> GraphicsPipelineStateDesc<'rs, 'sh, 'so, 'il> {
+        self.set_root_signature(root_signature);
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
+    pub fn root_signature(&self) -> RootSignature {
+        let root_signature = RootSignature {
+            this: self.0.pRootSignature,
+        };
+        root_signature.add_ref();
+        root_signature
     }
 
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
+    pub fn set_vs_bytecode(
+        &mut self,
+        bytecode: &'sh ShaderBytecode,
+    ) -> &mut Self {
+        self.0.VS = bytecode.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
+    pub fn with_vs_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_vs_bytecode(bytecode);
         self
     }
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+    pub fn vs_bytecode(&self) -> &'sh ShaderBytecode {
+        unsafe {
+            &*(&self.0.VS as *const D3D12_SHADER_BYTECODE
+                as *const ShaderBytecode)
+        }
     }
 
-    pub fn set_first_array_slice(
+    pub fn set_ps_bytecode(
         &mut self,
-        first_array_slice: u32,
+        bytecode: &'sh ShaderBytecode,
     ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+        self.0.PS = bytecode.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_ps_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_ps_bytecode(bytecode);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn ps_bytecode(&self) -> &'sh ShaderBytecode {
+        unsafe {
+            &*(&self.0.PS as *const D3D12_SHADER_BYTECODE
+                as *const ShaderBytecode)
+        }
     }
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+    pub fn set_ds_bytecode(
+        &mut self,
+        bytecode: &'sh ShaderBytecode,
+    ) -> &mut Self {
+        self.0.DS = bytecode.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_ds_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_ds_bytecode(bytecode);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn ds_bytecode(&self) -> &'sh ShaderBytecode {
+        unsafe {
+            &*(&self.0.DS as *const D3D12_SHADER_BYTECODE
+                as *const ShaderBytecode)
+        }
     }
 
-    pub fn set_resource_min_lod_clamp(
+    pub fn set_hs_bytecode(
         &mut self,
-        resource_min_lod_clamp: f32,
+        bytecode: &'sh ShaderBytecode,
     ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self.0.HS = bytecode.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_resource_min_lod_clamp(
-        mut self,
-        resource_min_lod_clamp: f32,
-    ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+    pub fn with_hs_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_hs_bytecode(bytecode);
         self
     }
 
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
+    pub fn hs_bytecode(&self) -> &'sh ShaderBytecode {
+        unsafe {
+            &*(&self.0.HS as *const D3D12_SHADER_BYTECODE
+                as *const ShaderBytecode)
+        }
     }
-}
-
-/// Wrapper around D3D12_TEX2D_SRV structure
-#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
-#[repr(transparent)]
-pub struct Tex2DSrv(pub(crate) D3D12_TEX2D_SRV);
 
-impl Tex2DSrv {
-    pub fn set_most_detailed_mip(
+    pub fn set_gs_bytecode(
         &mut self,
-        most_detailed_mip: u32,
+        bytecode: &'sh ShaderBytecode,
     ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
+        self.0.GS = bytecode.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+    pub fn with_gs_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_gs_bytecode(bytecode);
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
+    pub fn gs_bytecode(&self) -> &'sh ShaderBytecode {
+        unsafe {
+            &*(&self.0.GS as *const D3D12_SHADER_BYTECODE
+                as *const ShaderBytecode)
+        }
     }
 
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
+    pub fn set_stream_output(
+        &mut self,
+        stream_output: StreamOutputDesc,
+    ) -> &mut Self {
+        self.0.StreamOutput = stream_output.0;
         self
     }
 
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
+    pub fn with_stream_output(
+        mut self,
+        stream_output: StreamOutputDesc,
+    ) -> Self {
+        self.set_stream_output(stream_output);
         self
     }
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+    pub fn stream_output(&self) -> &'so StreamOutputDesc {
+        unsafe {
+            &*(&self.0.StreamOutput as *const D3D12_STREAM_OUTPUT_DESC
+                as *const StreamOutputDesc)
+        }
     }
 
-    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
-        self.0.PlaneSlice = plane_slice;
+    pub fn set_blend_state(&mut self, blend_state: BlendDesc) -> &mut Self {
+        self.0.BlendState = blend_state.0;
         self
     }
 
-    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
-        self.set_plane_slice(plane_slice);
+    pub fn with_blend_state(mut self, blend_state: BlendDesc) -> Self {
+        self.set_blend_state(blend_state);
         self
     }
 
-    pub fn plane_slice(&self) -> u32 {
-        self.0.PlaneSlice
+    pub fn blend_state(&self) -> BlendDesc {
+        BlendDesc(self.0.BlendState)
     }
 
-    pub fn set_resource_min_lod_clamp(
-        &mut self,
-        resource_min_lod_clamp: f32,
-    ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+    pub fn set_sample_mask(&mut self, sample_mask: u32) -> &mut Self {
+        self.0.SampleMask = sample_mask;
         self
     }
 
-    pub fn with_resource_min_lod_clamp(
-        mut self,
-        resource_min_lod_clamp: f32,
-    ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+    pub fn with_sample_mask(mut self, sample_mask: u32) -> Self {
+        self.set_sample_mask(sample_mask);
         self
     }
-
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
+    pub fn sample_mask(&self) -> u32 {
+        self.0.SampleMask
     }
-}
-
-/// Wrapper around D3D12_TEX2D_ARRAY_SRV structure
-#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
-#[repr(transparent)]
-pub struct Tex2DArraySrv(pub(crate) D3D12_TEX2D_ARRAY_SRV);
 
-impl Tex2DArraySrv {
-    pub fn set_most_detailed_mip(
+    pub fn set_rasterizer_state(
         &mut self,
-        most_detailed_mip: u32,
+        rasterizer_state: RasterizerDesc,
     ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
-        self
-    }
-
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+        self.0.RasterizerState = rasterizer_state.0;
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
-    }
-
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
-        self
-    }
-
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
+    pub fn with_rasterizer_state(
+        mut self,
+        rasterizer_state: RasterizerDesc,
+    ) -> Self {
+        self.set_rasterizer_state(rasterizer_state);
         self
     }
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+    pub fn rasterizer_state(&self) -> RasterizerDesc {
+        RasterizerDesc(self.0.RasterizerState)
     }
 
-    pub fn set_first_array_slice(
+    pub fn set_depth_stencil_state(
         &mut self,
-        first_array_slice: u32,
+        depth_stencil_state: DepthStencilDesc,
     ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+        self.0.DepthStencilState = depth_stencil_state.0;
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_depth_stencil_state(
+        mut self,
+        depth_stencil_state: DepthStencilDesc,
+    ) -> Self {
+        self.set_depth_stencil_state(depth_stencil_state);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn depth_stencil_state(&self) -> DepthStencilDesc {
+        DepthStencilDesc(self.0.DepthStencilState)
     }
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+    pub fn set_input_layout(
+        &mut self,
+        input_layout: &'il InputLayoutDesc,
+    ) -> &mut Self {
+        self.0.InputLayout = input_layout.0;
+        self.4 = PhantomData;
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_input_layout(
+        mut self,
+        input_layout: &'il InputLayoutDesc,
+    ) -> Self {
+        self.set_input_layout(input_layout);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn input_layout(&self) -> &'il InputLayoutDesc {
+        unsafe {
+            &*(&self.0.InputLayout as *const D3D12_INPUT_LAYOUT_DESC
+                as *const InputLayoutDesc)
+        }
     }
 
-    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
-        self.0.PlaneSlice = plane_slice;
+    pub fn set_ib_strip_cut_value(
+        &mut self,
+        ib_strip_cut_value: IndexBufferStripCutValue,
+    ) -> &mut Self {
+        self.0.IBStripCutValue = ib_strip_cut_value as i32;
         self
     }
 
-    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
-        self.set_plane_slice(plane_slice);
+    pub fn with_ib_strip_cut_value(
+        mut self,
+        ib_strip_cut_value: IndexBufferStripCutValue,
+    ) -> Self {
+        self.set_ib_strip_cut_value(ib_strip_cut_value);
         self
     }
 
-    pub fn plane_slice(&self) -> u32 {
-        self.0.PlaneSlice
+    pub fn ib_strip_cut_value(&self) -> IndexBufferStripCutValue {
+        unsafe { std::mem::transmute(self.0.IBStripCutValue) }
     }
 
-    pub fn set_resource_min_lod_clamp(
+    pub fn set_primitive_topology_type(
         &mut self,
-        resource_min_lod_clamp: f32,
+        primitive_topology_type: PrimitiveTopologyType,
     ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self.0.PrimitiveTopologyType = primitive_topology_type as i32;
         self
     }
 
-    pub fn with_resource_min_lod_clamp(
+    pub fn with_primitive_topology_type(
         mut self,
-        resource_min_lod_clamp: f32,
+        primitive_topology_type: PrimitiveTopologyType,
     ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self.set_primitive_topology_type(primitive_topology_type);
         self
     }
 
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
+    pub fn primitive_topology_type(&self) -> PrimitiveTopologyType {
+        unsafe { std::mem::transmute(self.0.PrimitiveTopologyType) }
     }
-}
-
-/// Wrapper around D3D12_TEX2DMS_SRV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct Tex2DMsSrv(pub(crate) D3D12_TEX2DMS_SRV);
-
-/// Wrapper around D3D12_TEX2DMS_ARRAY_SRV structure
-#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
-#[repr(transparent)]
-pub struct Tex2DMsArraySrv(pub(crate) D3D12_TEX2DMS_ARRAY_SRV);
 
-impl Tex2DMsArraySrv {
-    pub fn set_first_array_slice(
-        &mut self,
-        first_array_slice: u32,
-    ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+    pub fn set_rtv_formats(&mut self, rtv_formats: &[Format]) -> &mut Self {
+        for format_index in 0..rtv_formats.len() {
+            self.0.RTVFormats[format_index] = rtv_formats[format_index] as i32;
+        }
+        self.0.NumRenderTargets = rtv_formats.len() as u32;
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_rtv_formats(mut self, rtv_formats: &[Format]) -> Self {
+        self.set_rtv_formats(rtv_formats);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn rtv_formats(&self) -> &[Format] {
+        unsafe {
+            slice::from_raw_parts(
+                self.0.RTVFormats.as_ptr() as *const Format,
+                self.0.NumRenderTargets as usize,
+            )
+        }
     }
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
-        self
+    // Note there are no setters since they are both useless and can break the invariant
+    pub fn num_render_targets(&self) -> u32 {
+        self.0.NumRenderTargets
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn set_dsv_format(&mut self, dsv_format: Format) -> &mut Self {
+        self.0.DSVFormat = dsv_format as i32;
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn with_dsv_format(mut self, dsv_format: Format) -> Self {
+        self.set_dsv_format(dsv_format);
+        self
     }
-}
 
-/// Wrapper around D3D12_TEX3D_SRV structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct Tex3DSrv(pub(crate) D3D12_TEX3D_SRV);
+    pub fn dsv_format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.DSVFormat) }
+    }
 
-impl Tex3DSrv {
-    pub fn set_most_detailed_mip(
-        &mut self,
-        most_detailed_mip: u32,
-    ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
+    pub fn set_sample_desc(&mut self, sample_desc: SampleDesc) -> &mut Self {
+        self.0.SampleDesc = sample_desc.0;
         self
     }
 
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+    pub fn with_sample_desc(mut self, sample_desc: SampleDesc) -> Self {
+        self.set_sample_desc(sample_desc);
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
+    pub fn sample_desc(&self) -> SampleDesc {
+        SampleDesc(self.0.SampleDesc)
     }
 
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.0.NodeMask = node_mask;
         self
     }
 
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
         self
     }
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+    pub fn node_mask(&self) -> u32 {
+        self.0.NodeMask
     }
 
-    pub fn set_resource_min_lod_clamp(
+    pub fn set_cached_pso(
         &mut self,
-        resource_min_lod_clamp: f32,
+        cached_pso: &'sh CachedPipelineState,
     ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self.0.CachedPSO = cached_pso.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_resource_min_lod_clamp(
+    pub fn with_cached_pso(
         mut self,
-        resource_min_lod_clamp: f32,
+        cached_pso: &'sh CachedPipelineState,
     ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self.set_cached_pso(cached_pso);
         self
     }
 
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
+    // ToDo: probably it'd be simpler to just have one lifetime
+    // parameter on GraphicsPipelineStateDesc?
+    pub fn cached_pso(&self) -> &'sh CachedPipelineState {
+        unsafe {
+            &*(&self.0.CachedPSO as *const D3D12_CACHED_PIPELINE_STATE
+                as *const CachedPipelineState)
+        }
     }
-}
-
-/// Wrapper around D3D12_TEXCUBE_SRV structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct TexcubeSrv(pub(crate) D3D12_TEXCUBE_SRV);
 
-impl TexcubeSrv {
-    pub fn set_most_detailed_mip(
-        &mut self,
-        most_detailed_mip: u32,
-    ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
+    pub fn set_flags(&mut self, flags: PipelineStateFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
         self
     }
 
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+    pub fn with_flags(mut self, flags: PipelineStateFlags) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
+    pub fn flags(&self) -> PipelineStateFlags {
+        unsafe { std::mem::transmute(self.0.Flags) }
     }
+}
 
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
-        self
-    }
+/// Wrapper around D3D12_COMPUTE_PIPELINE_STATE_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct ComputePipelineStateDesc<'rs, 'sh>(
+    pub D3D12_COMPUTE_PIPELINE_STATE_DESC,
+    PhantomData<&'rs RootSignature>,
+    PhantomData<&'sh ShaderBytecode<'sh>>,
+);
 
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
+impl<'rs, 'sh> ComputePipelineStateDesc<'rs, 'sh> {
+    pub fn set_root_signature(
+        &mut self,
+        root_signature: &'rs RootSignature,
+    ) -> &mut ComputePipelineStateDesc<'rs, 'sh> {
+        self.0.pRootSignature = root_signature.this;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+    pub fn with_root_signature(
+        mut self,
+        root_signature: &'rs RootSignature,
+    ) -> ComputePipelineStateDesc<'rs, 'sh> {
+        self.set_root_signature(root_signature);
+        self
     }
 
-    pub fn set_resource_min_lod_clamp(
-        &mut self,
-        resource_min_lod_clamp: f32,
-    ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
-        self
-    }
-
-    pub fn with_resource_min_lod_clamp(
-        mut self,
-        resource_min_lod_clamp: f32,
-    ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
-        self
-    }
-
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
+    pub fn root_signature(&self) -> RootSignature {
+        let root_signature = RootSignature {
+            this: self.0.pRootSignature,
+        };
+        root_signature.add_ref();
+        root_signature
     }
-}
-
-/// Wrapper around D3D12_TEXCUBE_ARRAY_SRV structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct TexcubeArraySrv(pub(crate) D3D12_TEXCUBE_ARRAY_SRV);
 
-impl TexcubeArraySrv {
-    pub fn set_most_detailed_mip(
+    pub fn set_cs_bytecode(
         &mut self,
-        most_detailed_mip: u32,
-    ) -> &mut Self {
-        self.0.MostDetailedMip = most_detailed_mip;
+        bytecode: &'sh ShaderBytecode,
+    ) -> &mut ComputePipelineStateDesc<'rs, 'sh> {
+        self.0.CS = bytecode.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
-        self.set_most_detailed_mip(most_detailed_mip);
+    pub fn with_cs_bytecode(
+        mut self,
+        bytecode: &'sh ShaderBytecode,
+    ) -> ComputePipelineStateDesc<'rs, 'sh> {
+        self.set_cs_bytecode(bytecode);
         self
     }
 
-    pub fn most_detailed_mip(&self) -> u32 {
-        self.0.MostDetailedMip
+    pub fn cs_bytecode(&self) -> &'sh ShaderBytecode {
+        unsafe {
+            &*(&self.0.CS as *const D3D12_SHADER_BYTECODE
+                as *const ShaderBytecode)
+        }
     }
 
-    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
-        self.0.MipLevels = mip_levels;
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.0.NodeMask = node_mask;
         self
     }
 
-    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.set_mip_levels(mip_levels);
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
         self
     }
 
-    pub fn mip_levels(&self) -> u32 {
-        self.0.MipLevels
+    pub fn node_mask(&self) -> u32 {
+        self.0.NodeMask
     }
 
-    pub fn set_first_2d_array_face(
+    pub fn set_cached_pso(
         &mut self,
-        first_2d_array_face: u32,
-    ) -> &mut Self {
-        self.0.First2DArrayFace = first_2d_array_face;
+        cached_pso: &'sh CachedPipelineState,
+    ) -> &mut ComputePipelineStateDesc<'rs, 'sh> {
+        self.0.CachedPSO = cached_pso.0;
+        self.2 = PhantomData;
         self
     }
 
-    pub fn with_first_2d_array_face(
+    pub fn with_cached_pso(
         mut self,
-        first_2d_array_face: u32,
-    ) -> Self {
-        self.set_first_2d_array_face(first_2d_array_face);
-        self
-    }
-
-    pub fn first_2d_array_face(&self) -> u32 {
-        self.0.First2DArrayFace
-    }
-
-    pub fn set_num_cubes(&mut self, num_cubes: u32) -> &mut Self {
-        self.0.NumCubes = num_cubes;
-        self
-    }
-
-    pub fn with_num_cubes(mut self, num_cubes: u32) -> Self {
-        self.set_num_cubes(num_cubes);
+        cached_pso: &'sh CachedPipelineState,
+    ) -> ComputePipelineStateDesc<'rs, 'sh> {
+        self.set_cached_pso(cached_pso);
         self
     }
 
-    pub fn num_cubes(&self) -> u32 {
-        self.0.NumCubes
+    // ToDo: probably it'd be simpler to just have one lifetime
+    // parameter on ComputePipelineStateDesc?
+    pub fn cached_pso(&self) -> &'sh CachedPipelineState {
+        unsafe {
+            &*(&self.0.CachedPSO as *const D3D12_CACHED_PIPELINE_STATE
+                as *const CachedPipelineState)
+        }
     }
 
-    pub fn set_resource_min_lod_clamp(
+    pub fn set_flags(
         &mut self,
-        resource_min_lod_clamp: f32,
+        pipeline_state_flags: PipelineStateFlags,
     ) -> &mut Self {
-        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self.0.Flags = pipeline_state_flags.bits();
         self
     }
 
-    pub fn with_resource_min_lod_clamp(
+    pub fn with_flags(
         mut self,
-        resource_min_lod_clamp: f32,
+        pipeline_state_flags: PipelineStateFlags,
     ) -> Self {
-        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self.set_flags(pipeline_state_flags);
         self
     }
 
-    pub fn resource_min_lod_clamp(&self) -> f32 {
-        self.0.ResourceMinLODClamp
+    pub fn flags(&self) -> PipelineStateFlags {
+        unsafe { std::mem::transmute(self.0.Flags) }
     }
 }
 
-/// Wrapper around D3D12_RAYTRACING_ACCELERATION_STRUCTURE_SRV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+/// Wrapper around D3D12_SUBRESOURCE_FOOTPRINT structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
 #[repr(transparent)]
-pub struct RaytracingAccelerationStructureSrv(
-    pub D3D12_RAYTRACING_ACCELERATION_STRUCTURE_SRV,
-);
-
-impl RaytracingAccelerationStructureSrv {
-    pub fn set_location(&mut self, location: GpuVirtualAddress) -> &mut Self {
-        self.0.Location = location.0;
-        self
-    }
-
-    pub fn with_location(mut self, location: GpuVirtualAddress) -> Self {
-        self.set_location(location);
-        self
-    }
+pub struct SubresourceFootprint(pub(crate) D3D12_SUBRESOURCE_FOOTPRINT);
 
-    pub fn location(&self) -> GpuVirtualAddress {
-        GpuVirtualAddress(self.0.Location)
+impl Default for SubresourceFootprint {
+    fn default() -> Self {
+        Self(D3D12_SUBRESOURCE_FOOTPRINT {
+            Format: Format::R8G8B8A8Unorm as i32,
+            Width: 0,
+            Height: 1,
+            Depth: 1,
+            RowPitch: 0,
+        })
     }
 }
 
-/// Wrapper around D3D12_UNORDERED_ACCESS_VIEW_DESC structure
-#[repr(transparent)]
-#[derive(Copy, Clone, Default, Debug)]
-pub struct UnorderedAccessViewDesc(pub(crate) D3D12_UNORDERED_ACCESS_VIEW_DESC);
-
-impl UnorderedAccessViewDesc {
+impl SubresourceFootprint {
     pub fn set_format(&mut self, format: Format) -> &mut Self {
         self.0.Format = format as i32;
         self
@@ -5546,1197 +5703,4198 @@ impl UnorderedAccessViewDesc {
         unsafe { std::mem::transmute(self.0.Format) }
     }
 
-    pub fn view_dimension(&self) -> UavDimension {
-        unsafe { std::mem::transmute(self.0.ViewDimension) }
+    pub fn set_width(&mut self, width: u32) -> &mut Self {
+        self.0.Width = width;
+        self
     }
 
-    // ToDo: rename these new* since at the call site they look
-    // like a regular setter. Another option is to remove Default derive
-    pub fn new_buffer(mut self, buffer: &BufferUav) -> Self {
-        self.0.ViewDimension = UavDimension::Buffer as i32;
-        self.0.__bindgen_anon_1.Buffer = buffer.0;
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.set_width(width);
         self
     }
 
-    pub fn buffer(&self) -> Option<BufferUav> {
-        unsafe {
-            match self.view_dimension() {
-                UavDimension::Buffer => {
-                    Some(BufferUav(self.0.__bindgen_anon_1.Buffer))
-                }
-                _ => None,
-            }
-        }
+    pub fn width(&self) -> u32 {
+        self.0.Width
     }
 
-    pub fn new_texture_1d(mut self, texture_1d: &Tex1DUav) -> Self {
-        self.0.ViewDimension = UavDimension::Texture1D as i32;
-        self.0.__bindgen_anon_1.Texture1D = texture_1d.0;
+    pub fn set_height(&mut self, height: u32) -> &mut Self {
+        self.0.Height = height;
         self
     }
 
-    pub fn texture_1d(&self) -> Option<Tex1DUav> {
-        unsafe {
-            match self.view_dimension() {
-                UavDimension::Texture1D => {
-                    Some(Tex1DUav(self.0.__bindgen_anon_1.Texture1D))
-                }
-                _ => None,
-            }
-        }
-    }
-
-    pub fn new_texture_1d_array(
-        mut self,
-        texture_1d_array: &Tex1DArrayUav,
-    ) -> Self {
-        self.0.ViewDimension = UavDimension::Texture1DArray as i32;
-        self.0.__bindgen_anon_1.Texture1DArray = texture_1d_array.0;
+    pub fn with_height(mut self, height: u32) -> Self {
+        self.set_height(height);
         self
     }
 
-    pub fn texture_1d_array(&self) -> Option<Tex1DArrayUav> {
-        unsafe {
-            match self.view_dimension() {
-                UavDimension::Texture1DArray => {
-                    Some(Tex1DArrayUav(self.0.__bindgen_anon_1.Texture1DArray))
-                }
-                _ => None,
-            }
-        }
+    pub fn height(&self) -> u32 {
+        self.0.Height
     }
 
-    pub fn new_texture_2d(mut self, texture_2d: &Tex2DUav) -> Self {
-        self.0.ViewDimension = UavDimension::Texture2D as i32;
-        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
+    pub fn set_depth(&mut self, depth: u32) -> &mut Self {
+        self.0.Depth = depth;
         self
     }
 
-    pub fn texture_2d(&self) -> Option<Tex2DUav> {
-        unsafe {
-            match self.view_dimension() {
-                UavDimension::Texture2D => {
-                    Some(Tex2DUav(self.0.__bindgen_anon_1.Texture2D))
-                }
-                _ => None,
-            }
-        }
-    }
-
-    pub fn new_texture_2d_array(
-        mut self,
-        texture_2d_array: &Tex2DArrayUav,
-    ) -> Self {
-        self.0.ViewDimension = UavDimension::Texture2DArray as i32;
-        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
+    pub fn with_depth(mut self, depth: u32) -> Self {
+        self.set_depth(depth);
         self
     }
 
-    pub fn texture_2d_array(&self) -> Option<Tex2DArrayUav> {
-        unsafe {
-            match self.view_dimension() {
-                UavDimension::Texture2DArray => {
-                    Some(Tex2DArrayUav(self.0.__bindgen_anon_1.Texture2DArray))
-                }
-                _ => None,
-            }
-        }
+    pub fn depth(&self) -> u32 {
+        self.0.Depth
     }
 
-    pub fn new_texture_3d(mut self, texture_3d: &Tex3DUav) -> Self {
-        self.0.ViewDimension = UavDimension::Texture3D as i32;
-        self.0.__bindgen_anon_1.Texture3D = texture_3d.0;
+    pub fn set_row_pitch(&mut self, row_pitch: ByteCount) -> &mut Self {
+        self.0.RowPitch = row_pitch.0 as u32;
         self
     }
 
-    pub fn texture_3d(&self) -> Option<Tex3DUav> {
-        unsafe {
-            match self.view_dimension() {
-                UavDimension::Texture3D => {
-                    Some(Tex3DUav(self.0.__bindgen_anon_1.Texture3D))
-                }
-                _ => None,
-            }
-        }
-    }
-}
-
-/// Wrapper around D3D12_BUFFER_UAV structure
-#[repr(transparent)]
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-pub struct BufferUav(pub(crate) D3D12_BUFFER_UAV);
-
-impl BufferUav {
-    pub fn set_first_element(&mut self, first_element: u64) -> &mut Self {
-        self.0.FirstElement = first_element;
+    pub fn with_row_pitch(mut self, row_pitch: ByteCount) -> Self {
+        self.set_row_pitch(row_pitch);
         self
     }
 
-    pub fn with_first_element(mut self, first_element: u64) -> Self {
-        self.set_first_element(first_element);
-        self
+    pub fn row_pitch(&self) -> ByteCount {
+        ByteCount::from(self.0.RowPitch)
     }
+}
 
-    pub fn first_element(&self) -> u64 {
-        self.0.FirstElement
+/// Wrapper around D3D12_PLACED_SUBRESOURCE_FOOTPRINT structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct PlacedSubresourceFootprint(
+    pub(crate) D3D12_PLACED_SUBRESOURCE_FOOTPRINT,
+);
+
+impl Default for PlacedSubresourceFootprint {
+    fn default() -> Self {
+        Self(D3D12_PLACED_SUBRESOURCE_FOOTPRINT {
+            Offset: 0,
+            Footprint: SubresourceFootprint::default().0,
+        })
     }
+}
 
-    pub fn set_num_elements(&mut self, num_elements: u32) -> &mut Self {
-        self.0.NumElements = num_elements;
+impl PlacedSubresourceFootprint {
+    pub fn set_offset(&mut self, offset: ByteCount) -> &mut Self {
+        self.0.Offset = offset.0 as u64;
         self
     }
 
-    pub fn with_num_elements(mut self, num_elements: u32) -> Self {
-        self.set_num_elements(num_elements);
+    pub fn with_offset(mut self, offset: ByteCount) -> Self {
+        self.set_offset(offset);
         self
     }
 
-    pub fn num_elements(&self) -> u32 {
-        self.0.NumElements
+    pub fn offset(&self) -> ByteCount {
+        ByteCount::from(self.0.Offset)
     }
 
-    pub fn set_structure_byte_stride(
+    pub fn set_footprint(
         &mut self,
-        structure_byte_stride: ByteCount,
+        footprint: SubresourceFootprint,
     ) -> &mut Self {
-        self.0.StructureByteStride = structure_byte_stride.0 as u32;
+        self.0.Footprint = footprint.0;
         self
     }
 
-    pub fn with_structure_byte_stride(
-        mut self,
-        structure_byte_stride: ByteCount,
-    ) -> Self {
-        self.set_structure_byte_stride(structure_byte_stride);
+    pub fn with_footprint(mut self, footprint: SubresourceFootprint) -> Self {
+        self.set_footprint(footprint);
         self
     }
 
-    pub fn structure_byte_stride(&self) -> ByteCount {
-        ByteCount::from(self.0.StructureByteStride)
+    pub fn footprint(&self) -> SubresourceFootprint {
+        SubresourceFootprint(self.0.Footprint)
     }
+}
 
-    pub fn set_counter_offset_in_bytes(
+/// Wrapper around D3D12_CONSTANT_BUFFER_VIEW_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct ConstantBufferViewDesc(pub(crate) D3D12_CONSTANT_BUFFER_VIEW_DESC);
+
+impl ConstantBufferViewDesc {
+    pub fn set_buffer_location(
         &mut self,
-        counter_offset_in_bytes: ByteCount,
+        buffer_location: GpuVirtualAddress,
     ) -> &mut Self {
-        self.0.CounterOffsetInBytes = counter_offset_in_bytes.0;
+        self.0.BufferLocation = buffer_location.0;
         self
     }
 
-    pub fn with_counter_offset_in_bytes(
+    pub fn with_buffer_location(
         mut self,
-        counter_offset_in_bytes: ByteCount,
+        buffer_location: GpuVirtualAddress,
     ) -> Self {
-        self.set_counter_offset_in_bytes(counter_offset_in_bytes);
+        self.set_buffer_location(buffer_location);
         self
     }
 
-    pub fn counter_offset_in_bytes(&self) -> ByteCount {
-        ByteCount(self.0.CounterOffsetInBytes)
+    pub fn buffer_location(&self) -> GpuVirtualAddress {
+        GpuVirtualAddress(self.0.BufferLocation)
     }
 
-    pub fn set_flags(&mut self, flags: BufferUavFlags) -> &mut Self {
-        self.0.Flags = flags as i32;
+    pub fn set_size_in_bytes(&mut self, size_in_bytes: ByteCount) -> &mut Self {
+        self.0.SizeInBytes = size_in_bytes.0 as u32;
         self
     }
 
-    pub fn with_flags(mut self, flags: BufferUavFlags) -> Self {
-        self.set_flags(flags);
+    pub fn with_size_in_bytes(mut self, size_in_bytes: ByteCount) -> Self {
+        self.set_size_in_bytes(size_in_bytes);
         self
     }
 
-    pub fn flags(&self) -> BufferUavFlags {
-        unsafe { std::mem::transmute(self.0.Flags) }
+    pub fn size_in_bytes(&self) -> ByteCount {
+        ByteCount::from(self.0.SizeInBytes)
     }
 }
 
-/// Wrapper around D3D12_TEX1D_UAV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+// ToDo: rethink the 'pub's in such wrappers
+/// Wrapper around D3D12_DESCRIPTOR_HEAP_DESC structure
 #[repr(transparent)]
-pub struct Tex1DUav(pub(crate) D3D12_TEX1D_UAV);
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct DescriptorHeapDesc(pub(crate) D3D12_DESCRIPTOR_HEAP_DESC);
 
-impl Tex1DUav {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
-        self
+impl Default for DescriptorHeapDesc {
+    fn default() -> Self {
+        Self(D3D12_DESCRIPTOR_HEAP_DESC {
+            Type: DescriptorHeapType::CbvSrvUav as i32,
+            NumDescriptors: 0,
+            Flags: DescriptorHeapFlags::None.bits(),
+            NodeMask: 0,
+        })
     }
+}
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+impl DescriptorHeapDesc {
+    pub fn set_heap_type(
+        &mut self,
+        heap_type: DescriptorHeapType,
+    ) -> &mut Self {
+        self.0.Type = heap_type as i32;
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn with_heap_type(mut self, heap_type: DescriptorHeapType) -> Self {
+        self.set_heap_type(heap_type);
+        self
     }
-}
 
-/// Wrapper around D3D12_TEX1D_ARRAY_UAV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct Tex1DArrayUav(pub(crate) D3D12_TEX1D_ARRAY_UAV);
+    pub fn heap_type(&self) -> DescriptorHeapType {
+        unsafe { std::mem::transmute(self.0.Type) }
+    }
 
-impl Tex1DArrayUav {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
+    pub fn set_num_descriptors(&mut self, num_descriptors: u32) -> &mut Self {
+        self.0.NumDescriptors = num_descriptors;
         self
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+    pub fn with_num_descriptors(mut self, num_descriptors: u32) -> Self {
+        self.set_num_descriptors(num_descriptors);
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn num_descriptors(&self) -> u32 {
+        self.0.NumDescriptors
     }
 
-    pub fn set_first_array_slice(
-        &mut self,
-        first_array_slice: u32,
-    ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+    pub fn set_flags(&mut self, flags: DescriptorHeapFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_flags(mut self, flags: DescriptorHeapFlags) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn flags(&self) -> DescriptorHeapFlags {
+        unsafe { DescriptorHeapFlags::from_bits_unchecked(self.0.Flags) }
     }
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.0.NodeMask = node_mask;
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn node_mask(&self) -> u32 {
+        self.0.NodeMask
     }
-}
-
-/// Wrapper around D3D12_TEX2D_UAV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct Tex2DUav(pub(crate) D3D12_TEX2D_UAV);
 
-impl Tex2DUav {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
-        self
+    /// Checks that the heap is not empty, that only CBV/SRV/UAV and
+    /// sampler heaps are shader visible, the shader-visible sampler heap
+    /// size limit and that at most one node is selected
+    pub fn validate(&self) -> DxResult<()> {
+        self.check().map_err(|message| {
+            invalid_desc("DescriptorHeapDesc::validate", message)
+        })
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
-        self
+    /// Finishes building the description, see [DescriptorHeapDesc::validate]
+    pub fn finish(self) -> DxResult<Self> {
+        self.validate()?;
+        Ok(self)
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    fn check(&self) -> Result<(), String> {
+        if self.num_descriptors() == 0 {
+            return Err(String::from("descriptor heap is empty"));
+        }
+
+        if self.flags().contains(DescriptorHeapFlags::ShaderVisible) {
+            match self.heap_type() {
+                DescriptorHeapType::Rtv | DescriptorHeapType::Dsv => {
+                    return Err(format!(
+                        "{:?} heaps cannot be shader visible",
+                        self.heap_type()
+                    ));
+                }
+                DescriptorHeapType::Sampler
+                    if self.num_descriptors()
+                        > MAX_SHADER_VISIBLE_SAMPLER_HEAP_SIZE =>
+                {
+                    return Err(format!(
+                        "shader visible sampler heaps can have at most {} \
+                         descriptors, got {}",
+                        MAX_SHADER_VISIBLE_SAMPLER_HEAP_SIZE,
+                        self.num_descriptors()
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        if self.node_mask().count_ones() > 1 {
+            return Err(format!(
+                "descriptor heap node mask must select a single node, \
+                 got {:#x}",
+                self.node_mask()
+            ));
+        }
+
+        Ok(())
     }
+}
 
-    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
-        self.0.PlaneSlice = plane_slice;
+/// Wrapper around D3D12_COMMAND_QUEUE_DESC structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+#[repr(transparent)]
+pub struct CommandQueueDesc(pub(crate) D3D12_COMMAND_QUEUE_DESC);
+
+impl CommandQueueDesc {
+    pub fn set_queue_type(&mut self, queue_type: CommandListType) -> &mut Self {
+        self.0.Type = queue_type as i32;
         self
     }
 
-    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
-        self.set_plane_slice(plane_slice);
+    pub fn with_queue_type(mut self, queue_type: CommandListType) -> Self {
+        self.set_queue_type(queue_type);
         self
     }
 
-    pub fn plane_slice(&self) -> u32 {
-        self.0.PlaneSlice
+    pub fn queue_type(&self) -> CommandListType {
+        unsafe { std::mem::transmute(self.0.Type) }
     }
-}
-
-/// Wrapper around D3D12_TEX2D_ARRAY_UAV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct Tex2DArrayUav(pub(crate) D3D12_TEX2D_ARRAY_UAV);
 
-impl Tex2DArrayUav {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
+    pub fn set_priority(&mut self, priority: i32) -> &mut Self {
+        self.0.Priority = priority;
         self
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.set_priority(priority);
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn priority(&self) -> i32 {
+        self.0.Priority
     }
 
-    pub fn set_first_array_slice(
+    pub fn set_queue_priority(
         &mut self,
-        first_array_slice: u32,
+        priority: CommandQueuePriority,
     ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+        self.0.Priority = priority as i32;
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_queue_priority(
+        mut self,
+        priority: CommandQueuePriority,
+    ) -> Self {
+        self.set_queue_priority(priority);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
-    }
-
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+    pub fn set_flags(&mut self, flags: CommandQueueFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_flags(mut self, flags: CommandQueueFlags) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn flags(&self) -> CommandQueueFlags {
+        unsafe { CommandQueueFlags::from_bits_unchecked(self.0.Flags) }
     }
 
-    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
-        self.0.PlaneSlice = plane_slice;
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.0.NodeMask = node_mask;
         self
     }
 
-    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
-        self.set_plane_slice(plane_slice);
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
         self
     }
 
-    pub fn plane_slice(&self) -> u32 {
-        self.0.PlaneSlice
+    pub fn node_mask(&self) -> u32 {
+        self.0.NodeMask
     }
-}
 
-/// Wrapper around D3D12_TEX3D_UAV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct Tex3DUav(pub(crate) D3D12_TEX3D_UAV);
+    /// Checks that the queue type is not a bundle, the priority is one
+    /// of [CommandQueuePriority] values and at most one node is selected
+    pub fn validate(&self) -> DxResult<()> {
+        self.check().map_err(|message| {
+            invalid_desc("CommandQueueDesc::validate", message)
+        })
+    }
 
-impl Tex3DUav {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
-        self
+    /// Finishes building the description, see [CommandQueueDesc::validate]
+    pub fn finish(self) -> DxResult<Self> {
+        self.validate()?;
+        Ok(self)
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
-        self
+    fn check(&self) -> Result<(), String> {
+        if matches!(self.queue_type(), CommandListType::Bundle) {
+            return Err(String::from(
+                "bundles cannot be executed directly, so there are no \
+                 bundle queues",
+            ));
+        }
+
+        let priority = self.priority();
+        if priority != CommandQueuePriority::Normal as i32
+            && priority != CommandQueuePriority::High as i32
+            && priority != CommandQueuePriority::GlobalRealTime as i32
+        {
+            return Err(format!("unknown queue priority {}", priority));
+        }
+
+        if self.node_mask().count_ones() > 1 {
+            return Err(format!(
+                "command queue node mask must select a single node, got {:#x}",
+                self.node_mask()
+            ));
+        }
+
+        Ok(())
     }
+}
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+/// Wrapper around D3D12_FEATURE_DATA_ROOT_SIGNATURE structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct FeatureDataRootSignature(
+    pub(crate) D3D12_FEATURE_DATA_ROOT_SIGNATURE,
+);
+
+impl FeatureDataRootSignature {
+    pub fn new(version: RootSignatureVersion) -> Self {
+        Self(D3D12_FEATURE_DATA_ROOT_SIGNATURE {
+            HighestVersion: version as i32,
+        })
     }
 
-    pub fn set_first_w_slice(&mut self, first_w_slice: u32) -> &mut Self {
-        self.0.FirstWSlice = first_w_slice;
+    pub fn set_highest_version(
+        &mut self,
+        highest_version: RootSignatureVersion,
+    ) -> &mut Self {
+        self.0.HighestVersion = highest_version as i32;
         self
     }
 
-    pub fn with_first_w_slice(mut self, first_w_slice: u32) -> Self {
-        self.set_first_w_slice(first_w_slice);
+    pub fn with_highest_version(
+        mut self,
+        highest_version: RootSignatureVersion,
+    ) -> Self {
+        self.set_highest_version(highest_version);
         self
     }
 
-    pub fn first_w_slice(&self) -> u32 {
-        self.0.FirstWSlice
+    pub fn highest_version(&self) -> RootSignatureVersion {
+        unsafe { std::mem::transmute(self.0.HighestVersion) }
     }
+}
 
-    pub fn set_w_size(&mut self, w_size: u32) -> &mut Self {
-        self.0.WSize = w_size;
-        self
-    }
+/// Newtype around [u32] since it has a special value of [DESCRIPTOR_RANGE_OFFSET_APPEND]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct DescriptorRangeOffset(pub(crate) u32);
 
-    pub fn with_w_size(mut self, w_size: u32) -> Self {
-        self.set_w_size(w_size);
-        self
+impl From<u32> for DescriptorRangeOffset {
+    fn from(count: u32) -> Self {
+        Self(count)
     }
+}
 
-    pub fn w_size(&self) -> u32 {
-        self.0.WSize
+impl DescriptorRangeOffset {
+    pub fn append() -> Self {
+        Self(D3D12_DESCRIPTOR_RANGE_OFFSET_APPEND)
     }
 }
 
-/// Wrapper around D3D12_CLEAR_VALUE structure
-#[derive(Copy, Clone, Default, Debug)]
+/// Wrapper around D3D12_DESCRIPTOR_RANGE1 structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
 #[repr(transparent)]
-pub struct ClearValue(pub(crate) D3D12_CLEAR_VALUE);
+pub struct DescriptorRange(pub(crate) D3D12_DESCRIPTOR_RANGE1);
 
-impl ClearValue {
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
+impl DescriptorRange {
+    pub fn set_range_type(
+        &mut self,
+        range_type: DescriptorRangeType,
+    ) -> &mut Self {
+        self.0.RangeType = range_type as i32;
         self
     }
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
+    pub fn with_range_type(mut self, range_type: DescriptorRangeType) -> Self {
+        self.set_range_type(range_type);
         self
     }
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
+    pub fn range_type(&self) -> DescriptorRangeType {
+        unsafe { std::mem::transmute(self.0.RangeType) }
     }
 
-    pub fn set_color(&mut self, color: [f32; 4usize]) -> &mut Self {
-        self.0.__bindgen_anon_1.Color = color;
+    pub fn set_num_descriptors(&mut self, num_descriptors: u32) -> &mut Self {
+        self.0.NumDescriptors = num_descriptors;
         self
     }
 
-    pub fn with_color(mut self, color: [f32; 4usize]) -> Self {
-        self.set_color(color);
+    pub fn with_num_descriptors(mut self, num_descriptors: u32) -> Self {
+        self.set_num_descriptors(num_descriptors);
         self
     }
 
-    /// # Safety
-    ///
-    /// This function doesn't verify the current union variant
-    pub unsafe fn color(&self) -> [f32; 4usize] {
-        self.0.__bindgen_anon_1.Color
+    pub fn num_descriptors(&self) -> u32 {
+        self.0.NumDescriptors
     }
 
-    pub fn set_depth_stencil(
+    pub fn set_base_shader_register(
         &mut self,
-        depth_stencil: &DepthStencilValue,
+        base_shader_register: u32,
     ) -> &mut Self {
-        self.0.__bindgen_anon_1.DepthStencil = depth_stencil.0;
+        self.0.BaseShaderRegister = base_shader_register;
         self
     }
 
-    pub fn with_depth_stencil(
+    pub fn with_base_shader_register(
         mut self,
-        depth_stencil: &DepthStencilValue,
+        base_shader_register: u32,
     ) -> Self {
-        self.set_depth_stencil(depth_stencil);
+        self.set_base_shader_register(base_shader_register);
         self
     }
 
-    /// # Safety
-    ///
-    /// This function doesn't verify the current union variant
-    pub unsafe fn depth_stencil(&self) -> DepthStencilValue {
-        DepthStencilValue(self.0.__bindgen_anon_1.DepthStencil)
+    pub fn base_shader_register(&self) -> u32 {
+        self.0.BaseShaderRegister
     }
-}
 
-/// Wrapper around D3D12_DEPTH_STENCIL_VALUE structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct DepthStencilValue(pub(crate) D3D12_DEPTH_STENCIL_VALUE);
-
-impl DepthStencilValue {
-    pub fn set_depth(&mut self, depth: f32) -> &mut Self {
-        self.0.Depth = depth;
+    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
+        self.0.RegisterSpace = register_space;
         self
     }
 
-    pub fn with_depth(mut self, depth: f32) -> Self {
-        self.set_depth(depth);
+    pub fn with_register_space(mut self, register_space: u32) -> Self {
+        self.set_register_space(register_space);
         self
     }
 
-    pub fn depth(&self) -> f32 {
-        self.0.Depth
+    pub fn register_space(&self) -> u32 {
+        self.0.RegisterSpace
     }
 
-    pub fn set_stencil(&mut self, stencil: u8) -> &mut Self {
-        self.0.Stencil = stencil;
+    pub fn set_flags(&mut self, flags: DescriptorRangeFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
         self
     }
 
-    pub fn with_stencil(mut self, stencil: u8) -> Self {
-        self.set_stencil(stencil);
+    pub fn with_flags(mut self, flags: DescriptorRangeFlags) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn stencil(&self) -> u8 {
-        self.0.Stencil
+    pub fn flags(&self) -> DescriptorRangeFlags {
+        unsafe { DescriptorRangeFlags::from_bits_unchecked(self.0.Flags) }
     }
-}
-
-/// Wrapper around D3D12_DEPTH_STENCIL_VIEW_DESC structure
-#[derive(Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct DepthStencilViewDesc(pub(crate) D3D12_DEPTH_STENCIL_VIEW_DESC);
 
-// ToDo: encode the union variant in wrapper's type?
-impl DepthStencilViewDesc {
-    pub fn set_format(&mut self, format: Format) -> &mut Self {
-        self.0.Format = format as i32;
+    pub fn set_offset_in_descriptors_from_table_start(
+        &mut self,
+        offset_in_descriptors_from_table_start: DescriptorRangeOffset,
+    ) -> &mut Self {
+        self.0.OffsetInDescriptorsFromTableStart =
+            offset_in_descriptors_from_table_start.0;
         self
     }
 
-    pub fn with_format(mut self, format: Format) -> Self {
-        self.set_format(format);
+    pub fn with_offset_in_descriptors_from_table_start(
+        mut self,
+        offset_in_descriptors_from_table_start: DescriptorRangeOffset,
+    ) -> Self {
+        self.set_offset_in_descriptors_from_table_start(
+            offset_in_descriptors_from_table_start,
+        );
         self
     }
 
-    pub fn format(&self) -> Format {
-        unsafe { std::mem::transmute(self.0.Format) }
+    pub fn offset_in_descriptors_from_table_start(
+        &self,
+    ) -> DescriptorRangeOffset {
+        self.0.OffsetInDescriptorsFromTableStart.into()
     }
+}
 
-    pub fn view_dimension(&self) -> DsvDimension {
-        unsafe { std::mem::transmute(self.0.ViewDimension) }
-    }
+/// Wrapper around D3D12_ROOT_PARAMETER1 structure
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct RootParameter<'a>(
+    pub(crate) D3D12_ROOT_PARAMETER1,
+    PhantomData<&'a RootDescriptorTable<'a>>,
+);
 
-    pub fn set_flags(&mut self, flags: DsvFlags) -> &mut Self {
-        self.0.Flags = flags.bits();
-        self
+impl<'a> RootParameter<'a> {
+    pub fn parameter_type(&self) -> RootParameterType {
+        unsafe { std::mem::transmute(self.0.ParameterType) }
     }
 
-    pub fn with_flags(mut self, flags: DsvFlags) -> Self {
-        self.set_flags(flags);
+    pub fn new_descriptor_table(
+        mut self,
+        descriptor_table: &'a RootDescriptorTable<'a>,
+    ) -> Self {
+        self.0.ParameterType = RootParameterType::DescriptorTable as i32;
+        self.0.__bindgen_anon_1.DescriptorTable = descriptor_table.0;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn flags(&self) -> DsvFlags {
-        unsafe { DsvFlags::from_bits_unchecked(self.0.Flags) }
-    }
-
-    pub fn new_texture_1d(mut self, texture_1d: Tex1DDsv) -> Self {
-        self.0.ViewDimension = DsvDimension::Texture1D as i32;
-        self.0.__bindgen_anon_1.Texture1D = texture_1d.0;
-        self
-    }
-
-    pub fn texture_1d(&self) -> Option<Tex1DDsv> {
-        unsafe {
-            match self.view_dimension() {
-                DsvDimension::Texture1D => {
-                    Some(Tex1DDsv(self.0.__bindgen_anon_1.Texture1D))
-                }
-                _ => None,
-            }
-        }
-    }
-
-    pub fn new_texture_1d_array(
-        mut self,
-        texture_1d_array: Tex1DArrayDsv,
-    ) -> Self {
-        self.0.ViewDimension = DsvDimension::Texture1DArray as i32;
-        self.0.__bindgen_anon_1.Texture1DArray = texture_1d_array.0;
-        self
-    }
-
-    pub fn texture_1d_array(&self) -> Option<Tex1DArrayDsv> {
+    pub fn descriptor_table(&self) -> Option<RootDescriptorTable> {
         unsafe {
-            match self.view_dimension() {
-                DsvDimension::Texture1DArray => {
-                    Some(Tex1DArrayDsv(self.0.__bindgen_anon_1.Texture1DArray))
+            match self.parameter_type() {
+                RootParameterType::DescriptorTable => {
+                    Some(RootDescriptorTable(
+                        self.0.__bindgen_anon_1.DescriptorTable,
+                        PhantomData,
+                    ))
                 }
                 _ => None,
             }
         }
     }
 
-    pub fn new_texture_2d(mut self, texture_2d: Tex2DDsv) -> Self {
-        self.0.ViewDimension = DsvDimension::Texture2D as i32;
-        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
+    pub fn new_constants(mut self, constants: &RootConstants) -> Self {
+        self.0.ParameterType = RootParameterType::T32BitConstants as i32;
+        self.0.__bindgen_anon_1.Constants = constants.0;
         self
     }
 
-    pub fn texture_2d(&self) -> Option<Tex2DDsv> {
+    pub fn constants(&self) -> Option<RootConstants> {
         unsafe {
-            match self.view_dimension() {
-                DsvDimension::Texture2D => {
-                    Some(Tex2DDsv(self.0.__bindgen_anon_1.Texture2D))
+            match self.parameter_type() {
+                RootParameterType::T32BitConstants => {
+                    Some(RootConstants(self.0.__bindgen_anon_1.Constants))
                 }
                 _ => None,
             }
         }
     }
 
-    pub fn new_texture_2d_array(
+    pub fn new_descriptor(
         mut self,
-        texture_2d_array: Tex2DArrayDsv,
+        descriptor: &RootDescriptor,
+        descriptor_type: RootParameterType,
     ) -> Self {
-        self.0.ViewDimension = DsvDimension::Texture2DArray as i32;
-        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
+        assert!(matches!(
+            descriptor_type,
+            RootParameterType::Cbv
+                | RootParameterType::Srv
+                | RootParameterType::Uav
+        ));
+        self.0.ParameterType = descriptor_type as i32;
+        self.0.__bindgen_anon_1.Descriptor = descriptor.0;
         self
     }
 
-    pub fn texture_2d_array(&self) -> Option<Tex2DArrayDsv> {
+    pub fn descriptor(&self) -> Option<RootDescriptor> {
         unsafe {
-            match self.view_dimension() {
-                DsvDimension::Texture2DArray => {
-                    Some(Tex2DArrayDsv(self.0.__bindgen_anon_1.Texture2DArray))
+            match self.parameter_type() {
+                RootParameterType::Cbv
+                | RootParameterType::Srv
+                | RootParameterType::Uav => {
+                    Some(RootDescriptor(self.0.__bindgen_anon_1.Descriptor))
                 }
                 _ => None,
             }
         }
     }
 
-    pub fn new_texture_2d_ms(mut self, texture_2d_ms: Tex2DmsDsv) -> Self {
-        self.0.ViewDimension = DsvDimension::Texture2DMs as i32;
-        self.0.__bindgen_anon_1.Texture2DMS = texture_2d_ms.0;
+    pub fn set_shader_visibility(
+        &mut self,
+        shader_visibility: ShaderVisibility,
+    ) -> &mut Self {
+        self.0.ShaderVisibility = shader_visibility as i32;
         self
     }
 
-    pub fn texture_2d_ms(&self) -> Option<Tex2DmsDsv> {
-        unsafe {
-            match self.view_dimension() {
-                DsvDimension::Texture2DMs => {
-                    Some(Tex2DmsDsv(self.0.__bindgen_anon_1.Texture2DMS))
-                }
-                _ => None,
-            }
-        }
-    }
-
-    pub fn new_texture_2d_ms_array(
+    pub fn with_shader_visibility(
         mut self,
-        texture_2d_ms_array: Tex2DmsArrayDsv,
+        shader_visibility: ShaderVisibility,
     ) -> Self {
-        self.0.ViewDimension = DsvDimension::Texture2DMsArray as i32;
-        self.0.__bindgen_anon_1.Texture2DMSArray = texture_2d_ms_array.0;
+        self.set_shader_visibility(shader_visibility);
         self
     }
 
-    pub fn texture_2d_ms_array(&self) -> Option<Tex2DmsArrayDsv> {
-        unsafe {
-            match self.view_dimension() {
-                DsvDimension::Texture2DMsArray => Some(Tex2DmsArrayDsv(
-                    self.0.__bindgen_anon_1.Texture2DMSArray,
-                )),
-                _ => None,
-            }
-        }
+    pub fn shader_visibility(&self) -> ShaderVisibility {
+        unsafe { std::mem::transmute(self.0.ShaderVisibility) }
     }
 }
 
-/// Wrapper around D3D12_TEX1D_DSV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+/// Wrapper around D3D12_ROOT_DESCRIPTOR_TABLE1 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
 #[repr(transparent)]
-pub struct Tex1DDsv(pub(crate) D3D12_TEX1D_DSV);
+pub struct RootDescriptorTable<'a>(
+    pub D3D12_ROOT_DESCRIPTOR_TABLE1,
+    PhantomData<&'a DescriptorRange>,
+);
 
-impl Tex1DDsv {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
+impl<'a> RootDescriptorTable<'a> {
+    pub fn set_descriptor_ranges(
+        &mut self,
+        ranges: &'a [DescriptorRange],
+    ) -> &mut Self {
+        self.0.NumDescriptorRanges = ranges.len() as u32;
+        self.0.pDescriptorRanges =
+            ranges.as_ptr() as *const D3D12_DESCRIPTOR_RANGE1;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+    pub fn with_descriptor_ranges(
+        mut self,
+        ranges: &'a [DescriptorRange],
+    ) -> Self {
+        self.set_descriptor_ranges(ranges);
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn descriptor_ranges(&self) -> &'a [DescriptorRange] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.0.pDescriptorRanges as *const D3D12_DESCRIPTOR_RANGE1
+                    as *const DescriptorRange,
+                self.0.NumDescriptorRanges as usize,
+            )
+        }
     }
 }
 
-/// Wrapper around D3D12_TEX1D_ARRAY_DSV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+/// Wrapper around D3D12_ROOT_CONSTANTS structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
 #[repr(transparent)]
-pub struct Tex1DArrayDsv(pub(crate) D3D12_TEX1D_ARRAY_DSV);
+pub struct RootConstants(pub(crate) D3D12_ROOT_CONSTANTS);
 
-impl Tex1DArrayDsv {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
+impl RootConstants {
+    pub fn set_shader_register(&mut self, shader_register: u32) -> &mut Self {
+        self.0.ShaderRegister = shader_register;
         self
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+    pub fn with_shader_register(mut self, shader_register: u32) -> Self {
+        self.set_shader_register(shader_register);
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn shader_register(&self) -> u32 {
+        self.0.ShaderRegister
     }
 
-    pub fn set_first_array_slice(
-        &mut self,
-        first_array_slice: u32,
-    ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
+        self.0.RegisterSpace = register_space;
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_register_space(mut self, register_space: u32) -> Self {
+        self.set_register_space(register_space);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn register_space(&self) -> u32 {
+        self.0.RegisterSpace
     }
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+    pub fn set_num_32_bit_values(
+        &mut self,
+        num_32_bit_values: u32,
+    ) -> &mut Self {
+        self.0.Num32BitValues = num_32_bit_values;
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_num_32_bit_values(mut self, num_32_bit_values: u32) -> Self {
+        self.set_num_32_bit_values(num_32_bit_values);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn num_32_bit_values(&self) -> u32 {
+        self.0.Num32BitValues
     }
 }
 
-/// Wrapper around D3D12_TEX2D_DSV structure
+/// Wrapper around D3D12_ROOT_DESCRIPTOR1 structure
 #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
 #[repr(transparent)]
-pub struct Tex2DDsv(pub(crate) D3D12_TEX2D_DSV);
+pub struct RootDescriptor(pub(crate) D3D12_ROOT_DESCRIPTOR1);
 
-impl Tex2DDsv {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
+impl RootDescriptor {
+    pub fn set_shader_register(&mut self, shader_register: u32) -> &mut Self {
+        self.0.ShaderRegister = shader_register;
         self
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+    pub fn with_shader_register(mut self, shader_register: u32) -> Self {
+        self.set_shader_register(shader_register);
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn shader_register(&self) -> u32 {
+        self.0.ShaderRegister
     }
-}
-
-/// Wrapper around D3D12_TEX2D_ARRAY_DSV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct Tex2DArrayDsv(pub(crate) D3D12_TEX2D_ARRAY_DSV);
 
-impl Tex2DArrayDsv {
-    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
-        self.0.MipSlice = mip_slice;
+    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
+        self.0.RegisterSpace = register_space;
         self
     }
 
-    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
-        self.set_mip_slice(mip_slice);
+    pub fn with_register_space(mut self, register_space: u32) -> Self {
+        self.set_register_space(register_space);
         self
     }
 
-    pub fn mip_slice(&self) -> u32 {
-        self.0.MipSlice
+    pub fn register_space(&self) -> u32 {
+        self.0.RegisterSpace
     }
 
-    pub fn set_first_array_slice(
-        &mut self,
-        first_array_slice: u32,
-    ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+    pub fn set_flags(&mut self, flags: RootDescriptorFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_flags(mut self, flags: RootDescriptorFlags) -> Self {
+        self.set_flags(flags);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn flags(&self) -> RootDescriptorFlags {
+        unsafe { RootDescriptorFlags::from_bits_unchecked(self.0.Flags) }
     }
+}
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+/// Wrapper around D3D12_SAMPLER_DESC structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct SamplerDesc(pub(crate) D3D12_SAMPLER_DESC);
+
+impl SamplerDesc {
+    pub fn set_filter(&mut self, filter: Filter) -> &mut Self {
+        self.0.Filter = filter as i32;
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.set_filter(filter);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn filter(&self) -> Filter {
+        unsafe { std::mem::transmute(self.0.Filter) }
     }
-}
 
-/// Wrapper around D3D12_TEX2DMS_DSV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct Tex2DmsDsv(pub(crate) D3D12_TEX2DMS_DSV);
+    pub fn set_address_u(
+        &mut self,
+        address_u: TextureAddressMode,
+    ) -> &mut Self {
+        self.0.AddressU = address_u as i32;
+        self
+    }
 
-/// Wrapper around D3D12_TEX2DMS_ARRAY_DSV structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
-#[repr(transparent)]
-pub struct Tex2DmsArrayDsv(pub(crate) D3D12_TEX2DMS_ARRAY_DSV);
+    pub fn with_address_u(mut self, address_u: TextureAddressMode) -> Self {
+        self.set_address_u(address_u);
+        self
+    }
 
-impl Tex2DmsArrayDsv {
-    pub fn set_first_array_slice(
+    pub fn address_u(&self) -> TextureAddressMode {
+        unsafe { std::mem::transmute(self.0.AddressU) }
+    }
+
+    pub fn set_address_v(
         &mut self,
-        first_array_slice: u32,
+        address_v: TextureAddressMode,
     ) -> &mut Self {
-        self.0.FirstArraySlice = first_array_slice;
+        self.0.AddressV = address_v as i32;
         self
     }
 
-    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
-        self.set_first_array_slice(first_array_slice);
+    pub fn with_address_v(mut self, address_v: TextureAddressMode) -> Self {
+        self.set_address_v(address_v);
         self
     }
 
-    pub fn first_array_slice(&self) -> u32 {
-        self.0.FirstArraySlice
+    pub fn address_v(&self) -> TextureAddressMode {
+        unsafe { std::mem::transmute(self.0.AddressV) }
     }
 
-    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
-        self.0.ArraySize = array_size;
+    pub fn set_address_w(
+        &mut self,
+        address_w: TextureAddressMode,
+    ) -> &mut Self {
+        self.0.AddressW = address_w as i32;
         self
     }
 
-    pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.set_array_size(array_size);
+    pub fn with_address_w(mut self, address_w: TextureAddressMode) -> Self {
+        self.set_address_w(address_w);
         self
     }
 
-    pub fn array_size(&self) -> u32 {
-        self.0.ArraySize
+    pub fn address_w(&self) -> TextureAddressMode {
+        unsafe { std::mem::transmute(self.0.AddressW) }
     }
-}
 
-// ToDo: more ::new() constructors for one-field structs?
-/// Wrapper around D3D12_FEATURE_DATA_SHADER_MODEL structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
-#[repr(transparent)]
-pub struct FeatureDataShaderModel(pub(crate) D3D12_FEATURE_DATA_SHADER_MODEL);
+    pub fn set_mip_lod_bias(&mut self, mip_lod_bias: f32) -> &mut Self {
+        self.0.MipLODBias = mip_lod_bias;
+        self
+    }
 
-impl FeatureDataShaderModel {
-    pub fn new(highest_shader_model: ShaderModel) -> Self {
-        Self(D3D12_FEATURE_DATA_SHADER_MODEL {
-            HighestShaderModel: highest_shader_model as i32,
-        })
+    pub fn with_mip_lod_bias(mut self, mip_lod_bias: f32) -> Self {
+        self.set_mip_lod_bias(mip_lod_bias);
+        self
     }
 
-    pub fn highest_shader_model(&self) -> ShaderModel {
-        unsafe { std::mem::transmute(self.0.HighestShaderModel) }
+    pub fn mip_lod_bias(&self) -> f32 {
+        self.0.MipLODBias
     }
-}
 
-// ToDo: Default derives in the structs where they don't make sense
-// should be cleaned up (in favor of Builder pattern?)
-/// Wrapper around D3D12_PIPELINE_STATE_STREAM_DESC structure
-#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
-#[repr(transparent)]
-pub struct PipelineStateStreamDesc<'a>(
-    pub D3D12_PIPELINE_STATE_STREAM_DESC,
-    PhantomData<&'a [u8]>,
-);
+    pub fn set_max_anisotropy(&mut self, max_anisotropy: u32) -> &mut Self {
+        self.0.MaxAnisotropy = max_anisotropy;
+        self
+    }
 
-impl<'a> PipelineStateStreamDesc<'a> {
-    pub fn size_in_bytes(&self) -> ByteCount {
-        ByteCount::from(self.0.SizeInBytes)
+    pub fn with_max_anisotropy(mut self, max_anisotropy: u32) -> Self {
+        self.set_max_anisotropy(max_anisotropy);
+        self
     }
 
-    pub fn set_pipeline_state_subobject_stream(
+    pub fn max_anisotropy(&self) -> u32 {
+        self.0.MaxAnisotropy
+    }
+
+    pub fn set_comparison_func(
         &mut self,
-        subobject_stream: &'a [u8],
+        comparison_func: ComparisonFunc,
     ) -> &mut Self {
-        self.0.SizeInBytes = subobject_stream.len() as u64;
-        self.0.pPipelineStateSubobjectStream =
-            subobject_stream.as_ptr() as *mut std::ffi::c_void;
-        self.1 = PhantomData;
-
+        self.0.ComparisonFunc = comparison_func as i32;
         self
     }
 
-    pub fn with_pipeline_state_subobject_stream(
+    pub fn with_comparison_func(
         mut self,
-        subobject_stream: &'a [u8],
+        comparison_func: ComparisonFunc,
     ) -> Self {
-        self.set_pipeline_state_subobject_stream(subobject_stream);
+        self.set_comparison_func(comparison_func);
         self
     }
 
-    pub fn pipeline_state_subobject_stream(&self) -> &'a [u8] {
-        unsafe {
-            slice::from_raw_parts(
-                self.0.pPipelineStateSubobjectStream as *const u8,
-                self.0.SizeInBytes as usize,
-            )
-        }
+    pub fn comparison_func(&self) -> ComparisonFunc {
+        unsafe { std::mem::transmute(self.0.ComparisonFunc) }
     }
-}
 
-/// An element of a pipeline subobject stream (element type + subobject itself)
-#[derive(Default, Debug)]
-#[repr(C, align(8))]
-pub struct PipelineStateSubobject<T> {
-    ty: PipelineStateSubobjectType,
-    subobject: T,
-}
+    pub fn set_border_color(
+        &mut self,
+        border_color: [f32; 4usize],
+    ) -> &mut Self {
+        self.0.BorderColor = border_color;
+        self
+    }
 
-impl<T> PipelineStateSubobject<T> {
-    pub fn new(ty: PipelineStateSubobjectType, subobject: T) -> Self {
-        let mut subobject_wrapper: PipelineStateSubobject<T> =
-            unsafe { std::mem::zeroed() };
-        subobject_wrapper.ty = ty;
-        subobject_wrapper.subobject = subobject;
-        subobject_wrapper
+    pub fn with_border_color(mut self, border_color: [f32; 4usize]) -> Self {
+        self.set_border_color(border_color);
+        self
     }
-}
 
-// ToDo: a similar adapter for GraphicsPipelineState? In d3dx12.h
-// they have one, and also one more for compute PSO's
-// ToDo: do we realistically need getters here?
-/// Mesh shader pipeline description struct (a convenience struct that does not have C counterpart)
-#[repr(C)]
-#[derive(Debug)]
-pub struct MeshShaderPipelineStateDesc<'rs, 'sh> {
-    // We don't use wrapper types here since i) these members are private
-    // and don't leak into the public API, and ii) if we want to implement
-    // Default trait, we need to either wrap our objects like ShaderBytecode
-    // into Options or store raw pointers
-    // Fun fact: it turns out Option's are FFI-safe, but anyway, see i)
-    root_signature: PipelineStateSubobject<*mut ID3D12RootSignature>,
-    amplification_shader: PipelineStateSubobject<D3D12_SHADER_BYTECODE>,
-    mesh_shader: PipelineStateSubobject<D3D12_SHADER_BYTECODE>,
-    pixel_shader: PipelineStateSubobject<D3D12_SHADER_BYTECODE>,
-    blend_state: PipelineStateSubobject<D3D12_BLEND_DESC>,
-    sample_mask: PipelineStateSubobject<UINT>,
-    rasterizer_state: PipelineStateSubobject<D3D12_RASTERIZER_DESC>,
-    depth_stencil_state: PipelineStateSubobject<D3D12_DEPTH_STENCIL_DESC>,
-    primitive_topology_type:
-        PipelineStateSubobject<D3D12_PRIMITIVE_TOPOLOGY_TYPE>,
-    rtv_formats: PipelineStateSubobject<D3D12_RT_FORMAT_ARRAY>,
-    dsv_format: PipelineStateSubobject<DXGI_FORMAT>,
-    sample_desc: PipelineStateSubobject<DXGI_SAMPLE_DESC>,
-    node_mask: PipelineStateSubobject<UINT>,
-    cached_pso: PipelineStateSubobject<D3D12_CACHED_PIPELINE_STATE>,
-    flags: PipelineStateSubobject<i32>,
-    // ToDo: probably we need lifetimes on *mut IDXGI... wrappers, too?..
-    rs_phantom_data: PhantomData<&'rs RootSignature>,
-    sh_phantom_data: PhantomData<ShaderBytecode<'sh>>,
+    pub fn border_color(&self) -> [f32; 4usize] {
+        self.0.BorderColor
+    }
+
+    pub fn set_min_lod(&mut self, min_lod: f32) -> &mut Self {
+        self.0.MinLOD = min_lod;
+        self
+    }
+
+    pub fn with_min_lod(mut self, min_lod: f32) -> Self {
+        self.set_min_lod(min_lod);
+        self
+    }
+
+    pub fn min_lod(&self) -> f32 {
+        self.0.MinLOD
+    }
+
+    pub fn set_max_lod(&mut self, max_lod: f32) -> &mut Self {
+        self.0.MaxLOD = max_lod;
+        self
+    }
+
+    pub fn with_max_lod(mut self, max_lod: f32) -> Self {
+        self.set_max_lod(max_lod);
+        self
+    }
+
+    pub fn max_lod(&self) -> f32 {
+        self.0.MaxLOD
+    }
 }
 
-impl<'rs, 'sh> Default for MeshShaderPipelineStateDesc<'rs, 'sh> {
+/// Wrapper around D3D12_STATIC_SAMPLER_DESC structure
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct StaticSamplerDesc(pub(crate) D3D12_STATIC_SAMPLER_DESC);
+
+// based on the first constructor of CD3DX12_STATIC_SAMPLER_DESC
+impl Default for StaticSamplerDesc {
     fn default() -> Self {
-        let mut pso_desc: MeshShaderPipelineStateDesc =
-            unsafe { std::mem::zeroed() };
-        pso_desc.root_signature = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::RootSignature,
-            std::ptr::null_mut(),
-        );
-        pso_desc.amplification_shader = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::AS,
-            D3D12_SHADER_BYTECODE::default(),
-        );
-        pso_desc.mesh_shader = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::MS,
-            D3D12_SHADER_BYTECODE::default(),
-        );
-        pso_desc.pixel_shader = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::PS,
-            D3D12_SHADER_BYTECODE::default(),
-        );
-        pso_desc.blend_state = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::Blend,
-            BlendDesc::default().0,
-        );
-        pso_desc.sample_mask = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::SampleMask,
-            u32::MAX,
-        );
-        pso_desc.rasterizer_state = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::Rasterizer,
-            RasterizerDesc::default().0,
+        Self(D3D12_STATIC_SAMPLER_DESC {
+            Filter: D3D12_FILTER_D3D12_FILTER_ANISOTROPIC,
+            AddressU:
+                D3D12_TEXTURE_ADDRESS_MODE_D3D12_TEXTURE_ADDRESS_MODE_WRAP,
+            AddressV:
+                D3D12_TEXTURE_ADDRESS_MODE_D3D12_TEXTURE_ADDRESS_MODE_WRAP,
+            AddressW:
+                D3D12_TEXTURE_ADDRESS_MODE_D3D12_TEXTURE_ADDRESS_MODE_WRAP,
+            MipLODBias: 0.,
+            MaxAnisotropy: 16,
+            ComparisonFunc:
+                D3D12_COMPARISON_FUNC_D3D12_COMPARISON_FUNC_LESS_EQUAL,
+            BorderColor:
+                D3D12_STATIC_BORDER_COLOR_D3D12_STATIC_BORDER_COLOR_OPAQUE_WHITE,
+            MinLOD: 0.,
+            // ToDo: D3D12_FLOAT32_MAX - for some reason bindgen did not include this constant
+            MaxLOD: 3.402823466e+38,
+            ShaderRegister: 0,
+            RegisterSpace: 0,
+            ShaderVisibility:
+                D3D12_SHADER_VISIBILITY_D3D12_SHADER_VISIBILITY_ALL,
+        })
+    }
+}
+
+impl StaticSamplerDesc {
+    pub fn set_filter(&mut self, filter: Filter) -> &mut Self {
+        self.0.Filter = filter as i32;
+        self
+    }
+
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    pub fn filter(&self) -> Filter {
+        unsafe { std::mem::transmute(self.0.Filter) }
+    }
+
+    pub fn set_address_u(
+        &mut self,
+        address_u: TextureAddressMode,
+    ) -> &mut Self {
+        self.0.AddressU = address_u as i32;
+        self
+    }
+
+    pub fn with_address_u(mut self, address_u: TextureAddressMode) -> Self {
+        self.set_address_u(address_u);
+        self
+    }
+
+    pub fn address_u(&self) -> TextureAddressMode {
+        unsafe { std::mem::transmute(self.0.AddressU) }
+    }
+
+    pub fn set_address_v(
+        &mut self,
+        address_v: TextureAddressMode,
+    ) -> &mut Self {
+        self.0.AddressV = address_v as i32;
+        self
+    }
+
+    pub fn with_address_v(mut self, address_v: TextureAddressMode) -> Self {
+        self.set_address_v(address_v);
+        self
+    }
+
+    pub fn address_v(&self) -> TextureAddressMode {
+        unsafe { std::mem::transmute(self.0.AddressV) }
+    }
+
+    pub fn set_address_w(
+        &mut self,
+        address_w: TextureAddressMode,
+    ) -> &mut Self {
+        self.0.AddressW = address_w as i32;
+        self
+    }
+
+    pub fn with_address_w(mut self, address_w: TextureAddressMode) -> Self {
+        self.set_address_w(address_w);
+        self
+    }
+
+    pub fn address_w(&self) -> TextureAddressMode {
+        unsafe { std::mem::transmute(self.0.AddressW) }
+    }
+
+    pub fn set_mip_lod_bias(&mut self, mip_lod_bias: f32) -> &mut Self {
+        self.0.MipLODBias = mip_lod_bias;
+        self
+    }
+
+    pub fn with_mip_lod_bias(mut self, mip_lod_bias: f32) -> Self {
+        self.set_mip_lod_bias(mip_lod_bias);
+        self
+    }
+
+    pub fn mip_lod_bias(&self) -> f32 {
+        self.0.MipLODBias
+    }
+
+    pub fn set_max_anisotropy(&mut self, max_anisotropy: u32) -> &mut Self {
+        self.0.MaxAnisotropy = max_anisotropy;
+        self
+    }
+
+    pub fn with_max_anisotropy(mut self, max_anisotropy: u32) -> Self {
+        self.set_max_anisotropy(max_anisotropy);
+        self
+    }
+
+    pub fn max_anisotropy(&self) -> u32 {
+        self.0.MaxAnisotropy
+    }
+
+    pub fn set_comparison_func(
+        &mut self,
+        comparison_func: ComparisonFunc,
+    ) -> &mut Self {
+        self.0.ComparisonFunc = comparison_func as i32;
+        self
+    }
+
+    pub fn with_comparison_func(
+        mut self,
+        comparison_func: ComparisonFunc,
+    ) -> Self {
+        self.set_comparison_func(comparison_func);
+        self
+    }
+
+    pub fn comparison_func(&self) -> ComparisonFunc {
+        unsafe { std::mem::transmute(self.0.ComparisonFunc) }
+    }
+
+    pub fn set_border_color(
+        &mut self,
+        border_color: StaticBorderColor,
+    ) -> &mut Self {
+        self.0.BorderColor = border_color as i32;
+        self
+    }
+
+    pub fn with_border_color(
+        mut self,
+        border_color: StaticBorderColor,
+    ) -> Self {
+        self.set_border_color(border_color);
+        self
+    }
+
+    pub fn border_color(&self) -> StaticBorderColor {
+        unsafe { std::mem::transmute(self.0.BorderColor) }
+    }
+
+    pub fn set_min_lod(&mut self, min_lod: f32) -> &mut Self {
+        self.0.MinLOD = min_lod;
+        self
+    }
+
+    pub fn with_min_lod(mut self, min_lod: f32) -> Self {
+        self.set_min_lod(min_lod);
+        self
+    }
+
+    pub fn min_lod(&self) -> f32 {
+        self.0.MinLOD
+    }
+
+    pub fn set_max_lod(&mut self, max_lod: f32) -> &mut Self {
+        self.0.MaxLOD = max_lod;
+        self
+    }
+
+    pub fn with_max_lod(mut self, max_lod: f32) -> Self {
+        self.set_max_lod(max_lod);
+        self
+    }
+
+    pub fn max_lod(&self) -> f32 {
+        self.0.MaxLOD
+    }
+
+    pub fn set_shader_register(&mut self, shader_register: u32) -> &mut Self {
+        self.0.ShaderRegister = shader_register;
+        self
+    }
+
+    pub fn with_shader_register(mut self, shader_register: u32) -> Self {
+        self.set_shader_register(shader_register);
+        self
+    }
+
+    pub fn shader_register(&self) -> u32 {
+        self.0.ShaderRegister
+    }
+
+    pub fn set_register_space(&mut self, register_space: u32) -> &mut Self {
+        self.0.RegisterSpace = register_space;
+        self
+    }
+
+    pub fn with_register_space(mut self, register_space: u32) -> Self {
+        self.set_register_space(register_space);
+        self
+    }
+
+    pub fn register_space(&self) -> u32 {
+        self.0.RegisterSpace
+    }
+
+    pub fn set_shader_visibility(
+        &mut self,
+        shader_visibility: ShaderVisibility,
+    ) -> &mut Self {
+        self.0.ShaderVisibility = shader_visibility as i32;
+        self
+    }
+
+    pub fn with_shader_visibility(
+        mut self,
+        shader_visibility: ShaderVisibility,
+    ) -> Self {
+        self.set_shader_visibility(shader_visibility);
+        self
+    }
+
+    pub fn shader_visibility(&self) -> ShaderVisibility {
+        unsafe { std::mem::transmute(self.0.ShaderVisibility) }
+    }
+}
+
+/// Wrapper around D3D12_VERSIONED_ROOT_SIGNATURE_DESC structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct VersionedRootSignatureDesc(
+    pub(crate) D3D12_VERSIONED_ROOT_SIGNATURE_DESC,
+);
+
+impl VersionedRootSignatureDesc {
+    // RS v1.0 is not supported
+    // pub fn set_desc_1_0(self, _desc_1_0: &RootSignatureDesc) -> Self {
+    //     unimplemented!();
+    // }
+
+    pub fn set_desc_1_1(&mut self, desc_1_1: &RootSignatureDesc) -> &mut Self {
+        self.0.Version =
+            D3D_ROOT_SIGNATURE_VERSION_D3D_ROOT_SIGNATURE_VERSION_1_1;
+        self.0.__bindgen_anon_1.Desc_1_1 = desc_1_1.0;
+        self
+    }
+
+    pub fn with_desc_1_1(mut self, desc_1_1: &RootSignatureDesc) -> Self {
+        self.set_desc_1_1(desc_1_1);
+        self
+    }
+
+    pub fn desc_1_1(&self) -> RootSignatureDesc {
+        unsafe {
+            RootSignatureDesc(
+                self.0.__bindgen_anon_1.Desc_1_1,
+                PhantomData,
+                PhantomData,
+            )
+        }
+    }
+}
+
+/// Wrapper around D3D12_ROOT_SIGNATURE_DESC1 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct RootSignatureDesc<'a, 'b>(
+    pub D3D12_ROOT_SIGNATURE_DESC1,
+    PhantomData<&'a RootParameter<'a>>,
+    PhantomData<&'b StaticSamplerDesc>,
+);
+
+impl<'a, 'b> RootSignatureDesc<'a, 'b> {
+    pub fn set_parameters(
+        &mut self,
+        parameters: &'a [RootParameter],
+    ) -> &mut Self {
+        self.0.NumParameters = parameters.len() as u32;
+        self.0.pParameters =
+            parameters.as_ptr() as *const D3D12_ROOT_PARAMETER1;
+        self.1 = PhantomData;
+        self
+    }
+
+    pub fn with_parameters(mut self, parameters: &'a [RootParameter]) -> Self {
+        self.set_parameters(parameters);
+        self
+    }
+
+    pub fn parameters(&self) -> &'a [RootParameter] {
+        unsafe {
+            slice::from_raw_parts(
+                self.0.pParameters as *const D3D12_ROOT_PARAMETER1
+                    as *const RootParameter,
+                self.0.NumParameters as usize,
+            )
+        }
+    }
+
+    pub fn set_static_samplers(
+        &mut self,
+        static_samplers: &'b [StaticSamplerDesc],
+    ) -> &mut Self {
+        self.0.NumStaticSamplers = static_samplers.len() as u32;
+        self.0.pStaticSamplers =
+            static_samplers.as_ptr() as *const D3D12_STATIC_SAMPLER_DESC;
+        self.2 = PhantomData;
+        self
+    }
+
+    pub fn with_static_samplers(
+        mut self,
+        static_samplers: &'b [StaticSamplerDesc],
+    ) -> Self {
+        self.set_static_samplers(static_samplers);
+        self
+    }
+
+    pub fn static_samplers(&self) -> &'a [StaticSamplerDesc] {
+        unsafe {
+            slice::from_raw_parts(
+                self.0.pStaticSamplers as *const D3D12_STATIC_SAMPLER_DESC
+                    as *const StaticSamplerDesc,
+                self.0.NumStaticSamplers as usize,
+            )
+        }
+    }
+
+    pub fn set_flags(&mut self, flags: RootSignatureFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
+        self
+    }
+
+    pub fn with_flags(mut self, flags: RootSignatureFlags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    pub fn flags(&self) -> RootSignatureFlags {
+        unsafe { RootSignatureFlags::from_bits_unchecked(self.0.Flags) }
+    }
+}
+
+/// Wrapper around D3D12_SUBRESOURCE_DATA structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct SubresourceData<'a>(
+    pub D3D12_SUBRESOURCE_DATA,
+    PhantomData<&'a [()]>,
+);
+
+impl<'a> SubresourceData<'a> {
+    pub fn set_data<T>(&mut self, data: &'a [T]) -> &mut Self {
+        self.0.pData = data.as_ptr() as *const std::ffi::c_void;
+        self.1 = PhantomData;
+        self
+    }
+
+    pub fn with_data<T>(mut self, data: &'a [T]) -> Self {
+        self.set_data(data);
+        self
+    }
+
+    // ToDo?
+    // pub fn data<T>(&self) -> &'a [T] {
+    //     unsafe {
+    //         slice::from_raw_parts(
+    //             self.0.pData as *const T,
+    //             self.0.SizeInBytes as usize,
+    //         )
+    //     }
+    // }
+
+    pub fn set_row_pitch(&mut self, row_pitch: ByteCount) -> &mut Self {
+        self.0.RowPitch = row_pitch.0 as i64;
+        self
+    }
+
+    pub fn with_row_pitch(mut self, row_pitch: ByteCount) -> Self {
+        self.set_row_pitch(row_pitch);
+        self
+    }
+
+    pub fn row_pitch(&self) -> ByteCount {
+        ByteCount::from(self.0.RowPitch)
+    }
+
+    pub fn set_slice_pitch(&mut self, slice_pitch: ByteCount) -> &mut Self {
+        self.0.SlicePitch = slice_pitch.0 as i64;
+        self
+    }
+
+    pub fn with_slice_pitch(mut self, slice_pitch: ByteCount) -> Self {
+        self.set_slice_pitch(slice_pitch);
+        self
+    }
+
+    pub fn slice_pitch(&self) -> ByteCount {
+        ByteCount::from(self.0.SlicePitch)
+    }
+}
+
+/// Wrapper around D3D12_SHADER_RESOURCE_VIEW_DESC structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct ShaderResourceViewDesc(pub(crate) D3D12_SHADER_RESOURCE_VIEW_DESC);
+
+impl ShaderResourceViewDesc {
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
+    }
+
+    pub fn view_dimension(&self) -> SrvDimension {
+        unsafe { std::mem::transmute(self.0.ViewDimension) }
+    }
+
+    pub fn set_shader_4_component_mapping(
+        &mut self,
+        shader4_component_mapping: ShaderComponentMapping,
+    ) -> &mut Self {
+        self.0.Shader4ComponentMapping = shader4_component_mapping.into();
+        self
+    }
+
+    pub fn with_shader_4_component_mapping(
+        mut self,
+        shader4_component_mapping: ShaderComponentMapping,
+    ) -> Self {
+        self.set_shader_4_component_mapping(shader4_component_mapping);
+        self
+    }
+
+    pub fn shader_4_component_mapping(&self) -> ShaderComponentMapping {
+        self.0.Shader4ComponentMapping.into()
+    }
+
+    // ToDo: rename these new* since at the call site they look
+    // like a regular setter. Another option is to remove Default derive
+    pub fn new_buffer(mut self, buffer: &BufferSrv) -> Self {
+        self.0.ViewDimension = SrvDimension::Buffer as i32;
+        self.0.__bindgen_anon_1.Buffer = buffer.0;
+        self
+    }
+
+    pub fn buffer(&self) -> Option<BufferSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Buffer => {
+                    Some(BufferSrv(self.0.__bindgen_anon_1.Buffer))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_1d(mut self, texture_1d: &Tex1DSrv) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture1D as i32;
+        self.0.__bindgen_anon_1.Texture1D = texture_1d.0;
+        self
+    }
+
+    pub fn texture_1d(&self) -> Option<Tex1DSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture1D => {
+                    Some(Tex1DSrv(self.0.__bindgen_anon_1.Texture1D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_1d_array(
+        mut self,
+        texture_1d_array: &Tex1DArraySrv,
+    ) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture1DArray as i32;
+        self.0.__bindgen_anon_1.Texture1DArray = texture_1d_array.0;
+        self
+    }
+
+    pub fn texture_1d_array(&self) -> Option<Tex1DArraySrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture1DArray => {
+                    Some(Tex1DArraySrv(self.0.__bindgen_anon_1.Texture1DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d(mut self, texture_2d: &Tex2DSrv) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture2D as i32;
+        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
+        self
+    }
+
+    pub fn texture_2d(&self) -> Option<Tex2DSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture2D => {
+                    Some(Tex2DSrv(self.0.__bindgen_anon_1.Texture2D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_array(
+        mut self,
+        texture_2d_array: &Tex2DArraySrv,
+    ) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture2DArray as i32;
+        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
+        self
+    }
+
+    pub fn texture_2d_array(&self) -> Option<Tex2DArraySrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture2DArray => {
+                    Some(Tex2DArraySrv(self.0.__bindgen_anon_1.Texture2DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_ms(mut self, texture_2d_ms: &Tex2DMsSrv) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture2DMs as i32;
+        self.0.__bindgen_anon_1.Texture2DMS = texture_2d_ms.0;
+        self
+    }
+
+    pub fn texture_2d_ms(&self) -> Option<Tex2DMsSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture2DMs => {
+                    Some(Tex2DMsSrv(self.0.__bindgen_anon_1.Texture2DMS))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_ms_array(
+        mut self,
+        texture_2d_ms_array: &Tex2DMsArraySrv,
+    ) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture2DMsArray as i32;
+        self.0.__bindgen_anon_1.Texture2DMSArray = texture_2d_ms_array.0;
+        self
+    }
+
+    pub fn texture_2d_ms_array(&self) -> Option<Tex2DMsArraySrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture2DMsArray => Some(Tex2DMsArraySrv(
+                    self.0.__bindgen_anon_1.Texture2DMSArray,
+                )),
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_3d(mut self, texture_3d: &Tex3DSrv) -> Self {
+        self.0.ViewDimension = SrvDimension::Texture3D as i32;
+        self.0.__bindgen_anon_1.Texture3D = texture_3d.0;
+        self
+    }
+
+    pub fn texture_3d(&self) -> Option<Tex3DSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::Texture3D => {
+                    Some(Tex3DSrv(self.0.__bindgen_anon_1.Texture3D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_cube(mut self, texture_cube: &TexcubeSrv) -> Self {
+        self.0.ViewDimension = SrvDimension::TextureCube as i32;
+        self.0.__bindgen_anon_1.TextureCube = texture_cube.0;
+        self
+    }
+
+    pub fn texture_cube(&self) -> Option<TexcubeSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::TextureCube => {
+                    Some(TexcubeSrv(self.0.__bindgen_anon_1.TextureCube))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_cube_array(
+        mut self,
+        texture_cube_array: &TexcubeArraySrv,
+    ) -> Self {
+        self.0.ViewDimension = SrvDimension::TextureCubeArray as i32;
+        self.0.__bindgen_anon_1.TextureCubeArray = texture_cube_array.0;
+        self
+    }
+
+    pub fn texture_cube_array(&self) -> Option<TexcubeArraySrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::TextureCubeArray => Some(TexcubeArraySrv(
+                    self.0.__bindgen_anon_1.TextureCubeArray,
+                )),
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_raytracing_acceleration_structure(
+        mut self,
+        raytracing_acceleration_structure: &RaytracingAccelerationStructureSrv,
+    ) -> Self {
+        self.0.ViewDimension =
+            SrvDimension::RaytracingAccelerationStructure as i32;
+        self.0.__bindgen_anon_1.RaytracingAccelerationStructure =
+            raytracing_acceleration_structure.0;
+        self
+    }
+
+    pub fn raytracing_acceleration_structure(
+        &self,
+    ) -> Option<RaytracingAccelerationStructureSrv> {
+        unsafe {
+            match self.view_dimension() {
+                SrvDimension::RaytracingAccelerationStructure => {
+                    Some(RaytracingAccelerationStructureSrv(
+                        self.0.__bindgen_anon_1.RaytracingAccelerationStructure,
+                    ))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Wrapper around D3D12_BUFFER_SRV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct BufferSrv(pub(crate) D3D12_BUFFER_SRV);
+
+impl BufferSrv {
+    pub fn set_first_element(&mut self, first_element: u64) -> &mut Self {
+        self.0.FirstElement = first_element;
+        self
+    }
+
+    pub fn with_first_element(mut self, first_element: u64) -> Self {
+        self.set_first_element(first_element);
+        self
+    }
+
+    pub fn first_element(&self) -> u64 {
+        self.0.FirstElement
+    }
+
+    pub fn set_num_elements(&mut self, num_elements: u32) -> &mut Self {
+        self.0.NumElements = num_elements;
+        self
+    }
+
+    pub fn with_num_elements(mut self, num_elements: u32) -> Self {
+        self.set_num_elements(num_elements);
+        self
+    }
+
+    pub fn num_elements(&self) -> u32 {
+        self.0.NumElements
+    }
+
+    pub fn set_structure_byte_stride(
+        &mut self,
+        structure_byte_stride: ByteCount,
+    ) -> &mut Self {
+        self.0.StructureByteStride = structure_byte_stride.0 as u32;
+        self
+    }
+
+    pub fn with_structure_byte_stride(
+        mut self,
+        structure_byte_stride: ByteCount,
+    ) -> Self {
+        self.set_structure_byte_stride(structure_byte_stride);
+        self
+    }
+
+    pub fn structure_byte_stride(&self) -> ByteCount {
+        ByteCount::from(self.0.StructureByteStride)
+    }
+
+    pub fn set_flags(&mut self, flags: BufferSrvFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
+        self
+    }
+
+    pub fn with_flags(mut self, flags: BufferSrvFlags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    // ToDo: truncate instead of unchecked?
+    pub fn flags(&self) -> BufferSrvFlags {
+        unsafe { BufferSrvFlags::from_bits_unchecked(self.0.Flags) }
+    }
+}
+
+/// Wrapper around D3D12_TEX1D_SRV structure
+#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
+#[repr(transparent)]
+pub struct Tex1DSrv(pub(crate) D3D12_TEX1D_SRV);
+
+impl Tex1DSrv {
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_TEX1D_ARRAY_SRV structure
+#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
+#[repr(transparent)]
+pub struct Tex1DArraySrv(pub(crate) D3D12_TEX1D_ARRAY_SRV);
+
+impl Tex1DArraySrv {
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_SRV structure
+#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
+#[repr(transparent)]
+pub struct Tex2DSrv(pub(crate) D3D12_TEX2D_SRV);
+
+impl Tex2DSrv {
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
+        self.0.PlaneSlice = plane_slice;
+        self
+    }
+
+    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
+        self.set_plane_slice(plane_slice);
+        self
+    }
+
+    pub fn plane_slice(&self) -> u32 {
+        self.0.PlaneSlice
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_ARRAY_SRV structure
+#[derive(Default, Debug, PartialOrd, PartialEq, Clone)]
+#[repr(transparent)]
+pub struct Tex2DArraySrv(pub(crate) D3D12_TEX2D_ARRAY_SRV);
+
+impl Tex2DArraySrv {
+    /// Sets the mips, the array slices and the plane (which has to be
+    /// the only one in the range) of the view
+    pub fn set_subresource_range(
+        &mut self,
+        range: &SubresourceRange,
+    ) -> &mut Self {
+        debug_assert!(range.planes.len() == 1, "SRV must refer to one plane");
+        self.0.MostDetailedMip = range.mips.start;
+        self.0.MipLevels = range.mips.len() as u32;
+        self.0.FirstArraySlice = range.array_slices.start;
+        self.0.ArraySize = range.array_slices.len() as u32;
+        self.0.PlaneSlice = range.planes.start;
+        self
+    }
+
+    pub fn with_subresource_range(mut self, range: &SubresourceRange) -> Self {
+        self.set_subresource_range(range);
+        self
+    }
+
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+
+    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
+        self.0.PlaneSlice = plane_slice;
+        self
+    }
+
+    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
+        self.set_plane_slice(plane_slice);
+        self
+    }
+
+    pub fn plane_slice(&self) -> u32 {
+        self.0.PlaneSlice
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_TEX2DMS_SRV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DMsSrv(pub(crate) D3D12_TEX2DMS_SRV);
+
+/// Wrapper around D3D12_TEX2DMS_ARRAY_SRV structure
+#[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
+#[repr(transparent)]
+pub struct Tex2DMsArraySrv(pub(crate) D3D12_TEX2DMS_ARRAY_SRV);
+
+impl Tex2DMsArraySrv {
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+}
+
+/// Wrapper around D3D12_TEX3D_SRV structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex3DSrv(pub(crate) D3D12_TEX3D_SRV);
+
+impl Tex3DSrv {
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_TEXCUBE_SRV structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct TexcubeSrv(pub(crate) D3D12_TEXCUBE_SRV);
+
+impl TexcubeSrv {
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_TEXCUBE_ARRAY_SRV structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct TexcubeArraySrv(pub(crate) D3D12_TEXCUBE_ARRAY_SRV);
+
+impl TexcubeArraySrv {
+    pub fn set_most_detailed_mip(
+        &mut self,
+        most_detailed_mip: u32,
+    ) -> &mut Self {
+        self.0.MostDetailedMip = most_detailed_mip;
+        self
+    }
+
+    pub fn with_most_detailed_mip(mut self, most_detailed_mip: u32) -> Self {
+        self.set_most_detailed_mip(most_detailed_mip);
+        self
+    }
+
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.0.MostDetailedMip
+    }
+
+    pub fn set_mip_levels(&mut self, mip_levels: u32) -> &mut Self {
+        self.0.MipLevels = mip_levels;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.set_mip_levels(mip_levels);
+        self
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.0.MipLevels
+    }
+
+    pub fn set_first_2d_array_face(
+        &mut self,
+        first_2d_array_face: u32,
+    ) -> &mut Self {
+        self.0.First2DArrayFace = first_2d_array_face;
+        self
+    }
+
+    pub fn with_first_2d_array_face(
+        mut self,
+        first_2d_array_face: u32,
+    ) -> Self {
+        self.set_first_2d_array_face(first_2d_array_face);
+        self
+    }
+
+    pub fn first_2d_array_face(&self) -> u32 {
+        self.0.First2DArrayFace
+    }
+
+    pub fn set_num_cubes(&mut self, num_cubes: u32) -> &mut Self {
+        self.0.NumCubes = num_cubes;
+        self
+    }
+
+    pub fn with_num_cubes(mut self, num_cubes: u32) -> Self {
+        self.set_num_cubes(num_cubes);
+        self
+    }
+
+    pub fn num_cubes(&self) -> u32 {
+        self.0.NumCubes
+    }
+
+    pub fn set_resource_min_lod_clamp(
+        &mut self,
+        resource_min_lod_clamp: f32,
+    ) -> &mut Self {
+        self.0.ResourceMinLODClamp = resource_min_lod_clamp;
+        self
+    }
+
+    pub fn with_resource_min_lod_clamp(
+        mut self,
+        resource_min_lod_clamp: f32,
+    ) -> Self {
+        self.set_resource_min_lod_clamp(resource_min_lod_clamp);
+        self
+    }
+
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.0.ResourceMinLODClamp
+    }
+}
+
+/// Wrapper around D3D12_RAYTRACING_ACCELERATION_STRUCTURE_SRV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct RaytracingAccelerationStructureSrv(
+    pub D3D12_RAYTRACING_ACCELERATION_STRUCTURE_SRV,
+);
+
+impl RaytracingAccelerationStructureSrv {
+    pub fn set_location(&mut self, location: GpuVirtualAddress) -> &mut Self {
+        self.0.Location = location.0;
+        self
+    }
+
+    pub fn with_location(mut self, location: GpuVirtualAddress) -> Self {
+        self.set_location(location);
+        self
+    }
+
+    pub fn location(&self) -> GpuVirtualAddress {
+        GpuVirtualAddress(self.0.Location)
+    }
+}
+
+/// Wrapper around D3D12_UNORDERED_ACCESS_VIEW_DESC structure
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, Debug)]
+pub struct UnorderedAccessViewDesc(pub(crate) D3D12_UNORDERED_ACCESS_VIEW_DESC);
+
+impl UnorderedAccessViewDesc {
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
+    }
+
+    pub fn view_dimension(&self) -> UavDimension {
+        unsafe { std::mem::transmute(self.0.ViewDimension) }
+    }
+
+    // ToDo: rename these new* since at the call site they look
+    // like a regular setter. Another option is to remove Default derive
+    pub fn new_buffer(mut self, buffer: &BufferUav) -> Self {
+        self.0.ViewDimension = UavDimension::Buffer as i32;
+        self.0.__bindgen_anon_1.Buffer = buffer.0;
+        self
+    }
+
+    pub fn buffer(&self) -> Option<BufferUav> {
+        unsafe {
+            match self.view_dimension() {
+                UavDimension::Buffer => {
+                    Some(BufferUav(self.0.__bindgen_anon_1.Buffer))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_1d(mut self, texture_1d: &Tex1DUav) -> Self {
+        self.0.ViewDimension = UavDimension::Texture1D as i32;
+        self.0.__bindgen_anon_1.Texture1D = texture_1d.0;
+        self
+    }
+
+    pub fn texture_1d(&self) -> Option<Tex1DUav> {
+        unsafe {
+            match self.view_dimension() {
+                UavDimension::Texture1D => {
+                    Some(Tex1DUav(self.0.__bindgen_anon_1.Texture1D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_1d_array(
+        mut self,
+        texture_1d_array: &Tex1DArrayUav,
+    ) -> Self {
+        self.0.ViewDimension = UavDimension::Texture1DArray as i32;
+        self.0.__bindgen_anon_1.Texture1DArray = texture_1d_array.0;
+        self
+    }
+
+    pub fn texture_1d_array(&self) -> Option<Tex1DArrayUav> {
+        unsafe {
+            match self.view_dimension() {
+                UavDimension::Texture1DArray => {
+                    Some(Tex1DArrayUav(self.0.__bindgen_anon_1.Texture1DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d(mut self, texture_2d: &Tex2DUav) -> Self {
+        self.0.ViewDimension = UavDimension::Texture2D as i32;
+        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
+        self
+    }
+
+    pub fn texture_2d(&self) -> Option<Tex2DUav> {
+        unsafe {
+            match self.view_dimension() {
+                UavDimension::Texture2D => {
+                    Some(Tex2DUav(self.0.__bindgen_anon_1.Texture2D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_array(
+        mut self,
+        texture_2d_array: &Tex2DArrayUav,
+    ) -> Self {
+        self.0.ViewDimension = UavDimension::Texture2DArray as i32;
+        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
+        self
+    }
+
+    pub fn texture_2d_array(&self) -> Option<Tex2DArrayUav> {
+        unsafe {
+            match self.view_dimension() {
+                UavDimension::Texture2DArray => {
+                    Some(Tex2DArrayUav(self.0.__bindgen_anon_1.Texture2DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_3d(mut self, texture_3d: &Tex3DUav) -> Self {
+        self.0.ViewDimension = UavDimension::Texture3D as i32;
+        self.0.__bindgen_anon_1.Texture3D = texture_3d.0;
+        self
+    }
+
+    pub fn texture_3d(&self) -> Option<Tex3DUav> {
+        unsafe {
+            match self.view_dimension() {
+                UavDimension::Texture3D => {
+                    Some(Tex3DUav(self.0.__bindgen_anon_1.Texture3D))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Wrapper around D3D12_BUFFER_UAV structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+pub struct BufferUav(pub(crate) D3D12_BUFFER_UAV);
+
+impl BufferUav {
+    pub fn set_first_element(&mut self, first_element: u64) -> &mut Self {
+        self.0.FirstElement = first_element;
+        self
+    }
+
+    pub fn with_first_element(mut self, first_element: u64) -> Self {
+        self.set_first_element(first_element);
+        self
+    }
+
+    pub fn first_element(&self) -> u64 {
+        self.0.FirstElement
+    }
+
+    pub fn set_num_elements(&mut self, num_elements: u32) -> &mut Self {
+        self.0.NumElements = num_elements;
+        self
+    }
+
+    pub fn with_num_elements(mut self, num_elements: u32) -> Self {
+        self.set_num_elements(num_elements);
+        self
+    }
+
+    pub fn num_elements(&self) -> u32 {
+        self.0.NumElements
+    }
+
+    pub fn set_structure_byte_stride(
+        &mut self,
+        structure_byte_stride: ByteCount,
+    ) -> &mut Self {
+        self.0.StructureByteStride = structure_byte_stride.0 as u32;
+        self
+    }
+
+    pub fn with_structure_byte_stride(
+        mut self,
+        structure_byte_stride: ByteCount,
+    ) -> Self {
+        self.set_structure_byte_stride(structure_byte_stride);
+        self
+    }
+
+    pub fn structure_byte_stride(&self) -> ByteCount {
+        ByteCount::from(self.0.StructureByteStride)
+    }
+
+    pub fn set_counter_offset_in_bytes(
+        &mut self,
+        counter_offset_in_bytes: ByteCount,
+    ) -> &mut Self {
+        self.0.CounterOffsetInBytes = counter_offset_in_bytes.0;
+        self
+    }
+
+    pub fn with_counter_offset_in_bytes(
+        mut self,
+        counter_offset_in_bytes: ByteCount,
+    ) -> Self {
+        self.set_counter_offset_in_bytes(counter_offset_in_bytes);
+        self
+    }
+
+    pub fn counter_offset_in_bytes(&self) -> ByteCount {
+        ByteCount(self.0.CounterOffsetInBytes)
+    }
+
+    pub fn set_flags(&mut self, flags: BufferUavFlags) -> &mut Self {
+        self.0.Flags = flags as i32;
+        self
+    }
+
+    pub fn with_flags(mut self, flags: BufferUavFlags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    pub fn flags(&self) -> BufferUavFlags {
+        unsafe { std::mem::transmute(self.0.Flags) }
+    }
+}
+
+/// Wrapper around D3D12_TEX1D_UAV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex1DUav(pub(crate) D3D12_TEX1D_UAV);
+
+impl Tex1DUav {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+}
+
+/// Wrapper around D3D12_TEX1D_ARRAY_UAV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Tex1DArrayUav(pub(crate) D3D12_TEX1D_ARRAY_UAV);
+
+impl Tex1DArrayUav {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_UAV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Tex2DUav(pub(crate) D3D12_TEX2D_UAV);
+
+impl Tex2DUav {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
+        self.0.PlaneSlice = plane_slice;
+        self
+    }
+
+    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
+        self.set_plane_slice(plane_slice);
+        self
+    }
+
+    pub fn plane_slice(&self) -> u32 {
+        self.0.PlaneSlice
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_ARRAY_UAV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Tex2DArrayUav(pub(crate) D3D12_TEX2D_ARRAY_UAV);
+
+impl Tex2DArrayUav {
+    /// Sets the mip, the array slices and the plane of the view, the mip
+    /// and plane ranges have to consist of a single element
+    pub fn set_subresource_range(
+        &mut self,
+        range: &SubresourceRange,
+    ) -> &mut Self {
+        debug_assert!(range.mips.len() == 1, "UAV must refer to one mip");
+        debug_assert!(range.planes.len() == 1, "UAV must refer to one plane");
+        self.0.MipSlice = range.mips.start;
+        self.0.FirstArraySlice = range.array_slices.start;
+        self.0.ArraySize = range.array_slices.len() as u32;
+        self.0.PlaneSlice = range.planes.start;
+        self
+    }
+
+    pub fn with_subresource_range(mut self, range: &SubresourceRange) -> Self {
+        self.set_subresource_range(range);
+        self
+    }
+
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+
+    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
+        self.0.PlaneSlice = plane_slice;
+        self
+    }
+
+    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
+        self.set_plane_slice(plane_slice);
+        self
+    }
+
+    pub fn plane_slice(&self) -> u32 {
+        self.0.PlaneSlice
+    }
+}
+
+/// Wrapper around D3D12_TEX3D_UAV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Tex3DUav(pub(crate) D3D12_TEX3D_UAV);
+
+impl Tex3DUav {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_first_w_slice(&mut self, first_w_slice: u32) -> &mut Self {
+        self.0.FirstWSlice = first_w_slice;
+        self
+    }
+
+    pub fn with_first_w_slice(mut self, first_w_slice: u32) -> Self {
+        self.set_first_w_slice(first_w_slice);
+        self
+    }
+
+    pub fn first_w_slice(&self) -> u32 {
+        self.0.FirstWSlice
+    }
+
+    pub fn set_w_size(&mut self, w_size: u32) -> &mut Self {
+        self.0.WSize = w_size;
+        self
+    }
+
+    pub fn with_w_size(mut self, w_size: u32) -> Self {
+        self.set_w_size(w_size);
+        self
+    }
+
+    pub fn w_size(&self) -> u32 {
+        self.0.WSize
+    }
+}
+
+/// Wrapper around D3D12_CLEAR_VALUE structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct ClearValue(pub(crate) D3D12_CLEAR_VALUE);
+
+impl ClearValue {
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
+    }
+
+    pub fn set_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.0.__bindgen_anon_1.Color = color.into().0;
+        self
+    }
+
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    /// # Safety
+    ///
+    /// This function doesn't verify the current union variant
+    pub unsafe fn color(&self) -> [f32; 4usize] {
+        self.0.__bindgen_anon_1.Color
+    }
+
+    pub fn set_depth_stencil(
+        &mut self,
+        depth_stencil: &DepthStencilValue,
+    ) -> &mut Self {
+        self.0.__bindgen_anon_1.DepthStencil = depth_stencil.0;
+        self
+    }
+
+    pub fn with_depth_stencil(
+        mut self,
+        depth_stencil: &DepthStencilValue,
+    ) -> Self {
+        self.set_depth_stencil(depth_stencil);
+        self
+    }
+
+    /// # Safety
+    ///
+    /// This function doesn't verify the current union variant
+    pub unsafe fn depth_stencil(&self) -> DepthStencilValue {
+        DepthStencilValue(self.0.__bindgen_anon_1.DepthStencil)
+    }
+}
+
+/// Wrapper around D3D12_DEPTH_STENCIL_VALUE structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct DepthStencilValue(pub(crate) D3D12_DEPTH_STENCIL_VALUE);
+
+impl DepthStencilValue {
+    pub fn set_depth(&mut self, depth: f32) -> &mut Self {
+        self.0.Depth = depth;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.set_depth(depth);
+        self
+    }
+
+    pub fn depth(&self) -> f32 {
+        self.0.Depth
+    }
+
+    pub fn set_stencil(&mut self, stencil: u8) -> &mut Self {
+        self.0.Stencil = stencil;
+        self
+    }
+
+    pub fn with_stencil(mut self, stencil: u8) -> Self {
+        self.set_stencil(stencil);
+        self
+    }
+
+    pub fn stencil(&self) -> u8 {
+        self.0.Stencil
+    }
+}
+
+/// Wrapper around D3D12_RENDER_TARGET_VIEW_DESC structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct RenderTargetViewDesc(pub(crate) D3D12_RENDER_TARGET_VIEW_DESC);
+
+impl RenderTargetViewDesc {
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
+    }
+
+    pub fn view_dimension(&self) -> RtvDimension {
+        unsafe { std::mem::transmute(self.0.ViewDimension) }
+    }
+
+    pub fn new_texture_2d(mut self, texture_2d: Tex2DRtv) -> Self {
+        self.0.ViewDimension = RtvDimension::Texture2D as i32;
+        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
+        self
+    }
+
+    pub fn texture_2d(&self) -> Option<Tex2DRtv> {
+        unsafe {
+            match self.view_dimension() {
+                RtvDimension::Texture2D => {
+                    Some(Tex2DRtv(self.0.__bindgen_anon_1.Texture2D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_array(
+        mut self,
+        texture_2d_array: Tex2DArrayRtv,
+    ) -> Self {
+        self.0.ViewDimension = RtvDimension::Texture2DArray as i32;
+        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
+        self
+    }
+
+    pub fn texture_2d_array(&self) -> Option<Tex2DArrayRtv> {
+        unsafe {
+            match self.view_dimension() {
+                RtvDimension::Texture2DArray => {
+                    Some(Tex2DArrayRtv(self.0.__bindgen_anon_1.Texture2DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_RTV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DRtv(pub(crate) D3D12_TEX2D_RTV);
+
+impl Tex2DRtv {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
+        self.0.PlaneSlice = plane_slice;
+        self
+    }
+
+    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
+        self.set_plane_slice(plane_slice);
+        self
+    }
+
+    pub fn plane_slice(&self) -> u32 {
+        self.0.PlaneSlice
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_ARRAY_RTV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DArrayRtv(pub(crate) D3D12_TEX2D_ARRAY_RTV);
+
+impl Tex2DArrayRtv {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+
+    pub fn set_plane_slice(&mut self, plane_slice: u32) -> &mut Self {
+        self.0.PlaneSlice = plane_slice;
+        self
+    }
+
+    pub fn with_plane_slice(mut self, plane_slice: u32) -> Self {
+        self.set_plane_slice(plane_slice);
+        self
+    }
+
+    pub fn plane_slice(&self) -> u32 {
+        self.0.PlaneSlice
+    }
+}
+
+/// Wrapper around D3D12_DEPTH_STENCIL_VIEW_DESC structure
+#[derive(Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct DepthStencilViewDesc(pub(crate) D3D12_DEPTH_STENCIL_VIEW_DESC);
+
+// ToDo: encode the union variant in wrapper's type?
+impl DepthStencilViewDesc {
+    pub fn set_format(&mut self, format: Format) -> &mut Self {
+        self.0.Format = format as i32;
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
+    }
+
+    pub fn view_dimension(&self) -> DsvDimension {
+        unsafe { std::mem::transmute(self.0.ViewDimension) }
+    }
+
+    pub fn set_flags(&mut self, flags: DsvFlags) -> &mut Self {
+        self.0.Flags = flags.bits();
+        self
+    }
+
+    pub fn with_flags(mut self, flags: DsvFlags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    pub fn flags(&self) -> DsvFlags {
+        unsafe { DsvFlags::from_bits_unchecked(self.0.Flags) }
+    }
+
+    pub fn new_texture_1d(mut self, texture_1d: Tex1DDsv) -> Self {
+        self.0.ViewDimension = DsvDimension::Texture1D as i32;
+        self.0.__bindgen_anon_1.Texture1D = texture_1d.0;
+        self
+    }
+
+    pub fn texture_1d(&self) -> Option<Tex1DDsv> {
+        unsafe {
+            match self.view_dimension() {
+                DsvDimension::Texture1D => {
+                    Some(Tex1DDsv(self.0.__bindgen_anon_1.Texture1D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_1d_array(
+        mut self,
+        texture_1d_array: Tex1DArrayDsv,
+    ) -> Self {
+        self.0.ViewDimension = DsvDimension::Texture1DArray as i32;
+        self.0.__bindgen_anon_1.Texture1DArray = texture_1d_array.0;
+        self
+    }
+
+    pub fn texture_1d_array(&self) -> Option<Tex1DArrayDsv> {
+        unsafe {
+            match self.view_dimension() {
+                DsvDimension::Texture1DArray => {
+                    Some(Tex1DArrayDsv(self.0.__bindgen_anon_1.Texture1DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d(mut self, texture_2d: Tex2DDsv) -> Self {
+        self.0.ViewDimension = DsvDimension::Texture2D as i32;
+        self.0.__bindgen_anon_1.Texture2D = texture_2d.0;
+        self
+    }
+
+    pub fn texture_2d(&self) -> Option<Tex2DDsv> {
+        unsafe {
+            match self.view_dimension() {
+                DsvDimension::Texture2D => {
+                    Some(Tex2DDsv(self.0.__bindgen_anon_1.Texture2D))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_array(
+        mut self,
+        texture_2d_array: Tex2DArrayDsv,
+    ) -> Self {
+        self.0.ViewDimension = DsvDimension::Texture2DArray as i32;
+        self.0.__bindgen_anon_1.Texture2DArray = texture_2d_array.0;
+        self
+    }
+
+    pub fn texture_2d_array(&self) -> Option<Tex2DArrayDsv> {
+        unsafe {
+            match self.view_dimension() {
+                DsvDimension::Texture2DArray => {
+                    Some(Tex2DArrayDsv(self.0.__bindgen_anon_1.Texture2DArray))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_ms(mut self, texture_2d_ms: Tex2DmsDsv) -> Self {
+        self.0.ViewDimension = DsvDimension::Texture2DMs as i32;
+        self.0.__bindgen_anon_1.Texture2DMS = texture_2d_ms.0;
+        self
+    }
+
+    pub fn texture_2d_ms(&self) -> Option<Tex2DmsDsv> {
+        unsafe {
+            match self.view_dimension() {
+                DsvDimension::Texture2DMs => {
+                    Some(Tex2DmsDsv(self.0.__bindgen_anon_1.Texture2DMS))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    pub fn new_texture_2d_ms_array(
+        mut self,
+        texture_2d_ms_array: Tex2DmsArrayDsv,
+    ) -> Self {
+        self.0.ViewDimension = DsvDimension::Texture2DMsArray as i32;
+        self.0.__bindgen_anon_1.Texture2DMSArray = texture_2d_ms_array.0;
+        self
+    }
+
+    pub fn texture_2d_ms_array(&self) -> Option<Tex2DmsArrayDsv> {
+        unsafe {
+            match self.view_dimension() {
+                DsvDimension::Texture2DMsArray => Some(Tex2DmsArrayDsv(
+                    self.0.__bindgen_anon_1.Texture2DMSArray,
+                )),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Wrapper around D3D12_TEX1D_DSV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex1DDsv(pub(crate) D3D12_TEX1D_DSV);
+
+impl Tex1DDsv {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+}
+
+/// Wrapper around D3D12_TEX1D_ARRAY_DSV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex1DArrayDsv(pub(crate) D3D12_TEX1D_ARRAY_DSV);
+
+impl Tex1DArrayDsv {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_DSV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DDsv(pub(crate) D3D12_TEX2D_DSV);
+
+impl Tex2DDsv {
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+}
+
+/// Wrapper around D3D12_TEX2D_ARRAY_DSV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DArrayDsv(pub(crate) D3D12_TEX2D_ARRAY_DSV);
+
+impl Tex2DArrayDsv {
+    /// Sets the mip and the array slices of the view, the mip range has to
+    /// consist of a single element. DSVs always cover both depth and stencil
+    /// planes, so the plane range is ignored
+    pub fn set_subresource_range(
+        &mut self,
+        range: &SubresourceRange,
+    ) -> &mut Self {
+        debug_assert!(range.mips.len() == 1, "DSV must refer to one mip");
+        self.0.MipSlice = range.mips.start;
+        self.0.FirstArraySlice = range.array_slices.start;
+        self.0.ArraySize = range.array_slices.len() as u32;
+        self
+    }
+
+    pub fn with_subresource_range(mut self, range: &SubresourceRange) -> Self {
+        self.set_subresource_range(range);
+        self
+    }
+
+    pub fn set_mip_slice(&mut self, mip_slice: u32) -> &mut Self {
+        self.0.MipSlice = mip_slice;
+        self
+    }
+
+    pub fn with_mip_slice(mut self, mip_slice: u32) -> Self {
+        self.set_mip_slice(mip_slice);
+        self
+    }
+
+    pub fn mip_slice(&self) -> u32 {
+        self.0.MipSlice
+    }
+
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+}
+
+/// Wrapper around D3D12_TEX2DMS_DSV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DmsDsv(pub(crate) D3D12_TEX2DMS_DSV);
+
+/// Wrapper around D3D12_TEX2DMS_ARRAY_DSV structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Tex2DmsArrayDsv(pub(crate) D3D12_TEX2DMS_ARRAY_DSV);
+
+impl Tex2DmsArrayDsv {
+    pub fn set_first_array_slice(
+        &mut self,
+        first_array_slice: u32,
+    ) -> &mut Self {
+        self.0.FirstArraySlice = first_array_slice;
+        self
+    }
+
+    pub fn with_first_array_slice(mut self, first_array_slice: u32) -> Self {
+        self.set_first_array_slice(first_array_slice);
+        self
+    }
+
+    pub fn first_array_slice(&self) -> u32 {
+        self.0.FirstArraySlice
+    }
+
+    pub fn set_array_size(&mut self, array_size: u32) -> &mut Self {
+        self.0.ArraySize = array_size;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.set_array_size(array_size);
+        self
+    }
+
+    pub fn array_size(&self) -> u32 {
+        self.0.ArraySize
+    }
+}
+
+// ToDo: more ::new() constructors for one-field structs?
+/// Wrapper around D3D12_FEATURE_DATA_SHADER_MODEL structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct FeatureDataShaderModel(pub(crate) D3D12_FEATURE_DATA_SHADER_MODEL);
+
+impl FeatureDataShaderModel {
+    pub fn new(highest_shader_model: ShaderModel) -> Self {
+        Self(D3D12_FEATURE_DATA_SHADER_MODEL {
+            HighestShaderModel: highest_shader_model as i32,
+        })
+    }
+
+    pub fn highest_shader_model(&self) -> ShaderModel {
+        unsafe { std::mem::transmute(self.0.HighestShaderModel) }
+    }
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_COMMAND_QUEUE_PRIORITY structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct FeatureDataCommandQueuePriority(
+    pub(crate) D3D12_FEATURE_DATA_COMMAND_QUEUE_PRIORITY,
+);
+
+impl FeatureDataCommandQueuePriority {
+    pub fn new(
+        command_list_type: CommandListType,
+        priority: CommandQueuePriority,
+    ) -> Self {
+        Self(D3D12_FEATURE_DATA_COMMAND_QUEUE_PRIORITY {
+            CommandListType: command_list_type as i32,
+            Priority: priority as u32,
+            PriorityForTypeIsSupported: 0,
+        })
+    }
+
+    pub fn command_list_type(&self) -> CommandListType {
+        unsafe { std::mem::transmute(self.0.CommandListType) }
+    }
+
+    pub fn priority(&self) -> CommandQueuePriority {
+        unsafe { std::mem::transmute(self.0.Priority as i32) }
+    }
+
+    pub fn priority_for_type_is_supported(&self) -> bool {
+        self.0.PriorityForTypeIsSupported != 0
+    }
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_MULTISAMPLE_QUALITY_LEVELS structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct FeatureDataMultisampleQualityLevels(
+    pub(crate) D3D12_FEATURE_DATA_MULTISAMPLE_QUALITY_LEVELS,
+);
+
+impl FeatureDataMultisampleQualityLevels {
+    pub fn new(
+        format: Format,
+        sample_count: u32,
+        flags: MultisampleQualityLevelFlags,
+    ) -> Self {
+        Self(D3D12_FEATURE_DATA_MULTISAMPLE_QUALITY_LEVELS {
+            Format: format as i32,
+            SampleCount: sample_count,
+            Flags: flags.bits(),
+            NumQualityLevels: 0,
+        })
+    }
+
+    pub fn format(&self) -> Format {
+        unsafe { std::mem::transmute(self.0.Format) }
+    }
+
+    pub fn sample_count(&self) -> u32 {
+        self.0.SampleCount
+    }
+
+    pub fn flags(&self) -> MultisampleQualityLevelFlags {
+        unsafe {
+            MultisampleQualityLevelFlags::from_bits_unchecked(self.0.Flags)
+        }
+    }
+
+    /// Zero means the sample count is not supported for the format
+    pub fn num_quality_levels(&self) -> u32 {
+        self.0.NumQualityLevels
+    }
+}
+
+// ToDo: Default derives in the structs where they don't make sense
+// should be cleaned up (in favor of Builder pattern?)
+/// Wrapper around D3D12_PIPELINE_STATE_STREAM_DESC structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
+#[repr(transparent)]
+pub struct PipelineStateStreamDesc<'a>(
+    pub D3D12_PIPELINE_STATE_STREAM_DESC,
+    PhantomData<&'a [u8]>,
+);
+
+impl<'a> PipelineStateStreamDesc<'a> {
+    pub fn size_in_bytes(&self) -> ByteCount {
+        ByteCount::from(self.0.SizeInBytes)
+    }
+
+    pub fn set_pipeline_state_subobject_stream(
+        &mut self,
+        subobject_stream: &'a [u8],
+    ) -> &mut Self {
+        self.0.SizeInBytes = subobject_stream.len() as u64;
+        self.0.pPipelineStateSubobjectStream =
+            subobject_stream.as_ptr() as *mut std::ffi::c_void;
+        self.1 = PhantomData;
+
+        self
+    }
+
+    pub fn with_pipeline_state_subobject_stream(
+        mut self,
+        subobject_stream: &'a [u8],
+    ) -> Self {
+        self.set_pipeline_state_subobject_stream(subobject_stream);
+        self
+    }
+
+    pub fn pipeline_state_subobject_stream(&self) -> &'a [u8] {
+        unsafe {
+            slice::from_raw_parts(
+                self.0.pPipelineStateSubobjectStream as *const u8,
+                self.0.SizeInBytes as usize,
+            )
+        }
+    }
+}
+
+/// An element of a pipeline subobject stream (element type + subobject itself)
+#[derive(Default, Debug)]
+#[repr(C, align(8))]
+pub struct PipelineStateSubobject<T> {
+    ty: PipelineStateSubobjectType,
+    subobject: T,
+}
+
+impl<T> PipelineStateSubobject<T> {
+    pub fn new(ty: PipelineStateSubobjectType, subobject: T) -> Self {
+        let mut subobject_wrapper: PipelineStateSubobject<T> =
+            unsafe { std::mem::zeroed() };
+        subobject_wrapper.ty = ty;
+        subobject_wrapper.subobject = subobject;
+        subobject_wrapper
+    }
+}
+
+// ToDo: a similar adapter for GraphicsPipelineState? In d3dx12.h
+// they have one, and also one more for compute PSO's
+// ToDo: do we realistically need getters here?
+/// Mesh shader pipeline description struct (a convenience struct that does not have C counterpart)
+#[repr(C)]
+#[derive(Debug)]
+pub struct MeshShaderPipelineStateDesc<'rs, 'sh> {
+    // We don't use wrapper types here since i) these members are private
+    // and don't leak into the public API, and ii) if we want to implement
+    // Default trait, we need to either wrap our objects like ShaderBytecode
+    // into Options or store raw pointers
+    // Fun fact: it turns out Option's are FFI-safe, but anyway, see i)
+    root_signature: PipelineStateSubobject<*mut ID3D12RootSignature>,
+    amplification_shader: PipelineStateSubobject<D3D12_SHADER_BYTECODE>,
+    mesh_shader: PipelineStateSubobject<D3D12_SHADER_BYTECODE>,
+    pixel_shader: PipelineStateSubobject<D3D12_SHADER_BYTECODE>,
+    blend_state: PipelineStateSubobject<D3D12_BLEND_DESC>,
+    sample_mask: PipelineStateSubobject<UINT>,
+    rasterizer_state: PipelineStateSubobject<D3D12_RASTERIZER_DESC>,
+    depth_stencil_state: PipelineStateSubobject<D3D12_DEPTH_STENCIL_DESC>,
+    primitive_topology_type:
+        PipelineStateSubobject<D3D12_PRIMITIVE_TOPOLOGY_TYPE>,
+    rtv_formats: PipelineStateSubobject<D3D12_RT_FORMAT_ARRAY>,
+    dsv_format: PipelineStateSubobject<DXGI_FORMAT>,
+    sample_desc: PipelineStateSubobject<DXGI_SAMPLE_DESC>,
+    node_mask: PipelineStateSubobject<UINT>,
+    cached_pso: PipelineStateSubobject<D3D12_CACHED_PIPELINE_STATE>,
+    flags: PipelineStateSubobject<i32>,
+    // ToDo: probably we need lifetimes on *mut IDXGI... wrappers, too?..
+    rs_phantom_data: PhantomData<&'rs RootSignature>,
+    sh_phantom_data: PhantomData<ShaderBytecode<'sh>>,
+}
+
+impl<'rs, 'sh> Default for MeshShaderPipelineStateDesc<'rs, 'sh> {
+    fn default() -> Self {
+        let mut pso_desc: MeshShaderPipelineStateDesc =
+            unsafe { std::mem::zeroed() };
+        pso_desc.root_signature = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::RootSignature,
+            std::ptr::null_mut(),
+        );
+        pso_desc.amplification_shader = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::AS,
+            D3D12_SHADER_BYTECODE::default(),
+        );
+        pso_desc.mesh_shader = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::MS,
+            D3D12_SHADER_BYTECODE::default(),
+        );
+        pso_desc.pixel_shader = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::PS,
+            D3D12_SHADER_BYTECODE::default(),
+        );
+        pso_desc.blend_state = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::Blend,
+            BlendDesc::default().0,
+        );
+        pso_desc.sample_mask = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::SampleMask,
+            u32::MAX,
+        );
+        pso_desc.rasterizer_state = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::Rasterizer,
+            RasterizerDesc::default().0,
+        );
+        pso_desc.depth_stencil_state = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::DepthStencil,
+            DepthStencilDesc::default().0,
+        );
+        pso_desc.primitive_topology_type = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::PrimitiveTopology,
+            PrimitiveTopologyType::Triangle as i32,
+        );
+        pso_desc.rtv_formats = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::RenderTargetFormats,
+            RtFormatArray::default().0,
+        );
+        pso_desc.dsv_format = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::DepthStencilFormat,
+            Format::Unknown as i32,
+        );
+        pso_desc.sample_desc = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::SampleDesc,
+            SampleDesc::default().0,
+        );
+        pso_desc.node_mask = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::NodeMask,
+            0,
+        );
+        pso_desc.cached_pso = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::CachedPso,
+            CachedPipelineState::default().0,
         );
-        pso_desc.depth_stencil_state = PipelineStateSubobject::new(
+        pso_desc.flags = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::Flags,
+            PipelineStateFlags::None.bits(),
+        );
+        pso_desc.rs_phantom_data = PhantomData;
+        pso_desc.sh_phantom_data = PhantomData;
+        pso_desc
+    }
+}
+
+impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
+    pub fn set_root_signature(
+        &mut self,
+        root_signature: &'rs RootSignature,
+    ) -> &mut Self {
+        self.root_signature = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::RootSignature,
+            root_signature.this,
+        );
+        self.rs_phantom_data = PhantomData;
+        self
+    }
+
+    pub fn with_root_signature(
+        mut self,
+        root_signature: &'rs RootSignature,
+    ) -> Self {
+        self.set_root_signature(root_signature);
+        self
+    }
+
+    // ToDo: get rid of lifetimes on COM objects??
+    pub fn root_signature(&self) -> RootSignature {
+        let root_signature = RootSignature {
+            this: self.root_signature.subobject,
+        };
+        root_signature.add_ref();
+        root_signature
+    }
+
+    pub fn set_as_bytecode(
+        &mut self,
+        bytecode: &'sh ShaderBytecode,
+    ) -> &mut Self {
+        self.amplification_shader = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::AS,
+            bytecode.0,
+        );
+        self.sh_phantom_data = PhantomData;
+        self
+    }
+
+    pub fn with_as_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_as_bytecode(bytecode);
+        self
+    }
+
+    pub fn as_bytecode(&self) -> ShaderBytecode<'sh> {
+        ShaderBytecode(
+            D3D12_SHADER_BYTECODE {
+                pShaderBytecode: self
+                    .amplification_shader
+                    .subobject
+                    .pShaderBytecode,
+                BytecodeLength: self
+                    .amplification_shader
+                    .subobject
+                    .BytecodeLength,
+            },
+            PhantomData,
+        )
+    }
+
+    pub fn set_ms_bytecode(
+        &mut self,
+        bytecode: &'sh ShaderBytecode,
+    ) -> &mut Self {
+        self.mesh_shader = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::MS,
+            bytecode.0,
+        );
+        self.sh_phantom_data = PhantomData;
+        self
+    }
+
+    pub fn with_ms_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_ms_bytecode(bytecode);
+        self
+    }
+
+    pub fn ms_bytecode(&self) -> ShaderBytecode<'sh> {
+        ShaderBytecode(
+            D3D12_SHADER_BYTECODE {
+                pShaderBytecode: self.mesh_shader.subobject.pShaderBytecode,
+                BytecodeLength: self.mesh_shader.subobject.BytecodeLength,
+            },
+            PhantomData,
+        )
+    }
+
+    pub fn set_ps_bytecode(
+        &mut self,
+        bytecode: &'sh ShaderBytecode,
+    ) -> &mut Self {
+        self.pixel_shader = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::PS,
+            bytecode.0,
+        );
+
+        self.sh_phantom_data = PhantomData;
+        self
+    }
+
+    pub fn with_ps_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
+        self.set_ps_bytecode(bytecode);
+        self
+    }
+
+    pub fn ps_bytecode(&self) -> ShaderBytecode<'sh> {
+        ShaderBytecode(
+            D3D12_SHADER_BYTECODE {
+                pShaderBytecode: self.pixel_shader.subobject.pShaderBytecode,
+                BytecodeLength: self.pixel_shader.subobject.BytecodeLength,
+            },
+            PhantomData,
+        )
+    }
+
+    pub fn set_blend_state(&mut self, blend_state: BlendDesc) -> &mut Self {
+        self.blend_state = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::Blend,
+            blend_state.0,
+        );
+        self
+    }
+
+    pub fn with_blend_state(mut self, blend_state: BlendDesc) -> Self {
+        self.set_blend_state(blend_state);
+        self
+    }
+
+    pub fn blend_state(&self) -> BlendDesc {
+        BlendDesc(self.blend_state.subobject)
+    }
+
+    pub fn set_rasterizer_state(
+        &mut self,
+        rasterizer_state: RasterizerDesc,
+    ) -> &mut Self {
+        self.rasterizer_state = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::Rasterizer,
+            rasterizer_state.0,
+        );
+        self
+    }
+
+    pub fn with_rasterizer_state(
+        mut self,
+        rasterizer_state: RasterizerDesc,
+    ) -> Self {
+        self.set_rasterizer_state(rasterizer_state);
+        self
+    }
+
+    // ToDo: return reference in such cases??
+    pub fn rasterizer_state(&self) -> RasterizerDesc {
+        RasterizerDesc(self.rasterizer_state.subobject)
+    }
+
+    pub fn set_depth_stencil_state(
+        &mut self,
+        depth_stencil_state: DepthStencilDesc,
+    ) -> &mut Self {
+        self.depth_stencil_state = PipelineStateSubobject::new(
             PipelineStateSubobjectType::DepthStencil,
-            DepthStencilDesc::default().0,
+            depth_stencil_state.0,
         );
-        pso_desc.primitive_topology_type = PipelineStateSubobject::new(
+        self
+    }
+
+    pub fn with_depth_stencil_state(
+        mut self,
+        depth_stencil_state: DepthStencilDesc,
+    ) -> Self {
+        self.set_depth_stencil_state(depth_stencil_state);
+        self
+    }
+
+    pub fn depth_stencil_state(&self) -> DepthStencilDesc {
+        DepthStencilDesc(self.depth_stencil_state.subobject)
+    }
+
+    pub fn set_primitive_topology_type(
+        &mut self,
+        primitive_topology_type: PrimitiveTopologyType,
+    ) -> &mut Self {
+        self.primitive_topology_type = PipelineStateSubobject::new(
             PipelineStateSubobjectType::PrimitiveTopology,
-            PrimitiveTopologyType::Triangle as i32,
+            primitive_topology_type as i32,
         );
-        pso_desc.rtv_formats = PipelineStateSubobject::new(
+        self
+    }
+
+    pub fn with_primitive_topology_type(
+        mut self,
+        primitive_topology_type: PrimitiveTopologyType,
+    ) -> Self {
+        self.set_primitive_topology_type(primitive_topology_type);
+        self
+    }
+
+    pub fn primitive_topology_type(&self) -> PrimitiveTopologyType {
+        unsafe { std::mem::transmute(self.primitive_topology_type.subobject) }
+    }
+
+    pub fn set_rtv_formats(&mut self, rtv_formats: &[Format]) -> &mut Self {
+        let rt_format_struct =
+            RtFormatArray::default().with_rt_formats(rtv_formats);
+        self.rtv_formats = PipelineStateSubobject::new(
             PipelineStateSubobjectType::RenderTargetFormats,
-            RtFormatArray::default().0,
+            rt_format_struct.0,
         );
-        pso_desc.dsv_format = PipelineStateSubobject::new(
+        self
+    }
+
+    pub fn with_rtv_formats(mut self, rtv_formats: &[Format]) -> Self {
+        self.set_rtv_formats(rtv_formats);
+        self
+    }
+
+    pub fn rtv_formats(&self) -> &[Format] {
+        unsafe {
+            slice::from_raw_parts(
+                self.rtv_formats.subobject.RTFormats.as_ptr() as *const Format,
+                self.rtv_formats.subobject.NumRenderTargets as usize,
+            )
+        }
+    }
+
+    pub fn set_dsv_format(&mut self, dsv_format: Format) -> &mut Self {
+        self.dsv_format = PipelineStateSubobject::new(
             PipelineStateSubobjectType::DepthStencilFormat,
-            Format::Unknown as i32,
+            dsv_format as i32,
+        );
+        self
+    }
+
+    pub fn with_dsv_format(mut self, dsv_format: Format) -> Self {
+        self.set_dsv_format(dsv_format);
+        self
+    }
+
+    pub fn dsv_format(&self) -> Format {
+        unsafe { std::mem::transmute(self.dsv_format.subobject) }
+    }
+
+    pub fn set_sample_mask(&mut self, sample_mask: u32) -> &mut Self {
+        self.sample_mask = PipelineStateSubobject::new(
+            PipelineStateSubobjectType::SampleMask,
+            sample_mask,
         );
-        pso_desc.sample_desc = PipelineStateSubobject::new(
+        self
+    }
+
+    pub fn with_sample_mask(mut self, sample_mask: u32) -> Self {
+        self.set_sample_mask(sample_mask);
+        self
+    }
+
+    pub fn sample_mask(&self) -> u32 {
+        self.sample_mask.subobject
+    }
+
+    pub fn set_sample_desc(&mut self, sample_desc: SampleDesc) -> &mut Self {
+        self.sample_desc = PipelineStateSubobject::new(
             PipelineStateSubobjectType::SampleDesc,
-            SampleDesc::default().0,
+            sample_desc.0,
         );
-        pso_desc.node_mask = PipelineStateSubobject::new(
+        self
+    }
+
+    pub fn with_sample_desc(mut self, sample_desc: SampleDesc) -> Self {
+        self.set_sample_desc(sample_desc);
+        self
+    }
+
+    pub fn sample_desc(&self) -> SampleDesc {
+        SampleDesc(self.sample_desc.subobject)
+    }
+
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.node_mask = PipelineStateSubobject::new(
             PipelineStateSubobjectType::NodeMask,
-            0,
-        );
-        pso_desc.cached_pso = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::CachedPso,
-            CachedPipelineState::default().0,
+            node_mask,
         );
-        pso_desc.flags = PipelineStateSubobject::new(
+        self
+    }
+
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
+        self
+    }
+
+    pub fn node_mask(&self) -> u32 {
+        self.node_mask.subobject
+    }
+
+    pub fn set_flags(
+        &mut self,
+        pipeline_state_flags: PipelineStateFlags,
+    ) -> &mut Self {
+        self.flags = PipelineStateSubobject::new(
             PipelineStateSubobjectType::Flags,
-            PipelineStateFlags::None.bits(),
+            pipeline_state_flags.bits(),
         );
-        pso_desc.rs_phantom_data = PhantomData;
-        pso_desc.sh_phantom_data = PhantomData;
-        pso_desc
+        self
+    }
+
+    pub fn with_flags(
+        mut self,
+        pipeline_state_flags: PipelineStateFlags,
+    ) -> Self {
+        self.set_flags(pipeline_state_flags);
+        self
+    }
+
+    pub fn flags(&self) -> PipelineStateFlags {
+        unsafe { PipelineStateFlags::from_bits_unchecked(self.flags.subobject) }
+    }
+
+    pub fn as_byte_stream(&self) -> &[u8] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self as *const Self as *const u8,
+                std::mem::size_of::<Self>(),
+            )
+        }
     }
 }
 
-impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
+/// A pipeline state subobject stream which is assembled from typed
+/// subobjects (a convenience struct that does not have C counterpart).
+/// Unlike [MeshShaderPipelineStateDesc], only the subobjects that have been
+/// set explicitly end up in the stream, so it can describe any kind of PSO.
+/// Each subobject type should be set at most once
+#[derive(Default, Debug, Clone)]
+pub struct PipelineStateStream<'a> {
+    // u64 storage keeps the subobjects pointer-aligned
+    data: Vec<u64>,
+    phantom_data: PhantomData<&'a [u8]>,
+}
+
+impl<'a> PipelineStateStream<'a> {
+    fn push_subobject<T>(
+        &mut self,
+        ty: PipelineStateSubobjectType,
+        subobject: T,
+    ) -> &mut Self {
+        let offset = self.data.len();
+        self.data.resize(
+            offset + size_of::<PipelineStateSubobject<T>>() / size_of::<u64>(),
+            0,
+        );
+        unsafe {
+            std::ptr::write(
+                self.data.as_mut_ptr().add(offset)
+                    as *mut PipelineStateSubobject<T>,
+                PipelineStateSubobject::new(ty, subobject),
+            );
+        }
+        self
+    }
+
     pub fn set_root_signature(
         &mut self,
-        root_signature: &'rs RootSignature,
+        root_signature: &'a RootSignature,
     ) -> &mut Self {
-        self.root_signature = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::RootSignature,
             root_signature.this,
-        );
-        self.rs_phantom_data = PhantomData;
-        self
+        )
     }
 
     pub fn with_root_signature(
         mut self,
-        root_signature: &'rs RootSignature,
+        root_signature: &'a RootSignature,
     ) -> Self {
         self.set_root_signature(root_signature);
         self
     }
 
-    // ToDo: get rid of lifetimes on COM objects??
-    pub fn root_signature(&self) -> RootSignature {
-        let root_signature = RootSignature {
-            this: self.root_signature.subobject,
-        };
-        root_signature.add_ref();
-        root_signature
+    pub fn set_vs_bytecode(
+        &mut self,
+        bytecode: &ShaderBytecode<'a>,
+    ) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::VS, bytecode.0)
     }
 
-    pub fn set_as_bytecode(
+    pub fn with_vs_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_vs_bytecode(bytecode);
+        self
+    }
+
+    pub fn set_ps_bytecode(
         &mut self,
-        bytecode: &'sh ShaderBytecode,
+        bytecode: &ShaderBytecode<'a>,
     ) -> &mut Self {
-        self.amplification_shader = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::AS,
-            bytecode.0,
-        );
-        self.sh_phantom_data = PhantomData;
-        self
+        self.push_subobject(PipelineStateSubobjectType::PS, bytecode.0)
     }
 
-    pub fn with_as_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_as_bytecode(bytecode);
+    pub fn with_ps_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_ps_bytecode(bytecode);
         self
     }
 
-    pub fn as_bytecode(&self) -> ShaderBytecode<'sh> {
-        ShaderBytecode(
-            D3D12_SHADER_BYTECODE {
-                pShaderBytecode: self
-                    .amplification_shader
-                    .subobject
-                    .pShaderBytecode,
-                BytecodeLength: self
-                    .amplification_shader
-                    .subobject
-                    .BytecodeLength,
-            },
-            PhantomData,
-        )
+    pub fn set_ds_bytecode(
+        &mut self,
+        bytecode: &ShaderBytecode<'a>,
+    ) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::DS, bytecode.0)
     }
 
-    pub fn set_ms_bytecode(
+    pub fn with_ds_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_ds_bytecode(bytecode);
+        self
+    }
+
+    pub fn set_hs_bytecode(
         &mut self,
-        bytecode: &'sh ShaderBytecode,
+        bytecode: &ShaderBytecode<'a>,
     ) -> &mut Self {
-        self.mesh_shader = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::MS,
-            bytecode.0,
-        );
-        self.sh_phantom_data = PhantomData;
+        self.push_subobject(PipelineStateSubobjectType::HS, bytecode.0)
+    }
+
+    pub fn with_hs_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_hs_bytecode(bytecode);
         self
     }
 
-    pub fn with_ms_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_ms_bytecode(bytecode);
+    pub fn set_gs_bytecode(
+        &mut self,
+        bytecode: &ShaderBytecode<'a>,
+    ) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::GS, bytecode.0)
+    }
+
+    pub fn with_gs_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_gs_bytecode(bytecode);
         self
     }
 
-    pub fn ms_bytecode(&self) -> ShaderBytecode<'sh> {
-        ShaderBytecode(
-            D3D12_SHADER_BYTECODE {
-                pShaderBytecode: self.mesh_shader.subobject.pShaderBytecode,
-                BytecodeLength: self.mesh_shader.subobject.BytecodeLength,
-            },
-            PhantomData,
-        )
+    pub fn set_cs_bytecode(
+        &mut self,
+        bytecode: &ShaderBytecode<'a>,
+    ) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::CS, bytecode.0)
     }
 
-    pub fn set_ps_bytecode(
+    pub fn with_cs_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_cs_bytecode(bytecode);
+        self
+    }
+
+    pub fn set_as_bytecode(
         &mut self,
-        bytecode: &'sh ShaderBytecode,
+        bytecode: &ShaderBytecode<'a>,
     ) -> &mut Self {
-        self.pixel_shader = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::PS,
-            bytecode.0,
-        );
+        self.push_subobject(PipelineStateSubobjectType::AS, bytecode.0)
+    }
 
-        self.sh_phantom_data = PhantomData;
+    pub fn with_as_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_as_bytecode(bytecode);
         self
     }
 
-    pub fn with_ps_bytecode(mut self, bytecode: &'sh ShaderBytecode) -> Self {
-        self.set_ps_bytecode(bytecode);
+    pub fn set_ms_bytecode(
+        &mut self,
+        bytecode: &ShaderBytecode<'a>,
+    ) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::MS, bytecode.0)
+    }
+
+    pub fn with_ms_bytecode(mut self, bytecode: &ShaderBytecode<'a>) -> Self {
+        self.set_ms_bytecode(bytecode);
         self
     }
 
-    pub fn ps_bytecode(&self) -> ShaderBytecode<'sh> {
-        ShaderBytecode(
-            D3D12_SHADER_BYTECODE {
-                pShaderBytecode: self.pixel_shader.subobject.pShaderBytecode,
-                BytecodeLength: self.pixel_shader.subobject.BytecodeLength,
-            },
-            PhantomData,
+    pub fn set_stream_output(
+        &mut self,
+        stream_output: &StreamOutputDesc<'a>,
+    ) -> &mut Self {
+        self.push_subobject(
+            PipelineStateSubobjectType::StreamOutput,
+            stream_output.0,
         )
     }
 
-    pub fn set_blend_state(&mut self, blend_state: BlendDesc) -> &mut Self {
-        self.blend_state = PipelineStateSubobject::new(
-            PipelineStateSubobjectType::Blend,
-            blend_state.0,
-        );
+    pub fn with_stream_output(
+        mut self,
+        stream_output: &StreamOutputDesc<'a>,
+    ) -> Self {
+        self.set_stream_output(stream_output);
         self
     }
 
+    pub fn set_blend_state(&mut self, blend_state: BlendDesc) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::Blend, blend_state.0)
+    }
+
     pub fn with_blend_state(mut self, blend_state: BlendDesc) -> Self {
         self.set_blend_state(blend_state);
         self
     }
 
-    pub fn blend_state(&self) -> BlendDesc {
-        BlendDesc(self.blend_state.subobject)
+    pub fn set_sample_mask(&mut self, sample_mask: u32) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::SampleMask, sample_mask)
+    }
+
+    pub fn with_sample_mask(mut self, sample_mask: u32) -> Self {
+        self.set_sample_mask(sample_mask);
+        self
     }
 
     pub fn set_rasterizer_state(
         &mut self,
         rasterizer_state: RasterizerDesc,
     ) -> &mut Self {
-        self.rasterizer_state = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::Rasterizer,
             rasterizer_state.0,
-        );
-        self
+        )
     }
 
     pub fn with_rasterizer_state(
@@ -6747,20 +9905,14 @@ impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
         self
     }
 
-    // ToDo: return reference in such cases??
-    pub fn rasterizer_state(&self) -> RasterizerDesc {
-        RasterizerDesc(self.rasterizer_state.subobject)
-    }
-
     pub fn set_depth_stencil_state(
         &mut self,
         depth_stencil_state: DepthStencilDesc,
     ) -> &mut Self {
-        self.depth_stencil_state = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::DepthStencil,
             depth_stencil_state.0,
-        );
-        self
+        )
     }
 
     pub fn with_depth_stencil_state(
@@ -6771,19 +9923,32 @@ impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
         self
     }
 
-    pub fn depth_stencil_state(&self) -> DepthStencilDesc {
-        DepthStencilDesc(self.depth_stencil_state.subobject)
+    pub fn set_input_layout(
+        &mut self,
+        input_layout: &InputLayoutDesc<'a>,
+    ) -> &mut Self {
+        self.push_subobject(
+            PipelineStateSubobjectType::InputLayout,
+            input_layout.0,
+        )
+    }
+
+    pub fn with_input_layout(
+        mut self,
+        input_layout: &InputLayoutDesc<'a>,
+    ) -> Self {
+        self.set_input_layout(input_layout);
+        self
     }
 
     pub fn set_primitive_topology_type(
         &mut self,
         primitive_topology_type: PrimitiveTopologyType,
     ) -> &mut Self {
-        self.primitive_topology_type = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::PrimitiveTopology,
             primitive_topology_type as i32,
-        );
-        self
+        )
     }
 
     pub fn with_primitive_topology_type(
@@ -6794,18 +9959,11 @@ impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
         self
     }
 
-    pub fn primitive_topology_type(&self) -> PrimitiveTopologyType {
-        unsafe { std::mem::transmute(self.primitive_topology_type.subobject) }
-    }
-
     pub fn set_rtv_formats(&mut self, rtv_formats: &[Format]) -> &mut Self {
-        let rt_format_struct =
-            RtFormatArray::default().with_rt_formats(rtv_formats);
-        self.rtv_formats = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::RenderTargetFormats,
-            rt_format_struct.0,
-        );
-        self
+            RtFormatArray::default().with_rt_formats(rtv_formats).0,
+        )
     }
 
     pub fn with_rtv_formats(mut self, rtv_formats: &[Format]) -> Self {
@@ -6813,21 +9971,11 @@ impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
         self
     }
 
-    pub fn rtv_formats(&self) -> &[Format] {
-        unsafe {
-            slice::from_raw_parts(
-                self.rtv_formats.subobject.RTFormats.as_ptr() as *const Format,
-                self.rtv_formats.subobject.NumRenderTargets as usize,
-            )
-        }
-    }
-
     pub fn set_dsv_format(&mut self, dsv_format: Format) -> &mut Self {
-        self.dsv_format = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::DepthStencilFormat,
             dsv_format as i32,
-        );
-        self
+        )
     }
 
     pub fn with_dsv_format(mut self, dsv_format: Format) -> Self {
@@ -6835,15 +9983,50 @@ impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
         self
     }
 
+    pub fn set_sample_desc(&mut self, sample_desc: SampleDesc) -> &mut Self {
+        self.push_subobject(
+            PipelineStateSubobjectType::SampleDesc,
+            sample_desc.0,
+        )
+    }
+
+    pub fn with_sample_desc(mut self, sample_desc: SampleDesc) -> Self {
+        self.set_sample_desc(sample_desc);
+        self
+    }
+
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::NodeMask, node_mask)
+    }
+
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
+        self
+    }
+
+    pub fn set_cached_pso(
+        &mut self,
+        cached_pso: &CachedPipelineState<'a>,
+    ) -> &mut Self {
+        self.push_subobject(PipelineStateSubobjectType::CachedPso, cached_pso.0)
+    }
+
+    pub fn with_cached_pso(
+        mut self,
+        cached_pso: &CachedPipelineState<'a>,
+    ) -> Self {
+        self.set_cached_pso(cached_pso);
+        self
+    }
+
     pub fn set_flags(
         &mut self,
         pipeline_state_flags: PipelineStateFlags,
     ) -> &mut Self {
-        self.flags = PipelineStateSubobject::new(
+        self.push_subobject(
             PipelineStateSubobjectType::Flags,
             pipeline_state_flags.bits(),
-        );
-        self
+        )
     }
 
     pub fn with_flags(
@@ -6854,15 +10037,11 @@ impl<'rs, 'sh> MeshShaderPipelineStateDesc<'rs, 'sh> {
         self
     }
 
-    pub fn flags(&self) -> PipelineStateFlags {
-        unsafe { PipelineStateFlags::from_bits_unchecked(self.flags.subobject) }
-    }
-
     pub fn as_byte_stream(&self) -> &[u8] {
         unsafe {
             std::slice::from_raw_parts(
-                self as *const Self as *const u8,
-                std::mem::size_of::<Self>(),
+                self.data.as_ptr() as *const u8,
+                self.data.len() * size_of::<u64>(),
             )
         }
     }
@@ -6956,6 +10135,108 @@ impl QueryHeapDesc {
     }
 }
 
+/// Wrapper around D3D12_PROTECTED_RESOURCE_SESSION_DESC structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Debug, Copy, Clone)]
+pub struct ProtectedResourceSessionDesc(
+    pub(crate) D3D12_PROTECTED_RESOURCE_SESSION_DESC,
+);
+
+impl Default for ProtectedResourceSessionDesc {
+    fn default() -> Self {
+        Self(D3D12_PROTECTED_RESOURCE_SESSION_DESC {
+            NodeMask: 0,
+            Flags: ProtectedResourceSessionFlags::None.bits(),
+        })
+    }
+}
+
+impl ProtectedResourceSessionDesc {
+    pub fn set_node_mask(&mut self, node_mask: u32) -> &mut Self {
+        self.0.NodeMask = node_mask;
+        self
+    }
+
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.set_node_mask(node_mask);
+        self
+    }
+
+    pub fn node_mask(&self) -> u32 {
+        self.0.NodeMask
+    }
+
+    pub fn set_flags(
+        &mut self,
+        flags: ProtectedResourceSessionFlags,
+    ) -> &mut Self {
+        self.0.Flags = flags.bits();
+        self
+    }
+
+    pub fn with_flags(mut self, flags: ProtectedResourceSessionFlags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    pub fn flags(&self) -> ProtectedResourceSessionFlags {
+        unsafe {
+            ProtectedResourceSessionFlags::from_bits_unchecked(self.0.Flags)
+        }
+    }
+}
+
+/// Wrapper around D3D12_QUERY_DATA_PIPELINE_STATISTICS structure
+#[repr(transparent)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+pub struct PipelineStatistics(pub(crate) D3D12_QUERY_DATA_PIPELINE_STATISTICS);
+
+impl PipelineStatistics {
+    pub fn ia_vertices(&self) -> u64 {
+        self.0.IAVertices
+    }
+
+    pub fn ia_primitives(&self) -> u64 {
+        self.0.IAPrimitives
+    }
+
+    pub fn vs_invocations(&self) -> u64 {
+        self.0.VSInvocations
+    }
+
+    pub fn gs_invocations(&self) -> u64 {
+        self.0.GSInvocations
+    }
+
+    pub fn gs_primitives(&self) -> u64 {
+        self.0.GSPrimitives
+    }
+
+    pub fn c_invocations(&self) -> u64 {
+        self.0.CInvocations
+    }
+
+    pub fn c_primitives(&self) -> u64 {
+        self.0.CPrimitives
+    }
+
+    pub fn ps_invocations(&self) -> u64 {
+        self.0.PSInvocations
+    }
+
+    pub fn hs_invocations(&self) -> u64 {
+        self.0.HSInvocations
+    }
+
+    pub fn ds_invocations(&self) -> u64 {
+        self.0.DSInvocations
+    }
+
+    pub fn cs_invocations(&self) -> u64 {
+        self.0.CSInvocations
+    }
+}
+
 /// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS structure
 #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
 #[repr(transparent)]
@@ -7283,6 +10564,141 @@ impl FeatureDataOptions {
     }
 }
 
+/// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS1 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct FeatureDataOptions1(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS1);
+
+impl FeatureDataOptions1 {
+    pub fn wave_ops(&self) -> bool {
+        self.0.WaveOps != 0
+    }
+
+    pub fn wave_lane_count_min(&self) -> u32 {
+        self.0.WaveLaneCountMin
+    }
+
+    pub fn wave_lane_count_max(&self) -> u32 {
+        self.0.WaveLaneCountMax
+    }
+
+    pub fn total_lane_count(&self) -> u32 {
+        self.0.TotalLaneCount
+    }
+
+    pub fn expanded_compute_resource_states(&self) -> bool {
+        self.0.ExpandedComputeResourceStates != 0
+    }
+
+    pub fn int64_shader_ops(&self) -> bool {
+        self.0.Int64ShaderOps != 0
+    }
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS5 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct FeatureDataOptions5(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS5);
+
+impl FeatureDataOptions5 {
+    pub fn srv_only_tiled_resource_tier3(&self) -> bool {
+        self.0.SRVOnlyTiledResourceTier3 != 0
+    }
+
+    pub fn render_passes_tier(&self) -> RenderPassTier {
+        unsafe { std::mem::transmute(self.0.RenderPassesTier) }
+    }
+
+    pub fn raytracing_tier(&self) -> RaytracingTier {
+        unsafe { std::mem::transmute(self.0.RaytracingTier) }
+    }
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS6 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct FeatureDataOptions6(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS6);
+
+impl FeatureDataOptions6 {
+    pub fn additional_shading_rates_supported(&self) -> bool {
+        self.0.AdditionalShadingRatesSupported != 0
+    }
+
+    pub fn per_primitive_shading_rate_supported_with_viewport_indexing(
+        &self,
+    ) -> bool {
+        self.0.PerPrimitiveShadingRateSupportedWithViewportIndexing != 0
+    }
+
+    pub fn variable_shading_rate_tier(&self) -> VariableShadingRateTier {
+        unsafe { std::mem::transmute(self.0.VariableShadingRateTier) }
+    }
+
+    pub fn shading_rate_image_tile_size(&self) -> u32 {
+        self.0.ShadingRateImageTileSize
+    }
+
+    pub fn background_processing_supported(&self) -> bool {
+        self.0.BackgroundProcessingSupported != 0
+    }
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS7 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct FeatureDataOptions7(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS7);
+
+impl FeatureDataOptions7 {
+    pub fn mesh_shader_tier(&self) -> MeshShaderTier {
+        unsafe { std::mem::transmute(self.0.MeshShaderTier) }
+    }
+
+    pub fn sampler_feedback_tier(&self) -> SamplerFeedbackTier {
+        unsafe { std::mem::transmute(self.0.SamplerFeedbackTier) }
+    }
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS12 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct FeatureDataOptions12(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS12);
+
+impl FeatureDataOptions12 {
+    pub fn enhanced_barriers_supported(&self) -> bool {
+        self.0.EnhancedBarriersSupported != 0
+    }
+
+    pub fn relaxed_format_casting_supported(&self) -> bool {
+        self.0.RelaxedFormatCastingSupported != 0
+    }
+}
+
+// Not in the bundled headers yet, see HeapType::GpuUpload
+#[allow(non_camel_case_types, non_snake_case)]
+#[repr(C)]
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+pub(crate) struct D3D12_FEATURE_DATA_D3D12_OPTIONS16 {
+    pub DynamicDepthBiasSupported: BOOL,
+    pub GPUUploadHeapSupported: BOOL,
+}
+
+/// Wrapper around D3D12_FEATURE_DATA_D3D12_OPTIONS16 structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct FeatureDataOptions16(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS16);
+
+impl FeatureDataOptions16 {
+    pub fn dynamic_depth_bias_supported(&self) -> bool {
+        self.0.DynamicDepthBiasSupported != 0
+    }
+
+    /// True if [HeapType::GpuUpload] heaps can be created, which requires
+    /// Resizable BAR to be enabled in the firmware
+    pub fn gpu_upload_heap_supported(&self) -> bool {
+        self.0.GPUUploadHeapSupported != 0
+    }
+}
+
 /// Wrapper around D3D12_RESOURCE_ALLOCATION_INFO structure
 #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Default, Debug, Copy, Clone)]
 #[repr(transparent)]
@@ -7379,6 +10795,17 @@ impl HeapDesc {
     pub fn flags(&self) -> HeapFlags {
         unsafe { HeapFlags::from_bits_unchecked(self.0.Flags) }
     }
+
+    /// Default heap that can be opened by devices on other adapters
+    /// via [Device::create_shared_handle](crate::Device::create_shared_handle)
+    pub fn new_cross_adapter(size_in_bytes: ByteCount) -> Self {
+        Self::default()
+            .with_size_in_bytes(size_in_bytes)
+            .with_properties(
+                HeapProperties::default().with_heap_type(HeapType::Default),
+            )
+            .with_flags(HeapFlags::Shared | HeapFlags::SharedCrossAdapter)
+    }
 }
 
 /// Wrapper around D3D12_INFO_QUEUE_FILTER_DESC structure
@@ -7486,40 +10913,145 @@ impl<'a> InfoQueueFilterDesc<'a> {
 /// Wrapper around D3D12_INFO_QUEUE_FILTER structure
 #[derive(Default, Debug, Hash, PartialOrd, Ord, PartialEq, Eq, Clone)]
 #[repr(transparent)]
-pub struct InfoQueueFilter(pub(crate) D3D12_INFO_QUEUE_FILTER);
+pub struct InfoQueueFilter<'a>(
+    pub(crate) D3D12_INFO_QUEUE_FILTER,
+    PhantomData<&'a [i32]>,
+);
 
-impl InfoQueueFilter {
+impl<'a> InfoQueueFilter<'a> {
     pub fn set_allow_list(
         &mut self,
-        allow_list: &InfoQueueFilterDesc,
+        allow_list: &InfoQueueFilterDesc<'a>,
     ) -> &mut Self {
         self.0.AllowList = allow_list.0;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_allow_list(mut self, allow_list: &InfoQueueFilterDesc) -> Self {
+    pub fn with_allow_list(
+        mut self,
+        allow_list: &InfoQueueFilterDesc<'a>,
+    ) -> Self {
         self.set_allow_list(allow_list);
         self
     }
 
-    pub fn allow_list(&self) -> &InfoQueueFilterDesc {
+    pub fn allow_list(&self) -> &InfoQueueFilterDesc<'a> {
         unsafe { std::mem::transmute(&self.0.AllowList) }
     }
 
     pub fn set_deny_list(
         &mut self,
-        deny_list: &InfoQueueFilterDesc,
+        deny_list: &InfoQueueFilterDesc<'a>,
     ) -> &mut Self {
         self.0.DenyList = deny_list.0;
+        self.1 = PhantomData;
         self
     }
 
-    pub fn with_deny_list(mut self, deny_list: &InfoQueueFilterDesc) -> Self {
+    pub fn with_deny_list(
+        mut self,
+        deny_list: &InfoQueueFilterDesc<'a>,
+    ) -> Self {
         self.set_deny_list(deny_list);
         self
     }
 
-    pub fn deny_list(&self) -> &InfoQueueFilterDesc {
-        unsafe { std::mem::transmute(&self.0.AllowList) }
+    pub fn deny_list(&self) -> &InfoQueueFilterDesc<'a> {
+        unsafe { std::mem::transmute(&self.0.DenyList) }
+    }
+}
+
+/// Wrapper around SECURITY_ATTRIBUTES structure. Building the security
+/// descriptor itself is left to the caller (e.g. via
+/// ConvertStringSecurityDescriptorToSecurityDescriptorW), it must outlive
+/// the call the attributes are passed to
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct SecurityAttributes(pub(crate) SECURITY_ATTRIBUTES);
+
+impl Default for SecurityAttributes {
+    fn default() -> Self {
+        SecurityAttributes(SECURITY_ATTRIBUTES {
+            nLength: size_of::<SECURITY_ATTRIBUTES>() as u32,
+            lpSecurityDescriptor: std::ptr::null_mut(),
+            bInheritHandle: 0,
+        })
+    }
+}
+
+impl SecurityAttributes {
+    pub fn set_security_descriptor(
+        &mut self,
+        security_descriptor: *mut std::ffi::c_void,
+    ) -> &mut Self {
+        self.0.lpSecurityDescriptor = security_descriptor;
+        self
+    }
+
+    pub fn with_security_descriptor(
+        mut self,
+        security_descriptor: *mut std::ffi::c_void,
+    ) -> Self {
+        self.set_security_descriptor(security_descriptor);
+        self
+    }
+
+    pub fn security_descriptor(&self) -> *mut std::ffi::c_void {
+        self.0.lpSecurityDescriptor
+    }
+
+    pub fn set_inherit_handle(&mut self, inherit_handle: bool) -> &mut Self {
+        self.0.bInheritHandle = inherit_handle as i32;
+        self
+    }
+
+    pub fn with_inherit_handle(mut self, inherit_handle: bool) -> Self {
+        self.set_inherit_handle(inherit_handle);
+        self
+    }
+
+    pub fn inherit_handle(&self) -> bool {
+        self.0.bInheritHandle != 0
+    }
+}
+
+/// Wrapper around GUID structure
+#[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default, Debug)]
+#[repr(transparent)]
+pub struct Guid(pub(crate) GUID);
+
+impl Guid {
+    /// E.g. {4C6A1E3D-9B52-4F1E-A7D0-3E5B8C2F61A9} is
+    /// `Guid::new(0x4c6a1e3d, 0x9b52, 0x4f1e, [0xa7, 0xd0, 0x3e, 0x5b,
+    /// 0x8c, 0x2f, 0x61, 0xa9])`
+    pub const fn new(
+        data1: u32,
+        data2: u16,
+        data3: u16,
+        data4: [u8; 8],
+    ) -> Self {
+        Self(GUID {
+            Data1: data1,
+            Data2: data2,
+            Data3: data3,
+            Data4: data4,
+        })
+    }
+
+    pub fn data1(&self) -> u32 {
+        self.0.Data1
+    }
+
+    pub fn data2(&self) -> u16 {
+        self.0.Data2
+    }
+
+    pub fn data3(&self) -> u16 {
+        self.0.Data3
+    }
+
+    pub fn data4(&self) -> [u8; 8] {
+        self.0.Data4
     }
 }
@@ -0,0 +1,36 @@
+//! Stand-ins for COM objects, so that the bookkeeping of the helpers can be
+//! tested without a device
+
+use crate::raw_bindings::d3d12::*;
+use crate::Resource;
+
+unsafe extern "C" fn add_ref(_: *mut ID3D12Resource) -> ULONG {
+    1
+}
+
+unsafe extern "C" fn release(_: *mut ID3D12Resource) -> ULONG {
+    1
+}
+
+unsafe extern "C" fn unmap(
+    _: *mut ID3D12Resource,
+    _: UINT,
+    _: *const D3D12_RANGE,
+) {
+}
+
+/// A resource that only supports reference counting and unmapping, both
+/// of which do nothing. The object is leaked, so every call returns
+/// a resource with a distinct pointer
+pub(crate) fn fake_resource() -> Resource {
+    let vtbl = Box::leak(Box::new(ID3D12ResourceVtbl {
+        AddRef: Some(add_ref),
+        Release: Some(release),
+        Unmap: Some(unmap),
+        ..Default::default()
+    }));
+
+    Resource {
+        this: Box::leak(Box::new(ID3D12Resource { lpVtbl: vtbl })),
+    }
+}
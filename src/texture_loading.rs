@@ -0,0 +1,1076 @@
+//! Loading textures from DDS files and, through WIC, from common image
+//! formats such as PNG and JPEG.
+//!
+//! ```rust,no_run
+//! use rusty_d3d12::{texture_loading, ResourceStates};
+//! # fn load(
+//! #     device: &rusty_d3d12::Device,
+//! #     command_list: &rusty_d3d12::CommandList,
+//! #     srv_handle: rusty_d3d12::CpuDescriptorHandle,
+//! # ) -> rusty_d3d12::DxResult<()> {
+//! let texture = texture_loading::load_texture_file(
+//!     device,
+//!     command_list,
+//!     "assets/albedo.dds",
+//!     ResourceStates::PixelShaderResource,
+//! )?;
+//! // texture.upload_buffer must be kept alive until the command list
+//! // has been executed
+//! texture.create_srv(device, srv_handle);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    ByteCount, CommandList, CopyCapable, CpuDescriptorHandle, Device, DxError,
+    DxResult, Format, HeapFlags, HeapProperties, HeapType, Resource,
+    ResourceBarrier, ResourceDesc, ResourceDimension, ResourceStates,
+    ResourceTransitionBarrier, ShaderComponentMapping, ShaderResourceViewDesc,
+    SubresourceData, SubresourceRange, Tex1DArraySrv, Tex1DSrv, Tex2DArraySrv,
+    Tex2DSrv, Tex3DSrv, TexcubeArraySrv, TexcubeSrv, TextureLayout,
+    REQ_MIP_LEVELS, REQ_TEXTURE2D_ARRAY_AXIS_DIMENSION,
+    REQ_TEXTURE3D_U_V_OR_W_DIMENSION,
+};
+#[cfg(feature = "helpers")]
+use crate::{CbvSrvUavHandle, CbvSrvUavKind, DescriptorAllocator};
+
+const DDS_MAGIC: u32 = 0x2053_4444; // "DDS "
+const DDS_HEADER_SIZE: usize = 124;
+const DDS_HEADER_DXT10_SIZE: usize = 20;
+
+const DDSD_DEPTH: u32 = 0x0080_0000;
+const DDPF_ALPHAPIXELS: u32 = 0x1;
+const DDPF_ALPHA: u32 = 0x2;
+const DDPF_FOURCC: u32 = 0x4;
+const DDPF_RGB: u32 = 0x40;
+const DDPF_LUMINANCE: u32 = 0x2_0000;
+const DDPF_BUMPDUDV: u32 = 0x8_0000;
+const DDSCAPS2_CUBEMAP: u32 = 0x200;
+const DDSCAPS2_VOLUME: u32 = 0x20_0000;
+const DDS_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+// D3D10_RESOURCE_DIMENSION values used by the DX10 header extension
+const DDS_DIMENSION_TEXTURE1D: u32 = 2;
+const DDS_DIMENSION_TEXTURE2D: u32 = 3;
+const DDS_DIMENSION_TEXTURE3D: u32 = 4;
+
+/// Formats that can be loaded from DDS files
+const DDS_FORMATS: &[Format] = &[
+    Format::R32G32B32A32Float,
+    Format::R32G32B32A32Uint,
+    Format::R32G32B32A32Sint,
+    Format::R32G32B32Float,
+    Format::R32G32B32Uint,
+    Format::R32G32B32Sint,
+    Format::R16G16B16A16Float,
+    Format::R16G16B16A16Unorm,
+    Format::R16G16B16A16Uint,
+    Format::R16G16B16A16Snorm,
+    Format::R16G16B16A16Sint,
+    Format::R32G32Float,
+    Format::R32G32Uint,
+    Format::R32G32Sint,
+    Format::R10G10B10A2Unorm,
+    Format::R10G10B10A2Uint,
+    Format::R11G11B10Float,
+    Format::R8G8B8A8Unorm,
+    Format::R8G8B8A8UnormSrgb,
+    Format::R8G8B8A8Uint,
+    Format::R8G8B8A8Snorm,
+    Format::R8G8B8A8Sint,
+    Format::R16G16Float,
+    Format::R16G16Unorm,
+    Format::R16G16Uint,
+    Format::R16G16Snorm,
+    Format::R16G16Sint,
+    Format::R32Float,
+    Format::R32Uint,
+    Format::R32Sint,
+    Format::R8G8Unorm,
+    Format::R8G8Uint,
+    Format::R8G8Snorm,
+    Format::R8G8Sint,
+    Format::R16Float,
+    Format::R16Unorm,
+    Format::R16Uint,
+    Format::R16Snorm,
+    Format::R16Sint,
+    Format::R8Unorm,
+    Format::R8Uint,
+    Format::R8Snorm,
+    Format::R8Sint,
+    Format::A8Unorm,
+    Format::R9G9B9E5Sharedexp,
+    Format::Bc1Unorm,
+    Format::Bc1UnormSrgb,
+    Format::Bc2Unorm,
+    Format::Bc2UnormSrgb,
+    Format::Bc3Unorm,
+    Format::Bc3UnormSrgb,
+    Format::Bc4Unorm,
+    Format::Bc4Snorm,
+    Format::Bc5Unorm,
+    Format::Bc5Snorm,
+    Format::B5G6R5Unorm,
+    Format::B5G5R5A1Unorm,
+    Format::B8G8R8A8Unorm,
+    Format::B8G8R8X8Unorm,
+    Format::B8G8R8A8UnormSrgb,
+    Format::B8G8R8X8UnormSrgb,
+    Format::Bc6HUf16,
+    Format::Bc6HSf16,
+    Format::Bc7Unorm,
+    Format::Bc7UnormSrgb,
+    Format::B4G4R4A4Unorm,
+];
+
+enum FormatLayout {
+    BitsPerPixel(u64),
+    BytesPerBlock(u64),
+}
+
+fn format_layout(format: Format) -> FormatLayout {
+    use FormatLayout::*;
+
+    match format {
+        Format::R32G32B32A32Float
+        | Format::R32G32B32A32Uint
+        | Format::R32G32B32A32Sint => BitsPerPixel(128),
+        Format::R32G32B32Float
+        | Format::R32G32B32Uint
+        | Format::R32G32B32Sint => BitsPerPixel(96),
+        Format::R16G16B16A16Float
+        | Format::R16G16B16A16Unorm
+        | Format::R16G16B16A16Uint
+        | Format::R16G16B16A16Snorm
+        | Format::R16G16B16A16Sint
+        | Format::R32G32Float
+        | Format::R32G32Uint
+        | Format::R32G32Sint => BitsPerPixel(64),
+        Format::R8G8Unorm
+        | Format::R8G8Uint
+        | Format::R8G8Snorm
+        | Format::R8G8Sint
+        | Format::R16Float
+        | Format::R16Unorm
+        | Format::R16Uint
+        | Format::R16Snorm
+        | Format::R16Sint
+        | Format::B5G6R5Unorm
+        | Format::B5G5R5A1Unorm
+        | Format::B4G4R4A4Unorm => BitsPerPixel(16),
+        Format::R8Unorm
+        | Format::R8Uint
+        | Format::R8Snorm
+        | Format::R8Sint
+        | Format::A8Unorm => BitsPerPixel(8),
+        Format::Bc1Unorm
+        | Format::Bc1UnormSrgb
+        | Format::Bc4Unorm
+        | Format::Bc4Snorm => BytesPerBlock(8),
+        Format::Bc2Unorm
+        | Format::Bc2UnormSrgb
+        | Format::Bc3Unorm
+        | Format::Bc3UnormSrgb
+        | Format::Bc5Unorm
+        | Format::Bc5Snorm
+        | Format::Bc6HUf16
+        | Format::Bc6HSf16
+        | Format::Bc7Unorm
+        | Format::Bc7UnormSrgb => BytesPerBlock(16),
+        // the rest of DDS_FORMATS
+        _ => BitsPerPixel(32),
+    }
+}
+
+fn invalid_data(func_name: &'static str, message: &str) -> DxError {
+    error!("{}: {}", func_name, message);
+    DxError::new(func_name, winerror::E_INVALIDARG)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+const fn four_cc(code: &[u8; 4]) -> u32 {
+    u32::from_le_bytes(*code)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SubresourceLayout {
+    offset: usize,
+    row_pitch: u64,
+    slice_pitch: u64,
+}
+
+/// Texture contents in system memory, with the subresources laid out
+/// in the D3D12 subresource order. Produced by [TextureData::from_dds]
+/// or [TextureData::from_image_file] and turned into a GPU resource by
+/// [TextureData::upload]
+#[derive(Debug, Clone)]
+pub struct TextureData {
+    dimension: ResourceDimension,
+    format: Format,
+    width: u32,
+    height: u32,
+    depth_or_array_size: u32,
+    mip_levels: u32,
+    is_cube_map: bool,
+    data: Vec<u8>,
+    subresources: Vec<SubresourceLayout>,
+}
+
+impl TextureData {
+    /// Parses the contents of a DDS file, with or without the DX10
+    /// header extension. Legacy headers are supported for the formats
+    /// that map to DXGI ones without conversion (e.g. not 24-bit RGB)
+    pub fn from_dds(bytes: &[u8]) -> DxResult<Self> {
+        const FUNC_NAME: &str = "TextureData::from_dds";
+
+        if bytes.len() < 4 + DDS_HEADER_SIZE || read_u32(bytes, 0) != DDS_MAGIC
+        {
+            return Err(invalid_data(FUNC_NAME, "not a DDS file"));
+        }
+
+        let header = &bytes[4..4 + DDS_HEADER_SIZE];
+        if read_u32(header, 0) as usize != DDS_HEADER_SIZE {
+            return Err(invalid_data(FUNC_NAME, "invalid DDS header size"));
+        }
+
+        let flags = read_u32(header, 4);
+        let height = read_u32(header, 8);
+        let width = read_u32(header, 12);
+        let depth = read_u32(header, 20);
+        let mip_levels = read_u32(header, 24).max(1);
+        let pixel_format = &header[72..104];
+        let pf_flags = read_u32(pixel_format, 4);
+        let pf_four_cc = read_u32(pixel_format, 8);
+        let caps2 = read_u32(header, 108);
+
+        let mut data_offset = 4 + DDS_HEADER_SIZE;
+        let (dimension, format, depth_or_array_size, is_cube_map) = if pf_flags
+            & DDPF_FOURCC
+            != 0
+            && pf_four_cc == four_cc(b"DX10")
+        {
+            if bytes.len() < data_offset + DDS_HEADER_DXT10_SIZE {
+                return Err(invalid_data(FUNC_NAME, "truncated DX10 header"));
+            }
+            let dx10_header = &bytes[data_offset..];
+            data_offset += DDS_HEADER_DXT10_SIZE;
+
+            let raw_format = read_u32(dx10_header, 0);
+            let format = DDS_FORMATS
+                .iter()
+                .copied()
+                .find(|format| *format as u32 == raw_format)
+                .ok_or_else(|| {
+                    invalid_data(
+                        FUNC_NAME,
+                        &format!("unsupported DXGI format {}", raw_format),
+                    )
+                })?;
+            let misc_flag = read_u32(dx10_header, 8);
+            let array_size = read_u32(dx10_header, 12).max(1);
+
+            match read_u32(dx10_header, 4) {
+                DDS_DIMENSION_TEXTURE1D => {
+                    (ResourceDimension::Texture1D, format, array_size, false)
+                }
+                DDS_DIMENSION_TEXTURE2D => {
+                    match misc_flag & DDS_RESOURCE_MISC_TEXTURECUBE {
+                        0 => (
+                            ResourceDimension::Texture2D,
+                            format,
+                            array_size,
+                            false,
+                        ),
+                        _ => (
+                            ResourceDimension::Texture2D,
+                            format,
+                            array_size.checked_mul(6).ok_or_else(|| {
+                                invalid_data(FUNC_NAME, "invalid array size")
+                            })?,
+                            true,
+                        ),
+                    }
+                }
+                DDS_DIMENSION_TEXTURE3D => {
+                    (ResourceDimension::Texture3D, format, depth.max(1), false)
+                }
+                dimension => {
+                    return Err(invalid_data(
+                        FUNC_NAME,
+                        &format!("invalid resource dimension {}", dimension),
+                    ))
+                }
+            }
+        } else {
+            let format = legacy_format(pixel_format).ok_or_else(|| {
+                invalid_data(FUNC_NAME, "unsupported legacy pixel format")
+            })?;
+
+            if flags & DDSD_DEPTH != 0 && caps2 & DDSCAPS2_VOLUME != 0 {
+                (ResourceDimension::Texture3D, format, depth.max(1), false)
+            } else if caps2 & DDSCAPS2_CUBEMAP != 0 {
+                (ResourceDimension::Texture2D, format, 6, true)
+            } else {
+                (ResourceDimension::Texture2D, format, 1, false)
+            }
+        };
+
+        if width == 0 {
+            return Err(invalid_data(FUNC_NAME, "zero texture width"));
+        }
+        if mip_levels > REQ_MIP_LEVELS {
+            return Err(invalid_data(
+                FUNC_NAME,
+                &format!("too many mip levels ({})", mip_levels),
+            ));
+        }
+        let max_depth_or_array_size = match dimension {
+            ResourceDimension::Texture3D => REQ_TEXTURE3D_U_V_OR_W_DIMENSION,
+            _ => REQ_TEXTURE2D_ARRAY_AXIS_DIMENSION,
+        };
+        if depth_or_array_size > max_depth_or_array_size {
+            return Err(invalid_data(
+                FUNC_NAME,
+                &format!(
+                    "depth or array size {} exceeds {}",
+                    depth_or_array_size, max_depth_or_array_size
+                ),
+            ));
+        }
+
+        let mut texture = Self {
+            dimension,
+            format,
+            width,
+            height: height.max(1),
+            depth_or_array_size,
+            mip_levels,
+            is_cube_map,
+            data: vec![],
+            subresources: vec![],
+        };
+
+        let required_size =
+            texture.compute_subresource_layouts().ok_or_else(|| {
+                invalid_data(FUNC_NAME, "texture dimensions are too large")
+            })?;
+        let available_size = bytes.len() - data_offset;
+        if required_size > available_size {
+            return Err(invalid_data(
+                FUNC_NAME,
+                &format!(
+                    "DDS data is truncated ({} bytes expected, {} present)",
+                    required_size, available_size
+                ),
+            ));
+        }
+        texture.data = bytes[data_offset..data_offset + required_size].to_vec();
+
+        Ok(texture)
+    }
+
+    /// Decodes the first frame of an image (PNG, JPEG, BMP, TIFF, GIF
+    /// and whatever else the installed WIC codecs support) into
+    /// a single-mip R8G8B8A8Unorm texture
+    pub fn from_image_file(path: impl AsRef<Path>) -> DxResult<Self> {
+        let (width, height, pixels) = wic::decode_rgba8(path.as_ref())?;
+
+        let mut texture = Self {
+            dimension: ResourceDimension::Texture2D,
+            format: Format::R8G8B8A8Unorm,
+            width,
+            height,
+            depth_or_array_size: 1,
+            mip_levels: 1,
+            is_cube_map: false,
+            data: pixels,
+            subresources: vec![],
+        };
+        texture.compute_subresource_layouts().ok_or_else(|| {
+            invalid_data(
+                "TextureData::from_image_file",
+                "image dimensions are too large",
+            )
+        })?;
+
+        Ok(texture)
+    }
+
+    /// Fills in the subresource layouts and returns the data size,
+    /// or None if it overflows
+    fn compute_subresource_layouts(&mut self) -> Option<usize> {
+        let layout = format_layout(self.format);
+        let (array_size, depth) = match self.dimension {
+            ResourceDimension::Texture3D => (1, self.depth_or_array_size),
+            _ => (self.depth_or_array_size, 1),
+        };
+
+        let mut offset = 0_usize;
+        self.subresources.clear();
+        for _ in 0..array_size {
+            for mip in 0..self.mip_levels {
+                let width = mip_extent(self.width, mip);
+                let height = mip_extent(self.height, mip);
+                let depth = mip_extent(depth, mip);
+                let (row_pitch, row_count) = match layout {
+                    FormatLayout::BitsPerPixel(bits) => {
+                        (width.checked_mul(bits)?.checked_add(7)? / 8, height)
+                    }
+                    FormatLayout::BytesPerBlock(bytes) => (
+                        ((width + 3) / 4).checked_mul(bytes)?,
+                        (height + 3) / 4,
+                    ),
+                };
+                let slice_pitch = row_pitch.checked_mul(row_count)?;
+
+                self.subresources.push(SubresourceLayout {
+                    offset,
+                    row_pitch,
+                    slice_pitch,
+                });
+                let size =
+                    usize::try_from(slice_pitch.checked_mul(depth)?).ok()?;
+                offset = offset.checked_add(size)?;
+            }
+        }
+
+        Some(offset)
+    }
+
+    pub fn dimension(&self) -> ResourceDimension {
+        self.dimension
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Depth of 3D textures, array size of the others (six per cube)
+    pub fn depth_or_array_size(&self) -> u32 {
+        self.depth_or_array_size
+    }
+
+    pub fn mip_levels(&self) -> u32 {
+        self.mip_levels
+    }
+
+    pub fn is_cube_map(&self) -> bool {
+        self.is_cube_map
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Description of a texture that can hold the data
+    pub fn resource_desc(&self) -> ResourceDesc {
+        ResourceDesc::default()
+            .with_dimension(self.dimension)
+            .with_width(self.width as u64)
+            .with_height(self.height)
+            .with_depth_or_array_size(self.depth_or_array_size as u16)
+            .with_mip_levels(self.mip_levels as u16)
+            .with_format(self.format)
+            .with_layout(TextureLayout::Unknown)
+    }
+
+    /// A view of all mips and array slices (or cube faces)
+    pub fn srv_desc(&self) -> ShaderResourceViewDesc {
+        let desc = ShaderResourceViewDesc::default()
+            .with_format(self.format)
+            .with_shader_4_component_mapping(ShaderComponentMapping::default());
+        let array_size = self.depth_or_array_size;
+
+        match self.dimension {
+            ResourceDimension::Texture1D if array_size > 1 => desc
+                .new_texture_1d_array(
+                    &Tex1DArraySrv::default()
+                        .with_mip_levels(self.mip_levels)
+                        .with_array_size(array_size),
+                ),
+            ResourceDimension::Texture1D => desc.new_texture_1d(
+                &Tex1DSrv::default().with_mip_levels(self.mip_levels),
+            ),
+            ResourceDimension::Texture3D => desc.new_texture_3d(
+                &Tex3DSrv::default().with_mip_levels(self.mip_levels),
+            ),
+            _ if self.is_cube_map && array_size > 6 => desc
+                .new_texture_cube_array(
+                    &TexcubeArraySrv::default()
+                        .with_mip_levels(self.mip_levels)
+                        .with_num_cubes(array_size / 6),
+                ),
+            _ if self.is_cube_map => desc.new_texture_cube(
+                &TexcubeSrv::default().with_mip_levels(self.mip_levels),
+            ),
+            _ if array_size > 1 => desc.new_texture_2d_array(
+                &Tex2DArraySrv::default()
+                    .with_mip_levels(self.mip_levels)
+                    .with_array_size(array_size),
+            ),
+            _ => desc.new_texture_2d(
+                &Tex2DSrv::default().with_mip_levels(self.mip_levels),
+            ),
+        }
+    }
+
+    /// Source data for update_subresources, one entry per subresource
+    pub fn subresource_data(&self) -> Vec<SubresourceData<'_>> {
+        self.subresources
+            .iter()
+            .map(|layout| {
+                SubresourceData::default()
+                    .with_data(&self.data[layout.offset..])
+                    .with_row_pitch(ByteCount(layout.row_pitch))
+                    .with_slice_pitch(ByteCount(layout.slice_pitch))
+            })
+            .collect()
+    }
+
+    /// Creates a committed texture and an upload buffer, and records
+    /// the upload of all subresources into `command_list`, followed by
+    /// a transition into `state_after` (unless it is CopyDest)
+    pub fn upload<K: CopyCapable>(
+        &self,
+        device: &Device,
+        command_list: &CommandList<K>,
+        state_after: ResourceStates,
+    ) -> DxResult<LoadedTexture> {
+        let resource_desc = self.resource_desc();
+        let resource = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Default),
+            HeapFlags::None,
+            &resource_desc,
+            ResourceStates::CopyDest,
+            None,
+        )?;
+        if let Some(name) = device.make_object_name("Loaded texture") {
+            resource.set_name(&name)?;
+        }
+
+        let subresources = SubresourceRange::all(&resource_desc);
+        let upload_buffer_size =
+            resource.get_required_intermediate_size(&subresources)?;
+        let upload_buffer = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Upload),
+            HeapFlags::None,
+            &ResourceDesc::default()
+                .with_dimension(ResourceDimension::Buffer)
+                .with_width(upload_buffer_size.0)
+                .with_layout(TextureLayout::RowMajor),
+            ResourceStates::GenericRead,
+            None,
+        )?;
+        if let Some(name) = device.make_object_name("Texture upload buffer") {
+            upload_buffer.set_name(&name)?;
+        }
+
+        command_list.update_subresources_heap_alloc(
+            &resource,
+            &upload_buffer,
+            ByteCount(0),
+            &subresources,
+            &self.subresource_data(),
+        )?;
+
+        if state_after != ResourceStates::CopyDest {
+            command_list.resource_barrier(&[ResourceBarrier::new_transition(
+                &ResourceTransitionBarrier::default()
+                    .with_resource(&resource)
+                    .with_state_before(ResourceStates::CopyDest)
+                    .with_state_after(state_after),
+            )]);
+        }
+
+        Ok(LoadedTexture {
+            resource,
+            upload_buffer,
+            srv_desc: self.srv_desc(),
+        })
+    }
+}
+
+// Size of a mip level along one axis, never zero
+fn mip_extent(extent: u32, mip: u32) -> u64 {
+    extent.checked_shr(mip).unwrap_or(0).max(1) as u64
+}
+
+fn legacy_format(pixel_format: &[u8]) -> Option<Format> {
+    let flags = read_u32(pixel_format, 4);
+    let four_cc_code = read_u32(pixel_format, 8);
+    let bit_count = read_u32(pixel_format, 12);
+    let masks = (
+        read_u32(pixel_format, 16),
+        read_u32(pixel_format, 20),
+        read_u32(pixel_format, 24),
+        read_u32(pixel_format, 28),
+    );
+
+    if flags & DDPF_FOURCC != 0 {
+        return match four_cc_code {
+            code if code == four_cc(b"DXT1") => Some(Format::Bc1Unorm),
+            code if code == four_cc(b"DXT2") || code == four_cc(b"DXT3") => {
+                Some(Format::Bc2Unorm)
+            }
+            code if code == four_cc(b"DXT4") || code == four_cc(b"DXT5") => {
+                Some(Format::Bc3Unorm)
+            }
+            code if code == four_cc(b"ATI1") || code == four_cc(b"BC4U") => {
+                Some(Format::Bc4Unorm)
+            }
+            code if code == four_cc(b"BC4S") => Some(Format::Bc4Snorm),
+            code if code == four_cc(b"ATI2") || code == four_cc(b"BC5U") => {
+                Some(Format::Bc5Unorm)
+            }
+            code if code == four_cc(b"BC5S") => Some(Format::Bc5Snorm),
+            // D3DFORMAT values stored in the FourCC field
+            36 => Some(Format::R16G16B16A16Unorm),
+            110 => Some(Format::R16G16B16A16Snorm),
+            111 => Some(Format::R16Float),
+            112 => Some(Format::R16G16Float),
+            113 => Some(Format::R16G16B16A16Float),
+            114 => Some(Format::R32Float),
+            115 => Some(Format::R32G32Float),
+            116 => Some(Format::R32G32B32A32Float),
+            _ => None,
+        };
+    }
+
+    if flags & DDPF_RGB != 0 {
+        let alpha_mask = match flags & DDPF_ALPHAPIXELS {
+            0 => 0,
+            _ => masks.3,
+        };
+        return match (bit_count, masks.0, masks.1, masks.2, alpha_mask) {
+            (32, 0xff, 0xff00, 0xff_0000, 0xff00_0000) => {
+                Some(Format::R8G8B8A8Unorm)
+            }
+            (32, 0xff_0000, 0xff00, 0xff, 0xff00_0000) => {
+                Some(Format::B8G8R8A8Unorm)
+            }
+            (32, 0xff_0000, 0xff00, 0xff, 0) => Some(Format::B8G8R8X8Unorm),
+            (32, 0x3ff, 0xf_fc00, 0x3ff0_0000, 0xc000_0000) => {
+                Some(Format::R10G10B10A2Unorm)
+            }
+            (32, 0xffff, 0xffff_0000, 0, 0) => Some(Format::R16G16Unorm),
+            (32, 0xffff_ffff, 0, 0, 0) => Some(Format::R32Float),
+            (16, 0xf800, 0x7e0, 0x1f, 0) => Some(Format::B5G6R5Unorm),
+            (16, 0x7c00, 0x3e0, 0x1f, 0x8000) => Some(Format::B5G5R5A1Unorm),
+            (16, 0xf00, 0xf0, 0xf, 0xf000) => Some(Format::B4G4R4A4Unorm),
+            _ => None,
+        };
+    }
+
+    if flags & DDPF_LUMINANCE != 0 {
+        return match (bit_count, masks.0, masks.3) {
+            (8, 0xff, _) => Some(Format::R8Unorm),
+            (16, 0xffff, _) => Some(Format::R16Unorm),
+            (16, 0xff, 0xff00) => Some(Format::R8G8Unorm),
+            _ => None,
+        };
+    }
+
+    if flags & DDPF_ALPHA != 0 && bit_count == 8 {
+        return Some(Format::A8Unorm);
+    }
+
+    if flags & DDPF_BUMPDUDV != 0 {
+        return match (bit_count, masks.0, masks.1) {
+            (16, 0xff, 0xff00) => Some(Format::R8G8Snorm),
+            (32, 0xff, 0xff00) => Some(Format::R8G8B8A8Snorm),
+            (32, 0xffff, 0xffff_0000) => Some(Format::R16G16Snorm),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+/// A texture created by [TextureData::upload]
+#[derive(Debug)]
+pub struct LoadedTexture {
+    pub resource: Resource,
+    /// Holds the source data of the recorded copies, so it must be kept
+    /// alive until the command list has been executed
+    pub upload_buffer: Resource,
+    /// Suggested view of the whole texture
+    pub srv_desc: ShaderResourceViewDesc,
+}
+
+impl LoadedTexture {
+    /// Writes a view of the whole texture described by
+    /// [LoadedTexture::srv_desc] into `dest_descriptor`
+    pub fn create_srv(
+        &self,
+        device: &Device,
+        dest_descriptor: CpuDescriptorHandle,
+    ) {
+        device.create_shader_resource_view(
+            &self.resource,
+            Some(&self.srv_desc),
+            dest_descriptor,
+        );
+    }
+
+    /// Same as [LoadedTexture::create_srv], but takes the descriptor
+    /// from `allocator`
+    #[cfg(feature = "helpers")]
+    pub fn allocate_srv(
+        &self,
+        device: &Device,
+        allocator: &mut DescriptorAllocator<CbvSrvUavKind>,
+    ) -> DxResult<CbvSrvUavHandle> {
+        let handle = allocator.allocate()?;
+        allocator.create_shader_resource_view(
+            device,
+            &self.resource,
+            Some(&self.srv_desc),
+            &handle,
+        );
+
+        Ok(handle)
+    }
+}
+
+/// Parses a DDS file, see [TextureData::from_dds]
+pub fn load_dds(path: impl AsRef<Path>) -> DxResult<TextureData> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|err| {
+        error!("Cannot read {}: {}", path.display(), err);
+        DxError::new("std::fs::read", winerror::E_FAIL)
+    })?;
+
+    TextureData::from_dds(&bytes)
+}
+
+/// Loads a DDS file (by extension) or any other image through WIC and
+/// records its upload, see [TextureData::upload]
+pub fn load_texture_file<K: CopyCapable>(
+    device: &Device,
+    command_list: &CommandList<K>,
+    path: impl AsRef<Path>,
+    state_after: ResourceStates,
+) -> DxResult<LoadedTexture> {
+    let path = path.as_ref();
+    let is_dds = path
+        .extension()
+        .map_or(false, |extension| extension.eq_ignore_ascii_case("dds"));
+    let texture = match is_dds {
+        true => load_dds(path)?,
+        false => TextureData::from_image_file(path)?,
+    };
+
+    texture.upload(device, command_list, state_after)
+}
+
+mod wic {
+    use std::path::Path;
+
+    use log::error;
+    use winapi::shared::winerror::{self, HRESULT, RPC_E_CHANGED_MODE};
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+    use winapi::um::combaseapi::{
+        CoCreateInstance, CoInitializeEx, CoUninitialize,
+    };
+    use winapi::um::objbase::COINIT_MULTITHREADED;
+    use winapi::um::unknwnbase::IUnknown;
+    use winapi::um::wincodec::{
+        CLSID_WICImagingFactory, GUID_WICPixelFormat32bppRGBA,
+        IWICBitmapDecoder, IWICBitmapFrameDecode, IWICBitmapSource,
+        IWICImagingFactory, WICConvertBitmapSource,
+        WICDecodeMetadataCacheOnDemand,
+    };
+    use winapi::um::winnt::GENERIC_READ;
+    use winapi::Interface;
+
+    use crate::{DxError, DxResult};
+
+    // Releases the wrapped WIC object
+    struct WicPtr<T>(*mut T);
+
+    impl<T> Drop for WicPtr<T> {
+        fn drop(&mut self) {
+            if !self.0.is_null() {
+                unsafe {
+                    (*(self.0 as *mut IUnknown)).Release();
+                }
+            }
+        }
+    }
+
+    // Balances a successful CoInitializeEx
+    struct ComScope(bool);
+
+    impl Drop for ComScope {
+        fn drop(&mut self) {
+            if self.0 {
+                unsafe { CoUninitialize() };
+            }
+        }
+    }
+
+    fn check(func_name: &'static str, hr: HRESULT) -> DxResult<()> {
+        match winerror::SUCCEEDED(hr) {
+            true => Ok(()),
+            false => Err(DxError::new(func_name, hr)),
+        }
+    }
+
+    pub(super) fn decode_rgba8(path: &Path) -> DxResult<(u32, u32, Vec<u8>)> {
+        let path_wide = widestring::U16CString::from_os_str(path)
+            .expect("Cannot convert image path to utf-16");
+
+        unsafe {
+            // an STA initialized by the application works as well
+            let hr = CoInitializeEx(std::ptr::null_mut(), COINIT_MULTITHREADED);
+            if winerror::FAILED(hr) && hr != RPC_E_CHANGED_MODE {
+                return Err(DxError::new("CoInitializeEx", hr));
+            }
+            let _com_scope = ComScope(winerror::SUCCEEDED(hr));
+
+            let mut factory =
+                WicPtr::<IWICImagingFactory>(std::ptr::null_mut());
+            check(
+                "CoCreateInstance",
+                CoCreateInstance(
+                    &CLSID_WICImagingFactory,
+                    std::ptr::null_mut(),
+                    CLSCTX_INPROC_SERVER,
+                    &IWICImagingFactory::uuidof(),
+                    &mut factory.0 as *mut *mut _ as *mut *mut _,
+                ),
+            )?;
+
+            let mut decoder = WicPtr::<IWICBitmapDecoder>(std::ptr::null_mut());
+            if let Err(err) = check(
+                "CreateDecoderFromFilename",
+                (*factory.0).CreateDecoderFromFilename(
+                    path_wide.as_ptr(),
+                    std::ptr::null(),
+                    GENERIC_READ,
+                    WICDecodeMetadataCacheOnDemand,
+                    &mut decoder.0,
+                ),
+            ) {
+                error!("Cannot decode image {}: {}", path.display(), err);
+                return Err(err);
+            }
+
+            let mut frame =
+                WicPtr::<IWICBitmapFrameDecode>(std::ptr::null_mut());
+            check("GetFrame", (*decoder.0).GetFrame(0, &mut frame.0))?;
+
+            let mut converted =
+                WicPtr::<IWICBitmapSource>(std::ptr::null_mut());
+            check(
+                "WICConvertBitmapSource",
+                WICConvertBitmapSource(
+                    &GUID_WICPixelFormat32bppRGBA,
+                    frame.0 as *const IWICBitmapSource,
+                    &mut converted.0,
+                ),
+            )?;
+
+            let (mut width, mut height) = (0, 0);
+            check("GetSize", (*converted.0).GetSize(&mut width, &mut height))?;
+
+            // CopyPixels takes the buffer size as u32
+            let (stride, buffer_size) = match width
+                .checked_mul(4)
+                .and_then(|stride| Some((stride, stride.checked_mul(height)?)))
+            {
+                Some(sizes) => sizes,
+                None => {
+                    error!(
+                        "Image {} is too large ({}x{})",
+                        path.display(),
+                        width,
+                        height
+                    );
+                    return Err(DxError::new(
+                        "TextureData::from_image_file",
+                        winerror::E_INVALIDARG,
+                    ));
+                }
+            };
+            let mut pixels = vec![0_u8; buffer_size as usize];
+            check(
+                "CopyPixels",
+                (*converted.0).CopyPixels(
+                    std::ptr::null(),
+                    stride,
+                    buffer_size,
+                    pixels.as_mut_ptr(),
+                ),
+            )?;
+
+            Ok((width, height, pixels))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // DDS file with a legacy header; `pixel_format` is (flags, FourCC)
+    fn dds_file(
+        width: u32,
+        height: u32,
+        mip_levels: u32,
+        pixel_format: (u32, u32),
+        data_size: usize,
+    ) -> Vec<u8> {
+        let mut header = vec![0_u8; DDS_HEADER_SIZE];
+        let mut write = |offset: usize, value: u32| {
+            header[offset..offset + 4].copy_from_slice(&value.to_le_bytes())
+        };
+        write(0, DDS_HEADER_SIZE as u32);
+        write(8, height);
+        write(12, width);
+        write(24, mip_levels);
+        write(72, 32);
+        write(76, pixel_format.0);
+        write(80, pixel_format.1);
+
+        let mut bytes = DDS_MAGIC.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&header);
+        bytes.resize(bytes.len() + data_size, 0);
+        bytes
+    }
+
+    // DDS file with the DX10 header extension
+    fn dx10_dds_file(
+        width: u32,
+        height: u32,
+        format: Format,
+        misc_flag: u32,
+        array_size: u32,
+        data_size: usize,
+    ) -> Vec<u8> {
+        let mut bytes =
+            dds_file(width, height, 1, (DDPF_FOURCC, four_cc(b"DX10")), 0);
+        for value in [
+            format as u32,
+            DDS_DIMENSION_TEXTURE2D,
+            misc_flag,
+            array_size,
+            0,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.resize(bytes.len() + data_size, 0);
+        bytes
+    }
+
+    #[test]
+    fn block_compressed_mip_chain() {
+        // 8x8, 4x4, 2x2 and 1x1 mips of 8-byte 4x4 blocks
+        let bytes = dds_file(8, 8, 4, (DDPF_FOURCC, four_cc(b"DXT1")), 56);
+        let texture = TextureData::from_dds(&bytes).unwrap();
+
+        assert!(matches!(texture.format(), Format::Bc1Unorm));
+        assert_eq!(texture.mip_levels(), 4);
+        assert_eq!(texture.data().len(), 56);
+        let offsets = texture
+            .subresources
+            .iter()
+            .map(|layout| layout.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![0, 32, 40, 48]);
+        assert_eq!(texture.subresources[0].row_pitch, 16);
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(TextureData::from_dds(truncated).is_err());
+    }
+
+    #[test]
+    fn cube_map_array() {
+        let bytes = dx10_dds_file(
+            4,
+            4,
+            Format::R8G8B8A8Unorm,
+            DDS_RESOURCE_MISC_TEXTURECUBE,
+            2,
+            4 * 4 * 4 * 12,
+        );
+        let texture = TextureData::from_dds(&bytes).unwrap();
+
+        assert!(texture.is_cube_map());
+        assert_eq!(texture.depth_or_array_size(), 12);
+        assert_eq!(texture.subresource_data().len(), 12);
+        assert_eq!(texture.resource_desc().depth_or_array_size(), 12);
+    }
+
+    #[test]
+    fn malformed_headers_are_rejected() {
+        assert!(TextureData::from_dds(&[]).is_err());
+        assert!(TextureData::from_dds(b"DDS \0\0\0\0").is_err());
+
+        let dxt1 = (DDPF_FOURCC, four_cc(b"DXT1"));
+        // zero width
+        assert!(TextureData::from_dds(&dds_file(0, 4, 1, dxt1, 64)).is_err());
+        // more mips than any texture can have
+        assert!(TextureData::from_dds(&dds_file(4, 4, 40, dxt1, 64)).is_err());
+        // unknown FourCC
+        assert!(TextureData::from_dds(&dds_file(
+            4,
+            4,
+            1,
+            (DDPF_FOURCC, four_cc(b"ABCD")),
+            64
+        ))
+        .is_err());
+        // the data size would overflow
+        assert!(TextureData::from_dds(&dds_file(
+            u32::MAX,
+            u32::MAX,
+            1,
+            (DDPF_FOURCC, four_cc(b"DXT1")),
+            64
+        ))
+        .is_err());
+        // the cube face count would overflow
+        assert!(TextureData::from_dds(&dx10_dds_file(
+            4,
+            4,
+            Format::R8G8B8A8Unorm,
+            DDS_RESOURCE_MISC_TEXTURECUBE,
+            u32::MAX / 2,
+            64
+        ))
+        .is_err());
+        // too many array slices for a resource description
+        assert!(TextureData::from_dds(&dx10_dds_file(
+            4,
+            4,
+            Format::R8G8B8A8Unorm,
+            0,
+            0x1_0000,
+            64
+        ))
+        .is_err());
+    }
+}
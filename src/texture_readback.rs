@@ -0,0 +1,285 @@
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    ByteCount, CommandListType, CommandQueue, ComputeKind, CopyCapable,
+    CopyKind, CopyableSubresource, Device, DirectKind, DxError, DxResult,
+    Format, HeapFlags, HeapProperties, HeapType, Range, Resource,
+    ResourceBarrier, ResourceDesc, ResourceDimension, ResourceStates,
+    ResourceTransitionBarrier, SubresourceRange, TextureCopyLocation,
+    TextureLayout, Timeline, TEXTURE_DATA_PLACEMENT_ALIGNMENT,
+};
+
+/// Texels of a single subresource read back to the CPU. Rows are tightly
+/// packed, i.e. without the padding GPU copies require, so for block
+/// compressed formats a "row" is a row of blocks
+#[derive(Debug, Clone)]
+pub struct CpuImage {
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: Format,
+    row_size: usize,
+    rows_per_slice: u32,
+    data: Vec<u8>,
+}
+
+impl CpuImage {
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// 1 for everything but 3D textures
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Size of a row in bytes, which is also the distance between rows
+    pub fn row_size(&self) -> usize {
+        self.row_size
+    }
+
+    /// Number of rows in a depth slice, less than the height for block
+    /// compressed formats
+    pub fn rows_per_slice(&self) -> u32 {
+        self.rows_per_slice
+    }
+
+    /// `row` of the depth slice `slice`
+    pub fn row(&self, slice: u32, row: u32) -> &[u8] {
+        let start =
+            ((slice * self.rows_per_slice + row) as usize) * self.row_size;
+        &self.data[start..start + self.row_size]
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Copies `subresources` of `texture` into a readback buffer on `queue`,
+/// waits for the copy and returns the texels of each subresource in
+/// the order of [SubresourceRange::subresource_indices]. The images of
+/// the planes of depth-stencil formats have the format of the plane,
+/// e.g. the stencil of D24UnormS8Uint is R8Typeless. `state` is the state
+/// the texture is in, it is transitioned to CopySource for the copy
+/// and back afterwards (which a copy queue only allows for Common
+/// and CopySource). Multisampled textures have to be resolved first.
+///
+/// The queue is flushed, so this is meant for screenshots, tests
+/// and tools rather than for per-frame readbacks, see [ReadbackRing]
+/// for the latter
+///
+/// [ReadbackRing]: crate::ReadbackRing
+pub fn read_back_texture(
+    device: &Device,
+    queue: &CommandQueue,
+    texture: &Resource,
+    subresources: &SubresourceRange,
+    state: ResourceStates,
+) -> DxResult<Vec<CpuImage>> {
+    let desc = texture.get_desc();
+    if matches!(desc.dimension(), ResourceDimension::Buffer)
+        || desc.sample_desc().count() > 1
+    {
+        error!(
+            "Only single-sampled textures can be read back, resource {:p} \
+             is a {:?} with {} samples",
+            texture.this,
+            desc.dimension(),
+            desc.sample_desc().count()
+        );
+        return Err(DxError::new("read_back_texture", winerror::E_INVALIDARG));
+    }
+
+    // the depth of 3D textures is not an array size
+    let mip_levels = desc.mip_levels() as u32;
+    let array_size = match desc.dimension() {
+        ResourceDimension::Texture3D => 1,
+        _ => desc.array_size(),
+    };
+    if subresources.subresource_count() == 0
+        || subresources.mips.end > mip_levels
+        || subresources.array_slices.end > array_size
+        || subresources.planes.end > desc.format().plane_count()
+    {
+        error!(
+            "Subresource range {:?} is empty or exceeds the subresources \
+             of resource {:p}",
+            subresources, texture.this
+        );
+        return Err(DxError::new("read_back_texture", winerror::E_INVALIDARG));
+    }
+
+    // the subresources don't have to be consecutive, so each of them gets
+    // its own region of the buffer
+    let mut copies = vec![];
+    let mut buffer_size = ByteCount(0);
+    for subresource in subresources.subresource_indices(mip_levels, array_size)
+    {
+        let footprints =
+            device.get_copyable_footprints(&desc, subresource, 1, ByteCount(0));
+        let mut copyable = footprints.subresource(0);
+        let offset = buffer_size.align_up(TEXTURE_DATA_PLACEMENT_ALIGNMENT);
+        copyable.layout.set_offset(offset);
+        buffer_size = offset + footprints.total_size();
+        copies.push((subresource, copyable));
+    }
+
+    let buffer = device.create_committed_resource(
+        &HeapProperties::default().with_heap_type(HeapType::Readback),
+        HeapFlags::None,
+        &ResourceDesc::default()
+            .with_dimension(ResourceDimension::Buffer)
+            .with_width(buffer_size.0)
+            .with_layout(TextureLayout::RowMajor),
+        ResourceStates::CopyDest,
+        None,
+    )?;
+    if let Some(name) = device.make_object_name("Texture readback buffer") {
+        buffer.set_name(&name)?;
+    }
+
+    let copy = ReadbackCopy {
+        texture,
+        copies: &copies,
+        state,
+        buffer: &buffer,
+    };
+    match queue.get_desc().queue_type() {
+        CommandListType::Direct => copy.execute::<DirectKind>(device, queue)?,
+        CommandListType::Compute => {
+            copy.execute::<ComputeKind>(device, queue)?
+        }
+        CommandListType::Copy => copy.execute::<CopyKind>(device, queue)?,
+        queue_type => {
+            error!("Textures cannot be read back on {:?} queues", queue_type);
+            return Err(DxError::new(
+                "read_back_texture",
+                winerror::E_INVALIDARG,
+            ));
+        }
+    }
+
+    let mapped_data = buffer.map(0, None)?;
+    let images = copies
+        .iter()
+        .map(|(_, copyable)| unsafe { copy_rows(mapped_data, copyable) })
+        .collect();
+    // nothing has been written by the CPU
+    buffer.unmap(0, Some(&Range::default()));
+
+    Ok(images)
+}
+
+// Everything needed to record the copy with a command list of any kind
+struct ReadbackCopy<'a> {
+    texture: &'a Resource,
+    // subresource index and its placement in the buffer
+    copies: &'a [(u32, CopyableSubresource)],
+    state: ResourceStates,
+    buffer: &'a Resource,
+}
+
+impl ReadbackCopy<'_> {
+    fn execute<K: CopyCapable>(
+        &self,
+        device: &Device,
+        queue: &CommandQueue,
+    ) -> DxResult<()> {
+        let command_allocator = device.create_command_allocator(K::TYPE)?;
+        let command_list =
+            device.create_typed_command_list::<K>(&command_allocator, None)?;
+
+        let transitions = |before, after| {
+            self.copies
+                .iter()
+                .map(|(subresource, _)| {
+                    ResourceBarrier::new_transition(
+                        &ResourceTransitionBarrier::default()
+                            .with_resource(self.texture)
+                            .with_subresource(Some(*subresource))
+                            .with_state_before(before)
+                            .with_state_after(after),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let needs_transition = self.state != ResourceStates::CopySource;
+        if needs_transition {
+            command_list.resource_barrier(&transitions(
+                self.state,
+                ResourceStates::CopySource,
+            ));
+        }
+        for (subresource, copyable) in self.copies {
+            command_list.copy_texture_region(
+                TextureCopyLocation::new_placed_footprint(
+                    self.buffer,
+                    copyable.layout,
+                ),
+                0,
+                0,
+                0,
+                TextureCopyLocation::new_subresource_index(
+                    self.texture,
+                    *subresource,
+                ),
+                None,
+            );
+        }
+        if needs_transition {
+            command_list.resource_barrier(&transitions(
+                ResourceStates::CopySource,
+                self.state,
+            ));
+        }
+        command_list.close()?;
+
+        queue.execute_command_lists(std::slice::from_ref(&command_list));
+        Timeline::new(device)?.flush(queue)
+    }
+}
+
+// `mapped_data` points to the start of the mapped readback buffer
+unsafe fn copy_rows(
+    mapped_data: *const u8,
+    copyable: &CopyableSubresource,
+) -> CpuImage {
+    let footprint = copyable.layout.footprint();
+    let offset = copyable.layout.offset().0 as usize;
+    let row_pitch = footprint.row_pitch().0 as usize;
+    let row_size = copyable.row_size.0 as usize;
+    let row_count = (copyable.num_rows * footprint.depth()) as usize;
+
+    let mut data = Vec::with_capacity(row_size * row_count);
+    for row in 0..row_count {
+        data.extend_from_slice(std::slice::from_raw_parts(
+            mapped_data.add(offset + row * row_pitch),
+            row_size,
+        ));
+    }
+
+    CpuImage {
+        width: footprint.width(),
+        height: footprint.height(),
+        depth: footprint.depth(),
+        format: footprint.format(),
+        row_size,
+        rows_per_slice: copyable.num_rows,
+        data,
+    }
+}
@@ -0,0 +1,219 @@
+use log::{error, warn};
+use winapi::shared::winerror;
+
+use crate::{CommandQueue, Device, DxError, DxResult, Fence, FenceFlags};
+
+/// Signaling a value above it makes [Timeline] warn that
+/// [Timeline::rebase] should be called soon
+pub const TIMELINE_REBASE_THRESHOLD: u64 = u64::MAX - u32::MAX as u64;
+
+/// A fence with a monotonically increasing value. Every signal gets
+/// a new value, which is then used to wait for or check the completion
+/// of all the work submitted before the signal.
+///
+/// D3D12 accepts a signal of a value lower than the current one and simply
+/// moves the fence back, so that waits for values between the two never
+/// complete or complete too early. Explicit values passed to
+/// [Timeline::signal_value_from] and [Timeline::signal_value_on_cpu] are
+/// therefore checked to be greater than the last signaled one, as are the
+/// values waited for, and violations are returned as errors in debug builds.
+///
+/// A 64-bit value does not wrap around in practice (it would take over half
+/// a million years at a million signals per second), but processes that
+/// import fences with arbitrary values or run effectively forever can call
+/// [Timeline::rebase] when [Timeline::needs_rebase] returns true: it waits
+/// for all the signaled work and moves the fence back to zero. Values
+/// obtained before the rebase must not be used after it
+#[derive(Debug)]
+pub struct Timeline {
+    fence: Fence,
+    last_signaled_value: u64,
+}
+
+impl Timeline {
+    pub fn new(device: &Device) -> DxResult<Self> {
+        Self::with_flags(device, FenceFlags::None)
+    }
+
+    /// E.g. FenceFlags::Shared to share the timeline between devices
+    pub fn with_flags(device: &Device, flags: FenceFlags) -> DxResult<Self> {
+        let fence = device.create_fence(0, flags)?;
+        if let Some(name) = device.make_object_name("Timeline fence") {
+            fence.set_name(&name)?;
+        }
+
+        Ok(Self {
+            fence,
+            last_signaled_value: 0,
+        })
+    }
+
+    /// Wraps an existing fence, e.g. one opened from a shared handle,
+    /// continuing from its completed value. The fence must not have
+    /// pending signals of greater values
+    pub fn from_fence(fence: Fence) -> Self {
+        Self {
+            last_signaled_value: fence.get_completed_value(),
+            fence,
+        }
+    }
+
+    /// Enqueues a signal of the next value on `queue` and returns it
+    pub fn signal_from(&mut self, queue: &CommandQueue) -> DxResult<u64> {
+        let value = self.next_value("Timeline::signal_from")?;
+        queue.signal(&self.fence, value)?;
+        self.set_last_signaled_value(value);
+
+        Ok(value)
+    }
+
+    /// Enqueues a signal of an explicit value on `queue`, e.g. when the
+    /// values are dictated by another API. In debug builds an error is
+    /// returned if `value` is not greater than the last signaled one
+    pub fn signal_value_from(
+        &mut self,
+        queue: &CommandQueue,
+        value: u64,
+    ) -> DxResult<()> {
+        self.check_monotonic("Timeline::signal_value_from", value)?;
+        queue.signal(&self.fence, value)?;
+        self.set_last_signaled_value(value);
+
+        Ok(())
+    }
+
+    /// Sets the fence to an explicit value from the CPU, with the same
+    /// checks as [Timeline::signal_value_from]
+    pub fn signal_value_on_cpu(&mut self, value: u64) -> DxResult<()> {
+        self.check_monotonic("Timeline::signal_value_on_cpu", value)?;
+        self.fence.signal(value)?;
+        self.set_last_signaled_value(value);
+
+        Ok(())
+    }
+
+    /// Blocks until the GPU reaches `value`, returns false if `timeout`
+    /// (in milliseconds) has expired before that. In debug builds waiting
+    /// without a timeout for a value that has not been signaled yet is
+    /// an error, since it would block forever
+    pub fn wait_on_cpu(
+        &self,
+        value: u64,
+        timeout: Option<u32>,
+    ) -> DxResult<bool> {
+        if timeout.is_none() {
+            self.check_signaled("Timeline::wait_on_cpu", value)?;
+        }
+
+        self.fence.wait_blocking(value, timeout)
+    }
+
+    /// Makes `queue` wait on the GPU until the timeline reaches `value`,
+    /// e.g. to consume the results of another queue's work. Unlike
+    /// [Timeline::wait_on_cpu], the value may be signaled afterwards
+    pub fn wait_on_queue(
+        &self,
+        queue: &CommandQueue,
+        value: u64,
+    ) -> DxResult<()> {
+        queue.wait(&self.fence, value)
+    }
+
+    pub fn is_complete(&self, value: u64) -> bool {
+        self.fence.get_completed_value() >= value
+    }
+
+    /// Signals the next value on `queue` and waits for it, i.e. for all
+    /// the work submitted to the queue so far
+    pub fn flush(&mut self, queue: &CommandQueue) -> DxResult<()> {
+        let value = self.signal_from(queue)?;
+        self.wait_on_cpu(value, None)?;
+
+        Ok(())
+    }
+
+    pub fn completed_value(&self) -> u64 {
+        self.fence.get_completed_value()
+    }
+
+    pub fn last_signaled_value(&self) -> u64 {
+        self.last_signaled_value
+    }
+
+    pub fn fence(&self) -> &Fence {
+        &self.fence
+    }
+
+    /// True once the last signaled value has exceeded
+    /// [TIMELINE_REBASE_THRESHOLD]
+    pub fn needs_rebase(&self) -> bool {
+        self.last_signaled_value > TIMELINE_REBASE_THRESHOLD
+    }
+
+    /// Waits for all the signaled values and resets the fence to zero.
+    /// No queue may have a pending signal or wait on the fence that has
+    /// not been issued through this timeline
+    pub fn rebase(&mut self) -> DxResult<()> {
+        self.wait_on_cpu(self.last_signaled_value, None)?;
+        self.fence.signal(0)?;
+        self.last_signaled_value = 0;
+
+        Ok(())
+    }
+
+    fn next_value(&self, func_name: &'static str) -> DxResult<u64> {
+        match self.last_signaled_value.checked_add(1) {
+            Some(value) => Ok(value),
+            None => {
+                error!("Timeline value overflow, rebase is required");
+                Err(DxError::new(func_name, winerror::E_FAIL))
+            }
+        }
+    }
+
+    fn check_monotonic(
+        &self,
+        func_name: &'static str,
+        value: u64,
+    ) -> DxResult<()> {
+        if cfg!(debug_assertions) && value <= self.last_signaled_value {
+            error!(
+                "{}: value {} is not greater than the last signaled value {}",
+                func_name, value, self.last_signaled_value
+            );
+            return Err(DxError::new(func_name, winerror::E_INVALIDARG));
+        }
+
+        Ok(())
+    }
+
+    fn check_signaled(
+        &self,
+        func_name: &'static str,
+        value: u64,
+    ) -> DxResult<()> {
+        if cfg!(debug_assertions) && value > self.last_signaled_value {
+            error!(
+                "{}: value {} has not been signaled yet (last signaled \
+                 value is {})",
+                func_name, value, self.last_signaled_value
+            );
+            return Err(DxError::new(func_name, winerror::E_INVALIDARG));
+        }
+
+        Ok(())
+    }
+
+    fn set_last_signaled_value(&mut self, value: u64) {
+        if value > TIMELINE_REBASE_THRESHOLD
+            && self.last_signaled_value <= TIMELINE_REBASE_THRESHOLD
+        {
+            warn!(
+                "Timeline value {} is close to overflowing, it should be \
+                 rebased",
+                value
+            );
+        }
+        self.last_signaled_value = value;
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::VecDeque;
+use std::mem::size_of_val;
+
+use log::error;
+use winapi::shared::winerror;
+
+use crate::{
+    ByteCount, CommandList, CopyCapable, Device, DxError, DxResult,
+    GpuVirtualAddress, HeapFlags, HeapProperties, HeapType, Metric, Metrics,
+    Resource, ResourceDesc, ResourceDimension, ResourceStates, TextureLayout,
+    CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT,
+};
+
+/// A chunk of an [UploadHeapRing], valid until the frame it has been
+/// allocated in is released
+#[derive(Debug, Clone, Copy)]
+pub struct UploadAllocation {
+    cpu_ptr: *mut u8,
+    gpu_address: GpuVirtualAddress,
+    offset: ByteCount,
+    size: ByteCount,
+}
+
+impl UploadAllocation {
+    /// Write-combined memory: it should be written sequentially
+    /// and never read from
+    pub fn cpu_ptr(&self) -> *mut u8 {
+        self.cpu_ptr
+    }
+
+    /// E.g. for set_graphics_root_constant_buffer_view
+    pub fn gpu_address(&self) -> GpuVirtualAddress {
+        self.gpu_address
+    }
+
+    /// Offset from the start of [UploadHeapRing::buffer], e.g. to pass it
+    /// as the intermediate offset to update_subresources_heap_alloc
+    pub fn offset(&self) -> ByteCount {
+        self.offset
+    }
+
+    pub fn size(&self) -> ByteCount {
+        self.size
+    }
+}
+
+/// A persistently mapped upload buffer used as a linear allocator for
+/// per-frame data such as dynamic constants, vertices or staging copies,
+/// instead of creating a committed resource for every upload. Chunks
+/// allocated during a frame are retired once the fence value passed to
+/// [UploadHeapRing::finish_frame] has been reached. Every chunk is aligned
+/// to 256 bytes, so that it can be bound as a constant buffer
+#[derive(Debug)]
+pub struct UploadHeapRing {
+    buffer: Resource,
+    mapped_data: *mut u8,
+    gpu_start: GpuVirtualAddress,
+    capacity: u64,
+    head: u64,
+    used: u64,
+    high_water_mark: u64,
+    current_frame_used: u64,
+    // (fence value, bytes consumed) pairs of the frames in flight
+    pending_frames: VecDeque<(u64, u64)>,
+}
+
+impl UploadHeapRing {
+    /// `size` is rounded up to a multiple of 256 bytes
+    pub fn new(device: &Device, size: ByteCount) -> DxResult<Self> {
+        if size.0 == 0 {
+            return Err(DxError::new(
+                "UploadHeapRing::new",
+                winerror::E_INVALIDARG,
+            ));
+        }
+
+        let capacity =
+            size.align_up(CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT).0;
+
+        let buffer = device.create_committed_resource(
+            &HeapProperties::default().with_heap_type(HeapType::Upload),
+            HeapFlags::None,
+            &ResourceDesc::default()
+                .with_dimension(ResourceDimension::Buffer)
+                .with_width(capacity)
+                .with_layout(TextureLayout::RowMajor),
+            ResourceStates::GenericRead,
+            None,
+        )?;
+        if let Some(name) = device.make_object_name("Upload heap ring buffer") {
+            buffer.set_name(&name)?;
+        }
+
+        // upload heaps can stay mapped while the GPU reads from them
+        let mapped_data = buffer.map(0, None)?;
+
+        Ok(Self {
+            gpu_start: buffer.get_gpu_virtual_address(),
+            buffer,
+            mapped_data,
+            capacity,
+            head: 0,
+            used: 0,
+            high_water_mark: 0,
+            current_frame_used: 0,
+            pending_frames: VecDeque::new(),
+        })
+    }
+
+    /// Allocates a 256-byte-aligned chunk of `size` bytes
+    pub fn allocate(&mut self, size: ByteCount) -> DxResult<UploadAllocation> {
+        self.allocate_aligned(size, CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT)
+    }
+
+    /// Allocates a chunk with a stricter alignment than 256 bytes, e.g.
+    /// TEXTURE_DATA_PLACEMENT_ALIGNMENT for texture uploads
+    pub fn allocate_aligned(
+        &mut self,
+        size: ByteCount,
+        alignment: ByteCount,
+    ) -> DxResult<UploadAllocation> {
+        let alignment = alignment.max(CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT);
+        let size = size.align_up(CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT).0;
+
+        // chunks cannot wrap around, so the tail of the buffer is skipped
+        let aligned_head = ByteCount(self.head).align_up(alignment).0;
+        let (start, padding) = match aligned_head + size > self.capacity {
+            true => (0, self.capacity - self.head),
+            false => (aligned_head, aligned_head - self.head),
+        };
+
+        if size == 0 || self.used + padding + size > self.capacity {
+            error!(
+                "Upload heap ring cannot fit {} bytes ({} of {} used)",
+                size, self.used, self.capacity
+            );
+            return Err(DxError::new(
+                "UploadHeapRing::allocate",
+                winerror::E_OUTOFMEMORY,
+            ));
+        }
+
+        self.head = (start + size) % self.capacity;
+        self.used += padding + size;
+        self.current_frame_used += padding + size;
+        self.high_water_mark = self.high_water_mark.max(self.used);
+
+        Ok(UploadAllocation {
+            cpu_ptr: unsafe { self.mapped_data.add(start as usize) },
+            gpu_address: self.gpu_start + ByteCount(start),
+            offset: ByteCount(start),
+            size: ByteCount(size),
+        })
+    }
+
+    /// Allocates a chunk and copies `data` into it
+    pub fn upload<T: Copy>(
+        &mut self,
+        data: &[T],
+    ) -> DxResult<UploadAllocation> {
+        let allocation = self.allocate(ByteCount(size_of_val(data) as u64))?;
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                data.as_ptr() as *const u8,
+                allocation.cpu_ptr,
+                size_of_val(data),
+            );
+        }
+
+        Ok(allocation)
+    }
+
+    /// Uploads a constant buffer structure and returns its address,
+    /// ready to be bound as a root CBV
+    pub fn upload_constants<T: Copy>(
+        &mut self,
+        constants: &T,
+    ) -> DxResult<GpuVirtualAddress> {
+        Ok(self.upload(std::slice::from_ref(constants))?.gpu_address)
+    }
+
+    /// Uploads `data` and records a copy of it into `dest` at `dest_offset`
+    pub fn upload_to_buffer<T: Copy, K: CopyCapable>(
+        &mut self,
+        command_list: &CommandList<K>,
+        dest: &Resource,
+        dest_offset: ByteCount,
+        data: &[T],
+    ) -> DxResult<()> {
+        let allocation = self.upload(data)?;
+        command_list.copy_buffer_region(
+            dest,
+            dest_offset,
+            &self.buffer,
+            allocation.offset,
+            ByteCount(size_of_val(data) as u64),
+        );
+
+        Ok(())
+    }
+
+    /// Marks the end of the frame; its chunks will be reused
+    /// once `fence_value` is passed to [UploadHeapRing::release_completed]
+    pub fn finish_frame(&mut self, fence_value: u64) {
+        self.pending_frames
+            .push_back((fence_value, self.current_frame_used));
+        self.current_frame_used = 0;
+    }
+
+    pub fn release_completed(&mut self, completed_fence_value: u64) {
+        while let Some(&(fence_value, frame_used)) = self.pending_frames.front()
+        {
+            if fence_value > completed_fence_value {
+                break;
+            }
+
+            self.used -= frame_used;
+            self.pending_frames.pop_front();
+        }
+    }
+
+    pub fn buffer(&self) -> &Resource {
+        &self.buffer
+    }
+
+    pub fn capacity(&self) -> ByteCount {
+        ByteCount(self.capacity)
+    }
+
+    /// Bytes used by the frames which haven't been released yet,
+    /// including the current one and the alignment padding
+    pub fn used(&self) -> ByteCount {
+        ByteCount(self.used)
+    }
+
+    /// The largest value [UploadHeapRing::used] has ever reached,
+    /// useful for sizing the ring
+    pub fn high_water_mark(&self) -> ByteCount {
+        ByteCount(self.high_water_mark)
+    }
+}
+
+impl Metrics for UploadHeapRing {
+    fn metrics(&self) -> Vec<Metric> {
+        vec![
+            Metric::new("used bytes", self.used as f64),
+            Metric::new("high water mark", self.high_water_mark as f64),
+            Metric::new("frames in flight", self.pending_frames.len() as u32),
+            Metric::new("capacity", self.capacity as f64),
+        ]
+    }
+}
+
+impl Drop for UploadHeapRing {
+    fn drop(&mut self) {
+        self.buffer.unmap(0, None);
+    }
+}
+
+// the mapped pointer is only written through &mut self
+unsafe impl Send for UploadHeapRing {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fake_resource;
+
+    const CAPACITY: u64 = 1024;
+
+    // The ring only does pointer arithmetic on the mapped memory, so
+    // a plain allocation stands in for the upload buffer
+    fn test_ring(memory: &mut [u8]) -> UploadHeapRing {
+        UploadHeapRing {
+            buffer: fake_resource(),
+            mapped_data: memory.as_mut_ptr(),
+            gpu_start: GpuVirtualAddress(0x1_0000),
+            capacity: CAPACITY,
+            head: 0,
+            used: 0,
+            high_water_mark: 0,
+            current_frame_used: 0,
+            pending_frames: VecDeque::new(),
+        }
+    }
+
+    #[test]
+    fn allocations_are_aligned() {
+        let mut memory = vec![0; CAPACITY as usize];
+        let mut ring = test_ring(&mut memory);
+
+        let first = ring.allocate(ByteCount(10)).unwrap();
+        let second = ring
+            .allocate_aligned(ByteCount(300), ByteCount(512))
+            .unwrap();
+
+        assert_eq!(first.offset(), ByteCount(0));
+        assert_eq!(first.size(), ByteCount(256));
+        assert_eq!(second.offset(), ByteCount(512));
+        assert_eq!(second.size(), ByteCount(512));
+        assert_eq!(second.gpu_address(), GpuVirtualAddress(0x1_0200));
+        // the padding before the second chunk is used as well
+        assert_eq!(ring.used(), ByteCount(CAPACITY));
+        assert!(ring.allocate(ByteCount(1)).is_err());
+        assert!(ring.allocate(ByteCount(0)).is_err());
+    }
+
+    #[test]
+    fn frames_are_released_by_fence_value() {
+        let mut memory = vec![0; CAPACITY as usize];
+        let mut ring = test_ring(&mut memory);
+
+        ring.allocate(ByteCount(512)).unwrap();
+        ring.finish_frame(1);
+        ring.allocate(ByteCount(512)).unwrap();
+        ring.finish_frame(2);
+        assert!(ring.allocate(ByteCount(256)).is_err());
+
+        ring.release_completed(1);
+        assert_eq!(ring.used(), ByteCount(512));
+
+        // the head has wrapped around to the start of the buffer
+        let allocation = ring.allocate(ByteCount(256)).unwrap();
+        assert_eq!(allocation.offset(), ByteCount(0));
+
+        ring.release_completed(2);
+        assert_eq!(ring.used(), ByteCount(256));
+        assert_eq!(ring.high_water_mark(), ByteCount(CAPACITY));
+    }
+
+    #[test]
+    fn skipped_tail_counts_as_used() {
+        let mut memory = vec![0; CAPACITY as usize];
+        let mut ring = test_ring(&mut memory);
+
+        ring.allocate(ByteCount(768)).unwrap();
+        ring.finish_frame(1);
+        ring.release_completed(1);
+
+        // 256 bytes are left at the tail, which is too little for 512
+        let allocation = ring.allocate(ByteCount(512)).unwrap();
+        assert_eq!(allocation.offset(), ByteCount(0));
+        assert_eq!(ring.used(), ByteCount(768));
+    }
+}
@@ -3,7 +3,9 @@ use log::{debug, error, info, trace, warn};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{DxError, DxResult};
+use std::ops::Range;
+
+use crate::{BarrierSubresourceRange, DxError, DxResult, ResourceDesc};
 
 // ToDo: impl iterators
 
@@ -53,9 +55,9 @@ macro_rules! impl_mul_div {
     };
 }
 
-// ToDo: get rid of it in favor of usize??
-/// A newtype around [u64] made to distinguish between element counts and byte sizes in APIs
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+/// A newtype around [u64] made to distinguish between element counts and byte sizes in APIs.
+/// All byte sizes, offsets, pitches and alignments are expressed with it
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ByteCount(pub u64);
 
@@ -74,6 +76,87 @@ impl std::ops::AddAssign<ByteCount> for ByteCount {
     }
 }
 
+// ByteCount - ByteCount = ByteCount
+impl std::ops::Sub<ByteCount> for ByteCount {
+    type Output = Self;
+
+    fn sub(self, rhs: ByteCount) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::SubAssign<ByteCount> for ByteCount {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = Self(self.0 - rhs.0);
+    }
+}
+
+impl std::iter::Sum for ByteCount {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ByteCount(0), |sum, value| sum + value)
+    }
+}
+
+impl ByteCount {
+    /// Rounds the value up to the nearest multiple of `alignment`, which
+    /// must be a power of two, e.g.
+    /// `size.align_up(CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT)`
+    pub fn align_up(self, alignment: ByteCount) -> Self {
+        debug_assert!(
+            alignment.0.is_power_of_two(),
+            "Alignment {} is not a power of two",
+            alignment.0
+        );
+
+        Self((self.0 + (alignment.0 - 1)) & !(alignment.0 - 1))
+    }
+
+    /// Rounds the value down to the nearest multiple of `alignment`, which
+    /// must be a power of two
+    pub fn align_down(self, alignment: ByteCount) -> Self {
+        debug_assert!(
+            alignment.0.is_power_of_two(),
+            "Alignment {} is not a power of two",
+            alignment.0
+        );
+
+        Self(self.0 & !(alignment.0 - 1))
+    }
+
+    pub fn is_aligned(self, alignment: ByteCount) -> bool {
+        self.align_down(alignment) == self
+    }
+
+    pub fn checked_sub(self, rhs: ByteCount) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn saturating_sub(self, rhs: ByteCount) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+/// Prints exact values below 1 KiB and binary units with two decimals
+/// above it, e.g. "512 B" or "1.50 MiB"
+impl std::fmt::Display for ByteCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut value = self.0 as f64 / 1024.;
+        let mut unit = 0;
+        while value >= 1024. && unit < UNITS.len() - 1 {
+            value /= 1024.;
+            unit += 1;
+        }
+
+        write!(f, "{:.2} {}", value, UNITS[unit])
+    }
+}
+
 impl_mul_div!(ByteCount, u8);
 impl_mul_div!(ByteCount, i8);
 impl_mul_div!(ByteCount, u16);
@@ -100,6 +183,12 @@ impl Into<usize> for ByteCount {
     }
 }
 
+impl From<ByteCount> for u64 {
+    fn from(value: ByteCount) -> Self {
+        value.0
+    }
+}
+
 impl_from!(ByteCount, u8);
 impl_from!(ByteCount, i8);
 impl_from!(ByteCount, u16);
@@ -111,6 +200,287 @@ impl_from!(ByteCount, i64);
 impl_from!(ByteCount, usize);
 impl_from!(ByteCount, isize);
 
+/// An RGBA color with float components, e.g. a render target clear color
+#[derive(PartialEq, PartialOrd, Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Color(pub [f32; 4]);
+
+impl Color {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self([r, g, b, a])
+    }
+
+    pub fn r(&self) -> f32 {
+        self.0[0]
+    }
+
+    pub fn g(&self) -> f32 {
+        self.0[1]
+    }
+
+    pub fn b(&self) -> f32 {
+        self.0[2]
+    }
+
+    pub fn a(&self) -> f32 {
+        self.0[3]
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(rgba: [f32; 4]) -> Self {
+        Self(rgba)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        color.0
+    }
+}
+
+/// Unpacks a 0xRRGGBBAA value, each channel is mapped to [0, 1] range
+impl From<u32> for Color {
+    fn from(rgba: u32) -> Self {
+        let channel = |shift: u32| ((rgba >> shift) & 0xFF) as f32 / 255.;
+        Self([channel(24), channel(16), channel(8), channel(0)])
+    }
+}
+
+/// Mip level, array slice and plane of a single subresource, which
+/// [SubresourceIndex::calc_subresource] turns into the flat index
+/// the API expects and [SubresourceIndex::from_subresource] decomposes
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct SubresourceIndex {
+    pub mip: u32,
+    pub array_slice: u32,
+    pub plane: u32,
+}
+
+impl SubresourceIndex {
+    pub fn new(mip: u32, array_slice: u32, plane: u32) -> Self {
+        Self {
+            mip,
+            array_slice,
+            plane,
+        }
+    }
+
+    /// A mip level of a non-array, non-planar resource
+    pub fn mip(mip: u32) -> Self {
+        Self::new(mip, 0, 0)
+    }
+
+    /// `mip_levels` and `array_size` are the ones of the whole resource,
+    /// see [calc_subresource]
+    pub fn calc_subresource(&self, mip_levels: u32, array_size: u32) -> u32 {
+        debug_assert!(
+            self.mip < mip_levels && self.array_slice < array_size,
+            "Subresource {:?} is out of range of a resource with {} mips \
+             and {} array slices",
+            self,
+            mip_levels,
+            array_size
+        );
+        calc_subresource(
+            self.mip,
+            self.array_slice,
+            self.plane,
+            mip_levels,
+            array_size,
+        )
+    }
+
+    /// Same as [SubresourceIndex::calc_subresource] with the mip levels
+    /// and the array size taken from `desc`
+    pub fn subresource_in(&self, desc: &ResourceDesc) -> u32 {
+        self.calc_subresource(desc.mip_levels() as u32, desc.array_size())
+    }
+
+    /// Inverse of [SubresourceIndex::calc_subresource]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mip_levels` or `array_size` is zero
+    pub fn from_subresource(
+        subresource: u32,
+        mip_levels: u32,
+        array_size: u32,
+    ) -> Self {
+        assert!(
+            mip_levels > 0 && array_size > 0,
+            "Cannot decompose a subresource index of a resource with {} mip \
+             level(s) and {} array slice(s)",
+            mip_levels,
+            array_size
+        );
+
+        Self::new(
+            subresource % mip_levels,
+            (subresource / mip_levels) % array_size,
+            subresource / (mip_levels * array_size),
+        )
+    }
+}
+
+/// A set of subresources given as half-open ranges of mip levels, array
+/// slices and planes. Planes are only meaningful for planar formats, e.g.
+/// depth-stencil formats keep the depth in plane 0 and the stencil
+/// in plane 1, for all other formats the plane range is `0..1`
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct SubresourceRange {
+    pub mips: Range<u32>,
+    pub array_slices: Range<u32>,
+    pub planes: Range<u32>,
+}
+
+impl SubresourceRange {
+    pub fn new(
+        mips: Range<u32>,
+        array_slices: Range<u32>,
+        planes: Range<u32>,
+    ) -> Self {
+        Self {
+            mips,
+            array_slices,
+            planes,
+        }
+    }
+
+    /// All mips and array slices of the plane 0 of the resource,
+    /// use [SubresourceRange::with_planes] for planar formats
+    pub fn all(desc: &ResourceDesc) -> Self {
+        Self::new(0..desc.mip_levels() as u32, 0..desc.array_size(), 0..1)
+    }
+
+    /// A single mip level of a non-array, non-planar resource
+    pub fn mip(mip: u32) -> Self {
+        Self::new(mip..mip + 1, 0..1, 0..1)
+    }
+
+    /// The subresource with the given index, see [calc_subresource]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mip_levels` or `array_size` is zero
+    pub fn from_subresource_index(
+        index: u32,
+        mip_levels: u32,
+        array_size: u32,
+    ) -> Self {
+        SubresourceIndex::from_subresource(index, mip_levels, array_size).into()
+    }
+
+    pub fn with_mips(mut self, mips: Range<u32>) -> Self {
+        self.mips = mips;
+        self
+    }
+
+    pub fn with_array_slices(mut self, array_slices: Range<u32>) -> Self {
+        self.array_slices = array_slices;
+        self
+    }
+
+    pub fn with_planes(mut self, planes: Range<u32>) -> Self {
+        self.planes = planes;
+        self
+    }
+
+    pub fn subresource_count(&self) -> u32 {
+        self.mips.len() as u32
+            * self.array_slices.len() as u32
+            * self.planes.len() as u32
+    }
+
+    /// Subresource indices in ascending order, `mip_levels` and
+    /// `array_size` are the ones of the whole resource
+    pub fn subresource_indices(
+        &self,
+        mip_levels: u32,
+        array_size: u32,
+    ) -> impl Iterator<Item = u32> + '_ {
+        self.planes.clone().flat_map(move |plane| {
+            self.array_slices.clone().flat_map(move |array_slice| {
+                self.mips.clone().map(move |mip| {
+                    calc_subresource(
+                        mip,
+                        array_slice,
+                        plane,
+                        mip_levels,
+                        array_size,
+                    )
+                })
+            })
+        })
+    }
+
+    /// Returns the first subresource index and the subresource count if
+    /// the range maps to consecutive indices, which is required by the APIs
+    /// accepting (first_subresource, num_subresources) pairs
+    pub fn as_contiguous(
+        &self,
+        mip_levels: u32,
+        array_size: u32,
+    ) -> Option<(u32, u32)> {
+        let all_mips = self.mips == (0..mip_levels);
+        let all_array_slices = self.array_slices == (0..array_size);
+
+        let is_contiguous = match (all_mips, all_array_slices) {
+            (true, true) => true,
+            (true, false) => self.planes.len() <= 1,
+            (false, _) => {
+                self.array_slices.len() <= 1 && self.planes.len() <= 1
+            }
+        };
+        if !is_contiguous {
+            return None;
+        }
+
+        let first = calc_subresource(
+            self.mips.start,
+            self.array_slices.start,
+            self.planes.start,
+            mip_levels,
+            array_size,
+        );
+        Some((first, self.subresource_count()))
+    }
+}
+
+impl From<SubresourceIndex> for SubresourceRange {
+    fn from(index: SubresourceIndex) -> Self {
+        Self::new(
+            index.mip..index.mip + 1,
+            index.array_slice..index.array_slice + 1,
+            index.plane..index.plane + 1,
+        )
+    }
+}
+
+impl From<&SubresourceRange> for BarrierSubresourceRange {
+    fn from(range: &SubresourceRange) -> Self {
+        BarrierSubresourceRange::default()
+            .with_index_or_first_mip_level(range.mips.start)
+            .with_num_mip_levels(range.mips.len() as u32)
+            .with_first_array_slice(range.array_slices.start)
+            .with_num_array_slices(range.array_slices.len() as u32)
+            .with_first_plane(range.planes.start)
+            .with_num_planes(range.planes.len() as u32)
+    }
+}
+
+/// Same as D3D12CalcSubresource from d3dx12.h
+pub fn calc_subresource(
+    mip: u32,
+    array_slice: u32,
+    plane: u32,
+    mip_levels: u32,
+    array_size: u32,
+) -> u32 {
+    mip + array_slice * mip_levels + plane * mip_levels * array_size
+}
+
 pub fn compile_shader(
     name: &str,
     source: &str,
@@ -143,10 +513,6 @@ pub fn compile_shader(
     }
 }
 
-pub fn align_to_multiple(value: u64, alignment: u64) -> u64 {
-    (value + (alignment - 1)) & (!(alignment - 1))
-}
-
 /// A macro similar to [std::mem::size_of] function, but returns [ByteCount] instead of [usize]
 #[macro_export]
 macro_rules! size_of {
@@ -154,3 +520,330 @@ macro_rules! size_of {
         ByteCount::from(std::mem::size_of::<$struct_type>())
     };
 }
+
+/// Compiles a shader and writes its bytecode along with a generated Rust
+/// module containing metadata constants (thread group size and root
+/// parameter indices, if they can be deduced from the source) into `OUT_DIR`.
+/// Intended to be called from build scripts; the results can be embedded
+/// via [include_dxil!] macro
+#[cfg(feature = "dxc")]
+pub fn export_shader(
+    name: &str,
+    source_path: &str,
+    entry_point: &str,
+    shader_model: &str,
+    args: &[&str],
+    defines: &[(&str, Option<&str>)],
+) -> DxResult<()> {
+    let out_dir = std::env::var("OUT_DIR").map_err(|_| {
+        error!(
+            "OUT_DIR is not set, export_shader must be called from build.rs"
+        );
+        DxError::new("std::env::var", winapi::shared::winerror::E_FAIL)
+    })?;
+
+    let source = std::fs::read_to_string(source_path).map_err(|err| {
+        error!("Cannot read shader source {}: {}", source_path, err);
+        DxError::new(
+            "std::fs::read_to_string",
+            winapi::shared::winerror::E_FAIL,
+        )
+    })?;
+
+    let bytecode = compile_shader(
+        name,
+        &source,
+        entry_point,
+        shader_model,
+        args,
+        defines,
+    )?;
+
+    let mut module = format!(
+        "pub mod {} {{\n    pub const BYTECODE: &[u8] = \
+         include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{}.dxil\"));\n",
+        name, name
+    );
+
+    if let Some([x, y, z]) = parse_thread_group_size(&source, entry_point) {
+        module += &format!(
+            "    pub const THREAD_GROUP_SIZE: [u32; 3] = [{}, {}, {}];\n",
+            x, y, z
+        );
+    }
+
+    for (index, parameter_name) in parse_root_parameters(&source, entry_point)
+        .iter()
+        .enumerate()
+    {
+        module += &format!(
+            "    pub const ROOT_{}: u32 = {};\n",
+            parameter_name, index
+        );
+    }
+    module += "}\n";
+
+    let write_result = std::fs::write(
+        std::path::Path::new(&out_dir).join(format!("{}.dxil", name)),
+        bytecode,
+    )
+    .and_then(|_| {
+        std::fs::write(
+            std::path::Path::new(&out_dir).join(format!("{}.dxil.rs", name)),
+            module,
+        )
+    });
+    if let Err(err) = write_result {
+        error!("Cannot write exported shader {}: {}", name, err);
+        return Err(DxError::new(
+            "std::fs::write",
+            winapi::shared::winerror::E_FAIL,
+        ));
+    }
+
+    println!("cargo:rerun-if-changed={}", source_path);
+    Ok(())
+}
+
+/// Embeds a shader exported from a build script via [export_shader]. Expands
+/// to a module named after the shader which contains `BYTECODE` constant and
+/// the generated metadata constants, e.g. `THREAD_GROUP_SIZE` and `ROOT_CBV_B0`
+#[cfg(feature = "dxc")]
+#[macro_export]
+macro_rules! include_dxil {
+    ($name:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $name, ".dxil.rs"));
+    };
+}
+
+// Returns the offsets of whole identifiers only, so that `main` is not found
+// in `domain` and `RS` is not found in `RS_X`
+#[cfg(feature = "dxc")]
+fn find_identifier<'s>(
+    source: &'s str,
+    identifier: &'s str,
+) -> impl Iterator<Item = usize> + 's {
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    source
+        .match_indices(identifier)
+        .map(|(index, _)| index)
+        .filter(move |&index| {
+            !identifier.is_empty()
+                && !source[..index].ends_with(is_identifier_char)
+                && !source[index + identifier.len()..]
+                    .starts_with(is_identifier_char)
+        })
+}
+
+// Returns the attribute block (everything between the previous declaration
+// and the entry point signature)
+#[cfg(feature = "dxc")]
+fn find_entry_point_attributes<'s>(
+    source: &'s str,
+    entry_point: &str,
+) -> Option<&'s str> {
+    let signature_start =
+        find_identifier(source, entry_point).find(|&index| {
+            source[index + entry_point.len()..]
+                .trim_start()
+                .starts_with('(')
+        })?;
+    let attributes_start = source[..signature_start]
+        .rfind(|c| c == ';' || c == '}')
+        .map_or(0, |index| index + 1);
+
+    Some(&source[attributes_start..signature_start])
+}
+
+#[cfg(feature = "dxc")]
+fn parse_thread_group_size(
+    source: &str,
+    entry_point: &str,
+) -> Option<[u32; 3]> {
+    let attributes = find_entry_point_attributes(source, entry_point)?;
+    let arguments_start =
+        find_identifier(attributes, "numthreads").next()? + "numthreads".len();
+    let arguments = &attributes[arguments_start..];
+    let arguments =
+        &arguments[arguments.find('(')? + 1..arguments.find(')')?];
+
+    let mut size = [0u32; 3];
+    let mut components = arguments.split(',');
+    for component in size.iter_mut() {
+        *component = components.next()?.trim().parse().ok()?;
+    }
+
+    Some(size)
+}
+
+// Root parameter indices follow the order of the root signature entries
+// (except for flags and static samplers), so they can be deduced from
+// the root signature attribute, given that it refers to a #define'd string
+#[cfg(feature = "dxc")]
+fn parse_root_parameters(source: &str, entry_point: &str) -> Vec<String> {
+    let root_signature = find_entry_point_attributes(source, entry_point)
+        .and_then(|attributes| {
+            let arguments_start = find_identifier(attributes, "RootSignature")
+                .next()?
+                + "RootSignature".len();
+            let arguments = &attributes[arguments_start..];
+            let macro_name = arguments
+                [arguments.find('(')? + 1..arguments.find(')')?]
+                .trim();
+
+            let define_start =
+                find_identifier(source, macro_name).find(|&index| {
+                    source[..index]
+                        .trim_end_matches(|c| c == ' ' || c == '\t')
+                        .ends_with("#define")
+                })? + macro_name.len();
+            let mut definition = String::new();
+            for line in source[define_start..].lines() {
+                let line = line.trim_end();
+                definition += line.trim_end_matches('\\');
+                if !line.ends_with('\\') {
+                    break;
+                }
+            }
+
+            // concatenate string literals
+            Some(definition.split('"').skip(1).step_by(2).collect::<String>())
+        });
+
+    let root_signature = match root_signature {
+        Some(root_signature) => root_signature,
+        None => return vec![],
+    };
+
+    let mut entries = vec![];
+    let mut depth = 0;
+    let mut entry_start = 0;
+    for (index, c) in root_signature.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                entries.push(root_signature[entry_start..index].trim());
+                entry_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(root_signature[entry_start..].trim());
+
+    let mut parameter_names = vec![];
+    for entry in entries {
+        let kind = entry.split('(').next().unwrap_or("").trim();
+        let register = entry
+            .split(|c: char| c == '(' || c == ',' || c == ')')
+            .map(str::trim)
+            .find(|token| {
+                token.len() > 1
+                    && token.starts_with(|c| "btus".contains(c))
+                    && token[1..].chars().all(|c| c.is_ascii_digit())
+            })
+            .unwrap_or("")
+            .to_uppercase();
+
+        let prefix = match kind {
+            "CBV" => "CBV",
+            "SRV" => "SRV",
+            "UAV" => "UAV",
+            "RootConstants" => "CONSTANTS",
+            "DescriptorTable" => "TABLE",
+            _ => continue,
+        };
+
+        parameter_names.push(match register.is_empty() {
+            true => format!("{}_{}", prefix, parameter_names.len()),
+            false => format!("{}_{}", prefix, register),
+        });
+    }
+
+    parameter_names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_count_alignment() {
+        let alignment = ByteCount(256);
+
+        assert_eq!(ByteCount(0).align_up(alignment), ByteCount(0));
+        assert_eq!(ByteCount(1).align_up(alignment), ByteCount(256));
+        assert_eq!(ByteCount(256).align_up(alignment), ByteCount(256));
+        assert_eq!(ByteCount(257).align_up(alignment), ByteCount(512));
+
+        assert_eq!(ByteCount(255).align_down(alignment), ByteCount(0));
+        assert_eq!(ByteCount(511).align_down(alignment), ByteCount(256));
+
+        assert!(ByteCount(0).is_aligned(alignment));
+        assert!(ByteCount(1024).is_aligned(alignment));
+        assert!(!ByteCount(1000).is_aligned(alignment));
+        assert!(ByteCount(3).is_aligned(ByteCount(1)));
+    }
+
+    #[test]
+    fn byte_count_arithmetic() {
+        assert_eq!(ByteCount(3).checked_sub(ByteCount(4)), None);
+        assert_eq!(ByteCount(4).checked_sub(ByteCount(3)), Some(ByteCount(1)));
+        assert_eq!(ByteCount(3).saturating_sub(ByteCount(4)), ByteCount(0));
+        assert_eq!(ByteCount(512).to_string(), "512 B");
+        assert_eq!(ByteCount(1536).to_string(), "1.50 KiB");
+    }
+
+    #[test]
+    fn subresource_index_round_trip() {
+        let (mip_levels, array_size) = (4, 3);
+        for subresource in 0..mip_levels * array_size * 2 {
+            let index = SubresourceIndex::from_subresource(
+                subresource,
+                mip_levels,
+                array_size,
+            );
+            assert!(index.mip < mip_levels);
+            assert!(index.array_slice < array_size);
+            assert_eq!(
+                index.calc_subresource(mip_levels, array_size),
+                subresource
+            );
+        }
+
+        assert_eq!(
+            SubresourceIndex::new(1, 2, 1).calc_subresource(4, 3),
+            1 + 2 * 4 + 4 * 3
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn subresource_index_rejects_zero_mip_levels() {
+        SubresourceIndex::from_subresource(0, 0, 1);
+    }
+
+    #[cfg(feature = "dxc")]
+    #[test]
+    fn shader_attributes_match_whole_identifiers() {
+        let source = r#"
+            #define RS_X "CBV(b1)"
+            #define RS "RootConstants(num32BitConstants=4, b0), " \
+                       "SRV(t2)"
+
+            [domain("tri")]
+            void domain_main() {}
+
+            [RootSignature(RS)]
+            [numthreads(8, 4, 1)]
+            void main(uint3 id : SV_DispatchThreadID) {}
+        "#;
+
+        assert_eq!(parse_thread_group_size(source, "main"), Some([8, 4, 1]));
+        assert_eq!(
+            parse_root_parameters(source, "main"),
+            vec!["CONSTANTS_B0", "SRV_T2"]
+        );
+        assert_eq!(parse_thread_group_size(source, "mai"), None);
+    }
+}
@@ -0,0 +1,691 @@
+// Checks performed before recording copies, indirect executions and draws
+// when `validation` feature is enabled, since out-of-bounds accesses and
+// mismatched bindings otherwise tend to surface only as device removal or
+// cryptic debug layer messages
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{
+    Box, ByteCount, CommandListType, CommandSignature, CpuDescriptorHandle,
+    Format, GraphicsPipelineStateDesc, PipelineState, PrimitiveTopology,
+    PrimitiveTopologyType, Resource, ResourceDesc, ResourceDimension,
+    TextureCopyLocation, TextureCopyType, TileCopyFlags, TileRegionSize,
+    TEXTURE_DATA_PITCH_ALIGNMENT, TEXTURE_DATA_PLACEMENT_ALIGNMENT,
+    TILED_RESOURCE_TILE_SIZE,
+};
+
+// Offsets into argument and count buffers of ExecuteIndirect
+const INDIRECT_ARGUMENT_ALIGNMENT: ByteCount = ByteCount(4);
+
+// width, height, depth
+type Extent = (u32, u32, u32);
+
+fn is_block_compressed(format: Format) -> bool {
+    matches!(
+        format,
+        Format::Bc1Typeless
+            | Format::Bc1Unorm
+            | Format::Bc1UnormSrgb
+            | Format::Bc2Typeless
+            | Format::Bc2Unorm
+            | Format::Bc2UnormSrgb
+            | Format::Bc3Typeless
+            | Format::Bc3Unorm
+            | Format::Bc3UnormSrgb
+            | Format::Bc4Typeless
+            | Format::Bc4Unorm
+            | Format::Bc4Snorm
+            | Format::Bc5Typeless
+            | Format::Bc5Unorm
+            | Format::Bc5Snorm
+            | Format::Bc6HTypeless
+            | Format::Bc6HUf16
+            | Format::Bc6HSf16
+            | Format::Bc7Typeless
+            | Format::Bc7Unorm
+            | Format::Bc7UnormSrgb
+    )
+}
+
+// Width and height of the texel blocks of the format. The extents of
+// subresources are rounded up to them, e.g. the 2x2 and 1x1 mips of a BC
+// texture still occupy a whole 4x4 block
+fn block_size(format: Format) -> (u32, u32) {
+    if is_block_compressed(format) {
+        return (4, 4);
+    }
+
+    match format {
+        Format::R8G8B8G8Unorm
+        | Format::G8R8G8B8Unorm
+        | Format::Yuy2
+        | Format::Y210
+        | Format::Y216 => (2, 1),
+        _ => (1, 1),
+    }
+}
+
+fn round_up_to_block(extent: u32, block_extent: u32) -> u32 {
+    extent.saturating_add(block_extent - 1) / block_extent * block_extent
+}
+
+fn subresource_extent(
+    desc: &ResourceDesc,
+    subresource: u32,
+) -> Result<Extent, String> {
+    let mip_levels = desc.mip_levels().max(1) as u32;
+    let array_size = match desc.dimension() {
+        ResourceDimension::Texture3D => 1,
+        _ => desc.depth_or_array_size() as u32,
+    };
+    let subresource_count =
+        mip_levels * array_size * desc.format().plane_count();
+    if subresource >= subresource_count {
+        return Err(format!(
+            "subresource index {} is out of bounds, resource has {} subresources",
+            subresource, subresource_count
+        ));
+    }
+
+    let mip = subresource % mip_levels;
+    let width = ((desc.width() >> mip) as u32).max(1);
+    let height = match desc.dimension() {
+        ResourceDimension::Texture1D => 1,
+        _ => (desc.height() >> mip).max(1),
+    };
+    let depth = match desc.dimension() {
+        ResourceDimension::Texture3D => {
+            ((desc.depth_or_array_size() as u32) >> mip).max(1)
+        }
+        _ => 1,
+    };
+    let (block_width, block_height) = block_size(desc.format());
+
+    Ok((
+        round_up_to_block(width, block_width),
+        round_up_to_block(height, block_height),
+        depth,
+    ))
+}
+
+// Returns the extent of the texel data described by the location
+fn location_extent(location: &TextureCopyLocation) -> Result<Extent, String> {
+    let desc = location.resource().get_desc();
+
+    match location.copy_type() {
+        TextureCopyType::SubresourceIndex => {
+            if let ResourceDimension::Buffer = desc.dimension() {
+                return Err(String::from(
+                    "subresource index location refers to a buffer",
+                ));
+            }
+
+            subresource_extent(
+                &desc,
+                location
+                    .subresource_index()
+                    .expect("Location type is subresource index"),
+            )
+        }
+        TextureCopyType::PlacedFootprint => {
+            if !matches!(desc.dimension(), ResourceDimension::Buffer) {
+                return Err(String::from(
+                    "placed footprint location refers to a texture",
+                ));
+            }
+
+            let placed_footprint = location
+                .placed_footprint()
+                .expect("Location type is placed footprint");
+            let footprint = placed_footprint.footprint();
+            let offset = placed_footprint.offset();
+            let row_pitch = footprint.row_pitch();
+
+            if offset.0 % TEXTURE_DATA_PLACEMENT_ALIGNMENT.0 != 0 {
+                return Err(format!(
+                    "footprint offset {} is not aligned to {} bytes",
+                    offset.0, TEXTURE_DATA_PLACEMENT_ALIGNMENT.0
+                ));
+            }
+            if row_pitch.0 % TEXTURE_DATA_PITCH_ALIGNMENT.0 != 0 {
+                return Err(format!(
+                    "footprint row pitch {} is not aligned to {} bytes",
+                    row_pitch.0, TEXTURE_DATA_PITCH_ALIGNMENT.0
+                ));
+            }
+
+            let row_count = match is_block_compressed(footprint.format()) {
+                true => (footprint.height() as u64 + 3) / 4,
+                false => footprint.height() as u64,
+            };
+            // the last row does not have to be padded to the full pitch,
+            // so only the start of that row is checked
+            let last_row_offset = offset.0
+                + row_pitch.0
+                    * (row_count * footprint.depth() as u64).saturating_sub(1);
+            if last_row_offset >= desc.width() {
+                return Err(format!(
+                    "footprint ({}x{}x{}, row pitch {}) at offset {} \
+                     exceeds buffer size {}",
+                    footprint.width(),
+                    footprint.height(),
+                    footprint.depth(),
+                    row_pitch.0,
+                    offset.0,
+                    desc.width()
+                ));
+            }
+
+            Ok((footprint.width(), footprint.height(), footprint.depth()))
+        }
+    }
+}
+
+fn check_buffer_range(
+    name: &str,
+    resource: &Resource,
+    offset: ByteCount,
+    span: ByteCount,
+) -> Result<(), String> {
+    let desc = resource.get_desc();
+    if !matches!(desc.dimension(), ResourceDimension::Buffer) {
+        return Err(format!("{} is not a buffer", name));
+    }
+
+    match offset.0.checked_add(span.0) {
+        Some(end) if end <= desc.width() => Ok(()),
+        _ => Err(format!(
+            "{} range [{}, {} + {}) exceeds buffer size {}",
+            name,
+            offset.0,
+            offset.0,
+            span.0,
+            desc.width()
+        )),
+    }
+}
+
+pub(crate) fn validate_buffer_copy(
+    dest: &Resource,
+    dest_offset: ByteCount,
+    source: &Resource,
+    source_offset: ByteCount,
+    span: ByteCount,
+) -> Result<(), String> {
+    check_buffer_range("destination", dest, dest_offset, span)?;
+    check_buffer_range("source", source, source_offset, span)?;
+
+    if dest.this == source.this
+        && dest_offset.0 < source_offset.0 + span.0
+        && source_offset.0 < dest_offset.0 + span.0
+    {
+        return Err(String::from(
+            "source and destination ranges of the same buffer overlap",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn validate_tile_copy(
+    tiled_resource: &Resource,
+    region_size: &TileRegionSize,
+    buffer: &Resource,
+    buffer_offset: ByteCount,
+    flags: TileCopyFlags,
+) -> Result<(), String> {
+    let to_tiled_resource =
+        flags.contains(TileCopyFlags::LinearBufferToSwizzledTiledResource);
+    let to_buffer =
+        flags.contains(TileCopyFlags::SwizzledTiledResourceToLinearBuffer);
+    if to_tiled_resource == to_buffer {
+        return Err(String::from(
+            "exactly one copy direction flag must be specified",
+        ));
+    }
+
+    if tiled_resource.this == buffer.this {
+        return Err(String::from(
+            "tiled resource and buffer are the same resource",
+        ));
+    }
+
+    let tile_count = match region_size.use_box() {
+        true => {
+            region_size.width() as u64
+                * region_size.height() as u64
+                * region_size.depth() as u64
+        }
+        false => region_size.num_tiles() as u64,
+    };
+    check_buffer_range(
+        "buffer",
+        buffer,
+        buffer_offset,
+        ByteCount(tile_count * TILED_RESOURCE_TILE_SIZE.0),
+    )
+}
+
+pub(crate) fn validate_texture_copy(
+    dest_location: &TextureCopyLocation,
+    dest_x: u32,
+    dest_y: u32,
+    dest_z: u32,
+    source_location: &TextureCopyLocation,
+    source_box: Option<&Box>,
+) -> Result<(), String> {
+    let dest_extent = location_extent(dest_location)
+        .map_err(|err| format!("destination {}", err))?;
+    let source_extent = location_extent(source_location)
+        .map_err(|err| format!("source {}", err))?;
+
+    let copy_extent = match source_box {
+        Some(source_box) => {
+            if source_box.left() >= source_box.right()
+                || source_box.top() >= source_box.bottom()
+                || source_box.front() >= source_box.back()
+            {
+                return Err(String::from("source box is empty"));
+            }
+            if source_box.right() > source_extent.0
+                || source_box.bottom() > source_extent.1
+                || source_box.back() > source_extent.2
+            {
+                return Err(format!(
+                    "source box {:?} exceeds source extent {:?}",
+                    source_box, source_extent
+                ));
+            }
+
+            (
+                source_box.right() - source_box.left(),
+                source_box.bottom() - source_box.top(),
+                source_box.back() - source_box.front(),
+            )
+        }
+        None => source_extent,
+    };
+
+    if dest_x as u64 + copy_extent.0 as u64 > dest_extent.0 as u64
+        || dest_y as u64 + copy_extent.1 as u64 > dest_extent.1 as u64
+        || dest_z as u64 + copy_extent.2 as u64 > dest_extent.2 as u64
+    {
+        return Err(format!(
+            "copying {:?} texels to ({}, {}, {}) exceeds destination \
+             extent {:?}",
+            copy_extent, dest_x, dest_y, dest_z, dest_extent
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn validate_execute_indirect(
+    command_list_type: CommandListType,
+    command_signature: &CommandSignature,
+    max_command_count: u32,
+    argument_buffer: &Resource,
+    argument_buffer_offset: ByteCount,
+    count_buffer: Option<(&Resource, ByteCount)>,
+) -> Result<(), String> {
+    if command_signature.is_graphics()
+        && !matches!(command_list_type, CommandListType::Direct)
+    {
+        return Err(format!(
+            "{:?} commands can only be executed on direct command lists",
+            command_signature.command_type()
+        ));
+    }
+
+    if !argument_buffer_offset.is_aligned(INDIRECT_ARGUMENT_ALIGNMENT) {
+        return Err(format!(
+            "argument buffer offset {} is not a multiple of {}",
+            argument_buffer_offset.0, INDIRECT_ARGUMENT_ALIGNMENT.0
+        ));
+    }
+    if max_command_count > 0 {
+        // the last command does not need the padding up to the stride
+        let span = ByteCount(
+            command_signature.byte_stride().0 * (max_command_count as u64 - 1),
+        ) + command_signature.arguments_size();
+        check_buffer_range(
+            "argument buffer",
+            argument_buffer,
+            argument_buffer_offset,
+            span,
+        )
+        .map_err(|message| {
+            format!(
+                "{} (max command count {}, byte stride {})",
+                message,
+                max_command_count,
+                command_signature.byte_stride().0
+            )
+        })?;
+    }
+
+    if let Some((count_buffer, count_buffer_offset)) = count_buffer {
+        if !count_buffer_offset.is_aligned(INDIRECT_ARGUMENT_ALIGNMENT) {
+            return Err(format!(
+                "count buffer offset {} is not a multiple of {}",
+                count_buffer_offset.0, INDIRECT_ARGUMENT_ALIGNMENT.0
+            ));
+        }
+        check_buffer_range(
+            "count buffer",
+            count_buffer,
+            count_buffer_offset,
+            ByteCount::from(std::mem::size_of::<u32>()),
+        )?;
+    }
+
+    Ok(())
+}
+
+// Pipeline state tracking for draw validation. Command lists cannot carry
+// extra state, so the bindings are recorded in a process-wide table keyed
+// by the interface pointers, the same way `resource_stats` does it. The
+// entries are removed when the last wrapper reference is released, objects
+// released elsewhere can still leave stale ones behind
+
+#[derive(Debug)]
+struct PipelineInfo {
+    topology_type: PrimitiveTopologyType,
+    rtv_formats: Vec<Format>,
+}
+
+#[derive(Debug, Default)]
+struct CommandListBindings {
+    closed: bool,
+    descriptor_heaps_set: bool,
+    pipeline_state: Option<usize>,
+    topology: Option<PrimitiveTopology>,
+    // None if the formats of the bound views are unknown
+    rtv_formats: Option<Vec<Option<Format>>>,
+}
+
+#[derive(Debug, Default)]
+struct PipelineTracking {
+    // only PSOs created from GraphicsPipelineStateDesc, other ones
+    // are not checked
+    pipeline_states: HashMap<usize, PipelineInfo>,
+    // RTV descriptor address -> view format
+    render_target_views: HashMap<u64, Format>,
+    command_lists: HashMap<usize, CommandListBindings>,
+}
+
+static PIPELINE_TRACKING: Mutex<Option<PipelineTracking>> = Mutex::new(None);
+
+fn with_tracking<R>(f: impl FnOnce(&mut PipelineTracking) -> R) -> R {
+    let mut tracking = PIPELINE_TRACKING
+        .lock()
+        .expect("Pipeline tracking mutex is poisoned");
+    f(tracking.get_or_insert_with(PipelineTracking::default))
+}
+
+fn is_topology_compatible(
+    topology_type: PrimitiveTopologyType,
+    topology: PrimitiveTopology,
+) -> bool {
+    match topology_type {
+        PrimitiveTopologyType::Undefined => true,
+        PrimitiveTopologyType::Point => {
+            matches!(topology, PrimitiveTopology::PointList)
+        }
+        PrimitiveTopologyType::Line => matches!(
+            topology,
+            PrimitiveTopology::LineList
+                | PrimitiveTopology::LineStrip
+                | PrimitiveTopology::LineListAdj
+                | PrimitiveTopology::LineStripAdj
+        ),
+        PrimitiveTopologyType::Triangle => matches!(
+            topology,
+            PrimitiveTopology::TriangleList
+                | PrimitiveTopology::TriangleStrip
+                | PrimitiveTopology::TriangleListAdj
+                | PrimitiveTopology::TriangleStripAdj
+        ),
+        PrimitiveTopologyType::Patch => {
+            topology as i32 >= PrimitiveTopology::P1ControlPointPatchList as i32
+        }
+    }
+}
+
+pub(crate) fn record_graphics_pipeline_state(
+    pipeline_state: &PipelineState,
+    desc: &GraphicsPipelineStateDesc,
+) {
+    with_tracking(|tracking| {
+        tracking.pipeline_states.insert(
+            pipeline_state.this as usize,
+            PipelineInfo {
+                topology_type: desc.primitive_topology_type(),
+                rtv_formats: desc.rtv_formats().to_vec(),
+            },
+        )
+    });
+}
+
+// A PSO created in another way may reuse the address of a released one
+pub(crate) fn forget_pipeline_state(pipeline_state: &PipelineState) {
+    with_tracking(|tracking| {
+        tracking
+            .pipeline_states
+            .remove(&(pipeline_state.this as usize))
+    });
+}
+
+pub(crate) fn forget_command_list(command_list: usize) {
+    with_tracking(|tracking| tracking.command_lists.remove(&command_list));
+}
+
+pub(crate) fn record_render_target_view(
+    descriptor: CpuDescriptorHandle,
+    resource: &Resource,
+    view_format: Option<Format>,
+) {
+    let format = match view_format {
+        Some(format) if !matches!(format, Format::Unknown) => format,
+        _ => resource.get_desc().format(),
+    };
+    with_tracking(|tracking| {
+        tracking
+            .render_target_views
+            .insert(descriptor.hw_handle.ptr, format)
+    });
+}
+
+pub(crate) fn record_command_list_reset(
+    command_list: usize,
+    pipeline_state: Option<&PipelineState>,
+) {
+    with_tracking(|tracking| {
+        tracking.command_lists.insert(
+            command_list,
+            CommandListBindings {
+                pipeline_state: pipeline_state
+                    .map(|pipeline_state| pipeline_state.this as usize),
+                ..Default::default()
+            },
+        )
+    });
+}
+
+pub(crate) fn record_pipeline_state_binding(
+    command_list: usize,
+    pipeline_state: &PipelineState,
+) {
+    with_tracking(|tracking| {
+        tracking
+            .command_lists
+            .entry(command_list)
+            .or_default()
+            .pipeline_state = Some(pipeline_state.this as usize)
+    });
+}
+
+pub(crate) fn record_primitive_topology(
+    command_list: usize,
+    topology: PrimitiveTopology,
+) {
+    with_tracking(|tracking| {
+        tracking
+            .command_lists
+            .entry(command_list)
+            .or_default()
+            .topology = Some(topology)
+    });
+}
+
+pub(crate) fn record_render_targets(
+    command_list: usize,
+    descriptors: &[CpuDescriptorHandle],
+    single_handle_to_descriptor_range: bool,
+) {
+    with_tracking(|tracking| {
+        // the addresses of the descriptors following the first one
+        // in a range are not known without the handle size
+        let rtv_formats =
+            match single_handle_to_descriptor_range && descriptors.len() > 1 {
+                true => None,
+                false => Some(
+                    descriptors
+                        .iter()
+                        .map(|descriptor| {
+                            tracking
+                                .render_target_views
+                                .get(&descriptor.hw_handle.ptr)
+                                .copied()
+                        })
+                        .collect(),
+                ),
+            };
+        tracking
+            .command_lists
+            .entry(command_list)
+            .or_default()
+            .rtv_formats = rtv_formats;
+    });
+}
+
+pub(crate) fn record_command_list_close(command_list: usize) {
+    with_tracking(|tracking| {
+        tracking
+            .command_lists
+            .entry(command_list)
+            .or_default()
+            .closed = true
+    });
+}
+
+// Bundles inherit the descriptor heaps of the command list that executes
+// them, so they may only set them once, to the same heaps
+pub(crate) fn validate_descriptor_heaps(
+    command_list: usize,
+    command_list_type: CommandListType,
+) -> Result<(), String> {
+    with_tracking(|tracking| {
+        let bindings = tracking.command_lists.entry(command_list).or_default();
+        if matches!(command_list_type, CommandListType::Bundle)
+            && bindings.descriptor_heaps_set
+        {
+            return Err(String::from(
+                "descriptor heaps can only be set once per bundle recording",
+            ));
+        }
+        bindings.descriptor_heaps_set = true;
+
+        Ok(())
+    })
+}
+
+pub(crate) fn validate_bundle_execution(bundle: usize) -> Result<(), String> {
+    with_tracking(|tracking| match tracking.command_lists.get(&bundle) {
+        Some(bindings) if !bindings.closed => {
+            Err(String::from("the bundle has to be closed before execution"))
+        }
+        _ => Ok(()),
+    })
+}
+
+// Recording into a closed command list is a bug in the calling code, so
+// it panics in debug builds, release builds report the command and skip it.
+// Command lists created or reset outside of the wrappers are not known
+// and considered open
+pub(crate) fn validate_recording(
+    command_list: usize,
+    method_name: &str,
+) -> bool {
+    let closed = with_tracking(|tracking| {
+        tracking
+            .command_lists
+            .get(&command_list)
+            .map_or(false, |bindings| bindings.closed)
+    });
+    if !closed {
+        return true;
+    }
+
+    let message = "the command list is closed, it has to be reset before \
+                   recording";
+    if cfg!(debug_assertions) {
+        panic!("{}: {}", method_name, message);
+    }
+    log::error!("Skipping {}: {}", method_name, message);
+
+    false
+}
+
+// The bindings are only compared at draw time, since the PSO, the topology
+// and the render targets can legally be set in any order
+pub(crate) fn validate_draw(command_list: usize) -> Result<(), String> {
+    with_tracking(|tracking| {
+        let bindings = match tracking.command_lists.get(&command_list) {
+            Some(bindings) => bindings,
+            None => return Ok(()),
+        };
+        let pipeline_info = match bindings
+            .pipeline_state
+            .and_then(|pso| tracking.pipeline_states.get(&pso))
+        {
+            Some(pipeline_info) => pipeline_info,
+            None => return Ok(()),
+        };
+
+        if let Some(topology) = bindings.topology {
+            if !is_topology_compatible(pipeline_info.topology_type, topology) {
+                return Err(format!(
+                    "primitive topology {:?} does not match topology type \
+                     {:?} of the pipeline state",
+                    topology, pipeline_info.topology_type
+                ));
+            }
+        }
+
+        if let Some(rtv_formats) = &bindings.rtv_formats {
+            for (index, (view_format, pso_format)) in rtv_formats
+                .iter()
+                .zip(&pipeline_info.rtv_formats)
+                .enumerate()
+            {
+                match view_format {
+                    Some(view_format)
+                        if !matches!(pso_format, Format::Unknown)
+                            && *view_format as i32 != *pso_format as i32 =>
+                    {
+                        return Err(format!(
+                            "render target {} has format {:?}, but the \
+                             pipeline state expects {:?}",
+                            index, view_format, pso_format
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
@@ -0,0 +1,105 @@
+// Conversions between the wrappers and the COM interfaces of `windows`
+// crate. Both sides own a single reference, so converting by value moves
+// it over without touching the reference count, and converting
+// by reference adds one
+
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+use windows::core::Interface;
+use windows::Win32::Graphics::Direct3D12 as windows_d3d12;
+
+use crate::{
+    CommandAllocator, CommandList, CommandListKind, CommandQueue, Device,
+    DirectKind, Fence, Heap, PipelineState, QueryHeap, Resource, RootSignature,
+};
+
+macro_rules! impl_windows_interop {
+    ($wrapper:ty, $windows_type:ty) => {
+        impl From<$wrapper> for $windows_type {
+            fn from(object: $wrapper) -> Self {
+                let this = object.this;
+                std::mem::forget(object);
+                unsafe { <$windows_type>::from_raw(this as *mut c_void) }
+            }
+        }
+
+        impl From<&$wrapper> for $windows_type {
+            fn from(object: &$wrapper) -> Self {
+                object.clone().into()
+            }
+        }
+
+        impl From<$windows_type> for $wrapper {
+            fn from(object: $windows_type) -> Self {
+                Self {
+                    this: object.into_raw() as *mut _,
+                }
+            }
+        }
+
+        impl From<&$windows_type> for $wrapper {
+            fn from(object: &$windows_type) -> Self {
+                object.clone().into()
+            }
+        }
+    };
+}
+
+impl_windows_interop!(Device, windows_d3d12::ID3D12Device2);
+impl_windows_interop!(CommandQueue, windows_d3d12::ID3D12CommandQueue);
+impl_windows_interop!(CommandAllocator, windows_d3d12::ID3D12CommandAllocator);
+impl_windows_interop!(Resource, windows_d3d12::ID3D12Resource);
+impl_windows_interop!(Heap, windows_d3d12::ID3D12Heap);
+impl_windows_interop!(Fence, windows_d3d12::ID3D12Fence);
+impl_windows_interop!(RootSignature, windows_d3d12::ID3D12RootSignature);
+impl_windows_interop!(PipelineState, windows_d3d12::ID3D12PipelineState);
+impl_windows_interop!(QueryHeap, windows_d3d12::ID3D12QueryHeap);
+
+impl<K> From<CommandList<K>> for windows_d3d12::ID3D12GraphicsCommandList6 {
+    fn from(command_list: CommandList<K>) -> Self {
+        let this = command_list.this;
+        std::mem::forget(command_list);
+        unsafe { Self::from_raw(this as *mut c_void) }
+    }
+}
+
+impl<K> From<&CommandList<K>> for windows_d3d12::ID3D12GraphicsCommandList6 {
+    fn from(command_list: &CommandList<K>) -> Self {
+        command_list.clone().into()
+    }
+}
+
+/// The type of the list is checked at runtime, see
+/// [CommandList::try_into_kind]. The interface is given back if it doesn't
+/// match `K`
+impl<K: CommandListKind> TryFrom<windows_d3d12::ID3D12GraphicsCommandList6>
+    for CommandList<K>
+{
+    type Error = windows_d3d12::ID3D12GraphicsCommandList6;
+
+    fn try_from(
+        command_list: windows_d3d12::ID3D12GraphicsCommandList6,
+    ) -> Result<Self, Self::Error> {
+        let untyped_list = CommandList::<DirectKind> {
+            this: command_list.into_raw() as *mut _,
+            kind: PhantomData,
+        };
+
+        untyped_list
+            .try_into_kind()
+            .map_err(|command_list| command_list.into())
+    }
+}
+
+impl<K: CommandListKind> TryFrom<&windows_d3d12::ID3D12GraphicsCommandList6>
+    for CommandList<K>
+{
+    type Error = windows_d3d12::ID3D12GraphicsCommandList6;
+
+    fn try_from(
+        command_list: &windows_d3d12::ID3D12GraphicsCommandList6,
+    ) -> Result<Self, Self::Error> {
+        command_list.clone().try_into()
+    }
+}